@@ -9,6 +9,12 @@ pub enum Align {
 
     /// Align at the end of the axis.
     End,
+
+    /// Align the baselines of the text of the items.
+    ///
+    /// Only the items of a `Row` can be aligned on their baselines. Items
+    /// without any text, and any other container, align at the start.
+    Baseline,
 }
 
 /// The horizontal alignment of some resource.
@@ -25,7 +31,7 @@ pub enum HorizontalAlignment {
 }
 
 /// The vertical alignment of some resource.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum VerticalAlignment {
     /// Align top
     Top,
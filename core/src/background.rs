@@ -12,4 +12,4 @@ impl From<Color> for Background {
     fn from(color: Color) -> Self {
         Background::Color(color)
     }
-}
\ No newline at end of file
+}
@@ -65,6 +65,28 @@ impl Color {
             self.a,
         ]
     }
+
+    /// Creates a [`Color`] from its linear values.
+    ///
+    /// [`Color`]: struct.Color.html
+    pub fn from_linear([r, g, b, a]: [f32; 4]) -> Color {
+        // As described in:
+        // https://en.wikipedia.org/wiki/SRGB#The_forward_transformation_(CIE_XYZ_to_sRGB)
+        fn gamma_component(u: f32) -> f32 {
+            if u < 0.003_130_8 {
+                u * 12.92
+            } else {
+                1.055 * u.powf(1.0 / 2.4) - 0.055
+            }
+        }
+
+        Color {
+            r: gamma_component(r),
+            g: gamma_component(g),
+            b: gamma_component(b),
+            a,
+        }
+    }
 }
 
 impl From<[f32; 3]> for Color {
@@ -1,4 +1,7 @@
+use crate::{Dialog, Notification, Toast};
+
 use futures::future::{BoxFuture, Future, FutureExt};
+use std::path::PathBuf;
 
 /// A collection of async operations.
 ///
@@ -7,7 +10,11 @@ use futures::future::{BoxFuture, Future, FutureExt};
 ///
 /// [`Command`]: struct.Command.html
 pub struct Command<T> {
-    futures: Vec<BoxFuture<'static, T>>,
+    futures: Vec<BoxFuture<'static, Option<T>>>,
+    toasts: Vec<Toast<T>>,
+    dialogs: Vec<Dialog<T>>,
+    notifications: Vec<Notification<T>>,
+    urls: Vec<String>,
 }
 
 impl<T> Command<T> {
@@ -19,6 +26,10 @@ impl<T> Command<T> {
     pub fn none() -> Self {
         Self {
             futures: Vec::new(),
+            toasts: Vec::new(),
+            dialogs: Vec::new(),
+            notifications: Vec::new(),
+            urls: Vec::new(),
         }
     }
 
@@ -30,7 +41,166 @@ impl<T> Command<T> {
         f: impl Fn(T) -> A + 'static + Send,
     ) -> Command<A> {
         Command {
-            futures: vec![future.map(f).boxed()],
+            futures: vec![future.map(move |result| Some(f(result))).boxed()],
+            toasts: Vec::new(),
+            dialogs: Vec::new(),
+            notifications: Vec::new(),
+            urls: Vec::new(),
+        }
+    }
+
+    /// Creates a [`Command`] that posts a notification to the operating
+    /// system, with an optional icon.
+    ///
+    /// Unlike the contents of the window, notifications are noticeable even
+    /// when the application is minimized.
+    ///
+    /// Native runtimes post the notification using `notify-send` on Linux,
+    /// AppleScript on macOS, and PowerShell on Windows. If they are not
+    /// available, the [`Command`] does nothing. On the web, it does nothing
+    /// either.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn notify(
+        title: impl Into<String>,
+        body: impl Into<String>,
+        icon: Option<PathBuf>,
+    ) -> Self {
+        Self::notification(Notification {
+            title: title.into(),
+            body: body.into(),
+            icon,
+            on_click: None,
+        })
+    }
+
+    /// Creates a [`Command`] that posts a notification to the operating
+    /// system, like [`notify`], and produces the given message once the user
+    /// clicks on it.
+    ///
+    /// Click-back messages are only supported by the notification servers
+    /// implementing actions on Linux. If the notification is dismissed, no
+    /// message is produced.
+    ///
+    /// On macOS, the notification is posted with AppleScript, which cannot
+    /// tell whether it was clicked, so the message is never produced. On
+    /// Windows, PowerShell cannot either, so the message is never produced
+    /// there as well.
+    ///
+    /// [`Command`]: struct.Command.html
+    /// [`notify`]: #method.notify
+    pub fn notify_clickable(
+        title: impl Into<String>,
+        body: impl Into<String>,
+        icon: Option<PathBuf>,
+        on_click: T,
+    ) -> Self {
+        Self::notification(Notification {
+            title: title.into(),
+            body: body.into(),
+            icon,
+            on_click: Some(on_click),
+        })
+    }
+
+    /// Creates a [`Command`] that posts the given [`Notification`] to the
+    /// operating system.
+    ///
+    /// [`Command`]: struct.Command.html
+    /// [`Notification`]: notification/struct.Notification.html
+    pub fn notification(notification: Notification<T>) -> Self {
+        Self {
+            futures: Vec::new(),
+            toasts: Vec::new(),
+            dialogs: Vec::new(),
+            notifications: vec![notification],
+            urls: Vec::new(),
+        }
+    }
+
+    /// Creates a [`Command`] that opens the given URL with the default
+    /// application of the operating system, like a web browser.
+    ///
+    /// Native runtimes open the URL using `xdg-open` on Linux, `open` on
+    /// macOS, and the URL protocol handler on Windows. On the web, the
+    /// [`Command`] does nothing for now. It produces no message.
+    ///
+    /// By default, native runtimes only open `http`, `https`, and `mailto`
+    /// URLs, and drop any other. Other schemes need to be allowed in the
+    /// settings of the application.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn open_url(url: impl Into<String>) -> Self {
+        Self {
+            futures: Vec::new(),
+            toasts: Vec::new(),
+            dialogs: Vec::new(),
+            notifications: Vec::new(),
+            urls: vec![url.into()],
+        }
+    }
+
+    /// Creates a [`Command`] that shows a [`Toast`] in the notification area
+    /// of the window.
+    ///
+    /// Toasts stack in the bottom right corner, above the rest of the user
+    /// interface. Clicking a [`Toast`] dismisses it and produces its message,
+    /// if any.
+    ///
+    /// Toasts are only shown by native runtimes. On the web, the [`Command`]
+    /// does nothing.
+    ///
+    /// [`Command`]: struct.Command.html
+    /// [`Toast`]: toast/struct.Toast.html
+    pub fn toast(toast: Toast<T>) -> Self {
+        Self {
+            futures: Vec::new(),
+            toasts: vec![toast],
+            dialogs: Vec::new(),
+            notifications: Vec::new(),
+            urls: Vec::new(),
+        }
+    }
+
+    /// Creates a [`Command`] that shows a [`Dialog`] on top of the window.
+    ///
+    /// The [`Dialog`] produces its message, if any, once the user closes it.
+    ///
+    /// # Example
+    /// ```
+    /// # use iced_core::{Command, Dialog};
+    /// #
+    /// #[derive(Debug, Clone)]
+    /// enum Message {
+    ///     QuitRequested,
+    ///     QuitConfirmed(Option<usize>),
+    /// }
+    ///
+    /// fn update(message: Message) -> Command<Message> {
+    ///     match message {
+    ///         Message::QuitRequested => Command::dialog(Dialog::confirm(
+    ///             "Quit",
+    ///             "You have unsaved changes.",
+    ///             &["Save", "Discard", "Cancel"],
+    ///             Message::QuitConfirmed,
+    ///         )),
+    ///         Message::QuitConfirmed(_) => Command::none(),
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Dialogs are only shown by native runtimes. On the web, the [`Command`]
+    /// does nothing.
+    ///
+    /// [`Command`]: struct.Command.html
+    /// [`Dialog`]: dialog/struct.Dialog.html
+    pub fn dialog(dialog: Dialog<T>) -> Self {
+        Self {
+            futures: Vec::new(),
+            toasts: Vec::new(),
+            dialogs: vec![dialog],
+            notifications: Vec::new(),
+            urls: Vec::new(),
         }
     }
 
@@ -53,9 +223,29 @@ impl<T> Command<T> {
                 .map(|future| {
                     let f = f.clone();
 
-                    future.map(move |result| f(result)).boxed()
+                    future.map(move |result| result.map(|r| f(r))).boxed()
                 })
                 .collect(),
+            toasts: self
+                .toasts
+                .drain(..)
+                .map(|toast| toast.map(|message| f(message)))
+                .collect(),
+            dialogs: self
+                .dialogs
+                .drain(..)
+                .map(|dialog| {
+                    let f = f.clone();
+
+                    dialog.map(move |message| f(message))
+                })
+                .collect(),
+            notifications: self
+                .notifications
+                .drain(..)
+                .map(|notification| notification.map(|message| f(message)))
+                .collect(),
+            urls: self.urls,
         }
     }
 
@@ -66,26 +256,70 @@ impl<T> Command<T> {
     ///
     /// [`Command`]: struct.Command.html
     pub fn batch(commands: impl Iterator<Item = Command<T>>) -> Self {
-        Self {
-            futures: commands.flat_map(|command| command.futures).collect(),
+        let mut batch = Self::none();
+
+        for command in commands {
+            batch.futures.extend(command.futures);
+            batch.toasts.extend(command.toasts);
+            batch.dialogs.extend(command.dialogs);
+            batch.notifications.extend(command.notifications);
+            batch.urls.extend(command.urls);
         }
+
+        batch
+    }
+
+    /// Takes the toasts that the [`Command`] shows, leaving its futures.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn take_toasts(&mut self) -> Vec<Toast<T>> {
+        std::mem::take(&mut self.toasts)
+    }
+
+    /// Takes the dialogs that the [`Command`] shows, leaving its futures.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn take_dialogs(&mut self) -> Vec<Dialog<T>> {
+        std::mem::take(&mut self.dialogs)
+    }
+
+    /// Takes the notifications that the [`Command`] posts, leaving its
+    /// futures.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn take_notifications(&mut self) -> Vec<Notification<T>> {
+        std::mem::take(&mut self.notifications)
+    }
+
+    /// Takes the URLs that the [`Command`] opens, leaving its futures.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn take_urls(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.urls)
     }
 
     /// Converts a [`Command`] into its underlying list of futures.
     ///
+    /// Any toasts, dialogs, notifications and URLs are discarded.
+    ///
     /// [`Command`]: struct.Command.html
-    pub fn futures(self) -> Vec<BoxFuture<'static, T>> {
+    pub fn futures(self) -> Vec<BoxFuture<'static, Option<T>>> {
         self.futures
     }
 }
 
 impl<T, A> From<A> for Command<T>
 where
+    T: 'static,
     A: Future<Output = T> + 'static + Send,
 {
     fn from(future: A) -> Self {
         Self {
-            futures: vec![future.boxed()],
+            futures: vec![future.map(Some).boxed()],
+            toasts: Vec::new(),
+            dialogs: Vec::new(),
+            notifications: Vec::new(),
+            urls: Vec::new(),
         }
     }
 }
@@ -0,0 +1,157 @@
+//! Ask the user for a decision with a dialog on top of the window.
+//!
+//! A [`Dialog`] is shown with [`Command::dialog`], and it produces a message
+//! once the user closes it.
+//!
+//! Dialogs are modal to the whole application. If a dialog is requested while
+//! another one is open, it is queued and shown once the previous one closes,
+//! in the same order they were requested.
+//!
+//! [`Dialog`]: struct.Dialog.html
+//! [`Command::dialog`]: ../struct.Command.html#method.dialog
+
+/// A message along with some buttons to choose from and, optionally, a text
+/// field.
+///
+/// Native runtimes show it centered on top of the user interface, blocking
+/// the rest of it until it is closed. Pressing `Escape` or clicking outside
+/// of the [`Dialog`] dismisses it.
+///
+/// [`Dialog`]: struct.Dialog.html
+pub struct Dialog<T> {
+    /// The title of the [`Dialog`]
+    ///
+    /// [`Dialog`]: struct.Dialog.html
+    pub title: String,
+
+    /// The body of the [`Dialog`]
+    ///
+    /// [`Dialog`]: struct.Dialog.html
+    pub body: String,
+
+    /// The labels of the buttons of the [`Dialog`]
+    ///
+    /// [`Dialog`]: struct.Dialog.html
+    pub buttons: Vec<String>,
+
+    /// The initial contents of the text field of the [`Dialog`], if it has
+    /// one
+    ///
+    /// [`Dialog`]: struct.Dialog.html
+    pub input: Option<String>,
+
+    on_close: Box<dyn FnOnce(Option<usize>, String) -> Option<T> + Send>,
+}
+
+impl<T> Dialog<T> {
+    /// Creates a [`Dialog`] showing a message with a single button to
+    /// acknowledge it.
+    ///
+    /// It produces no message once closed.
+    ///
+    /// [`Dialog`]: struct.Dialog.html
+    pub fn alert(title: impl Into<String>, body: impl Into<String>) -> Self {
+        Dialog {
+            title: title.into(),
+            body: body.into(),
+            buttons: vec![String::from("OK")],
+            input: None,
+            on_close: Box::new(|_, _| None),
+        }
+    }
+
+    /// Creates a [`Dialog`] showing a message along with some buttons to
+    /// choose from.
+    ///
+    /// Once closed, it produces the message returned by the given function
+    /// with the index of the chosen button, or `None` if the [`Dialog`] was
+    /// dismissed.
+    ///
+    /// [`Dialog`]: struct.Dialog.html
+    pub fn confirm(
+        title: impl Into<String>,
+        body: impl Into<String>,
+        buttons: &[&str],
+        on_choice: impl FnOnce(Option<usize>) -> T + Send + 'static,
+    ) -> Self {
+        Dialog {
+            title: title.into(),
+            body: body.into(),
+            buttons: buttons
+                .iter()
+                .map(|button| String::from(*button))
+                .collect(),
+            input: None,
+            on_close: Box::new(move |choice, _| Some(on_choice(choice))),
+        }
+    }
+
+    /// Creates a [`Dialog`] showing a message along with a text field,
+    /// prefilled with the given value.
+    ///
+    /// Once closed, it produces the message returned by the given function
+    /// with the contents of the field, or `None` if the [`Dialog`] was
+    /// cancelled.
+    ///
+    /// [`Dialog`]: struct.Dialog.html
+    pub fn prompt(
+        title: impl Into<String>,
+        body: impl Into<String>,
+        value: impl Into<String>,
+        on_submit: impl FnOnce(Option<String>) -> T + Send + 'static,
+    ) -> Self {
+        Dialog {
+            title: title.into(),
+            body: body.into(),
+            buttons: vec![String::from("OK"), String::from("Cancel")],
+            input: Some(value.into()),
+            on_close: Box::new(move |choice, value| {
+                Some(on_submit(match choice {
+                    Some(0) => Some(value),
+                    _ => None,
+                }))
+            }),
+        }
+    }
+
+    /// Closes the [`Dialog`] with the index of the chosen button, or `None`
+    /// if it was dismissed, along with the contents of its text field.
+    ///
+    /// It returns the message produced by the [`Dialog`], if any.
+    ///
+    /// [`Dialog`]: struct.Dialog.html
+    pub fn close(self, choice: Option<usize>, value: String) -> Option<T> {
+        (self.on_close)(choice, value)
+    }
+
+    /// Applies a transformation to the message produced by the [`Dialog`].
+    ///
+    /// [`Dialog`]: struct.Dialog.html
+    pub fn map<A>(self, f: impl FnOnce(T) -> A + Send + 'static) -> Dialog<A>
+    where
+        T: 'static,
+    {
+        let on_close = self.on_close;
+
+        Dialog {
+            title: self.title,
+            body: self.body,
+            buttons: self.buttons,
+            input: self.input,
+            on_close: Box::new(move |choice, value| {
+                on_close(choice, value).map(f)
+            }),
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for Dialog<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Dialog")
+            .field("title", &self.title)
+            .field("body", &self.body)
+            .field("buttons", &self.buttons)
+            .field("input", &self.input)
+            .finish()
+    }
+}
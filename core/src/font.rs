@@ -1,3 +1,5 @@
+//! Choose the typeface of your text.
+
 /// A font.
 #[derive(Debug, Clone, Copy)]
 pub enum Font {
@@ -15,4 +17,106 @@ pub enum Font {
         /// The bytes of the external font
         bytes: &'static [u8],
     },
+
+    /// A font installed in the system, found by its family name.
+    ///
+    /// The closest match to the [`Weight`] and [`Style`] is used, like a bold
+    /// face for a semibold weight. If the family is not installed, the
+    /// [`Default`] font is used instead.
+    ///
+    /// [`Weight`]: enum.Weight.html
+    /// [`Style`]: enum.Style.html
+    /// [`Default`]: #variant.Default
+    System {
+        /// The family name of the font, like `"Segoe UI"` or `"Noto Sans"`
+        family: &'static str,
+
+        /// The weight of the font
+        weight: Weight,
+
+        /// The style of the font
+        style: Style,
+    },
+}
+
+impl Font {
+    /// Returns the regular face of the installed font family with the given
+    /// name.
+    pub const fn system(family: &'static str) -> Font {
+        Font::System {
+            family,
+            weight: Weight::Normal,
+            style: Style::Normal,
+        }
+    }
+}
+
+/// The weight of a [`Font`], or how thick its strokes are.
+///
+/// [`Font`]: enum.Font.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Weight {
+    /// A weight of 100.
+    Thin,
+
+    /// A weight of 200.
+    ExtraLight,
+
+    /// A weight of 300.
+    Light,
+
+    /// A weight of 400.
+    #[default]
+    Normal,
+
+    /// A weight of 500.
+    Medium,
+
+    /// A weight of 600.
+    Semibold,
+
+    /// A weight of 700.
+    Bold,
+
+    /// A weight of 800.
+    ExtraBold,
+
+    /// A weight of 900.
+    Black,
+}
+
+impl Weight {
+    /// Returns the numeric value of the [`Weight`], as used by CSS and
+    /// OpenType.
+    ///
+    /// [`Weight`]: enum.Weight.html
+    pub fn value(self) -> u16 {
+        match self {
+            Weight::Thin => 100,
+            Weight::ExtraLight => 200,
+            Weight::Light => 300,
+            Weight::Normal => 400,
+            Weight::Medium => 500,
+            Weight::Semibold => 600,
+            Weight::Bold => 700,
+            Weight::ExtraBold => 800,
+            Weight::Black => 900,
+        }
+    }
+}
+
+/// The style of a [`Font`].
+///
+/// [`Font`]: enum.Font.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Style {
+    /// Upright glyphs.
+    #[default]
+    Normal,
+
+    /// Cursive glyphs, designed to be slanted.
+    Italic,
+
+    /// Upright glyphs, slanted artificially.
+    Oblique,
 }
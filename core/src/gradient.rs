@@ -0,0 +1,243 @@
+//! Blend colors smoothly.
+use crate::Color;
+
+/// A ramp of colors, defined by a list of [`ColorStop`]s.
+///
+/// A [`Gradient`] only describes how colors change along a normalized offset
+/// from `0.0` to `1.0`. How that offset maps to the screen, like the angle of
+/// a linear gradient or the center of a radial one, is up to its user.
+///
+/// # Example
+/// ```
+/// use iced_core::{
+///     gradient::{Gradient, Interpolation},
+///     Color,
+/// };
+///
+/// let gradient = Gradient::new()
+///     .add_stop(0.0, Color::BLACK)
+///     .add_stop(1.0, Color::WHITE)
+///     .interpolation(Interpolation::Oklab);
+///
+/// let middle = gradient.color_at(0.5).unwrap();
+/// ```
+///
+/// [`ColorStop`]: struct.ColorStop.html
+/// [`Gradient`]: struct.Gradient.html
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Gradient {
+    stops: Vec<ColorStop>,
+    interpolation: Interpolation,
+}
+
+impl Gradient {
+    /// Creates a new [`Gradient`] with no stops.
+    ///
+    /// [`Gradient`]: struct.Gradient.html
+    pub fn new() -> Self {
+        Gradient::default()
+    }
+
+    /// Adds a [`ColorStop`] to the [`Gradient`].
+    ///
+    /// The offset is clamped between `0.0` and `1.0`. Stops are kept sorted by
+    /// offset. A stop with the same offset as an existing one is placed after
+    /// it, producing a hard edge.
+    ///
+    /// [`ColorStop`]: struct.ColorStop.html
+    /// [`Gradient`]: struct.Gradient.html
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        let offset = offset.clamp(0.0, 1.0);
+
+        let index = self
+            .stops
+            .iter()
+            .position(|stop| stop.offset > offset)
+            .unwrap_or(self.stops.len());
+
+        self.stops.insert(index, ColorStop { offset, color });
+        self
+    }
+
+    /// Sets the [`Interpolation`] of the [`Gradient`].
+    ///
+    /// [`Interpolation`]: enum.Interpolation.html
+    /// [`Gradient`]: struct.Gradient.html
+    pub fn interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Returns the [`ColorStop`]s of the [`Gradient`], sorted by offset.
+    ///
+    /// [`ColorStop`]: struct.ColorStop.html
+    /// [`Gradient`]: struct.Gradient.html
+    pub fn stops(&self) -> &[ColorStop] {
+        &self.stops
+    }
+
+    /// Returns the [`Interpolation`] of the [`Gradient`].
+    ///
+    /// [`Interpolation`]: enum.Interpolation.html
+    /// [`Gradient`]: struct.Gradient.html
+    pub fn interpolation_mode(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    /// Returns the [`Color`] of the [`Gradient`] at the given offset.
+    ///
+    /// Offsets before the first stop or after the last one take the color of
+    /// the closest stop. It returns `None` if the [`Gradient`] has no stops.
+    ///
+    /// [`Color`]: ../struct.Color.html
+    /// [`Gradient`]: struct.Gradient.html
+    pub fn color_at(&self, offset: f32) -> Option<Color> {
+        let first = self.stops.first()?;
+        let last = self.stops.last()?;
+
+        if offset <= first.offset {
+            return Some(first.color);
+        }
+
+        if offset >= last.offset {
+            return Some(last.color);
+        }
+
+        self.stops.windows(2).find_map(|pair| {
+            let (start, end) = (pair[0], pair[1]);
+
+            if offset < start.offset || offset >= end.offset {
+                return None;
+            }
+
+            let t = (offset - start.offset) / (end.offset - start.offset);
+
+            Some(self.interpolation.mix(start.color, end.color, t))
+        })
+    }
+}
+
+/// A [`Color`] at a specific offset of a [`Gradient`].
+///
+/// [`Color`]: ../struct.Color.html
+/// [`Gradient`]: struct.Gradient.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorStop {
+    /// The offset of the stop, between `0.0` and `1.0`.
+    pub offset: f32,
+
+    /// The color of the stop.
+    pub color: Color,
+}
+
+/// The color space used to blend the colors of a [`Gradient`].
+///
+/// Alpha is always blended linearly, and colors are premultiplied by it while
+/// blending to avoid dark fringes around transparent stops.
+///
+/// [`Gradient`]: struct.Gradient.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Interpolation {
+    /// Blend the sRGB components directly.
+    ///
+    /// This is how most browsers and design tools blend gradients by default.
+    #[default]
+    Srgb,
+
+    /// Blend the linear-light RGB components.
+    ///
+    /// This is physically accurate, but midpoints look lighter than expected.
+    LinearRgb,
+
+    /// Blend in the [Oklab] color space.
+    ///
+    /// Midpoints keep a perceptually even lightness and hue.
+    ///
+    /// [Oklab]: https://bottosson.github.io/posts/oklab/
+    Oklab,
+}
+
+impl Interpolation {
+    /// Blends two colors, where `t` goes from `0.0`, producing `a`, to `1.0`,
+    /// producing `b`.
+    pub fn mix(self, a: Color, b: Color, t: f32) -> Color {
+        let alpha = a.a + (b.a - a.a) * t;
+
+        if alpha <= 0.0 {
+            return Color { a: 0.0, ..a };
+        }
+
+        let [a0, a1, a2] = self.encode(a);
+        let [b0, b1, b2] = self.encode(b);
+
+        let blend =
+            |u: f32, v: f32| (u * a.a + (v * b.a - u * a.a) * t) / alpha;
+
+        let [r, g, b] =
+            self.decode([blend(a0, b0), blend(a1, b1), blend(a2, b2)]);
+
+        Color { r, g, b, a: alpha }
+    }
+
+    fn encode(self, color: Color) -> [f32; 3] {
+        match self {
+            Interpolation::Srgb => [color.r, color.g, color.b],
+            Interpolation::LinearRgb => {
+                let [r, g, b, _] = color.into_linear();
+
+                [r, g, b]
+            }
+            Interpolation::Oklab => {
+                let [r, g, b, _] = color.into_linear();
+
+                linear_to_oklab([r, g, b])
+            }
+        }
+    }
+
+    fn decode(self, components: [f32; 3]) -> [f32; 3] {
+        let [r, g, b] = match self {
+            Interpolation::Srgb => return components,
+            Interpolation::LinearRgb => components,
+            Interpolation::Oklab => oklab_to_linear(components),
+        };
+
+        // Blending in Oklab may produce colors outside of the sRGB gamut
+        let Color { r, g, b, .. } = Color::from_linear([
+            r.clamp(0.0, 1.0),
+            g.clamp(0.0, 1.0),
+            b.clamp(0.0, 1.0),
+            1.0,
+        ]);
+
+        [r, g, b]
+    }
+}
+
+// As described in:
+// https://bottosson.github.io/posts/oklab/#converting-from-linear-srgb-to-oklab
+fn linear_to_oklab([r, g, b]: [f32; 3]) -> [f32; 3] {
+    let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+    let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+    let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+
+    [
+        0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+        1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+        0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+    ]
+}
+
+fn oklab_to_linear([l, a, b]: [f32; 3]) -> [f32; 3] {
+    let l_ = l + 0.396_337_78 * a + 0.215_803_76 * b;
+    let m_ = l - 0.105_561_346 * a - 0.063_854_17 * b;
+    let s_ = l - 0.089_484_18 * a - 1.291_485_5 * b;
+
+    let (l, m, s) = (l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_);
+
+    [
+        4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+        -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+        -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+    ]
+}
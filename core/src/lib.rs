@@ -18,19 +18,33 @@
 mod align;
 mod background;
 mod color;
-mod font;
 mod length;
 mod point;
 mod rectangle;
+mod shadow;
 mod vector;
 
+pub mod dialog;
+pub mod font;
+pub mod gradient;
+pub mod notification;
+pub mod path;
+pub mod text;
+pub mod toast;
+
 pub use align::{Align, HorizontalAlignment, VerticalAlignment};
 pub use background::Background;
 pub use color::Color;
+pub use dialog::Dialog;
 pub use font::Font;
+pub use gradient::Gradient;
 pub use length::Length;
+pub use notification::Notification;
+pub use path::Path;
 pub use point::Point;
 pub use rectangle::Rectangle;
+pub use shadow::Shadow;
+pub use toast::Toast;
 pub use vector::Vector;
 
 #[cfg(feature = "command")]
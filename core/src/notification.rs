@@ -0,0 +1,47 @@
+//! Post notifications to the operating system.
+use std::path::PathBuf;
+
+/// A notification posted to the operating system, outside of the window.
+///
+/// Unlike the contents of the window, a [`Notification`] is noticeable even
+/// when the application is minimized. Native runtimes post the notifications
+/// of every [`Command::notify`].
+///
+/// [`Notification`]: struct.Notification.html
+/// [`Command::notify`]: ../struct.Command.html#method.notify
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification<T> {
+    /// The title of the [`Notification`]
+    ///
+    /// [`Notification`]: struct.Notification.html
+    pub title: String,
+
+    /// The body of the [`Notification`]
+    ///
+    /// [`Notification`]: struct.Notification.html
+    pub body: String,
+
+    /// The path of the icon of the [`Notification`], if any
+    ///
+    /// [`Notification`]: struct.Notification.html
+    pub icon: Option<PathBuf>,
+
+    /// The message produced when the [`Notification`] is clicked, if any
+    ///
+    /// [`Notification`]: struct.Notification.html
+    pub on_click: Option<T>,
+}
+
+impl<T> Notification<T> {
+    /// Applies a transformation to the message of the [`Notification`].
+    ///
+    /// [`Notification`]: struct.Notification.html
+    pub fn map<A>(self, f: impl FnOnce(T) -> A) -> Notification<A> {
+        Notification {
+            title: self.title,
+            body: self.body,
+            icon: self.icon,
+            on_click: self.on_click.map(f),
+        }
+    }
+}
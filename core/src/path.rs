@@ -0,0 +1,141 @@
+//! Describe two-dimensional shapes.
+mod builder;
+mod svg;
+
+pub use builder::Builder;
+pub use svg::Error;
+
+use crate::{Point, Vector};
+
+/// A two-dimensional shape, made of straight lines and curves.
+///
+/// A [`Path`] can be created with a [`Builder`] or loaded from [SVG path
+/// data].
+///
+/// # Example
+/// ```
+/// use iced_core::{Path, Point};
+///
+/// let triangle = Path::new(|builder| {
+///     builder.move_to(Point::new(0.0, 0.0));
+///     builder.line_to(Point::new(10.0, 0.0));
+///     builder.line_to(Point::new(5.0, 10.0));
+///     builder.close();
+/// });
+///
+/// assert_eq!(triangle.to_svg_path(), "M0 0 L10 0 L5 10 Z");
+/// assert_eq!(Path::from_svg_path("M0,0 h10 l-5 10 z"), Ok(triangle));
+/// ```
+///
+/// [`Path`]: struct.Path.html
+/// [`Builder`]: struct.Builder.html
+/// [SVG path data]: https://www.w3.org/TR/SVG/paths.html#PathData
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Path {
+    segments: Vec<Segment>,
+}
+
+impl Path {
+    /// Creates a new [`Path`] with the provided closure.
+    ///
+    /// Use the [`Builder`] to configure your [`Path`].
+    ///
+    /// [`Path`]: struct.Path.html
+    /// [`Builder`]: struct.Builder.html
+    pub fn new(f: impl FnOnce(&mut Builder)) -> Self {
+        let mut builder = Builder::new();
+
+        f(&mut builder);
+
+        builder.build()
+    }
+
+    /// Parses a [`Path`] from [SVG path data], like the `d` attribute of a
+    /// `path` element.
+    ///
+    /// Relative commands and shorthand curves are converted into their
+    /// absolute, explicit counterparts.
+    ///
+    /// [`Path`]: struct.Path.html
+    /// [SVG path data]: https://www.w3.org/TR/SVG/paths.html#PathData
+    pub fn from_svg_path(data: &str) -> Result<Self, Error> {
+        svg::parse(data)
+    }
+
+    /// Serializes the [`Path`] into [SVG path data], using absolute commands.
+    ///
+    /// [`Path`]: struct.Path.html
+    /// [SVG path data]: https://www.w3.org/TR/SVG/paths.html#PathData
+    pub fn to_svg_path(&self) -> String {
+        svg::serialize(self)
+    }
+
+    /// Returns the segments of the [`Path`].
+    ///
+    /// [`Path`]: struct.Path.html
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    /// Returns true if the [`Path`] has no segments.
+    ///
+    /// [`Path`]: struct.Path.html
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+}
+
+/// A segment of a [`Path`], in absolute coordinates.
+///
+/// [`Path`]: struct.Path.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Segment {
+    /// Starts a new subpath at the given point.
+    MoveTo(Point),
+
+    /// Draws a straight line to the given point.
+    LineTo(Point),
+
+    /// Draws a quadratic Bézier curve to the given point.
+    QuadraticTo {
+        /// The control point of the curve.
+        control: Point,
+
+        /// The end point of the curve.
+        to: Point,
+    },
+
+    /// Draws a cubic Bézier curve to the given point.
+    CubicTo {
+        /// The first control point of the curve.
+        control_a: Point,
+
+        /// The second control point of the curve.
+        control_b: Point,
+
+        /// The end point of the curve.
+        to: Point,
+    },
+
+    /// Draws an elliptical arc to the given point, like the `A` command of
+    /// SVG path data.
+    ArcTo {
+        /// The radii of the ellipse.
+        radii: Vector,
+
+        /// The rotation of the ellipse, in degrees.
+        x_rotation: f32,
+
+        /// Whether the arc spans more than 180 degrees.
+        large_arc: bool,
+
+        /// Whether the arc is drawn in the positive-angle direction.
+        sweep: bool,
+
+        /// The end point of the arc.
+        to: Point,
+    },
+
+    /// Closes the current subpath with a straight line to its starting point.
+    Close,
+}
@@ -0,0 +1,87 @@
+use crate::{
+    path::{Path, Segment},
+    Point, Vector,
+};
+
+/// A [`Path`] builder.
+///
+/// Once a [`Path`] is built, it can no longer be mutated.
+///
+/// [`Path`]: struct.Path.html
+#[derive(Debug, Clone, Default)]
+pub struct Builder {
+    segments: Vec<Segment>,
+}
+
+impl Builder {
+    /// Creates a new [`Builder`].
+    ///
+    /// [`Builder`]: struct.Builder.html
+    pub fn new() -> Self {
+        Builder::default()
+    }
+
+    /// Starts a new subpath at the given point.
+    pub fn move_to(&mut self, point: Point) {
+        self.segments.push(Segment::MoveTo(point));
+    }
+
+    /// Connects the last point of the current subpath to the given point
+    /// with a straight line.
+    pub fn line_to(&mut self, point: Point) {
+        self.segments.push(Segment::LineTo(point));
+    }
+
+    /// Adds a quadratic Bézier curve to the current subpath.
+    pub fn quadratic_curve_to(&mut self, control: Point, to: Point) {
+        self.segments.push(Segment::QuadraticTo { control, to });
+    }
+
+    /// Adds a cubic Bézier curve to the current subpath.
+    pub fn bezier_curve_to(
+        &mut self,
+        control_a: Point,
+        control_b: Point,
+        to: Point,
+    ) {
+        self.segments.push(Segment::CubicTo {
+            control_a,
+            control_b,
+            to,
+        });
+    }
+
+    /// Adds an elliptical arc to the current subpath, with the same
+    /// parameters as the `A` command of SVG path data.
+    pub fn elliptical_arc_to(
+        &mut self,
+        radii: Vector,
+        x_rotation: f32,
+        large_arc: bool,
+        sweep: bool,
+        to: Point,
+    ) {
+        self.segments.push(Segment::ArcTo {
+            radii,
+            x_rotation,
+            large_arc,
+            sweep,
+            to,
+        });
+    }
+
+    /// Closes the current subpath.
+    pub fn close(&mut self) {
+        self.segments.push(Segment::Close);
+    }
+
+    /// Builds the [`Path`] of this [`Builder`].
+    ///
+    /// [`Path`]: struct.Path.html
+    /// [`Builder`]: struct.Builder.html
+    pub fn build(self) -> Path {
+        Path {
+            segments: self.segments,
+        }
+    }
+}
@@ -0,0 +1,342 @@
+use crate::{
+    path::{Builder, Path, Segment},
+    Point, Vector,
+};
+
+use std::fmt::Write;
+
+/// An error produced when parsing SVG path data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The path data does not start with a move command.
+    MissingMoveTo,
+
+    /// An unexpected character was found at the given byte position.
+    UnexpectedCharacter {
+        /// The byte position of the character.
+        position: usize,
+
+        /// The unexpected character.
+        character: char,
+    },
+
+    /// The path data ended in the middle of a command.
+    UnexpectedEnd,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::MissingMoveTo => {
+                write!(f, "path data must start with a move command")
+            }
+            Error::UnexpectedCharacter {
+                position,
+                character,
+            } => write!(
+                f,
+                "unexpected character '{}' at position {}",
+                character, position
+            ),
+            Error::UnexpectedEnd => write!(f, "unexpected end of path data"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub fn parse(data: &str) -> Result<Path, Error> {
+    let mut parser = Parser { data, position: 0 };
+    let mut builder = Builder::new();
+
+    let mut command = None;
+    let mut has_started = false;
+    let mut current = Point::new(0.0, 0.0);
+    let mut subpath_start = current;
+    let mut last_cubic_control: Option<Point> = None;
+    let mut last_quadratic_control: Option<Point> = None;
+
+    loop {
+        parser.skip_separators();
+
+        let next = match parser.peek() {
+            Some(next) => next,
+            None => break,
+        };
+
+        if next.is_ascii_alphabetic() {
+            parser.position += 1;
+            command = Some(next);
+        } else if command.is_none() {
+            return Err(parser.unexpected(next));
+        }
+
+        let name = command.unwrap();
+        let is_relative = name.is_ascii_lowercase();
+
+        if !has_started && !name.eq_ignore_ascii_case(&'M') {
+            return Err(Error::MissingMoveTo);
+        }
+
+        let point = |parser: &mut Parser<'_>| -> Result<Point, Error> {
+            let x = parser.number()?;
+            let y = parser.number()?;
+
+            Ok(if is_relative {
+                Point::new(current.x + x, current.y + y)
+            } else {
+                Point::new(x, y)
+            })
+        };
+
+        let mut cubic_control = None;
+        let mut quadratic_control = None;
+
+        match name.to_ascii_uppercase() {
+            'M' => {
+                current = point(&mut parser)?;
+                subpath_start = current;
+
+                builder.move_to(current);
+                has_started = true;
+
+                // Subsequent pairs of coordinates are implicit line commands
+                command = Some(if is_relative { 'l' } else { 'L' });
+            }
+            'L' => {
+                current = point(&mut parser)?;
+
+                builder.line_to(current);
+            }
+            'H' => {
+                let x = parser.number()?;
+
+                current.x = if is_relative { current.x + x } else { x };
+
+                builder.line_to(current);
+            }
+            'V' => {
+                let y = parser.number()?;
+
+                current.y = if is_relative { current.y + y } else { y };
+
+                builder.line_to(current);
+            }
+            'C' | 'S' => {
+                let control_a = if name.eq_ignore_ascii_case(&'C') {
+                    point(&mut parser)?
+                } else {
+                    reflect(last_cubic_control, current)
+                };
+
+                let control_b = point(&mut parser)?;
+                current = point(&mut parser)?;
+
+                builder.bezier_curve_to(control_a, control_b, current);
+                cubic_control = Some(control_b);
+            }
+            'Q' | 'T' => {
+                let control = if name.eq_ignore_ascii_case(&'Q') {
+                    point(&mut parser)?
+                } else {
+                    reflect(last_quadratic_control, current)
+                };
+
+                current = point(&mut parser)?;
+
+                builder.quadratic_curve_to(control, current);
+                quadratic_control = Some(control);
+            }
+            'A' => {
+                let radii = Vector::new(parser.number()?, parser.number()?);
+                let x_rotation = parser.number()?;
+                let large_arc = parser.flag()?;
+                let sweep = parser.flag()?;
+
+                current = point(&mut parser)?;
+
+                builder.elliptical_arc_to(
+                    radii, x_rotation, large_arc, sweep, current,
+                );
+            }
+            'Z' => {
+                current = subpath_start;
+
+                builder.close();
+
+                // Coordinates cannot follow a close command
+                command = None;
+            }
+            _ => {
+                return Err(Error::UnexpectedCharacter {
+                    position: parser.position - 1,
+                    character: name,
+                });
+            }
+        }
+
+        last_cubic_control = cubic_control;
+        last_quadratic_control = quadratic_control;
+    }
+
+    Ok(builder.build())
+}
+
+pub fn serialize(path: &Path) -> String {
+    let mut data = String::new();
+
+    for segment in path.segments() {
+        if !data.is_empty() {
+            data.push(' ');
+        }
+
+        let _ = match segment {
+            Segment::MoveTo(to) => write!(data, "M{} {}", to.x, to.y),
+            Segment::LineTo(to) => write!(data, "L{} {}", to.x, to.y),
+            Segment::QuadraticTo { control, to } => {
+                write!(data, "Q{} {} {} {}", control.x, control.y, to.x, to.y)
+            }
+            Segment::CubicTo {
+                control_a,
+                control_b,
+                to,
+            } => write!(
+                data,
+                "C{} {} {} {} {} {}",
+                control_a.x, control_a.y, control_b.x, control_b.y, to.x, to.y
+            ),
+            Segment::ArcTo {
+                radii,
+                x_rotation,
+                large_arc,
+                sweep,
+                to,
+            } => write!(
+                data,
+                "A{} {} {} {} {} {} {}",
+                radii.x,
+                radii.y,
+                x_rotation,
+                *large_arc as u8,
+                *sweep as u8,
+                to.x,
+                to.y
+            ),
+            Segment::Close => write!(data, "Z"),
+        };
+    }
+
+    data
+}
+
+fn reflect(control: Option<Point>, current: Point) -> Point {
+    match control {
+        Some(control) => {
+            Point::new(2.0 * current.x - control.x, 2.0 * current.y - control.y)
+        }
+        None => current,
+    }
+}
+
+struct Parser<'a> {
+    data: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.data[self.position..].chars().next()
+    }
+
+    fn unexpected(&self, character: char) -> Error {
+        Error::UnexpectedCharacter {
+            position: self.position,
+            character,
+        }
+    }
+
+    fn skip_separators(&mut self) {
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() || c == ',' {
+                self.position += c.len_utf8();
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<f32, Error> {
+        self.skip_separators();
+
+        let bytes = self.data.as_bytes();
+        let start = self.position;
+        let mut end = start;
+
+        let digits = |end: &mut usize| {
+            let from = *end;
+
+            while *end < bytes.len() && bytes[*end].is_ascii_digit() {
+                *end += 1;
+            }
+
+            *end > from
+        };
+
+        if end < bytes.len() && (bytes[end] == b'+' || bytes[end] == b'-') {
+            end += 1;
+        }
+
+        let mut has_digits = digits(&mut end);
+
+        if end < bytes.len() && bytes[end] == b'.' {
+            end += 1;
+            has_digits |= digits(&mut end);
+        }
+
+        if !has_digits {
+            return Err(match self.data[end..].chars().next() {
+                Some(character) => Error::UnexpectedCharacter {
+                    position: end,
+                    character,
+                },
+                None => Error::UnexpectedEnd,
+            });
+        }
+
+        if end < bytes.len() && (bytes[end] == b'e' || bytes[end] == b'E') {
+            let mut exponent_end = end + 1;
+
+            if exponent_end < bytes.len()
+                && (bytes[exponent_end] == b'+' || bytes[exponent_end] == b'-')
+            {
+                exponent_end += 1;
+            }
+
+            if digits(&mut exponent_end) {
+                end = exponent_end;
+            }
+        }
+
+        self.position = end;
+
+        // The slice only contains a valid float literal at this point
+        Ok(self.data[start..end].parse().unwrap_or(0.0))
+    }
+
+    fn flag(&mut self) -> Result<bool, Error> {
+        self.skip_separators();
+
+        match self.peek() {
+            Some('0') => {
+                self.position += 1;
+                Ok(false)
+            }
+            Some('1') => {
+                self.position += 1;
+                Ok(true)
+            }
+            Some(character) => Err(self.unexpected(character)),
+            None => Err(Error::UnexpectedEnd),
+        }
+    }
+}
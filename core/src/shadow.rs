@@ -0,0 +1,41 @@
+use crate::{Color, Vector};
+
+/// A shadow cast by some element.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Shadow {
+    /// The color of the [`Shadow`]
+    ///
+    /// [`Shadow`]: struct.Shadow.html
+    pub color: Color,
+
+    /// The offset of the [`Shadow`] from the element casting it
+    ///
+    /// [`Shadow`]: struct.Shadow.html
+    pub offset: Vector,
+
+    /// The blur radius of the [`Shadow`]
+    ///
+    /// [`Shadow`]: struct.Shadow.html
+    pub blur_radius: f32,
+}
+
+impl Shadow {
+    /// Creates a new sharp [`Shadow`] with the given color and offset.
+    ///
+    /// [`Shadow`]: struct.Shadow.html
+    pub fn new(color: Color, offset: Vector) -> Self {
+        Shadow {
+            color,
+            offset,
+            blur_radius: 0.0,
+        }
+    }
+
+    /// Sets the blur radius of the [`Shadow`].
+    ///
+    /// [`Shadow`]: struct.Shadow.html
+    pub fn blur_radius(mut self, blur_radius: f32) -> Self {
+        self.blur_radius = blur_radius;
+        self
+    }
+}
@@ -0,0 +1,136 @@
+//! Lay out paragraphs of text.
+use crate::Color;
+
+use std::hash::{Hash, Hasher};
+
+/// How the lines of some text are spaced and wrapped.
+///
+/// Dense interfaces, like tables of data, can use a tighter line height,
+/// while headlines usually look better with some tracking.
+///
+/// # Example
+/// ```
+/// use iced_core::text::{Params, Wrap};
+///
+/// let headline = Params {
+///     letter_spacing: 2.0,
+///     ..Params::default()
+/// };
+///
+/// let cell = Params {
+///     line_height: 0.9,
+///     wrap: Wrap::None,
+///     ..Params::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Params {
+    /// The height of each line, relative to the natural line height of the
+    /// font.
+    ///
+    /// The extra space, or lack of it, is split evenly above and below the
+    /// glyphs. By default, it is `1.0`.
+    pub line_height: f32,
+
+    /// The space added after every glyph, in logical pixels.
+    ///
+    /// It can be negative to bring glyphs closer together. By default, it is
+    /// `0.0`.
+    pub letter_spacing: f32,
+
+    /// How lines are broken when they do not fit in the width of the text.
+    pub wrap: Wrap,
+
+    /// The lines drawn along the text, like an underline.
+    pub decoration: Decoration,
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        Params {
+            line_height: 1.0,
+            letter_spacing: 0.0,
+            wrap: Wrap::Word,
+            decoration: Decoration::default(),
+        }
+    }
+}
+
+impl Hash for Params {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.line_height.to_bits().hash(state);
+        self.letter_spacing.to_bits().hash(state);
+        self.wrap.hash(state);
+
+        // Decorations do not change the layout of the text
+    }
+}
+
+/// The strategy used to break the lines of some text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Wrap {
+    /// Break lines between words, after whitespace.
+    #[default]
+    Word,
+
+    /// Break lines between any two characters, like for long identifiers or
+    /// URLs.
+    Character,
+
+    /// Never break lines. Only explicit line breaks start a new line.
+    None,
+}
+
+/// The lines drawn along some text.
+///
+/// They are positioned from the metrics of the font and follow every line of
+/// the text, so they stay aligned with the glyphs at any scale factor.
+///
+/// # Example
+/// ```
+/// use iced_core::{text::Decoration, Color};
+///
+/// let removed = Decoration {
+///     strikethrough: true,
+///     color: Some(Color::from_rgb(0.8, 0.0, 0.0)),
+///     ..Decoration::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Decoration {
+    /// Whether a line is drawn below the baseline.
+    pub underline: bool,
+
+    /// Whether a line is drawn through the middle of the glyphs.
+    pub strikethrough: bool,
+
+    /// Whether a line is drawn above the glyphs.
+    pub overline: bool,
+
+    /// The color of the lines. By default, it is the color of the text.
+    pub color: Option<Color>,
+
+    /// The thickness of the lines, in logical pixels. By default, it is
+    /// derived from the size of the text.
+    pub thickness: Option<f32>,
+}
+
+impl Decoration {
+    /// A [`Decoration`] with only an underline.
+    ///
+    /// [`Decoration`]: struct.Decoration.html
+    pub const UNDERLINE: Decoration = Decoration {
+        underline: true,
+        strikethrough: false,
+        overline: false,
+        color: None,
+        thickness: None,
+    };
+
+    /// Returns whether the [`Decoration`] draws no lines.
+    ///
+    /// [`Decoration`]: struct.Decoration.html
+    pub fn is_empty(&self) -> bool {
+        !self.underline && !self.strikethrough && !self.overline
+    }
+}
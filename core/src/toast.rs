@@ -0,0 +1,165 @@
+//! Show short-lived notifications inside of the window.
+use std::time::Duration;
+
+/// A short notification shown in a corner of the window, on top of the rest
+/// of the user interface.
+///
+/// A [`Toast`] is dismissed automatically once its duration elapses, or when
+/// the user clicks on it.
+///
+/// [`Toast`]: struct.Toast.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Toast<T> {
+    /// The identifier of the [`Toast`], if any
+    ///
+    /// Showing a [`Toast`] with the identifier of a visible one replaces it,
+    /// which is useful to update its progress.
+    ///
+    /// [`Toast`]: struct.Toast.html
+    pub id: Option<u64>,
+
+    /// The title of the [`Toast`]
+    ///
+    /// [`Toast`]: struct.Toast.html
+    pub title: String,
+
+    /// The body of the [`Toast`], if any
+    ///
+    /// [`Toast`]: struct.Toast.html
+    pub body: Option<String>,
+
+    /// The [`Level`] of the [`Toast`]
+    ///
+    /// [`Toast`]: struct.Toast.html
+    /// [`Level`]: enum.Level.html
+    pub level: Level,
+
+    /// How long the [`Toast`] stays visible, or `None` if it stays until it
+    /// is clicked
+    ///
+    /// [`Toast`]: struct.Toast.html
+    pub duration: Option<Duration>,
+
+    /// The progress of the task reported by the [`Toast`], between `0.0`
+    /// and `1.0`, if any
+    ///
+    /// [`Toast`]: struct.Toast.html
+    pub progress: Option<f32>,
+
+    /// The message produced when the [`Toast`] is clicked, if any
+    ///
+    /// [`Toast`]: struct.Toast.html
+    pub on_click: Option<T>,
+}
+
+impl<T> Toast<T> {
+    /// Creates a new informative [`Toast`] with the given title, dismissed
+    /// after 5 seconds.
+    ///
+    /// [`Toast`]: struct.Toast.html
+    pub fn new(title: impl Into<String>) -> Self {
+        Toast {
+            id: None,
+            title: title.into(),
+            body: None,
+            level: Level::Info,
+            duration: Some(Duration::from_secs(5)),
+            progress: None,
+            on_click: None,
+        }
+    }
+
+    /// Sets the identifier of the [`Toast`].
+    ///
+    /// [`Toast`]: struct.Toast.html
+    pub fn id(mut self, id: u64) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the body of the [`Toast`].
+    ///
+    /// [`Toast`]: struct.Toast.html
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Sets the [`Level`] of the [`Toast`].
+    ///
+    /// [`Toast`]: struct.Toast.html
+    /// [`Level`]: enum.Level.html
+    pub fn level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Sets how long the [`Toast`] stays visible.
+    ///
+    /// [`Toast`]: struct.Toast.html
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Keeps the [`Toast`] visible until it is clicked.
+    ///
+    /// [`Toast`]: struct.Toast.html
+    pub fn sticky(mut self) -> Self {
+        self.duration = None;
+        self
+    }
+
+    /// Sets the progress of the task reported by the [`Toast`].
+    ///
+    /// It is clamped between `0.0` and `1.0`.
+    ///
+    /// [`Toast`]: struct.Toast.html
+    pub fn progress(mut self, progress: f32) -> Self {
+        self.progress = Some(progress.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Sets the message produced when the [`Toast`] is clicked.
+    ///
+    /// [`Toast`]: struct.Toast.html
+    pub fn on_click(mut self, message: T) -> Self {
+        self.on_click = Some(message);
+        self
+    }
+
+    /// Applies a transformation to the message of the [`Toast`].
+    ///
+    /// [`Toast`]: struct.Toast.html
+    pub fn map<A>(self, f: impl FnOnce(T) -> A) -> Toast<A> {
+        Toast {
+            id: self.id,
+            title: self.title,
+            body: self.body,
+            level: self.level,
+            duration: self.duration,
+            progress: self.progress,
+            on_click: self.on_click.map(f),
+        }
+    }
+}
+
+/// The importance of a [`Toast`].
+///
+/// [`Toast`]: struct.Toast.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Level {
+    /// Some information
+    #[default]
+    Info,
+
+    /// A task finished successfully
+    Success,
+
+    /// Something may need attention
+    Warning,
+
+    /// Something went wrong
+    Error,
+}
+
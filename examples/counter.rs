@@ -1,4 +1,4 @@
-use iced::{button, Button, Column, Element, Sandbox, Settings, Text};
+use iced::{Button, Column, Element, Sandbox, Settings, Text};
 
 pub fn main() {
     Counter::run(Settings::default())
@@ -7,8 +7,6 @@ pub fn main() {
 #[derive(Default)]
 struct Counter {
     value: i32,
-    increment_button: button::State,
-    decrement_button: button::State,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -39,16 +37,16 @@ impl Sandbox for Counter {
         }
     }
 
-    fn view(&mut self) -> Element<Message> {
+    fn view(&self) -> Element<Message> {
         Column::new()
             .padding(20)
             .push(
-                Button::new(&mut self.increment_button, Text::new("Increment"))
+                Button::managed(Text::new("Increment"))
                     .on_press(Message::IncrementPressed),
             )
             .push(Text::new(self.value.to_string()).size(50))
             .push(
-                Button::new(&mut self.decrement_button, Text::new("Decrement"))
+                Button::managed(Text::new("Decrement"))
                     .on_press(Message::DecrementPressed),
             )
             .into()
@@ -62,6 +62,7 @@ mod circle {
                     bounds: layout.bounds(),
                     background: Background::Color(Color::BLACK),
                     border_radius: self.radius,
+                    shadow: None,
                 },
                 MouseCursor::OutOfBounds,
             )
@@ -77,8 +78,7 @@ mod circle {
 
 use circle::Circle;
 use iced::{
-    slider, Align, Column, Container, Element, Length, Sandbox, Settings,
-    Slider, Text,
+    Align, Column, Container, Element, Length, Sandbox, Settings, Slider, Text,
 };
 
 pub fn main() {
@@ -87,7 +87,6 @@ pub fn main() {
 
 struct Example {
     radius: u16,
-    slider: slider::State,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -99,10 +98,7 @@ impl Sandbox for Example {
     type Message = Message;
 
     fn new() -> Self {
-        Example {
-            radius: 50,
-            slider: slider::State::new(),
-        }
+        Example { radius: 50 }
     }
 
     fn title(&self) -> String {
@@ -117,7 +113,7 @@ impl Sandbox for Example {
         }
     }
 
-    fn view(&mut self) -> Element<Message> {
+    fn view(&self) -> Element<Message> {
         let content = Column::new()
             .padding(20)
             .spacing(20)
@@ -128,8 +124,7 @@ impl Sandbox for Example {
                 Text::new(format!("Radius: {}", self.radius.to_string()))
                     .width(Length::Shrink),
             )
-            .push(Slider::new(
-                &mut self.slider,
+            .push(Slider::managed(
                 1.0..=100.0,
                 f32::from(self.radius),
                 Message::RadiusChanged,
@@ -55,7 +55,7 @@ impl Application for Events {
         }
     }
 
-    fn view(&mut self) -> Element<Message> {
+    fn view(&self) -> Element<Message> {
         let events = self.last.iter().fold(
             Column::new().width(Length::Shrink).spacing(10),
             |column, event| {
@@ -1,6 +1,6 @@
 use iced::{
-    button, image, Align, Application, Button, Color, Column, Command,
-    Container, Element, Image, Length, Row, Settings, Text,
+    image, Align, Application, Button, Color, Column, Command, Container,
+    Element, Image, Length, Row, Settings, Text,
 };
 
 pub fn main() {
@@ -10,14 +10,8 @@ pub fn main() {
 #[derive(Debug)]
 enum Pokedex {
     Loading,
-    Loaded {
-        pokemon: Pokemon,
-        search: button::State,
-    },
-    Errored {
-        error: Error,
-        try_again: button::State,
-    },
+    Loaded { pokemon: Pokemon },
+    Errored { error: Error },
 }
 
 #[derive(Debug, Clone)]
@@ -49,18 +43,12 @@ impl Application for Pokedex {
     fn update(&mut self, message: Message) -> Command<Message> {
         match message {
             Message::PokemonFound(Ok(pokemon)) => {
-                *self = Pokedex::Loaded {
-                    pokemon,
-                    search: button::State::new(),
-                };
+                *self = Pokedex::Loaded { pokemon };
 
                 Command::none()
             }
             Message::PokemonFound(Err(error)) => {
-                *self = Pokedex::Errored {
-                    error,
-                    try_again: button::State::new(),
-                };
+                *self = Pokedex::Errored { error };
 
                 Command::none()
             }
@@ -75,22 +63,20 @@ impl Application for Pokedex {
         }
     }
 
-    fn view(&mut self) -> Element<Message> {
+    fn view(&self) -> Element<Message> {
         let content = match self {
             Pokedex::Loading => Column::new().width(Length::Shrink).push(
                 Text::new("Searching for Pokémon...")
                     .width(Length::Shrink)
                     .size(40),
             ),
-            Pokedex::Loaded { pokemon, search } => Column::new()
+            Pokedex::Loaded { pokemon } => Column::new()
                 .max_width(500)
                 .spacing(20)
                 .align_items(Align::End)
                 .push(pokemon.view())
-                .push(
-                    button(search, "Keep searching!").on_press(Message::Search),
-                ),
-            Pokedex::Errored { try_again, .. } => Column::new()
+                .push(button("Keep searching!").on_press(Message::Search)),
+            Pokedex::Errored { .. } => Column::new()
                 .width(Length::Shrink)
                 .spacing(20)
                 .align_items(Align::End)
@@ -99,7 +85,7 @@ impl Application for Pokedex {
                         .width(Length::Shrink)
                         .size(40),
                 )
-                .push(button(try_again, "Try again").on_press(Message::Search)),
+                .push(button("Try again").on_press(Message::Search)),
         };
 
         Container::new(content)
@@ -218,8 +204,8 @@ impl From<surf::Exception> for Error {
     }
 }
 
-fn button<'a>(state: &'a mut button::State, text: &str) -> Button<'a, Message> {
-    Button::new(state, Text::new(text).color(Color::WHITE))
+fn button<'a>(text: &str) -> Button<'a, Message> {
+    Button::managed(Text::new(text).color(Color::WHITE))
         .background(Color::from_rgb(0.11, 0.42, 0.87))
         .border_radius(10)
         .padding(10)
@@ -1,7 +1,6 @@
 use iced::{
-    button, Align, Application, Background, Button, Color, Column, Command,
-    Container, Element, HorizontalAlignment, Length, Row, Settings,
-    Subscription, Text,
+    Align, Application, Background, Button, Color, Column, Command, Container,
+    Element, HorizontalAlignment, Length, Row, Settings, Subscription, Text,
 };
 use std::time::{Duration, Instant};
 
@@ -12,8 +11,6 @@ pub fn main() {
 struct Stopwatch {
     duration: Duration,
     state: State,
-    toggle: button::State,
-    reset: button::State,
 }
 
 enum State {
@@ -36,8 +33,6 @@ impl Application for Stopwatch {
             Stopwatch {
                 duration: Duration::default(),
                 state: State::Idle,
-                toggle: button::State::new(),
-                reset: button::State::new(),
             },
             Command::none(),
         )
@@ -83,7 +78,7 @@ impl Application for Stopwatch {
         }
     }
 
-    fn view(&mut self) -> Element<Message> {
+    fn view(&self) -> Element<Message> {
         const MINUTE: u64 = 60;
         const HOUR: u64 = 60 * MINUTE;
 
@@ -99,9 +94,8 @@ impl Application for Stopwatch {
         .width(Length::Shrink)
         .size(40);
 
-        let button = |state, label, color: [f32; 3]| {
-            Button::new(
-                state,
+        let button = |label, color: [f32; 3]| {
+            Button::managed(
                 Text::new(label)
                     .color(Color::WHITE)
                     .horizontal_alignment(HorizontalAlignment::Center),
@@ -118,11 +112,11 @@ impl Application for Stopwatch {
                 State::Ticking { .. } => ("Stop", [0.9, 0.4, 0.4]),
             };
 
-            button(&mut self.toggle, label, color).on_press(Message::Toggle)
+            button(label, color).on_press(Message::Toggle)
         };
 
-        let reset_button = button(&mut self.reset, "Reset", [0.7, 0.7, 0.7])
-            .on_press(Message::Reset);
+        let reset_button =
+            button("Reset", [0.7, 0.7, 0.7]).on_press(Message::Reset);
 
         let controls = Row::new()
             .width(Length::Shrink)
@@ -20,7 +20,7 @@ impl Sandbox for Tiger {
 
     fn update(&mut self, _message: ()) {}
 
-    fn view(&mut self) -> Element<()> {
+    fn view(&self) -> Element<()> {
         #[cfg(feature = "svg")]
         let content = {
             use iced::{Column, Svg};
@@ -1,7 +1,7 @@
 use iced::{
-    button, scrollable, text_input, Align, Application, Button, Checkbox,
-    Color, Column, Command, Container, Element, Font, HorizontalAlignment,
-    Length, Row, Scrollable, Settings, Text, TextInput,
+    text_input, Align, Application, Button, Checkbox, Color, Column, Command,
+    Container, Element, Font, HorizontalAlignment, Length, Row, Scrollable,
+    Settings, Text, TextInput,
 };
 use serde::{Deserialize, Serialize};
 
@@ -17,12 +17,9 @@ enum Todos {
 
 #[derive(Debug, Default)]
 struct State {
-    scroll: scrollable::State,
-    input: text_input::State,
     input_value: String,
     filter: Filter,
     tasks: Vec<Task>,
-    controls: Controls,
     dirty: bool,
     saving: bool,
 }
@@ -133,16 +130,13 @@ impl Application for Todos {
         }
     }
 
-    fn view(&mut self) -> Element<Message> {
+    fn view(&self) -> Element<Message> {
         match self {
             Todos::Loading => loading_message(),
             Todos::Loaded(State {
-                scroll,
-                input,
                 input_value,
                 filter,
                 tasks,
-                controls,
                 ..
             }) => {
                 let title = Text::new("todos")
@@ -150,8 +144,7 @@ impl Application for Todos {
                     .color([0.5, 0.5, 0.5])
                     .horizontal_alignment(HorizontalAlignment::Center);
 
-                let input = TextInput::new(
-                    input,
+                let input = TextInput::managed(
                     "What needs to be done?",
                     input_value,
                     Message::InputChanged,
@@ -160,19 +153,22 @@ impl Application for Todos {
                 .size(30)
                 .on_submit(Message::CreateTask);
 
-                let controls = controls.view(&tasks, *filter);
+                let controls = controls(&tasks, *filter);
                 let filtered_tasks =
                     tasks.iter().filter(|task| filter.matches(task));
 
                 let tasks: Element<_> = if filtered_tasks.count() > 0 {
                     tasks
-                        .iter_mut()
+                        .iter()
                         .enumerate()
                         .filter(|(_, task)| filter.matches(task))
                         .fold(Column::new().spacing(20), |column, (i, task)| {
-                            column.push(task.view().map(move |message| {
-                                Message::TaskMessage(i, message)
-                            }))
+                            column.push_keyed(
+                                i,
+                                task.view().map(move |message| {
+                                    Message::TaskMessage(i, message)
+                                }),
+                            )
                         })
                         .into()
                 } else {
@@ -193,7 +189,7 @@ impl Application for Todos {
                     .push(controls)
                     .push(tasks);
 
-                Scrollable::new(scroll)
+                Scrollable::managed()
                     .padding(40)
                     .push(
                         Container::new(content).width(Length::Fill).center_x(),
@@ -215,20 +211,15 @@ struct Task {
 
 #[derive(Debug, Clone)]
 pub enum TaskState {
-    Idle {
-        edit_button: button::State,
-    },
+    Idle,
     Editing {
-        text_input: text_input::State,
-        delete_button: button::State,
+        text_input: text_input::Link<text_input::State>,
     },
 }
 
 impl Default for TaskState {
     fn default() -> Self {
-        TaskState::Idle {
-            edit_button: button::State::new(),
-        }
+        TaskState::Idle
     }
 }
 
@@ -246,9 +237,7 @@ impl Task {
         Task {
             description,
             completed: false,
-            state: TaskState::Idle {
-                edit_button: button::State::new(),
-            },
+            state: TaskState::Idle,
         }
     }
 
@@ -259,8 +248,9 @@ impl Task {
             }
             TaskMessage::Edit => {
                 self.state = TaskState::Editing {
-                    text_input: text_input::State::focused(),
-                    delete_button: button::State::new(),
+                    text_input: text_input::Link::new(
+                        text_input::State::focused(),
+                    ),
                 };
             }
             TaskMessage::DescriptionEdited(new_description) => {
@@ -268,18 +258,16 @@ impl Task {
             }
             TaskMessage::FinishEdition => {
                 if !self.description.is_empty() {
-                    self.state = TaskState::Idle {
-                        edit_button: button::State::new(),
-                    }
+                    self.state = TaskState::Idle
                 }
             }
             TaskMessage::Delete => {}
         }
     }
 
-    fn view(&mut self) -> Element<TaskMessage> {
-        match &mut self.state {
-            TaskState::Idle { edit_button } => {
+    fn view(&self) -> Element<TaskMessage> {
+        match &self.state {
+            TaskState::Idle => {
                 let checkbox = Checkbox::new(
                     self.completed,
                     &self.description,
@@ -291,20 +279,14 @@ impl Task {
                     .align_items(Align::Center)
                     .push(checkbox)
                     .push(
-                        Button::new(
-                            edit_button,
-                            edit_icon().color([0.5, 0.5, 0.5]),
-                        )
-                        .on_press(TaskMessage::Edit)
-                        .padding(10),
+                        Button::managed(edit_icon().color([0.5, 0.5, 0.5]))
+                            .on_press(TaskMessage::Edit)
+                            .padding(10),
                     )
                     .into()
             }
-            TaskState::Editing {
-                text_input,
-                delete_button,
-            } => {
-                let text_input = TextInput::new(
+            TaskState::Editing { text_input } => {
+                let text_input = TextInput::linked(
                     text_input,
                     "Describe your task...",
                     &self.description,
@@ -318,8 +300,7 @@ impl Task {
                     .align_items(Align::Center)
                     .push(text_input)
                     .push(
-                        Button::new(
-                            delete_button,
+                        Button::managed(
                             Row::new()
                                 .spacing(10)
                                 .push(delete_icon().color(Color::WHITE))
@@ -340,73 +321,47 @@ impl Task {
     }
 }
 
-#[derive(Debug, Default, Clone)]
-pub struct Controls {
-    all_button: button::State,
-    active_button: button::State,
-    completed_button: button::State,
-}
+fn controls(tasks: &[Task], current_filter: Filter) -> Row<'static, Message> {
+    let tasks_left = tasks.iter().filter(|task| !task.completed).count();
 
-impl Controls {
-    fn view(&mut self, tasks: &[Task], current_filter: Filter) -> Row<Message> {
-        let Controls {
-            all_button,
-            active_button,
-            completed_button,
-        } = self;
-
-        let tasks_left = tasks.iter().filter(|task| !task.completed).count();
-
-        let filter_button = |state, label, filter, current_filter| {
-            let label = Text::new(label).size(16).width(Length::Shrink);
-            let button = if filter == current_filter {
-                Button::new(state, label.color(Color::WHITE))
-                    .background(Color::from_rgb(0.2, 0.2, 0.7))
-            } else {
-                Button::new(state, label)
-            };
-
-            button
-                .on_press(Message::FilterChanged(filter))
-                .padding(8)
-                .border_radius(10)
+    let filter_button = |label, filter, current_filter| {
+        let label = Text::new(label).size(16).width(Length::Shrink);
+        let button = if filter == current_filter {
+            Button::managed(label.color(Color::WHITE))
+                .background(Color::from_rgb(0.2, 0.2, 0.7))
+        } else {
+            Button::managed(label)
         };
 
-        Row::new()
-            .spacing(20)
-            .align_items(Align::Center)
-            .push(
-                Text::new(&format!(
-                    "{} {} left",
-                    tasks_left,
-                    if tasks_left == 1 { "task" } else { "tasks" }
-                ))
-                .size(16),
-            )
-            .push(
-                Row::new()
-                    .width(Length::Shrink)
-                    .spacing(10)
-                    .push(filter_button(
-                        all_button,
-                        "All",
-                        Filter::All,
-                        current_filter,
-                    ))
-                    .push(filter_button(
-                        active_button,
-                        "Active",
-                        Filter::Active,
-                        current_filter,
-                    ))
-                    .push(filter_button(
-                        completed_button,
-                        "Completed",
-                        Filter::Completed,
-                        current_filter,
-                    )),
-            )
-    }
+        button
+            .on_press(Message::FilterChanged(filter))
+            .padding(8)
+            .border_radius(10)
+    };
+
+    Row::new()
+        .spacing(20)
+        .align_items(Align::Center)
+        .push(
+            Text::new(&format!(
+                "{} {} left",
+                tasks_left,
+                if tasks_left == 1 { "task" } else { "tasks" }
+            ))
+            .size(16),
+        )
+        .push(
+            Row::new()
+                .width(Length::Shrink)
+                .spacing(10)
+                .push(filter_button("All", Filter::All, current_filter))
+                .push(filter_button("Active", Filter::Active, current_filter))
+                .push(filter_button(
+                    "Completed",
+                    Filter::Completed,
+                    current_filter,
+                )),
+        )
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
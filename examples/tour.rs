@@ -1,7 +1,7 @@
 use iced::{
-    button, scrollable, slider, text_input, Button, Checkbox, Color, Column,
-    Container, Element, HorizontalAlignment, Image, Length, Radio, Row,
-    Sandbox, Scrollable, Settings, Slider, Space, Text, TextInput,
+    Button, Checkbox, Color, Column, Container, Element, HorizontalAlignment,
+    Image, Length, Radio, Row, Sandbox, Scrollable, Settings, Slider, Space,
+    Text, TextInput,
 };
 
 pub fn main() {
@@ -12,9 +12,6 @@ pub fn main() {
 
 pub struct Tour {
     steps: Steps,
-    scroll: scrollable::State,
-    back_button: button::State,
-    next_button: button::State,
     debug: bool,
 }
 
@@ -24,9 +21,6 @@ impl Sandbox for Tour {
     fn new() -> Tour {
         Tour {
             steps: Steps::new(),
-            scroll: scrollable::State::new(),
-            back_button: button::State::new(),
-            next_button: button::State::new(),
             debug: false,
         }
     }
@@ -49,31 +43,21 @@ impl Sandbox for Tour {
         }
     }
 
-    fn view(&mut self) -> Element<Message> {
-        let Tour {
-            steps,
-            scroll,
-            back_button,
-            next_button,
-            ..
-        } = self;
+    fn view(&self) -> Element<Message> {
+        let Tour { steps, .. } = self;
 
         let mut controls = Row::new();
 
         if steps.has_previous() {
-            controls = controls.push(
-                secondary_button(back_button, "Back")
-                    .on_press(Message::BackPressed),
-            );
+            controls = controls
+                .push(secondary_button("Back").on_press(Message::BackPressed));
         }
 
         controls = controls.push(Space::with_width(Length::Fill));
 
         if steps.can_continue() {
-            controls = controls.push(
-                primary_button(next_button, "Next")
-                    .on_press(Message::NextPressed),
-            );
+            controls = controls
+                .push(primary_button("Next").on_press(Message::NextPressed));
         }
 
         let content: Element<_> = Column::new()
@@ -90,7 +74,7 @@ impl Sandbox for Tour {
             content
         };
 
-        let scrollable = Scrollable::new(scroll)
+        let scrollable = Scrollable::managed()
             .push(Container::new(content).width(Length::Fill).center_x());
 
         Container::new(scrollable)
@@ -117,31 +101,21 @@ impl Steps {
         Steps {
             steps: vec![
                 Step::Welcome,
-                Step::Slider {
-                    state: slider::State::new(),
-                    value: 50,
-                },
+                Step::Slider { value: 50 },
                 Step::RowsAndColumns {
                     layout: Layout::Row,
-                    spacing_slider: slider::State::new(),
                     spacing: 20,
                 },
                 Step::Text {
-                    size_slider: slider::State::new(),
                     size: 30,
-                    color_sliders: [slider::State::new(); 3],
                     color: Color::BLACK,
                 },
                 Step::Radio { selection: None },
-                Step::Image {
-                    width: 300,
-                    slider: slider::State::new(),
-                },
+                Step::Image { width: 300 },
                 Step::Scrollable,
                 Step::TextInput {
                     value: String::new(),
                     is_secure: false,
-                    state: text_input::State::new(),
                 },
                 Step::Debugger,
                 Step::End,
@@ -154,7 +128,7 @@ impl Steps {
         self.steps[self.current].update(msg, debug);
     }
 
-    fn view(&mut self, debug: bool) -> Element<StepMessage> {
+    fn view(&self, debug: bool) -> Element<StepMessage> {
         self.steps[self.current].view(debug)
     }
 
@@ -186,34 +160,13 @@ impl Steps {
 
 enum Step {
     Welcome,
-    Slider {
-        state: slider::State,
-        value: u16,
-    },
-    RowsAndColumns {
-        layout: Layout,
-        spacing_slider: slider::State,
-        spacing: u16,
-    },
-    Text {
-        size_slider: slider::State,
-        size: u16,
-        color_sliders: [slider::State; 3],
-        color: Color,
-    },
-    Radio {
-        selection: Option<Language>,
-    },
-    Image {
-        width: u16,
-        slider: slider::State,
-    },
+    Slider { value: u16 },
+    RowsAndColumns { layout: Layout, spacing: u16 },
+    Text { size: u16, color: Color },
+    Radio { selection: Option<Language> },
+    Image { width: u16 },
     Scrollable,
-    TextInput {
-        value: String,
-        is_secure: bool,
-        state: text_input::State,
-    },
+    TextInput { value: String, is_secure: bool },
     Debugger,
     End,
 }
@@ -318,29 +271,20 @@ impl<'a> Step {
         }
     }
 
-    fn view(&mut self, debug: bool) -> Element<StepMessage> {
+    fn view(&self, debug: bool) -> Element<StepMessage> {
         match self {
             Step::Welcome => Self::welcome(),
             Step::Radio { selection } => Self::radio(*selection),
-            Step::Slider { state, value } => Self::slider(state, *value),
-            Step::Text {
-                size_slider,
-                size,
-                color_sliders,
-                color,
-            } => Self::text(size_slider, *size, color_sliders, *color),
-            Step::Image { width, slider } => Self::image(*width, slider),
-            Step::RowsAndColumns {
-                layout,
-                spacing_slider,
-                spacing,
-            } => Self::rows_and_columns(*layout, spacing_slider, *spacing),
+            Step::Slider { value } => Self::slider(*value),
+            Step::Text { size, color } => Self::text(*size, *color),
+            Step::Image { width } => Self::image(*width),
+            Step::RowsAndColumns { layout, spacing } => {
+                Self::rows_and_columns(*layout, *spacing)
+            }
             Step::Scrollable => Self::scrollable(),
-            Step::TextInput {
-                value,
-                is_secure,
-                state,
-            } => Self::text_input(value, *is_secure, state),
+            Step::TextInput { value, is_secure } => {
+                Self::text_input(value, *is_secure)
+            }
             Step::Debugger => Self::debugger(debug),
             Step::End => Self::end(),
         }
@@ -380,10 +324,7 @@ impl<'a> Step {
             ))
     }
 
-    fn slider(
-        state: &'a mut slider::State,
-        value: u16,
-    ) -> Column<'a, StepMessage> {
+    fn slider(value: u16) -> Column<'a, StepMessage> {
         Self::container("Slider")
             .push(Text::new(
                 "A slider allows you to smoothly select a value from a range \
@@ -393,8 +334,7 @@ impl<'a> Step {
                 "The following slider lets you choose an integer from \
                  0 to 100:",
             ))
-            .push(Slider::new(
-                state,
+            .push(Slider::managed(
                 0.0..=100.0,
                 value as f32,
                 StepMessage::SliderChanged,
@@ -407,7 +347,6 @@ impl<'a> Step {
 
     fn rows_and_columns(
         layout: Layout,
-        spacing_slider: &'a mut slider::State,
         spacing: u16,
     ) -> Column<'a, StepMessage> {
         let row_radio = Radio::new(
@@ -439,8 +378,7 @@ impl<'a> Step {
 
         let spacing_section = Column::new()
             .spacing(10)
-            .push(Slider::new(
-                spacing_slider,
+            .push(Slider::managed(
                 0.0..=80.0,
                 spacing as f32,
                 StepMessage::SpacingChanged,
@@ -467,12 +405,7 @@ impl<'a> Step {
             .push(spacing_section)
     }
 
-    fn text(
-        size_slider: &'a mut slider::State,
-        size: u16,
-        color_sliders: &'a mut [slider::State; 3],
-        color: Color,
-    ) -> Column<'a, StepMessage> {
+    fn text(size: u16, color: Color) -> Column<'a, StepMessage> {
         let size_section = Column::new()
             .padding(20)
             .spacing(20)
@@ -480,14 +413,12 @@ impl<'a> Step {
             .push(
                 Text::new(&format!("This text is {} pixels", size)).size(size),
             )
-            .push(Slider::new(
-                size_slider,
+            .push(Slider::managed(
                 10.0..=70.0,
                 size as f32,
                 StepMessage::TextSizeChanged,
             ));
 
-        let [red, green, blue] = color_sliders;
         let color_section = Column::new()
             .padding(20)
             .spacing(20)
@@ -496,13 +427,13 @@ impl<'a> Step {
             .push(
                 Row::new()
                     .spacing(10)
-                    .push(Slider::new(red, 0.0..=1.0, color.r, move |r| {
+                    .push(Slider::managed(0.0..=1.0, color.r, move |r| {
                         StepMessage::TextColorChanged(Color { r, ..color })
                     }))
-                    .push(Slider::new(green, 0.0..=1.0, color.g, move |g| {
+                    .push(Slider::managed(0.0..=1.0, color.g, move |g| {
                         StepMessage::TextColorChanged(Color { g, ..color })
                     }))
-                    .push(Slider::new(blue, 0.0..=1.0, color.b, move |b| {
+                    .push(Slider::managed(0.0..=1.0, color.b, move |b| {
                         StepMessage::TextColorChanged(Color { b, ..color })
                     })),
             );
@@ -546,15 +477,11 @@ impl<'a> Step {
             ))
     }
 
-    fn image(
-        width: u16,
-        slider: &'a mut slider::State,
-    ) -> Column<'a, StepMessage> {
+    fn image(width: u16) -> Column<'a, StepMessage> {
         Self::container("Image")
             .push(Text::new("An image that tries to keep its aspect ratio."))
             .push(ferris(width))
-            .push(Slider::new(
-                slider,
+            .push(Slider::managed(
                 100.0..=500.0,
                 width as f32,
                 StepMessage::ImageWidthChanged,
@@ -592,13 +519,8 @@ impl<'a> Step {
             )
     }
 
-    fn text_input(
-        value: &str,
-        is_secure: bool,
-        state: &'a mut text_input::State,
-    ) -> Column<'a, StepMessage> {
-        let text_input = TextInput::new(
-            state,
+    fn text_input(value: &str, is_secure: bool) -> Column<'a, StepMessage> {
+        let text_input = TextInput::managed(
             "Type something to continue...",
             value,
             StepMessage::InputChanged,
@@ -686,12 +608,8 @@ fn ferris<'a>(width: u16) -> Container<'a, StepMessage> {
     .center_x()
 }
 
-fn button<'a, Message>(
-    state: &'a mut button::State,
-    label: &str,
-) -> Button<'a, Message> {
-    Button::new(
-        state,
+fn button<'a, Message>(label: &str) -> Button<'a, Message> {
+    Button::managed(
         Text::new(label)
             .color(Color::WHITE)
             .horizontal_alignment(HorizontalAlignment::Center),
@@ -701,18 +619,12 @@ fn button<'a, Message>(
     .min_width(100)
 }
 
-fn primary_button<'a, Message>(
-    state: &'a mut button::State,
-    label: &str,
-) -> Button<'a, Message> {
-    button(state, label).background(Color::from_rgb(0.11, 0.42, 0.87))
+fn primary_button<'a, Message>(label: &str) -> Button<'a, Message> {
+    button(label).background(Color::from_rgb(0.11, 0.42, 0.87))
 }
 
-fn secondary_button<'a, Message>(
-    state: &'a mut button::State,
-    label: &str,
-) -> Button<'a, Message> {
-    button(state, label).background(Color::from_rgb(0.4, 0.4, 0.4))
+fn secondary_button<'a, Message>(label: &str) -> Button<'a, Message> {
+    button(label).background(Color::from_rgb(0.4, 0.4, 0.4))
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
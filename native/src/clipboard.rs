@@ -1,3 +1,5 @@
+//! Access the clipboard of the operating system.
+
 /// A buffer for short-term storage and transfer within and between
 /// applications.
 pub trait Clipboard {
@@ -5,4 +7,56 @@ pub trait Clipboard {
     ///
     /// [`Clipboard`]: trait.Clipboard.html
     fn content(&self) -> Option<String>;
+
+    /// Replaces the content of the [`Clipboard`] with the given text.
+    ///
+    /// By default, it fails with [`Error::Unsupported`], for clipboards that
+    /// can only be read.
+    ///
+    /// [`Clipboard`]: trait.Clipboard.html
+    /// [`Error::Unsupported`]: enum.Error.html#variant.Unsupported
+    fn write(&self, _content: String) -> Result<(), Error> {
+        Err(Error::Unsupported)
+    }
+
+    /// Replaces the content of the [`Clipboard`] with an image, given its
+    /// dimensions and its pixels in RGBA order.
+    ///
+    /// By default, it fails with [`Error::Unsupported`], for clipboards that
+    /// can only hold text.
+    ///
+    /// [`Clipboard`]: trait.Clipboard.html
+    /// [`Error::Unsupported`]: enum.Error.html#variant.Unsupported
+    fn write_image(
+        &self,
+        _width: u32,
+        _height: u32,
+        _pixels: Vec<u8>,
+    ) -> Result<(), Error> {
+        Err(Error::Unsupported)
+    }
+
+    /// Replaces the content of the [`Clipboard`] with some HTML, along with
+    /// its text for the applications that cannot read HTML.
+    ///
+    /// By default, only the text is written.
+    ///
+    /// [`Clipboard`]: trait.Clipboard.html
+    fn write_html(&self, _html: String, text: String) -> Result<(), Error> {
+        self.write(text)
+    }
+}
+
+/// An error produced when writing to a [`Clipboard`].
+///
+/// [`Clipboard`]: trait.Clipboard.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The [`Clipboard`] cannot hold this kind of content.
+    ///
+    /// [`Clipboard`]: trait.Clipboard.html
+    Unsupported,
+
+    /// The operating system refused to store the content.
+    Failed,
 }
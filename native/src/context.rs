@@ -0,0 +1,80 @@
+//! Pass values down the widget tree.
+//!
+//! Some widgets need to know about their ancestors. For instance, a header
+//! inside a [`Scrollable`] may want to shrink as the user scrolls, or a
+//! background may want to move slower than the rest of the contents.
+//!
+//! A widget can [`provide`] a value while it lays out, draws, or handles
+//! events for its children. Any descendant can then [`get`] the value
+//! provided by its nearest ancestor during the same call.
+//!
+//! # Example
+//! ```
+//! use iced_native::context;
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! struct Depth(u32);
+//!
+//! assert_eq!(context::get::<Depth>(), None);
+//!
+//! context::provide(Depth(1), || {
+//!     assert_eq!(context::get::<Depth>(), Some(Depth(1)));
+//!
+//!     // The nearest value wins
+//!     context::provide(Depth(2), || {
+//!         assert_eq!(context::get::<Depth>(), Some(Depth(2)));
+//!     });
+//!
+//!     assert_eq!(context::get::<Depth>(), Some(Depth(1)));
+//! });
+//! ```
+//!
+//! [`Scrollable`]: ../widget/scrollable/struct.Scrollable.html
+//! [`provide`]: fn.provide.html
+//! [`get`]: fn.get.html
+use std::{any::Any, cell::RefCell};
+
+thread_local! {
+    static VALUES: RefCell<Vec<Box<dyn Any>>> = RefCell::new(Vec::new());
+}
+
+/// Provides a value to every [`get`] call made while running the given
+/// closure, unless a nested call provides another value of the same type.
+///
+/// [`get`]: fn.get.html
+pub fn provide<T, R>(value: T, f: impl FnOnce() -> R) -> R
+where
+    T: 'static,
+{
+    VALUES.with(|values| values.borrow_mut().push(Box::new(value)));
+
+    // The value is removed even if the closure panics
+    let _provided = Provided;
+
+    f()
+}
+
+/// Returns the value of type `T` provided by the nearest ancestor, if any.
+pub fn get<T>() -> Option<T>
+where
+    T: Clone + 'static,
+{
+    VALUES.with(|values| {
+        values
+            .borrow()
+            .iter()
+            .rev()
+            .find_map(|value| value.downcast_ref::<T>())
+            .cloned()
+    })
+}
+
+struct Provided;
+
+impl Drop for Provided {
+    fn drop(&mut self) {
+        VALUES.with(|values| {
+            let _ = values.borrow_mut().pop();
+        });
+    }
+}
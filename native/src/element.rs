@@ -1,8 +1,11 @@
 use crate::{
-    layout, renderer, Clipboard, Color, Event, Hasher, Layout, Length, Point,
-    Widget,
+    layout, overlay, renderer, Clipboard, Color, Event, Focusable, Hasher,
+    Layout, Length, Point, Size, Tree, Widget,
 };
 
+#[cfg(feature = "devtools")]
+use crate::inspect;
+
 /// A generic [`Widget`].
 ///
 /// It is useful to build composable user interfaces that do not leak
@@ -228,6 +231,19 @@ where
         self.widget.layout(renderer, limits)
     }
 
+    /// Returns the size the [`Element`] wants to have in the given
+    /// [`Limits`].
+    ///
+    /// [`Element`]: struct.Element.html
+    /// [`Limits`]: layout/struct.Limits.html
+    pub fn measure(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> Size {
+        self.widget.measure(renderer, limits)
+    }
+
     /// Draws the [`Element`] and its children using the given [`Layout`].
     ///
     /// [`Element`]: struct.Element.html
@@ -241,6 +257,91 @@ where
         self.widget.draw(renderer, layout, cursor_position)
     }
 
+    /// Reconciles the [`Element`] with the [`Tree`] of the previous view.
+    ///
+    /// Custom widgets with children should call this method for each of
+    /// them in their [`Widget::diff`] implementation.
+    ///
+    /// [`Element`]: struct.Element.html
+    /// [`Tree`]: tree/struct.Tree.html
+    /// [`Widget::diff`]: widget/trait.Widget.html#method.diff
+    pub fn diff(&mut self, tree: &mut Tree) {
+        self.widget.diff(tree);
+    }
+
+    /// Finds the descendant of the [`Element`] under the cursor, as described
+    /// in [`Widget::hover`].
+    ///
+    /// Custom widgets with children should call this method for the hovered
+    /// child in their [`Widget::hover`] implementation.
+    ///
+    /// [`Element`]: struct.Element.html
+    /// [`Widget::hover`]: widget/trait.Widget.html#method.hover
+    pub fn hover(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        path: &mut Vec<usize>,
+    ) {
+        self.widget.hover(layout, cursor_position, path);
+    }
+
+    /// Pushes the descendants of the [`Element`] that can have the keyboard
+    /// focus, as described in [`Widget::focusables`].
+    ///
+    /// Custom widgets with children should call this method for each of
+    /// them in their [`Widget::focusables`] implementation.
+    ///
+    /// [`Element`]: struct.Element.html
+    /// [`Widget::focusables`]: widget/trait.Widget.html#method.focusables
+    pub fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        self.widget.focusables(path, focusables);
+    }
+
+    /// Returns whether the [`Element`] captures the mouse, as described in
+    /// [`Widget::captures_mouse`].
+    ///
+    /// Custom widgets with children should call this method for each of
+    /// them in their [`Widget::captures_mouse`] implementation.
+    ///
+    /// [`Element`]: struct.Element.html
+    /// [`Widget::captures_mouse`]: widget/trait.Widget.html#method.captures_mouse
+    pub fn captures_mouse(&self) -> bool {
+        self.widget.captures_mouse()
+    }
+
+    /// Returns the overlay of the [`Element`], if it has one, as described
+    /// in [`Widget::overlay`].
+    ///
+    /// Custom widgets with children should call this method for each of
+    /// them in their [`Widget::overlay`] implementation.
+    ///
+    /// [`Element`]: struct.Element.html
+    /// [`Widget::overlay`]: widget/trait.Widget.html#method.overlay
+    pub fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.widget.overlay(layout)
+    }
+
+    /// Returns a snapshot of the [`Element`] and its children, as described
+    /// in [`Widget::inspect`].
+    ///
+    /// Custom widgets with children should call this method for each of
+    /// them in their [`Widget::inspect`] implementation.
+    ///
+    /// [`Element`]: struct.Element.html
+    /// [`Widget::inspect`]: widget/trait.Widget.html#method.inspect
+    #[cfg(feature = "devtools")]
+    pub fn inspect(&self, layout: Layout<'_>) -> inspect::Node {
+        self.widget.inspect(layout)
+    }
+
     pub(crate) fn hash_layout(&self, state: &mut Hasher) {
         self.widget.hash_layout(state);
     }
@@ -287,6 +388,10 @@ where
         self.widget.layout(renderer, limits)
     }
 
+    fn measure(&self, renderer: &Renderer, limits: &layout::Limits) -> Size {
+        self.widget.measure(renderer, limits)
+    }
+
     fn on_event(
         &mut self,
         event: Event,
@@ -325,6 +430,47 @@ where
     fn hash_layout(&self, state: &mut Hasher) {
         self.widget.hash_layout(state);
     }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.widget.diff(tree);
+    }
+
+    fn hover(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        path: &mut Vec<usize>,
+    ) {
+        self.widget.hover(layout, cursor_position, path);
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        self.widget.focusables(path, focusables);
+    }
+
+    fn captures_mouse(&self) -> bool {
+        self.widget.captures_mouse()
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, B, Renderer>> {
+        let mapper = &self.mapper;
+
+        self.widget
+            .overlay(layout)
+            .map(move |overlay| overlay.map(mapper.as_ref()))
+    }
+
+    #[cfg(feature = "devtools")]
+    fn inspect(&self, layout: Layout<'_>) -> inspect::Node {
+        self.widget.inspect(layout)
+    }
 }
 
 struct Explain<'a, Message, Renderer: crate::Renderer> {
@@ -362,6 +508,10 @@ where
         self.element.widget.layout(renderer, limits)
     }
 
+    fn measure(&self, renderer: &Renderer, limits: &layout::Limits) -> Size {
+        self.element.widget.measure(renderer, limits)
+    }
+
     fn on_event(
         &mut self,
         event: Event,
@@ -398,4 +548,41 @@ where
     fn hash_layout(&self, state: &mut Hasher) {
         self.element.widget.hash_layout(state);
     }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.element.widget.diff(tree);
+    }
+
+    fn hover(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        path: &mut Vec<usize>,
+    ) {
+        self.element.widget.hover(layout, cursor_position, path);
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        self.element.widget.focusables(path, focusables);
+    }
+
+    fn captures_mouse(&self) -> bool {
+        self.element.widget.captures_mouse()
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.element.widget.overlay(layout)
+    }
+
+    #[cfg(feature = "devtools")]
+    fn inspect(&self, layout: Layout<'_>) -> inspect::Node {
+        self.element.widget.inspect(layout)
+    }
 }
@@ -1,4 +1,8 @@
-use crate::input::{keyboard, mouse};
+use crate::{
+    frame::Frame,
+    input::{ime, keyboard, mouse},
+    memory,
+};
 
 /// A user interface event.
 ///
@@ -6,11 +10,75 @@ use crate::input::{keyboard, mouse};
 /// additional events, feel free to [open an issue] and share your use case!_
 ///
 /// [open an issue]: https://github.com/hecrj/iced/issues
-#[derive(PartialEq, Clone, Copy, Debug)]
+#[derive(PartialEq, Clone, Debug)]
 pub enum Event {
     /// A keyboard event
     Keyboard(keyboard::Event),
 
     /// A mouse event
     Mouse(mouse::Event),
+
+    /// An input method editor event
+    Ime(ime::Event),
+
+    /// A frame has been presented
+    ///
+    /// It is only delivered to subscriptions, see [`subscription::frames`].
+    ///
+    /// [`subscription::frames`]: subscription/fn.frames.html
+    Frame(Frame),
+
+    /// The renderer is running low on device memory
+    ///
+    /// It is only delivered to subscriptions, see
+    /// [`subscription::memory_pressure`].
+    ///
+    /// [`subscription::memory_pressure`]: subscription/fn.memory_pressure.html
+    MemoryPressure(memory::Pressure),
+
+    /// The cursor entered or exited a widget
+    ///
+    /// It is only delivered to widgets. While handling it, the
+    /// [`Layout::is_hovered`] method returns whether the event targets the
+    /// widget.
+    ///
+    /// [`Layout::is_hovered`]: struct.Layout.html#method.is_hovered
+    Hover(Hover),
+
+    /// A widget gained or lost the keyboard focus
+    ///
+    /// It is only delivered to widgets. While handling it, the
+    /// [`Layout::is_focus_target`] method returns whether the event targets
+    /// the widget.
+    ///
+    /// [`Layout::is_focus_target`]: struct.Layout.html#method.is_focus_target
+    Focus(Focus),
+}
+
+/// A change in the widget under the cursor.
+///
+/// The runtime tracks which widget is under the cursor. Only the topmost of
+/// overlapping widgets is hovered, along with its ancestors.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Hover {
+    /// The cursor entered the widget.
+    Entered,
+
+    /// The cursor exited the widget.
+    Exited,
+}
+
+/// A change in the widget with the keyboard focus.
+///
+/// The runtime moves the focus along the widgets that can be focused when the
+/// tab key is pressed. See [`Widget::focusables`].
+///
+/// [`Widget::focusables`]: widget/trait.Widget.html#method.focusables
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Focus {
+    /// The widget gained the focus.
+    Gained,
+
+    /// The widget lost the focus.
+    Lost,
 }
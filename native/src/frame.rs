@@ -0,0 +1,134 @@
+//! Measure how smoothly frames are presented.
+//!
+//! Every time a shell presents a new frame, it produces an
+//! [`Event::Frame`] describing how long it took. You can listen to a summary
+//! of these with [`subscription::frames`] to adapt your application or log
+//! stutters in the field.
+//!
+//! [`Event::Frame`]: ../enum.Event.html#variant.Frame
+//! [`subscription::frames`]: ../subscription/fn.frames.html
+use std::time::{Duration, Instant};
+
+/// The timing of a presented frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Frame {
+    /// The time spent rendering and presenting the frame.
+    pub present_time: Duration,
+
+    /// The time elapsed since the previous frame started, if any.
+    pub interval: Option<Duration>,
+
+    /// The amount of vertical syncs missed while presenting the frame.
+    ///
+    /// It is `0` when the frame was presented within the refresh interval of
+    /// the display.
+    ///
+    /// This is an estimate: shells cannot observe the actual vertical syncs,
+    /// so it is the [`present_time`] divided by the refresh interval of the
+    /// display.
+    ///
+    /// [`present_time`]: #structfield.present_time
+    pub missed_vsyncs: u32,
+}
+
+/// A summary of the frames presented during some time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Stats {
+    /// The amount of presented frames.
+    pub frames: u32,
+
+    /// The amount of frames that missed at least one vertical sync.
+    pub janky_frames: u32,
+
+    /// The total amount of missed vertical syncs.
+    pub missed_vsyncs: u32,
+
+    /// The total time spent presenting frames.
+    pub total_present_time: Duration,
+
+    /// The longest time spent presenting a single frame.
+    pub max_present_time: Duration,
+}
+
+impl Stats {
+    /// Adds a [`Frame`] to the [`Stats`].
+    ///
+    /// [`Frame`]: struct.Frame.html
+    /// [`Stats`]: struct.Stats.html
+    pub fn record(&mut self, frame: Frame) {
+        self.frames += 1;
+        self.missed_vsyncs += frame.missed_vsyncs;
+        self.total_present_time += frame.present_time;
+        self.max_present_time = self.max_present_time.max(frame.present_time);
+
+        if frame.missed_vsyncs > 0 {
+            self.janky_frames += 1;
+        }
+    }
+
+    /// Returns the average time spent presenting a frame.
+    pub fn average_present_time(&self) -> Duration {
+        if self.frames == 0 {
+            Duration::from_secs(0)
+        } else {
+            self.total_present_time / self.frames
+        }
+    }
+}
+
+/// A stopwatch that measures the [`Frame`]s of a shell.
+///
+/// Shells should call [`begin`] before rendering a frame and [`end`] once it
+/// has been presented.
+///
+/// [`Frame`]: struct.Frame.html
+/// [`begin`]: #method.begin
+/// [`end`]: #method.end
+#[derive(Debug, Clone)]
+pub struct Pacer {
+    refresh_interval: Duration,
+    last_start: Option<Instant>,
+    current_start: Option<Instant>,
+}
+
+impl Pacer {
+    /// Creates a new [`Pacer`] for a display with the given refresh rate, in
+    /// hertz.
+    ///
+    /// Shells should use the refresh rate reported by the windowing system,
+    /// and fall back to `60` when it is unknown.
+    ///
+    /// [`Pacer`]: struct.Pacer.html
+    pub fn new(refresh_rate: u16) -> Self {
+        Pacer {
+            refresh_interval: Duration::from_secs(1)
+                / u32::from(refresh_rate.max(1)),
+            last_start: None,
+            current_start: None,
+        }
+    }
+
+    /// Starts measuring a new frame.
+    pub fn begin(&mut self) {
+        self.last_start = self.current_start.take();
+        self.current_start = Some(Instant::now());
+    }
+
+    /// Finishes measuring the current frame and returns its [`Frame`] timing.
+    ///
+    /// [`Frame`]: struct.Frame.html
+    pub fn end(&mut self) -> Frame {
+        let now = Instant::now();
+        let start = *self.current_start.get_or_insert(now);
+
+        let present_time = now.duration_since(start);
+
+        Frame {
+            present_time,
+            interval: self.last_start.map(|last| start.duration_since(last)),
+            missed_vsyncs: (present_time.as_secs_f64()
+                / self.refresh_interval.as_secs_f64())
+                as u32,
+        }
+    }
+}
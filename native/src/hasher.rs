@@ -17,3 +17,12 @@ impl core::hash::Hasher for Hasher {
         self.0.finish()
     }
 }
+
+pub(crate) fn hash<T: core::hash::Hash>(value: &T) -> u64 {
+    use core::hash::Hasher as _;
+
+    let mut hasher = Hasher::default();
+    value.hash(&mut hasher);
+
+    hasher.finish()
+}
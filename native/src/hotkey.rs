@@ -0,0 +1,131 @@
+//! Bind keyboard shortcuts to messages.
+//!
+//! A [`Hotkey`] is matched before any widget handles the key press, so it
+//! works the same way whether a text input has the focus or not.
+//!
+//! [`Hotkey`]: struct.Hotkey.html
+use crate::{
+    input::{keyboard, ButtonState},
+    widget::text_input::platform,
+    Event,
+};
+
+/// A keyboard shortcut producing a message, like `Ctrl+S` saving a file.
+///
+/// # Example
+/// ```
+/// # use iced_native::{input::keyboard::KeyCode, Hotkey};
+/// #
+/// #[derive(Debug)]
+/// enum Message {
+///     Save,
+///     SaveAs,
+/// }
+///
+/// let hotkeys = vec![
+///     Hotkey::new(KeyCode::S, Message::Save).command(),
+///     Hotkey::new(KeyCode::S, Message::SaveAs).command().shift(),
+/// ];
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hotkey<Message> {
+    key_code: keyboard::KeyCode,
+    command: bool,
+    shift: bool,
+    alt: bool,
+    message: Message,
+}
+
+impl<Message> Hotkey<Message> {
+    /// Creates a new [`Hotkey`] producing the given message when the key is
+    /// pressed without modifiers.
+    ///
+    /// [`Hotkey`]: struct.Hotkey.html
+    pub fn new(key_code: keyboard::KeyCode, message: Message) -> Self {
+        Hotkey {
+            key_code,
+            command: false,
+            shift: false,
+            alt: false,
+            message,
+        }
+    }
+
+    /// Requires the command modifier of the platform for the [`Hotkey`].
+    ///
+    /// It is the command key on macOS and the control key anywhere else.
+    ///
+    /// [`Hotkey`]: struct.Hotkey.html
+    pub fn command(mut self) -> Self {
+        self.command = true;
+        self
+    }
+
+    /// Requires the shift key for the [`Hotkey`].
+    ///
+    /// [`Hotkey`]: struct.Hotkey.html
+    pub fn shift(mut self) -> Self {
+        self.shift = true;
+        self
+    }
+
+    /// Requires the alt key for the [`Hotkey`].
+    ///
+    /// It is the option key on macOS.
+    ///
+    /// [`Hotkey`]: struct.Hotkey.html
+    pub fn alt(mut self) -> Self {
+        self.alt = true;
+        self
+    }
+
+    /// Returns whether the given [`Event`] presses the [`Hotkey`].
+    ///
+    /// The modifiers must match exactly, so `Ctrl+Shift+S` does not press a
+    /// `Ctrl+S` [`Hotkey`].
+    ///
+    /// [`Event`]: ../enum.Event.html
+    /// [`Hotkey`]: struct.Hotkey.html
+    pub fn matches(&self, event: &Event) -> bool {
+        match event {
+            Event::Keyboard(keyboard::Event::Input {
+                key_code,
+                state: ButtonState::Pressed,
+                modifiers,
+            }) => {
+                *key_code == self.key_code
+                    && platform::is_copy_paste_modifier_pressed(*modifiers)
+                        == self.command
+                    && modifiers.shift == self.shift
+                    && modifiers.alt == self.alt
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Removes the events pressing any of the given hotkeys and pushes the
+/// message of each one of them.
+///
+/// Shells should call it before updating a [`UserInterface`] with the
+/// events, so the widgets never see the key presses of a [`Hotkey`]. Every
+/// [`Hotkey`] produces its message once at most.
+///
+/// [`UserInterface`]: ../struct.UserInterface.html
+/// [`Hotkey`]: struct.Hotkey.html
+pub fn dispatch<Message>(
+    mut hotkeys: Vec<Hotkey<Message>>,
+    events: &mut Vec<Event>,
+    messages: &mut Vec<Message>,
+) {
+    events.retain(|event| {
+        match hotkeys.iter().position(|hotkey| hotkey.matches(event)) {
+            Some(index) => {
+                messages.push(hotkeys.swap_remove(index).message);
+
+                false
+            }
+            None => true,
+        }
+    });
+}
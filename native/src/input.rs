@@ -1,4 +1,5 @@
 //! Map your system events into input events that the runtime can understand.
+pub mod ime;
 pub mod keyboard;
 pub mod mouse;
 
@@ -0,0 +1,17 @@
+//! Compose text with an input method editor.
+//!
+//! An input method editor (IME) lets users type text out of several
+//! keystrokes, like the characters of Chinese, Japanese, or Korean.
+
+/// An input method editor event.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    /// The text being composed changed.
+    ///
+    /// It is not part of the value of a text field yet, but it is displayed
+    /// at its cursor. An empty text means the composition was cancelled.
+    Preedit(String),
+
+    /// The composition finished, producing the given text.
+    Commit(String),
+}
@@ -10,6 +10,12 @@ pub enum Button {
     /// The middle (wheel) button.
     Middle,
 
+    /// The back button, usually on the side of the mouse.
+    Back,
+
+    /// The forward button, usually on the side of the mouse.
+    Forward,
+
     /// Some other button.
     Other(u8),
 }
@@ -1,5 +1,5 @@
 use super::Button;
-use crate::input::ButtonState;
+use crate::{input::ButtonState, Vector};
 
 /// A mouse event.
 ///
@@ -35,12 +35,24 @@ pub enum Event {
 
     /// The mouse wheel was scrolled.
     WheelScrolled {
-        /// The scroll movement.
+        /// The scroll movement, as reported by the platform.
         delta: ScrollDelta,
+
+        /// The scroll movement in logical pixels.
+        ///
+        /// Lines are converted to pixels, and both are scaled, following the
+        /// settings of the application, so scrolling feels the same on every
+        /// platform and device. Positive values scroll towards the top left
+        /// of the content.
+        pixels: Vector,
     },
 }
 
 /// A scroll movement.
+///
+/// Mouse wheels usually scroll by lines, one per notch, or a fraction of one
+/// for high-resolution wheels. Trackpads and touchscreens usually scroll by
+/// pixels.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ScrollDelta {
     /// A line-based scroll movement
@@ -59,3 +71,18 @@ pub enum ScrollDelta {
         y: f32,
     },
 }
+
+impl ScrollDelta {
+    /// Converts the [`ScrollDelta`] into logical pixels, given the height of a
+    /// line and a multiplier applied to both kinds of movement.
+    ///
+    /// [`ScrollDelta`]: enum.ScrollDelta.html
+    pub fn to_pixels(self, line_height: f32, multiplier: f32) -> Vector {
+        let (x, y) = match self {
+            ScrollDelta::Lines { x, y } => (x * line_height, y * line_height),
+            ScrollDelta::Pixels { x, y } => (x, y),
+        };
+
+        Vector::new(x * multiplier, y * multiplier)
+    }
+}
@@ -0,0 +1,220 @@
+//! Inspect the widgets of a user interface, for development tools.
+//!
+//! A [`Node`] is a snapshot of the widget tree of a [`UserInterface`] after
+//! layout. External tools can compare the snapshots of consecutive frames
+//! with [`Node::diff`] to highlight what changed, and a hot-reload harness
+//! can use them to find where the state of a widget went after the view code
+//! changed, and move it there with [`Cache::remap`].
+//!
+//! This module is only available with the `devtools` feature.
+//!
+//! [`Node`]: struct.Node.html
+//! [`Node::diff`]: struct.Node.html#method.diff
+//! [`UserInterface`]: ../struct.UserInterface.html
+//! [`Cache::remap`]: ../struct.Cache.html#method.remap
+use crate::Rectangle;
+
+use std::collections::HashMap;
+
+/// A widget of a user interface, with its children, after layout.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+    /// The full type name of the widget, like
+    /// `iced_native::widget::button::Button<Message, Renderer>`.
+    pub type_name: &'static str,
+
+    /// The key of the widget, when its parent has one for it.
+    pub key: Option<u64>,
+
+    /// The bounds of the widget, as laid out.
+    pub bounds: Rectangle,
+
+    /// The children of the widget, in the order of their layout.
+    pub children: Vec<Node>,
+}
+
+impl Node {
+    /// Creates a [`Node`] without children.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn new(type_name: &'static str, bounds: Rectangle) -> Self {
+        Node {
+            type_name,
+            key: None,
+            bounds,
+            children: Vec::new(),
+        }
+    }
+
+    /// Returns the short name of the type of the widget, without its path
+    /// and generic parameters, like `Button`.
+    pub fn name(&self) -> &'static str {
+        let path = self.type_name.split('<').next().unwrap_or(self.type_name);
+
+        path.rsplit("::").next().unwrap_or(path)
+    }
+
+    /// Returns the descendant of the [`Node`] at the given path of child
+    /// indices, if any.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn find(&self, path: &[usize]) -> Option<&Node> {
+        path.iter()
+            .try_fold(self, |node, index| node.children.get(*index))
+    }
+
+    /// Compares the [`Node`] with the one of the next frame and returns the
+    /// changes between them.
+    ///
+    /// Children are matched like the runtime matches their state: by key,
+    /// when they have one, and by position otherwise. A matched widget of a
+    /// different type is [`Replaced`] and its children are not compared.
+    ///
+    /// [`Node`]: struct.Node.html
+    /// [`Replaced`]: enum.Change.html#variant.Replaced
+    ///
+    /// # Example
+    /// ```
+    /// use iced_native::{
+    ///     inspect::{Change, Node},
+    ///     Rectangle,
+    /// };
+    ///
+    /// let bounds = Rectangle {
+    ///     x: 0.0,
+    ///     y: 0.0,
+    ///     width: 100.0,
+    ///     height: 20.0,
+    /// };
+    ///
+    /// let previous = Node {
+    ///     children: vec![Node::new("Text", bounds)],
+    ///     ..Node::new("Column", bounds)
+    /// };
+    ///
+    /// let next = Node {
+    ///     children: vec![
+    ///         Node::new("Text", bounds),
+    ///         Node::new("Button", Rectangle { y: 20.0, ..bounds }),
+    ///     ],
+    ///     ..Node::new("Column", Rectangle { height: 40.0, ..bounds })
+    /// };
+    ///
+    /// let changes = previous.diff(&next);
+    ///
+    /// assert_eq!(changes.len(), 2);
+    /// assert_eq!(changes[1], Change::Added(vec![1]));
+    /// ```
+    pub fn diff(&self, next: &Node) -> Vec<Change> {
+        let mut changes = Vec::new();
+
+        diff(self, next, &mut Vec::new(), &mut Vec::new(), &mut changes);
+
+        changes
+    }
+}
+
+/// A difference between two snapshots of a user interface.
+///
+/// Paths are the indices of the children on the way to a [`Node`] from the
+/// root. They point into the next snapshot, except for [`Removed`] nodes.
+///
+/// [`Node`]: struct.Node.html
+/// [`Removed`]: #variant.Removed
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    /// A widget appeared.
+    Added(Vec<usize>),
+
+    /// A widget disappeared. The path points into the previous snapshot.
+    Removed(Vec<usize>),
+
+    /// A widget took the place of a widget of a different type.
+    Replaced(Vec<usize>),
+
+    /// A widget changed its bounds.
+    Moved {
+        /// The path of the widget.
+        path: Vec<usize>,
+
+        /// The previous bounds of the widget.
+        from: Rectangle,
+
+        /// The new bounds of the widget.
+        to: Rectangle,
+    },
+}
+
+fn diff(
+    previous: &Node,
+    next: &Node,
+    previous_path: &mut Vec<usize>,
+    next_path: &mut Vec<usize>,
+    changes: &mut Vec<Change>,
+) {
+    if previous.type_name != next.type_name {
+        changes.push(Change::Replaced(next_path.clone()));
+        return;
+    }
+
+    if previous.bounds != next.bounds {
+        changes.push(Change::Moved {
+            path: next_path.clone(),
+            from: previous.bounds,
+            to: next.bounds,
+        });
+    }
+
+    let mut keyed = HashMap::new();
+    let mut unkeyed = Vec::new();
+
+    for (index, child) in previous.children.iter().enumerate() {
+        match child.key {
+            Some(key) => {
+                let _ = keyed.insert(key, index);
+            }
+            None => unkeyed.push(index),
+        }
+    }
+
+    let mut unkeyed = unkeyed.into_iter();
+    let mut is_matched = vec![false; previous.children.len()];
+
+    for (index, child) in next.children.iter().enumerate() {
+        next_path.push(index);
+
+        let matched = match child.key {
+            Some(key) => keyed.remove(&key),
+            None => unkeyed.next(),
+        };
+
+        match matched {
+            Some(previous_index) => {
+                is_matched[previous_index] = true;
+
+                previous_path.push(previous_index);
+
+                diff(
+                    &previous.children[previous_index],
+                    child,
+                    previous_path,
+                    next_path,
+                    changes,
+                );
+
+                let _ = previous_path.pop();
+            }
+            None => changes.push(Change::Added(next_path.clone())),
+        }
+
+        let _ = next_path.pop();
+    }
+
+    for (index, is_matched) in is_matched.into_iter().enumerate() {
+        if !is_matched {
+            previous_path.push(index);
+            changes.push(Change::Removed(previous_path.clone()));
+            let _ = previous_path.pop();
+        }
+    }
+}
@@ -16,6 +16,21 @@ use crate::{Point, Rectangle, Vector};
 pub struct Layout<'a> {
     position: Point,
     node: &'a Node,
+    hover: Option<Hover<'a>>,
+    focus: Option<&'a [usize]>,
+}
+
+/// The part of a hovered path below a [`Layout`].
+///
+/// [`Layout`]: struct.Layout.html
+#[derive(Debug, Clone, Copy)]
+struct Hover<'a> {
+    /// The indices of the hovered children, from the next level down
+    path: &'a [usize],
+
+    /// The amount of levels, starting with this one, that are on the path
+    /// but not hovered
+    skipped: usize,
 }
 
 impl<'a> Layout<'a> {
@@ -29,9 +44,62 @@ impl<'a> Layout<'a> {
         Self {
             position: Point::new(bounds.x, bounds.y) + offset,
             node,
+            hover: None,
+            focus: None,
+        }
+    }
+
+    /// Marks the nodes on the given path as hovered, except for the first
+    /// `skipped` levels.
+    pub(crate) fn hovered(
+        node: &'a Node,
+        path: &'a [usize],
+        skipped: usize,
+    ) -> Self {
+        Self {
+            hover: Some(Hover { path, skipped }),
+            ..Self::new(node)
+        }
+    }
+
+    /// Targets the node at the end of the given path with an
+    /// [`Event::Focus`].
+    ///
+    /// [`Event::Focus`]: ../enum.Event.html#variant.Focus
+    pub(crate) fn focus_target(node: &'a Node, path: &'a [usize]) -> Self {
+        Self {
+            focus: Some(path),
+            ..Self::new(node)
         }
     }
 
+    /// Returns whether the cursor is over the [`Layout`], according to the
+    /// runtime.
+    ///
+    /// Unlike checking whether the bounds contain the cursor, only the
+    /// topmost of overlapping nodes is hovered, along with its ancestors.
+    ///
+    /// While handling an [`Event::Hover`], it returns whether the event
+    /// targets the [`Layout`] instead.
+    ///
+    /// [`Layout`]: struct.Layout.html
+    /// [`Event::Hover`]: ../enum.Event.html#variant.Hover
+    pub fn is_hovered(&self) -> bool {
+        self.hover.map(|hover| hover.skipped == 0).unwrap_or(false)
+    }
+
+    /// Returns whether the [`Event::Focus`] being handled targets the
+    /// [`Layout`].
+    ///
+    /// It always returns `false` while handling any other [`Event`].
+    ///
+    /// [`Layout`]: struct.Layout.html
+    /// [`Event::Focus`]: ../enum.Event.html#variant.Focus
+    /// [`Event`]: ../enum.Event.html
+    pub fn is_focus_target(&self) -> bool {
+        self.focus.map(<[usize]>::is_empty).unwrap_or(false)
+    }
+
     /// Gets the bounds of the [`Layout`].
     ///
     /// The returned [`Rectangle`] describes the position and size of a
@@ -56,11 +124,79 @@ impl<'a> Layout<'a> {
     /// [`Layout`]: struct.Layout.html
     /// [`Node`]: struct.Node.html
     pub fn children(&'a self) -> impl Iterator<Item = Layout<'a>> {
-        self.node.children().iter().map(move |node| {
-            Layout::with_offset(
+        self.node
+            .children()
+            .iter()
+            .enumerate()
+            .map(move |(index, node)| self.child(index, node))
+    }
+
+    /// Returns the [`Layout`] of the given [`Node`] as the child of this one
+    /// at the given index.
+    ///
+    /// The [`Node`] does not need to be part of the [`Layout`]. This way,
+    /// widgets building their children on demand, like a [`LazyColumn`],
+    /// keep the hovered and focused paths of the runtime.
+    ///
+    /// [`Layout`]: struct.Layout.html
+    /// [`Node`]: struct.Node.html
+    /// [`LazyColumn`]: ../widget/lazy_column/struct.LazyColumn.html
+    pub(crate) fn child<'b>(&self, index: usize, node: &'b Node) -> Layout<'b>
+    where
+        'a: 'b,
+    {
+        let hover = self.hover.and_then(|hover| {
+            let (first, path) = hover.path.split_first()?;
+
+            if *first == index {
+                Some(Hover {
+                    path,
+                    skipped: hover.skipped.saturating_sub(1),
+                })
+            } else {
+                None
+            }
+        });
+
+        let focus = self.focus.and_then(|path| {
+            let (first, path) = path.split_first()?;
+
+            if *first == index {
+                Some(path)
+            } else {
+                None
+            }
+        });
+
+        Layout {
+            hover,
+            focus,
+            ..Layout::with_offset(
                 Vector::new(self.position.x, self.position.y),
                 node,
             )
-        })
+        }
+    }
+}
+
+/// Finds the topmost descendant of a [`Layout`] that contains the given
+/// position, pushing the index of every child on the way to it.
+///
+/// It is the default implementation of [`Widget::hover`]. Later children are
+/// considered to be on top of earlier ones.
+///
+/// [`Layout`]: struct.Layout.html
+/// [`Widget::hover`]: ../widget/trait.Widget.html#method.hover
+pub fn hover(layout: Layout<'_>, position: Point, path: &mut Vec<usize>) {
+    let hovered = layout
+        .children()
+        .enumerate()
+        .filter(|(_, child)| child.bounds().contains(position))
+        .last();
+
+    if let Some((index, child)) = hovered {
+        path.push(index);
+
+        hover(child, position, path);
     }
 }
@@ -143,6 +143,30 @@ where
         }
     }
 
+    // Items aligned on their baselines are moved down to the lowest
+    // baseline, which can make the row taller.
+    let max_baseline = match axis {
+        Axis::Horizontal if align_items == Align::Baseline => nodes
+            .iter()
+            .filter_map(Node::baseline)
+            .fold(None, |max: Option<f32>, baseline| match max {
+                Some(max) => Some(max.max(baseline)),
+                None => Some(baseline),
+            }),
+        _ => None,
+    };
+
+    if let Some(max_baseline) = max_baseline {
+        for node in &nodes {
+            let offset = match node.baseline() {
+                Some(baseline) => max_baseline - baseline,
+                None => 0.0,
+            };
+
+            cross = cross.max(offset + node.size().height);
+        }
+    }
+
     let mut main = padding;
 
     for (i, node) in nodes.iter_mut().enumerate() {
@@ -156,9 +180,18 @@ where
         node.bounds.y = y;
 
         match axis {
-            Axis::Horizontal => {
-                node.align(Align::Start, align_items, Size::new(0.0, cross));
-            }
+            Axis::Horizontal => match (max_baseline, node.baseline()) {
+                (Some(max_baseline), Some(baseline)) => {
+                    node.bounds.y += max_baseline - baseline;
+                }
+                _ => {
+                    node.align(
+                        Align::Start,
+                        align_items,
+                        Size::new(0.0, cross),
+                    );
+                }
+            },
             Axis::Vertical => {
                 node.align(align_items, Align::Start, Size::new(cross, 0.0));
             }
@@ -176,4 +209,5 @@ where
         Size::new(size.width + padding * 2.0, size.height + padding * 2.0),
         nodes,
     )
+    .inherit_baseline()
 }
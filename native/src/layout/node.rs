@@ -1,9 +1,10 @@
-use crate::{Align, Rectangle, Size};
+use crate::{Align, Point, Rectangle, Size};
 
 /// The bounds of an element and its children.
 #[derive(Debug, Clone, Default)]
 pub struct Node {
     pub(crate) bounds: Rectangle,
+    baseline: Option<f32>,
     children: Vec<Node>,
 }
 
@@ -28,10 +29,52 @@ impl Node {
                 width: size.width,
                 height: size.height,
             },
+            baseline: None,
             children,
         }
     }
 
+    /// Sets the baseline of the [`Node`], as the distance from its top to
+    /// the baseline of its first line of text.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn with_baseline(mut self, baseline: f32) -> Self {
+        self.baseline = Some(baseline);
+        self
+    }
+
+    /// Moves the [`Node`] to the given position.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn move_to(&mut self, position: Point) {
+        self.bounds.x = position.x;
+        self.bounds.y = position.y;
+    }
+
+    /// Returns the baseline of the [`Node`], if it contains any text.
+    ///
+    /// The baseline is the distance from the top of the [`Node`] to the
+    /// baseline of its first line of text. It is used to align items with
+    /// [`Align::Baseline`].
+    ///
+    /// [`Node`]: struct.Node.html
+    /// [`Align::Baseline`]: ../enum.Align.html#variant.Baseline
+    pub fn baseline(&self) -> Option<f32> {
+        self.baseline
+    }
+
+    /// Sets the baseline of the [`Node`] to the baseline of its first child
+    /// containing text, if any.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub(crate) fn inherit_baseline(mut self) -> Self {
+        self.baseline = self.children.iter().find_map(|child| {
+            child.baseline.map(|baseline| child.bounds.y + baseline)
+        });
+
+        self
+    }
+
     /// Returns the [`Size`] of the [`Node`].
     ///
     /// [`Node`]: struct.Node.html
@@ -61,7 +104,7 @@ impl Node {
         space: Size,
     ) {
         match horizontal_alignment {
-            Align::Start => {}
+            Align::Start | Align::Baseline => {}
             Align::Center => {
                 self.bounds.x += (space.width - self.bounds.width) / 2.0;
             }
@@ -71,7 +114,7 @@ impl Node {
         }
 
         match vertical_alignment {
-            Align::Start => {}
+            Align::Start | Align::Baseline => {}
             Align::Center => {
                 self.bounds.y += (space.height - self.bounds.height) / 2.0;
             }
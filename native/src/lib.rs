@@ -17,12 +17,14 @@
 //! - A [`Windowed`] trait, leveraging [`raw-window-handle`], which can be
 //!   implemented by graphical renderers that target _windows_. Window-based
 //!   shells (like [`iced_winit`]) can use this trait to stay renderer-agnostic.
+//! - A [`Shell`] trait, describing the windowing system hosting a user
+//!   interface. It allows alternative shells to drive the runtime.
 //!
 //! # Usage
 //! The strategy to use this crate depends on your particular use case. If you
 //! want to:
 //! - Implement a custom shell or integrate it in your own system, you should
-//!   check out the [`UserInterface`] type.
+//!   check out the [`UserInterface`] type and the [`Shell`] trait.
 //! - Build a new renderer, see the [renderer] module.
 //! - Build a custom widget, start at the [`Widget`] trait.
 //!
@@ -32,6 +34,7 @@
 //! [`raw-window-handle`]: https://github.com/rust-windowing/raw-window-handle
 //! [`Widget`]: widget/trait.Widget.html
 //! [`Windowed`]: renderer/trait.Windowed.html
+//! [`Shell`]: shell/trait.Shell.html
 //! [`UserInterface`]: struct.UserInterface.html
 //! [renderer]: renderer/index.html
 #![deny(missing_docs)]
@@ -39,13 +42,23 @@
 #![deny(unused_results)]
 #![deny(unsafe_code)]
 #![deny(rust_2018_idioms)]
+pub mod clipboard;
+pub mod context;
+pub mod frame;
+pub mod hotkey;
 pub mod input;
+#[cfg(feature = "devtools")]
+pub mod inspect;
 pub mod layout;
+pub mod memory;
+pub mod overlay;
 pub mod renderer;
+pub mod shell;
+pub mod snap;
 pub mod subscription;
+pub mod tree;
 pub mod widget;
 
-mod clipboard;
 mod element;
 mod event;
 mod hasher;
@@ -54,18 +67,22 @@ mod size;
 mod user_interface;
 
 pub use iced_core::{
-    Align, Background, Color, Command, Font, HorizontalAlignment, Length,
-    Point, Rectangle, Vector, VerticalAlignment,
+    dialog, font, notification, toast, Align, Background, Color, Command,
+    Dialog, Font, Gradient, HorizontalAlignment, Length, Notification, Point,
+    Rectangle, Shadow, Toast, Vector, VerticalAlignment,
 };
 
 pub use clipboard::Clipboard;
 pub use element::Element;
-pub use event::Event;
+pub use event::{Event, Focus, Hover};
 pub use hasher::Hasher;
+pub use hotkey::Hotkey;
 pub use layout::Layout;
 pub use mouse_cursor::MouseCursor;
 pub use renderer::Renderer;
+pub use shell::Shell;
 pub use size::Size;
 pub use subscription::Subscription;
+pub use tree::Tree;
 pub use user_interface::{Cache, UserInterface};
 pub use widget::*;
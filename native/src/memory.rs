@@ -0,0 +1,40 @@
+//! React when the renderer runs low on device memory.
+//!
+//! A renderer can produce an [`Event::MemoryPressure`] when it fails to
+//! allocate device memory or exceeds a configured budget. You can listen to
+//! it with [`subscription::memory_pressure`] to drop optional content.
+//!
+//! [`Event::MemoryPressure`]: ../enum.Event.html#variant.MemoryPressure
+//! [`subscription::memory_pressure`]: ../subscription/fn.memory_pressure.html
+
+/// A warning that the renderer is running low on device memory.
+///
+/// The renderer has already trimmed its caches when it reports it. Anything
+/// drawn again will be uploaded again, so applications should stop showing
+/// optional content, like thumbnails or previews, to relieve the pressure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pressure {
+    /// What caused the [`Pressure`].
+    ///
+    /// [`Pressure`]: struct.Pressure.html
+    pub cause: Cause,
+
+    /// The estimated device memory used by the caches of the renderer after
+    /// trimming them, in bytes.
+    pub used: u64,
+
+    /// The configured budget of device memory, in bytes, if any.
+    pub budget: Option<u64>,
+}
+
+/// The cause of a [`Pressure`].
+///
+/// [`Pressure`]: struct.Pressure.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Cause {
+    /// The caches of the renderer grew beyond the configured budget.
+    Budget,
+
+    /// The renderer failed to allocate device memory.
+    AllocationFailed,
+}
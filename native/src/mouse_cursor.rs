@@ -1,4 +1,7 @@
 /// The state of the mouse cursor.
+///
+/// When the children of a widget report different states, renderers usually
+/// keep the one declared last.
 #[derive(Debug, Eq, PartialEq, Clone, Copy, PartialOrd, Ord)]
 pub enum MouseCursor {
     /// The cursor is out of the bounds of the user interface.
@@ -21,6 +24,46 @@ pub enum MouseCursor {
 
     /// The cursor is over a text widget.
     Text,
+
+    /// The cursor is resizing a widget horizontally.
+    ResizingHorizontally,
+
+    /// The cursor is resizing a widget vertically.
+    ResizingVertically,
+
+    /// The cursor is resizing a widget from its top-left or bottom-right
+    /// corner.
+    ResizingDiagonallyDown,
+
+    /// The cursor is resizing a widget from its top-right or bottom-left
+    /// corner.
+    ResizingDiagonallyUp,
+
+    /// The cursor is over a widget that can be moved in any direction.
+    Move,
+
+    /// The cursor is over a widget that needs precise positioning, like a
+    /// drawing tool.
+    Crosshair,
+
+    /// The cursor is over a widget that zooms in when clicked.
+    ZoomIn,
+
+    /// The cursor is over a widget that zooms out when clicked.
+    ZoomOut,
+
+    /// The cursor is over a widget that shows help.
+    Help,
+
+    /// The cursor is over a widget that cannot be interacted with right now,
+    /// like a disabled button.
+    NotAllowed,
+
+    /// The cursor is hidden.
+    ///
+    /// A widget can draw a cursor of its own, like an image, at the cursor
+    /// position instead.
+    Hidden,
 }
 
 impl Default for MouseCursor {
@@ -0,0 +1,251 @@
+//! Display interactive elements on top of the user interface.
+//!
+//! Widgets can float some content over the rest of the user interface, like
+//! the menu of a [`ComboBox`], by returning an overlay [`Element`] from
+//! [`Widget::overlay`].
+//!
+//! An overlay is laid out within the bounds of the whole user interface and
+//! drawn above it, outside of the clipping bounds of the widgets containing
+//! its owner. It also processes events before them. While the cursor is over
+//! an overlay, the widgets under it cannot see the cursor, and clicks and
+//! scrolls do not reach them. An overlay can also [capture] any other event,
+//! keeping it from the widgets under it.
+//!
+//! [`ComboBox`]: ../widget/combo_box/struct.ComboBox.html
+//! [`Element`]: struct.Element.html
+//! [`Widget::overlay`]: ../widget/trait.Widget.html#method.overlay
+//! [capture]: enum.Status.html#variant.Captured
+use crate::{layout, Clipboard, Event, Layout, Point, Size, Vector};
+
+/// Some content floating over the user interface.
+///
+/// If you want a widget to display an overlay, you will need to implement
+/// this trait.
+///
+/// [`Widget`]: ../widget/trait.Widget.html
+pub trait Overlay<Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    /// Returns the [`Node`] of the [`Overlay`], given the size of the user
+    /// interface and the position its owner asked for.
+    ///
+    /// The [`Node`] should be positioned in absolute coordinates. It is up
+    /// to the [`Overlay`] to keep itself inside of the user interface.
+    ///
+    /// [`Node`]: ../layout/struct.Node.html
+    /// [`Overlay`]: trait.Overlay.html
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node;
+
+    /// Draws the [`Overlay`] using the associated `Renderer`.
+    ///
+    /// [`Overlay`]: trait.Overlay.html
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output;
+
+    /// Processes a runtime [`Event`], before the widgets under the
+    /// [`Overlay`] do.
+    ///
+    /// It returns whether the [`Overlay`] captured the [`Event`]. A captured
+    /// [`Event`] does not reach the widgets under the [`Overlay`].
+    ///
+    /// By default, it does nothing and ignores the [`Event`].
+    ///
+    /// [`Event`]: ../enum.Event.html
+    /// [`Overlay`]: trait.Overlay.html
+    fn on_event(
+        &mut self,
+        _event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> Status {
+        Status::Ignored
+    }
+}
+
+/// Whether an [`Overlay`] captured an [`Event`].
+///
+/// [`Overlay`]: trait.Overlay.html
+/// [`Event`]: ../enum.Event.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Event`] also reaches the widgets under the [`Overlay`].
+    ///
+    /// Clicks and scrolls over the [`Overlay`] never do, though.
+    ///
+    /// [`Event`]: ../enum.Event.html
+    /// [`Overlay`]: trait.Overlay.html
+    Ignored,
+
+    /// The [`Event`] does not reach the widgets under the [`Overlay`].
+    ///
+    /// [`Event`]: ../enum.Event.html
+    /// [`Overlay`]: trait.Overlay.html
+    Captured,
+}
+
+/// A generic [`Overlay`], placed at some position.
+///
+/// [`Overlay`]: trait.Overlay.html
+#[allow(missing_debug_implementations)]
+pub struct Element<'a, Message, Renderer> {
+    position: Point,
+    overlay: Box<dyn Overlay<Message, Renderer> + 'a>,
+}
+
+impl<'a, Message, Renderer> Element<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    /// Creates a new [`Element`] placing the given [`Overlay`] at a position,
+    /// in the coordinates of the [`Layout`] of its owner.
+    ///
+    /// [`Element`]: struct.Element.html
+    /// [`Overlay`]: trait.Overlay.html
+    /// [`Layout`]: ../layout/struct.Layout.html
+    pub fn new(
+        position: Point,
+        overlay: Box<dyn Overlay<Message, Renderer> + 'a>,
+    ) -> Self {
+        Element { position, overlay }
+    }
+
+    /// Moves the [`Element`] by the given translation.
+    ///
+    /// Widgets that move their children on the screen, like a
+    /// [`Scrollable`], should translate the overlays of their children the
+    /// same way.
+    ///
+    /// [`Element`]: struct.Element.html
+    /// [`Scrollable`]: ../widget/scrollable/struct.Scrollable.html
+    pub fn translate(mut self, translation: Vector) -> Self {
+        self.position = self.position + translation;
+        self
+    }
+
+    /// Applies a transformation to the produced messages of the [`Element`].
+    ///
+    /// [`Element`]: struct.Element.html
+    pub fn map<B>(self, f: &'a dyn Fn(Message) -> B) -> Element<'a, B, Renderer>
+    where
+        Message: 'a,
+        Renderer: 'a,
+    {
+        Element {
+            position: self.position,
+            overlay: Box::new(Map {
+                content: self.overlay,
+                mapper: f,
+            }),
+        }
+    }
+
+    /// Computes the [`Node`] of the [`Element`] in the given bounds.
+    ///
+    /// [`Node`]: ../layout/struct.Node.html
+    /// [`Element`]: struct.Element.html
+    pub fn layout(&self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        self.overlay.layout(renderer, bounds, self.position)
+    }
+
+    /// Processes a runtime [`Event`], returning whether the [`Element`]
+    /// captured it.
+    ///
+    /// [`Event`]: ../enum.Event.html
+    /// [`Element`]: struct.Element.html
+    pub fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> Status {
+        self.overlay.on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    /// Draws the [`Element`].
+    ///
+    /// [`Element`]: struct.Element.html
+    pub fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.overlay.draw(renderer, layout, cursor_position)
+    }
+}
+
+struct Map<'a, A, B, Renderer> {
+    content: Box<dyn Overlay<A, Renderer> + 'a>,
+    mapper: &'a dyn Fn(A) -> B,
+}
+
+impl<'a, A, B, Renderer> Overlay<B, Renderer> for Map<'a, A, B, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        self.content.layout(renderer, bounds, position)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.content.draw(renderer, layout, cursor_position)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<B>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> Status {
+        let mut original_messages = Vec::new();
+
+        let status = self.content.on_event(
+            event,
+            layout,
+            cursor_position,
+            &mut original_messages,
+            renderer,
+            clipboard,
+        );
+
+        messages.extend(original_messages.into_iter().map(self.mapper));
+
+        status
+    }
+}
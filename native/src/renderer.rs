@@ -23,14 +23,18 @@
 mod debugger;
 #[cfg(debug_assertions)]
 mod null;
+mod text_rendering;
 mod windowed;
 
 pub use debugger::Debugger;
 #[cfg(debug_assertions)]
 pub use null::Null;
+pub use text_rendering::{TextAntialiasing, TextRendering};
 pub use windowed::{Target, Windowed};
 
-use crate::{layout, Element};
+use crate::{layout, Element, Rectangle};
+
+use std::time::Instant;
 
 /// A component that can take the state of a user interface and produce an
 /// output for its users.
@@ -53,4 +57,28 @@ pub trait Renderer: Sized {
     ) -> layout::Node {
         element.layout(self, &layout::Limits::NONE)
     }
+
+    /// Draws an overlay on top of the rest of the user interface.
+    ///
+    /// The overlay must be drawn above the `base`, and it must not be clipped
+    /// by any of the clipping bounds of the `base`.
+    fn overlay(
+        &mut self,
+        base: Self::Output,
+        overlay: Self::Output,
+        overlay_bounds: Rectangle,
+    ) -> Self::Output;
+
+    /// Requests the user interface to be drawn again at the given instant,
+    /// even if no events happen until then.
+    ///
+    /// Widgets can call this while drawing to animate, like blinking the
+    /// caret of a text input. It is ignored by default.
+    fn schedule_redraw(&mut self, _at: Instant) {}
+
+    /// Tells the platform where the caret of the focused text field is.
+    ///
+    /// Input method editors place their candidate window next to it. Text
+    /// fields call this while drawing. It is ignored by default.
+    fn set_ime_caret(&mut self, _caret: Rectangle) {}
 }
@@ -1,7 +1,12 @@
 use crate::{
-    button, checkbox, column, radio, row, scrollable, text, text_input,
-    Background, Color, Element, Font, HorizontalAlignment, Layout, Point,
-    Rectangle, Renderer, Size, VerticalAlignment,
+    avatar, badge, button, cache_stats, cached, checkbox, column, combo_box,
+    date_picker, dialogs, draggable, image, knob, lazy_column, link, menu_bar,
+    minimap, modal, mouse_area, number_input, progress_bar, radio, rating,
+    reveal, rich_text, row, scrollable, segmented_button, selectable_text,
+    snap, space, spinner, table, tabs, text, text_editor, text_input,
+    time_picker, timeline, toasts, xy_pad, Background, Color, Element, Font,
+    HorizontalAlignment, Layout, MouseCursor, Point, Rectangle, Renderer,
+    Shadow, Size, Vector, VerticalAlignment,
 };
 
 /// A renderer that does nothing.
@@ -10,6 +15,9 @@ pub struct Null;
 
 impl Renderer for Null {
     type Output = ();
+
+    fn overlay(&mut self, _base: (), _overlay: (), _overlay_bounds: Rectangle) {
+    }
 }
 
 impl column::Renderer for Null {
@@ -32,6 +40,24 @@ impl row::Renderer for Null {
     }
 }
 
+impl space::Renderer for Null {
+    fn draw(&mut self, _bounds: Rectangle) {}
+}
+
+impl image::Renderer for Null {
+    fn dimensions(&self, _handle: &image::Handle) -> (u32, u32) {
+        (0, 0)
+    }
+
+    fn draw(
+        &mut self,
+        _handle: image::Handle,
+        _fit: image::Fit,
+        _layout: Layout<'_>,
+    ) {
+    }
+}
+
 impl text::Renderer for Null {
     fn default_size(&self) -> u16 {
         20
@@ -42,30 +68,130 @@ impl text::Renderer for Null {
         _content: &str,
         _size: u16,
         _font: Font,
+        _params: text::Params,
         _bounds: Size,
     ) -> (f32, f32) {
         (0.0, 20.0)
     }
 
+    fn baseline(&self, _size: u16, _font: Font) -> f32 {
+        16.0
+    }
+
     fn draw(
         &mut self,
         _bounds: Rectangle,
         _content: &str,
         _size: u16,
         _font: Font,
+        _params: text::Params,
         _color: Option<Color>,
+        _shadow: Option<Shadow>,
         _horizontal_alignment: HorizontalAlignment,
         _vertical_alignment: VerticalAlignment,
     ) {
     }
 }
 
+impl link::Renderer for Null {
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _content: &str,
+        _size: u16,
+        _font: Font,
+        _is_visited: bool,
+    ) {
+    }
+}
+
+impl rich_text::Renderer for Null {
+    fn measure(
+        &self,
+        _spans: &[rich_text::Span],
+        _params: text::Params,
+        _bounds: Size,
+    ) -> (f32, f32) {
+        (0.0, 20.0)
+    }
+
+    fn span_at(
+        &self,
+        _bounds: Rectangle,
+        _spans: &[rich_text::Span],
+        _params: text::Params,
+        _horizontal_alignment: HorizontalAlignment,
+        _vertical_alignment: VerticalAlignment,
+        _point: Point,
+    ) -> Option<usize> {
+        None
+    }
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _spans: &[rich_text::Span],
+        _params: text::Params,
+        _horizontal_alignment: HorizontalAlignment,
+        _vertical_alignment: VerticalAlignment,
+    ) {
+    }
+}
+
+impl selectable_text::Renderer for Null {
+    fn offset_at(
+        &self,
+        _bounds: Rectangle,
+        _content: &str,
+        _size: u16,
+        _font: Font,
+        _params: text::Params,
+        _horizontal_alignment: HorizontalAlignment,
+        _vertical_alignment: VerticalAlignment,
+        _point: Point,
+    ) -> usize {
+        0
+    }
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _content: &str,
+        _size: u16,
+        _font: Font,
+        _params: text::Params,
+        _color: Option<Color>,
+        _horizontal_alignment: HorizontalAlignment,
+        _vertical_alignment: VerticalAlignment,
+        _selection: Option<std::ops::Range<usize>>,
+    ) {
+    }
+}
+
+impl lazy_column::Renderer for Null {
+    fn draw(&mut self, _rows: Vec<()>) {}
+}
+
 impl scrollable::Renderer for Null {
     fn scrollbar(
         &self,
         _bounds: Rectangle,
         _content_bounds: Rectangle,
         _offset: u32,
+        _style: &scrollable::Style,
+    ) -> Option<scrollable::Scrollbar> {
+        None
+    }
+
+    fn horizontal_scrollbar(
+        &self,
+        _bounds: Rectangle,
+        _content_bounds: Rectangle,
+        _offset: u32,
+        _style: &scrollable::Style,
     ) -> Option<scrollable::Scrollbar> {
         None
     }
@@ -76,23 +202,60 @@ impl scrollable::Renderer for Null {
         _bounds: Rectangle,
         _content_bounds: Rectangle,
         _is_mouse_over: bool,
-        _is_mouse_over_scrollbar: bool,
-        _scrollbar: Option<scrollable::Scrollbar>,
-        _offset: u32,
+        _mouse_over_scrollbar: Option<draggable::Axis>,
+        _scrollbars: scrollable::Scrollbars,
+        _offset: Vector<u32>,
         _content: Self::Output,
     ) {
     }
 }
 
+impl text_editor::Renderer for Null {
+    fn line_height(&self, _size: u16, _font: Font) -> f32 {
+        20.0
+    }
+
+    fn caret_position(
+        &self,
+        _spans: &[rich_text::Span],
+        _offset: usize,
+    ) -> f32 {
+        0.0
+    }
+
+    fn offset_at(&self, _spans: &[rich_text::Span], _x: f32) -> usize {
+        0
+    }
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _lines: &[text_editor::Line],
+        _selection: &[Rectangle],
+        _caret: Option<Rectangle>,
+        _is_focused: bool,
+    ) {
+    }
+}
+
 impl text_input::Renderer for Null {
     fn default_size(&self) -> u16 {
         20
     }
 
+    fn default_caret(&self) -> text_input::Caret {
+        text_input::Caret::default()
+    }
+
     fn measure_value(&self, _value: &str, _size: u16) -> f32 {
         0.0
     }
 
+    fn baseline(&self, _size: u16) -> f32 {
+        16.0
+    }
+
     fn offset(
         &self,
         _text_bounds: Rectangle,
@@ -112,15 +275,24 @@ impl text_input::Renderer for Null {
         _placeholder: &str,
         _value: &text_input::Value,
         _state: &text_input::State,
+        _caret: Option<text_input::Caret>,
+        _misspellings: &[text_input::Misspelling],
     ) -> Self::Output {
     }
+
+    fn draw_menu(
+        &mut self,
+        _cursor_position: Point,
+        _menu: &text_input::Menu<'_>,
+    ) {
+    }
 }
 
 impl button::Renderer for Null {
     fn draw(
         &mut self,
         _bounds: Rectangle,
-        _cursor_position: Point,
+        _is_mouse_over: bool,
         _is_pressed: bool,
         _background: Option<Background>,
         _border_radius: u16,
@@ -144,6 +316,16 @@ impl radio::Renderer for Null {
     }
 }
 
+impl reveal::Renderer for Null {
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _content: Self::Output,
+        _visible: Option<reveal::Visible>,
+    ) {
+    }
+}
+
 impl checkbox::Renderer for Null {
     fn default_size(&self) -> u32 {
         20
@@ -158,3 +340,259 @@ impl checkbox::Renderer for Null {
     ) {
     }
 }
+
+impl avatar::Renderer for Null {
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _image: Option<image::Handle>,
+        _initials: &str,
+        _background: Color,
+        _foreground: Color,
+    ) {
+    }
+}
+
+impl progress_bar::Renderer for Null {
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _progress: f32,
+        _label: Option<&str>,
+        _text_size: u16,
+    ) {
+    }
+}
+
+impl spinner::Renderer for Null {
+    fn draw(&mut self, _bounds: Rectangle, _phase: f32, _color: Color) {}
+}
+
+impl badge::Renderer for Null {
+    fn draw(
+        &mut self,
+        _content: Self::Output,
+        _bounds: Rectangle,
+        _label: &str,
+        _text_size: u16,
+        _background: Color,
+        _foreground: Color,
+    ) {
+    }
+}
+
+impl cached::Renderer for Null {
+    fn cache(&mut self, _output: Self::Output) {}
+}
+
+impl timeline::Renderer for Null {
+    fn ruler_height(&self) -> u16 {
+        20
+    }
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _ticks: &[timeline::Tick],
+        _lanes: &[timeline::Lane<'_>],
+        _spans: &[timeline::Span<'_>],
+        _is_dragging: bool,
+    ) {
+    }
+}
+
+impl minimap::Renderer for Null {
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _content: Option<()>,
+        _projection: Option<minimap::Projection>,
+        _is_dragging: bool,
+    ) {
+    }
+}
+
+impl cache_stats::Renderer for Null {
+    fn stats(&self) -> cache_stats::Stats {
+        cache_stats::Stats::default()
+    }
+
+    fn draw(&mut self, _bounds: Rectangle, _stats: &cache_stats::Stats) {}
+}
+
+impl table::Renderer for Null {
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _regions: &[table::Region],
+        _is_resizing: bool,
+        _editor: Option<()>,
+    ) {
+    }
+}
+
+impl combo_box::Renderer for Null {
+    fn draw(&mut self, _cursor_position: Point, _menu: &combo_box::Menu) {}
+}
+
+impl number_input::Renderer for Null {
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _input: (),
+        _buttons: &number_input::Buttons,
+        _is_valid: bool,
+    ) {
+    }
+}
+
+impl segmented_button::Renderer for Null {
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _segments: &[segmented_button::Segment],
+        _text_size: u16,
+        _style: &segmented_button::Style,
+    ) {
+    }
+}
+
+impl rating::Renderer for Null {
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _stars: &[rating::Star],
+        _is_focused: bool,
+        _is_mouse_over: bool,
+        _style: &rating::Style,
+    ) {
+    }
+}
+
+impl tabs::Renderer for Null {
+    fn draw(
+        &mut self,
+        _strip: Rectangle,
+        _cursor_position: Point,
+        _tabs: &[tabs::Tab],
+        _headers: &[tabs::Header],
+        _is_focused: bool,
+        _style: &tabs::Style,
+        _content: (),
+    ) {
+    }
+}
+
+impl snap::Renderer for Null {
+    fn guide_thickness(&self) -> f32 {
+        0.0
+    }
+
+    fn draw_snap(&mut self, _snap: &snap::Snap) {}
+}
+
+impl draggable::Renderer for Null {
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _is_dragging: bool,
+        _axis: Option<draggable::Axis>,
+        _content: (),
+    ) {
+    }
+}
+
+impl menu_bar::Renderer for Null {
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _titles: &[menu_bar::Title<'_>],
+        _text_size: u16,
+    ) {
+    }
+
+    fn menus(
+        &mut self,
+        _cursor_position: Point,
+        _panels: &[menu_bar::Panel<'_>],
+        _text_size: u16,
+    ) {
+    }
+}
+
+impl modal::Renderer for Null {
+    fn draw(&mut self, _bounds: Rectangle, _backdrop: Color, _dialog: ()) {}
+}
+
+impl date_picker::Renderer for Null {
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _calendar: &date_picker::Calendar<'_>,
+        _text_size: u16,
+    ) {
+    }
+}
+
+impl time_picker::Renderer for Null {
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _fields: &[time_picker::Field],
+        _separator: Rectangle,
+        _text_size: u16,
+    ) {
+    }
+}
+
+impl dialogs::Renderer for Null {
+    fn draw(&mut self, _bounds: Rectangle, _content: ()) {}
+}
+
+impl toasts::Renderer for Null {
+    fn draw(
+        &mut self,
+        _cursor_position: Point,
+        _content: (),
+        _cards: &[toasts::Card<'_>],
+    ) {
+    }
+}
+
+impl mouse_area::Renderer for Null {
+    fn draw(&mut self, _mouse_cursor: Option<MouseCursor>, _content: ()) {}
+}
+
+impl knob::Renderer for Null {
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _range: std::ops::RangeInclusive<f32>,
+        _value: f32,
+        _is_dragging: bool,
+    ) {
+    }
+}
+
+impl xy_pad::Renderer for Null {
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _x_range: std::ops::RangeInclusive<f32>,
+        _y_range: std::ops::RangeInclusive<f32>,
+        _value: (f32, f32),
+        _is_dragging: bool,
+    ) {
+    }
+}
@@ -0,0 +1,35 @@
+/// How a renderer rasterizes the glyphs of text.
+///
+/// Small text on a screen with a low DPI looks blurry with the default
+/// grayscale antialiasing. Subpixel antialiasing and hinting make it look
+/// sharper, like the text of most native toolkits, at the cost of some color
+/// fringes and slightly less accurate shapes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct TextRendering {
+    /// How the edges of glyphs are smoothed.
+    pub antialiasing: TextAntialiasing,
+
+    /// Whether glyphs are snapped to the pixel grid.
+    ///
+    /// The baseline of every line is moved to a whole pixel, so horizontal
+    /// stems look crisp instead of smeared over two rows of pixels. By
+    /// default, it is disabled.
+    pub hinting: bool,
+}
+
+/// How the edges of glyphs are smoothed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TextAntialiasing {
+    /// Every pixel is covered by a shade of the color of the text.
+    ///
+    /// It looks the same on any screen, including rotated ones.
+    #[default]
+    Grayscale,
+
+    /// The red, green, and blue subpixels of every pixel are covered
+    /// separately, tripling the horizontal resolution of text.
+    ///
+    /// It only looks right on screens with subpixels in RGB order, which are
+    /// the most common ones, and when the text is not rotated.
+    SubpixelRgb,
+}
@@ -1,6 +1,7 @@
-use crate::MouseCursor;
+use crate::{memory, renderer::TextRendering, MouseCursor, Rectangle};
 
 use raw_window_handle::HasRawWindowHandle;
+use std::time::Instant;
 
 /// A renderer that can target windows.
 pub trait Windowed: super::Renderer + Sized {
@@ -12,17 +13,90 @@ pub trait Windowed: super::Renderer + Sized {
     /// [`Windowed`]: trait.Windowed.html
     fn new() -> Self;
 
+    /// Sets the factor multiplying the size of all the text.
+    ///
+    /// It is independent of the DPI of the target, and it allows users to
+    /// read larger text without scaling the rest of the user interface.
+    fn set_text_scale(&mut self, scale: f32);
+
+    /// Sets how the glyphs of text are rasterized.
+    ///
+    /// By default, it is ignored.
+    fn set_text_rendering(&mut self, _text_rendering: TextRendering) {}
+
     /// Performs the drawing operations described in the output on the given
     /// target.
     ///
+    /// The damage is the list of regions that changed since the last output
+    /// drawn on the target, as returned by [`damage`]. The renderer may keep
+    /// the last frame and only draw those regions again. If it is `None`, the
+    /// whole target must be drawn.
+    ///
     /// The overlay can be a bunch of debug text logs. It should be rendered on
     /// top of the GUI on most scenarios.
+    ///
+    /// [`damage`]: #tymethod.damage
     fn draw<T: AsRef<str>>(
         &mut self,
         output: &Self::Output,
+        damage: Option<&[Rectangle]>,
         overlay: &[T],
         target: &mut Self::Target,
     ) -> MouseCursor;
+
+    /// Compares two outputs and returns the regions of the target that
+    /// differ between them.
+    ///
+    /// An empty list means that drawing the `current` output would not
+    /// change what is on the screen, so the redraw can be skipped. Otherwise,
+    /// the regions can be given to [`draw`], so only they are drawn again.
+    ///
+    /// [`draw`]: #tymethod.draw
+    fn damage(
+        &self,
+        previous: &Self::Output,
+        current: &Self::Output,
+    ) -> Vec<Rectangle>;
+
+    /// Returns the [`MouseCursor`] requested by the given output.
+    ///
+    /// [`MouseCursor`]: ../enum.MouseCursor.html
+    fn mouse_cursor(&self, output: &Self::Output) -> MouseCursor;
+
+    /// Returns the earliest redraw scheduled while drawing the last output,
+    /// if any, and clears it.
+    ///
+    /// See [`Renderer::schedule_redraw`].
+    ///
+    /// [`Renderer::schedule_redraw`]: trait.Renderer.html#method.schedule_redraw
+    fn next_redraw(&mut self) -> Option<Instant>;
+
+    /// Returns the caret requested while drawing the last output, if any,
+    /// and clears it.
+    ///
+    /// See [`Renderer::set_ime_caret`]. By default, no caret is ever
+    /// requested.
+    ///
+    /// [`Renderer::set_ime_caret`]: trait.Renderer.html#method.set_ime_caret
+    fn ime_caret(&mut self) -> Option<Rectangle> {
+        None
+    }
+
+    /// Returns the memory [`Pressure`] detected while drawing the last
+    /// output, if any, and clears it.
+    ///
+    /// By default, no pressure is ever detected.
+    ///
+    /// [`Pressure`]: ../memory/struct.Pressure.html
+    fn memory_pressure(&mut self) -> Option<memory::Pressure> {
+        None
+    }
+
+    /// Hands back an output that will not be drawn anymore.
+    ///
+    /// The runtime calls it when an output is replaced by a new one, so the
+    /// renderer can reuse its allocations. By default, the output is dropped.
+    fn recycle(&mut self, _output: Self::Output) {}
 }
 
 /// A rendering target.
@@ -0,0 +1,175 @@
+//! Host a user interface in your own windowing system.
+//!
+//! `iced_winit` is the default shell of Iced, and it drives its event loop
+//! through this trait. However, some platforms cannot use [`winit`] (for
+//! instance, an embedded Wayland compositor, an SDL2 game loop, or an Android
+//! activity). The [`Shell`] trait describes everything the runtime needs from
+//! a windowing system, so the same [`UserInterface`] and [`Windowed`]
+//! renderer can be hosted anywhere.
+//!
+//! A typical event loop built on top of a [`Shell`] looks like this:
+//!
+//! 1. [`pump`] the [`Shell`] to wait for new [`Event`] values, until the
+//!    next redraw scheduled by the renderer is due, or until its [`waker`]
+//!    is woken up by a background task.
+//! 2. Build a [`UserInterface`] and feed it those events, using the
+//!    [`Clipboard`] of the [`Shell`].
+//! 3. Draw the [`UserInterface`] on a [`Target`] created from the
+//!    [`Shell::window`], resizing it whenever the [`Shell`] reports a new size.
+//! 4. Apply the returned [`MouseCursor`] with [`Shell::set_mouse_cursor`].
+//!
+//! [`winit`]: https://github.com/rust-windowing/winit
+//! [`Shell`]: trait.Shell.html
+//! [`pump`]: trait.Shell.html#tymethod.pump
+//! [`waker`]: trait.Shell.html#tymethod.waker
+//! [`Shell::window`]: trait.Shell.html#tymethod.window
+//! [`Shell::set_mouse_cursor`]: trait.Shell.html#tymethod.set_mouse_cursor
+//! [`Event`]: ../enum.Event.html
+//! [`UserInterface`]: ../struct.UserInterface.html
+//! [`Windowed`]: ../renderer/trait.Windowed.html
+//! [`Target`]: ../renderer/trait.Target.html
+//! [`Clipboard`]: ../trait.Clipboard.html
+//! [`MouseCursor`]: ../enum.MouseCursor.html
+use crate::{Clipboard, Event, MouseCursor, Rectangle, Size};
+
+use raw_window_handle::HasRawWindowHandle;
+use std::{task::Waker, time::Instant};
+
+/// A windowing system able to host a user interface.
+pub trait Shell {
+    /// The type of window managed by the [`Shell`].
+    ///
+    /// It is used to create the rendering [`Target`] of a [`Windowed`]
+    /// renderer.
+    ///
+    /// [`Shell`]: trait.Shell.html
+    /// [`Target`]: ../renderer/trait.Target.html
+    /// [`Windowed`]: ../renderer/trait.Windowed.html
+    type Window: HasRawWindowHandle;
+
+    /// Returns the window of the [`Shell`].
+    ///
+    /// [`Shell`]: trait.Shell.html
+    fn window(&self) -> &Self::Window;
+
+    /// Returns the current logical size of the window of the [`Shell`].
+    ///
+    /// [`Shell`]: trait.Shell.html
+    fn size(&self) -> Size;
+
+    /// Returns the current DPI factor of the window of the [`Shell`].
+    ///
+    /// [`Shell`]: trait.Shell.html
+    fn dpi(&self) -> f32;
+
+    /// Returns the refresh rate of the display showing the window of the
+    /// [`Shell`], in hertz, if known.
+    ///
+    /// It is used to count the vertical syncs missed by every [`Frame`].
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Shell`]: trait.Shell.html
+    /// [`Frame`]: ../frame/struct.Frame.html
+    fn refresh_rate(&self) -> Option<u16> {
+        None
+    }
+
+    /// Waits for the windowing system to produce new events, appending the
+    /// ones relevant to the runtime to the given list.
+    ///
+    /// It returns as soon as there is something to process, or once the
+    /// given deadline is reached, so animations keep running while the
+    /// windowing system is idle.
+    ///
+    /// It returns the [`Status`] of the [`Shell`] after processing.
+    ///
+    /// [`Shell`]: trait.Shell.html
+    /// [`Status`]: enum.Status.html
+    fn pump(
+        &mut self,
+        events: &mut Vec<Event>,
+        deadline: Option<Instant>,
+    ) -> Status;
+
+    /// Returns a [`Waker`] that makes a pending [`pump`] return, from any
+    /// thread.
+    ///
+    /// The runtime wakes the [`Shell`] up when a command or a subscription
+    /// produces a message in the background.
+    ///
+    /// [`Waker`]: https://doc.rust-lang.org/std/task/struct.Waker.html
+    /// [`pump`]: #tymethod.pump
+    /// [`Shell`]: trait.Shell.html
+    fn waker(&self) -> Waker;
+
+    /// Returns the [`Clipboard`] of the [`Shell`], if available.
+    ///
+    /// [`Shell`]: trait.Shell.html
+    /// [`Clipboard`]: ../trait.Clipboard.html
+    fn clipboard(&self) -> Option<&dyn Clipboard> {
+        None
+    }
+
+    /// Updates the icon of the mouse cursor of the [`Shell`].
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Shell`]: trait.Shell.html
+    fn set_mouse_cursor(&mut self, _mouse_cursor: MouseCursor) {}
+
+    /// Tells the [`Shell`] whether a widget has captured the mouse.
+    ///
+    /// The release of the mouse button may never arrive once the window
+    /// loses the focus. In that case, the [`Shell`] should produce it.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Shell`]: trait.Shell.html
+    fn set_mouse_captured(&mut self, _is_mouse_captured: bool) {}
+
+    /// Updates the title of the window of the [`Shell`].
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Shell`]: trait.Shell.html
+    fn set_title(&mut self, _title: &str) {}
+
+    /// Moves the candidate window of the input method editor next to the
+    /// given caret, in logical coordinates.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Shell`]: trait.Shell.html
+    fn set_ime_caret(&mut self, _caret: Rectangle) {}
+}
+
+/// The status of a [`Shell`] after processing its pending events.
+///
+/// [`Shell`]: trait.Shell.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Shell`] is running and the window keeps its size.
+    ///
+    /// [`Shell`]: trait.Shell.html
+    Running,
+
+    /// The window of the [`Shell`] has been resized.
+    ///
+    /// The rendering [`Target`] should be resized before drawing again.
+    ///
+    /// [`Shell`]: trait.Shell.html
+    /// [`Target`]: ../renderer/trait.Target.html
+    Resized,
+
+    /// The window of the [`Shell`] needs to be drawn again, even though its
+    /// size has not changed (for instance, after being uncovered).
+    ///
+    /// [`Shell`]: trait.Shell.html
+    Redraw,
+
+    /// The window of the [`Shell`] has been closed.
+    ///
+    /// [`Shell`]: trait.Shell.html
+    Closed,
+}
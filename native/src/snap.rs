@@ -0,0 +1,330 @@
+//! Align dragged rectangles with their surroundings.
+//!
+//! Widgets that let users drag things around, like panes or floating panels,
+//! can use [`snap`] to compute where the dragged item should land and which
+//! guides should be drawn on top of the user interface while dragging.
+//!
+//! The resulting [`Snap`] can be drawn as an [`overlay`], floating over the
+//! rest of the user interface. A [`Draggable`] does it for you when you
+//! give it some targets to snap to.
+//!
+//! [`snap`]: fn.snap.html
+//! [`Snap`]: struct.Snap.html
+//! [`overlay`]: struct.Snap.html#method.overlay
+//! [`Draggable`]: ../widget/draggable/struct.Draggable.html#method.snap
+use crate::{layout, overlay, Layout, Point, Rectangle, Size, Vector};
+
+/// The result of snapping a dragged rectangle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snap {
+    /// Where the dragged rectangle should land.
+    ///
+    /// It can be used to draw a preview rectangle.
+    pub bounds: Rectangle,
+
+    /// The guides showing the alignment of the snapped rectangle.
+    pub guides: Vec<Guide>,
+}
+
+impl Snap {
+    /// Turns the [`Snap`] into an overlay [`Element`], drawing a preview of
+    /// where the dragged rectangle will land and its guides above the rest
+    /// of the user interface.
+    ///
+    /// The overlay does not process any events.
+    ///
+    /// [`Snap`]: struct.Snap.html
+    /// [`Element`]: ../overlay/struct.Element.html
+    pub fn overlay<'a, Message, Renderer>(
+        self,
+    ) -> overlay::Element<'a, Message, Renderer>
+    where
+        Renderer: self::Renderer,
+    {
+        overlay::Element::new(
+            Point::new(self.bounds.x, self.bounds.y),
+            Box::new(Preview { snap: self }),
+        )
+    }
+
+    fn extent(&self, thickness: f32) -> Rectangle {
+        self.guides
+            .iter()
+            .map(|guide| guide.bounds(thickness))
+            .fold(self.bounds, |a, b| {
+                let x = a.x.min(b.x);
+                let y = a.y.min(b.y);
+
+                Rectangle {
+                    x,
+                    y,
+                    width: (a.x + a.width).max(b.x + b.width) - x,
+                    height: (a.y + a.height).max(b.y + b.height) - y,
+                }
+            })
+    }
+
+    fn translate(&self, translation: Vector) -> Snap {
+        Snap {
+            bounds: Rectangle {
+                x: self.bounds.x + translation.x,
+                y: self.bounds.y + translation.y,
+                ..self.bounds
+            },
+            guides: self
+                .guides
+                .iter()
+                .map(|guide| {
+                    let (position, start) = match guide.axis {
+                        Axis::Vertical => (translation.x, translation.y),
+                        Axis::Horizontal => (translation.y, translation.x),
+                    };
+
+                    Guide {
+                        position: guide.position + position,
+                        start: guide.start + start,
+                        end: guide.end + start,
+                        ..*guide
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A line showing that two rectangles are aligned.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Guide {
+    /// The orientation of the line.
+    pub axis: Axis,
+
+    /// The position of the line: its `x` coordinate for a vertical line or
+    /// its `y` coordinate for a horizontal one.
+    pub position: f32,
+
+    /// The coordinate where the line starts, along its axis.
+    pub start: f32,
+
+    /// The coordinate where the line ends, along its axis.
+    pub end: f32,
+}
+
+impl Guide {
+    /// Returns the bounds of the [`Guide`] when drawn with the given
+    /// thickness.
+    ///
+    /// [`Guide`]: struct.Guide.html
+    pub fn bounds(&self, thickness: f32) -> Rectangle {
+        match self.axis {
+            Axis::Vertical => Rectangle {
+                x: self.position - thickness / 2.0,
+                y: self.start,
+                width: thickness,
+                height: self.end - self.start,
+            },
+            Axis::Horizontal => Rectangle {
+                x: self.start,
+                y: self.position - thickness / 2.0,
+                width: self.end - self.start,
+                height: thickness,
+            },
+        }
+    }
+}
+
+/// The orientation of a [`Guide`].
+///
+/// [`Guide`]: struct.Guide.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    /// A horizontal line, aligning top edges, bottom edges, or centers.
+    Horizontal,
+
+    /// A vertical line, aligning left edges, right edges, or centers.
+    Vertical,
+}
+
+/// Snaps the `dragged` rectangle to the edges and centers of the `targets`
+/// that are closer than the given `distance`.
+///
+/// Each axis snaps independently, to the closest alignment.
+///
+/// # Example
+/// ```
+/// use iced_native::{snap, Rectangle};
+///
+/// let panel = Rectangle { x: 0.0, y: 0.0, width: 100.0, height: 100.0 };
+/// let dragged = Rectangle { x: 104.0, y: 37.0, width: 50.0, height: 50.0 };
+///
+/// let snap = snap::snap(dragged, &[panel], 8.0);
+///
+/// // The left edge of the dragged rectangle sticks to the right edge of
+/// // the panel
+/// assert_eq!(snap.bounds.x, 100.0);
+/// assert_eq!(snap.bounds.y, 37.0);
+/// assert_eq!(snap.guides.len(), 1);
+/// ```
+pub fn snap(dragged: Rectangle, targets: &[Rectangle], distance: f32) -> Snap {
+    let horizontal = |rectangle: &Rectangle| {
+        [
+            rectangle.x,
+            rectangle.x + rectangle.width / 2.0,
+            rectangle.x + rectangle.width,
+        ]
+    };
+
+    let vertical = |rectangle: &Rectangle| {
+        [
+            rectangle.y,
+            rectangle.y + rectangle.height / 2.0,
+            rectangle.y + rectangle.height,
+        ]
+    };
+
+    let delta_x = closest_delta(&dragged, targets, distance, horizontal);
+    let delta_y = closest_delta(&dragged, targets, distance, vertical);
+
+    let bounds = Rectangle {
+        x: dragged.x + delta_x.unwrap_or(0.0),
+        y: dragged.y + delta_y.unwrap_or(0.0),
+        ..dragged
+    };
+
+    let mut guides = Vec::new();
+
+    for target in targets {
+        if delta_x.is_some() {
+            for edge in aligned(&bounds, target, horizontal) {
+                guides.push(Guide {
+                    axis: Axis::Vertical,
+                    position: edge,
+                    start: bounds.y.min(target.y),
+                    end: (bounds.y + bounds.height)
+                        .max(target.y + target.height),
+                });
+            }
+        }
+
+        if delta_y.is_some() {
+            for edge in aligned(&bounds, target, vertical) {
+                guides.push(Guide {
+                    axis: Axis::Horizontal,
+                    position: edge,
+                    start: bounds.x.min(target.x),
+                    end: (bounds.x + bounds.width).max(target.x + target.width),
+                });
+            }
+        }
+    }
+
+    Snap { bounds, guides }
+}
+
+fn closest_delta(
+    dragged: &Rectangle,
+    targets: &[Rectangle],
+    distance: f32,
+    edges: impl Fn(&Rectangle) -> [f32; 3],
+) -> Option<f32> {
+    let dragged_edges = edges(dragged);
+
+    targets
+        .iter()
+        .flat_map(|target| edges(target).to_vec())
+        .flat_map(|target_edge| {
+            dragged_edges.iter().map(move |edge| target_edge - edge)
+        })
+        .filter(|delta| delta.abs() <= distance)
+        .fold(None, |closest: Option<f32>, delta| match closest {
+            Some(closest) if closest.abs() <= delta.abs() => Some(closest),
+            _ => Some(delta),
+        })
+}
+
+fn aligned(
+    bounds: &Rectangle,
+    target: &Rectangle,
+    edges: impl Fn(&Rectangle) -> [f32; 3],
+) -> Vec<f32> {
+    let target_edges = edges(target);
+
+    let mut aligned: Vec<f32> = edges(bounds)
+        .iter()
+        .copied()
+        .filter(|edge| {
+            target_edges
+                .iter()
+                .any(|target_edge| (target_edge - edge).abs() < 0.5)
+        })
+        .collect();
+
+    aligned.dedup();
+    aligned
+}
+
+struct Preview {
+    snap: Snap,
+}
+
+impl<Message, Renderer> overlay::Overlay<Message, Renderer> for Preview
+where
+    Renderer: self::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        _bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let extent = self.snap.extent(renderer.guide_thickness());
+
+        let mut node =
+            layout::Node::new(Size::new(extent.width, extent.height));
+
+        // The position may be translated from the bounds of the snap, like
+        // inside of a scrollable
+        node.move_to(Point::new(
+            extent.x + position.x - self.snap.bounds.x,
+            extent.y + position.y - self.snap.bounds.y,
+        ));
+
+        node
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> Renderer::Output {
+        let extent = self.snap.extent(renderer.guide_thickness());
+        let bounds = layout.bounds();
+
+        let snap = self
+            .snap
+            .translate(Vector::new(bounds.x - extent.x, bounds.y - extent.y));
+
+        renderer.draw_snap(&snap)
+    }
+}
+
+/// The renderer of a [`Snap`] overlay.
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to draw a [`Snap`] with [`Snap::overlay`].
+///
+/// [`Snap`]: struct.Snap.html
+/// [`Snap::overlay`]: struct.Snap.html#method.overlay
+/// [renderer]: ../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// Returns the thickness of the drawn guides of a [`Snap`].
+    ///
+    /// [`Snap`]: struct.Snap.html
+    fn guide_thickness(&self) -> f32;
+
+    /// Draws a [`Snap`]: a preview rectangle where the dragged rectangle
+    /// will land and its guides.
+    ///
+    /// [`Snap`]: struct.Snap.html
+    fn draw_snap(&mut self, snap: &Snap) -> Self::Output;
+}
@@ -1,5 +1,5 @@
 //! Listen to external events in your application.
-use crate::{Event, Hasher};
+use crate::{frame, memory, Event, Hasher};
 use futures::stream::BoxStream;
 
 /// A request to listen to external events.
@@ -26,17 +26,256 @@ pub type EventStream = BoxStream<'static, Event>;
 
 pub use iced_core::subscription::Recipe;
 
+mod animation_frames;
+mod debounce;
 mod events;
+mod fixed;
+mod frames;
+mod memory_pressure;
+mod samples;
+mod throttle;
+mod timer;
 
+use animation_frames::AnimationFrames;
+use debounce::Debounce;
 use events::Events;
+use fixed::Fixed;
+use frames::Frames;
+use memory_pressure::MemoryPressure;
+use samples::Samples;
+use throttle::Throttle;
+
+pub use samples::{Producer, Ring, Snapshot};
+
+use std::{hash::Hash, time::Duration};
 
 /// Returns a [`Subscription`] to all the runtime events.
 ///
 /// This subscription will notify your application of any [`Event`] handled by
-/// the runtime.
+/// the runtime, except for [`Event::Frame`].
 ///
 /// [`Subscription`]: type.Subscription.html
 /// [`Event`]: ../enum.Event.html
+/// [`Event::Frame`]: ../enum.Event.html#variant.Frame
 pub fn events() -> Subscription<Event> {
     Subscription::from_recipe(Events)
 }
+
+/// Returns a [`Subscription`] that produces frame pacing [`Stats`] of the
+/// window, summarizing the frames presented during each `interval`.
+///
+/// A summary is produced with the first frame presented after the `interval`
+/// has elapsed. Therefore, an idle window does not produce any summaries.
+///
+/// ```
+/// use iced_native::{frame, subscription::{self, Subscription}};
+/// use std::time::Duration;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     FramesPresented(frame::Stats),
+/// }
+///
+/// fn subscription() -> Subscription<Message> {
+///     subscription::frames(Duration::from_secs(5))
+///         .map(Message::FramesPresented)
+/// }
+/// ```
+///
+/// [`Subscription`]: type.Subscription.html
+/// [`Stats`]: ../frame/struct.Stats.html
+pub fn frames(interval: Duration) -> Subscription<frame::Stats> {
+    Subscription::from_recipe(Frames::new(interval))
+}
+
+/// Returns a [`Subscription`] that produces the time elapsed since the
+/// previous frame, every time the window presents a frame.
+///
+/// Use it to advance animations by the real time that passed, so they keep
+/// their speed when frames are dropped. The first frame produces a zero
+/// duration.
+///
+/// Frames are only presented while something changes on the screen, so an
+/// animation that stops changing the view stops receiving frames too. Use
+/// [`fixed`] for simulations that must keep running regardless.
+///
+/// ```
+/// use iced_native::subscription::{self, Subscription};
+/// use std::time::Duration;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Animate(Duration),
+/// }
+///
+/// fn subscription(is_animating: bool) -> Subscription<Message> {
+///     if is_animating {
+///         subscription::animation_frames().map(Message::Animate)
+///     } else {
+///         Subscription::none()
+///     }
+/// }
+/// ```
+///
+/// [`Subscription`]: type.Subscription.html
+/// [`fixed`]: fn.fixed.html
+pub fn animation_frames() -> Subscription<Duration> {
+    Subscription::from_recipe(AnimationFrames)
+}
+
+/// Returns a [`Subscription`] that produces a [`Pressure`] every time the
+/// renderer runs low on device memory.
+///
+/// ```
+/// use iced_native::{memory, subscription::{self, Subscription}};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     MemoryPressure(memory::Pressure),
+/// }
+///
+/// fn subscription() -> Subscription<Message> {
+///     subscription::memory_pressure().map(Message::MemoryPressure)
+/// }
+/// ```
+///
+/// [`Subscription`]: type.Subscription.html
+/// [`Pressure`]: ../memory/struct.Pressure.html
+pub fn memory_pressure() -> Subscription<memory::Pressure> {
+    Subscription::from_recipe(MemoryPressure)
+}
+
+/// Returns a [`Subscription`] that drives a simulation with a fixed
+/// timestep.
+///
+/// It produces the amount of steps of the given duration that have to be
+/// simulated, at most once per `step`. When your application falls behind,
+/// the missed steps are produced together, so the simulation catches up
+/// without ever losing time. Consider capping the steps you simulate at once
+/// if a single step can take longer than its duration.
+///
+/// ```
+/// use iced_native::subscription::{self, Subscription};
+/// use std::time::Duration;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Step(u32),
+/// }
+///
+/// fn subscription() -> Subscription<Message> {
+///     // 60 steps per second
+///     subscription::fixed(Duration::from_micros(16_667)).map(Message::Step)
+/// }
+/// ```
+///
+/// [`Subscription`]: type.Subscription.html
+pub fn fixed(step: Duration) -> Subscription<u32> {
+    Subscription::from_recipe(Fixed::new(step))
+}
+
+/// Returns a [`Subscription`] that produces a [`Snapshot`] of the samples
+/// pushed to the given [`Ring`] by its [`Producer`] at most once per
+/// `interval`.
+///
+/// Nothing is produced while no new samples arrive. If your application falls
+/// behind, the pending samples are coalesced into a single [`Snapshot`]
+/// instead of queueing up. This makes it a good fit to visualize data
+/// produced by an audio or sensor thread:
+///
+/// ```
+/// use iced_native::subscription::{self, Ring, Snapshot, Subscription};
+/// use std::time::Duration;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Samples(Snapshot),
+/// }
+///
+/// struct Visualizer {
+///     ring: Ring,
+/// }
+///
+/// impl Visualizer {
+///     fn new() -> Self {
+///         let (mut producer, ring) = Ring::new(48_000);
+///
+///         let _ = std::thread::spawn(move || {
+///             // Push samples from your audio callback
+///             producer.extend(&[0.0, 0.5, 1.0]);
+///         });
+///
+///         Visualizer { ring }
+///     }
+///
+///     fn subscription(&self) -> Subscription<Message> {
+///         subscription::samples(&self.ring, Duration::from_millis(16))
+///             .map(Message::Samples)
+///     }
+/// }
+/// ```
+///
+/// [`Subscription`]: type.Subscription.html
+/// [`Snapshot`]: struct.Snapshot.html
+/// [`Ring`]: struct.Ring.html
+/// [`Producer`]: struct.Producer.html
+pub fn samples(ring: &Ring, interval: Duration) -> Subscription<Snapshot> {
+    Subscription::from_recipe(Samples::new(ring.clone(), interval))
+}
+
+/// Returns a [`Subscription`] that produces the given message once the
+/// provided duration has elapsed.
+///
+/// The `key` identifies the [`Subscription`]. Whenever the `key` changes, the
+/// pending message of the previous `key` is cancelled and the countdown starts
+/// again. Therefore, you can use this to react to the last of a burst of
+/// changes. For instance, you can fire a search query 300ms after the last
+/// keystroke by using the current query as the `key`:
+///
+/// ```
+/// use iced_native::subscription::{self, Subscription};
+/// use std::time::Duration;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Search(String),
+/// }
+///
+/// fn subscription(query: &str) -> Subscription<Message> {
+///     subscription::debounce(
+///         query.to_string(),
+///         Duration::from_millis(300),
+///         Message::Search(query.to_string()),
+///     )
+/// }
+/// ```
+///
+/// [`Subscription`]: type.Subscription.html
+pub fn debounce<K, T>(key: K, duration: Duration, message: T) -> Subscription<T>
+where
+    K: 'static + Hash,
+    T: 'static + Send,
+{
+    Subscription::from_recipe(Debounce::new(key, duration, message))
+}
+
+/// Throttles the given [`Subscription`], producing at most one output per
+/// `interval`.
+///
+/// Any output produced before the `interval` has elapsed since the last
+/// emitted one is held back, replacing the previous one held back, if any.
+/// The held output is produced once the `interval` elapses. Therefore, the
+/// last output of a burst is never lost.
+///
+/// [`Subscription`]: type.Subscription.html
+pub fn throttle<T>(
+    subscription: Subscription<T>,
+    interval: Duration,
+) -> Subscription<T>
+where
+    T: 'static + Send,
+{
+    Subscription::batch(subscription.recipes().into_iter().map(|recipe| {
+        Subscription::from_recipe(Throttle::new(recipe, interval))
+    }))
+}
@@ -0,0 +1,34 @@
+use crate::{
+    subscription::{EventStream, Recipe},
+    Event, Hasher,
+};
+use futures::stream::StreamExt;
+use std::{hash::Hash, time::Duration};
+
+pub struct AnimationFrames;
+
+impl Recipe<Hasher, EventStream> for AnimationFrames {
+    type Output = Duration;
+
+    fn hash(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Self>().hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        input: EventStream,
+    ) -> futures::stream::BoxStream<'static, Self::Output> {
+        input
+            .filter_map(|event| {
+                let delta = match event {
+                    Event::Frame(frame) => {
+                        Some(frame.interval.unwrap_or_default())
+                    }
+                    _ => None,
+                };
+
+                futures::future::ready(delta)
+            })
+            .boxed()
+    }
+}
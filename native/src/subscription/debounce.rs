@@ -0,0 +1,49 @@
+use crate::{
+    subscription::{timer, EventStream, Recipe},
+    Hasher,
+};
+use futures::{future::FutureExt, stream::StreamExt};
+use std::{hash::Hash, time::Duration};
+
+pub struct Debounce<K, T> {
+    key: K,
+    duration: Duration,
+    message: T,
+}
+
+impl<K, T> Debounce<K, T> {
+    pub fn new(key: K, duration: Duration, message: T) -> Self {
+        Debounce {
+            key,
+            duration,
+            message,
+        }
+    }
+}
+
+impl<K, T> Recipe<Hasher, EventStream> for Debounce<K, T>
+where
+    K: 'static + Hash,
+    T: 'static + Send,
+{
+    type Output = T;
+
+    fn hash(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Self>().hash(state);
+
+        self.key.hash(state);
+        self.duration.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        _input: EventStream,
+    ) -> futures::stream::BoxStream<'static, Self::Output> {
+        let message = self.message;
+
+        timer::delay(self.duration)
+            .map(move |_| message)
+            .into_stream()
+            .boxed()
+    }
+}
@@ -2,6 +2,7 @@ use crate::{
     subscription::{EventStream, Recipe},
     Event, Hasher,
 };
+use futures::stream::StreamExt;
 
 pub struct Events;
 
@@ -18,6 +19,11 @@ impl Recipe<Hasher, EventStream> for Events {
         self: Box<Self>,
         event_stream: EventStream,
     ) -> futures::stream::BoxStream<'static, Self::Output> {
+        // Reacting to frame events would present new frames forever
         event_stream
+            .filter(|event| {
+                futures::future::ready(!matches!(event, Event::Frame(_)))
+            })
+            .boxed()
     }
 }
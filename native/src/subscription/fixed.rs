@@ -0,0 +1,58 @@
+use crate::{
+    subscription::{timer, EventStream, Recipe},
+    Hasher,
+};
+use futures::stream::StreamExt;
+use std::{
+    hash::Hash,
+    time::{Duration, Instant},
+};
+
+pub struct Fixed {
+    step: Duration,
+}
+
+impl Fixed {
+    pub fn new(step: Duration) -> Self {
+        Fixed {
+            step: step.max(Duration::from_millis(1)),
+        }
+    }
+}
+
+impl Recipe<Hasher, EventStream> for Fixed {
+    type Output = u32;
+
+    fn hash(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Self>().hash(state);
+
+        self.step.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        _input: EventStream,
+    ) -> futures::stream::BoxStream<'static, Self::Output> {
+        let step = self.step;
+
+        // Steps are counted from the start instead of from every tick, so
+        // late and coalesced ticks never lose any time
+        let start = Instant::now();
+        let mut consumed: u128 = 0;
+
+        timer::interval(step)
+            .filter_map(move |_| {
+                let elapsed = start.elapsed().as_nanos() / step.as_nanos();
+                let steps = elapsed - consumed;
+
+                consumed = elapsed;
+
+                futures::future::ready(if steps > 0 {
+                    Some(steps.min(u128::from(u32::MAX)) as u32)
+                } else {
+                    None
+                })
+            })
+            .boxed()
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{
+    frame,
+    subscription::{EventStream, Recipe},
+    Event, Hasher,
+};
+use futures::stream::StreamExt;
+use std::{
+    hash::Hash,
+    time::{Duration, Instant},
+};
+
+pub struct Frames {
+    interval: Duration,
+}
+
+impl Frames {
+    pub fn new(interval: Duration) -> Self {
+        Frames { interval }
+    }
+}
+
+impl Recipe<Hasher, EventStream> for Frames {
+    type Output = frame::Stats;
+
+    fn hash(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Self>().hash(state);
+
+        self.interval.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        input: EventStream,
+    ) -> futures::stream::BoxStream<'static, Self::Output> {
+        let interval = self.interval;
+        let mut stats = frame::Stats::default();
+        let mut last_report = Instant::now();
+
+        input
+            .filter_map(move |event| {
+                let report = match event {
+                    Event::Frame(frame) => {
+                        stats.record(frame);
+
+                        let now = Instant::now();
+
+                        if now.duration_since(last_report) >= interval {
+                            last_report = now;
+
+                            Some(std::mem::take(&mut stats))
+                        } else {
+                            None
+                        }
+                    }
+                    _ => None,
+                };
+
+                futures::future::ready(report)
+            })
+            .boxed()
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{
+    memory,
+    subscription::{EventStream, Recipe},
+    Event, Hasher,
+};
+use futures::stream::StreamExt;
+use std::hash::Hash;
+
+pub struct MemoryPressure;
+
+impl Recipe<Hasher, EventStream> for MemoryPressure {
+    type Output = memory::Pressure;
+
+    fn hash(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Self>().hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        input: EventStream,
+    ) -> futures::stream::BoxStream<'static, Self::Output> {
+        input
+            .filter_map(|event| {
+                let pressure = match event {
+                    Event::MemoryPressure(pressure) => Some(pressure),
+                    _ => None,
+                };
+
+                futures::future::ready(pressure)
+            })
+            .boxed()
+    }
+}
@@ -0,0 +1,214 @@
+use crate::{
+    subscription::{timer, EventStream, Recipe},
+    Hasher,
+};
+use futures::stream::StreamExt;
+use std::{
+    hash::Hash,
+    sync::{
+        atomic::{self, AtomicU32, AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+/// A lock-free ring buffer of samples, filled by a [`Producer`] and read by
+/// a [`Subscription`].
+///
+/// When the [`Producer`] outpaces the readers, the oldest samples are
+/// overwritten.
+///
+/// Cloning a [`Ring`] produces another reader of the same buffer. Every
+/// [`Subscription`] reading it keeps track of its own position.
+///
+/// [`Producer`]: struct.Producer.html
+/// [`Subscription`]: type.Subscription.html
+/// [`Ring`]: struct.Ring.html
+#[derive(Debug, Clone)]
+pub struct Ring {
+    inner: Arc<Inner>,
+}
+
+/// The only writer of a [`Ring`].
+///
+/// Pushing samples never blocks nor allocates, so it is safe to do it from
+/// a real-time thread, like an audio callback.
+///
+/// [`Ring`]: struct.Ring.html
+#[derive(Debug)]
+pub struct Producer {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    samples: Box<[AtomicU32]>,
+    written: AtomicU64,
+    reserved: AtomicU64,
+}
+
+impl Ring {
+    /// Creates a new [`Ring`] that holds up to `capacity` samples, along with
+    /// its [`Producer`].
+    ///
+    /// [`Ring`]: struct.Ring.html
+    /// [`Producer`]: struct.Producer.html
+    pub fn new(capacity: usize) -> (Producer, Self) {
+        let inner = Arc::new(Inner {
+            samples: (0..capacity.max(1)).map(|_| AtomicU32::new(0)).collect(),
+            written: AtomicU64::new(0),
+            reserved: AtomicU64::new(0),
+        });
+
+        (
+            Producer {
+                inner: inner.clone(),
+            },
+            Ring { inner },
+        )
+    }
+
+    /// Returns the amount of samples the [`Ring`] can hold.
+    ///
+    /// [`Ring`]: struct.Ring.html
+    pub fn capacity(&self) -> usize {
+        self.inner.samples.len()
+    }
+
+    fn read(&self, position: &mut u64) -> Option<Snapshot> {
+        let capacity = self.inner.samples.len() as u64;
+        let written = self.inner.written.load(Ordering::Acquire);
+
+        if written == *position {
+            return None;
+        }
+
+        let start = written.saturating_sub(capacity).max(*position);
+
+        let mut samples: Vec<f32> = (start..written)
+            .map(|i| {
+                f32::from_bits(
+                    self.inner.samples[(i % capacity) as usize]
+                        .load(Ordering::Relaxed),
+                )
+            })
+            .collect();
+
+        // The producer may have overwritten some of the samples while we were
+        // copying them, even if it did not publish the new ones yet. If we
+        // read any overwritten sample, this fence makes sure we see the
+        // reservation made before overwriting it.
+        atomic::fence(Ordering::Acquire);
+
+        let overwritten = self
+            .inner
+            .reserved
+            .load(Ordering::Relaxed)
+            .saturating_sub(capacity)
+            .saturating_sub(start)
+            .min(samples.len() as u64);
+
+        let _ = samples.drain(..overwritten as usize);
+
+        let dropped = (start + overwritten - *position) as usize;
+
+        *position = written;
+
+        Some(Snapshot { samples, dropped })
+    }
+}
+
+impl Producer {
+    /// Returns the amount of samples the [`Ring`] of the [`Producer`] can
+    /// hold.
+    ///
+    /// [`Ring`]: struct.Ring.html
+    /// [`Producer`]: struct.Producer.html
+    pub fn capacity(&self) -> usize {
+        self.inner.samples.len()
+    }
+
+    /// Adds a sample to the [`Ring`], overwriting the oldest one if full.
+    ///
+    /// [`Ring`]: struct.Ring.html
+    pub fn push(&mut self, sample: f32) {
+        self.extend(&[sample]);
+    }
+
+    /// Adds some samples to the [`Ring`], overwriting the oldest ones if
+    /// full.
+    ///
+    /// [`Ring`]: struct.Ring.html
+    pub fn extend(&mut self, samples: &[f32]) {
+        let capacity = self.inner.samples.len() as u64;
+        let written = self.inner.written.load(Ordering::Relaxed);
+        let reserved = written + samples.len() as u64;
+
+        // Tells the readers which samples are about to be overwritten, before
+        // overwriting any of them
+        self.inner.reserved.store(reserved, Ordering::Relaxed);
+        atomic::fence(Ordering::Release);
+
+        for (i, sample) in samples.iter().enumerate() {
+            let index = (written + i as u64) % capacity;
+
+            self.inner.samples[index as usize]
+                .store(sample.to_bits(), Ordering::Relaxed);
+        }
+
+        // Publishes the samples to the readers
+        self.inner.written.store(reserved, Ordering::Release);
+    }
+}
+
+/// The samples pushed to a [`Ring`] since the last [`Snapshot`].
+///
+/// [`Ring`]: struct.Ring.html
+/// [`Snapshot`]: struct.Snapshot.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot {
+    /// The new samples, from oldest to newest.
+    pub samples: Vec<f32>,
+
+    /// The amount of samples that were overwritten before they could be
+    /// read.
+    pub dropped: usize,
+}
+
+pub struct Samples {
+    ring: Ring,
+    interval: Duration,
+}
+
+impl Samples {
+    pub fn new(ring: Ring, interval: Duration) -> Self {
+        Samples { ring, interval }
+    }
+}
+
+impl Recipe<Hasher, EventStream> for Samples {
+    type Output = Snapshot;
+
+    fn hash(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Self>().hash(state);
+
+        (Arc::as_ptr(&self.ring.inner) as usize).hash(state);
+        self.interval.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        _input: EventStream,
+    ) -> futures::stream::BoxStream<'static, Self::Output> {
+        let Samples { ring, interval } = *self;
+
+        // Samples pushed before subscribing are skipped
+        let mut position = ring.inner.written.load(Ordering::Acquire);
+
+        timer::interval(interval)
+            .filter_map(move |_| {
+                futures::future::ready(ring.read(&mut position))
+            })
+            .boxed()
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{
+    subscription::{timer, EventStream, Recipe},
+    Hasher,
+};
+use futures::{
+    future::Future,
+    stream::{BoxStream, Stream, StreamExt},
+    task::{Context, Poll},
+};
+use std::{
+    hash::Hash,
+    pin::Pin,
+    time::{Duration, Instant},
+};
+
+pub struct Throttle<T> {
+    recipe: Box<dyn Recipe<Hasher, EventStream, Output = T>>,
+    interval: Duration,
+}
+
+impl<T> Throttle<T> {
+    pub fn new(
+        recipe: Box<dyn Recipe<Hasher, EventStream, Output = T>>,
+        interval: Duration,
+    ) -> Self {
+        Throttle { recipe, interval }
+    }
+}
+
+impl<T> Recipe<Hasher, EventStream> for Throttle<T>
+where
+    T: 'static + Send,
+{
+    type Output = T;
+
+    fn hash(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Throttle<()>>().hash(state);
+
+        self.interval.hash(state);
+        self.recipe.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        input: EventStream,
+    ) -> futures::stream::BoxStream<'static, Self::Output> {
+        Throttled {
+            outputs: Some(self.recipe.stream(input)),
+            interval: self.interval,
+            last_output: None,
+            pending: None,
+            timer: None,
+        }
+        .boxed()
+    }
+}
+
+struct Throttled<T> {
+    outputs: Option<BoxStream<'static, T>>,
+    interval: Duration,
+    last_output: Option<Instant>,
+    pending: Option<T>,
+    timer: Option<timer::Delay>,
+}
+
+// The outputs are never pinned
+impl<T> Unpin for Throttled<T> {}
+
+impl<T> Stream for Throttled<T> {
+    type Item = T;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        context: &mut Context<'_>,
+    ) -> Poll<Option<T>> {
+        let throttled = self.get_mut();
+
+        while let Some(outputs) = &mut throttled.outputs {
+            match outputs.poll_next_unpin(context) {
+                Poll::Ready(Some(output)) => {
+                    let now = Instant::now();
+
+                    let next_output = throttled
+                        .last_output
+                        .map(|last| last + throttled.interval)
+                        .unwrap_or(now);
+
+                    if next_output <= now {
+                        throttled.last_output = Some(now);
+                        throttled.pending = None;
+                        throttled.timer = None;
+
+                        return Poll::Ready(Some(output));
+                    }
+
+                    // The latest output of a burst is produced once the
+                    // interval elapses, so it is never lost
+                    throttled.pending = Some(output);
+
+                    if throttled.timer.is_none() {
+                        throttled.timer = Some(timer::delay(next_output - now));
+                    }
+                }
+                Poll::Ready(None) => throttled.outputs = None,
+                Poll::Pending => break,
+            }
+        }
+
+        if let Some(timer) = &mut throttled.timer {
+            if Pin::new(timer).poll(context).is_ready() {
+                throttled.timer = None;
+
+                if let Some(output) = throttled.pending.take() {
+                    throttled.last_output = Some(Instant::now());
+
+                    return Poll::Ready(Some(output));
+                }
+            }
+        }
+
+        if throttled.outputs.is_none() && throttled.pending.is_none() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! A single thread waking up the timers of every subscription.
+use futures::{
+    future::Future,
+    stream::Stream,
+    task::{Context, Poll, Waker},
+};
+use std::{
+    pin::Pin,
+    sync::{Arc, Condvar, Mutex, Weak},
+    time::{Duration, Instant},
+};
+
+static TIMERS: Mutex<Timers> = Mutex::new(Timers {
+    entries: Vec::new(),
+    is_running: false,
+});
+
+static WAKE: Condvar = Condvar::new();
+
+struct Timers {
+    entries: Vec<Entry>,
+    is_running: bool,
+}
+
+struct Entry {
+    deadline: Instant,
+    slot: Weak<Mutex<Slot>>,
+}
+
+#[derive(Default)]
+struct Slot {
+    is_elapsed: bool,
+    waker: Option<Waker>,
+}
+
+/// A future that resolves once a duration has elapsed.
+///
+/// Dropping it cancels the timer.
+pub struct Delay {
+    slot: Arc<Mutex<Slot>>,
+}
+
+/// Returns a [`Delay`] resolving once the given duration has elapsed.
+///
+/// [`Delay`]: struct.Delay.html
+pub fn delay(duration: Duration) -> Delay {
+    delay_until(Instant::now() + duration)
+}
+
+/// Returns an [`Interval`] ticking once every `period`.
+///
+/// [`Interval`]: struct.Interval.html
+pub fn interval(period: Duration) -> Interval {
+    let deadline = Instant::now() + period;
+
+    Interval {
+        period,
+        deadline,
+        delay: delay_until(deadline),
+    }
+}
+
+fn delay_until(deadline: Instant) -> Delay {
+    let slot = Arc::new(Mutex::new(Slot::default()));
+    let mut timers = TIMERS.lock().expect("Lock timers");
+
+    if !timers.is_running {
+        timers.is_running = true;
+
+        let _ = std::thread::spawn(run);
+    }
+
+    timers.entries.push(Entry {
+        deadline,
+        slot: Arc::downgrade(&slot),
+    });
+
+    WAKE.notify_one();
+
+    Delay { slot }
+}
+
+impl Future for Delay {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<()> {
+        let mut slot = self.slot.lock().expect("Lock timer");
+
+        if slot.is_elapsed {
+            Poll::Ready(())
+        } else {
+            slot.waker = Some(context.waker().clone());
+
+            Poll::Pending
+        }
+    }
+}
+
+/// A stream ticking periodically.
+///
+/// Ticks that are not consumed in time are coalesced, instead of queueing up.
+pub struct Interval {
+    period: Duration,
+    deadline: Instant,
+    delay: Delay,
+}
+
+impl Stream for Interval {
+    type Item = ();
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        context: &mut Context<'_>,
+    ) -> Poll<Option<()>> {
+        let interval = self.get_mut();
+
+        match Pin::new(&mut interval.delay).poll(context) {
+            Poll::Ready(()) => {
+                let now = Instant::now();
+
+                interval.deadline += interval.period;
+
+                if interval.deadline <= now {
+                    interval.deadline = now + interval.period;
+                }
+
+                interval.delay = delay_until(interval.deadline);
+
+                Poll::Ready(Some(()))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+fn run() {
+    let mut timers = TIMERS.lock().expect("Lock timers");
+    let mut elapsed = Vec::new();
+
+    loop {
+        let now = Instant::now();
+
+        // Dropped delays are cancelled, so they are discarded here
+        timers.entries.retain(|entry| match entry.slot.upgrade() {
+            Some(slot) if entry.deadline <= now => {
+                elapsed.push(slot);
+                false
+            }
+            Some(_) => true,
+            None => false,
+        });
+
+        for slot in elapsed.drain(..) {
+            let waker = {
+                let mut slot = slot.lock().expect("Lock timer");
+                slot.is_elapsed = true;
+
+                slot.waker.take()
+            };
+
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        }
+
+        let next = timers.entries.iter().map(|entry| entry.deadline).min();
+
+        timers = match next {
+            Some(deadline) => {
+                WAKE.wait_timeout(timers, deadline - now)
+                    .expect("Wait for timers")
+                    .0
+            }
+            None => WAKE.wait(timers).expect("Wait for timers"),
+        };
+    }
+}
@@ -0,0 +1,394 @@
+//! Keep the state of widgets between views.
+//!
+//! Stateful widgets, like a [`Button`] or a [`TextInput`], can either borrow
+//! their state from your application or let the runtime manage it. Managed
+//! state lives in a [`Tree`] that mirrors your widget tree. Every time a
+//! [`UserInterface`] is built, the new widgets are reconciled against the
+//! [`Tree`] of the previous one:
+//!
+//! - children are matched by key, when they have one, and by position
+//!   otherwise (see [`Column::push_keyed`]),
+//! - state is kept as long as the widget in the same place has the same type
+//!   of state, and reset otherwise.
+//!
+//! This way, your `view` logic does not need to thread widget state through
+//! your application, and state follows its logical item when a list is
+//! reordered or filtered.
+//!
+//! # Example
+//! ```
+//! # use iced_native::{renderer::Null, Button, Text};
+//! #
+//! # type Column<'a, Message> = iced_native::Column<'a, Message, Null>;
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Remove(u32),
+//! }
+//!
+//! struct Tasks {
+//!     tasks: Vec<(u32, String)>,
+//! }
+//!
+//! impl Tasks {
+//!     fn view(&self) -> Column<'_, Message> {
+//!         self.tasks.iter().fold(Column::new(), |column, (id, task)| {
+//!             column.push_keyed(
+//!                 id,
+//!                 Button::managed(Text::new(task.as_str()))
+//!                     .on_press(Message::Remove(*id)),
+//!             )
+//!         })
+//!     }
+//! }
+//! ```
+//!
+//! [`Button`]: ../widget/button/struct.Button.html
+//! [`TextInput`]: ../widget/text_input/struct.TextInput.html
+//! [`Tree`]: struct.Tree.html
+//! [`UserInterface`]: ../struct.UserInterface.html
+//! [`Column::push_keyed`]: ../widget/column/struct.Column.html#method.push_keyed
+use std::{
+    any::{Any, TypeId},
+    cell::{self, RefCell},
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+    rc::Rc,
+};
+
+/// The state of a widget and its children, kept between views.
+///
+/// [`Widget::diff`] implementations use a [`Tree`] to obtain their managed
+/// [`State`] and to reconcile their children.
+///
+/// [`Widget::diff`]: ../widget/trait.Widget.html#method.diff
+/// [`Tree`]: struct.Tree.html
+/// [`State`]: enum.State.html
+#[derive(Clone)]
+pub struct Tree {
+    tag: TypeId,
+    state: Option<Rc<dyn Any>>,
+    key: Option<u64>,
+    children: Vec<Tree>,
+}
+
+impl Tree {
+    /// Creates an empty [`Tree`].
+    ///
+    /// [`Tree`]: struct.Tree.html
+    pub fn empty() -> Self {
+        Tree {
+            tag: TypeId::of::<()>(),
+            state: None,
+            key: None,
+            children: Vec::new(),
+        }
+    }
+
+    /// Returns the state of type `T` stored in the [`Tree`].
+    ///
+    /// If the [`Tree`] holds no state or state of a different type, it is
+    /// replaced by the default value of `T`.
+    ///
+    /// [`Tree`]: struct.Tree.html
+    pub fn state<T: Default + 'static>(&mut self) -> Rc<RefCell<T>> {
+        let state = match self.state.take() {
+            Some(state) if self.tag == TypeId::of::<T>() => state
+                .downcast::<RefCell<T>>()
+                .unwrap_or_else(|_| Rc::new(RefCell::new(T::default()))),
+            _ => Rc::new(RefCell::new(T::default())),
+        };
+
+        self.tag = TypeId::of::<T>();
+        self.state = Some(state.clone());
+
+        state
+    }
+
+    /// Reconciles the children of the [`Tree`] with a new list of children,
+    /// identified by the given keys.
+    ///
+    /// Keyed children are matched with the old child that had the same key.
+    /// The rest are matched by position with the old children that had no
+    /// key. Unmatched old children are dropped.
+    ///
+    /// It returns the children of the [`Tree`] in the order of the keys.
+    ///
+    /// [`Tree`]: struct.Tree.html
+    pub fn diff_children(&mut self, keys: &[Option<u64>]) -> &mut [Tree] {
+        let mut keyed = HashMap::new();
+        let mut unkeyed = Vec::new();
+
+        for child in self.children.drain(..) {
+            match child.key {
+                Some(key) => {
+                    let _ = keyed.insert(key, child);
+                }
+                None => unkeyed.push(child),
+            }
+        }
+
+        let mut unkeyed = unkeyed.into_iter();
+
+        self.children = keys
+            .iter()
+            .map(|key| {
+                let child = match key {
+                    Some(key) => keyed.remove(key),
+                    None => unkeyed.next(),
+                };
+
+                Tree {
+                    key: *key,
+                    ..child.unwrap_or_else(Tree::empty)
+                }
+            })
+            .collect();
+
+        &mut self.children
+    }
+
+    /// Returns the descendant of the [`Tree`] at the given path of child
+    /// indices.
+    ///
+    /// [`Tree`]: struct.Tree.html
+    #[cfg(feature = "devtools")]
+    pub(crate) fn find(&self, path: &[usize]) -> Option<&Tree> {
+        path.iter()
+            .try_fold(self, |tree, index| tree.children.get(*index))
+    }
+
+    #[cfg(feature = "devtools")]
+    pub(crate) fn find_mut(&mut self, path: &[usize]) -> Option<&mut Tree> {
+        path.iter()
+            .try_fold(self, |tree, index| tree.children.get_mut(*index))
+    }
+
+    /// Replaces the state and children of the [`Tree`] with the ones of
+    /// another, keeping its key so it is still matched in the same place.
+    ///
+    /// [`Tree`]: struct.Tree.html
+    #[cfg(feature = "devtools")]
+    pub(crate) fn graft(&mut self, other: &Tree) {
+        *self = Tree {
+            key: self.key,
+            ..other.clone()
+        };
+    }
+}
+
+impl Default for Tree {
+    fn default() -> Self {
+        Tree::empty()
+    }
+}
+
+impl std::fmt::Debug for Tree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Tree")
+            .field("key", &self.key)
+            .field("children", &self.children)
+            .finish()
+    }
+}
+
+/// The state of a stateful widget, either borrowed from the application or
+/// managed by the runtime.
+///
+/// [`Managed`] state starts with its default value and is replaced by the
+/// one stored in the [`Tree`] once the widget is diffed.
+///
+/// [`Managed`]: #variant.Managed
+/// [`Tree`]: struct.Tree.html
+#[derive(Debug)]
+pub enum State<'a, T> {
+    /// State borrowed from the application.
+    Borrowed(&'a mut T),
+
+    /// State managed by the runtime.
+    Managed(Rc<RefCell<T>>),
+
+    /// State shared with other widgets through a [`Link`].
+    ///
+    /// [`Link`]: struct.Link.html
+    Linked(Link<T>),
+}
+
+impl<'a, T> State<'a, T>
+where
+    T: Default + 'static,
+{
+    /// Creates some new [`Managed`] state.
+    ///
+    /// [`Managed`]: #variant.Managed
+    pub fn managed() -> Self {
+        State::Managed(Rc::new(RefCell::new(T::default())))
+    }
+
+    /// Replaces [`Managed`] state with the one stored in the given [`Tree`].
+    ///
+    /// [`Borrowed`] and [`Linked`] state is left untouched.
+    ///
+    /// [`Managed`]: #variant.Managed
+    /// [`Borrowed`]: #variant.Borrowed
+    /// [`Linked`]: #variant.Linked
+    /// [`Tree`]: struct.Tree.html
+    pub fn diff(&mut self, tree: &mut Tree) {
+        if let State::Managed(state) = self {
+            *state = tree.state();
+        }
+    }
+}
+
+impl<'a, T> State<'a, T> {
+    /// Immutably borrows the state.
+    ///
+    /// # Panics
+    /// Panics if [`Managed`] or [`Linked`] state is currently mutably
+    /// borrowed.
+    ///
+    /// [`Managed`]: #variant.Managed
+    /// [`Linked`]: #variant.Linked
+    pub fn borrow(&self) -> Ref<'_, T> {
+        match self {
+            State::Borrowed(state) => Ref::Borrowed(state),
+            State::Managed(state) => Ref::Managed(state.borrow()),
+            State::Linked(link) => Ref::Managed(link.borrow()),
+        }
+    }
+
+    /// Mutably borrows the state.
+    ///
+    /// # Panics
+    /// Panics if [`Managed`] or [`Linked`] state is currently borrowed.
+    ///
+    /// [`Managed`]: #variant.Managed
+    /// [`Linked`]: #variant.Linked
+    pub fn borrow_mut(&mut self) -> RefMut<'_, T> {
+        match self {
+            State::Borrowed(state) => RefMut::Borrowed(state),
+            State::Managed(state) => RefMut::Managed(state.borrow_mut()),
+            State::Linked(link) => RefMut::Managed(link.borrow_mut()),
+        }
+    }
+}
+
+impl<'a, T> From<&'a mut T> for State<'a, T> {
+    fn from(state: &'a mut T) -> Self {
+        State::Borrowed(state)
+    }
+}
+
+impl<'a, T> From<&Link<T>> for State<'a, T> {
+    fn from(link: &Link<T>) -> Self {
+        State::Linked(link.clone())
+    }
+}
+
+/// Some state shared between different widgets and owned by your
+/// application.
+///
+/// A [`Link`] lets a widget read and change the state of another one in the
+/// same view, like a [`MiniMap`] controlling a [`Scrollable`]. Cloning a
+/// [`Link`] produces another handle to the same state.
+///
+/// [`Link`]: struct.Link.html
+/// [`MiniMap`]: ../widget/minimap/struct.MiniMap.html
+/// [`Scrollable`]: ../widget/scrollable/struct.Scrollable.html
+#[derive(Debug, Default)]
+pub struct Link<T>(Rc<RefCell<T>>);
+
+impl<T> Link<T> {
+    /// Creates a new [`Link`] to the given state.
+    ///
+    /// [`Link`]: struct.Link.html
+    pub fn new(state: T) -> Self {
+        Link(Rc::new(RefCell::new(state)))
+    }
+
+    /// Immutably borrows the linked state.
+    ///
+    /// # Panics
+    /// Panics if the state is currently mutably borrowed.
+    pub fn borrow(&self) -> cell::Ref<'_, T> {
+        self.0.borrow()
+    }
+
+    /// Mutably borrows the linked state.
+    ///
+    /// # Panics
+    /// Panics if the state is currently borrowed.
+    pub fn borrow_mut(&self) -> cell::RefMut<'_, T> {
+        self.0.borrow_mut()
+    }
+}
+
+impl<T> Clone for Link<T> {
+    fn clone(&self) -> Self {
+        Link(self.0.clone())
+    }
+}
+
+/// An immutable borrow of some [`State`].
+///
+/// [`State`]: enum.State.html
+#[derive(Debug)]
+pub enum Ref<'a, T> {
+    /// A borrow of [`Borrowed`] state.
+    ///
+    /// [`Borrowed`]: enum.State.html#variant.Borrowed
+    Borrowed(&'a T),
+
+    /// A borrow of [`Managed`] or [`Linked`] state.
+    ///
+    /// [`Managed`]: enum.State.html#variant.Managed
+    /// [`Linked`]: enum.State.html#variant.Linked
+    Managed(cell::Ref<'a, T>),
+}
+
+impl<'a, T> Deref for Ref<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match self {
+            Ref::Borrowed(state) => state,
+            Ref::Managed(state) => state,
+        }
+    }
+}
+
+/// A mutable borrow of some [`State`].
+///
+/// [`State`]: enum.State.html
+#[derive(Debug)]
+pub enum RefMut<'a, T> {
+    /// A borrow of [`Borrowed`] state.
+    ///
+    /// [`Borrowed`]: enum.State.html#variant.Borrowed
+    Borrowed(&'a mut T),
+
+    /// A borrow of [`Managed`] or [`Linked`] state.
+    ///
+    /// [`Managed`]: enum.State.html#variant.Managed
+    /// [`Linked`]: enum.State.html#variant.Linked
+    Managed(cell::RefMut<'a, T>),
+}
+
+impl<'a, T> Deref for RefMut<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match self {
+            RefMut::Borrowed(state) => state,
+            RefMut::Managed(state) => state,
+        }
+    }
+}
+
+impl<'a, T> DerefMut for RefMut<'a, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        match self {
+            RefMut::Borrowed(state) => state,
+            RefMut::Managed(state) => state,
+        }
+    }
+}
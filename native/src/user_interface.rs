@@ -1,9 +1,15 @@
 use crate::{
-    input::mouse, layout, Clipboard, Element, Event, Layout, Point, Size,
+    input::{keyboard, mouse, ButtonState},
+    layout, overlay, Clipboard, Element, Event, Focus, Hover, Layout, Point,
+    Size, Tree,
 };
 
 use std::hash::Hasher;
 
+// The cursor position the widgets see when the cursor is not over them,
+// because it left the window or it is over an overlay
+const HIDDEN_CURSOR: Point = Point { x: -1.0, y: -1.0 };
+
 /// A set of interactive graphical elements with a specific [`Layout`].
 ///
 /// It can be updated and drawn.
@@ -18,6 +24,8 @@ pub struct UserInterface<'a, Message, Renderer> {
     root: Element<'a, Message, Renderer>,
     layout: layout::Node,
     cursor_position: Point,
+    hovered: Option<Vec<usize>>,
+    tree: Tree,
 }
 
 impl<'a, Message, Renderer> UserInterface<'a, Message, Renderer>
@@ -49,7 +57,16 @@ where
     /// #         pub fn new() -> Self { Renderer }
     /// #     }
     /// #
-    /// #     impl iced_native::Renderer for Renderer { type Output = (); }
+    /// #     impl iced_native::Renderer for Renderer {
+    /// #         type Output = ();
+    /// #
+    /// #         fn overlay(
+    /// #             &mut self,
+    /// #             _base: (),
+    /// #             _overlay: (),
+    /// #             _overlay_bounds: iced_native::Rectangle,
+    /// #         ) {}
+    /// #     }
     /// #
     /// #     impl iced_native::column::Renderer for Renderer {
     /// #         fn draw<Message>(
@@ -101,7 +118,10 @@ where
         cache: Cache,
         renderer: &mut Renderer,
     ) -> Self {
-        let root = root.into();
+        let mut root = root.into();
+        let mut tree = cache.tree;
+
+        root.diff(&mut tree);
 
         let hasher = &mut crate::Hasher::default();
         root.hash_layout(hasher);
@@ -119,6 +139,8 @@ where
             root,
             layout,
             cursor_position: cache.cursor_position,
+            hovered: cache.hovered,
+            tree,
         }
     }
 
@@ -145,7 +167,16 @@ where
     /// #         pub fn new() -> Self { Renderer }
     /// #     }
     /// #
-    /// #     impl iced_native::Renderer for Renderer { type Output = (); }
+    /// #     impl iced_native::Renderer for Renderer {
+    /// #         type Output = ();
+    /// #
+    /// #         fn overlay(
+    /// #             &mut self,
+    /// #             _base: (),
+    /// #             _overlay: (),
+    /// #             _overlay_bounds: iced_native::Rectangle,
+    /// #         ) {}
+    /// #     }
     /// #
     /// #     impl iced_native::column::Renderer for Renderer {
     /// #         fn draw<Message>(
@@ -205,22 +236,248 @@ where
     ) -> Vec<Message> {
         let mut messages = Vec::new();
 
+        // The widget under the cursor may have changed since the last update
+        self.update_hover(&mut messages, renderer, clipboard);
+
         for event in events {
-            if let Event::Mouse(mouse::Event::CursorMoved { x, y }) = event {
-                self.cursor_position = Point::new(x, y);
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { x, y }) => {
+                    self.cursor_position = Point::new(x, y);
+
+                    self.update_hover(&mut messages, renderer, clipboard);
+                }
+                // A drag keeps the last cursor position until it ends
+                Event::Mouse(mouse::Event::CursorLeft)
+                    if !self.is_mouse_captured() =>
+                {
+                    self.cursor_position = HIDDEN_CURSOR;
+
+                    self.update_hover(&mut messages, renderer, clipboard);
+                }
+                _ => {}
             }
 
-            self.root.widget.on_event(
+            let is_released = matches!(
                 event,
+                Event::Mouse(mouse::Event::Input {
+                    state: ButtonState::Released,
+                    ..
+                })
+            );
+
+            let is_mouse_captured = self.is_mouse_captured();
+            let mut is_over_overlay = false;
+            let mut status = overlay::Status::Ignored;
+
+            if let Some(mut overlay) =
+                self.root.overlay(Layout::new(&self.layout))
+            {
+                let layout = overlay.layout(renderer, self.layout.size());
+
+                is_over_overlay = !is_mouse_captured
+                    && layout.bounds().contains(self.cursor_position);
+
+                status = overlay.on_event(
+                    event.clone(),
+                    Layout::new(&layout),
+                    self.cursor_position,
+                    &mut messages,
+                    renderer,
+                    clipboard,
+                );
+            }
+
+            // Clicks and scrolls on an overlay do not reach the widgets under
+            // it
+            let is_occluded = status == overlay::Status::Captured
+                || (is_over_overlay
+                    && matches!(
+                        event,
+                        Event::Mouse(mouse::Event::Input { .. })
+                            | Event::Mouse(mouse::Event::WheelScrolled { .. })
+                    ));
+
+            // The tab key is consumed when it moves the focus
+            let is_focus_moved = match event {
+                Event::Keyboard(keyboard::Event::Input {
+                    key_code: keyboard::KeyCode::Tab,
+                    state: ButtonState::Pressed,
+                    modifiers,
+                }) if !is_occluded => self.move_focus(
+                    modifiers.shift,
+                    modifiers.control,
+                    &mut messages,
+                    renderer,
+                    clipboard,
+                ),
+                _ => false,
+            };
+
+            if !is_occluded && !is_focus_moved {
+                self.root.widget.on_event(
+                    event,
+                    hovered_layout(&self.layout, &self.hovered),
+                    if is_over_overlay {
+                        HIDDEN_CURSOR
+                    } else {
+                        self.cursor_position
+                    },
+                    &mut messages,
+                    renderer,
+                    clipboard,
+                );
+            }
+
+            // The hovered widgets may have changed during a drag
+            if is_released {
+                self.update_hover(&mut messages, renderer, clipboard);
+            }
+        }
+
+        messages
+    }
+
+    /// Returns whether a widget of the [`UserInterface`] captures the mouse,
+    /// as described in [`Widget::captures_mouse`].
+    ///
+    /// Shells can use it to keep delivering mouse events to the
+    /// [`UserInterface`] while the cursor is outside of the window, or to
+    /// end the drag when the window loses the focus.
+    ///
+    /// [`UserInterface`]: struct.UserInterface.html
+    /// [`Widget::captures_mouse`]: widget/trait.Widget.html#method.captures_mouse
+    pub fn is_mouse_captured(&self) -> bool {
+        self.root.captures_mouse()
+    }
+
+    /// Finds the widgets under the cursor and notifies the ones that the
+    /// cursor entered or exited.
+    ///
+    /// The hovered widgets do not change while the mouse is captured.
+    fn update_hover(
+        &mut self,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        if self.is_mouse_captured() {
+            return;
+        }
+
+        let size = self.layout.size();
+
+        let overlay_bounds = self
+            .root
+            .overlay(Layout::new(&self.layout))
+            .map(|overlay| overlay.layout(renderer, size).bounds());
+
+        let is_over_overlay = overlay_bounds
+            .map(|bounds| bounds.contains(self.cursor_position))
+            .unwrap_or(false);
+
+        let hovered = if !is_over_overlay
+            && self.layout.bounds().contains(self.cursor_position)
+        {
+            let mut path = Vec::new();
+
+            self.root.widget.hover(
                 Layout::new(&self.layout),
                 self.cursor_position,
-                &mut messages,
+                &mut path,
+            );
+
+            Some(path)
+        } else {
+            None
+        };
+
+        if hovered == self.hovered {
+            return;
+        }
+
+        // The root and the common ancestors stay hovered
+        let unchanged = match (&self.hovered, &hovered) {
+            (Some(old), Some(new)) => {
+                1 + old.iter().zip(new).take_while(|(a, b)| a == b).count()
+            }
+            _ => 0,
+        };
+
+        let changes =
+            [(&self.hovered, Hover::Exited), (&hovered, Hover::Entered)];
+
+        for (path, hover) in changes.iter() {
+            if let Some(path) = path {
+                if path.len() + 1 > unchanged {
+                    self.root.widget.on_event(
+                        Event::Hover(*hover),
+                        Layout::hovered(&self.layout, path, unchanged),
+                        self.cursor_position,
+                        messages,
+                        renderer,
+                        clipboard,
+                    );
+                }
+            }
+        }
+
+        self.hovered = hovered;
+    }
+
+    /// Moves the keyboard focus to the next widget that can be focused, or
+    /// to the previous one if `backwards`.
+    ///
+    /// It returns `false` and does nothing if no widget can be focused, or if
+    /// the focused widget uses the tab key and the focus is not `forced` out
+    /// of it.
+    fn move_focus(
+        &mut self,
+        backwards: bool,
+        forced: bool,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        let mut focusables = Vec::new();
+        self.root
+            .widget
+            .focusables(&mut Vec::new(), &mut focusables);
+
+        let focused =
+            focusables.iter().position(|focusable| focusable.is_focused);
+
+        let count = focusables.len();
+
+        let next = match focused {
+            _ if count == 0 => return false,
+            Some(index) if focusables[index].uses_tab && !forced => {
+                return false;
+            }
+            Some(index) if backwards => (index + count - 1) % count,
+            Some(index) => (index + 1) % count,
+            None if backwards => count - 1,
+            None => 0,
+        };
+
+        // Widgets track their own focus, so more than one may claim it
+        let targets = focusables
+            .iter()
+            .filter(|focusable| focusable.is_focused)
+            .map(|focusable| (&focusable.path, Focus::Lost))
+            .chain(std::iter::once((&focusables[next].path, Focus::Gained)));
+
+        for (path, focus) in targets {
+            self.root.widget.on_event(
+                Event::Focus(focus),
+                Layout::focus_target(&self.layout, path),
+                self.cursor_position,
+                messages,
                 renderer,
                 clipboard,
             );
         }
 
-        messages
+        true
     }
 
     /// Draws the [`UserInterface`] with the provided [`Renderer`].
@@ -247,7 +504,16 @@ where
     /// #         pub fn new() -> Self { Renderer }
     /// #     }
     /// #
-    /// #     impl iced_native::Renderer for Renderer { type Output = (); }
+    /// #     impl iced_native::Renderer for Renderer {
+    /// #         type Output = ();
+    /// #
+    /// #         fn overlay(
+    /// #             &mut self,
+    /// #             _base: (),
+    /// #             _overlay: (),
+    /// #             _overlay_bounds: iced_native::Rectangle,
+    /// #         ) {}
+    /// #     }
     /// #
     /// #     impl iced_native::column::Renderer for Renderer {
     /// #         fn draw<Message>(
@@ -301,12 +567,53 @@ where
     ///     // Flush rendering operations...
     /// }
     /// ```
-    pub fn draw(&self, renderer: &mut Renderer) -> Renderer::Output {
-        self.root.widget.draw(
+    pub fn draw(&mut self, renderer: &mut Renderer) -> Renderer::Output {
+        let size = self.layout.size();
+        let cursor_position = self.cursor_position;
+
+        let overlay =
+            self.root.overlay(Layout::new(&self.layout)).map(|overlay| {
+                let layout = overlay.layout(renderer, size);
+                let output = overlay.draw(
+                    renderer,
+                    Layout::new(&layout),
+                    cursor_position,
+                );
+
+                (output, layout.bounds())
+            });
+
+        let is_over_overlay = !self.is_mouse_captured()
+            && overlay
+                .as_ref()
+                .map(|(_, bounds)| bounds.contains(self.cursor_position))
+                .unwrap_or(false);
+
+        let base = self.root.widget.draw(
             renderer,
-            Layout::new(&self.layout),
-            self.cursor_position,
-        )
+            hovered_layout(&self.layout, &self.hovered),
+            if is_over_overlay {
+                HIDDEN_CURSOR
+            } else {
+                self.cursor_position
+            },
+        );
+
+        match overlay {
+            Some((overlay, bounds)) => renderer.overlay(base, overlay, bounds),
+            None => base,
+        }
+    }
+
+    /// Returns a snapshot of the widgets of the [`UserInterface`], with
+    /// their bounds after layout.
+    ///
+    /// Only available with the `devtools` feature.
+    ///
+    /// [`UserInterface`]: struct.UserInterface.html
+    #[cfg(feature = "devtools")]
+    pub fn inspect(&self) -> crate::inspect::Node {
+        self.root.widget.inspect(Layout::new(&self.layout))
     }
 
     /// Extract the [`Cache`] of the [`UserInterface`], consuming it in the
@@ -319,18 +626,38 @@ where
             hash: self.hash,
             layout: self.layout,
             cursor_position: self.cursor_position,
+            hovered: self.hovered,
+            tree: self.tree,
         }
     }
 }
 
+fn hovered_layout<'a>(
+    layout: &'a layout::Node,
+    hovered: &'a Option<Vec<usize>>,
+) -> Layout<'a> {
+    match hovered {
+        Some(path) => Layout::hovered(layout, path, 0),
+        None => Layout::new(layout),
+    }
+}
+
 /// Reusable data of a specific [`UserInterface`].
 ///
+/// A [`Cache`] holds the runtime-managed state of the widgets, which is
+/// shared with the widgets through reference counting. Therefore, it is not
+/// `Send` and must stay in the thread running the [`UserInterface`]. A clone
+/// of a [`Cache`] shares the same widget state.
+///
+/// [`Cache`]: struct.Cache.html
 /// [`UserInterface`]: struct.UserInterface.html
 #[derive(Debug, Clone)]
 pub struct Cache {
     hash: u64,
     layout: layout::Node,
     cursor_position: Point,
+    hovered: Option<Vec<usize>>,
+    tree: Tree,
 }
 
 impl Cache {
@@ -346,6 +673,54 @@ impl Cache {
             hash: 0,
             layout: layout::Node::new(Size::new(0.0, 0.0)),
             cursor_position: Point::new(-1.0, -1.0),
+            hovered: None,
+            tree: Tree::empty(),
+        }
+    }
+
+    /// Forces the next [`UserInterface`] built with this [`Cache`] to compute
+    /// its layout again, even if its widgets have not changed.
+    ///
+    /// This is useful when something affecting the layout changes outside of
+    /// the widgets, like the text scale of the renderer.
+    ///
+    /// [`Cache`]: struct.Cache.html
+    /// [`UserInterface`]: struct.UserInterface.html
+    pub fn invalidate_layout(&mut self) {
+        self.hash = 0;
+    }
+
+    /// Moves the runtime-managed state of the widget at the `from` path of
+    /// a `previous` [`Cache`] to the widget at the `to` path of this one,
+    /// including the state of its children.
+    ///
+    /// Paths are the indices of the children on the way to a widget, like in
+    /// the snapshots of [`UserInterface::inspect`]. A hot-reload harness can
+    /// compare the snapshots before and after the view code changed to find
+    /// where each widget went, and keep its state where the runtime could
+    /// not match it by key or position.
+    ///
+    /// It returns `false` and does nothing if either path does not exist.
+    ///
+    /// Only available with the `devtools` feature.
+    ///
+    /// [`Cache`]: struct.Cache.html
+    /// [`UserInterface::inspect`]: struct.UserInterface.html#method.inspect
+    #[cfg(feature = "devtools")]
+    pub fn remap(
+        &mut self,
+        previous: &Cache,
+        from: &[usize],
+        to: &[usize],
+    ) -> bool {
+        match (previous.tree.find(from), self.tree.find_mut(to)) {
+            (Some(state), Some(target)) => {
+                target.graft(state);
+                self.invalidate_layout();
+
+                true
+            }
+            _ => false,
         }
     }
 }
@@ -20,48 +20,168 @@
 //!
 //! [`Widget`]: trait.Widget.html
 //! [renderer]: ../renderer/index.html
+pub mod aspect_ratio;
+pub mod avatar;
+pub mod badge;
 pub mod button;
+pub mod cache_stats;
+pub mod cached;
 pub mod checkbox;
 pub mod column;
+pub mod combo_box;
 pub mod container;
+pub mod custom_layout;
+pub mod date_picker;
+pub mod dialogs;
+pub mod draggable;
+pub mod grid;
 pub mod image;
+pub mod knob;
+pub mod lazy_column;
+pub mod link;
+#[cfg(feature = "markdown")]
+pub mod markdown;
+pub mod menu_bar;
+pub mod minimap;
+pub mod modal;
+pub mod mouse_area;
+pub mod navigation;
+pub mod number_input;
+pub mod progress_bar;
+#[cfg(feature = "qr_code")]
+pub mod qr_code;
 pub mod radio;
+pub mod range_slider;
+pub mod rating;
+pub mod reveal;
+pub mod rich_text;
 pub mod row;
 pub mod scrollable;
+pub mod segmented_button;
+pub mod selectable_text;
 pub mod slider;
 pub mod space;
+pub mod spinner;
 pub mod svg;
+pub mod table;
+pub mod tabs;
 pub mod text;
+pub mod text_editor;
 pub mod text_input;
+pub mod time_picker;
+pub mod timeline;
+pub mod toasts;
+pub mod xy_pad;
 
+#[doc(no_inline)]
+pub use aspect_ratio::AspectRatio;
+#[doc(no_inline)]
+pub use avatar::Avatar;
+#[doc(no_inline)]
+pub use badge::Badge;
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
+pub use cache_stats::CacheStats;
+#[doc(no_inline)]
+pub use cached::Cached;
+#[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
 pub use column::Column;
 #[doc(no_inline)]
+pub use combo_box::ComboBox;
+#[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use custom_layout::CustomLayout;
+#[doc(no_inline)]
+pub use date_picker::DatePicker;
+#[doc(no_inline)]
+pub use dialogs::Dialogs;
+pub use draggable::Draggable;
+#[doc(no_inline)]
+pub use grid::Grid;
+#[doc(no_inline)]
 pub use image::Image;
 #[doc(no_inline)]
+pub use knob::Knob;
+#[doc(no_inline)]
+pub use lazy_column::LazyColumn;
+#[doc(no_inline)]
+pub use link::Link;
+#[cfg(feature = "markdown")]
+#[doc(no_inline)]
+pub use markdown::Markdown;
+#[doc(no_inline)]
+pub use menu_bar::MenuBar;
+#[doc(no_inline)]
+pub use minimap::MiniMap;
+#[doc(no_inline)]
+pub use modal::Modal;
+#[doc(no_inline)]
+pub use mouse_area::MouseArea;
+#[doc(no_inline)]
+pub use navigation::Navigation;
+#[doc(no_inline)]
+pub use number_input::NumberInput;
+#[doc(no_inline)]
+pub use progress_bar::ProgressBar;
+#[cfg(feature = "qr_code")]
+#[doc(no_inline)]
+pub use qr_code::QrCode;
+#[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use range_slider::RangeSlider;
+#[doc(no_inline)]
+pub use rating::Rating;
+#[doc(no_inline)]
+pub use reveal::Reveal;
+#[doc(no_inline)]
+pub use rich_text::RichText;
+#[doc(no_inline)]
 pub use row::Row;
 #[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
+pub use segmented_button::SegmentedButton;
+#[doc(no_inline)]
+pub use selectable_text::SelectableText;
+#[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
 pub use space::Space;
 #[doc(no_inline)]
+pub use spinner::Spinner;
+#[doc(no_inline)]
 pub use svg::Svg;
 #[doc(no_inline)]
+pub use table::Table;
+#[doc(no_inline)]
+pub use tabs::Tabs;
+#[doc(no_inline)]
 pub use text::Text;
 #[doc(no_inline)]
+pub use text_editor::TextEditor;
+#[doc(no_inline)]
 pub use text_input::TextInput;
+#[doc(no_inline)]
+pub use time_picker::TimePicker;
+#[doc(no_inline)]
+pub use timeline::Timeline;
+#[doc(no_inline)]
+pub use toasts::Toasts;
+#[doc(no_inline)]
+pub use xy_pad::XYPad;
+
+use crate::{
+    layout, overlay, Clipboard, Event, Hasher, Layout, Length, Point, Size,
+    Tree,
+};
 
-use crate::{layout, Clipboard, Event, Hasher, Layout, Length, Point};
+#[cfg(feature = "devtools")]
+use crate::inspect;
 
 /// A component that displays information and allows interaction.
 ///
@@ -98,6 +218,24 @@ where
         limits: &layout::Limits,
     ) -> layout::Node;
 
+    /// Returns the size the [`Widget`] wants to have in the given limits,
+    /// without positioning its contents.
+    ///
+    /// This is the _measure_ phase of a two-phase layout: a parent asks its
+    /// children how big they want to be, decides their final sizes, and then
+    /// calls [`layout`] with exact limits to _arrange_ them. A
+    /// [`CustomLayout`] works this way.
+    ///
+    /// By default, it computes the whole [`layout`] and returns its size.
+    /// Widgets that can measure themselves faster should override it.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    /// [`layout`]: #tymethod.layout
+    /// [`CustomLayout`]: custom_layout/struct.CustomLayout.html
+    fn measure(&self, renderer: &Renderer, limits: &layout::Limits) -> Size {
+        self.layout(renderer, limits).size()
+    }
+
     /// Draws the [`Widget`] using the associated `Renderer`.
     ///
     /// [`Widget`]: trait.Widget.html
@@ -148,4 +286,248 @@ where
         _clipboard: Option<&dyn Clipboard>,
     ) {
     }
+
+    /// Reconciles the [`Widget`] with the [`Tree`] of the previous view.
+    ///
+    /// Widgets with runtime-managed [`State`] should obtain it from the
+    /// [`Tree`]. Widgets with children should reconcile them with the
+    /// children of the [`Tree`] and diff each of them.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    /// [`Tree`]: ../tree/struct.Tree.html
+    /// [`State`]: ../tree/enum.State.html
+    fn diff(&mut self, _tree: &mut Tree) {}
+
+    /// Finds the descendant of the [`Widget`] under the cursor, pushing the
+    /// index of the [`Layout`] of every child on the way to it.
+    ///
+    /// The runtime calls it when the cursor is inside the bounds of the
+    /// [`Widget`], to know which widgets are hovered.
+    ///
+    /// By default, it uses [`layout::hover`], picking the last child under
+    /// the cursor at every level. Widgets with children should forward the
+    /// call to the hovered child, so it can be overridden deeper in the tree.
+    /// Widgets that change the cursor position for their children, like a
+    /// [`Scrollable`], should override it too.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    /// [`Layout`]: ../layout/struct.Layout.html
+    /// [`layout::hover`]: ../layout/fn.hover.html
+    /// [`Scrollable`]: scrollable/struct.Scrollable.html
+    fn hover(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        path: &mut Vec<usize>,
+    ) {
+        layout::hover(layout, cursor_position, path)
+    }
+
+    /// Pushes every descendant of the [`Widget`] that can have the keyboard
+    /// focus, in the order the tab key moves the focus along them.
+    ///
+    /// The `path` contains the index of the [`Layout`] of every child on the
+    /// way to the [`Widget`].
+    ///
+    /// By default, the [`Widget`] cannot be focused. Widgets that can be
+    /// focused should push a [`Focusable`] with the current `path`, and
+    /// handle [`Event::Focus`]. Widgets with children should forward the call
+    /// to the children they forward events to.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    /// [`Layout`]: ../layout/struct.Layout.html
+    /// [`Focusable`]: struct.Focusable.html
+    /// [`Event::Focus`]: ../enum.Event.html#variant.Focus
+    fn focusables(
+        &self,
+        _path: &mut Vec<usize>,
+        _focusables: &mut Vec<Focusable>,
+    ) {
+    }
+
+    /// Returns whether the [`Widget`], or any of its descendants, captures
+    /// the mouse.
+    ///
+    /// A widget should capture the mouse while the user drags something with
+    /// it. While the mouse is captured, the runtime keeps the widgets under
+    /// the cursor when the capture started hovered, and it keeps the last
+    /// cursor position when the cursor leaves the window. Widgets that change
+    /// the cursor position for their children, like a [`Scrollable`], stop
+    /// hiding it from a child that captures the mouse, so the drag continues
+    /// outside of their bounds.
+    ///
+    /// By default, the [`Widget`] does not capture the mouse. Widgets with
+    /// children should forward the call to the children they forward events
+    /// to.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    /// [`Scrollable`]: scrollable/struct.Scrollable.html
+    fn captures_mouse(&self) -> bool {
+        false
+    }
+
+    /// Returns the overlay of the [`Widget`], if it has one, laid out with
+    /// the given [`Layout`].
+    ///
+    /// An overlay floats over the rest of the user interface, outside of the
+    /// clipping bounds of its ancestors, and processes events before them.
+    /// Only one overlay is shown at a time.
+    ///
+    /// By default, the [`Widget`] has no overlay. Widgets with children
+    /// should forward the call to the children they forward events to, and
+    /// [`translate`] the returned overlay if they move their children on the
+    /// screen.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    /// [`Layout`]: ../layout/struct.Layout.html
+    /// [`translate`]: ../overlay/struct.Element.html#method.translate
+    fn overlay(
+        &mut self,
+        _layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        None
+    }
+
+    /// Returns a snapshot of the [`Widget`] and its children, laid out with
+    /// the given [`Layout`].
+    ///
+    /// By default, it describes the [`Widget`] without children. Widgets with
+    /// children should include a [`Node`] for each of them, with the key they
+    /// use to reconcile it, if any.
+    ///
+    /// Only available with the `devtools` feature.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    /// [`Layout`]: ../layout/struct.Layout.html
+    /// [`Node`]: ../inspect/struct.Node.html
+    #[cfg(feature = "devtools")]
+    fn inspect(&self, layout: Layout<'_>) -> inspect::Node {
+        inspect::Node::new(std::any::type_name::<Self>(), layout.bounds())
+    }
+}
+
+/// Describes a widget of the given type with the given keyed children, as
+/// described in [`Widget::inspect`].
+///
+/// [`Widget::inspect`]: trait.Widget.html#method.inspect
+#[cfg(feature = "devtools")]
+pub(crate) fn inspect_children<Message, Renderer>(
+    type_name: &'static str,
+    children: &[crate::Element<'_, Message, Renderer>],
+    keys: &[Option<u64>],
+    layout: Layout<'_>,
+) -> inspect::Node
+where
+    Renderer: crate::Renderer,
+{
+    inspect::Node {
+        children: children
+            .iter()
+            .zip(keys)
+            .zip(layout.children())
+            .map(|((child, key), layout)| inspect::Node {
+                key: *key,
+                ..child.inspect(layout)
+            })
+            .collect(),
+        ..inspect::Node::new(type_name, layout.bounds())
+    }
+}
+
+/// A widget that can have the keyboard focus, as described in
+/// [`Widget::focusables`].
+///
+/// [`Widget::focusables`]: trait.Widget.html#method.focusables
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Focusable {
+    /// The index of the [`Layout`] of every child on the way to the widget
+    ///
+    /// [`Layout`]: ../layout/struct.Layout.html
+    pub path: Vec<usize>,
+
+    /// Whether the widget has the focus
+    pub is_focused: bool,
+
+    /// Whether the widget uses the tab key while it has the focus, like a
+    /// [`TextEditor`] inserting indentation
+    ///
+    /// The tab key only moves the focus away from it when the control key is
+    /// pressed too.
+    ///
+    /// [`TextEditor`]: text_editor/struct.TextEditor.html
+    pub uses_tab: bool,
+}
+
+/// Forwards [`Widget::focusables`] to every child, in order.
+///
+/// [`Widget::focusables`]: trait.Widget.html#method.focusables
+pub(crate) fn focusables_children<Message, Renderer>(
+    children: &[crate::Element<'_, Message, Renderer>],
+    path: &mut Vec<usize>,
+    focusables: &mut Vec<Focusable>,
+) where
+    Renderer: crate::Renderer,
+{
+    for (index, child) in children.iter().enumerate() {
+        path.push(index);
+        child.focusables(path, focusables);
+        let _ = path.pop();
+    }
+}
+
+/// Forwards [`Widget::captures_mouse`] to every child, returning whether any
+/// of them captures the mouse.
+///
+/// [`Widget::captures_mouse`]: trait.Widget.html#method.captures_mouse
+pub(crate) fn captures_mouse_children<Message, Renderer>(
+    children: &[crate::Element<'_, Message, Renderer>],
+) -> bool
+where
+    Renderer: crate::Renderer,
+{
+    children.iter().any(crate::Element::captures_mouse)
+}
+
+/// Forwards [`Widget::overlay`] to every child, in order, returning the
+/// first overlay found.
+///
+/// [`Widget::overlay`]: trait.Widget.html#method.overlay
+pub(crate) fn overlay_children<'a, 'b, Message, Renderer>(
+    children: &'b mut [crate::Element<'a, Message, Renderer>],
+    layout: Layout<'_>,
+) -> Option<overlay::Element<'b, Message, Renderer>>
+where
+    Renderer: crate::Renderer,
+{
+    children
+        .iter_mut()
+        .zip(layout.children())
+        .find_map(|(child, layout)| child.overlay(layout))
+}
+
+/// Forwards [`Widget::hover`] to the topmost child under the cursor, if any.
+///
+/// [`Widget::hover`]: trait.Widget.html#method.hover
+pub(crate) fn hover_children<Message, Renderer>(
+    children: &[crate::Element<'_, Message, Renderer>],
+    layout: Layout<'_>,
+    cursor_position: Point,
+    path: &mut Vec<usize>,
+) where
+    Renderer: crate::Renderer,
+{
+    let hovered = children
+        .iter()
+        .zip(layout.children())
+        .enumerate()
+        .filter(|(_, (_, layout))| layout.bounds().contains(cursor_position))
+        .last();
+
+    if let Some((index, (child, layout))) = hovered {
+        path.push(index);
+
+        child.hover(layout, cursor_position, path);
+    }
 }
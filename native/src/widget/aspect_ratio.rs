@@ -0,0 +1,232 @@
+//! Keep some content at a fixed ratio between its width and its height.
+use std::hash::Hash;
+
+use crate::{
+    layout, overlay, Clipboard, Element, Event, Focusable, Hasher, Layout,
+    Length, Point, Size, Tree, Widget,
+};
+
+/// A container that sizes its content to a fixed ratio between its width and
+/// its height.
+///
+/// An [`AspectRatio`] takes the biggest size with its ratio that fits its
+/// limits. It is useful for content that must keep its proportions, like
+/// video surfaces, thumbnails, or square canvases.
+///
+/// # Example
+/// ```
+/// # use iced_native::{renderer::Null, Length, Space};
+/// #
+/// # pub type AspectRatio<'a, Message> =
+/// #     iced_native::AspectRatio<'a, Message, Null>;
+/// let video: AspectRatio<()> =
+///     AspectRatio::new(16.0 / 9.0, Space::new(Length::Fill, Length::Fill))
+///         .width(Length::Units(640));
+/// ```
+///
+/// [`AspectRatio`]: struct.AspectRatio.html
+#[allow(missing_debug_implementations)]
+pub struct AspectRatio<'a, Message, Renderer> {
+    ratio: f32,
+    width: Length,
+    height: Length,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> AspectRatio<'a, Message, Renderer> {
+    /// Creates an [`AspectRatio`] with the given ratio of its width to its
+    /// height, like `16.0 / 9.0` or `1.0` for a square.
+    ///
+    /// Ratios that are not positive are replaced with `1.0`.
+    ///
+    /// [`AspectRatio`]: struct.AspectRatio.html
+    pub fn new<E>(ratio: f32, content: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        AspectRatio {
+            ratio: if ratio > 0.0 && ratio.is_finite() {
+                ratio
+            } else {
+                1.0
+            },
+            width: Length::Fill,
+            height: Length::Shrink,
+            content: content.into(),
+        }
+    }
+
+    /// Sets the width of the [`AspectRatio`].
+    ///
+    /// When both its width and its height shrink, an [`AspectRatio`] is as
+    /// wide as its content.
+    ///
+    /// [`AspectRatio`]: struct.AspectRatio.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`AspectRatio`].
+    ///
+    /// [`AspectRatio`]: struct.AspectRatio.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for AspectRatio<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let max = limits.max();
+
+        // A side is only given by the limits when it does not shrink and
+        // the limits are bounded
+        let available = |length: Length, max: f32| {
+            Some(max).filter(|max| length != Length::Shrink && max.is_finite())
+        };
+
+        let width = match (
+            available(self.width, max.width),
+            available(self.height, max.height),
+        ) {
+            (Some(width), Some(height)) => width.min(height * self.ratio),
+            (Some(width), None) => width,
+            (None, Some(height)) => height * self.ratio,
+            (None, None) => {
+                self.content.measure(renderer, &limits.loose()).width
+            }
+        }
+        .min(max.width)
+        .min(max.height * self.ratio);
+
+        let size = Size::new(width, width / self.ratio);
+        let content = self
+            .content
+            .layout(renderer, &layout::Limits::new(size, size));
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.content.widget.on_event(
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.content.draw(
+            renderer,
+            layout.children().next().unwrap(),
+            cursor_position,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<AspectRatio<'static, (), ()>>().hash(state);
+        self.ratio.to_bits().hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.content.diff(&mut tree.diff_children(&[None])[0]);
+    }
+
+    #[cfg(feature = "devtools")]
+    fn inspect(&self, layout: Layout<'_>) -> crate::inspect::Node {
+        super::inspect_children(
+            std::any::type_name::<Self>(),
+            std::slice::from_ref(&self.content),
+            &[None],
+            layout,
+        )
+    }
+
+    fn hover(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        path: &mut Vec<usize>,
+    ) {
+        super::hover_children(
+            std::slice::from_ref(&self.content),
+            layout,
+            cursor_position,
+            path,
+        );
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        super::focusables_children(
+            std::slice::from_ref(&self.content),
+            path,
+            focusables,
+        );
+    }
+
+    fn captures_mouse(&self) -> bool {
+        super::captures_mouse_children(std::slice::from_ref(&self.content))
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        super::overlay_children(std::slice::from_mut(&mut self.content), layout)
+    }
+}
+
+impl<'a, Message, Renderer> From<AspectRatio<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'static,
+{
+    fn from(
+        aspect_ratio: AspectRatio<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(aspect_ratio)
+    }
+}
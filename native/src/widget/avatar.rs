@@ -0,0 +1,203 @@
+//! Represent people and entities with a picture or their initials.
+use crate::{
+    image, layout, Color, Element, Hasher, Layout, Length, Point, Rectangle,
+    Size, Widget,
+};
+
+use std::hash::{Hash, Hasher as _};
+
+const PALETTE: [[f32; 3]; 8] = [
+    [0.91, 0.30, 0.24],
+    [0.90, 0.49, 0.13],
+    [0.95, 0.61, 0.07],
+    [0.18, 0.80, 0.44],
+    [0.10, 0.74, 0.61],
+    [0.20, 0.60, 0.86],
+    [0.61, 0.35, 0.71],
+    [0.20, 0.29, 0.37],
+];
+
+/// A circular picture of a person or entity.
+///
+/// When no image is provided, or while it is not available, an [`Avatar`]
+/// displays the initials of its name over a colored circle.
+///
+/// # Example
+/// ```
+/// # use iced_native::Avatar;
+/// #
+/// let avatar = Avatar::new("Ferris the Crab").size(48);
+/// ```
+///
+/// [`Avatar`]: struct.Avatar.html
+#[derive(Debug)]
+pub struct Avatar {
+    name: String,
+    image: Option<image::Handle>,
+    size: u16,
+    background: Option<Color>,
+    foreground: Color,
+}
+
+impl Avatar {
+    /// Creates a new [`Avatar`] for the given name.
+    ///
+    /// [`Avatar`]: struct.Avatar.html
+    pub fn new<T: Into<String>>(name: T) -> Self {
+        Avatar {
+            name: name.into(),
+            image: None,
+            size: 40,
+            background: None,
+            foreground: Color::WHITE,
+        }
+    }
+
+    /// Sets the image of the [`Avatar`].
+    ///
+    /// [`Avatar`]: struct.Avatar.html
+    pub fn image<T: Into<image::Handle>>(mut self, image: T) -> Self {
+        self.image = Some(image.into());
+        self
+    }
+
+    /// Sets the diameter of the [`Avatar`].
+    ///
+    /// [`Avatar`]: struct.Avatar.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the background color of the initials of the [`Avatar`].
+    ///
+    /// By default, a color is picked from a palette based on the name.
+    ///
+    /// [`Avatar`]: struct.Avatar.html
+    pub fn background<C: Into<Color>>(mut self, color: C) -> Self {
+        self.background = Some(color.into());
+        self
+    }
+
+    /// Sets the color of the initials of the [`Avatar`].
+    ///
+    /// [`Avatar`]: struct.Avatar.html
+    pub fn foreground<C: Into<Color>>(mut self, color: C) -> Self {
+        self.foreground = color.into();
+        self
+    }
+
+    /// Returns the initials of the [`Avatar`].
+    ///
+    /// They are the uppercased first letters of the first and last words of
+    /// its name.
+    ///
+    /// [`Avatar`]: struct.Avatar.html
+    pub fn initials(&self) -> String {
+        let mut words = self.name.split_whitespace();
+
+        let first = words.next().and_then(|word| word.chars().next());
+        let last = words.last().and_then(|word| word.chars().next());
+
+        first
+            .into_iter()
+            .chain(last)
+            .flat_map(char::to_uppercase)
+            .collect()
+    }
+
+    fn background_color(&self) -> Color {
+        self.background.unwrap_or_else(|| {
+            let mut hasher = Hasher::default();
+            self.name.hash(&mut hasher);
+
+            let index = hasher.finish() as usize % PALETTE.len();
+
+            PALETTE[index].into()
+        })
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Avatar
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Units(self.size)
+    }
+
+    fn height(&self) -> Length {
+        Length::Units(self.size)
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = limits
+            .width(Length::Units(self.size))
+            .height(Length::Units(self.size))
+            .resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.draw(
+            layout.bounds(),
+            self.image.clone(),
+            &self.initials(),
+            self.background_color(),
+            self.foreground,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Avatar>().hash(state);
+
+        self.size.hash(state);
+    }
+}
+
+/// The renderer of an [`Avatar`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use an [`Avatar`] in your user interface.
+///
+/// [`Avatar`]: struct.Avatar.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// Draws an [`Avatar`].
+    ///
+    /// It receives:
+    /// - the bounds of the [`Avatar`]
+    /// - the image of the [`Avatar`], if any, which should be cropped into a
+    ///   circle
+    /// - the initials to display when there is no image
+    /// - the background and foreground colors of the initials
+    ///
+    /// [`Avatar`]: struct.Avatar.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        image: Option<image::Handle>,
+        initials: &str,
+        background: Color,
+        foreground: Color,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Avatar> for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn from(avatar: Avatar) -> Element<'a, Message, Renderer> {
+        Element::new(avatar)
+    }
+}
@@ -0,0 +1,304 @@
+//! Attach a small count bubble to the corner of some content.
+use crate::{
+    layout, overlay, text, Clipboard, Color, Element, Event, Focusable, Font,
+    Hasher, Layout, Length, Point, Rectangle, Size, Tree, Widget,
+};
+
+use std::hash::Hash;
+
+/// A bubble displaying a short label, like a count of notifications, on a
+/// corner of some content.
+///
+/// The bubble is centered on the chosen [`Corner`] and overflows the
+/// content, which keeps its own size in the layout.
+///
+/// # Example
+/// ```
+/// # use iced_native::{badge, renderer::Null, Avatar, Badge};
+/// #
+/// # pub type Element<'a, Message> = iced_native::Element<'a, Message, Null>;
+/// let avatar: Element<()> = Avatar::new("Ferris the Crab").into();
+///
+/// let badge = Badge::new(avatar, "3").corner(badge::Corner::BottomRight);
+/// ```
+///
+/// [`Corner`]: enum.Corner.html
+#[allow(missing_debug_implementations)]
+pub struct Badge<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    label: String,
+    corner: Corner,
+    text_size: u16,
+    background: Color,
+    foreground: Color,
+}
+
+impl<'a, Message, Renderer> Badge<'a, Message, Renderer> {
+    /// Creates a new [`Badge`] with the given content and label.
+    ///
+    /// An empty label displays a small dot.
+    ///
+    /// [`Badge`]: struct.Badge.html
+    pub fn new<T, L>(content: T, label: L) -> Self
+    where
+        T: Into<Element<'a, Message, Renderer>>,
+        L: Into<String>,
+    {
+        Badge {
+            content: content.into(),
+            label: label.into(),
+            corner: Corner::TopRight,
+            text_size: 12,
+            background: Color::from_rgb(0.91, 0.30, 0.24),
+            foreground: Color::WHITE,
+        }
+    }
+
+    /// Sets the [`Corner`] of the content the [`Badge`] is attached to.
+    ///
+    /// [`Corner`]: enum.Corner.html
+    /// [`Badge`]: struct.Badge.html
+    pub fn corner(mut self, corner: Corner) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    /// Sets the text size of the label of the [`Badge`].
+    ///
+    /// [`Badge`]: struct.Badge.html
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the background color of the bubble of the [`Badge`].
+    ///
+    /// [`Badge`]: struct.Badge.html
+    pub fn background<C: Into<Color>>(mut self, color: C) -> Self {
+        self.background = color.into();
+        self
+    }
+
+    /// Sets the color of the label of the [`Badge`].
+    ///
+    /// [`Badge`]: struct.Badge.html
+    pub fn foreground<C: Into<Color>>(mut self, color: C) -> Self {
+        self.foreground = color.into();
+        self
+    }
+}
+
+/// A corner of a rectangle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Corner {
+    /// The top-left corner.
+    TopLeft,
+
+    /// The top-right corner.
+    TopRight,
+
+    /// The bottom-left corner.
+    BottomLeft,
+
+    /// The bottom-right corner.
+    BottomRight,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Badge<'a, Message, Renderer>
+where
+    Renderer: self::Renderer + text::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self.content.layout(renderer, limits);
+        let size = content.size();
+
+        let height = if self.label.is_empty() {
+            f32::from(self.text_size) / 2.0
+        } else {
+            f32::from(self.text_size) * 1.5
+        };
+
+        let (label_width, _) = renderer.measure(
+            &self.label,
+            self.text_size,
+            Font::Default,
+            text::Params::default(),
+            Size::INFINITY,
+        );
+
+        let width = (label_width + height / 2.0).max(height);
+
+        let (x, y) = match self.corner {
+            Corner::TopLeft => (0.0, 0.0),
+            Corner::TopRight => (size.width, 0.0),
+            Corner::BottomLeft => (0.0, size.height),
+            Corner::BottomRight => (size.width, size.height),
+        };
+
+        let mut bubble = layout::Node::new(Size::new(width, height));
+        bubble.bounds.x = (x - width / 2.0).round();
+        bubble.bounds.y = (y - height / 2.0).round();
+
+        layout::Node::with_children(size, vec![content, bubble])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.content.widget.on_event(
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let mut children = layout.children();
+        let content_layout = children.next().unwrap();
+        let bubble_layout = children.next().unwrap();
+
+        let content =
+            self.content.draw(renderer, content_layout, cursor_position);
+
+        self::Renderer::draw(
+            renderer,
+            content,
+            bubble_layout.bounds(),
+            &self.label,
+            self.text_size,
+            self.background,
+            self.foreground,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Badge<'static, (), ()>>().hash(state);
+
+        self.label.hash(state);
+        self.corner.hash(state);
+        self.text_size.hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.content.diff(&mut tree.diff_children(&[None])[0]);
+    }
+
+    fn hover(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        path: &mut Vec<usize>,
+    ) {
+        super::hover_children(
+            std::slice::from_ref(&self.content),
+            layout,
+            cursor_position,
+            path,
+        );
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        super::focusables_children(
+            std::slice::from_ref(&self.content),
+            path,
+            focusables,
+        );
+    }
+
+    fn captures_mouse(&self) -> bool {
+        super::captures_mouse_children(std::slice::from_ref(&self.content))
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        super::overlay_children(std::slice::from_mut(&mut self.content), layout)
+    }
+
+    #[cfg(feature = "devtools")]
+    fn inspect(&self, layout: Layout<'_>) -> crate::inspect::Node {
+        super::inspect_children(
+            std::any::type_name::<Self>(),
+            std::slice::from_ref(&self.content),
+            &[None],
+            layout,
+        )
+    }
+}
+
+/// The renderer of a [`Badge`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Badge`] in your user interface.
+///
+/// [`Badge`]: struct.Badge.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// Draws a [`Badge`].
+    ///
+    /// It receives:
+    /// - the output of the decorated content
+    /// - the bounds of the bubble, which should be drawn on top of the
+    ///   content
+    /// - the label of the [`Badge`], which may be empty
+    /// - the text size of the label
+    /// - the background color of the bubble and the color of the label
+    ///
+    /// [`Badge`]: struct.Badge.html
+    fn draw(
+        &mut self,
+        content: Self::Output,
+        bounds: Rectangle,
+        label: &str,
+        text_size: u16,
+        background: Color,
+        foreground: Color,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Badge<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer + text::Renderer,
+    Message: 'static,
+{
+    fn from(
+        badge: Badge<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(badge)
+    }
+}
@@ -6,8 +6,10 @@
 //! [`State`]: struct.State.html
 use crate::{
     input::{mouse, ButtonState},
-    layout, Background, Clipboard, Element, Event, Hasher, Layout, Length,
-    Point, Rectangle, Widget,
+    layout, overlay,
+    tree::{self, Tree},
+    Background, Clipboard, Element, Event, Focusable, Hasher, Hover, Layout,
+    Length, Point, Rectangle, Widget,
 };
 use std::hash::Hash;
 
@@ -29,9 +31,12 @@ use std::hash::Hash;
 /// ```
 #[allow(missing_debug_implementations)]
 pub struct Button<'a, Message, Renderer> {
-    state: &'a mut State,
+    state: tree::State<'a, State>,
     content: Element<'a, Message, Renderer>,
     on_press: Option<Message>,
+    on_right_press: Option<Message>,
+    on_middle_press: Option<Message>,
+    on_hover: Option<Box<dyn Fn(bool) -> Message + 'a>>,
     width: Length,
     height: Length,
     min_width: u32,
@@ -48,6 +53,25 @@ impl<'a, Message, Renderer> Button<'a, Message, Renderer> {
     /// [`Button`]: struct.Button.html
     /// [`State`]: struct.State.html
     pub fn new<E>(state: &'a mut State, content: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Self::with_state(tree::State::Borrowed(state), content)
+    }
+
+    /// Creates a new [`Button`] with the given content, whose local [`State`]
+    /// is managed by the runtime.
+    ///
+    /// [`Button`]: struct.Button.html
+    /// [`State`]: struct.State.html
+    pub fn managed<E>(content: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Self::with_state(tree::State::managed(), content)
+    }
+
+    fn with_state<E>(state: tree::State<'a, State>, content: E) -> Self
     where
         E: Into<Element<'a, Message, Renderer>>,
     {
@@ -55,6 +79,9 @@ impl<'a, Message, Renderer> Button<'a, Message, Renderer> {
             state,
             content: content.into(),
             on_press: None,
+            on_right_press: None,
+            on_middle_press: None,
+            on_hover: None,
             width: Length::Shrink,
             height: Length::Shrink,
             min_width: 0,
@@ -129,6 +156,47 @@ impl<'a, Message, Renderer> Button<'a, Message, Renderer> {
         self.on_press = Some(msg);
         self
     }
+
+    /// Sets the message that will be produced when the [`Button`] is pressed
+    /// with the right mouse button, like to open a context menu.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn on_right_press(mut self, msg: Message) -> Self {
+        self.on_right_press = Some(msg);
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Button`] is pressed
+    /// with the middle mouse button.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn on_middle_press(mut self, msg: Message) -> Self {
+        self.on_middle_press = Some(msg);
+        self
+    }
+
+    /// Sets the message that will be produced when the cursor enters or
+    /// exits the [`Button`].
+    ///
+    /// The closure receives whether the [`Button`] is hovered.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn on_hover<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(bool) -> Message,
+    {
+        self.on_hover = Some(Box::new(f));
+        self
+    }
+
+    fn on_press_with(&self, button: mouse::Button) -> Option<&Message> {
+        match button {
+            mouse::Button::Left => self.on_press.as_ref(),
+            mouse::Button::Right => self.on_right_press.as_ref(),
+            mouse::Button::Middle => self.on_middle_press.as_ref(),
+            _ => None,
+        }
+    }
 }
 
 /// The local state of a [`Button`].
@@ -136,7 +204,7 @@ impl<'a, Message, Renderer> Button<'a, Message, Renderer> {
 /// [`Button`]: struct.Button.html
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct State {
-    is_pressed: bool,
+    pressed_button: Option<mouse::Button>,
 }
 
 impl State {
@@ -182,7 +250,7 @@ where
 
         let size = limits.resolve(content.size()).pad(padding);
 
-        layout::Node::with_children(size, vec![content])
+        layout::Node::with_children(size, vec![content]).inherit_baseline()
     }
 
     fn on_event(
@@ -194,33 +262,42 @@ where
         _renderer: &Renderer,
         _clipboard: Option<&dyn Clipboard>,
     ) {
-        match event {
-            Event::Mouse(mouse::Event::Input {
-                button: mouse::Button::Left,
-                state,
-            }) => {
-                if let Some(on_press) = self.on_press.clone() {
-                    let bounds = layout.bounds();
-
-                    match state {
-                        ButtonState::Pressed => {
-                            self.state.is_pressed =
-                                bounds.contains(cursor_position);
-                        }
-                        ButtonState::Released => {
-                            let is_clicked = self.state.is_pressed
-                                && bounds.contains(cursor_position);
+        if let Event::Hover(hover) = event {
+            if let Some(on_hover) = &self.on_hover {
+                if layout.is_hovered() {
+                    messages.push(on_hover(hover == Hover::Entered));
+                }
+            }
+        }
 
-                            self.state.is_pressed = false;
+        if let Event::Mouse(mouse::Event::Input {
+            button,
+            state: button_state,
+        }) = event
+        {
+            if let Some(on_press) = self.on_press_with(button).cloned() {
+                let mut state = self.state.borrow_mut();
+                let bounds = layout.bounds();
 
-                            if is_clicked {
+                match button_state {
+                    ButtonState::Pressed => {
+                        if state.pressed_button.is_none()
+                            && bounds.contains(cursor_position)
+                        {
+                            state.pressed_button = Some(button);
+                        }
+                    }
+                    ButtonState::Released => {
+                        if state.pressed_button == Some(button) {
+                            state.pressed_button = None;
+
+                            if bounds.contains(cursor_position) {
                                 messages.push(on_press);
                             }
                         }
                     }
                 }
             }
-            _ => {}
         }
     }
 
@@ -230,6 +307,8 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
     ) -> Renderer::Output {
+        let state = self.state.borrow();
+
         let content = self.content.draw(
             renderer,
             layout.children().next().unwrap(),
@@ -238,8 +317,8 @@ where
 
         renderer.draw(
             layout.bounds(),
-            cursor_position,
-            self.state.is_pressed,
+            layout.is_hovered(),
+            state.pressed_button.is_some(),
             self.background,
             self.border_radius,
             content,
@@ -250,6 +329,58 @@ where
         self.width.hash(state);
         self.content.hash_layout(state);
     }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.state.diff(tree);
+        self.content.diff(&mut tree.diff_children(&[None])[0]);
+    }
+
+    #[cfg(feature = "devtools")]
+    fn inspect(&self, layout: Layout<'_>) -> crate::inspect::Node {
+        super::inspect_children(
+            std::any::type_name::<Self>(),
+            std::slice::from_ref(&self.content),
+            &[None],
+            layout,
+        )
+    }
+
+    fn hover(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        path: &mut Vec<usize>,
+    ) {
+        super::hover_children(
+            std::slice::from_ref(&self.content),
+            layout,
+            cursor_position,
+            path,
+        );
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        super::focusables_children(
+            std::slice::from_ref(&self.content),
+            path,
+            focusables,
+        );
+    }
+
+    fn captures_mouse(&self) -> bool {
+        super::captures_mouse_children(std::slice::from_ref(&self.content))
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        super::overlay_children(std::slice::from_mut(&mut self.content), layout)
+    }
 }
 
 /// The renderer of a [`Button`].
@@ -266,7 +397,7 @@ pub trait Renderer: crate::Renderer + Sized {
     fn draw(
         &mut self,
         bounds: Rectangle,
-        cursor_position: Point,
+        is_mouse_over: bool,
         is_pressed: bool,
         background: Option<Background>,
         border_radius: u16,
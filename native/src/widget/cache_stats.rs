@@ -0,0 +1,154 @@
+//! Inspect the texture caches of a renderer.
+use std::hash::Hash;
+
+use crate::{
+    layout, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+/// A debug view showing statistics about the glyph and image caches of the
+/// renderer, like their size and how often they grow or evict entries.
+///
+/// It also draws the glyph atlas, with every packed glyph outlined. Keeping
+/// the atlas around has a cost, so it is only tracked from the first time the
+/// view is drawn.
+///
+/// It can be useful to diagnose text cache thrashing, or images that keep
+/// being uploaded and evicted.
+///
+/// # Example
+///
+/// ```
+/// use iced_native::{CacheStats, Length};
+///
+/// let stats = CacheStats::new().width(Length::Units(300));
+/// ```
+#[derive(Debug)]
+pub struct CacheStats {
+    width: Length,
+    height: Length,
+}
+
+impl CacheStats {
+    /// Creates a new [`CacheStats`].
+    ///
+    /// [`CacheStats`]: struct.CacheStats.html
+    pub fn new() -> Self {
+        CacheStats {
+            width: Length::Units(240),
+            height: Length::Units(160),
+        }
+    }
+
+    /// Sets the width of the [`CacheStats`].
+    ///
+    /// [`CacheStats`]: struct.CacheStats.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`CacheStats`].
+    ///
+    /// [`CacheStats`]: struct.CacheStats.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+}
+
+impl Default for CacheStats {
+    fn default() -> Self {
+        CacheStats::new()
+    }
+}
+
+/// The state of the texture caches of a renderer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Stats {
+    /// The dimensions of the texture pages holding the rasterized glyphs.
+    pub glyph_page: (u32, u32),
+
+    /// The amount of times the glyph cache has grown since startup.
+    pub glyph_page_resizes: u32,
+
+    /// The amount of images currently cached.
+    pub images: usize,
+
+    /// The amount of cached images that live in device memory.
+    pub uploaded_images: usize,
+
+    /// The amount of pixel data of the cached images, in bytes.
+    pub image_bytes: u64,
+
+    /// The amount of images evicted from the cache since startup.
+    pub evicted_images: usize,
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for CacheStats
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        layout::Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> Renderer::Output {
+        let stats = renderer.stats();
+
+        renderer.draw(layout.bounds(), &stats)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<CacheStats>().hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`CacheStats`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`CacheStats`] in your user interface.
+///
+/// [`CacheStats`]: struct.CacheStats.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// Returns the current [`Stats`] of the texture caches.
+    ///
+    /// [`Stats`]: struct.Stats.html
+    fn stats(&self) -> Stats;
+
+    /// Draws a [`CacheStats`] showing the given [`Stats`].
+    ///
+    /// [`CacheStats`]: struct.CacheStats.html
+    /// [`Stats`]: struct.Stats.html
+    fn draw(&mut self, bounds: Rectangle, stats: &Stats) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<CacheStats> for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn from(cache_stats: CacheStats) -> Element<'a, Message, Renderer> {
+        Element::new(cache_stats)
+    }
+}
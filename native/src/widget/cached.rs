@@ -0,0 +1,331 @@
+//! Reuse the output of static content between frames.
+//!
+//! A [`Cached`] widget has some local [`State`].
+//!
+//! [`Cached`]: struct.Cached.html
+//! [`State`]: struct.State.html
+use crate::{
+    layout, overlay,
+    tree::{self, Tree},
+    Clipboard, Element, Event, Focusable, Hasher, Layout, Length, Point,
+    Rectangle, Widget,
+};
+
+use std::{
+    cell::RefCell,
+    hash::{Hash, Hasher as _},
+};
+
+/// An element that stores the output of its content and reuses it while the
+/// content does not change.
+///
+/// The content is considered unchanged as long as its layout hash, its
+/// bounds and the [`key`] of the [`Cached`] widget stay the same. Properties
+/// that do not affect layout, like colors, should be hashed into the
+/// [`key`].
+///
+/// Cached content does not react visually to the mouse cursor. It is meant
+/// for static parts of your user interface, like sidebars and headers. The
+/// content keeps receiving events normally.
+///
+/// # Example
+/// ```
+/// # use iced_native::{renderer::Null, Text};
+/// #
+/// # type Cached<'a, Message> = iced_native::Cached<'a, Message, Null>;
+/// let header: Cached<()> =
+///     Cached::managed(Text::new("An expensive header")).key(1);
+/// ```
+///
+/// [`Cached`]: struct.Cached.html
+/// [`key`]: #method.key
+#[allow(missing_debug_implementations)]
+pub struct Cached<'a, Message, Renderer: crate::Renderer> {
+    state: tree::State<'a, State<Renderer::Output>>,
+    content: Element<'a, Message, Renderer>,
+    key: u64,
+}
+
+impl<'a, Message, Renderer> Cached<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Output: 'static,
+{
+    /// Creates a new [`Cached`] widget with some local [`State`] and the
+    /// given content.
+    ///
+    /// [`Cached`]: struct.Cached.html
+    /// [`State`]: struct.State.html
+    pub fn new<E>(state: &'a mut State<Renderer::Output>, content: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Self::with_state(tree::State::Borrowed(state), content)
+    }
+
+    /// Creates a new [`Cached`] widget with the given content, whose local
+    /// [`State`] is managed by the runtime.
+    ///
+    /// [`Cached`]: struct.Cached.html
+    /// [`State`]: struct.State.html
+    pub fn managed<E>(content: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Self::with_state(tree::State::managed(), content)
+    }
+
+    /// Creates a new [`Cached`] widget with the given content, whose local
+    /// [`State`] is shared through the given [`Link`].
+    ///
+    /// Other widgets, like a [`MiniMap`], can use the same [`Link`] to reuse
+    /// the cached output.
+    ///
+    /// [`Cached`]: struct.Cached.html
+    /// [`State`]: struct.State.html
+    /// [`Link`]: ../../tree/struct.Link.html
+    /// [`MiniMap`]: ../minimap/struct.MiniMap.html
+    pub fn linked<E>(
+        link: &tree::Link<State<Renderer::Output>>,
+        content: E,
+    ) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Self::with_state(link.into(), content)
+    }
+
+    fn with_state<E>(
+        state: tree::State<'a, State<Renderer::Output>>,
+        content: E,
+    ) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Cached {
+            state,
+            content: content.into(),
+            key: 0,
+        }
+    }
+
+    /// Sets a key that invalidates the cached output when it changes.
+    ///
+    /// [`Cached`]: struct.Cached.html
+    pub fn key<K: Hash>(mut self, key: K) -> Self {
+        self.key = crate::hasher::hash(&key);
+        self
+    }
+}
+
+/// The local state of a [`Cached`] widget.
+///
+/// [`Cached`]: struct.Cached.html
+pub struct State<Output> {
+    entry: RefCell<Option<Entry<Output>>>,
+}
+
+struct Entry<Output> {
+    hash: u64,
+    bounds: Rectangle,
+    output: Output,
+}
+
+impl<Output> State<Output> {
+    /// Creates a new empty [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        State {
+            entry: RefCell::new(None),
+        }
+    }
+
+    /// Discards the cached output, forcing the content to be drawn again.
+    pub fn clear(&mut self) {
+        *self.entry.get_mut() = None;
+    }
+
+    /// Returns the cached output and the bounds it was drawn in, if any.
+    pub fn output(&self) -> Option<(Output, Rectangle)>
+    where
+        Output: Clone,
+    {
+        self.entry
+            .borrow()
+            .as_ref()
+            .map(|entry| (entry.output.clone(), entry.bounds))
+    }
+}
+
+impl<Output> Default for State<Output> {
+    fn default() -> Self {
+        State::new()
+    }
+}
+
+impl<Output> std::fmt::Debug for State<Output> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("State")
+            .field("is_cached", &self.entry.borrow().is_some())
+            .finish()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Cached<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+    Renderer::Output: Clone + 'static,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.content.widget.on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let state = self.state.borrow();
+        let mut entry = state.entry.borrow_mut();
+
+        let bounds = layout.bounds();
+        let hash = {
+            let mut hasher = Hasher::default();
+            self.key.hash(&mut hasher);
+            self.content.hash_layout(&mut hasher);
+
+            hasher.finish()
+        };
+
+        if let Some(entry) = entry.as_ref() {
+            if entry.hash == hash && entry.bounds == bounds {
+                return entry.output.clone();
+            }
+        }
+
+        let output = self.content.draw(renderer, layout, cursor_position);
+        let output = renderer.cache(output);
+
+        *entry = Some(Entry {
+            hash,
+            bounds,
+            output: output.clone(),
+        });
+
+        output
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        self.content.hash_layout(state);
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.state.diff(tree);
+        self.content.diff(&mut tree.diff_children(&[None])[0]);
+    }
+
+    fn hover(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        path: &mut Vec<usize>,
+    ) {
+        self.content.hover(layout, cursor_position, path);
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        self.content.focusables(path, focusables);
+    }
+
+    fn captures_mouse(&self) -> bool {
+        self.content.captures_mouse()
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout)
+    }
+
+    #[cfg(feature = "devtools")]
+    fn inspect(&self, layout: Layout<'_>) -> crate::inspect::Node {
+        crate::inspect::Node {
+            children: vec![self.content.inspect(layout)],
+            ..crate::inspect::Node::new(
+                std::any::type_name::<Self>(),
+                layout.bounds(),
+            )
+        }
+    }
+}
+
+/// The renderer of a [`Cached`] widget.
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Cached`] widget in your user interface.
+///
+/// [`Cached`]: struct.Cached.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// Prepares the output of some content to be stored by a [`Cached`]
+    /// widget.
+    ///
+    /// The returned output will be cloned every frame while it is reused, so
+    /// cloning it should be cheap.
+    ///
+    /// [`Cached`]: struct.Cached.html
+    fn cache(&mut self, output: Self::Output) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Cached<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Renderer::Output: Clone + 'static,
+    Message: 'static,
+{
+    fn from(
+        cached: Cached<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(cached)
+    }
+}
@@ -2,8 +2,8 @@
 use std::hash::Hash;
 
 use crate::{
-    layout, Align, Clipboard, Element, Event, Hasher, Layout, Length, Point,
-    Widget,
+    layout, overlay, Align, Clipboard, Element, Event, Focusable, Hasher,
+    Layout, Length, Point, Rectangle, Tree, Widget,
 };
 
 use std::u32;
@@ -23,6 +23,7 @@ pub struct Column<'a, Message, Renderer> {
     max_height: u32,
     align_items: Align,
     children: Vec<Element<'a, Message, Renderer>>,
+    keys: Vec<Option<u64>>,
 }
 
 impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
@@ -39,9 +40,28 @@ impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
             max_height: u32::MAX,
             align_items: Align::Start,
             children: Vec::new(),
+            keys: Vec::new(),
         }
     }
 
+    /// Creates a [`Column`] with the given keyed children.
+    ///
+    /// It is equivalent to calling [`push_keyed`] for every child.
+    ///
+    /// [`Column`]: struct.Column.html
+    /// [`push_keyed`]: #method.push_keyed
+    pub fn with_keys<K, E>(children: impl IntoIterator<Item = (K, E)>) -> Self
+    where
+        K: Hash,
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        children
+            .into_iter()
+            .fold(Self::new(), |column, (key, child)| {
+                column.push_keyed(key, child)
+            })
+    }
+
     /// Sets the vertical spacing _between_ elements.
     ///
     /// Custom margins per element do not exist in Iced. You should use this
@@ -108,8 +128,43 @@ impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
         E: Into<Element<'a, Message, Renderer>>,
     {
         self.children.push(child.into());
+        self.keys.push(None);
         self
     }
+
+    /// Adds an element to the [`Column`] identified by the given key.
+    ///
+    /// Keys identify children across different views, so they can be
+    /// matched by identity instead of by position when the children of the
+    /// [`Column`] are reordered or filtered.
+    ///
+    /// Keys should be unique among the children of the same [`Column`].
+    ///
+    /// [`Column`]: struct.Column.html
+    pub fn push_keyed<K, E>(mut self, key: K, child: E) -> Self
+    where
+        K: Hash,
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.children.push(child.into());
+        self.keys.push(Some(crate::hasher::hash(&key)));
+        self
+    }
+
+    /// Returns the bounds of the child with the given hashed key, given the
+    /// [`Layout`] of the [`Column`].
+    ///
+    /// [`Layout`]: ../../struct.Layout.html
+    /// [`Column`]: struct.Column.html
+    pub(crate) fn child_bounds(
+        &self,
+        key: u64,
+        layout: Layout<'_>,
+    ) -> Option<Rectangle> {
+        let index = self.keys.iter().position(|k| *k == Some(key))?;
+
+        layout.children().nth(index).map(|child| child.bounds())
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -159,7 +214,7 @@ where
         self.children.iter_mut().zip(layout.children()).for_each(
             |(child, layout)| {
                 child.widget.on_event(
-                    event,
+                    event.clone(),
                     layout,
                     cursor_position,
                     messages,
@@ -188,10 +243,57 @@ where
         self.align_items.hash(state);
         self.spacing.hash(state);
 
-        for child in &self.children {
+        for (child, key) in self.children.iter().zip(&self.keys) {
+            key.hash(state);
             child.widget.hash_layout(state);
         }
     }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        let trees = tree.diff_children(&self.keys);
+
+        for (child, tree) in self.children.iter_mut().zip(trees) {
+            child.diff(tree);
+        }
+    }
+
+    #[cfg(feature = "devtools")]
+    fn inspect(&self, layout: Layout<'_>) -> crate::inspect::Node {
+        super::inspect_children(
+            std::any::type_name::<Self>(),
+            &self.children,
+            &self.keys,
+            layout,
+        )
+    }
+
+    fn hover(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        path: &mut Vec<usize>,
+    ) {
+        super::hover_children(&self.children, layout, cursor_position, path);
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        super::focusables_children(&self.children, path, focusables);
+    }
+
+    fn captures_mouse(&self) -> bool {
+        super::captures_mouse_children(&self.children)
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        super::overlay_children(&mut self.children, layout)
+    }
 }
 
 /// The renderer of a [`Column`].
@@ -0,0 +1,792 @@
+//! Pick an option from a list filtered by what is typed, or type your own.
+//!
+//! A [`ComboBox`] has some local [`State`].
+//!
+//! [`ComboBox`]: struct.ComboBox.html
+//! [`State`]: struct.State.html
+use crate::{
+    input::{keyboard, mouse, ButtonState},
+    layout, overlay, text_input,
+    tree::{self, Tree},
+    Clipboard, Element, Event, Focusable, Hasher, Layout, Length, Point,
+    Rectangle, Size, TextInput, Widget,
+};
+
+use std::borrow::Cow;
+
+// The space around the label of every option in the menu
+const MENU_PADDING: f32 = 5.0;
+
+type Filter<'a, T> = Box<dyn Fn(&str, &T) -> Option<u32> + 'a>;
+
+/// A [`TextInput`] suggesting options that match what is typed in a menu
+/// below it.
+///
+/// While the [`ComboBox`] has the focus, the menu shows the options accepted
+/// by its filter, best matches first. The arrow keys move through them,
+/// `Enter` picks the highlighted one, and `Escape` closes the menu. Clicking
+/// an option picks it too.
+///
+/// Pressing `Enter` without a highlighted option submits the typed text
+/// instead, so the [`ComboBox`] can also take values that are not in the
+/// list.
+///
+/// By default, options are matched with [`fuzzy`]. You can provide your own
+/// matching logic with [`ComboBox::filter`].
+///
+/// # Example
+/// ```
+/// # use iced_native::{combo_box, ComboBox};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     FontTyped(String),
+///     FontPicked(&'static str),
+///     FontEntered(String),
+/// }
+///
+/// let fonts = ["Fira Sans", "Inter", "Iosevka", "Lato"];
+/// let query = "is";
+///
+/// let mut state = combo_box::State::new();
+///
+/// let combo_box = ComboBox::new(
+///     &mut state,
+///     "Font family",
+///     query,
+///     &fonts[..],
+///     Message::FontTyped,
+///     Message::FontPicked,
+/// )
+/// .on_submit(Message::FontEntered);
+/// ```
+///
+/// [`TextInput`]: ../text_input/struct.TextInput.html
+/// [`ComboBox`]: struct.ComboBox.html
+/// [`fuzzy`]: fn.fuzzy.html
+/// [`ComboBox::filter`]: struct.ComboBox.html#method.filter
+#[allow(missing_debug_implementations)]
+pub struct ComboBox<'a, T, Message>
+where
+    [T]: ToOwned<Owned = Vec<T>>,
+{
+    state: tree::State<'a, Dropdown>,
+    input: TextInput<'a, Message>,
+    query: String,
+    options: Cow<'a, [T]>,
+    filter: Filter<'a, T>,
+    on_select: Box<dyn Fn(T) -> Message + 'a>,
+    on_submit: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    size: Option<u16>,
+    max_visible: usize,
+}
+
+impl<'a, T, Message> ComboBox<'a, T, Message>
+where
+    T: ToString + Clone,
+    [T]: ToOwned<Owned = Vec<T>>,
+{
+    /// Creates a new [`ComboBox`].
+    ///
+    /// It expects:
+    /// - some [`State`]
+    /// - a placeholder
+    /// - the typed text
+    /// - the options to suggest
+    /// - a function that produces a message when the typed text changes
+    /// - a function that produces a message when an option is picked
+    ///
+    /// [`ComboBox`]: struct.ComboBox.html
+    /// [`State`]: struct.State.html
+    pub fn new<F, G>(
+        state: &'a mut State,
+        placeholder: &str,
+        query: &str,
+        options: impl Into<Cow<'a, [T]>>,
+        on_change: F,
+        on_select: G,
+    ) -> Self
+    where
+        F: 'static + Fn(String) -> Message,
+        G: 'a + Fn(T) -> Message,
+    {
+        let State { input, dropdown } = state;
+
+        Self::with_state(
+            tree::State::Borrowed(dropdown),
+            TextInput::new(input, placeholder, query, on_change),
+            query,
+            options.into(),
+            on_select,
+        )
+    }
+
+    /// Creates a new [`ComboBox`] whose local [`State`] is managed by the
+    /// runtime.
+    ///
+    /// [`ComboBox`]: struct.ComboBox.html
+    /// [`State`]: struct.State.html
+    pub fn managed<F, G>(
+        placeholder: &str,
+        query: &str,
+        options: impl Into<Cow<'a, [T]>>,
+        on_change: F,
+        on_select: G,
+    ) -> Self
+    where
+        F: 'static + Fn(String) -> Message,
+        G: 'a + Fn(T) -> Message,
+    {
+        Self::with_state(
+            tree::State::managed(),
+            TextInput::managed(placeholder, query, on_change),
+            query,
+            options.into(),
+            on_select,
+        )
+    }
+
+    fn with_state<G>(
+        state: tree::State<'a, Dropdown>,
+        input: TextInput<'a, Message>,
+        query: &str,
+        options: Cow<'a, [T]>,
+        on_select: G,
+    ) -> Self
+    where
+        G: 'a + Fn(T) -> Message,
+    {
+        ComboBox {
+            state,
+            input,
+            query: query.to_owned(),
+            options,
+            filter: Box::new(|query, option: &T| {
+                fuzzy(query, &option.to_string())
+            }),
+            on_select: Box::new(on_select),
+            on_submit: None,
+            size: None,
+            max_visible: 8,
+        }
+    }
+
+    /// Sets the message that should be produced when `Enter` is pressed
+    /// without a highlighted option, given the typed text.
+    pub fn on_submit<F>(mut self, on_submit: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        self.on_submit = Some(Box::new(on_submit));
+        self
+    }
+
+    /// Sets the function matching an option against the typed text.
+    ///
+    /// It returns the score of the option, or `None` to leave it out of the
+    /// menu. Options with higher scores are shown first.
+    pub fn filter<F>(mut self, filter: F) -> Self
+    where
+        F: 'a + Fn(&str, &T) -> Option<u32>,
+    {
+        self.filter = Box::new(filter);
+        self
+    }
+
+    /// Sets the maximum amount of options visible at once in the menu.
+    ///
+    /// The menu scrolls to show the rest.
+    pub fn max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = max_visible.max(1);
+        self
+    }
+
+    /// Sets the width of the [`ComboBox`].
+    ///
+    /// [`ComboBox`]: struct.ComboBox.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.input = self.input.width(width);
+        self
+    }
+
+    /// Sets the padding of the [`ComboBox`].
+    ///
+    /// [`ComboBox`]: struct.ComboBox.html
+    pub fn padding(mut self, units: u16) -> Self {
+        self.input = self.input.padding(units);
+        self
+    }
+
+    /// Sets the text size of the [`ComboBox`] and its menu.
+    ///
+    /// [`ComboBox`]: struct.ComboBox.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.input = self.input.size(size);
+        self.size = Some(size);
+        self
+    }
+
+    /// Returns the indices of the options matching the typed text, best
+    /// matches first.
+    fn matches(&self) -> Vec<usize> {
+        let mut scored: Vec<(usize, u32)> = self
+            .options
+            .iter()
+            .enumerate()
+            .filter_map(|(index, option)| {
+                (self.filter)(&self.query, option).map(|score| (index, score))
+            })
+            .collect();
+
+        // Stable, so options with the same score keep their order
+        scored.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+        scored.into_iter().map(|(index, _)| index).collect()
+    }
+
+    /// Returns the [`Dropdown`] for the current typed text.
+    ///
+    /// Typing something new resets the highlighted option and reopens the
+    /// menu.
+    ///
+    /// [`Dropdown`]: struct.Dropdown.html
+    fn dropdown(&self) -> Dropdown {
+        let dropdown = self.state.borrow();
+
+        if dropdown.query == self.query {
+            dropdown.clone()
+        } else {
+            Dropdown {
+                query: self.query.clone(),
+                ..Dropdown::default()
+            }
+        }
+    }
+
+    /// Returns whether the menu is open, given the matches and the
+    /// [`Dropdown`].
+    ///
+    /// [`Dropdown`]: struct.Dropdown.html
+    fn is_open(&self, matches: &[usize], dropdown: &Dropdown) -> bool {
+        self.input.is_focused()
+            && !dropdown.is_dismissed
+            && dropdown.offset < matches.len()
+    }
+
+    /// Highlights the match at the given index, scrolling the menu to show
+    /// it.
+    fn highlight(&self, dropdown: &mut Dropdown, index: Option<usize>) {
+        dropdown.highlighted = index;
+
+        if let Some(index) = index {
+            if index < dropdown.offset {
+                dropdown.offset = index;
+            } else if index >= dropdown.offset + self.max_visible {
+                dropdown.offset = index + 1 - self.max_visible;
+            }
+        }
+    }
+}
+
+/// The local state of a [`ComboBox`].
+///
+/// [`ComboBox`]: struct.ComboBox.html
+#[derive(Debug, Default, Clone)]
+pub struct State {
+    input: text_input::State,
+    dropdown: Dropdown,
+}
+
+impl State {
+    /// Creates a new [`State`], representing an unfocused [`ComboBox`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`ComboBox`]: struct.ComboBox.html
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Creates a new [`State`], representing a focused [`ComboBox`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`ComboBox`]: struct.ComboBox.html
+    pub fn focused() -> Self {
+        State {
+            input: text_input::State::focused(),
+            dropdown: Dropdown::default(),
+        }
+    }
+
+    /// Returns whether the [`ComboBox`] is currently focused or not.
+    ///
+    /// [`ComboBox`]: struct.ComboBox.html
+    pub fn is_focused(&self) -> bool {
+        self.input.is_focused()
+    }
+}
+
+/// The state of the menu of a [`ComboBox`].
+///
+/// [`ComboBox`]: struct.ComboBox.html
+#[derive(Debug, Default, Clone, PartialEq)]
+struct Dropdown {
+    query: String,
+    highlighted: Option<usize>,
+    offset: usize,
+    is_dismissed: bool,
+}
+
+/// The menu of options of a [`ComboBox`].
+///
+/// [`ComboBox`]: struct.ComboBox.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Menu {
+    /// The bounds of the [`Menu`].
+    ///
+    /// [`Menu`]: struct.Menu.html
+    pub bounds: Rectangle,
+
+    /// The labels of the visible options, from top to bottom.
+    pub options: Vec<String>,
+
+    /// The index of the highlighted option among the visible ones, if any.
+    pub highlighted: Option<usize>,
+
+    /// The height of each option.
+    pub item_height: f32,
+
+    /// The text size of the options.
+    pub text_size: u16,
+}
+
+impl Menu {
+    /// Returns the bounds of the option with the given index.
+    pub fn item_bounds(&self, index: usize) -> Rectangle {
+        Rectangle {
+            y: self.bounds.y + index as f32 * self.item_height,
+            height: self.item_height,
+            ..self.bounds
+        }
+    }
+
+    /// Returns the index of the option at the given point, if any.
+    pub fn item_at(&self, point: Point) -> Option<usize> {
+        if !self.bounds.contains(point) {
+            return None;
+        }
+
+        let index = ((point.y - self.bounds.y) / self.item_height) as usize;
+
+        Some(index.min(self.options.len().saturating_sub(1)))
+    }
+}
+
+// The menu of a `ComboBox`, floating below its input
+struct Options<'a, 'b, T, Message> {
+    state: &'b mut tree::State<'a, Dropdown>,
+    dropdown: Dropdown,
+    options: &'b [T],
+    matches: Vec<usize>,
+    on_select: &'b dyn Fn(T) -> Message,
+    size: Option<u16>,
+    max_visible: usize,
+    width: f32,
+    input_height: f32,
+}
+
+impl<'a, 'b, T, Message> Options<'a, 'b, T, Message>
+where
+    T: ToString + Clone,
+{
+    fn visible(&self) -> &[usize] {
+        let start = self.dropdown.offset;
+        let end = (start + self.max_visible).min(self.matches.len());
+
+        &self.matches[start..end]
+    }
+
+    fn text_size<Renderer>(&self, renderer: &Renderer) -> u16
+    where
+        Renderer: self::Renderer,
+    {
+        self.size.unwrap_or(renderer.default_size())
+    }
+
+    fn menu(&self, bounds: Rectangle, text_size: u16) -> Menu {
+        Menu {
+            bounds,
+            options: self
+                .visible()
+                .iter()
+                .map(|index| self.options[*index].to_string())
+                .collect(),
+            highlighted: self.dropdown.highlighted.and_then(|highlighted| {
+                highlighted.checked_sub(self.dropdown.offset)
+            }),
+            item_height: f32::from(text_size) + 2.0 * MENU_PADDING,
+            text_size,
+        }
+    }
+}
+
+impl<'a, 'b, T, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Options<'a, 'b, T, Message>
+where
+    T: ToString + Clone,
+    Renderer: self::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let item_height =
+            f32::from(self.text_size(renderer)) + 2.0 * MENU_PADDING;
+        let height = item_height * self.visible().len() as f32;
+
+        // Open upwards when the menu does not fit below the input, as long
+        // as it fits above
+        let above = position.y - self.input_height - height;
+
+        let y = if position.y + height > bounds.height && above >= 0.0 {
+            above
+        } else {
+            position.y
+        };
+
+        let mut node = layout::Node::new(Size::new(self.width, height));
+        node.move_to(Point::new(position.x, y));
+
+        node
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let menu = self.menu(layout.bounds(), self.text_size(renderer));
+
+        self::Renderer::draw(renderer, cursor_position, &menu)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> overlay::Status {
+        let menu = self.menu(layout.bounds(), self.text_size(renderer));
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(index) = menu.item_at(cursor_position) {
+                    self.dropdown.highlighted =
+                        Some(self.dropdown.offset + index);
+                }
+            }
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) => {
+                if let Some(index) = menu.item_at(cursor_position) {
+                    let option = &self.options
+                        [self.matches[self.dropdown.offset + index]];
+
+                    messages.push((self.on_select)(option.clone()));
+                    self.dropdown.is_dismissed = true;
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { pixels, .. })
+                if menu.bounds.contains(cursor_position) =>
+            {
+                let last = self.matches.len().saturating_sub(self.max_visible);
+
+                self.dropdown.offset = if pixels.y > 0.0 {
+                    self.dropdown.offset.saturating_sub(1)
+                } else if pixels.y < 0.0 {
+                    (self.dropdown.offset + 1).min(last)
+                } else {
+                    self.dropdown.offset
+                };
+            }
+            _ => {}
+        }
+
+        *self.state.borrow_mut() = self.dropdown.clone();
+
+        // Clicks and scrolls over the menu never reach the input anyway
+        overlay::Status::Ignored
+    }
+}
+
+/// Matches an option against some typed text, fuzzily.
+///
+/// The characters of the typed text must appear in the option in the same
+/// order, ignoring case, but not necessarily next to each other. The score
+/// rewards consecutive characters and characters starting a word, so `ios`
+/// ranks `Iosevka` above `Fira Sans Condensed`.
+///
+/// Empty text matches every option with a score of zero.
+pub fn fuzzy(query: &str, option: &str) -> Option<u32> {
+    let mut score = 0;
+    let mut candidates = option.chars().flat_map(char::to_lowercase);
+    let mut previous = None;
+    let mut is_consecutive = true;
+
+    for wanted in query.chars().flat_map(char::to_lowercase) {
+        loop {
+            let current = candidates.next()?;
+            let is_word_start = previous
+                .map(|previous: char| !previous.is_alphanumeric())
+                .unwrap_or(true);
+
+            previous = Some(current);
+
+            if current == wanted {
+                score += 1;
+
+                if is_consecutive {
+                    score += 2;
+                }
+
+                if is_word_start {
+                    score += 3;
+                }
+
+                is_consecutive = true;
+                break;
+            }
+
+            is_consecutive = false;
+        }
+    }
+
+    Some(score)
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for ComboBox<'a, T, Message>
+where
+    T: ToString + Clone,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: self::Renderer,
+    Message: Clone + std::fmt::Debug,
+{
+    fn width(&self) -> Length {
+        Widget::<Message, Renderer>::width(&self.input)
+    }
+
+    fn height(&self) -> Length {
+        Widget::<Message, Renderer>::height(&self.input)
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.input.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let matches = self.matches();
+        let mut dropdown = self.dropdown();
+        let is_open = self.is_open(&matches, &dropdown);
+
+        // Picking and scrolling options happens in the menu overlay
+        let is_handled = match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) => {
+                if layout.bounds().contains(cursor_position) {
+                    dropdown.is_dismissed = false;
+                }
+
+                false
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                state: ButtonState::Pressed,
+                key_code,
+                ..
+            }) if self.input.is_focused() => match key_code {
+                keyboard::KeyCode::Down if !matches.is_empty() => {
+                    let next = if !is_open {
+                        dropdown.highlighted
+                    } else {
+                        Some(
+                            dropdown
+                                .highlighted
+                                .map(|index| index + 1)
+                                .unwrap_or(0)
+                                .min(matches.len() - 1),
+                        )
+                    };
+
+                    dropdown.is_dismissed = false;
+                    self.highlight(&mut dropdown, next);
+
+                    true
+                }
+                keyboard::KeyCode::Up if is_open => {
+                    let previous = dropdown
+                        .highlighted
+                        .and_then(|index| index.checked_sub(1));
+
+                    self.highlight(&mut dropdown, previous);
+
+                    true
+                }
+                keyboard::KeyCode::Enter | keyboard::KeyCode::NumpadEnter => {
+                    match (is_open, dropdown.highlighted) {
+                        (true, Some(index)) => {
+                            let option = &self.options[matches[index]];
+
+                            messages.push((self.on_select)(option.clone()));
+                        }
+                        _ => {
+                            if let Some(on_submit) = &self.on_submit {
+                                messages.push(on_submit(self.query.clone()));
+                            }
+                        }
+                    }
+
+                    dropdown.is_dismissed = true;
+
+                    true
+                }
+                keyboard::KeyCode::Escape if is_open => {
+                    dropdown.is_dismissed = true;
+
+                    true
+                }
+                _ => false,
+            },
+            _ => false,
+        };
+
+        *self.state.borrow_mut() = dropdown;
+
+        if !is_handled {
+            self.input.on_event(
+                event,
+                layout,
+                cursor_position,
+                messages,
+                renderer,
+                clipboard,
+            );
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.input.draw(renderer, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        Widget::<Message, Renderer>::hash_layout(&self.input, state)
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.state.diff(tree);
+
+        Widget::<Message, Renderer>::diff(
+            &mut self.input,
+            &mut tree.diff_children(&[None])[0],
+        );
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        Widget::<Message, Renderer>::focusables(&self.input, path, focusables)
+    }
+
+    fn captures_mouse(&self) -> bool {
+        Widget::<Message, Renderer>::captures_mouse(&self.input)
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let matches = self.matches();
+        let dropdown = self.dropdown();
+
+        if !self.is_open(&matches, &dropdown) {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        Some(overlay::Element::new(
+            Point::new(bounds.x, bounds.y + bounds.height),
+            Box::new(Options {
+                state: &mut self.state,
+                dropdown,
+                options: &self.options,
+                matches,
+                on_select: &*self.on_select,
+                size: self.size,
+                max_visible: self.max_visible,
+                width: bounds.width,
+                input_height: bounds.height,
+            }),
+        ))
+    }
+}
+
+/// The renderer of a [`ComboBox`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`ComboBox`] in your user interface.
+///
+/// [`ComboBox`]: struct.ComboBox.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text_input::Renderer {
+    /// Draws the open [`Menu`] of a [`ComboBox`].
+    ///
+    /// The [`Menu`] floats over the user interface, so it is drawn on its
+    /// own, after the [`TextInput`] of the [`ComboBox`].
+    ///
+    /// [`Menu`]: struct.Menu.html
+    /// [`ComboBox`]: struct.ComboBox.html
+    /// [`TextInput`]: ../text_input/struct.TextInput.html
+    fn draw(&mut self, cursor_position: Point, menu: &Menu) -> Self::Output;
+}
+
+impl<'a, T, Message, Renderer> From<ComboBox<'a, T, Message>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + ToString + Clone,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: 'a + self::Renderer,
+    Message: 'static + Clone + std::fmt::Debug,
+{
+    fn from(
+        combo_box: ComboBox<'a, T, Message>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(combo_box)
+    }
+}
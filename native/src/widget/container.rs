@@ -2,8 +2,8 @@
 use std::hash::Hash;
 
 use crate::{
-    layout, Align, Clipboard, Element, Event, Hasher, Layout, Length, Point,
-    Widget,
+    layout, overlay, Align, Clipboard, Element, Event, Focusable, Hasher,
+    Hover, Layout, Length, Point, Tree, Widget,
 };
 
 use std::u32;
@@ -20,6 +20,7 @@ pub struct Container<'a, Message, Renderer> {
     horizontal_alignment: Align,
     vertical_alignment: Align,
     content: Element<'a, Message, Renderer>,
+    on_hover: Option<Box<dyn Fn(bool) -> Message + 'a>>,
 }
 
 impl<'a, Message, Renderer> Container<'a, Message, Renderer> {
@@ -38,6 +39,7 @@ impl<'a, Message, Renderer> Container<'a, Message, Renderer> {
             horizontal_alignment: Align::Start,
             vertical_alignment: Align::Start,
             content: content.into(),
+            on_hover: None,
         }
     }
 
@@ -90,6 +92,20 @@ impl<'a, Message, Renderer> Container<'a, Message, Renderer> {
 
         self
     }
+
+    /// Sets the message that will be produced when the cursor enters or
+    /// exits the [`Container`].
+    ///
+    /// The closure receives whether the [`Container`] is hovered.
+    ///
+    /// [`Container`]: struct.Container.html
+    pub fn on_hover<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(bool) -> Message,
+    {
+        self.on_hover = Some(Box::new(f));
+        self
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -122,7 +138,7 @@ where
 
         content.align(self.horizontal_alignment, self.vertical_alignment, size);
 
-        layout::Node::with_children(size, vec![content])
+        layout::Node::with_children(size, vec![content]).inherit_baseline()
     }
 
     fn on_event(
@@ -134,6 +150,14 @@ where
         renderer: &Renderer,
         clipboard: Option<&dyn Clipboard>,
     ) {
+        if let Event::Hover(hover) = event {
+            if let Some(on_hover) = &self.on_hover {
+                if layout.is_hovered() {
+                    messages.push(on_hover(hover == Hover::Entered));
+                }
+            }
+        }
+
         self.content.widget.on_event(
             event,
             layout.children().next().unwrap(),
@@ -166,6 +190,57 @@ where
 
         self.content.hash_layout(state);
     }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.content.diff(&mut tree.diff_children(&[None])[0]);
+    }
+
+    #[cfg(feature = "devtools")]
+    fn inspect(&self, layout: Layout<'_>) -> crate::inspect::Node {
+        super::inspect_children(
+            std::any::type_name::<Self>(),
+            std::slice::from_ref(&self.content),
+            &[None],
+            layout,
+        )
+    }
+
+    fn hover(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        path: &mut Vec<usize>,
+    ) {
+        super::hover_children(
+            std::slice::from_ref(&self.content),
+            layout,
+            cursor_position,
+            path,
+        );
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        super::focusables_children(
+            std::slice::from_ref(&self.content),
+            path,
+            focusables,
+        );
+    }
+
+    fn captures_mouse(&self) -> bool {
+        super::captures_mouse_children(std::slice::from_ref(&self.content))
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        super::overlay_children(std::slice::from_mut(&mut self.content), layout)
+    }
 }
 
 impl<'a, Message, Renderer> From<Container<'a, Message, Renderer>>
@@ -0,0 +1,352 @@
+//! Position elements with your own layout logic.
+use std::hash::Hash;
+
+use crate::{
+    layout, overlay, Clipboard, Element, Event, Focusable, Hasher, Layout,
+    Length, Point, Rectangle, Size, Tree, Widget,
+};
+
+/// A container that lets you measure and arrange its children yourself.
+///
+/// The layout of a [`CustomLayout`] happens in two phases, driven by a
+/// closure. First, the closure can [`measure`] every [`Child`] in any limits
+/// it wants, as many times as it needs. Then, it [`arrange`]s each child with
+/// its final position and size, and returns the size of the whole
+/// [`CustomLayout`].
+///
+/// This is useful when the size of some content depends on the size of some
+/// other content, like a caption wrapping to the width of an image.
+///
+/// Children that are not arranged are not displayed.
+///
+/// # Example
+/// ```ignore
+/// // Puts the caption below the image, wrapped to the width of the image
+/// let figure = CustomLayout::new(|limits, children| {
+///     let image = children[0].measure(limits);
+///     let caption = children[1].measure(
+///         &layout::Limits::new(Size::ZERO, Size::new(image.width, f32::INFINITY)),
+///     );
+///
+///     children[0].arrange(Point::ORIGIN, image);
+///     children[1].arrange(Point::new(0.0, image.height), caption);
+///
+///     Size::new(image.width, image.height + caption.height)
+/// })
+/// .push(image)
+/// .push(caption);
+/// ```
+///
+/// [`CustomLayout`]: struct.CustomLayout.html
+/// [`Child`]: struct.Child.html
+/// [`measure`]: struct.Child.html#method.measure
+/// [`arrange`]: struct.Child.html#method.arrange
+#[allow(missing_debug_implementations)]
+pub struct CustomLayout<'a, Message, Renderer> {
+    width: Length,
+    height: Length,
+    dependency: u64,
+    resolve: Resolve<'a, Message, Renderer>,
+    children: Vec<Element<'a, Message, Renderer>>,
+    keys: Vec<Option<u64>>,
+}
+
+type Resolve<'a, Message, Renderer> = Box<
+    dyn Fn(&layout::Limits, &mut [Child<'_, '_, Message, Renderer>]) -> Size
+        + 'a,
+>;
+
+impl<'a, Message, Renderer> CustomLayout<'a, Message, Renderer> {
+    /// Creates an empty [`CustomLayout`] with the closure computing its
+    /// layout.
+    ///
+    /// The closure receives the limits of the [`CustomLayout`] and its
+    /// children, and returns the size of the [`CustomLayout`]. The returned
+    /// size is clamped to the limits.
+    ///
+    /// [`CustomLayout`]: struct.CustomLayout.html
+    pub fn new<F>(resolve: F) -> Self
+    where
+        F: 'a
+            + Fn(&layout::Limits, &mut [Child<'_, '_, Message, Renderer>]) -> Size,
+    {
+        CustomLayout {
+            width: Length::Shrink,
+            height: Length::Shrink,
+            dependency: 0,
+            resolve: Box::new(resolve),
+            children: Vec::new(),
+            keys: Vec::new(),
+        }
+    }
+
+    /// Sets the width of the [`CustomLayout`].
+    ///
+    /// [`CustomLayout`]: struct.CustomLayout.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`CustomLayout`].
+    ///
+    /// [`CustomLayout`]: struct.CustomLayout.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Declares a value the layout closure depends on.
+    ///
+    /// The layout is only recomputed when the layout of the user interface
+    /// changes. If the closure captures any value affecting the layout, like
+    /// a spacing or a ratio, it should be hashed here.
+    pub fn depends_on<T: Hash>(mut self, value: &T) -> Self {
+        self.dependency = crate::hasher::hash(value);
+        self
+    }
+
+    /// Adds an element to the [`CustomLayout`].
+    ///
+    /// [`CustomLayout`]: struct.CustomLayout.html
+    pub fn push<E>(mut self, child: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.children.push(child.into());
+        self.keys.push(None);
+        self
+    }
+
+    /// Adds an element to the [`CustomLayout`] identified by the given key.
+    ///
+    /// Keys should be unique among the children of the same
+    /// [`CustomLayout`].
+    ///
+    /// [`CustomLayout`]: struct.CustomLayout.html
+    pub fn push_keyed<K, E>(mut self, key: K, child: E) -> Self
+    where
+        K: Hash,
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.children.push(child.into());
+        self.keys.push(Some(crate::hasher::hash(&key)));
+        self
+    }
+}
+
+/// A child of a [`CustomLayout`], while its layout is being computed.
+///
+/// [`CustomLayout`]: struct.CustomLayout.html
+#[allow(missing_debug_implementations)]
+pub struct Child<'a, 'b, Message, Renderer> {
+    element: &'a Element<'b, Message, Renderer>,
+    renderer: &'a Renderer,
+    node: Option<layout::Node>,
+}
+
+impl<'a, 'b, Message, Renderer> Child<'a, 'b, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    /// Returns the width of the [`Child`].
+    ///
+    /// [`Child`]: struct.Child.html
+    pub fn width(&self) -> Length {
+        self.element.width()
+    }
+
+    /// Returns the height of the [`Child`].
+    ///
+    /// [`Child`]: struct.Child.html
+    pub fn height(&self) -> Length {
+        self.element.height()
+    }
+
+    /// Returns the size the [`Child`] wants to have in the given limits.
+    ///
+    /// [`Child`]: struct.Child.html
+    pub fn measure(&self, limits: &layout::Limits) -> Size {
+        self.element.measure(self.renderer, limits)
+    }
+
+    /// Places the [`Child`] at the given position, relative to the
+    /// [`CustomLayout`], and lays it out with the given size.
+    ///
+    /// Arranging a [`Child`] again replaces its previous arrangement.
+    ///
+    /// [`Child`]: struct.Child.html
+    /// [`CustomLayout`]: struct.CustomLayout.html
+    pub fn arrange(&mut self, position: Point, size: Size) {
+        let limits = layout::Limits::new(size, size);
+        let mut node = self.element.layout(self.renderer, &limits);
+
+        node.bounds.x = position.x;
+        node.bounds.y = position.y;
+
+        self.node = Some(node);
+    }
+
+    /// Returns the bounds of the [`Child`], if it has been arranged.
+    ///
+    /// [`Child`]: struct.Child.html
+    pub fn bounds(&self) -> Option<Rectangle> {
+        self.node.as_ref().map(layout::Node::bounds)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for CustomLayout<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let mut children: Vec<_> = self
+            .children
+            .iter()
+            .map(|element| Child {
+                element,
+                renderer,
+                node: None,
+            })
+            .collect();
+
+        let size = (self.resolve)(&limits, &mut children);
+
+        layout::Node::with_children(
+            limits.resolve(size),
+            children
+                .into_iter()
+                .map(|child| child.node.unwrap_or_default())
+                .collect(),
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.children.iter_mut().zip(layout.children()).for_each(
+            |(child, layout)| {
+                child.widget.on_event(
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    messages,
+                    renderer,
+                    clipboard,
+                )
+            },
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.draw(&self.children, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<CustomLayout<'static, (), ()>>().hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+        self.dependency.hash(state);
+
+        for (child, key) in self.children.iter().zip(&self.keys) {
+            key.hash(state);
+            child.widget.hash_layout(state);
+        }
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        let trees = tree.diff_children(&self.keys);
+
+        for (child, tree) in self.children.iter_mut().zip(trees) {
+            child.diff(tree);
+        }
+    }
+
+    #[cfg(feature = "devtools")]
+    fn inspect(&self, layout: Layout<'_>) -> crate::inspect::Node {
+        super::inspect_children(
+            std::any::type_name::<Self>(),
+            &self.children,
+            &self.keys,
+            layout,
+        )
+    }
+
+    fn hover(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        path: &mut Vec<usize>,
+    ) {
+        super::hover_children(&self.children, layout, cursor_position, path);
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        super::focusables_children(&self.children, path, focusables);
+    }
+
+    fn captures_mouse(&self) -> bool {
+        super::captures_mouse_children(&self.children)
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        super::overlay_children(&mut self.children, layout)
+    }
+}
+
+/// The renderer of a [`CustomLayout`].
+///
+/// A [`CustomLayout`] only draws its children, so any renderer able to draw
+/// a [`Column`] can draw it too.
+///
+/// [`CustomLayout`]: struct.CustomLayout.html
+/// [`Column`]: ../column/struct.Column.html
+pub trait Renderer: crate::Renderer + super::column::Renderer {}
+
+impl<T> Renderer for T where T: crate::Renderer + super::column::Renderer {}
+
+impl<'a, Message, Renderer> From<CustomLayout<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        custom_layout: CustomLayout<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(custom_layout)
+    }
+}
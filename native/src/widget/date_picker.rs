@@ -0,0 +1,919 @@
+//! Let your users pick a date from a calendar.
+//!
+//! A [`DatePicker`] has some local [`State`].
+//!
+//! [`DatePicker`]: struct.DatePicker.html
+//! [`State`]: struct.State.html
+use crate::{
+    input::{keyboard, mouse, ButtonState},
+    layout, text,
+    tree::{self, Tree},
+    Clipboard, Element, Event, Focus, Focusable, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
+
+use std::fmt;
+use std::hash::Hash;
+
+/// A calendar showing the days of a month, letting your users pick one.
+///
+/// The arrows in the header, or the mouse wheel, move to the previous and
+/// next months. Days before the minimum date or after the maximum one cannot
+/// be picked.
+///
+/// While it has the focus, the arrow keys move the highlighted day by a day
+/// or a week, `PageUp` and `PageDown` by a month, or a year along with
+/// `Shift`, and `Home` and `End` to the first and last day of the month.
+/// `Enter` or `Space` picks the highlighted day.
+///
+/// # Example
+/// ```
+/// # use iced_native::date_picker::{self, Date, Locale};
+/// # use iced_native::DatePicker;
+/// #
+/// #[derive(Debug)]
+/// enum Message {
+///     DueDateChanged(Date),
+/// }
+///
+/// let mut state = date_picker::State::new();
+/// let due_date = Date::new(2020, 2, 29);
+///
+/// let date_picker =
+///     DatePicker::new(&mut state, due_date, Message::DueDateChanged)
+///         .min(Date::new(2020, 1, 1).unwrap())
+///         .locale(Locale::EN_GB);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DatePicker<'a, Message> {
+    state: tree::State<'a, State>,
+    value: Option<Date>,
+    on_change: Box<dyn Fn(Date) -> Message + 'a>,
+    min: Option<Date>,
+    max: Option<Date>,
+    locale: Locale,
+    size: Option<u16>,
+}
+
+impl<'a, Message> DatePicker<'a, Message> {
+    /// Creates a new [`DatePicker`] with some local [`State`], the picked
+    /// [`Date`], if any, and a function producing a message when a day is
+    /// picked.
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    /// [`State`]: struct.State.html
+    /// [`Date`]: struct.Date.html
+    pub fn new<F>(
+        state: &'a mut State,
+        value: Option<Date>,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'a + Fn(Date) -> Message,
+    {
+        Self::with_state(tree::State::Borrowed(state), value, on_change)
+    }
+
+    /// Creates a new [`DatePicker`] whose local [`State`] is managed by the
+    /// runtime.
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    /// [`State`]: struct.State.html
+    pub fn managed<F>(value: Option<Date>, on_change: F) -> Self
+    where
+        F: 'a + Fn(Date) -> Message,
+    {
+        Self::with_state(tree::State::managed(), value, on_change)
+    }
+
+    fn with_state<F>(
+        state: tree::State<'a, State>,
+        value: Option<Date>,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'a + Fn(Date) -> Message,
+    {
+        DatePicker {
+            state,
+            value,
+            on_change: Box::new(on_change),
+            min: None,
+            max: None,
+            locale: Locale::default(),
+            size: None,
+        }
+    }
+
+    /// Sets the earliest [`Date`] that can be picked.
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn min(mut self, date: Date) -> Self {
+        self.min = Some(date);
+        self
+    }
+
+    /// Sets the latest [`Date`] that can be picked.
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn max(mut self, date: Date) -> Self {
+        self.max = Some(date);
+        self
+    }
+
+    /// Sets the [`Locale`] of the [`DatePicker`], deciding the names of the
+    /// months and days and the first day of the week.
+    ///
+    /// [`Locale`]: struct.Locale.html
+    /// [`DatePicker`]: struct.DatePicker.html
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Sets the text size of the [`DatePicker`].
+    ///
+    /// Every day of the calendar is a square twice as large.
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    fn is_enabled(&self, date: Date) -> bool {
+        self.min.is_none_or(|min| date >= min)
+            && self.max.is_none_or(|max| date <= max)
+    }
+
+    fn clamp(&self, date: Date) -> Date {
+        let date = self.min.map_or(date, |min| date.max(min));
+
+        self.max.map_or(date, |max| date.min(max))
+    }
+
+    // The first day of the month shown by the calendar
+    fn month(&self, state: &State) -> Date {
+        state
+            .month
+            .or(self.value)
+            .or(self.min)
+            .unwrap_or_else(Date::today_utc)
+            .first_of_month()
+    }
+
+    fn has_previous(&self, month: Date) -> bool {
+        self.min.is_none_or(|min| min < month)
+    }
+
+    fn has_next(&self, month: Date) -> bool {
+        self.max
+            .is_none_or(|max| max >= month.add_months(1).first_of_month())
+    }
+}
+
+/// The local state of a [`DatePicker`].
+///
+/// [`DatePicker`]: struct.DatePicker.html
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct State {
+    month: Option<Date>,
+    highlighted: Option<Date>,
+    is_focused: bool,
+}
+
+impl State {
+    /// Creates a new [`State`], showing the month of the picked date.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the [`DatePicker`] has the focus.
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    // Highlights a date and shows its month
+    fn highlight(&mut self, date: Date) {
+        self.highlighted = Some(date);
+        self.month = Some(date.first_of_month());
+    }
+}
+
+// The areas of the calendar of a month
+struct Grid {
+    bounds: Rectangle,
+    cell: f32,
+    first: Date,
+}
+
+impl Grid {
+    fn new(
+        bounds: Rectangle,
+        text_size: u16,
+        month: Date,
+        locale: &Locale,
+    ) -> Self {
+        let offset =
+            (month.weekday().index() + 7 - locale.first_weekday.index()) % 7;
+
+        Grid {
+            bounds,
+            cell: cell_size(text_size),
+            first: month.add_days(-(offset as i64)),
+        }
+    }
+
+    fn cell(&self, row: usize, column: usize) -> Rectangle {
+        Rectangle {
+            x: self.bounds.x + column as f32 * self.cell,
+            y: self.bounds.y + row as f32 * self.cell,
+            width: self.cell,
+            height: self.cell,
+        }
+    }
+
+    fn previous(&self) -> Rectangle {
+        self.cell(0, 0)
+    }
+
+    fn next(&self) -> Rectangle {
+        self.cell(0, 6)
+    }
+
+    fn title(&self) -> Rectangle {
+        Rectangle {
+            x: self.bounds.x + self.cell,
+            width: self.cell * 5.0,
+            ..self.cell(0, 1)
+        }
+    }
+
+    fn days(&self) -> impl Iterator<Item = (Date, Rectangle)> + '_ {
+        (0..WEEKS * 7).map(move |index| {
+            (
+                self.first.add_days(index as i64),
+                self.cell(2 + index / 7, index % 7),
+            )
+        })
+    }
+
+    fn day_at(&self, point: Point) -> Option<Date> {
+        self.days()
+            .find(|(_, bounds)| bounds.contains(point))
+            .map(|(date, _)| date)
+    }
+}
+
+// The amount of weeks shown, enough for any month
+const WEEKS: usize = 6;
+
+fn cell_size(text_size: u16) -> f32 {
+    f32::from(text_size) * 2.0
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for DatePicker<'a, Message>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        _limits: &layout::Limits,
+    ) -> layout::Node {
+        let cell = cell_size(self.size.unwrap_or(renderer.default_size()));
+
+        // A header, the names of the days, and the weeks
+        layout::Node::new(Size::new(7.0 * cell, (2 + WEEKS) as f32 * cell))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+        let text_size = self.size.unwrap_or(renderer.default_size());
+
+        let mut state = *self.state.borrow();
+        let month = self.month(&state);
+        let grid = Grid::new(bounds, text_size, month, &self.locale);
+
+        match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) => {
+                state.is_focused = bounds.contains(cursor_position);
+
+                if grid.previous().contains(cursor_position) {
+                    if self.has_previous(month) {
+                        state.month = Some(month.add_months(-1));
+                    }
+                } else if grid.next().contains(cursor_position) {
+                    if self.has_next(month) {
+                        state.month = Some(month.add_months(1));
+                    }
+                } else if let Some(date) = grid.day_at(cursor_position) {
+                    if self.is_enabled(date) {
+                        state.highlight(date);
+                        messages.push((self.on_change)(date));
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { pixels, .. })
+                if bounds.contains(cursor_position) =>
+            {
+                if pixels.y > 0.0 && self.has_previous(month) {
+                    state.month = Some(month.add_months(-1));
+                } else if pixels.y < 0.0 && self.has_next(month) {
+                    state.month = Some(month.add_months(1));
+                }
+            }
+            Event::Focus(focus) if layout.is_focus_target() => {
+                state.is_focused = focus == Focus::Gained;
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code,
+                state: ButtonState::Pressed,
+                modifiers,
+            }) if state.is_focused => {
+                let highlighted = state
+                    .highlighted
+                    .filter(|date| date.first_of_month() == month)
+                    .or(self
+                        .value
+                        .filter(|date| date.first_of_month() == month))
+                    .unwrap_or_else(|| self.clamp(month));
+
+                let years = if modifiers.shift { 12 } else { 1 };
+
+                let target = match key_code {
+                    keyboard::KeyCode::Left => highlighted.add_days(-1),
+                    keyboard::KeyCode::Right => highlighted.add_days(1),
+                    keyboard::KeyCode::Up => highlighted.add_days(-7),
+                    keyboard::KeyCode::Down => highlighted.add_days(7),
+                    keyboard::KeyCode::PageUp => highlighted.add_months(-years),
+                    keyboard::KeyCode::PageDown => {
+                        highlighted.add_months(years)
+                    }
+                    keyboard::KeyCode::Home => highlighted.first_of_month(),
+                    keyboard::KeyCode::End => highlighted.last_of_month(),
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter
+                    | keyboard::KeyCode::Space => {
+                        if self.is_enabled(highlighted) {
+                            messages.push((self.on_change)(highlighted));
+                        }
+
+                        highlighted
+                    }
+                    _ => return,
+                };
+
+                state.highlight(self.clamp(target));
+            }
+            _ => {}
+        }
+
+        *self.state.borrow_mut() = state;
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let text_size = self.size.unwrap_or(renderer.default_size());
+
+        let state = self.state.borrow();
+        let month = self.month(&state);
+        let grid = Grid::new(bounds, text_size, month, &self.locale);
+
+        let first_weekday = self.locale.first_weekday.index();
+
+        let weekdays = (0..7)
+            .map(|column| {
+                (
+                    self.locale.weekdays[(first_weekday + column) % 7],
+                    grid.cell(1, column),
+                )
+            })
+            .collect();
+
+        let highlighted = state
+            .highlighted
+            .filter(|date| state.is_focused && date.first_of_month() == month);
+
+        let days = grid
+            .days()
+            .map(|(date, bounds)| Day {
+                bounds,
+                date,
+                is_outside: date.first_of_month() != month,
+                is_enabled: self.is_enabled(date),
+                is_selected: self.value == Some(date),
+                is_highlighted: highlighted == Some(date),
+            })
+            .collect();
+
+        let calendar = Calendar {
+            title: format!(
+                "{} {}",
+                self.locale.months[usize::from(month.month - 1)],
+                month.year
+            ),
+            title_bounds: grid.title(),
+            previous: Some(grid.previous())
+                .filter(|_| self.has_previous(month)),
+            next: Some(grid.next()).filter(|_| self.has_next(month)),
+            weekdays,
+            days,
+            is_focused: state.is_focused,
+        };
+
+        self::Renderer::draw(
+            renderer,
+            bounds,
+            cursor_position,
+            &calendar,
+            text_size,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<DatePicker<'static, ()>>().hash(state);
+
+        self.size.hash(state);
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.state.diff(tree);
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        focusables.push(Focusable {
+            path: path.clone(),
+            is_focused: self.state.borrow().is_focused,
+            uses_tab: false,
+        });
+    }
+}
+
+/// A day of the year in the proleptic Gregorian calendar.
+///
+/// Dates are ordered chronologically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    /// The year of the [`Date`]
+    ///
+    /// [`Date`]: struct.Date.html
+    pub year: i32,
+
+    /// The month of the [`Date`], from `1` to `12`
+    ///
+    /// [`Date`]: struct.Date.html
+    pub month: u8,
+
+    /// The day of the month of the [`Date`], starting at `1`
+    ///
+    /// [`Date`]: struct.Date.html
+    pub day: u8,
+}
+
+impl Date {
+    /// Creates a new [`Date`], if the day exists.
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Date> {
+        if (1..=12).contains(&month) && day >= 1 {
+            Some(Date { year, month, day })
+                .filter(|date| day <= date.days_in_month())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the current [`Date`] in UTC.
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn today_utc() -> Date {
+        let seconds = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0);
+
+        Date::from_days(seconds as i64 / 86_400)
+    }
+
+    /// Returns the [`Weekday`] of the [`Date`].
+    ///
+    /// [`Weekday`]: enum.Weekday.html
+    /// [`Date`]: struct.Date.html
+    pub fn weekday(self) -> Weekday {
+        // The first of January of 1970 was a Thursday
+        Weekday::ALL[(self.days() + 3).rem_euclid(7) as usize]
+    }
+
+    /// Returns the [`Date`] the given amount of days later, or earlier if
+    /// negative.
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn add_days(self, days: i64) -> Date {
+        Date::from_days(self.days() + days)
+    }
+
+    /// Returns the [`Date`] the given amount of months later, or earlier if
+    /// negative.
+    ///
+    /// The day is clamped to the length of the resulting month, so a month
+    /// after the 31st of January is the last day of February.
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn add_months(self, months: i32) -> Date {
+        let index = self.year * 12 + i32::from(self.month) - 1 + months;
+
+        let month = Date {
+            year: index.div_euclid(12),
+            month: index.rem_euclid(12) as u8 + 1,
+            day: 1,
+        };
+
+        Date {
+            day: self.day.min(month.days_in_month()),
+            ..month
+        }
+    }
+
+    /// Returns the first day of the month of the [`Date`].
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn first_of_month(self) -> Date {
+        Date { day: 1, ..self }
+    }
+
+    /// Returns the last day of the month of the [`Date`].
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn last_of_month(self) -> Date {
+        Date {
+            day: self.days_in_month(),
+            ..self
+        }
+    }
+
+    /// Returns the amount of days in the month of the [`Date`].
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn days_in_month(self) -> u8 {
+        match self.month {
+            2 if self.is_leap_year() => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    /// Returns whether the year of the [`Date`] is a leap year.
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn is_leap_year(self) -> bool {
+        self.year % 4 == 0 && (self.year % 100 != 0 || self.year % 400 == 0)
+    }
+
+    // The amount of days since the first of January of 1970
+    //
+    // See http://howardhinnant.github.io/date_algorithms.html
+    fn days(self) -> i64 {
+        let month = i64::from(self.month);
+        let year = i64::from(self.year) - if month <= 2 { 1 } else { 0 };
+
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year =
+            (153 * ((month + 9) % 12) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4
+            - year_of_era / 100
+            + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    fn from_days(days: i64) -> Date {
+        let days = days + 719_468;
+
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era = (day_of_era - day_of_era / 1460
+            + day_of_era / 36_524
+            - day_of_era / 146_096)
+            / 365;
+        let day_of_year = day_of_era
+            - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let month = (month_index + 2) % 12 + 1;
+
+        Date {
+            year: (year_of_era + era * 400 + if month <= 2 { 1 } else { 0 })
+                as i32,
+            month: month as u8,
+            day: (day_of_year - (153 * month_index + 2) / 5 + 1) as u8,
+        }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// A day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// All the days of the week, starting on Monday.
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    // The position of the day in the week, starting on Monday
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// The conventions of a language and region for dates and times.
+///
+/// You can use one of the provided locales or build your own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Locale {
+    /// The names of the months, starting on January
+    pub months: [&'static str; 12],
+
+    /// The short names of the days of the week, starting on Monday
+    pub weekdays: [&'static str; 7],
+
+    /// The first day of the week
+    pub first_weekday: Weekday,
+
+    /// Whether hours are counted from 1 to 12, followed by a period
+    pub is_12_hour: bool,
+
+    /// The names of the periods of the day, before and after noon
+    pub periods: [&'static str; 2],
+}
+
+impl Locale {
+    /// English, as used in the United States.
+    pub const EN_US: Locale = Locale {
+        months: [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        weekdays: ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"],
+        first_weekday: Weekday::Sunday,
+        is_12_hour: true,
+        periods: ["AM", "PM"],
+    };
+
+    /// English, as used in the United Kingdom.
+    pub const EN_GB: Locale = Locale {
+        first_weekday: Weekday::Monday,
+        is_12_hour: false,
+        ..Locale::EN_US
+    };
+
+    /// German.
+    pub const DE: Locale = Locale {
+        months: [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        weekdays: ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"],
+        first_weekday: Weekday::Monday,
+        is_12_hour: false,
+        periods: ["AM", "PM"],
+    };
+
+    /// French.
+    pub const FR: Locale = Locale {
+        months: [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ],
+        weekdays: ["lu", "ma", "me", "je", "ve", "sa", "di"],
+        first_weekday: Weekday::Monday,
+        is_12_hour: false,
+        periods: ["AM", "PM"],
+    };
+
+    /// Spanish.
+    pub const ES: Locale = Locale {
+        months: [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ],
+        weekdays: ["lu", "ma", "mi", "ju", "vi", "sá", "do"],
+        first_weekday: Weekday::Monday,
+        is_12_hour: false,
+        periods: ["a. m.", "p. m."],
+    };
+}
+
+impl Default for Locale {
+    fn default() -> Locale {
+        Locale::EN_US
+    }
+}
+
+/// The calendar of a [`DatePicker`], ready to be drawn.
+///
+/// [`DatePicker`]: struct.DatePicker.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Calendar<'a> {
+    /// The name of the month shown and its year.
+    pub title: String,
+
+    /// The bounds of the title.
+    pub title_bounds: Rectangle,
+
+    /// The bounds of the arrow moving to the previous month, if it has any
+    /// day that can be picked.
+    pub previous: Option<Rectangle>,
+
+    /// The bounds of the arrow moving to the next month, if it has any day
+    /// that can be picked.
+    pub next: Option<Rectangle>,
+
+    /// The short names of the days of the week and their bounds, from the
+    /// first day of the week.
+    pub weekdays: Vec<(&'a str, Rectangle)>,
+
+    /// Every [`Day`] shown, week by week.
+    ///
+    /// [`Day`]: struct.Day.html
+    pub days: Vec<Day>,
+
+    /// Whether the [`DatePicker`] has the focus.
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    pub is_focused: bool,
+}
+
+/// A day in the [`Calendar`] of a [`DatePicker`].
+///
+/// [`Calendar`]: struct.Calendar.html
+/// [`DatePicker`]: struct.DatePicker.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Day {
+    /// The bounds of the [`Day`].
+    ///
+    /// [`Day`]: struct.Day.html
+    pub bounds: Rectangle,
+
+    /// The [`Date`] of the [`Day`].
+    ///
+    /// [`Date`]: struct.Date.html
+    /// [`Day`]: struct.Day.html
+    pub date: Date,
+
+    /// Whether the [`Day`] belongs to the previous or next month.
+    ///
+    /// [`Day`]: struct.Day.html
+    pub is_outside: bool,
+
+    /// Whether the [`Day`] can be picked.
+    ///
+    /// [`Day`]: struct.Day.html
+    pub is_enabled: bool,
+
+    /// Whether the [`Day`] is the picked one.
+    ///
+    /// [`Day`]: struct.Day.html
+    pub is_selected: bool,
+
+    /// Whether the [`Day`] is highlighted by the keyboard.
+    ///
+    /// [`Day`]: struct.Day.html
+    pub is_highlighted: bool,
+}
+
+/// The renderer of a [`DatePicker`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`DatePicker`] in your user interface.
+///
+/// [`DatePicker`]: struct.DatePicker.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer {
+    /// Draws a [`DatePicker`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`DatePicker`]
+    ///   * the cursor position
+    ///   * the [`Calendar`] to draw
+    ///   * the text size
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    /// [`Calendar`]: struct.Calendar.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        calendar: &Calendar<'_>,
+        text_size: u16,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<DatePicker<'a, Message>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'static + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        date_picker: DatePicker<'a, Message>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(date_picker)
+    }
+}
@@ -0,0 +1,583 @@
+//! Show dialogs on top of some content, one at a time.
+//!
+//! The runtime wraps the user interface of every application in a
+//! [`Dialogs`] widget, showing the [`Dialog`] of every [`Command::dialog`] in
+//! a [`Modal`].
+//!
+//! [`Dialogs`]: struct.Dialogs.html
+//! [`Dialog`]: ../../dialog/struct.Dialog.html
+//! [`Command::dialog`]: ../../struct.Command.html#method.dialog
+//! [`Modal`]: ../modal/struct.Modal.html
+use crate::{
+    button, column, layout, modal, overlay, row, space, text, text_input,
+    tree::Link, Align, Button, Clipboard, Column, Dialog, Element, Event,
+    Focusable, Hasher, Layout, Length, Modal, Point, Rectangle, Row, Size,
+    Space, Text, TextInput, Tree, Widget,
+};
+
+use std::collections::VecDeque;
+use std::hash::Hash;
+use std::time::Instant;
+
+/// The dialogs that are waiting to be closed, starting with the one that is
+/// shown.
+///
+/// [`Dialogs`] keep their [`Queue`] between frames, so it has to be owned by
+/// whoever builds the user interface.
+///
+/// [`Dialogs`]: struct.Dialogs.html
+/// [`Queue`]: struct.Queue.html
+#[derive(Debug)]
+pub struct Queue<Message> {
+    dialogs: VecDeque<Entry<Message>>,
+    version: u64,
+}
+
+#[derive(Debug)]
+struct Entry<Message> {
+    dialog: Dialog<Message>,
+    value: String,
+    input: Link<text_input::State>,
+}
+
+impl<Message> Queue<Message> {
+    /// Creates an empty [`Queue`].
+    ///
+    /// [`Queue`]: struct.Queue.html
+    pub fn new() -> Self {
+        Queue {
+            dialogs: VecDeque::new(),
+            version: 0,
+        }
+    }
+
+    /// Queues a [`Dialog`], to be shown once the previous ones are closed.
+    ///
+    /// [`Dialog`]: ../../dialog/struct.Dialog.html
+    pub fn push(&mut self, dialog: Dialog<Message>) {
+        let value = dialog.input.clone().unwrap_or_default();
+
+        self.dialogs.push_back(Entry {
+            dialog,
+            value,
+            input: Link::new(text_input::State::focused()),
+        });
+
+        self.version += 1;
+    }
+
+    /// Returns true if there are no dialogs in the [`Queue`].
+    ///
+    /// [`Queue`]: struct.Queue.html
+    pub fn is_empty(&self) -> bool {
+        self.dialogs.is_empty()
+    }
+
+    // Closes the dialog that is shown, returning its message
+    fn close(&mut self, choice: Option<usize>) -> Option<Message> {
+        let entry = self.dialogs.pop_front()?;
+
+        self.version += 1;
+
+        entry.dialog.close(choice, entry.value)
+    }
+}
+
+impl<Message> Default for Queue<Message> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An element showing the first [`Dialog`] of a [`Queue`] on top of its
+/// content.
+///
+/// The [`Dialog`] is shown in a [`Modal`], blocking the content until the
+/// user closes it. Then, the next one in the [`Queue`] is shown, if any.
+///
+/// # Example
+/// ```
+/// # use iced_native::{dialogs::Queue, Dialog, Text};
+/// #
+/// # type Dialogs<'a, Message> =
+/// #     iced_native::Dialogs<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug)]
+/// enum Message {
+///     Delete(Option<usize>),
+/// }
+///
+/// let mut queue = Queue::new();
+///
+/// queue.push(Dialog::confirm(
+///     "Delete",
+///     "Do you want to delete this file?",
+///     &["Delete", "Cancel"],
+///     Message::Delete,
+/// ));
+///
+/// let dialogs = Dialogs::new(&mut queue, Text::new("Files"));
+/// ```
+///
+/// [`Dialog`]: ../../dialog/struct.Dialog.html
+/// [`Queue`]: struct.Queue.html
+/// [`Modal`]: ../modal/struct.Modal.html
+#[allow(missing_debug_implementations)]
+pub struct Dialogs<'a, Message, Renderer> {
+    queue: &'a mut Queue<Message>,
+    version: u64,
+    content: Element<'a, Message, Renderer>,
+    modal: Option<Modal<'a, Action, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Dialogs<'a, Message, Renderer>
+where
+    Renderer: 'static + self::Renderer,
+{
+    /// Creates new [`Dialogs`] showing the given [`Queue`] on top of some
+    /// content.
+    ///
+    /// [`Dialogs`]: struct.Dialogs.html
+    /// [`Queue`]: struct.Queue.html
+    pub fn new<E>(queue: &'a mut Queue<Message>, content: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        let modal = queue.dialogs.front().map(|entry| {
+            Modal::new(
+                Space::new(Length::Shrink, Length::Shrink),
+                Some(card(entry)),
+            )
+            .on_close(Action::Dismissed)
+        });
+
+        Dialogs {
+            version: queue.version,
+            queue,
+            content: content.into(),
+            modal,
+        }
+    }
+}
+
+// What the user did with the dialog that is shown
+#[derive(Debug, Clone)]
+enum Action {
+    Chosen(usize),
+    InputChanged(String),
+    Submitted,
+    Dismissed,
+}
+
+fn card<'a, Message, Renderer>(
+    entry: &Entry<Message>,
+) -> Element<'a, Action, Renderer>
+where
+    Renderer: 'static + self::Renderer,
+{
+    let dialog = &entry.dialog;
+
+    let mut content = Column::new()
+        .max_width(400)
+        .padding(20)
+        .spacing(12)
+        .push(Text::new(dialog.title.as_str()).size(24))
+        .push(Text::new(dialog.body.as_str()));
+
+    if dialog.input.is_some() {
+        content = content.push(
+            TextInput::linked(
+                &entry.input,
+                "",
+                &entry.value,
+                Action::InputChanged,
+            )
+            .padding(8)
+            .on_submit(Action::Submitted),
+        );
+    }
+
+    let buttons = dialog.buttons.iter().enumerate().fold(
+        Row::new().spacing(8).push(Space::with_width(Length::Fill)),
+        |buttons, (index, label)| {
+            buttons.push(
+                Button::managed(Text::new(label.as_str()))
+                    .padding(8)
+                    .min_width(80)
+                    .on_press(Action::Chosen(index)),
+            )
+        },
+    );
+
+    Element::new(Card {
+        content: content.push(buttons.align_items(Align::Center)).into(),
+    })
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Dialogs<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self.content.layout(renderer, limits);
+
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.content.widget.on_event(
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        // The dialog is built along with the rest of the user interface, so
+        // we ask for a new one as soon as the queue changes
+        if self.queue.version != self.version {
+            renderer.schedule_redraw(Instant::now());
+        }
+
+        self.content.draw(
+            renderer,
+            layout.children().next().unwrap(),
+            cursor_position,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Dialogs<'static, (), ()>>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        match &mut self.modal {
+            Some(modal) => {
+                let children = tree.diff_children(&[None, None]);
+
+                self.content.diff(&mut children[0]);
+                modal.diff(&mut children[1]);
+            }
+            // Closing a dialog drops its state, so the next one opens fresh
+            None => self.content.diff(&mut tree.diff_children(&[None])[0]),
+        }
+    }
+
+    #[cfg(feature = "devtools")]
+    fn inspect(&self, layout: Layout<'_>) -> crate::inspect::Node {
+        super::inspect_children(
+            std::any::type_name::<Self>(),
+            std::slice::from_ref(&self.content),
+            &[None],
+            layout,
+        )
+    }
+
+    fn hover(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        path: &mut Vec<usize>,
+    ) {
+        super::hover_children(
+            std::slice::from_ref(&self.content),
+            layout,
+            cursor_position,
+            path,
+        );
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        super::focusables_children(
+            std::slice::from_ref(&self.content),
+            path,
+            focusables,
+        );
+    }
+
+    fn captures_mouse(&self) -> bool {
+        super::captures_mouse_children(std::slice::from_ref(&self.content))
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let Dialogs {
+            queue,
+            content,
+            modal,
+            ..
+        } = self;
+
+        match modal {
+            // A dialog that was closed already is not shown anymore
+            Some(modal) if queue.version == self.version => {
+                let dialog =
+                    Widget::<Action, Renderer>::overlay(modal, layout)?;
+
+                Some(overlay::Element::new(
+                    Point::new(0.0, 0.0),
+                    Box::new(Overlay {
+                        dialog,
+                        queue: &mut **queue,
+                    }),
+                ))
+            }
+            _ => super::overlay_children(std::slice::from_mut(content), layout),
+        }
+    }
+}
+
+// The `Modal` overlay of the dialog that is shown, closing it as the user
+// interacts with it
+struct Overlay<'a, Message, Renderer> {
+    dialog: overlay::Element<'a, Action, Renderer>,
+    queue: &'a mut Queue<Message>,
+}
+
+impl<'a, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Overlay<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        _position: Point,
+    ) -> layout::Node {
+        self.dialog.layout(renderer, bounds)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.dialog.draw(renderer, layout, cursor_position)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> overlay::Status {
+        let mut actions = Vec::new();
+
+        let status = self.dialog.on_event(
+            event,
+            layout,
+            cursor_position,
+            &mut actions,
+            renderer,
+            clipboard,
+        );
+
+        for action in actions {
+            let choice = match action {
+                Action::InputChanged(value) => {
+                    if let Some(entry) = self.queue.dialogs.front_mut() {
+                        entry.value = value;
+                        self.queue.version += 1;
+                    }
+
+                    continue;
+                }
+                Action::Chosen(index) => Some(index),
+                Action::Submitted => Some(0),
+                Action::Dismissed => None,
+            };
+
+            messages.extend(self.queue.close(choice));
+
+            // The rest of the actions belong to the closed dialog
+            break;
+        }
+
+        status
+    }
+}
+
+// The background of the dialog that is shown
+struct Card<'a, Renderer> {
+    content: Element<'a, Action, Renderer>,
+}
+
+impl<'a, Renderer> Widget<Action, Renderer> for Card<'a, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self.content.layout(renderer, limits);
+
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Action>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.content.widget.on_event(
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let content = self.content.draw(
+            renderer,
+            layout.children().next().unwrap(),
+            cursor_position,
+        );
+
+        self::Renderer::draw(renderer, layout.bounds(), content)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Card<'static, ()>>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.content.diff(&mut tree.diff_children(&[None])[0]);
+    }
+
+    fn hover(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        path: &mut Vec<usize>,
+    ) {
+        super::hover_children(
+            std::slice::from_ref(&self.content),
+            layout,
+            cursor_position,
+            path,
+        );
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        super::focusables_children(
+            std::slice::from_ref(&self.content),
+            path,
+            focusables,
+        );
+    }
+
+    fn captures_mouse(&self) -> bool {
+        super::captures_mouse_children(std::slice::from_ref(&self.content))
+    }
+}
+
+/// The renderer of [`Dialogs`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use [`Dialogs`] in your user interface.
+///
+/// [`Dialogs`]: struct.Dialogs.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer:
+    modal::Renderer
+    + column::Renderer
+    + row::Renderer
+    + space::Renderer
+    + text::Renderer
+    + text_input::Renderer
+    + button::Renderer
+{
+    /// Draws the background of the dialog that is shown, under its contents.
+    ///
+    /// It receives:
+    ///   * the bounds of the dialog
+    ///   * the drawn contents of the dialog
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        content: Self::Output,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Dialogs<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'static + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        dialogs: Dialogs<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(dialogs)
+    }
+}
@@ -0,0 +1,522 @@
+//! Let your users drag things around and resize them.
+//!
+//! A [`Draggable`] has some local [`State`], which other widgets can reuse to
+//! track their own drag interactions.
+//!
+//! [`Draggable`]: struct.Draggable.html
+//! [`State`]: struct.State.html
+use crate::{
+    input::{mouse, ButtonState},
+    layout, overlay, snap, space,
+    tree::{self, Tree},
+    Clipboard, Element, Event, Focusable, Hasher, Layout, Length, Point,
+    Rectangle, Space, Vector, Widget,
+};
+
+use std::hash::Hash;
+
+const GRIP_THICKNESS: u16 = 6;
+
+/// An element that can be dragged with the mouse.
+///
+/// A [`Draggable`] does not move its content by itself. Instead, it reports
+/// how far the cursor has moved since the last message, so your application
+/// can move, resize, or reorder things as it sees fit.
+///
+/// The drag continues after the cursor leaves the [`Draggable`], until the
+/// mouse button is released.
+///
+/// A [`Draggable`] can also [snap] to the edges and centers of other
+/// rectangles, drawing guides and a preview of where it will land while it is
+/// dragged.
+///
+/// # Example
+/// ```
+/// # use iced_native::{draggable, Rectangle, Text, Vector};
+/// #
+/// # type Draggable<'a, Message> =
+/// #     iced_native::Draggable<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Moved(Vector),
+///     Resized(Vector),
+///     Snapped(Rectangle),
+/// }
+///
+/// let sidebar = Rectangle { x: 0.0, y: 0.0, width: 200.0, height: 600.0 };
+///
+/// let card = Draggable::managed(Text::new("Drag me!"))
+///     .on_drag(Message::Moved)
+///     .snap(vec![sidebar], 8.0)
+///     .on_snap(Message::Snapped);
+///
+/// let divider = Draggable::resize_grip(draggable::Axis::Horizontal)
+///     .on_drag(Message::Resized);
+/// ```
+///
+/// [`Draggable`]: struct.Draggable.html
+/// [snap]: #method.snap
+#[allow(missing_debug_implementations)]
+pub struct Draggable<'a, Message, Renderer> {
+    state: tree::State<'a, State>,
+    content: Element<'a, Message, Renderer>,
+    axis: Option<Axis>,
+    on_press: Option<Message>,
+    on_drag: Option<Box<dyn Fn(Vector) -> Message + 'a>>,
+    on_release: Option<Message>,
+    snap: Option<(Vec<Rectangle>, f32)>,
+    on_snap: Option<Box<dyn Fn(Rectangle) -> Message + 'a>>,
+}
+
+impl<'a, Message, Renderer> Draggable<'a, Message, Renderer> {
+    /// Creates a new [`Draggable`] with some local [`State`] and the given
+    /// content.
+    ///
+    /// [`Draggable`]: struct.Draggable.html
+    /// [`State`]: struct.State.html
+    pub fn new<E>(state: &'a mut State, content: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Self::with_state(tree::State::Borrowed(state), content)
+    }
+
+    /// Creates a new [`Draggable`] with the given content, whose local
+    /// [`State`] is managed by the runtime.
+    ///
+    /// [`Draggable`]: struct.Draggable.html
+    /// [`State`]: struct.State.html
+    pub fn managed<E>(content: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Self::with_state(tree::State::managed(), content)
+    }
+
+    fn with_state<E>(state: tree::State<'a, State>, content: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Draggable {
+            state,
+            content: content.into(),
+            axis: None,
+            on_press: None,
+            on_drag: None,
+            on_release: None,
+            snap: None,
+            on_snap: None,
+        }
+    }
+
+    /// Restricts the drag of the [`Draggable`] to the given [`Axis`].
+    ///
+    /// The movement along the other axis is always reported as `0`.
+    ///
+    /// [`Draggable`]: struct.Draggable.html
+    /// [`Axis`]: enum.Axis.html
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = Some(axis);
+        self
+    }
+
+    /// Sets the message that should be produced when a drag starts.
+    pub fn on_press(mut self, message: Message) -> Self {
+        self.on_press = Some(message);
+        self
+    }
+
+    /// Sets the message that should be produced when the cursor moves during
+    /// a drag.
+    ///
+    /// It receives the movement of the cursor since the last message.
+    pub fn on_drag<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(Vector) -> Message,
+    {
+        self.on_drag = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that should be produced when a drag ends.
+    pub fn on_release(mut self, message: Message) -> Self {
+        self.on_release = Some(message);
+        self
+    }
+
+    /// Snaps the [`Draggable`] to the edges and centers of the given targets
+    /// that are closer than `distance`, in the coordinates of the user
+    /// interface.
+    ///
+    /// While it is dragged, the [`Draggable`] draws guides and a preview of
+    /// where it will land on top of the user interface. Use [`on_snap`] to
+    /// move it there when the drag ends.
+    ///
+    /// [`Draggable`]: struct.Draggable.html
+    /// [`on_snap`]: #method.on_snap
+    pub fn snap(mut self, targets: Vec<Rectangle>, distance: f32) -> Self {
+        self.snap = Some((targets, distance));
+        self
+    }
+
+    /// Sets the message that should be produced when a drag ends close
+    /// enough to one of the [`snap`] targets.
+    ///
+    /// It receives the bounds where the [`Draggable`] should land, and it is
+    /// produced before the [`on_release`] message.
+    ///
+    /// [`snap`]: #method.snap
+    /// [`Draggable`]: struct.Draggable.html
+    /// [`on_release`]: #method.on_release
+    pub fn on_snap<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(Rectangle) -> Message,
+    {
+        self.on_snap = Some(Box::new(f));
+        self
+    }
+
+    fn snapped(&self, bounds: Rectangle) -> Option<snap::Snap> {
+        let (targets, distance) = self.snap.as_ref()?;
+        let snap = snap::snap(bounds, targets, *distance);
+
+        if snap.guides.is_empty() {
+            None
+        } else {
+            Some(snap)
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Draggable<'a, Message, Renderer>
+where
+    Renderer: 'static + space::Renderer,
+    Message: 'static,
+{
+    /// Creates a thin [`Draggable`] that resizes things along the given
+    /// [`Axis`], like the divider between two panes.
+    ///
+    /// A grip that resizes horizontally fills the available height, and vice
+    /// versa.
+    ///
+    /// [`Draggable`]: struct.Draggable.html
+    /// [`Axis`]: enum.Axis.html
+    pub fn resize_grip(axis: Axis) -> Self {
+        let thickness = Length::Units(GRIP_THICKNESS);
+
+        let grip = match axis {
+            Axis::Horizontal => Space::new(thickness, Length::Fill),
+            Axis::Vertical => Space::new(Length::Fill, thickness),
+        };
+
+        Self::managed(grip).axis(axis)
+    }
+}
+
+/// A direction in which a [`Draggable`] can be dragged.
+///
+/// [`Draggable`]: struct.Draggable.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    /// Left and right.
+    Horizontal,
+
+    /// Up and down.
+    Vertical,
+}
+
+impl Axis {
+    fn project(self, delta: Vector) -> Vector {
+        match self {
+            Axis::Horizontal => Vector::new(delta.x, 0.0),
+            Axis::Vertical => Vector::new(0.0, delta.y),
+        }
+    }
+}
+
+/// The local state of a [`Draggable`].
+///
+/// It can be used by any widget that needs to track a drag interaction.
+///
+/// [`Draggable`]: struct.Draggable.html
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct State {
+    cursor_position: Option<Point>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether a drag is in progress.
+    pub fn is_dragging(&self) -> bool {
+        self.cursor_position.is_some()
+    }
+
+    /// Processes an [`Event`] and returns the [`Drag`] it produced, if any.
+    ///
+    /// A drag starts when the left mouse button is pressed inside the given
+    /// bounds, and it ends when the button is released anywhere.
+    ///
+    /// [`Event`]: ../../enum.Event.html
+    /// [`Drag`]: enum.Drag.html
+    pub fn update(
+        &mut self,
+        event: Event,
+        bounds: Rectangle,
+        cursor_position: Point,
+    ) -> Option<Drag> {
+        match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) if bounds.contains(cursor_position) => {
+                self.cursor_position = Some(cursor_position);
+
+                Some(Drag::Started)
+            }
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Released,
+            }) => self.cursor_position.take().map(|_| Drag::Ended),
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let last = self.cursor_position?;
+
+                self.cursor_position = Some(cursor_position);
+
+                Some(Drag::Moved(Vector::new(
+                    cursor_position.x - last.x,
+                    cursor_position.y - last.y,
+                )))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A step of a drag interaction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Drag {
+    /// The mouse button was pressed.
+    Started,
+
+    /// The cursor moved by the given amount since the last step.
+    Moved(Vector),
+
+    /// The mouse button was released.
+    Ended,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Draggable<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+    Message: Clone,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self.content.layout(renderer, limits);
+
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.content.widget.on_event(
+            event.clone(),
+            layout.children().next().unwrap(),
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+
+        let drag = self.state.borrow_mut().update(
+            event,
+            layout.bounds(),
+            cursor_position,
+        );
+
+        match drag {
+            Some(Drag::Started) => {
+                messages.extend(self.on_press.clone());
+            }
+            Some(Drag::Moved(delta)) => {
+                let delta = match self.axis {
+                    Some(axis) => axis.project(delta),
+                    None => delta,
+                };
+
+                if let Some(on_drag) = &self.on_drag {
+                    if delta.x != 0.0 || delta.y != 0.0 {
+                        messages.push(on_drag(delta));
+                    }
+                }
+            }
+            Some(Drag::Ended) => {
+                if let Some(on_snap) = &self.on_snap {
+                    if let Some(snap) = self.snapped(layout.bounds()) {
+                        if snap.bounds != layout.bounds() {
+                            messages.push(on_snap(snap.bounds));
+                        }
+                    }
+                }
+
+                messages.extend(self.on_release.clone());
+            }
+            None => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let state = self.state.borrow();
+
+        let content = self.content.draw(
+            renderer,
+            layout.children().next().unwrap(),
+            cursor_position,
+        );
+
+        renderer.draw(
+            layout.bounds(),
+            cursor_position,
+            state.is_dragging(),
+            self.axis,
+            content,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Draggable<'static, (), ()>>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.state.diff(tree);
+        self.content.diff(&mut tree.diff_children(&[None])[0]);
+    }
+
+    #[cfg(feature = "devtools")]
+    fn inspect(&self, layout: Layout<'_>) -> crate::inspect::Node {
+        super::inspect_children(
+            std::any::type_name::<Self>(),
+            std::slice::from_ref(&self.content),
+            &[None],
+            layout,
+        )
+    }
+
+    fn hover(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        path: &mut Vec<usize>,
+    ) {
+        super::hover_children(
+            std::slice::from_ref(&self.content),
+            layout,
+            cursor_position,
+            path,
+        );
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        super::focusables_children(
+            std::slice::from_ref(&self.content),
+            path,
+            focusables,
+        );
+    }
+
+    fn captures_mouse(&self) -> bool {
+        self.state.borrow().is_dragging() || self.content.captures_mouse()
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if self.state.borrow().is_dragging() {
+            if let Some(snap) = self.snapped(layout.bounds()) {
+                return Some(snap.overlay());
+            }
+        }
+
+        super::overlay_children(std::slice::from_mut(&mut self.content), layout)
+    }
+}
+
+/// The renderer of a [`Draggable`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Draggable`] in your user interface.
+///
+/// [`Draggable`]: struct.Draggable.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: snap::Renderer + Sized {
+    /// Draws a [`Draggable`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Draggable`]
+    ///   * the current cursor position
+    ///   * whether the [`Draggable`] is being dragged
+    ///   * the [`Axis`] the drag is restricted to, if any
+    ///   * the drawn content
+    ///
+    /// [`Draggable`]: struct.Draggable.html
+    /// [`Axis`]: enum.Axis.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        is_dragging: bool,
+        axis: Option<Axis>,
+        content: Self::Output,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Draggable<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'static + self::Renderer,
+    Message: 'static + Clone,
+{
+    fn from(
+        draggable: Draggable<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(draggable)
+    }
+}
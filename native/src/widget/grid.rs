@@ -0,0 +1,463 @@
+//! Distribute content in rows and columns.
+use std::hash::Hash;
+
+use crate::{
+    layout, overlay, Align, Clipboard, Element, Event, Focusable, Hasher,
+    Layout, Length, Point, Size, Tree, Widget,
+};
+
+/// A container that distributes its contents in rows of equally wide
+/// columns.
+///
+/// Children fill the cells from left to right, wrapping to a new row once the
+/// columns run out. Every row is as tall as its tallest child, so the cells of
+/// a row stay aligned even when their contents have different heights.
+///
+/// The amount of columns is either fixed or as many as fit in the available
+/// width, given a minimum width per column.
+///
+/// # Example
+/// ```
+/// # use iced_native::{renderer::Null, Align, Text};
+/// #
+/// # pub type Grid<'a, Message> = iced_native::Grid<'a, Message, Null>;
+/// let gallery: Grid<()> = Grid::auto_fit(120)
+///     .spacing(10)
+///     .align_x(Align::Center)
+///     .push(Text::new("Sunrise"))
+///     .push(Text::new("Harbour at night"))
+///     .push(Text::new("Glacier"));
+/// ```
+///
+/// [`Grid`]: struct.Grid.html
+#[allow(missing_debug_implementations)]
+pub struct Grid<'a, Message, Renderer> {
+    columns: Columns,
+    row_spacing: u16,
+    column_spacing: u16,
+    padding: u16,
+    width: Length,
+    height: Length,
+    align_x: Align,
+    align_y: Align,
+    children: Vec<Element<'a, Message, Renderer>>,
+    keys: Vec<Option<u64>>,
+    alignments: Vec<Option<(Align, Align)>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Columns {
+    Fixed(usize),
+    Fit(u16),
+}
+
+impl<'a, Message, Renderer> Grid<'a, Message, Renderer> {
+    /// Creates an empty [`Grid`] with the given amount of columns.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn new(columns: usize) -> Self {
+        Self::with_columns(Columns::Fixed(columns.max(1)))
+    }
+
+    /// Creates an empty [`Grid`] with as many columns as fit in its width,
+    /// each being at least as wide as the given amount of units.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn auto_fit(min_column_width: u16) -> Self {
+        Self::with_columns(Columns::Fit(min_column_width))
+    }
+
+    fn with_columns(columns: Columns) -> Self {
+        Grid {
+            columns,
+            row_spacing: 0,
+            column_spacing: 0,
+            padding: 0,
+            width: Length::Fill,
+            height: Length::Shrink,
+            align_x: Align::Start,
+            align_y: Align::Start,
+            children: Vec::new(),
+            keys: Vec::new(),
+            alignments: Vec::new(),
+        }
+    }
+
+    /// Sets both the spacing between rows and between columns of the
+    /// [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.row_spacing = units;
+        self.column_spacing = units;
+        self
+    }
+
+    /// Sets the vertical spacing _between_ rows.
+    pub fn row_spacing(mut self, units: u16) -> Self {
+        self.row_spacing = units;
+        self
+    }
+
+    /// Sets the horizontal spacing _between_ columns.
+    pub fn column_spacing(mut self, units: u16) -> Self {
+        self.column_spacing = units;
+        self
+    }
+
+    /// Sets the padding of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn padding(mut self, units: u16) -> Self {
+        self.padding = units;
+        self
+    }
+
+    /// Sets the width of the [`Grid`].
+    ///
+    /// A [`Grid`] that shrinks makes its columns as wide as its widest child.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the horizontal alignment of the contents of every cell.
+    pub fn align_x(mut self, align: Align) -> Self {
+        self.align_x = align;
+        self
+    }
+
+    /// Sets the vertical alignment of the contents of every cell.
+    ///
+    /// With [`Align::Baseline`], the first lines of text of a row share the
+    /// same baseline.
+    ///
+    /// [`Align::Baseline`]: ../../enum.Align.html#variant.Baseline
+    pub fn align_y(mut self, align: Align) -> Self {
+        self.align_y = align;
+        self
+    }
+
+    /// Adds an element to the next cell of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn push<E>(mut self, child: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.children.push(child.into());
+        self.keys.push(None);
+        self.alignments.push(None);
+        self
+    }
+
+    /// Adds an element to the next cell of the [`Grid`], aligned in its own
+    /// way instead of the alignment of the whole [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn push_aligned<E>(mut self, child: E, x: Align, y: Align) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.children.push(child.into());
+        self.keys.push(None);
+        self.alignments.push(Some((x, y)));
+        self
+    }
+
+    /// Adds an element to the next cell of the [`Grid`] identified by the
+    /// given key.
+    ///
+    /// Keys should be unique among the children of the same [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn push_keyed<K, E>(mut self, key: K, child: E) -> Self
+    where
+        K: Hash,
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.children.push(child.into());
+        self.keys.push(Some(crate::hasher::hash(&key)));
+        self.alignments.push(None);
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Grid<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let padding = f32::from(self.padding);
+        let row_spacing = f32::from(self.row_spacing);
+        let column_spacing = f32::from(self.column_spacing);
+
+        let available = limits.pad(padding).max();
+
+        let columns = match self.columns {
+            Columns::Fixed(columns) => columns,
+            Columns::Fit(min_width) if available.width.is_finite() => {
+                ((available.width + column_spacing)
+                    / (f32::from(min_width.max(1)) + column_spacing))
+                    .floor()
+                    .max(1.0) as usize
+            }
+            Columns::Fit(_) => self.children.len().max(1),
+        };
+
+        let total_spacing = column_spacing * (columns - 1) as f32;
+        let fit_width =
+            ((available.width - total_spacing) / columns as f32).max(0.0);
+
+        let column_width =
+            if self.width == Length::Shrink || !fit_width.is_finite() {
+                let cell = layout::Limits::new(
+                    Size::ZERO,
+                    Size::new(fit_width, available.height),
+                );
+
+                let widest = self
+                    .children
+                    .iter()
+                    .map(|child| child.measure(renderer, &cell).width)
+                    .fold(0.0, f32::max);
+
+                match self.columns {
+                    Columns::Fit(min_width) => widest.max(f32::from(min_width)),
+                    Columns::Fixed(_) => widest,
+                }
+                .min(fit_width)
+            } else {
+                fit_width
+            };
+
+        let cell = layout::Limits::new(
+            Size::ZERO,
+            Size::new(column_width, available.height),
+        );
+
+        let mut nodes = Vec::with_capacity(self.children.len());
+        let mut y = padding;
+
+        for (index, row) in self.children.chunks(columns).enumerate() {
+            let start = index * columns;
+
+            let mut row: Vec<_> = row
+                .iter()
+                .map(|child| child.layout(renderer, &cell))
+                .collect();
+
+            let alignments: Vec<_> = self.alignments[start..start + row.len()]
+                .iter()
+                .map(|alignment| {
+                    alignment.unwrap_or((self.align_x, self.align_y))
+                })
+                .collect();
+
+            let baseline = row
+                .iter()
+                .zip(&alignments)
+                .filter(|(_, (_, y))| *y == Align::Baseline)
+                .filter_map(|(node, _)| node.baseline())
+                .fold(0.0, f32::max);
+
+            let baseline_offset = |node: &layout::Node| {
+                baseline - node.baseline().unwrap_or(baseline)
+            };
+
+            let height = row
+                .iter()
+                .zip(&alignments)
+                .map(|(node, (_, y))| match y {
+                    Align::Baseline => {
+                        baseline_offset(node) + node.size().height
+                    }
+                    _ => node.size().height,
+                })
+                .fold(0.0, f32::max);
+
+            for (column, (node, (align_x, align_y))) in
+                row.iter_mut().zip(alignments).enumerate()
+            {
+                let size = node.size();
+
+                let x = padding
+                    + column as f32 * (column_width + column_spacing)
+                    + match align_x {
+                        Align::Center => (column_width - size.width) / 2.0,
+                        Align::End => column_width - size.width,
+                        Align::Start | Align::Baseline => 0.0,
+                    };
+
+                let offset = match align_y {
+                    Align::Center => (height - size.height) / 2.0,
+                    Align::End => height - size.height,
+                    Align::Baseline => baseline_offset(node),
+                    Align::Start => 0.0,
+                };
+
+                node.move_to(Point::new(x, y + offset));
+            }
+
+            nodes.extend(row);
+
+            y += height + row_spacing;
+        }
+
+        let rows = nodes.len().div_ceil(columns);
+
+        let size = Size::new(
+            column_width * columns as f32 + total_spacing + padding * 2.0,
+            y - if rows > 0 { row_spacing } else { 0.0 } + padding,
+        );
+
+        layout::Node::with_children(limits.resolve(size), nodes)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.children.iter_mut().zip(layout.children()).for_each(
+            |(child, layout)| {
+                child.widget.on_event(
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    messages,
+                    renderer,
+                    clipboard,
+                )
+            },
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.draw(&self.children, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Grid<'static, (), ()>>().hash(state);
+        self.columns.hash(state);
+        self.row_spacing.hash(state);
+        self.column_spacing.hash(state);
+        self.padding.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+        self.align_x.hash(state);
+        self.align_y.hash(state);
+
+        for ((child, key), alignment) in
+            self.children.iter().zip(&self.keys).zip(&self.alignments)
+        {
+            key.hash(state);
+            alignment.hash(state);
+            child.widget.hash_layout(state);
+        }
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        let trees = tree.diff_children(&self.keys);
+
+        for (child, tree) in self.children.iter_mut().zip(trees) {
+            child.diff(tree);
+        }
+    }
+
+    #[cfg(feature = "devtools")]
+    fn inspect(&self, layout: Layout<'_>) -> crate::inspect::Node {
+        super::inspect_children(
+            std::any::type_name::<Self>(),
+            &self.children,
+            &self.keys,
+            layout,
+        )
+    }
+
+    fn hover(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        path: &mut Vec<usize>,
+    ) {
+        super::hover_children(&self.children, layout, cursor_position, path);
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        super::focusables_children(&self.children, path, focusables);
+    }
+
+    fn captures_mouse(&self) -> bool {
+        super::captures_mouse_children(&self.children)
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        super::overlay_children(&mut self.children, layout)
+    }
+}
+
+/// The renderer of a [`Grid`].
+///
+/// A [`Grid`] only draws its children, so any renderer able to draw a
+/// [`Column`] can draw it too.
+///
+/// [`Grid`]: struct.Grid.html
+/// [`Column`]: ../column/struct.Column.html
+pub trait Renderer: crate::Renderer + super::column::Renderer {}
+
+impl<T> Renderer for T where T: crate::Renderer + super::column::Renderer {}
+
+impl<'a, Message, Renderer> From<Grid<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        grid: Grid<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(grid)
+    }
+}
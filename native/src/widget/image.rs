@@ -13,9 +13,15 @@ use std::{
 /// # Example
 ///
 /// ```
-/// # use iced_native::Image;
+/// # use iced_native::{image::Fit, Image, Length};
 /// #
 /// let image = Image::new("resources/ferris.png");
+///
+/// // A background that repeats a texture
+/// let background = Image::new("resources/tile.png")
+///     .width(Length::Fill)
+///     .height(Length::Fill)
+///     .fit(Fit::Tile);
 /// ```
 ///
 /// <img src="https://github.com/hecrj/iced/blob/9712b319bb7a32848001b96bd84977430f14b623/examples/resources/ferris.png?raw=true" width="300">
@@ -24,6 +30,7 @@ pub struct Image {
     handle: Handle,
     width: Length,
     height: Length,
+    fit: Fit,
 }
 
 impl Image {
@@ -35,6 +42,7 @@ impl Image {
             handle: handle.into(),
             width: Length::Shrink,
             height: Length::Shrink,
+            fit: Fit::Contain,
         }
     }
 
@@ -53,6 +61,18 @@ impl Image {
         self.height = height;
         self
     }
+
+    /// Sets how the image fills the boundaries of the [`Image`].
+    ///
+    /// By default, the boundaries shrink to keep the aspect ratio of the
+    /// image. Any other [`Fit`] keeps the boundaries as they are.
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`Fit`]: enum.Fit.html
+    pub fn fit(mut self, fit: Fit) -> Self {
+        self.fit = fit;
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Image
@@ -81,6 +101,10 @@ where
             .height(self.height)
             .resolve(Size::new(width as f32, height as f32));
 
+        if self.fit != Fit::Contain {
+            return layout::Node::new(size);
+        }
+
         let viewport_aspect_ratio = size.width / size.height;
 
         if viewport_aspect_ratio > aspect_ratio {
@@ -98,13 +122,14 @@ where
         layout: Layout<'_>,
         _cursor_position: Point,
     ) -> Renderer::Output {
-        renderer.draw(self.handle.clone(), layout)
+        renderer.draw(self.handle.clone(), self.fit, layout)
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
         self.handle.hash(state);
         self.width.hash(state);
         self.height.hash(state);
+        self.fit.hash(state);
     }
 }
 
@@ -114,12 +139,17 @@ where
 #[derive(Debug, Clone)]
 pub struct Handle {
     id: u64,
+    data_id: u64,
     data: Arc<Data>,
+    sampling: Sampling,
 }
 
 impl Handle {
     /// Creates an image [`Handle`] pointing to the image of the given path.
     ///
+    /// Animated GIFs and APNGs are played automatically, looping as many
+    /// times as the file requests.
+    ///
     /// [`Handle`]: struct.Handle.html
     pub fn from_path<T: Into<PathBuf>>(path: T) -> Handle {
         Self::from_data(Data::Path(path.into()))
@@ -128,9 +158,11 @@ impl Handle {
     /// Creates an image [`Handle`] containing the image data directly.
     ///
     /// This is useful if you already have your image loaded in-memory, maybe
-    /// because you downloaded or generated it procedurally.
+    /// because you downloaded or generated it procedurally. Like with
+    /// [`from_path`], animated GIFs and APNGs are played automatically.
     ///
     /// [`Handle`]: struct.Handle.html
+    /// [`from_path`]: #method.from_path
     pub fn from_memory(bytes: Vec<u8>) -> Handle {
         Self::from_data(Data::Bytes(bytes))
     }
@@ -139,12 +171,38 @@ impl Handle {
         let mut hasher = Hasher::default();
         data.hash(&mut hasher);
 
+        let id = hasher.finish();
+
         Handle {
-            id: hasher.finish(),
+            id,
+            data_id: id,
             data: Arc::new(data),
+            sampling: Sampling::default(),
         }
     }
 
+    /// Sets the [`Sampling`] used to draw the image of the [`Handle`].
+    ///
+    /// Handles with different [`Sampling`] have different identifiers, so
+    /// renderers can keep a separate texture for each one.
+    ///
+    /// [`Sampling`]: struct.Sampling.html
+    /// [`Handle`]: struct.Handle.html
+    pub fn with_sampling(mut self, sampling: Sampling) -> Handle {
+        self.id = if sampling == Sampling::default() {
+            self.data_id
+        } else {
+            let mut hasher = Hasher::default();
+            self.data_id.hash(&mut hasher);
+            sampling.hash(&mut hasher);
+
+            hasher.finish()
+        };
+
+        self.sampling = sampling;
+        self
+    }
+
     /// Returns the unique identifier of the [`Handle`].
     ///
     /// [`Handle`]: struct.Handle.html
@@ -158,6 +216,127 @@ impl Handle {
     pub fn data(&self) -> &Data {
         &self.data
     }
+
+    /// Returns the [`Sampling`] of the [`Handle`].
+    ///
+    /// [`Sampling`]: struct.Sampling.html
+    /// [`Handle`]: struct.Handle.html
+    pub fn sampling(&self) -> Sampling {
+        self.sampling
+    }
+}
+
+/// How an image is sampled when it is drawn at a different size.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::image::{Filter, Handle, Sampling};
+/// #
+/// // Pixel art should stay crisp when scaled up
+/// let sprite = Handle::from_path("resources/sprite.png").with_sampling(
+///     Sampling {
+///         filter: Filter::Nearest,
+///         mipmaps: false,
+///     },
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Sampling {
+    /// The filter used to pick the colors of the image
+    pub filter: Filter,
+
+    /// Whether smaller versions of the image should be generated and used
+    /// when it is drawn scaled down
+    ///
+    /// Mipmaps avoid shimmering when downscaling detailed images, like
+    /// photos, at the cost of using a third more memory.
+    pub mipmaps: bool,
+}
+
+impl Default for Sampling {
+    fn default() -> Sampling {
+        Sampling {
+            filter: Filter::Linear,
+            mipmaps: false,
+        }
+    }
+}
+
+/// The filter used to sample an image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Filter {
+    /// Blend the nearest pixels, producing smooth results.
+    Linear,
+
+    /// Pick the nearest pixel, keeping hard edges. Great for pixel art.
+    Nearest,
+}
+
+/// The way an image fills its boundaries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Fit {
+    /// Stretch the image to fill the boundaries, ignoring its aspect ratio.
+    Fill,
+
+    /// Scale the image to fit inside of the boundaries, keeping its aspect
+    /// ratio. The image is centered.
+    Contain,
+
+    /// Scale the image to cover the boundaries, keeping its aspect ratio and
+    /// cropping whatever overflows. The image is centered.
+    Cover,
+
+    /// Repeat the image at its original size, starting from the top-left
+    /// corner of the boundaries.
+    Tile,
+}
+
+/// The distances, in pixels of the source image, from each edge of an image
+/// to the lines that slice it into a 3x3 grid.
+///
+/// When an image is drawn as a nine-patch, its corners keep their size, its
+/// edges stretch along a single axis, and its center stretches along both.
+/// This lets a small bitmap skin a button or a panel of any size.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::image::Slices;
+/// #
+/// // A frame with 8px corners and a thicker bottom edge
+/// let frame = Slices {
+///     bottom: 12,
+///     ..Slices::uniform(8)
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Slices {
+    /// The distance from the top edge
+    pub top: u16,
+
+    /// The distance from the right edge
+    pub right: u16,
+
+    /// The distance from the bottom edge
+    pub bottom: u16,
+
+    /// The distance from the left edge
+    pub left: u16,
+}
+
+impl Slices {
+    /// Creates some [`Slices`] at the same distance from every edge.
+    ///
+    /// [`Slices`]: struct.Slices.html
+    pub fn uniform(distance: u16) -> Slices {
+        Slices {
+            top: distance,
+            right: distance,
+            bottom: distance,
+            left: distance,
+        }
+    }
 }
 
 impl From<String> for Handle {
@@ -212,10 +391,16 @@ pub trait Renderer: crate::Renderer {
     /// [`Image`]: struct.Image.html
     fn dimensions(&self, handle: &Handle) -> (u32, u32);
 
-    /// Draws an [`Image`].
+    /// Draws an [`Image`] filling its boundaries with the given [`Fit`].
     ///
     /// [`Image`]: struct.Image.html
-    fn draw(&mut self, handle: Handle, layout: Layout<'_>) -> Self::Output;
+    /// [`Fit`]: enum.Fit.html
+    fn draw(
+        &mut self,
+        handle: Handle,
+        fit: Fit,
+        layout: Layout<'_>,
+    ) -> Self::Output;
 }
 
 impl<'a, Message, Renderer> From<Image> for Element<'a, Message, Renderer>
@@ -0,0 +1,347 @@
+//! Control a value by turning a rotary knob.
+//!
+//! A [`Knob`] has some local [`State`].
+//!
+//! [`Knob`]: struct.Knob.html
+//! [`State`]: struct.State.html
+use crate::{
+    draggable::{self, Drag},
+    input::keyboard,
+    layout,
+    tree::{self, Tree},
+    Clipboard, Element, Event, Hasher, Layout, Length, Point, Rectangle, Size,
+    Widget,
+};
+
+use std::{hash::Hash, ops::RangeInclusive};
+
+/// A round knob selecting a single continuous value, like the gain of an
+/// amplifier.
+///
+/// The knob turns clockwise while the cursor is dragged upwards or to the
+/// right, and it does not jump to the cursor when pressed. While shift is
+/// held, it follows a fraction of the movement of the cursor, for fine
+/// adjustments.
+///
+/// The drag continues after the cursor leaves the [`Knob`], until the mouse
+/// button is released.
+///
+/// [`Knob`]: struct.Knob.html
+///
+/// # Example
+/// ```
+/// # use iced_native::{knob, Knob};
+/// #
+/// pub enum Message {
+///     GainChanged(f32),
+/// }
+///
+/// let state = &mut knob::State::new();
+/// let gain = -6.0;
+///
+/// Knob::new(state, -60.0..=12.0, gain, Message::GainChanged).step(0.5);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Knob<'a, Message> {
+    state: tree::State<'a, State>,
+    range: RangeInclusive<f32>,
+    value: f32,
+    step: Option<f32>,
+    fine_factor: f32,
+    travel: f32,
+    on_change: Box<dyn Fn(f32) -> Message>,
+    size: u16,
+}
+
+impl<'a, Message> Knob<'a, Message> {
+    /// Creates a new [`Knob`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`Knob`]
+    ///   * an inclusive range of possible values
+    ///   * the current value of the [`Knob`]
+    ///   * a function that will be called when the [`Knob`] is turned.
+    ///     It receives the new value of the [`Knob`] and must produce a
+    ///     `Message`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(
+        state: &'a mut State,
+        range: RangeInclusive<f32>,
+        value: f32,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(f32) -> Message,
+    {
+        Self::with_state(tree::State::Borrowed(state), range, value, on_change)
+    }
+
+    /// Creates a new [`Knob`] whose local [`State`] is managed by the
+    /// runtime.
+    ///
+    /// It expects the same arguments as [`new`], except for the [`State`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`State`]: struct.State.html
+    /// [`new`]: #method.new
+    pub fn managed<F>(
+        range: RangeInclusive<f32>,
+        value: f32,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(f32) -> Message,
+    {
+        Self::with_state(tree::State::managed(), range, value, on_change)
+    }
+
+    fn with_state<F>(
+        state: tree::State<'a, State>,
+        range: RangeInclusive<f32>,
+        value: f32,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(f32) -> Message,
+    {
+        Knob {
+            state,
+            value: clamp(value, &range),
+            range,
+            step: None,
+            fine_factor: 0.1,
+            travel: 200.0,
+            on_change: Box::new(on_change),
+            size: 48,
+        }
+    }
+
+    /// Snaps the value of the [`Knob`] to multiples of the given step,
+    /// counting from the start of its range.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// Sets the fraction of the movement of the cursor the [`Knob`] follows
+    /// while shift is held.
+    ///
+    /// By default, it is `0.1`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn fine_factor(mut self, fine_factor: f32) -> Self {
+        self.fine_factor = fine_factor;
+        self
+    }
+
+    /// Sets the distance, in pixels, the cursor needs to travel to turn the
+    /// [`Knob`] from the start of its range to the end.
+    ///
+    /// By default, it is `200`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn travel(mut self, travel: f32) -> Self {
+        self.travel = travel;
+        self
+    }
+
+    /// Sets the diameter of the [`Knob`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = size;
+        self
+    }
+
+    fn turn(&self, value: f32, distance: f32, is_fine: bool) -> f32 {
+        let span = self.range.end() - self.range.start();
+        let factor = if is_fine { self.fine_factor } else { 1.0 };
+
+        clamp(
+            value + distance / self.travel.max(1.0) * span * factor,
+            &self.range,
+        )
+    }
+
+    fn snap(&self, value: f32) -> f32 {
+        match self.step {
+            Some(step) if step > 0.0 => {
+                let steps = ((value - self.range.start()) / step).round();
+
+                clamp(self.range.start() + steps * step, &self.range)
+            }
+            _ => value,
+        }
+    }
+}
+
+fn clamp(value: f32, range: &RangeInclusive<f32>) -> f32 {
+    value.max(*range.start()).min(*range.end())
+}
+
+/// The local state of a [`Knob`].
+///
+/// [`Knob`]: struct.Knob.html
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct State {
+    drag: draggable::State,
+    is_fine: bool,
+
+    // The unsnapped value during a drag, so movements smaller than a step
+    // still add up
+    value: Option<f32>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Knob<'a, Message>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Units(self.size)
+    }
+
+    fn height(&self) -> Length {
+        Length::Units(self.size)
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = Length::Units(self.size);
+        let limits = limits.width(size).height(size);
+
+        layout::Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        let (drag, is_fine, last_value) = {
+            let mut state = self.state.borrow_mut();
+
+            if let Event::Keyboard(keyboard::Event::Input {
+                modifiers, ..
+            }) = event
+            {
+                state.is_fine = modifiers.shift;
+            }
+
+            let drag =
+                state.drag.update(event, layout.bounds(), cursor_position);
+
+            (drag, state.is_fine, state.value)
+        };
+
+        let value = match drag {
+            Some(Drag::Started) => Some(self.value),
+            Some(Drag::Moved(delta)) => Some(self.turn(
+                last_value.unwrap_or(self.value),
+                delta.x - delta.y,
+                is_fine,
+            )),
+            Some(Drag::Ended) => None,
+            None => return,
+        };
+
+        self.state.borrow_mut().value = value;
+
+        if let Some(value) = value {
+            let value = self.snap(value);
+
+            if value != self.value {
+                messages.push((self.on_change)(value));
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let state = self.state.borrow();
+
+        renderer.draw(
+            layout.bounds(),
+            cursor_position,
+            self.range.clone(),
+            self.value,
+            state.drag.is_dragging(),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Knob<'static, ()>>().hash(state);
+
+        self.size.hash(state);
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.state.diff(tree);
+    }
+
+    fn captures_mouse(&self) -> bool {
+        self.state.borrow().drag.is_dragging()
+    }
+}
+
+/// The renderer of a [`Knob`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Knob`] in your user interface.
+///
+/// [`Knob`]: struct.Knob.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// Draws a [`Knob`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Knob`]
+    ///   * the current cursor position
+    ///   * the range of values of the [`Knob`]
+    ///   * the current value of the [`Knob`]
+    ///   * whether the [`Knob`] is being turned
+    ///
+    /// [`Knob`]: struct.Knob.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        range: RangeInclusive<f32>,
+        value: f32,
+        is_dragging: bool,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Knob<'a, Message>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+    Message: 'static,
+{
+    fn from(knob: Knob<'a, Message>) -> Element<'a, Message, Renderer> {
+        Element::new(knob)
+    }
+}
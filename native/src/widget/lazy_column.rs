@@ -0,0 +1,411 @@
+//! Display a huge list of rows, building only the visible ones.
+//!
+//! A [`LazyColumn`] builds its rows on demand, every time it lays them out,
+//! draws them, or handles events for them. Inside a [`Scrollable`], only the
+//! rows inside its [`Viewport`] are built. This way, a list of thousands of
+//! log lines costs as much as the few dozen lines on screen.
+//!
+//! [`LazyColumn`]: struct.LazyColumn.html
+//! [`Scrollable`]: ../scrollable/struct.Scrollable.html
+//! [`Viewport`]: ../scrollable/struct.Viewport.html
+use crate::{
+    context, layout, overlay, scrollable::Viewport, Clipboard, Element, Event,
+    Focusable, Hasher, Layout, Length, Point, Rectangle, Size, Tree, Widget,
+};
+
+use std::{
+    cell::RefCell, collections::HashMap, hash::Hash, ops::Range, rc::Rc,
+};
+
+/// A vertical list of rows of the same height that only builds, lays out,
+/// and draws the visible ones.
+///
+/// A [`LazyColumn`] is meant to be placed inside a [`Scrollable`]. Outside
+/// of one, all of its rows are visible.
+///
+/// Rows are built with a closure, given their index. The runtime only keeps
+/// the managed state of the visible rows. Rows scrolled out of view start
+/// over with their default state when they come back.
+///
+/// # Example
+/// ```
+/// # use iced_native::{renderer::Null, Text};
+/// #
+/// # type Scrollable<'a, Message> =
+/// #     iced_native::Scrollable<'a, Message, Null>;
+/// # type LazyColumn<'a, Message> =
+/// #     iced_native::LazyColumn<'a, Message, Null>;
+/// #
+/// struct Log {
+///     lines: Vec<String>,
+/// }
+///
+/// impl Log {
+///     fn view(&self) -> Scrollable<'_, ()> {
+///         Scrollable::managed().push(LazyColumn::new(
+///             self.lines.len(),
+///             20,
+///             move |index| Text::new(self.lines[index].as_str()).into(),
+///         ))
+///     }
+/// }
+/// ```
+///
+/// [`LazyColumn`]: struct.LazyColumn.html
+/// [`Scrollable`]: ../scrollable/struct.Scrollable.html
+#[allow(missing_debug_implementations)]
+pub struct LazyColumn<'a, Message, Renderer> {
+    count: usize,
+    row_height: u16,
+    spacing: u16,
+    width: Length,
+    view: Box<dyn Fn(usize) -> Element<'a, Message, Renderer> + 'a>,
+    state: Rc<RefCell<State>>,
+    overlay_rows: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> LazyColumn<'a, Message, Renderer> {
+    /// Creates a new [`LazyColumn`] with the given amount of rows, the height
+    /// of every row, and the closure building the row at an index.
+    ///
+    /// [`LazyColumn`]: struct.LazyColumn.html
+    pub fn new<F>(count: usize, row_height: u16, view: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Element<'a, Message, Renderer>,
+    {
+        LazyColumn {
+            count,
+            row_height,
+            spacing: 0,
+            width: Length::Fill,
+            view: Box::new(view),
+            state: Rc::default(),
+            overlay_rows: Vec::new(),
+        }
+    }
+
+    /// Sets the vertical spacing _between_ rows.
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.spacing = units;
+        self
+    }
+
+    /// Sets the width of the [`LazyColumn`].
+    ///
+    /// [`LazyColumn`]: struct.LazyColumn.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    fn stride(&self) -> f32 {
+        f32::from(self.row_height) + f32::from(self.spacing)
+    }
+
+    /// Returns the indices of the rows inside the [`Viewport`] of the nearest
+    /// [`Scrollable`], given the bounds of the [`LazyColumn`].
+    ///
+    /// [`Viewport`]: ../scrollable/struct.Viewport.html
+    /// [`Scrollable`]: ../scrollable/struct.Scrollable.html
+    /// [`LazyColumn`]: struct.LazyColumn.html
+    fn visible(&self, bounds: Rectangle) -> Range<usize> {
+        let (top, bottom) = match context::get::<Viewport>() {
+            Some(viewport) => {
+                let top =
+                    viewport.content_bounds.y + viewport.offset - bounds.y;
+
+                (top, top + viewport.bounds.height)
+            }
+            None => (0.0, bounds.height),
+        };
+
+        let stride = self.stride();
+        let first = (top.max(0.0) / stride).floor() as usize;
+        let last = (bottom.max(0.0) / stride).ceil() as usize;
+
+        first.min(self.count)..last.min(self.count)
+    }
+
+    /// Builds the row at the given index with its managed state.
+    fn build(&self, index: usize) -> Element<'a, Message, Renderer>
+    where
+        Renderer: crate::Renderer,
+    {
+        let mut row = (self.view)(index);
+
+        row.diff(self.state.borrow_mut().rows.entry(index).or_default());
+
+        row
+    }
+
+    /// Builds the row at the given index and lays it out, relative to the
+    /// [`LazyColumn`].
+    ///
+    /// [`LazyColumn`]: struct.LazyColumn.html
+    fn row(
+        &self,
+        renderer: &Renderer,
+        index: usize,
+        width: f32,
+    ) -> (Element<'a, Message, Renderer>, layout::Node)
+    where
+        Renderer: crate::Renderer,
+    {
+        let row = self.build(index);
+
+        let limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(width, f32::from(self.row_height)),
+        );
+
+        let mut node = row.layout(renderer, &limits);
+        node.bounds.y = index as f32 * self.stride();
+
+        (row, node)
+    }
+
+    /// Returns the index and the layout of the rows drawn last, from top to
+    /// bottom.
+    fn drawn(&self) -> Vec<(usize, layout::Node)> {
+        let mut drawn: Vec<(usize, layout::Node)> = self
+            .state
+            .borrow()
+            .drawn
+            .iter()
+            .map(|(index, node)| (*index, node.clone()))
+            .collect();
+
+        drawn.sort_by_key(|(index, _)| *index);
+
+        drawn
+    }
+}
+
+/// The managed state of a [`LazyColumn`].
+///
+/// [`LazyColumn`]: struct.LazyColumn.html
+#[derive(Debug, Default)]
+struct State {
+    rows: HashMap<usize, Tree>,
+    drawn: HashMap<usize, layout::Node>,
+    is_mouse_captured: bool,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for LazyColumn<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let height = if self.count > 0 {
+            self.count as f32 * self.stride() - f32::from(self.spacing)
+        } else {
+            0.0
+        };
+
+        let size = limits
+            .width(self.width)
+            .height(Length::Shrink)
+            .resolve(Size::new(0.0, height));
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+        let mut is_mouse_captured = false;
+
+        for index in self.visible(bounds) {
+            let (mut row, node) = self.row(renderer, index, bounds.width);
+
+            row.widget.on_event(
+                event.clone(),
+                layout.child(index, &node),
+                cursor_position,
+                messages,
+                renderer,
+                clipboard,
+            );
+
+            is_mouse_captured = is_mouse_captured || row.captures_mouse();
+        }
+
+        self.state.borrow_mut().is_mouse_captured = is_mouse_captured;
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let visible = self.visible(bounds);
+
+        let mut drawn = HashMap::new();
+
+        self.state
+            .borrow_mut()
+            .rows
+            .retain(|index, _| visible.contains(index));
+
+        let rows = visible
+            .map(|index| {
+                let (row, node) = self.row(renderer, index, bounds.width);
+                let output = row.draw(
+                    renderer,
+                    layout.child(index, &node),
+                    cursor_position,
+                );
+
+                let _ = drawn.insert(index, node);
+
+                output
+            })
+            .collect();
+
+        self.state.borrow_mut().drawn = drawn;
+
+        self::Renderer::draw(renderer, rows)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<LazyColumn<'static, (), ()>>().hash(state);
+
+        self.count.hash(state);
+        self.row_height.hash(state);
+        self.spacing.hash(state);
+        self.width.hash(state);
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.state = tree.state();
+    }
+
+    #[cfg(feature = "devtools")]
+    fn inspect(&self, layout: Layout<'_>) -> crate::inspect::Node {
+        crate::inspect::Node {
+            children: self
+                .drawn()
+                .into_iter()
+                .map(|(index, node)| {
+                    self.build(index).inspect(layout.child(index, &node))
+                })
+                .collect(),
+            ..crate::inspect::Node::new(
+                std::any::type_name::<Self>(),
+                layout.bounds(),
+            )
+        }
+    }
+
+    fn hover(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        path: &mut Vec<usize>,
+    ) {
+        // Only drawn rows can be hovered, and they were laid out already
+        let hovered =
+            self.state.borrow().drawn.iter().find_map(|(index, node)| {
+                let layout = layout.child(*index, node);
+
+                if layout.bounds().contains(cursor_position) {
+                    Some((*index, node.clone()))
+                } else {
+                    None
+                }
+            });
+
+        if let Some((index, node)) = hovered {
+            let row = self.build(index);
+
+            path.push(index);
+
+            row.hover(layout.child(index, &node), cursor_position, path);
+        }
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        // Rows out of view are not built, so only the drawn ones can take
+        // the focus
+        for (index, _) in self.drawn() {
+            path.push(index);
+            self.build(index).focusables(path, focusables);
+            let _ = path.pop();
+        }
+    }
+
+    fn captures_mouse(&self) -> bool {
+        self.state.borrow().is_mouse_captured
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let drawn = self.drawn();
+
+        // Rows are built on demand, so we keep the drawn ones around while
+        // their overlays are alive
+        self.overlay_rows =
+            drawn.iter().map(|(index, _)| self.build(*index)).collect();
+
+        self.overlay_rows.iter_mut().zip(&drawn).find_map(
+            |(row, (index, node))| row.overlay(layout.child(*index, node)),
+        )
+    }
+}
+
+/// The renderer of a [`LazyColumn`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`LazyColumn`] in your user interface.
+///
+/// [`LazyColumn`]: struct.LazyColumn.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// Draws the visible rows of a [`LazyColumn`].
+    ///
+    /// It receives the drawn rows, from top to bottom.
+    ///
+    /// [`LazyColumn`]: struct.LazyColumn.html
+    fn draw(&mut self, rows: Vec<Self::Output>) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<LazyColumn<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        lazy_column: LazyColumn<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(lazy_column)
+    }
+}
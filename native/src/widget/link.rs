@@ -0,0 +1,282 @@
+//! Point your users to other places, like a website.
+//!
+//! A [`Link`] has some local [`State`].
+//!
+//! [`Link`]: struct.Link.html
+//! [`State`]: struct.State.html
+use crate::{
+    input::{mouse, ButtonState},
+    layout, text,
+    tree::{self, Tree},
+    Clipboard, Element, Event, Font, Hasher, Layout, Length, Point, Rectangle,
+    Size, Widget,
+};
+
+use std::hash::Hash;
+
+/// A piece of text that produces a message when clicked, styled as a
+/// hyperlink.
+///
+/// A [`Link`] does not open anything by itself. Instead, your application
+/// can open the URL with [`Command::open_url`] when handling the message.
+///
+/// # Example
+/// ```
+/// # use iced_native::{Command, Link};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     OpenWebsite,
+/// }
+///
+/// let link = Link::managed("Visit our website")
+///     .on_press(Message::OpenWebsite);
+///
+/// fn update(message: Message) -> Command<Message> {
+///     match message {
+///         Message::OpenWebsite => Command::open_url("https://iced.rs"),
+///     }
+/// }
+/// ```
+///
+/// [`Link`]: struct.Link.html
+/// [`Command::open_url`]: ../../struct.Command.html#method.open_url
+#[allow(missing_debug_implementations)]
+pub struct Link<'a, Message> {
+    state: tree::State<'a, State>,
+    content: String,
+    size: Option<u16>,
+    font: Font,
+    on_press: Option<Message>,
+}
+
+impl<'a, Message> Link<'a, Message> {
+    /// Creates a new [`Link`] with some local [`State`] and the given
+    /// content.
+    ///
+    /// [`Link`]: struct.Link.html
+    /// [`State`]: struct.State.html
+    pub fn new(state: &'a mut State, content: &str) -> Self {
+        Self::with_state(tree::State::Borrowed(state), content)
+    }
+
+    /// Creates a new [`Link`] with the given content, whose local [`State`]
+    /// is managed by the runtime.
+    ///
+    /// [`Link`]: struct.Link.html
+    /// [`State`]: struct.State.html
+    pub fn managed(content: &str) -> Self {
+        Self::with_state(tree::State::managed(), content)
+    }
+
+    fn with_state(state: tree::State<'a, State>, content: &str) -> Self {
+        Link {
+            state,
+            content: String::from(content),
+            size: None,
+            font: Font::Default,
+            on_press: None,
+        }
+    }
+
+    /// Sets the size of the text of the [`Link`].
+    ///
+    /// [`Link`]: struct.Link.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`Link`].
+    ///
+    /// [`Link`]: struct.Link.html
+    /// [`Font`]: ../../struct.Font.html
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Link`] is clicked.
+    ///
+    /// [`Link`]: struct.Link.html
+    pub fn on_press(mut self, message: Message) -> Self {
+        self.on_press = Some(message);
+        self
+    }
+}
+
+/// The local state of a [`Link`].
+///
+/// [`Link`]: struct.Link.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    is_pressed: bool,
+    is_visited: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the [`Link`] has been clicked before.
+    ///
+    /// [`Link`]: struct.Link.html
+    pub fn is_visited(&self) -> bool {
+        self.is_visited
+    }
+
+    /// Marks the [`Link`] as not visited.
+    ///
+    /// [`Link`]: struct.Link.html
+    pub fn forget_visit(&mut self) {
+        self.is_visited = false;
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Link<'a, Message>
+where
+    Renderer: self::Renderer,
+    Message: Clone,
+{
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = self.size.unwrap_or(renderer.default_size());
+
+        let (width, height) = renderer.measure(
+            &self.content,
+            size,
+            self.font,
+            text::Params::default(),
+            limits.max(),
+        );
+
+        layout::Node::new(limits.resolve(Size::new(width, height)))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        let mut state = self.state.borrow_mut();
+
+        if let Event::Mouse(mouse::Event::Input {
+            button: mouse::Button::Left,
+            state: button_state,
+        }) = event
+        {
+            if let Some(on_press) = self.on_press.clone() {
+                let bounds = layout.bounds();
+
+                match button_state {
+                    ButtonState::Pressed => {
+                        state.is_pressed = bounds.contains(cursor_position);
+                    }
+                    ButtonState::Released => {
+                        let is_clicked = state.is_pressed
+                            && bounds.contains(cursor_position);
+
+                        state.is_pressed = false;
+
+                        if is_clicked {
+                            state.is_visited = true;
+
+                            messages.push(on_press);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let state = self.state.borrow();
+
+        self::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            cursor_position,
+            &self.content,
+            self.size.unwrap_or(renderer.default_size()),
+            self.font,
+            state.is_visited,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Link<'static, ()>>().hash(state);
+
+        self.content.hash(state);
+        self.size.hash(state);
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.state.diff(tree);
+    }
+}
+
+/// The renderer of a [`Link`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Link`] in your user interface.
+///
+/// [`Link`]: struct.Link.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer {
+    /// Draws a [`Link`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Link`]
+    ///   * the current cursor position
+    ///   * the contents of the [`Link`]
+    ///   * the size of the text
+    ///   * the [`Font`] of the text
+    ///   * whether the [`Link`] has been visited
+    ///
+    /// [`Link`]: struct.Link.html
+    /// [`Font`]: ../../struct.Font.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        content: &str,
+        size: u16,
+        font: Font,
+        is_visited: bool,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Link<'a, Message>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'static + self::Renderer,
+    Message: 'static + Clone,
+{
+    fn from(link: Link<'a, Message>) -> Element<'a, Message, Renderer> {
+        Element::new(link)
+    }
+}
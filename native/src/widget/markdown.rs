@@ -0,0 +1,535 @@
+//! Display documents written in Markdown.
+//!
+//! A [`Markdown`] displays some previously parsed [`Content`].
+//!
+//! [`Markdown`]: struct.Markdown.html
+//! [`Content`]: struct.Content.html
+use crate::{
+    column,
+    font::{Style, Weight},
+    image,
+    rich_text::{self, Span},
+    row, space, Color, Column, Element, Font, Image, Length, RichText, Row,
+    Space,
+};
+
+use pulldown_cmark::{Event, Parser, Tag};
+use std::rc::Rc;
+
+/// A document written in [CommonMark], built out of [`RichText`], [`Image`],
+/// and layout widgets.
+///
+/// It displays headings, paragraphs with strong and emphasized text, inline
+/// code, links, lists, block quotes, code blocks, and images. Clicking a link
+/// produces a message with its URL.
+///
+/// A [`Markdown`] is turned into an [`Element`] right away, so it does not
+/// need any state of its own.
+///
+/// # Example
+/// ```
+/// # use iced_native::{markdown, renderer::Null, Markdown};
+/// #
+/// # pub type Element<'a, Message> = iced_native::Element<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     LinkClicked(String),
+/// }
+///
+/// let notes = markdown::Content::parse(
+///     "# Release notes\n\
+///      \n\
+///      - **Faster** layouts\n\
+///      - A new [website](https://iced.rs)",
+/// );
+///
+/// let markdown: Element<Message> =
+///     Markdown::new(&notes).on_link(Message::LinkClicked).into();
+/// ```
+///
+/// [CommonMark]: https://commonmark.org
+/// [`RichText`]: ../rich_text/struct.RichText.html
+/// [`Image`]: ../image/struct.Image.html
+/// [`Markdown`]: struct.Markdown.html
+/// [`Element`]: ../../struct.Element.html
+#[allow(missing_debug_implementations)]
+pub struct Markdown<'a, Message> {
+    content: &'a Content,
+    on_link: Option<Rc<dyn Fn(String) -> Message + 'a>>,
+    images: Box<dyn Fn(&str) -> image::Handle + 'a>,
+    width: Length,
+    text_size: u16,
+    spacing: u16,
+    font: Font,
+    code_font: Font,
+    link_color: Color,
+    code_color: Color,
+}
+
+impl<'a, Message> Markdown<'a, Message> {
+    /// Creates a new [`Markdown`] displaying the given [`Content`].
+    ///
+    /// [`Markdown`]: struct.Markdown.html
+    /// [`Content`]: struct.Content.html
+    pub fn new(content: &'a Content) -> Self {
+        Markdown {
+            content,
+            on_link: None,
+            images: Box::new(|url: &str| image::Handle::from_path(url)),
+            width: Length::Fill,
+            text_size: 16,
+            spacing: 10,
+            font: Font::Default,
+            code_font: Font::Default,
+            link_color: Color::from_rgb(0.20, 0.60, 0.86),
+            code_color: Color::from_rgb(0.75, 0.22, 0.17),
+        }
+    }
+
+    /// Sets the function that produces a message when a link is clicked.
+    ///
+    /// It receives the URL of the link.
+    pub fn on_link<F>(mut self, on_link: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        self.on_link = Some(Rc::new(on_link));
+        self
+    }
+
+    /// Sets the function that turns the URL of an image into an
+    /// [`image::Handle`].
+    ///
+    /// By default, URLs are treated as paths on the file system.
+    ///
+    /// [`image::Handle`]: ../image/struct.Handle.html
+    pub fn images<F>(mut self, images: F) -> Self
+    where
+        F: 'a + Fn(&str) -> image::Handle,
+    {
+        self.images = Box::new(images);
+        self
+    }
+
+    /// Sets the width of the [`Markdown`].
+    ///
+    /// [`Markdown`]: struct.Markdown.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the size of the body text of the [`Markdown`]. Headings are
+    /// larger.
+    ///
+    /// [`Markdown`]: struct.Markdown.html
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the vertical space between the blocks of the [`Markdown`].
+    ///
+    /// [`Markdown`]: struct.Markdown.html
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the [`Font`] of the text of the [`Markdown`].
+    ///
+    /// Only a [`Font::System`] has italic faces, so emphasized text looks
+    /// like the rest with other fonts.
+    ///
+    /// [`Font`]: ../../enum.Font.html
+    /// [`Font::System`]: ../../enum.Font.html#variant.System
+    /// [`Markdown`]: struct.Markdown.html
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the [`Font`] of inline code and code blocks, normally a
+    /// monospaced one.
+    ///
+    /// [`Font`]: ../../enum.Font.html
+    pub fn code_font(mut self, font: Font) -> Self {
+        self.code_font = font;
+        self
+    }
+
+    /// Sets the color of links.
+    pub fn link_color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.link_color = color.into();
+        self
+    }
+
+    /// Sets the color of inline code and code blocks.
+    pub fn code_color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.code_color = color.into();
+        self
+    }
+
+    fn blocks<Renderer>(
+        &self,
+        blocks: &[Block],
+    ) -> Column<'a, Message, Renderer>
+    where
+        Renderer: 'static + self::Renderer,
+        Message: 'static,
+    {
+        blocks.iter().fold(
+            Column::new().width(Length::Fill).spacing(self.spacing),
+            |column, block| column.push(self.block(block)),
+        )
+    }
+
+    fn block<Renderer>(&self, block: &Block) -> Element<'a, Message, Renderer>
+    where
+        Renderer: 'static + self::Renderer,
+        Message: 'static,
+    {
+        match block {
+            Block::Heading(level, inlines) => {
+                let scale = match level {
+                    1 => 2.0,
+                    2 => 1.6,
+                    3 => 1.35,
+                    4 => 1.2,
+                    5 => 1.1,
+                    _ => 1.0,
+                };
+
+                let size = (f32::from(self.text_size) * scale).round() as u16;
+
+                self.paragraph(inlines, size, Some(Weight::Bold)).into()
+            }
+            Block::Paragraph(inlines) => {
+                self.paragraph(inlines, self.text_size, None).into()
+            }
+            Block::Code(code) => Column::new()
+                .padding(self.spacing / 2)
+                .push(RichText::new(vec![Span::new(code.trim_end())
+                    .font(self.code_font)
+                    .size(self.text_size)
+                    .color(self.code_color)]))
+                .into(),
+            Block::List { start, items } => items
+                .iter()
+                .enumerate()
+                .fold(
+                    Column::new().spacing(self.spacing / 2),
+                    |list, (index, item)| {
+                        let marker = match start {
+                            Some(start) => {
+                                format!("{}.", start + index as u64)
+                            }
+                            None => String::from("\u{2022}"),
+                        };
+
+                        list.push(
+                            Row::new()
+                                .spacing(self.text_size / 2)
+                                .push(
+                                    RichText::new(vec![Span::new(marker)
+                                        .font(self.font)
+                                        .size(self.text_size)])
+                                    .width(Length::Shrink),
+                                )
+                                .push(self.blocks(item)),
+                        )
+                    },
+                )
+                .into(),
+            Block::Quote(blocks) => Row::new()
+                .push(Space::with_width(Length::Units(self.text_size)))
+                .push(self.blocks(blocks))
+                .into(),
+            Block::Image(url) => Image::new((self.images)(url)).into(),
+        }
+    }
+
+    fn paragraph(
+        &self,
+        inlines: &[Inline],
+        size: u16,
+        weight: Option<Weight>,
+    ) -> RichText<'a, Message>
+    where
+        Message: 'a,
+    {
+        let spans = inlines
+            .iter()
+            .map(|inline| {
+                let mut span = Span::new(inline.text.clone()).size(size);
+
+                span = span.font(match (inline.is_code, inline.is_emphasis) {
+                    (true, _) => self.code_font,
+                    (false, true) => italic(self.font),
+                    (false, false) => self.font,
+                });
+
+                if let Some(weight) =
+                    weight.or(Some(Weight::Bold).filter(|_| inline.is_strong))
+                {
+                    span = span.weight(weight);
+                }
+
+                if inline.is_code {
+                    span = span.color(self.code_color);
+                }
+
+                if let Some(link) = &inline.link {
+                    span = span.color(self.link_color).link(link.clone());
+                }
+
+                span
+            })
+            .collect();
+
+        let paragraph = RichText::new(spans);
+
+        match &self.on_link {
+            Some(on_link) => {
+                let on_link = Rc::clone(on_link);
+
+                paragraph.on_link(move |url| on_link(url))
+            }
+            None => paragraph,
+        }
+    }
+}
+
+/// Some parsed Markdown, ready to be displayed by a [`Markdown`].
+///
+/// Parsing is done once, so keep the [`Content`] in your application state
+/// instead of parsing it in every `view`.
+///
+/// [`Markdown`]: struct.Markdown.html
+/// [`Content`]: struct.Content.html
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Content {
+    blocks: Vec<Block>,
+}
+
+impl Content {
+    /// Parses some [CommonMark].
+    ///
+    /// Raw HTML is displayed as text, and tables are not supported.
+    ///
+    /// [CommonMark]: https://commonmark.org
+    pub fn parse(markdown: &str) -> Self {
+        Content {
+            blocks: parse_blocks(&mut Parser::new(markdown)),
+        }
+    }
+
+    /// Returns whether the [`Content`] has nothing to display.
+    ///
+    /// [`Content`]: struct.Content.html
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Block {
+    Heading(u8, Vec<Inline>),
+    Paragraph(Vec<Inline>),
+    Code(String),
+    List {
+        start: Option<u64>,
+        items: Vec<Vec<Block>>,
+    },
+    Quote(Vec<Block>),
+    Image(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Inline {
+    text: String,
+    is_strong: bool,
+    is_emphasis: bool,
+    is_code: bool,
+    link: Option<String>,
+}
+
+// The formatting applied to the text found while parsing
+#[derive(Debug, Default)]
+struct Format {
+    strong: usize,
+    emphasis: usize,
+    link: Option<String>,
+    image: Option<String>,
+}
+
+impl Format {
+    fn inline(&self, text: &str, is_code: bool) -> Inline {
+        Inline {
+            text: text.to_owned(),
+            is_strong: self.strong > 0,
+            is_emphasis: self.emphasis > 0,
+            is_code,
+            link: self.link.clone(),
+        }
+    }
+}
+
+// Parses blocks until the end of the container they are in
+fn parse_blocks<'a>(
+    events: &mut impl Iterator<Item = Event<'a>>,
+) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut inlines = Vec::new();
+    let mut format = Format::default();
+    let mut heading = None;
+
+    // Tight lists put their text right inside of their items, without a
+    // paragraph, so loose text is collected as one
+    let flush = |inlines: &mut Vec<Inline>, blocks: &mut Vec<Block>| {
+        if !inlines.is_empty() {
+            blocks.push(Block::Paragraph(std::mem::take(inlines)));
+        }
+    };
+
+    while let Some(event) = events.next() {
+        match event {
+            Event::Start(Tag::Paragraph) | Event::End(Tag::Paragraph) => {
+                flush(&mut inlines, &mut blocks);
+            }
+            Event::Start(Tag::Heading(level, ..)) => {
+                flush(&mut inlines, &mut blocks);
+                heading = Some(level as u8);
+            }
+            Event::End(Tag::Heading(..)) => {
+                blocks.push(Block::Heading(
+                    heading.take().unwrap_or(1),
+                    std::mem::take(&mut inlines),
+                ));
+            }
+            Event::Start(Tag::BlockQuote) => {
+                flush(&mut inlines, &mut blocks);
+                blocks.push(Block::Quote(parse_blocks(events)));
+            }
+            Event::Start(Tag::CodeBlock(_)) => {
+                flush(&mut inlines, &mut blocks);
+
+                let mut code = String::new();
+
+                for event in &mut *events {
+                    match event {
+                        Event::Text(text) => code.push_str(&text),
+                        _ => break,
+                    }
+                }
+
+                blocks.push(Block::Code(code));
+            }
+            Event::Start(Tag::List(start)) => {
+                flush(&mut inlines, &mut blocks);
+
+                let mut items = Vec::new();
+
+                while let Some(Event::Start(Tag::Item)) = events.next() {
+                    items.push(parse_blocks(events));
+                }
+
+                blocks.push(Block::List { start, items });
+            }
+            Event::Start(Tag::Strong) => format.strong += 1,
+            Event::End(Tag::Strong) => {
+                format.strong = format.strong.saturating_sub(1)
+            }
+            Event::Start(Tag::Emphasis) => format.emphasis += 1,
+            Event::End(Tag::Emphasis) => {
+                format.emphasis = format.emphasis.saturating_sub(1)
+            }
+            Event::Start(Tag::Link(_, url, _)) => {
+                format.link = Some(url.to_string());
+            }
+            Event::End(Tag::Link(..)) => format.link = None,
+            Event::Start(Tag::Image(_, url, _)) => {
+                format.image = Some(url.to_string());
+            }
+            Event::End(Tag::Image(..)) => {
+                // Images are displayed on their own, between paragraphs
+                if let Some(url) = format.image.take() {
+                    flush(&mut inlines, &mut blocks);
+                    blocks.push(Block::Image(url));
+                }
+            }
+            Event::Start(_) | Event::End(Tag::Strikethrough) => {}
+            Event::End(_) => break,
+            Event::Text(text) | Event::Html(text) => {
+                // The alternative text of an image is not displayed
+                if format.image.is_none() {
+                    inlines.push(format.inline(&text, false));
+                }
+            }
+            Event::Code(code) => inlines.push(format.inline(&code, true)),
+            Event::SoftBreak => inlines.push(format.inline(" ", false)),
+            Event::HardBreak => inlines.push(format.inline("\n", false)),
+            Event::TaskListMarker(is_checked) => inlines.push(format.inline(
+                if is_checked { "\u{2611} " } else { "\u{2610} " },
+                false,
+            )),
+            Event::FootnoteReference(label) => {
+                inlines.push(format.inline(&format!("[{}]", label), false))
+            }
+            Event::Rule => flush(&mut inlines, &mut blocks),
+        }
+    }
+
+    flush(&mut inlines, &mut blocks);
+
+    blocks
+}
+
+fn italic(font: Font) -> Font {
+    match font {
+        Font::System { family, weight, .. } => Font::System {
+            family,
+            weight,
+            style: Style::Italic,
+        },
+        font => font,
+    }
+}
+
+/// The renderer of a [`Markdown`].
+///
+/// It is implemented by every renderer of the widgets a [`Markdown`] is made
+/// of.
+///
+/// [`Markdown`]: struct.Markdown.html
+pub trait Renderer:
+    column::Renderer
+    + row::Renderer
+    + rich_text::Renderer
+    + image::Renderer
+    + space::Renderer
+{
+}
+
+impl<T> Renderer for T where
+    T: column::Renderer
+        + row::Renderer
+        + rich_text::Renderer
+        + image::Renderer
+        + space::Renderer
+{
+}
+
+impl<'a, Message, Renderer> From<Markdown<'a, Message>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'static + self::Renderer,
+    Message: 'static,
+{
+    fn from(markdown: Markdown<'a, Message>) -> Element<'a, Message, Renderer> {
+        markdown
+            .blocks(&markdown.content.blocks)
+            .width(markdown.width)
+            .into()
+    }
+}
@@ -0,0 +1,1144 @@
+//! Offer the commands of your application in a bar of dropdown menus.
+//!
+//! A [`MenuBar`] has some local [`State`].
+//!
+//! [`MenuBar`]: struct.MenuBar.html
+//! [`State`]: struct.State.html
+use crate::{
+    input::{keyboard, mouse, ButtonState},
+    layout, overlay, text,
+    tree::{self, Tree},
+    Clipboard, Element, Event, Font, Hasher, Layout, Length, Point, Rectangle,
+    Size, Widget,
+};
+
+use std::hash::Hash;
+
+// The horizontal space around the title of a menu and the label of an item
+const PADDING: f32 = 8.0;
+
+// The vertical space around the title of a menu and the label of an item
+const ITEM_PADDING: f32 = 5.0;
+
+// The height of a separator
+const SEPARATOR_HEIGHT: f32 = 9.0;
+
+// The space between the label of an item and its shortcut hint or submenu
+// arrow
+const GAP: f32 = 24.0;
+
+const MIN_MENU_WIDTH: f32 = 160.0;
+
+/// A bar of dropdown menus, like the `File`, `Edit`, and `View` menus of a
+/// desktop application.
+///
+/// Clicking the title of a menu opens it. While a menu is open, hovering
+/// another title opens that one instead, and hovering an item with a
+/// submenu opens the submenu next to it.
+///
+/// The menus can also be used with the keyboard. Pressing `Alt` along with
+/// the mnemonic of a menu opens it. While a menu is open, the arrow keys move
+/// through the items and menus, `Enter` activates the highlighted item,
+/// `Escape` closes the innermost menu, and pressing the mnemonic of an item
+/// activates it.
+///
+/// Mnemonics are marked with `&` in labels, like `&File`. Use `&&` for a
+/// literal `&`.
+///
+/// # Example
+/// ```
+/// # use iced_native::{
+/// #     menu_bar::{Item, Menu},
+/// #     MenuBar,
+/// # };
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     New,
+///     Open,
+///     OpenRecent(usize),
+///     Quit,
+/// }
+///
+/// let recent = vec!["notes.txt", "todo.md"];
+///
+/// let file = Menu::new("&File")
+///     .push(Item::new("&New", Message::New).shortcut("Ctrl+N"))
+///     .push(Item::new("&Open...", Message::Open).shortcut("Ctrl+O"))
+///     .push(Item::submenu(recent.iter().enumerate().fold(
+///         Menu::new("Open &Recent"),
+///         |menu, (index, name)| {
+///             menu.push(Item::new(name, Message::OpenRecent(index)))
+///         },
+///     )))
+///     .push(Item::separator())
+///     .push(Item::new("&Quit", Message::Quit).shortcut("Ctrl+Q"));
+///
+/// let menu_bar = MenuBar::managed(vec![file]);
+/// ```
+///
+/// [`MenuBar`]: struct.MenuBar.html
+#[allow(missing_debug_implementations)]
+pub struct MenuBar<'a, Message> {
+    state: tree::State<'a, State>,
+    menus: Vec<Menu<Message>>,
+    width: Length,
+    size: Option<u16>,
+}
+
+impl<'a, Message> MenuBar<'a, Message> {
+    /// Creates a new [`MenuBar`] with some local [`State`] and the given
+    /// menus.
+    ///
+    /// [`MenuBar`]: struct.MenuBar.html
+    /// [`State`]: struct.State.html
+    pub fn new(state: &'a mut State, menus: Vec<Menu<Message>>) -> Self {
+        Self::with_state(tree::State::Borrowed(state), menus)
+    }
+
+    /// Creates a new [`MenuBar`] with the given menus, whose local [`State`]
+    /// is managed by the runtime.
+    ///
+    /// [`MenuBar`]: struct.MenuBar.html
+    /// [`State`]: struct.State.html
+    pub fn managed(menus: Vec<Menu<Message>>) -> Self {
+        Self::with_state(tree::State::managed(), menus)
+    }
+
+    fn with_state(
+        state: tree::State<'a, State>,
+        menus: Vec<Menu<Message>>,
+    ) -> Self {
+        MenuBar {
+            state,
+            menus,
+            width: Length::Fill,
+            size: None,
+        }
+    }
+
+    /// Sets the width of the [`MenuBar`].
+    ///
+    /// [`MenuBar`]: struct.MenuBar.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the text size of the [`MenuBar`] and its menus.
+    ///
+    /// [`MenuBar`]: struct.MenuBar.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+}
+
+/// A dropdown menu of a [`MenuBar`].
+///
+/// [`MenuBar`]: struct.MenuBar.html
+#[derive(Debug, Clone)]
+pub struct Menu<Message> {
+    label: Label,
+    items: Vec<Item<Message>>,
+}
+
+impl<Message> Menu<Message> {
+    /// Creates a new empty [`Menu`] with the given title.
+    ///
+    /// The title may mark a mnemonic with `&`.
+    ///
+    /// [`Menu`]: struct.Menu.html
+    pub fn new(title: &str) -> Self {
+        Menu {
+            label: Label::new(title),
+            items: Vec::new(),
+        }
+    }
+
+    /// Adds an [`Item`] to the [`Menu`].
+    ///
+    /// [`Item`]: struct.Item.html
+    /// [`Menu`]: struct.Menu.html
+    pub fn push(mut self, item: Item<Message>) -> Self {
+        self.items.push(item);
+        self
+    }
+}
+
+/// An entry of a [`Menu`].
+///
+/// [`Menu`]: struct.Menu.html
+#[derive(Debug, Clone)]
+pub struct Item<Message> {
+    label: Label,
+    kind: Kind<Message>,
+    shortcut: Option<String>,
+    is_enabled: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Kind<Message> {
+    Action(Message),
+    Submenu(Vec<Item<Message>>),
+    Separator,
+}
+
+impl<Message> Item<Message> {
+    /// Creates a new [`Item`] producing the given message when activated.
+    ///
+    /// The label may mark a mnemonic with `&`.
+    ///
+    /// [`Item`]: struct.Item.html
+    pub fn new(label: &str, message: Message) -> Self {
+        Self::with_kind(Label::new(label), Kind::Action(message))
+    }
+
+    /// Creates a new [`Item`] opening the given [`Menu`] as a submenu.
+    ///
+    /// [`Item`]: struct.Item.html
+    /// [`Menu`]: struct.Menu.html
+    pub fn submenu(menu: Menu<Message>) -> Self {
+        Self::with_kind(menu.label, Kind::Submenu(menu.items))
+    }
+
+    /// Creates a new [`Item`] separating groups of items.
+    ///
+    /// [`Item`]: struct.Item.html
+    pub fn separator() -> Self {
+        Self::with_kind(Label::new(""), Kind::Separator)
+    }
+
+    fn with_kind(label: Label, kind: Kind<Message>) -> Self {
+        Item {
+            label,
+            kind,
+            shortcut: None,
+            is_enabled: true,
+        }
+    }
+
+    /// Sets the keyboard shortcut hint shown next to the label of the
+    /// [`Item`], like `Ctrl+S`.
+    ///
+    /// It is only a hint. Bind the shortcut itself with a [`Hotkey`].
+    ///
+    /// [`Item`]: struct.Item.html
+    /// [`Hotkey`]: ../../struct.Hotkey.html
+    pub fn shortcut(mut self, hint: impl Into<String>) -> Self {
+        self.shortcut = Some(hint.into());
+        self
+    }
+
+    /// Sets whether the [`Item`] can be activated.
+    ///
+    /// Disabled items are still shown, but they cannot be highlighted.
+    ///
+    /// [`Item`]: struct.Item.html
+    pub fn enabled(mut self, is_enabled: bool) -> Self {
+        self.is_enabled = is_enabled;
+        self
+    }
+
+    fn is_selectable(&self) -> bool {
+        self.is_enabled && !matches!(self.kind, Kind::Separator)
+    }
+
+    fn submenu_items(&self) -> Option<&[Item<Message>]> {
+        match &self.kind {
+            Kind::Submenu(items) if self.is_enabled => Some(items),
+            _ => None,
+        }
+    }
+}
+
+/// The text of a [`Menu`] or [`Item`], with its mnemonic.
+///
+/// [`Menu`]: struct.Menu.html
+/// [`Item`]: struct.Item.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Label {
+    /// The text to show, without the `&` markers.
+    pub text: String,
+
+    /// The byte offset of the mnemonic in the text, if any.
+    pub mnemonic: Option<usize>,
+}
+
+impl Label {
+    /// Creates a new [`Label`], where the first `&` marks the character
+    /// after it as the mnemonic and `&&` stands for a literal `&`.
+    ///
+    /// [`Label`]: struct.Label.html
+    pub fn new(label: &str) -> Self {
+        let mut text = String::with_capacity(label.len());
+        let mut mnemonic = None;
+        let mut chars = label.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match (c, chars.peek()) {
+                ('&', Some('&')) => {
+                    let _ = chars.next();
+                    text.push('&');
+                }
+                ('&', Some(_)) if mnemonic.is_none() => {
+                    mnemonic = Some(text.len());
+                }
+                _ => text.push(c),
+            }
+        }
+
+        Label { text, mnemonic }
+    }
+
+    fn mnemonic_char(&self) -> Option<char> {
+        self.mnemonic
+            .and_then(|offset| self.text[offset..].chars().next())
+            .and_then(|c| c.to_lowercase().next())
+    }
+}
+
+/// The local state of a [`MenuBar`].
+///
+/// [`MenuBar`]: struct.MenuBar.html
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct State {
+    open: Option<usize>,
+    // The highlighted item of every open menu, from the outermost one. Every
+    // menu but the last one highlights the item of the next one.
+    path: Vec<Option<usize>>,
+}
+
+impl State {
+    /// Creates a new [`State`], with every menu closed.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns whether a menu of the [`MenuBar`] is open.
+    ///
+    /// [`MenuBar`]: struct.MenuBar.html
+    pub fn is_open(&self) -> bool {
+        self.open.is_some()
+    }
+
+    /// Returns the items of every open menu, from the outermost one.
+    fn levels<'m, Message>(
+        &self,
+        menus: &'m [Menu<Message>],
+    ) -> Vec<&'m [Item<Message>]> {
+        let mut levels = Vec::new();
+
+        let mut items = match self.open.and_then(|open| menus.get(open)) {
+            Some(menu) => &menu.items[..],
+            None => return levels,
+        };
+
+        for (level, highlighted) in self.path.iter().enumerate() {
+            levels.push(items);
+
+            if level + 1 == self.path.len() {
+                break;
+            }
+
+            // The menus may have changed since the path was built
+            match highlighted
+                .and_then(|index| items.get(index))
+                .and_then(Item::submenu_items)
+            {
+                Some(submenu) => items = submenu,
+                None => break,
+            }
+        }
+
+        levels
+    }
+
+    fn open<Message>(
+        &mut self,
+        index: usize,
+        menus: &[Menu<Message>],
+        highlight_first: bool,
+    ) {
+        let items = &menus[index].items;
+
+        self.open = Some(index);
+        self.path = vec![if highlight_first {
+            items.iter().position(Item::is_selectable)
+        } else {
+            None
+        }];
+    }
+
+    fn close(&mut self) {
+        self.open = None;
+        self.path.clear();
+    }
+
+    /// Highlights an item, opening its submenu if it has one.
+    fn highlight<Message>(
+        &mut self,
+        level: usize,
+        index: usize,
+        items: &[Item<Message>],
+    ) {
+        self.path.truncate(level + 1);
+        self.path[level] = Some(index).filter(|_| items[index].is_selectable());
+
+        if self.path[level].is_some() && items[index].submenu_items().is_some()
+        {
+            self.path.push(None);
+        }
+    }
+
+    /// Activates an item, returning its message if it is an action.
+    fn activate<Message: Clone>(
+        &mut self,
+        level: usize,
+        index: usize,
+        items: &[Item<Message>],
+    ) -> Option<Message> {
+        let item = &items[index];
+
+        if !item.is_selectable() {
+            return None;
+        }
+
+        match &item.kind {
+            Kind::Action(message) => {
+                self.close();
+
+                Some(message.clone())
+            }
+            Kind::Submenu(submenu) => {
+                self.path.truncate(level + 1);
+                self.path[level] = Some(index);
+                self.path.push(submenu.iter().position(Item::is_selectable));
+
+                None
+            }
+            Kind::Separator => None,
+        }
+    }
+}
+
+/// Returns the next selectable item after the given one, wrapping around.
+fn step<Message>(
+    items: &[Item<Message>],
+    current: Option<usize>,
+    forward: bool,
+) -> Option<usize> {
+    let count = items.len();
+
+    (1..=count)
+        .map(|offset| match (current, forward) {
+            (Some(current), true) => (current + offset) % count,
+            (Some(current), false) => (current + count - offset) % count,
+            (None, true) => offset - 1,
+            (None, false) => count - offset,
+        })
+        .find(|index| items[*index].is_selectable())
+}
+
+fn key_char(key_code: keyboard::KeyCode) -> Option<char> {
+    use keyboard::KeyCode;
+
+    Some(match key_code {
+        KeyCode::A => 'a',
+        KeyCode::B => 'b',
+        KeyCode::C => 'c',
+        KeyCode::D => 'd',
+        KeyCode::E => 'e',
+        KeyCode::F => 'f',
+        KeyCode::G => 'g',
+        KeyCode::H => 'h',
+        KeyCode::I => 'i',
+        KeyCode::J => 'j',
+        KeyCode::K => 'k',
+        KeyCode::L => 'l',
+        KeyCode::M => 'm',
+        KeyCode::N => 'n',
+        KeyCode::O => 'o',
+        KeyCode::P => 'p',
+        KeyCode::Q => 'q',
+        KeyCode::R => 'r',
+        KeyCode::S => 's',
+        KeyCode::T => 't',
+        KeyCode::U => 'u',
+        KeyCode::V => 'v',
+        KeyCode::W => 'w',
+        KeyCode::X => 'x',
+        KeyCode::Y => 'y',
+        KeyCode::Z => 'z',
+        KeyCode::Key0 => '0',
+        KeyCode::Key1 => '1',
+        KeyCode::Key2 => '2',
+        KeyCode::Key3 => '3',
+        KeyCode::Key4 => '4',
+        KeyCode::Key5 => '5',
+        KeyCode::Key6 => '6',
+        KeyCode::Key7 => '7',
+        KeyCode::Key8 => '8',
+        KeyCode::Key9 => '9',
+        _ => return None,
+    })
+}
+
+/// The title of a [`Menu`] in a [`MenuBar`].
+///
+/// [`Menu`]: struct.Menu.html
+/// [`MenuBar`]: struct.MenuBar.html
+#[derive(Debug, Clone, Copy)]
+pub struct Title<'a> {
+    /// The bounds of the [`Title`].
+    ///
+    /// [`Title`]: struct.Title.html
+    pub bounds: Rectangle,
+
+    /// The [`Label`] of the [`Menu`].
+    ///
+    /// [`Label`]: struct.Label.html
+    /// [`Menu`]: struct.Menu.html
+    pub label: &'a Label,
+
+    /// Whether the [`Menu`] is open.
+    ///
+    /// [`Menu`]: struct.Menu.html
+    pub is_open: bool,
+}
+
+/// An open [`Menu`], floating over the user interface.
+///
+/// [`Menu`]: struct.Menu.html
+#[derive(Debug, Clone)]
+pub struct Panel<'a> {
+    /// The bounds of the [`Panel`].
+    ///
+    /// [`Panel`]: struct.Panel.html
+    pub bounds: Rectangle,
+
+    /// The entries of the [`Panel`], from top to bottom.
+    ///
+    /// [`Panel`]: struct.Panel.html
+    pub entries: Vec<Entry<'a>>,
+}
+
+/// An [`Item`] of an open [`Menu`].
+///
+/// [`Item`]: struct.Item.html
+/// [`Menu`]: struct.Menu.html
+#[derive(Debug, Clone, Copy)]
+pub struct Entry<'a> {
+    /// The bounds of the [`Entry`].
+    ///
+    /// [`Entry`]: struct.Entry.html
+    pub bounds: Rectangle,
+
+    /// The [`Label`] of the [`Item`].
+    ///
+    /// [`Label`]: struct.Label.html
+    /// [`Item`]: struct.Item.html
+    pub label: &'a Label,
+
+    /// The shortcut hint of the [`Item`], if any.
+    ///
+    /// [`Item`]: struct.Item.html
+    pub shortcut: Option<&'a str>,
+
+    /// Whether the [`Item`] opens a submenu.
+    ///
+    /// [`Item`]: struct.Item.html
+    pub has_submenu: bool,
+
+    /// Whether the [`Item`] is a separator.
+    ///
+    /// [`Item`]: struct.Item.html
+    pub is_separator: bool,
+
+    /// Whether the [`Item`] can be activated.
+    ///
+    /// [`Item`]: struct.Item.html
+    pub is_enabled: bool,
+
+    /// Whether the [`Item`] is highlighted.
+    ///
+    /// [`Item`]: struct.Item.html
+    pub is_highlighted: bool,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for MenuBar<'a, Message>
+where
+    Renderer: self::Renderer,
+    Message: Clone,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self.size.unwrap_or(renderer.default_size());
+        let height = f32::from(text_size) + 2.0 * ITEM_PADDING;
+
+        let mut x = 0.0;
+
+        let titles = self
+            .menus
+            .iter()
+            .map(|menu| {
+                let (width, _) = renderer.measure(
+                    &menu.label.text,
+                    text_size,
+                    Font::Default,
+                    text::Params::default(),
+                    Size::INFINITY,
+                );
+
+                let mut title =
+                    layout::Node::new(Size::new(width + 2.0 * PADDING, height));
+                title.move_to(Point::new(x, 0.0));
+
+                x += title.size().width;
+
+                title
+            })
+            .collect();
+
+        let size = limits
+            .width(self.width)
+            .height(Length::Shrink)
+            .resolve(Size::new(x, height));
+
+        layout::Node::with_children(size, titles)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        let mut state = self.state.borrow_mut();
+
+        let title = layout
+            .children()
+            .position(|title| title.bounds().contains(cursor_position));
+
+        // Clicks and keys over open menus are handled by their overlay
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let (Some(open), Some(index)) = (state.open, title) {
+                    if open != index {
+                        state.open(index, &self.menus, false);
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) => match title {
+                Some(index) if state.open != Some(index) => {
+                    state.open(index, &self.menus, false);
+                }
+                _ => state.close(),
+            },
+            Event::Keyboard(keyboard::Event::Input {
+                key_code,
+                state: ButtonState::Pressed,
+                modifiers,
+            }) if modifiers.alt && state.open.is_none() => {
+                let mnemonic = key_char(key_code);
+
+                if let Some(index) = self.menus.iter().position(|menu| {
+                    mnemonic.is_some() && menu.label.mnemonic_char() == mnemonic
+                }) {
+                    state.open(index, &self.menus, true);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let state = self.state.borrow();
+        let text_size = self.size.unwrap_or(renderer.default_size());
+
+        let titles: Vec<Title<'_>> = self
+            .menus
+            .iter()
+            .zip(layout.children())
+            .enumerate()
+            .map(|(index, (menu, title))| Title {
+                bounds: title.bounds(),
+                label: &menu.label,
+                is_open: state.open == Some(index),
+            })
+            .collect();
+
+        self::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            cursor_position,
+            &titles,
+            text_size,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<MenuBar<'static, ()>>().hash(state);
+
+        self.width.hash(state);
+        self.size.hash(state);
+
+        for menu in &self.menus {
+            menu.label.hash(state);
+        }
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.state.diff(tree);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let open = self.state.borrow().open?;
+        let title = layout.children().nth(open)?.bounds();
+
+        Some(overlay::Element::new(
+            Point::new(title.x, title.y + title.height),
+            Box::new(Menus {
+                state: &mut self.state,
+                menus: &self.menus,
+                size: self.size,
+            }),
+        ))
+    }
+}
+
+// The open menus of a `MenuBar`, cascading from the title of the outermost
+// one
+struct Menus<'a, 'b, Message> {
+    state: &'b mut tree::State<'a, State>,
+    menus: &'b [Menu<Message>],
+    size: Option<u16>,
+}
+
+impl<'a, 'b, Message> Menus<'a, 'b, Message> {
+    fn text_size<Renderer>(&self, renderer: &Renderer) -> u16
+    where
+        Renderer: self::Renderer,
+    {
+        self.size.unwrap_or(renderer.default_size())
+    }
+
+    /// Returns the level and index of the item under the cursor, if any.
+    fn item_at(
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<(usize, usize)> {
+        layout
+            .children()
+            .enumerate()
+            .filter(|(_, panel)| panel.bounds().contains(cursor_position))
+            .last()
+            .and_then(|(level, panel)| {
+                panel
+                    .children()
+                    .position(|item| item.bounds().contains(cursor_position))
+                    .map(|index| (level, index))
+            })
+    }
+}
+
+impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Menus<'a, 'b, Message>
+where
+    Renderer: self::Renderer,
+    Message: Clone,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let state = self.state.borrow();
+        let text_size = self.text_size(renderer);
+        let item_height = f32::from(text_size) + 2.0 * ITEM_PADDING;
+
+        let measure = |content: &str| {
+            renderer
+                .measure(
+                    content,
+                    text_size,
+                    Font::Default,
+                    text::Params::default(),
+                    Size::INFINITY,
+                )
+                .0
+        };
+
+        let mut panels: Vec<layout::Node> = Vec::new();
+
+        for (level, items) in state.levels(self.menus).into_iter().enumerate() {
+            let width = items
+                .iter()
+                .map(|item| {
+                    let extra = match (&item.shortcut, &item.kind) {
+                        (Some(shortcut), _) => GAP + measure(shortcut),
+                        (None, Kind::Submenu(_)) => GAP,
+                        _ => 0.0,
+                    };
+
+                    measure(&item.label.text) + extra + 2.0 * PADDING
+                })
+                .fold(MIN_MENU_WIDTH, f32::max);
+
+            let mut y = 0.0;
+
+            let entries: Vec<layout::Node> = items
+                .iter()
+                .map(|item| {
+                    let height = match item.kind {
+                        Kind::Separator => SEPARATOR_HEIGHT,
+                        _ => item_height,
+                    };
+
+                    let mut entry = layout::Node::new(Size::new(width, height));
+                    entry.move_to(Point::new(0.0, y));
+
+                    y += height;
+
+                    entry
+                })
+                .collect();
+
+            let mut panel =
+                layout::Node::with_children(Size::new(width, y), entries);
+
+            // Submenus open next to their item, on the left when there is no
+            // room on the right
+            let origin = match panels.last() {
+                None => {
+                    Point::new(position.x.min(bounds.width - width), position.y)
+                }
+                Some(parent) => {
+                    let parent_bounds = parent.bounds();
+                    let item = state.path[level - 1]
+                        .and_then(|index| parent.children().get(index))
+                        .map(|item| item.bounds().y)
+                        .unwrap_or(0.0);
+
+                    let right = parent_bounds.x + parent_bounds.width;
+
+                    Point::new(
+                        if right + width <= bounds.width {
+                            right
+                        } else {
+                            parent_bounds.x - width
+                        },
+                        (parent_bounds.y + item).min(bounds.height - y),
+                    )
+                }
+            };
+
+            panel.move_to(Point::new(origin.x.max(0.0), origin.y.max(0.0)));
+            panels.push(panel);
+        }
+
+        // The overlay spans every panel, which are positioned relative to it
+        let area = panels
+            .iter()
+            .map(layout::Node::bounds)
+            .fold(None, |area: Option<Rectangle>, panel| {
+                Some(match area {
+                    None => panel,
+                    Some(area) => {
+                        let x = area.x.min(panel.x);
+                        let y = area.y.min(panel.y);
+
+                        Rectangle {
+                            x,
+                            y,
+                            width: (area.x + area.width)
+                                .max(panel.x + panel.width)
+                                - x,
+                            height: (area.y + area.height)
+                                .max(panel.y + panel.height)
+                                - y,
+                        }
+                    }
+                })
+            })
+            .unwrap_or(Rectangle {
+                x: position.x,
+                y: position.y,
+                width: 0.0,
+                height: 0.0,
+            });
+
+        for panel in &mut panels {
+            let bounds = panel.bounds();
+
+            panel.move_to(Point::new(bounds.x - area.x, bounds.y - area.y));
+        }
+
+        let mut node = layout::Node::with_children(
+            Size::new(area.width, area.height),
+            panels,
+        );
+        node.move_to(Point::new(area.x, area.y));
+
+        node
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let state = self.state.borrow();
+        let text_size = self.text_size(renderer);
+
+        let panels: Vec<Panel<'_>> = state
+            .levels(self.menus)
+            .into_iter()
+            .zip(layout.children())
+            .enumerate()
+            .map(|(level, (items, panel))| Panel {
+                bounds: panel.bounds(),
+                entries: items
+                    .iter()
+                    .zip(panel.children())
+                    .enumerate()
+                    .map(|(index, (item, entry))| Entry {
+                        bounds: entry.bounds(),
+                        label: &item.label,
+                        shortcut: item.shortcut.as_deref(),
+                        has_submenu: matches!(item.kind, Kind::Submenu(_)),
+                        is_separator: matches!(item.kind, Kind::Separator),
+                        is_enabled: item.is_enabled,
+                        is_highlighted: state.path[level] == Some(index),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        self::Renderer::menus(renderer, cursor_position, &panels, text_size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> overlay::Status {
+        let menus = self.menus;
+        let mut state = self.state.borrow_mut();
+        let levels = state.levels(menus);
+
+        let (level, items) = match levels.last() {
+            Some(items) => (levels.len() - 1, *items),
+            None => return overlay::Status::Ignored,
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some((level, index)) =
+                    Self::item_at(layout, cursor_position)
+                {
+                    state.highlight(level, index, levels[level]);
+                }
+
+                overlay::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) => {
+                match Self::item_at(layout, cursor_position) {
+                    Some((level, index)) => {
+                        messages.extend(state.activate(
+                            level,
+                            index,
+                            levels[level],
+                        ));
+                    }
+                    // Clicks outside of the overlay close the menus in the
+                    // bar itself, so the title of the open menu can toggle it
+                    None if layout.bounds().contains(cursor_position) => {
+                        state.close();
+                    }
+                    None => {}
+                }
+
+                overlay::Status::Ignored
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code,
+                state: ButtonState::Pressed,
+                modifiers,
+            }) => {
+                let highlighted = state.path[level];
+
+                match key_code {
+                    keyboard::KeyCode::Down | keyboard::KeyCode::Up => {
+                        state.path[level] = step(
+                            items,
+                            highlighted,
+                            key_code == keyboard::KeyCode::Down,
+                        );
+                    }
+                    keyboard::KeyCode::Home => {
+                        state.path[level] = step(items, None, true);
+                    }
+                    keyboard::KeyCode::End => {
+                        state.path[level] = step(items, None, false);
+                    }
+                    keyboard::KeyCode::Right => {
+                        match highlighted.filter(|index| {
+                            items[*index].submenu_items().is_some()
+                        }) {
+                            Some(index) => {
+                                let _ = state.activate(level, index, items);
+                            }
+                            None => {
+                                let open = state.open.unwrap_or(0);
+
+                                state.open(
+                                    (open + 1) % menus.len(),
+                                    menus,
+                                    true,
+                                );
+                            }
+                        }
+                    }
+                    keyboard::KeyCode::Left => {
+                        if level > 0 {
+                            let _ = state.path.pop();
+                        } else {
+                            let open = state.open.unwrap_or(0);
+
+                            state.open(
+                                (open + menus.len() - 1) % menus.len(),
+                                menus,
+                                true,
+                            );
+                        }
+                    }
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter
+                    | keyboard::KeyCode::Space => {
+                        if let Some(index) = highlighted {
+                            messages
+                                .extend(state.activate(level, index, items));
+                        }
+                    }
+                    keyboard::KeyCode::Escape => {
+                        if level > 0 {
+                            let _ = state.path.pop();
+                        } else {
+                            state.close();
+                        }
+                    }
+                    _ if !modifiers.control && !modifiers.logo => {
+                        let mnemonic = key_char(key_code);
+
+                        if let Some(index) = items.iter().position(|item| {
+                            mnemonic.is_some()
+                                && item.label.mnemonic_char() == mnemonic
+                        }) {
+                            messages
+                                .extend(state.activate(level, index, items));
+                        }
+                    }
+                    _ => {}
+                }
+
+                // The widgets under the menus do not get the keyboard while
+                // they are open
+                overlay::Status::Captured
+            }
+            Event::Keyboard(_) | Event::Ime(_) => overlay::Status::Captured,
+            _ => overlay::Status::Ignored,
+        }
+    }
+}
+
+/// The renderer of a [`MenuBar`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`MenuBar`] in your user interface.
+///
+/// [`MenuBar`]: struct.MenuBar.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer {
+    /// Draws a [`MenuBar`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`MenuBar`]
+    ///   * the cursor position
+    ///   * the [`Title`] of every menu
+    ///   * the text size
+    ///
+    /// [`MenuBar`]: struct.MenuBar.html
+    /// [`Title`]: struct.Title.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        titles: &[Title<'_>],
+        text_size: u16,
+    ) -> Self::Output;
+
+    /// Draws the open menus of a [`MenuBar`], floating over the user
+    /// interface.
+    ///
+    /// It receives:
+    ///   * the cursor position
+    ///   * the [`Panel`] of every open menu, from the outermost one
+    ///   * the text size
+    ///
+    /// [`MenuBar`]: struct.MenuBar.html
+    /// [`Panel`]: struct.Panel.html
+    fn menus(
+        &mut self,
+        cursor_position: Point,
+        panels: &[Panel<'_>],
+        text_size: u16,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<MenuBar<'a, Message>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'static + Clone,
+{
+    fn from(menu_bar: MenuBar<'a, Message>) -> Element<'a, Message, Renderer> {
+        Element::new(menu_bar)
+    }
+}
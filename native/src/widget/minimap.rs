@@ -0,0 +1,384 @@
+//! Display a scaled-down overview of scrollable content.
+//!
+//! A [`MiniMap`] has some local [`State`].
+//!
+//! [`MiniMap`]: struct.MiniMap.html
+//! [`State`]: struct.State.html
+use crate::{
+    cached,
+    input::{mouse, ButtonState},
+    layout, scrollable,
+    tree::{self, Link, Tree},
+    Clipboard, Element, Event, Hasher, Layout, Length, Point, Rectangle, Size,
+    Widget,
+};
+
+use std::hash::Hash;
+
+/// A scaled-down overview of the content of a [`Scrollable`], with a
+/// draggable rectangle that shows and controls its visible part.
+///
+/// A [`MiniMap`] does not draw the content again. Instead, it reuses the
+/// output of a [`Cached`] widget wrapping the content of the [`Scrollable`].
+/// Both widgets must be created with a [`Link`] to their state, which the
+/// [`MiniMap`] uses to follow them.
+///
+/// # Example
+/// ```
+/// # use iced_native::{cached, renderer::Null, scrollable, tree::Link, Text};
+/// #
+/// # type Cached<'a, Message> = iced_native::Cached<'a, Message, Null>;
+/// # type MiniMap<'a> = iced_native::MiniMap<'a, Null>;
+/// # type Row<'a, Message> = iced_native::Row<'a, Message, Null>;
+/// # type Scrollable<'a, Message> = iced_native::Scrollable<'a, Message, Null>;
+/// struct Document {
+///     scroll: Link<scrollable::State>,
+///     cache: Link<cached::State<()>>,
+///     text: String,
+/// }
+///
+/// impl Document {
+///     fn view(&self) -> Row<'_, ()> {
+///         let content = Scrollable::linked(&self.scroll).push(
+///             Cached::linked(&self.cache, Text::new(self.text.as_str())),
+///         );
+///
+///         Row::new()
+///             .push(content)
+///             .push(MiniMap::managed(&self.scroll, &self.cache))
+///     }
+/// }
+/// ```
+///
+/// [`Scrollable`]: ../scrollable/struct.Scrollable.html
+/// [`Cached`]: ../cached/struct.Cached.html
+/// [`Link`]: ../../tree/struct.Link.html
+/// [`MiniMap`]: struct.MiniMap.html
+#[allow(missing_debug_implementations)]
+pub struct MiniMap<'a, Renderer: crate::Renderer> {
+    state: tree::State<'a, State>,
+    scrollable: Link<scrollable::State>,
+    cache: Link<cached::State<Renderer::Output>>,
+    width: Length,
+    height: Length,
+}
+
+impl<'a, Renderer> MiniMap<'a, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    /// Creates a new [`MiniMap`] with some local [`State`], following the
+    /// given [`Scrollable`] and [`Cached`] links.
+    ///
+    /// [`MiniMap`]: struct.MiniMap.html
+    /// [`State`]: struct.State.html
+    /// [`Scrollable`]: ../scrollable/struct.Scrollable.html
+    /// [`Cached`]: ../cached/struct.Cached.html
+    pub fn new(
+        state: &'a mut State,
+        scrollable: &Link<scrollable::State>,
+        cache: &Link<cached::State<Renderer::Output>>,
+    ) -> Self {
+        Self::with_state(tree::State::Borrowed(state), scrollable, cache)
+    }
+
+    /// Creates a new [`MiniMap`] whose local [`State`] is managed by the
+    /// runtime.
+    ///
+    /// It expects the same arguments as [`new`], except for the [`State`].
+    ///
+    /// [`MiniMap`]: struct.MiniMap.html
+    /// [`State`]: struct.State.html
+    /// [`new`]: #method.new
+    pub fn managed(
+        scrollable: &Link<scrollable::State>,
+        cache: &Link<cached::State<Renderer::Output>>,
+    ) -> Self {
+        Self::with_state(tree::State::managed(), scrollable, cache)
+    }
+
+    fn with_state(
+        state: tree::State<'a, State>,
+        scrollable: &Link<scrollable::State>,
+        cache: &Link<cached::State<Renderer::Output>>,
+    ) -> Self {
+        MiniMap {
+            state,
+            scrollable: scrollable.clone(),
+            cache: cache.clone(),
+            width: Length::Units(120),
+            height: Length::Fill,
+        }
+    }
+
+    /// Sets the width of the [`MiniMap`].
+    ///
+    /// [`MiniMap`]: struct.MiniMap.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`MiniMap`].
+    ///
+    /// [`MiniMap`]: struct.MiniMap.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+}
+
+/// The local state of a [`MiniMap`].
+///
+/// [`MiniMap`]: struct.MiniMap.html
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct State {
+    grabbed_at: Option<f32>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns whether the viewport of the [`MiniMap`] is being dragged.
+    ///
+    /// [`MiniMap`]: struct.MiniMap.html
+    pub fn is_dragging(&self) -> bool {
+        self.grabbed_at.is_some()
+    }
+}
+
+/// The geometry of a [`MiniMap`], mapping the content of a [`Scrollable`]
+/// into the bounds of the [`MiniMap`].
+///
+/// [`MiniMap`]: struct.MiniMap.html
+/// [`Scrollable`]: ../scrollable/struct.Scrollable.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Projection {
+    /// The bounds of the whole content of the [`Scrollable`].
+    ///
+    /// [`Scrollable`]: ../scrollable/struct.Scrollable.html
+    pub content: Rectangle,
+
+    /// The bounds the content is scaled into.
+    pub target: Rectangle,
+
+    /// The visible part of the content, scaled into the [`MiniMap`].
+    ///
+    /// [`MiniMap`]: struct.MiniMap.html
+    pub viewport: Rectangle,
+
+    /// The ratio between the size of the target and the content.
+    pub scale: f32,
+}
+
+impl Projection {
+    fn new(
+        bounds: Rectangle,
+        viewport: Rectangle,
+        content: Rectangle,
+        offset: f32,
+    ) -> Self {
+        let scale = (bounds.width / content.width.max(1.0))
+            .min(bounds.height / content.height.max(1.0))
+            .min(1.0);
+
+        let target = Rectangle {
+            x: bounds.x + (bounds.width - content.width * scale) / 2.0,
+            y: bounds.y,
+            width: content.width * scale,
+            height: content.height * scale,
+        };
+
+        Projection {
+            content,
+            target,
+            viewport: Rectangle {
+                x: target.x + (viewport.x - content.x) * scale,
+                y: target.y + offset * scale,
+                width: viewport.width * scale,
+                height: viewport.height.min(content.height) * scale,
+            },
+            scale,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for MiniMap<'a, Renderer>
+where
+    Renderer: self::Renderer,
+    Renderer::Output: Clone,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = limits
+            .width(self.width)
+            .height(self.height)
+            .resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+        let mut state = self.state.borrow_mut();
+        let mut scrollable = self.scrollable.borrow_mut();
+
+        let (viewport, content) = match scrollable.last_bounds() {
+            Some(last_bounds) => last_bounds,
+            None => return,
+        };
+
+        let offset = scrollable.offset(viewport, content) as f32;
+        let projection = Projection::new(bounds, viewport, content, offset);
+
+        match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) if bounds.contains(cursor_position) => {
+                let grabbed_at =
+                    if projection.viewport.contains(cursor_position) {
+                        cursor_position.y - projection.viewport.y
+                    } else {
+                        projection.viewport.height / 2.0
+                    };
+
+                state.grabbed_at = Some(grabbed_at);
+            }
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Released,
+            }) => {
+                state.grabbed_at = None;
+                return;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {}
+            _ => return,
+        }
+
+        if let Some(grabbed_at) = state.grabbed_at {
+            let hidden = content.height - viewport.height;
+
+            if hidden > 0.0 {
+                let offset =
+                    (cursor_position.y - grabbed_at - projection.target.y)
+                        / projection.scale;
+
+                scrollable.scroll_to(
+                    (offset / hidden).clamp(0.0, 1.0),
+                    viewport,
+                    content,
+                );
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let state = self.state.borrow();
+        let scrollable = self.scrollable.borrow();
+
+        let projection = scrollable.last_bounds().map(|(viewport, content)| {
+            let offset = scrollable.offset(viewport, content) as f32;
+
+            Projection::new(bounds, viewport, content, offset)
+        });
+
+        renderer.draw(
+            bounds,
+            cursor_position,
+            self.cache.borrow().output().map(|(output, _)| output),
+            projection,
+            state.is_dragging(),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<MiniMap<'static, crate::renderer::Null>>()
+            .hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.state.diff(tree);
+    }
+
+    fn captures_mouse(&self) -> bool {
+        self.state.borrow().is_dragging()
+    }
+}
+
+/// The renderer of a [`MiniMap`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`MiniMap`] in your user interface.
+///
+/// [`MiniMap`]: struct.MiniMap.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// Draws a [`MiniMap`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`MiniMap`]
+    ///   * the current cursor position
+    ///   * the cached output of the content, if available, which should be
+    ///     scaled down from the content bounds of the [`Projection`] into its
+    ///     target
+    ///   * the [`Projection`] of the content, if the linked [`Scrollable`]
+    ///     has been drawn already
+    ///   * whether the viewport is being dragged
+    ///
+    /// [`MiniMap`]: struct.MiniMap.html
+    /// [`Projection`]: struct.Projection.html
+    /// [`Scrollable`]: ../scrollable/struct.Scrollable.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        content: Option<Self::Output>,
+        projection: Option<Projection>,
+        is_dragging: bool,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<MiniMap<'a, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Renderer::Output: Clone,
+{
+    fn from(minimap: MiniMap<'a, Renderer>) -> Element<'a, Message, Renderer> {
+        Element::new(minimap)
+    }
+}
@@ -0,0 +1,375 @@
+//! Show some content in a dialog, blocking the rest of the user interface.
+use crate::{
+    input::{keyboard, mouse, ButtonState},
+    layout, overlay, Clipboard, Color, Element, Event, Focusable, Hasher,
+    Layout, Length, Point, Rectangle, Size, Tree, Widget,
+};
+
+use std::hash::Hash;
+
+/// An element that can show a dialog on top of its content.
+///
+/// While the dialog is open, the rest of the user interface is dimmed by a
+/// backdrop and stops receiving clicks, scrolls, and keyboard input. The
+/// dialog is centered in the window.
+///
+/// A [`Modal`] does not close by itself. Pressing `Escape` or clicking the
+/// backdrop produces the message set with [`Modal::on_close`], and your
+/// application decides whether to close the dialog by not providing it
+/// anymore.
+///
+/// # Example
+/// ```
+/// # use iced_native::{Column, Text};
+/// #
+/// # type Modal<'a, Message> =
+/// #     iced_native::Modal<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Delete,
+///     Cancel,
+/// }
+///
+/// let is_confirming = true;
+///
+/// let dialog = Column::new()
+///     .padding(20)
+///     .push(Text::new("Delete this file?"));
+///
+/// let modal = Modal::new(
+///     Text::new("Files"),
+///     if is_confirming { Some(dialog.into()) } else { None },
+/// )
+/// .on_close(Message::Cancel);
+/// ```
+///
+/// [`Modal`]: struct.Modal.html
+/// [`Modal::on_close`]: struct.Modal.html#method.on_close
+#[allow(missing_debug_implementations)]
+pub struct Modal<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    dialog: Option<Element<'a, Message, Renderer>>,
+    on_close: Option<Message>,
+    padding: u16,
+    backdrop: Color,
+}
+
+impl<'a, Message, Renderer> Modal<'a, Message, Renderer> {
+    /// Creates a new [`Modal`] with the given content and dialog, if open.
+    ///
+    /// [`Modal`]: struct.Modal.html
+    pub fn new<E>(
+        content: E,
+        dialog: Option<Element<'a, Message, Renderer>>,
+    ) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Modal {
+            content: content.into(),
+            dialog,
+            on_close: None,
+            padding: 20,
+            backdrop: Color {
+                r: 0.0,
+                g: 0.0,
+                b: 0.0,
+                a: 0.5,
+            },
+        }
+    }
+
+    /// Sets the message that should be produced when `Escape` is pressed or
+    /// the backdrop is clicked while the dialog is open.
+    pub fn on_close(mut self, message: Message) -> Self {
+        self.on_close = Some(message);
+        self
+    }
+
+    /// Sets the minimum distance between the dialog and the edges of the
+    /// window.
+    pub fn padding(mut self, units: u16) -> Self {
+        self.padding = units;
+        self
+    }
+
+    /// Sets the [`Color`] of the backdrop dimming the content.
+    ///
+    /// [`Color`]: ../../struct.Color.html
+    pub fn backdrop(mut self, color: Color) -> Self {
+        self.backdrop = color;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Modal<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+    Message: Clone,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self.content.layout(renderer, limits);
+
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.content.widget.on_event(
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.content.draw(
+            renderer,
+            layout.children().next().unwrap(),
+            cursor_position,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Modal<'static, (), ()>>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        match &mut self.dialog {
+            Some(dialog) => {
+                let children = tree.diff_children(&[None, None]);
+
+                self.content.diff(&mut children[0]);
+                dialog.diff(&mut children[1]);
+            }
+            // Closing the dialog drops its state, so it opens fresh
+            None => self.content.diff(&mut tree.diff_children(&[None])[0]),
+        }
+    }
+
+    #[cfg(feature = "devtools")]
+    fn inspect(&self, layout: Layout<'_>) -> crate::inspect::Node {
+        super::inspect_children(
+            std::any::type_name::<Self>(),
+            std::slice::from_ref(&self.content),
+            &[None],
+            layout,
+        )
+    }
+
+    fn hover(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        path: &mut Vec<usize>,
+    ) {
+        super::hover_children(
+            std::slice::from_ref(&self.content),
+            layout,
+            cursor_position,
+            path,
+        );
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        super::focusables_children(
+            std::slice::from_ref(&self.content),
+            path,
+            focusables,
+        );
+    }
+
+    fn captures_mouse(&self) -> bool {
+        super::captures_mouse_children(std::slice::from_ref(&self.content))
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        match &mut self.dialog {
+            Some(dialog) => Some(overlay::Element::new(
+                Point::new(0.0, 0.0),
+                Box::new(Dialog {
+                    content: dialog,
+                    on_close: self.on_close.clone(),
+                    padding: self.padding,
+                    backdrop: self.backdrop,
+                }),
+            )),
+            None => super::overlay_children(
+                std::slice::from_mut(&mut self.content),
+                layout,
+            ),
+        }
+    }
+}
+
+// The dialog of a `Modal`, covering the whole user interface
+struct Dialog<'a, 'b, Message, Renderer> {
+    content: &'b mut Element<'a, Message, Renderer>,
+    on_close: Option<Message>,
+    padding: u16,
+    backdrop: Color,
+}
+
+impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Dialog<'a, 'b, Message, Renderer>
+where
+    Renderer: self::Renderer,
+    Message: Clone,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        _position: Point,
+    ) -> layout::Node {
+        let limits = layout::Limits::new(Size::ZERO, bounds)
+            .pad(f32::from(self.padding));
+
+        let mut content = self.content.layout(renderer, &limits);
+        let size = content.size();
+
+        content.move_to(Point::new(
+            ((bounds.width - size.width) / 2.0).max(0.0),
+            ((bounds.height - size.height) / 2.0).max(0.0),
+        ));
+
+        layout::Node::with_children(bounds, vec![content])
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let content = self.content.draw(
+            renderer,
+            layout.children().next().unwrap(),
+            cursor_position,
+        );
+
+        self::Renderer::draw(renderer, layout.bounds(), self.backdrop, content)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> overlay::Status {
+        let content = layout.children().next().unwrap();
+
+        self.content.widget.on_event(
+            event.clone(),
+            content,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+
+        let is_closed = match event {
+            Event::Keyboard(keyboard::Event::Input {
+                key_code: keyboard::KeyCode::Escape,
+                state: ButtonState::Pressed,
+                ..
+            }) => true,
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) => !content.bounds().contains(cursor_position),
+            _ => false,
+        };
+
+        if is_closed {
+            messages.extend(self.on_close.clone());
+        }
+
+        // The dialog covers the whole user interface, so clicks and scrolls
+        // never reach the widgets under it. The keyboard has to be blocked
+        // too.
+        match event {
+            Event::Keyboard(_) | Event::Ime(_) => overlay::Status::Captured,
+            _ => overlay::Status::Ignored,
+        }
+    }
+}
+
+/// The renderer of a [`Modal`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Modal`] in your user interface.
+///
+/// [`Modal`]: struct.Modal.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// Draws the open dialog of a [`Modal`].
+    ///
+    /// It receives:
+    ///   * the bounds of the whole user interface
+    ///   * the [`Color`] of the backdrop covering them
+    ///   * the drawn dialog
+    ///
+    /// [`Modal`]: struct.Modal.html
+    /// [`Color`]: ../../struct.Color.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        backdrop: Color,
+        dialog: Self::Output,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Modal<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'static + self::Renderer,
+    Message: 'static + Clone,
+{
+    fn from(
+        modal: Modal<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(modal)
+    }
+}
@@ -0,0 +1,419 @@
+//! Listen to any mouse interaction with some content.
+//!
+//! A [`MouseArea`] has some local [`State`].
+//!
+//! [`MouseArea`]: struct.MouseArea.html
+//! [`State`]: struct.State.html
+use crate::{
+    input::{mouse, ButtonState},
+    layout, overlay,
+    tree::{self, Tree},
+    Clipboard, Element, Event, Focusable, Hasher, Hover, Layout, Length,
+    MouseCursor, Point, Widget,
+};
+
+use std::hash::Hash;
+
+/// An element that reports the mouse interactions with its content.
+///
+/// A [`MouseArea`] produces messages when the cursor enters, moves over, or
+/// exits its content, and when any mouse button is pressed or released over
+/// it. Positions are relative to the top-left corner of the [`MouseArea`].
+///
+/// The content keeps receiving every event, so a [`MouseArea`] can wrap
+/// interactive widgets too. A [`MouseArea`] can also replace the mouse cursor
+/// of its content.
+///
+/// # Example
+/// ```
+/// # use iced_native::{input::mouse, MouseCursor, Point, Text};
+/// #
+/// # type MouseArea<'a, Message> =
+/// #     iced_native::MouseArea<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     ContextMenuRequested(Point),
+///     Pressed(mouse::Button, Point),
+/// }
+///
+/// let area = MouseArea::managed(Text::new("Right-click me!"))
+///     .on_press(|button, position| match button {
+///         mouse::Button::Right => Message::ContextMenuRequested(position),
+///         _ => Message::Pressed(button, position),
+///     })
+///     .cursor(MouseCursor::Crosshair);
+/// ```
+///
+/// [`MouseArea`]: struct.MouseArea.html
+#[allow(missing_debug_implementations)]
+pub struct MouseArea<'a, Message, Renderer> {
+    state: tree::State<'a, State>,
+    content: Element<'a, Message, Renderer>,
+    on_press: Option<Box<dyn Fn(mouse::Button, Point) -> Message + 'a>>,
+    on_release: Option<Box<dyn Fn(mouse::Button, Point) -> Message + 'a>>,
+    on_move: Option<Box<dyn Fn(Point) -> Message + 'a>>,
+    on_enter: Option<Message>,
+    on_exit: Option<Message>,
+    cursor: Option<MouseCursor>,
+}
+
+impl<'a, Message, Renderer> MouseArea<'a, Message, Renderer> {
+    /// Creates a new [`MouseArea`] with some local [`State`] and the given
+    /// content.
+    ///
+    /// [`MouseArea`]: struct.MouseArea.html
+    /// [`State`]: struct.State.html
+    pub fn new<E>(state: &'a mut State, content: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Self::with_state(tree::State::Borrowed(state), content)
+    }
+
+    /// Creates a new [`MouseArea`] with the given content, whose local
+    /// [`State`] is managed by the runtime.
+    ///
+    /// [`MouseArea`]: struct.MouseArea.html
+    /// [`State`]: struct.State.html
+    pub fn managed<E>(content: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Self::with_state(tree::State::managed(), content)
+    }
+
+    fn with_state<E>(state: tree::State<'a, State>, content: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        MouseArea {
+            state,
+            content: content.into(),
+            on_press: None,
+            on_release: None,
+            on_move: None,
+            on_enter: None,
+            on_exit: None,
+            cursor: None,
+        }
+    }
+
+    /// Sets the message that should be produced when a mouse button is
+    /// pressed over the [`MouseArea`].
+    ///
+    /// It receives the pressed button and the position of the cursor.
+    ///
+    /// [`MouseArea`]: struct.MouseArea.html
+    pub fn on_press<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(mouse::Button, Point) -> Message,
+    {
+        self.on_press = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that should be produced when a mouse button that was
+    /// pressed over the [`MouseArea`] is released, even if the cursor has
+    /// left it.
+    ///
+    /// It receives the released button and the position of the cursor.
+    ///
+    /// [`MouseArea`]: struct.MouseArea.html
+    pub fn on_release<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(mouse::Button, Point) -> Message,
+    {
+        self.on_release = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that should be produced when the cursor moves over
+    /// the [`MouseArea`].
+    ///
+    /// It receives the position of the cursor.
+    ///
+    /// [`MouseArea`]: struct.MouseArea.html
+    pub fn on_move<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(Point) -> Message,
+    {
+        self.on_move = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that should be produced when the cursor enters the
+    /// [`MouseArea`].
+    ///
+    /// [`MouseArea`]: struct.MouseArea.html
+    pub fn on_enter(mut self, message: Message) -> Self {
+        self.on_enter = Some(message);
+        self
+    }
+
+    /// Sets the message that should be produced when the cursor exits the
+    /// [`MouseArea`].
+    ///
+    /// [`MouseArea`]: struct.MouseArea.html
+    pub fn on_exit(mut self, message: Message) -> Self {
+        self.on_exit = Some(message);
+        self
+    }
+
+    /// Sets the [`MouseCursor`] shown while the cursor is over the
+    /// [`MouseArea`], or while a button pressed over it is held, replacing
+    /// the one of its content.
+    ///
+    /// [`MouseCursor`]: ../../enum.MouseCursor.html
+    /// [`MouseArea`]: struct.MouseArea.html
+    pub fn cursor(mut self, cursor: MouseCursor) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+}
+
+/// The local state of a [`MouseArea`].
+///
+/// [`MouseArea`]: struct.MouseArea.html
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct State {
+    is_hovered: bool,
+    pressed: Vec<mouse::Button>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the cursor is over the [`MouseArea`].
+    ///
+    /// [`MouseArea`]: struct.MouseArea.html
+    pub fn is_hovered(&self) -> bool {
+        self.is_hovered
+    }
+
+    /// Returns whether the given button was pressed over the [`MouseArea`]
+    /// and has not been released yet.
+    ///
+    /// [`MouseArea`]: struct.MouseArea.html
+    pub fn is_pressed(&self, button: mouse::Button) -> bool {
+        self.pressed.contains(&button)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for MouseArea<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+    Message: Clone,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self.content.layout(renderer, limits);
+
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.content.widget.on_event(
+            event.clone(),
+            layout.children().next().unwrap(),
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+
+        let mut state = self.state.borrow_mut();
+
+        let bounds = layout.bounds();
+        let position = Point::new(
+            cursor_position.x - bounds.x,
+            cursor_position.y - bounds.y,
+        );
+
+        match event {
+            Event::Hover(hover) if layout.is_hovered() => {
+                state.is_hovered = hover == Hover::Entered;
+
+                let message = if state.is_hovered {
+                    &self.on_enter
+                } else {
+                    &self.on_exit
+                };
+
+                messages.extend(message.clone());
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+                if state.is_hovered =>
+            {
+                if let Some(on_move) = &self.on_move {
+                    messages.push(on_move(position));
+                }
+            }
+            Event::Mouse(mouse::Event::Input {
+                button,
+                state: ButtonState::Pressed,
+            }) if bounds.contains(cursor_position) => {
+                if !state.pressed.contains(&button) {
+                    state.pressed.push(button);
+                }
+
+                if let Some(on_press) = &self.on_press {
+                    messages.push(on_press(button, position));
+                }
+            }
+            Event::Mouse(mouse::Event::Input {
+                button,
+                state: ButtonState::Released,
+            }) => {
+                if let Some(index) =
+                    state.pressed.iter().position(|pressed| *pressed == button)
+                {
+                    let _ = state.pressed.remove(index);
+
+                    if let Some(on_release) = &self.on_release {
+                        messages.push(on_release(button, position));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let state = self.state.borrow();
+
+        let content = self.content.draw(
+            renderer,
+            layout.children().next().unwrap(),
+            cursor_position,
+        );
+
+        let is_active = state.is_hovered || !state.pressed.is_empty();
+
+        renderer.draw(self.cursor.filter(|_| is_active), content)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<MouseArea<'static, (), ()>>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.state.diff(tree);
+        self.content.diff(&mut tree.diff_children(&[None])[0]);
+    }
+
+    #[cfg(feature = "devtools")]
+    fn inspect(&self, layout: Layout<'_>) -> crate::inspect::Node {
+        super::inspect_children(
+            std::any::type_name::<Self>(),
+            std::slice::from_ref(&self.content),
+            &[None],
+            layout,
+        )
+    }
+
+    fn hover(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        path: &mut Vec<usize>,
+    ) {
+        super::hover_children(
+            std::slice::from_ref(&self.content),
+            layout,
+            cursor_position,
+            path,
+        );
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        super::focusables_children(
+            std::slice::from_ref(&self.content),
+            path,
+            focusables,
+        );
+    }
+
+    fn captures_mouse(&self) -> bool {
+        super::captures_mouse_children(std::slice::from_ref(&self.content))
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        super::overlay_children(std::slice::from_mut(&mut self.content), layout)
+    }
+}
+
+/// The renderer of a [`MouseArea`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`MouseArea`] in your user interface.
+///
+/// [`MouseArea`]: struct.MouseArea.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// Draws a [`MouseArea`].
+    ///
+    /// It receives:
+    ///   * the [`MouseCursor`] replacing the one of the content, if any
+    ///   * the drawn content
+    ///
+    /// [`MouseArea`]: struct.MouseArea.html
+    /// [`MouseCursor`]: ../../enum.MouseCursor.html
+    fn draw(
+        &mut self,
+        mouse_cursor: Option<MouseCursor>,
+        content: Self::Output,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<MouseArea<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'static + self::Renderer,
+    Message: 'static + Clone,
+{
+    fn from(
+        mouse_area: MouseArea<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(mouse_area)
+    }
+}
@@ -0,0 +1,557 @@
+//! Navigate between the pages of your application.
+//!
+//! A [`Stack`] keeps track of the routes of your application, while a
+//! [`Navigation`] widget displays the current page and animates the
+//! transition from the previous one.
+//!
+//! [`Stack`]: struct.Stack.html
+//! [`Navigation`]: struct.Navigation.html
+use crate::{
+    input::{keyboard, ButtonState},
+    layout, overlay, Clipboard, Element, Event, Focusable, Hasher, Layout,
+    Length, Point, Rectangle, Tree, Widget,
+};
+
+use std::{
+    hash::Hash,
+    time::{Duration, Instant},
+};
+
+/// A typed stack of routes.
+///
+/// # Example
+/// ```
+/// # use iced_native::navigation::Stack;
+/// #
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Route {
+///     Home,
+///     Settings,
+/// }
+///
+/// let mut stack = Stack::new(Route::Home);
+///
+/// stack.push(Route::Settings);
+/// assert_eq!(stack.current(), &Route::Settings);
+///
+/// stack.pop();
+/// assert_eq!(stack.current(), &Route::Home);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Stack<Route> {
+    routes: Vec<Route>,
+    animation: Animation,
+    duration: Duration,
+    leaving: Option<Leaving<Route>>,
+}
+
+#[derive(Debug, Clone)]
+struct Leaving<Route> {
+    route: Route,
+    direction: Direction,
+    started_at: Instant,
+}
+
+impl<Route> Stack<Route>
+where
+    Route: Clone,
+{
+    /// Creates a new [`Stack`] with the given root route.
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn new(root: Route) -> Self {
+        Stack {
+            routes: vec![root],
+            animation: Animation::Slide,
+            duration: Duration::from_millis(250),
+            leaving: None,
+        }
+    }
+
+    /// Sets the [`Animation`] of the transitions of the [`Stack`].
+    ///
+    /// [`Animation`]: enum.Animation.html
+    /// [`Stack`]: struct.Stack.html
+    pub fn animation(mut self, animation: Animation) -> Self {
+        self.animation = animation;
+        self
+    }
+
+    /// Sets the duration of the transitions of the [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Returns the current route of the [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn current(&self) -> &Route {
+        self.routes.last().expect("Navigation stack is never empty")
+    }
+
+    /// Returns all the routes of the [`Stack`], from the root to the current
+    /// one.
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn routes(&self) -> &[Route] {
+        &self.routes
+    }
+
+    /// Returns whether the [`Stack`] can go back to a previous route.
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn can_go_back(&self) -> bool {
+        self.routes.len() > 1
+    }
+
+    /// Pushes a new route on top of the [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn push(&mut self, route: Route) {
+        self.leave(Direction::Forward);
+        self.routes.push(route);
+    }
+
+    /// Pops the current route of the [`Stack`], going back to the previous
+    /// one.
+    ///
+    /// The root route is never popped. It returns the popped route, if any.
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn pop(&mut self) -> Option<Route> {
+        if !self.can_go_back() {
+            return None;
+        }
+
+        self.leave(Direction::Backward);
+        self.routes.pop()
+    }
+
+    /// Replaces the current route of the [`Stack`].
+    ///
+    /// It returns the replaced route.
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn replace(&mut self, route: Route) -> Route {
+        self.leave(Direction::Forward);
+
+        std::mem::replace(
+            self.routes
+                .last_mut()
+                .expect("Navigation stack is never empty"),
+            route,
+        )
+    }
+
+    /// Returns the route that is being left and the current [`Transition`],
+    /// if the [`Stack`] is animating.
+    ///
+    /// [`Transition`]: struct.Transition.html
+    /// [`Stack`]: struct.Stack.html
+    pub fn transition(&self) -> Option<(&Route, Transition)> {
+        let leaving = self.leaving.as_ref()?;
+
+        let elapsed = leaving.started_at.elapsed().as_secs_f32();
+        let duration = self.duration.as_secs_f32();
+
+        if elapsed >= duration || self.animation == Animation::None {
+            return None;
+        }
+
+        Some((
+            &leaving.route,
+            Transition {
+                animation: self.animation,
+                direction: leaving.direction,
+                progress: elapsed / duration,
+            },
+        ))
+    }
+
+    /// Returns whether the [`Stack`] is currently animating a transition.
+    ///
+    /// A [`Navigation`] keeps redrawing your user interface while this is
+    /// `true`.
+    ///
+    /// [`Stack`]: struct.Stack.html
+    /// [`Navigation`]: struct.Navigation.html
+    pub fn is_animating(&self) -> bool {
+        self.transition().is_some()
+    }
+
+    fn leave(&mut self, direction: Direction) {
+        self.leaving = Some(Leaving {
+            route: self.current().clone(),
+            direction,
+            started_at: Instant::now(),
+        });
+    }
+}
+
+/// The animation of a [`Transition`].
+///
+/// [`Transition`]: struct.Transition.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Animation {
+    /// The pages are swapped instantly.
+    None,
+
+    /// The new page slides in, pushing the previous one out.
+    Slide,
+
+    /// The previous page fades out and the new page fades in.
+    Fade,
+}
+
+/// The direction of a [`Transition`].
+///
+/// [`Transition`]: struct.Transition.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// A new route was pushed.
+    Forward,
+
+    /// A route was popped.
+    Backward,
+}
+
+/// An ongoing transition between two pages.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transition {
+    /// The [`Animation`] of the transition.
+    ///
+    /// [`Animation`]: enum.Animation.html
+    pub animation: Animation,
+
+    /// The [`Direction`] of the transition.
+    ///
+    /// [`Direction`]: enum.Direction.html
+    pub direction: Direction,
+
+    /// The progress of the transition, from `0.0` to `1.0`.
+    pub progress: f32,
+}
+
+/// A widget that displays the current page of a [`Stack`] and animates the
+/// transitions between pages.
+///
+/// [`Stack`]: struct.Stack.html
+#[allow(missing_debug_implementations)]
+pub struct Navigation<'a, Message, Renderer> {
+    current: Element<'a, Message, Renderer>,
+    leaving: Option<(Element<'a, Message, Renderer>, Transition)>,
+    on_back: Option<Message>,
+    width: Length,
+    height: Length,
+}
+
+impl<'a, Message, Renderer> Navigation<'a, Message, Renderer> {
+    /// Creates a new [`Navigation`] displaying the given page.
+    ///
+    /// [`Navigation`]: struct.Navigation.html
+    pub fn new<E>(current: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Navigation {
+            current: current.into(),
+            leaving: None,
+            on_back: None,
+            width: Length::Fill,
+            height: Length::Fill,
+        }
+    }
+
+    /// Sets the page that is being left and the [`Transition`] to animate.
+    ///
+    /// You will normally obtain the [`Transition`] from
+    /// [`Stack::transition`].
+    ///
+    /// [`Transition`]: struct.Transition.html
+    /// [`Stack::transition`]: struct.Stack.html#method.transition
+    pub fn leaving<E>(mut self, page: E, transition: Transition) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.leaving = Some((page.into(), transition));
+        self
+    }
+
+    /// Sets the message that will be produced when the user requests to go
+    /// back, using the back key of the keyboard, `Cmd+[` on macOS, or
+    /// `Alt+Left` elsewhere.
+    ///
+    /// [`Navigation`]: struct.Navigation.html
+    pub fn on_back(mut self, message: Message) -> Self {
+        self.on_back = Some(message);
+        self
+    }
+
+    /// Sets the width of the [`Navigation`].
+    ///
+    /// [`Navigation`]: struct.Navigation.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Navigation`].
+    ///
+    /// [`Navigation`]: struct.Navigation.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Navigation<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+    Message: Clone,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let mut current = self.current.layout(renderer, &limits);
+        let size = limits.resolve(current.size());
+
+        let mut children = Vec::with_capacity(2);
+
+        if let Some((leaving, transition)) = &self.leaving {
+            let mut leaving = leaving.layout(renderer, &limits);
+
+            if transition.animation == Animation::Slide {
+                let progress = transition.progress.clamp(0.0, 1.0);
+
+                let (current_x, leaving_x) = match transition.direction {
+                    Direction::Forward => {
+                        (size.width * (1.0 - progress), -size.width * progress)
+                    }
+                    Direction::Backward => {
+                        (-size.width * (1.0 - progress), size.width * progress)
+                    }
+                };
+
+                current.bounds.x = current_x.round();
+                leaving.bounds.x = leaving_x.round();
+            }
+
+            children.push(current);
+            children.push(leaving);
+        } else {
+            children.push(current);
+        }
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        if let Event::Keyboard(keyboard::Event::Input {
+            key_code,
+            state: ButtonState::Pressed,
+            modifiers,
+        }) = event
+        {
+            // Alt+Left moves the cursor of text inputs by words on macOS
+            let is_back = match key_code {
+                keyboard::KeyCode::WebBack
+                | keyboard::KeyCode::NavigateBackward => true,
+                keyboard::KeyCode::LBracket => {
+                    cfg!(target_os = "macos") && modifiers.logo
+                }
+                keyboard::KeyCode::Left => {
+                    !cfg!(target_os = "macos") && modifiers.alt
+                }
+                _ => false,
+            };
+
+            if is_back {
+                if let Some(on_back) = self.on_back.clone() {
+                    messages.push(on_back);
+                    return;
+                }
+            }
+        }
+
+        self.current.widget.on_event(
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let mut children = layout.children();
+        let current_layout = children.next().unwrap();
+
+        let current =
+            self.current.draw(renderer, current_layout, cursor_position);
+
+        let leaving = match (&self.leaving, children.next()) {
+            (Some((leaving, transition)), Some(layout)) => {
+                renderer.schedule_redraw(Instant::now());
+
+                Some((
+                    leaving.draw(renderer, layout, Point::new(-1.0, -1.0)),
+                    *transition,
+                ))
+            }
+            _ => None,
+        };
+
+        self::Renderer::draw(renderer, layout.bounds(), current, leaving)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Navigation<'static, (), ()>>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.current.hash_layout(state);
+
+        if let Some((leaving, transition)) = &self.leaving {
+            transition.animation.hash(state);
+            transition.direction.hash(state);
+            ((transition.progress * 1000.0) as u32).hash(state);
+
+            leaving.hash_layout(state);
+        }
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        let trees = tree.diff_children(&[None, None]);
+
+        self.current.diff(&mut trees[0]);
+
+        if let Some((leaving, _)) = &mut self.leaving {
+            leaving.diff(&mut trees[1]);
+        }
+    }
+
+    fn hover(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        path: &mut Vec<usize>,
+    ) {
+        super::hover_children(
+            std::slice::from_ref(&self.current),
+            layout,
+            cursor_position,
+            path,
+        );
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        super::focusables_children(
+            std::slice::from_ref(&self.current),
+            path,
+            focusables,
+        );
+    }
+
+    fn captures_mouse(&self) -> bool {
+        super::captures_mouse_children(std::slice::from_ref(&self.current))
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        super::overlay_children(std::slice::from_mut(&mut self.current), layout)
+    }
+
+    #[cfg(feature = "devtools")]
+    fn inspect(&self, layout: Layout<'_>) -> crate::inspect::Node {
+        let pages = std::iter::once(&self.current)
+            .chain(self.leaving.as_ref().map(|(leaving, _)| leaving));
+
+        crate::inspect::Node {
+            children: pages
+                .zip(layout.children())
+                .map(|(page, layout)| page.inspect(layout))
+                .collect(),
+            ..crate::inspect::Node::new(
+                std::any::type_name::<Self>(),
+                layout.bounds(),
+            )
+        }
+    }
+}
+
+/// The renderer of a [`Navigation`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Navigation`] in your user interface.
+///
+/// [`Navigation`]: struct.Navigation.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// Draws a [`Navigation`].
+    ///
+    /// It receives:
+    /// - the bounds of the [`Navigation`]
+    /// - the drawn current page
+    /// - the drawn page that is being left, if any, and its [`Transition`]
+    ///
+    /// Pages that slide are already positioned by the layout. The renderer is
+    /// only in charge of clipping them and applying any fading.
+    ///
+    /// [`Navigation`]: struct.Navigation.html
+    /// [`Transition`]: struct.Transition.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        current: Self::Output,
+        leaving: Option<(Self::Output, Transition)>,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Navigation<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'static + Clone,
+{
+    fn from(
+        navigation: Navigation<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(navigation)
+    }
+}
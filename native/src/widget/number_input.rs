@@ -0,0 +1,613 @@
+//! Let your users type a number or change it in steps.
+//!
+//! A [`NumberInput`] has some local [`State`].
+//!
+//! [`NumberInput`]: struct.NumberInput.html
+//! [`State`]: struct.State.html
+use crate::{
+    input::{keyboard, mouse, ButtonState},
+    layout, text_input,
+    tree::{self, Tree},
+    Clipboard, Element, Event, Focusable, Hasher, Layout, Length, Point,
+    Rectangle, Size, TextInput, Widget,
+};
+
+use std::fmt::Display;
+use std::hash::Hash;
+use std::str::FromStr;
+
+/// A [`TextInput`] for numbers, with buttons to increase and decrease its
+/// value by a step.
+///
+/// Typed text only produces a message when it is a number inside of the
+/// range of the [`NumberInput`]. Any other text is kept while the
+/// [`NumberInput`] has the focus. Pressing `Enter` or moving the focus away
+/// replaces it with the value, clamped to the range if needed.
+///
+/// The value also changes with the spin buttons, the up and down arrows, and
+/// the mouse wheel while the [`NumberInput`] has the focus.
+///
+/// # Example
+/// ```
+/// # use iced_native::{number_input, NumberInput};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     QuantityChanged(u32),
+/// }
+///
+/// let mut state = number_input::State::new();
+/// let quantity = 3;
+///
+/// let input =
+///     NumberInput::new(&mut state, quantity, Message::QuantityChanged)
+///         .min(1)
+///         .max(99);
+/// ```
+///
+/// [`TextInput`]: ../text_input/struct.TextInput.html
+/// [`NumberInput`]: struct.NumberInput.html
+#[allow(missing_debug_implementations)]
+pub struct NumberInput<'a, T, Message> {
+    state: tree::State<'a, Editing>,
+    input: TextInput<'a, String>,
+    value: T,
+    min: Option<T>,
+    max: Option<T>,
+    step: T,
+    width: Length,
+    on_change: Box<dyn Fn(T) -> Message + 'a>,
+}
+
+impl<'a, T, Message> NumberInput<'a, T, Message>
+where
+    T: Number,
+{
+    /// Creates a new [`NumberInput`] with some local [`State`], the current
+    /// value, and a function producing a message when the value changes.
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(state: &'a mut State, value: T, on_change: F) -> Self
+    where
+        F: 'a + Fn(T) -> Message,
+    {
+        let State { input, editing } = state;
+
+        let text = editing.text.clone().unwrap_or_else(|| value.to_string());
+
+        Self::with_state(
+            tree::State::Borrowed(editing),
+            TextInput::new(input, "", &text, |text| text),
+            value,
+            on_change,
+        )
+    }
+
+    /// Creates a new [`NumberInput`] whose local [`State`] is managed by the
+    /// runtime.
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    /// [`State`]: struct.State.html
+    pub fn managed<F>(value: T, on_change: F) -> Self
+    where
+        F: 'a + Fn(T) -> Message,
+    {
+        Self::with_state(
+            tree::State::managed(),
+            TextInput::managed("", &value.to_string(), |text| text),
+            value,
+            on_change,
+        )
+    }
+
+    fn with_state<F>(
+        state: tree::State<'a, Editing>,
+        input: TextInput<'a, String>,
+        value: T,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'a + Fn(T) -> Message,
+    {
+        NumberInput {
+            state,
+            input: input.width(Length::Fill).padding(5),
+            value,
+            min: None,
+            max: None,
+            step: T::ONE,
+            width: Length::Units(120),
+            on_change: Box::new(on_change),
+        }
+    }
+
+    /// Sets the minimum value of the [`NumberInput`].
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn min(mut self, min: T) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum value of the [`NumberInput`].
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn max(mut self, max: T) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the amount added or removed by the spin buttons, the arrow keys,
+    /// and the mouse wheel.
+    ///
+    /// By default, it is one.
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the width of the [`NumberInput`], including its spin buttons.
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the padding of the [`NumberInput`].
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn padding(mut self, units: u16) -> Self {
+        self.input = self.input.padding(units);
+        self
+    }
+
+    /// Sets the text size of the [`NumberInput`].
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.input = self.input.size(size);
+        self
+    }
+
+    fn is_in_range(&self, value: T) -> bool {
+        self.min.is_none_or(|min| value >= min)
+            && self.max.is_none_or(|max| value <= max)
+    }
+
+    fn clamp(&self, value: T) -> T {
+        match (self.min, self.max) {
+            (Some(min), _) if value < min => min,
+            (_, Some(max)) if value > max => max,
+            _ => value,
+        }
+    }
+
+    fn can_increase(&self) -> bool {
+        self.max.is_none_or(|max| self.value < max)
+    }
+
+    fn can_decrease(&self) -> bool {
+        self.min.is_none_or(|min| self.value > min)
+    }
+
+    // Moves the value by a step, dropping any typed text
+    fn spin(&mut self, direction: Direction, messages: &mut Vec<Message>) {
+        let value = self.clamp(match direction {
+            Direction::Up => self.value.add(self.step),
+            Direction::Down => self.value.sub(self.step),
+        });
+
+        self.state.borrow_mut().text = None;
+        self.input.set_value(&value.to_string());
+
+        if value != self.value {
+            messages.push((self.on_change)(value));
+        }
+    }
+
+    // Replaces any typed text with the value it represents
+    fn commit(&mut self, messages: &mut Vec<Message>) {
+        let text = match self.state.borrow_mut().text.take() {
+            Some(text) => text,
+            None => return,
+        };
+
+        let value = text
+            .trim()
+            .parse::<T>()
+            .map(|value| self.clamp(value))
+            .unwrap_or(self.value);
+
+        self.input.set_value(&value.to_string());
+
+        if value != self.value {
+            messages.push((self.on_change)(value));
+        }
+    }
+
+    fn buttons(&self, bounds: Rectangle) -> Buttons {
+        let width = bounds.height.min(bounds.width);
+        let x = bounds.x + bounds.width - width;
+
+        Buttons {
+            increase: Rectangle {
+                x,
+                y: bounds.y,
+                width,
+                height: bounds.height / 2.0,
+            },
+            decrease: Rectangle {
+                x,
+                y: bounds.y + bounds.height / 2.0,
+                width,
+                height: bounds.height / 2.0,
+            },
+            can_increase: self.can_increase(),
+            can_decrease: self.can_decrease(),
+        }
+    }
+}
+
+/// The local state of a [`NumberInput`].
+///
+/// [`NumberInput`]: struct.NumberInput.html
+#[derive(Debug, Default, Clone)]
+pub struct State {
+    input: text_input::State,
+    editing: Editing,
+}
+
+impl State {
+    /// Creates a new [`State`], representing an unfocused [`NumberInput`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns whether the [`NumberInput`] is currently focused or not.
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn is_focused(&self) -> bool {
+        self.input.is_focused()
+    }
+}
+
+// The text typed in a `NumberInput`, until it is committed
+#[derive(Debug, Default, Clone, PartialEq)]
+struct Editing {
+    text: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Up,
+    Down,
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for NumberInput<'a, T, Message>
+where
+    T: Number,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width);
+
+        // The spin buttons are as wide as the input is tall
+        let height = Widget::<String, Renderer>::layout(
+            &self.input,
+            renderer,
+            &limits.loose(),
+        )
+        .size()
+        .height;
+
+        let input = Widget::<String, Renderer>::layout(
+            &self.input,
+            renderer,
+            &limits.shrink(Size::new(height, 0.0)),
+        );
+
+        let size = input.size();
+
+        layout::Node::with_children(
+            Size::new(size.width + height, size.height),
+            vec![input],
+        )
+        .inherit_baseline()
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+        let buttons = self.buttons(bounds);
+        let is_focused = self.input.is_focused();
+
+        match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) if buttons.increase.contains(cursor_position) => {
+                self.spin(Direction::Up, messages);
+                return;
+            }
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) if buttons.decrease.contains(cursor_position) => {
+                self.spin(Direction::Down, messages);
+                return;
+            }
+            // The wheel only spins the value of a focused input, so it does
+            // not get in the way of scrolling past it
+            Event::Mouse(mouse::Event::WheelScrolled { pixels, .. })
+                if is_focused && bounds.contains(cursor_position) =>
+            {
+                if pixels.y > 0.0 {
+                    self.spin(Direction::Up, messages);
+                } else if pixels.y < 0.0 {
+                    self.spin(Direction::Down, messages);
+                }
+
+                return;
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code,
+                state: ButtonState::Pressed,
+                ..
+            }) if is_focused => match key_code {
+                keyboard::KeyCode::Up => {
+                    self.spin(Direction::Up, messages);
+                    return;
+                }
+                keyboard::KeyCode::Down => {
+                    self.spin(Direction::Down, messages);
+                    return;
+                }
+                keyboard::KeyCode::Enter | keyboard::KeyCode::NumpadEnter => {
+                    self.commit(messages);
+                    return;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+
+        let mut edits = Vec::new();
+
+        Widget::<String, Renderer>::on_event(
+            &mut self.input,
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            &mut edits,
+            renderer,
+            clipboard,
+        );
+
+        if let Some(text) = edits.pop() {
+            let value = text.trim().parse::<T>().ok();
+
+            self.state.borrow_mut().text = Some(text);
+
+            // Numbers outside of the range may be on their way to one inside
+            // of it, so they are not clamped until committed
+            if let Some(value) = value {
+                if value != self.value && self.is_in_range(value) {
+                    messages.push((self.on_change)(value));
+                }
+            }
+        }
+
+        if is_focused && !self.input.is_focused() {
+            self.commit(messages);
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+
+        let is_valid = self.state.borrow().text.as_ref().is_none_or(|text| {
+            text.trim()
+                .parse::<T>()
+                .is_ok_and(|value| self.is_in_range(value))
+        });
+
+        let input = Widget::<String, Renderer>::draw(
+            &self.input,
+            renderer,
+            layout.children().next().unwrap(),
+            cursor_position,
+        );
+
+        self::Renderer::draw(
+            renderer,
+            bounds,
+            cursor_position,
+            input,
+            &self.buttons(bounds),
+            is_valid,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<NumberInput<'static, (), ()>>().hash(state);
+
+        self.width.hash(state);
+        Widget::<String, Renderer>::hash_layout(&self.input, state);
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.state.diff(tree);
+
+        Widget::<String, Renderer>::diff(
+            &mut self.input,
+            &mut tree.diff_children(&[None])[0],
+        );
+
+        // Managed state is only known now, so the typed text is restored
+        if let Some(text) = self.state.borrow().text.as_ref() {
+            self.input.set_value(text);
+        }
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        path.push(0);
+        Widget::<String, Renderer>::focusables(&self.input, path, focusables);
+        let _ = path.pop();
+    }
+
+    fn captures_mouse(&self) -> bool {
+        Widget::<String, Renderer>::captures_mouse(&self.input)
+    }
+}
+
+/// A number that can be edited in a [`NumberInput`].
+///
+/// It is implemented for all the primitive integer and floating point types.
+///
+/// [`NumberInput`]: struct.NumberInput.html
+pub trait Number: Copy + PartialOrd + FromStr + Display {
+    /// The default step of a [`NumberInput`].
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    const ONE: Self;
+
+    /// Adds a step to the number, saturating at its bounds.
+    fn add(self, step: Self) -> Self;
+
+    /// Subtracts a step from the number, saturating at its bounds.
+    fn sub(self, step: Self) -> Self;
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                const ONE: $t = 1;
+
+                fn add(self, step: $t) -> $t {
+                    self.saturating_add(step)
+                }
+
+                fn sub(self, step: $t) -> $t {
+                    self.saturating_sub(step)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! float {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                const ONE: $t = 1.0;
+
+                fn add(self, step: $t) -> $t {
+                    self + step
+                }
+
+                fn sub(self, step: $t) -> $t {
+                    self - step
+                }
+            }
+        )*
+    };
+}
+
+integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+float!(f32, f64);
+
+/// The spin buttons of a [`NumberInput`].
+///
+/// [`NumberInput`]: struct.NumberInput.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Buttons {
+    /// The bounds of the button increasing the value.
+    pub increase: Rectangle,
+
+    /// The bounds of the button decreasing the value.
+    pub decrease: Rectangle,
+
+    /// Whether the value is below the maximum.
+    pub can_increase: bool,
+
+    /// Whether the value is above the minimum.
+    pub can_decrease: bool,
+}
+
+/// The renderer of a [`NumberInput`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`NumberInput`] in your user interface.
+///
+/// [`NumberInput`]: struct.NumberInput.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text_input::Renderer {
+    /// Draws a [`NumberInput`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`NumberInput`]
+    ///   * the cursor position
+    ///   * the drawn text input
+    ///   * the spin [`Buttons`]
+    ///   * whether the typed text is a number inside of the range
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    /// [`Buttons`]: struct.Buttons.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        input: Self::Output,
+        buttons: &Buttons,
+        is_valid: bool,
+    ) -> Self::Output;
+}
+
+impl<'a, T, Message, Renderer> From<NumberInput<'a, T, Message>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'static + Number,
+    Renderer: 'static + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        number_input: NumberInput<'a, T, Message>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(number_input)
+    }
+}
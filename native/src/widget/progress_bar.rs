@@ -0,0 +1,178 @@
+//! Show the progress of a task.
+use crate::{
+    layout, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use std::{hash::Hash, ops::RangeInclusive};
+
+/// A bar filled proportionally to the progress of a task.
+///
+/// It can display some text on top of it, like a percentage or the amount of
+/// downloaded bytes.
+///
+/// # Example
+/// ```
+/// # use iced_native::ProgressBar;
+/// #
+/// let downloaded = 42.0;
+///
+/// let progress_bar = ProgressBar::new(0.0..=100.0, downloaded)
+///     .label(format!("{}%", downloaded));
+/// ```
+#[derive(Debug)]
+pub struct ProgressBar {
+    range: RangeInclusive<f32>,
+    value: f32,
+    label: Option<String>,
+    width: Length,
+    height: u16,
+    text_size: u16,
+}
+
+impl ProgressBar {
+    /// Creates a new [`ProgressBar`].
+    ///
+    /// It expects:
+    ///   * an inclusive range of possible values
+    ///   * the current value of the [`ProgressBar`]
+    ///
+    /// [`ProgressBar`]: struct.ProgressBar.html
+    pub fn new(range: RangeInclusive<f32>, value: f32) -> Self {
+        ProgressBar {
+            value: value.max(*range.start()).min(*range.end()),
+            range,
+            label: None,
+            width: Length::Fill,
+            height: 20,
+            text_size: 14,
+        }
+    }
+
+    /// Sets the text displayed on top of the [`ProgressBar`].
+    ///
+    /// [`ProgressBar`]: struct.ProgressBar.html
+    pub fn label<T: Into<String>>(mut self, label: T) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the width of the [`ProgressBar`].
+    ///
+    /// [`ProgressBar`]: struct.ProgressBar.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`ProgressBar`], in pixels.
+    ///
+    /// [`ProgressBar`]: struct.ProgressBar.html
+    pub fn height(mut self, height: u16) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the text size of the label of the [`ProgressBar`].
+    ///
+    /// [`ProgressBar`]: struct.ProgressBar.html
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Returns the completed fraction of the [`ProgressBar`], between `0.0`
+    /// and `1.0`.
+    ///
+    /// [`ProgressBar`]: struct.ProgressBar.html
+    pub fn progress(&self) -> f32 {
+        let (start, end) = (*self.range.start(), *self.range.end());
+
+        if end > start {
+            (self.value - start) / (end - start)
+        } else {
+            1.0
+        }
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for ProgressBar
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Units(self.height)
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = limits
+            .width(self.width)
+            .height(Length::Units(self.height))
+            .resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.draw(
+            layout.bounds(),
+            self.progress(),
+            self.label.as_deref(),
+            self.text_size,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<ProgressBar>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`ProgressBar`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`ProgressBar`] in your user interface.
+///
+/// [`ProgressBar`]: struct.ProgressBar.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// Draws a [`ProgressBar`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`ProgressBar`]
+    ///   * the completed fraction, between `0.0` and `1.0`
+    ///   * the label of the [`ProgressBar`], if any
+    ///   * the text size of the label
+    ///
+    /// [`ProgressBar`]: struct.ProgressBar.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        progress: f32,
+        label: Option<&str>,
+        text_size: u16,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<ProgressBar> for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn from(progress_bar: ProgressBar) -> Element<'a, Message, Renderer> {
+        Element::new(progress_bar)
+    }
+}
@@ -0,0 +1,305 @@
+//! Encode and display information in a QR code.
+//!
+//! A [`QrCode`] displays some previously encoded [`Data`].
+//!
+//! [`QrCode`]: struct.QrCode.html
+//! [`Data`]: struct.Data.html
+use crate::{
+    layout, Color, Element, Hasher, Layout, Length, Point, Rectangle, Size,
+    Widget,
+};
+
+use std::hash::Hash;
+
+const DEFAULT_CELL_SIZE: u16 = 4;
+const DEFAULT_QUIET_ZONE: u16 = 4;
+
+/// A type of matrix barcode consisting of squares and dots.
+///
+/// # Example
+/// ```
+/// # use iced_native::{qr_code, QrCode};
+/// #
+/// let data = qr_code::Data::new("https://github.com/hecrj/iced")
+///     .expect("Encode data");
+///
+/// let qr_code = QrCode::new(&data).cell_size(5);
+/// ```
+#[derive(Debug)]
+pub struct QrCode<'a> {
+    data: &'a Data,
+    cell_size: u16,
+    quiet_zone: u16,
+    dark: Color,
+    light: Color,
+}
+
+impl<'a> QrCode<'a> {
+    /// Creates a new [`QrCode`] displaying the given [`Data`].
+    ///
+    /// [`QrCode`]: struct.QrCode.html
+    /// [`Data`]: struct.Data.html
+    pub fn new(data: &'a Data) -> Self {
+        QrCode {
+            data,
+            cell_size: DEFAULT_CELL_SIZE,
+            quiet_zone: DEFAULT_QUIET_ZONE,
+            dark: Color::BLACK,
+            light: Color::WHITE,
+        }
+    }
+
+    /// Sets the size of the cells of the [`QrCode`], in units.
+    ///
+    /// [`QrCode`]: struct.QrCode.html
+    pub fn cell_size(mut self, cell_size: u16) -> Self {
+        self.cell_size = cell_size;
+        self
+    }
+
+    /// Sets the amount of light cells surrounding the [`QrCode`].
+    ///
+    /// Most scanners need a quiet zone of at least 4 cells to recognize a
+    /// [`QrCode`] reliably.
+    ///
+    /// [`QrCode`]: struct.QrCode.html
+    pub fn quiet_zone(mut self, quiet_zone: u16) -> Self {
+        self.quiet_zone = quiet_zone;
+        self
+    }
+
+    /// Sets the color of the dark cells of the [`QrCode`].
+    ///
+    /// [`QrCode`]: struct.QrCode.html
+    pub fn dark<C: Into<Color>>(mut self, color: C) -> Self {
+        self.dark = color.into();
+        self
+    }
+
+    /// Sets the color of the light cells of the [`QrCode`].
+    ///
+    /// [`QrCode`]: struct.QrCode.html
+    pub fn light<C: Into<Color>>(mut self, color: C) -> Self {
+        self.light = color.into();
+        self
+    }
+
+    fn side_length(&self) -> f32 {
+        let cells = self.data.width() + 2 * usize::from(self.quiet_zone);
+
+        (cells * usize::from(self.cell_size)) as f32
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for QrCode<'a>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        _limits: &layout::Limits,
+    ) -> layout::Node {
+        let side_length = self.side_length();
+
+        layout::Node::new(Size::new(side_length, side_length))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.draw(
+            layout.bounds(),
+            self.data,
+            f32::from(self.cell_size),
+            self.quiet_zone,
+            self.dark,
+            self.light,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<QrCode<'static>>().hash(state);
+
+        self.data.width().hash(state);
+        self.cell_size.hash(state);
+        self.quiet_zone.hash(state);
+    }
+}
+
+/// The encoded contents of a [`QrCode`].
+///
+/// Encoding can be expensive. You should create the [`Data`] once and keep it
+/// in your application state.
+///
+/// [`QrCode`]: struct.QrCode.html
+/// [`Data`]: struct.Data.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Data {
+    width: usize,
+    cells: Vec<bool>,
+}
+
+impl Data {
+    /// Encodes the given data using the default [`ErrorCorrection`] level.
+    ///
+    /// [`ErrorCorrection`]: enum.ErrorCorrection.html
+    pub fn new<T: AsRef<[u8]>>(data: T) -> Result<Self, Error> {
+        Self::with_error_correction(data, ErrorCorrection::Medium)
+    }
+
+    /// Encodes the given data using the provided [`ErrorCorrection`] level.
+    ///
+    /// [`ErrorCorrection`]: enum.ErrorCorrection.html
+    pub fn with_error_correction<T: AsRef<[u8]>>(
+        data: T,
+        error_correction: ErrorCorrection,
+    ) -> Result<Self, Error> {
+        let encoded = qrcode::QrCode::with_error_correction_level(
+            data,
+            error_correction.into(),
+        )?;
+
+        Ok(Data {
+            width: encoded.width(),
+            cells: encoded
+                .into_colors()
+                .into_iter()
+                .map(|color| color == qrcode::Color::Dark)
+                .collect(),
+        })
+    }
+
+    /// Returns the amount of cells per side of the encoded [`Data`].
+    ///
+    /// [`Data`]: struct.Data.html
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns whether the cell at the given position is dark.
+    pub fn is_dark(&self, x: usize, y: usize) -> bool {
+        self.cells[y * self.width + x]
+    }
+
+    /// Returns an iterator over the rows of the [`Data`], where `true`
+    /// represents a dark cell.
+    ///
+    /// [`Data`]: struct.Data.html
+    pub fn rows(&self) -> impl Iterator<Item = &[bool]> {
+        self.cells.chunks(self.width)
+    }
+}
+
+/// The error correction level of some [`Data`].
+///
+/// Higher levels can recover from more damage, at the cost of bigger codes.
+///
+/// [`Data`]: struct.Data.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCorrection {
+    /// Up to 7% of the data can be recovered.
+    Low,
+
+    /// Up to 15% of the data can be recovered.
+    Medium,
+
+    /// Up to 25% of the data can be recovered.
+    Quartile,
+
+    /// Up to 30% of the data can be recovered.
+    High,
+}
+
+impl From<ErrorCorrection> for qrcode::EcLevel {
+    fn from(error_correction: ErrorCorrection) -> Self {
+        match error_correction {
+            ErrorCorrection::Low => qrcode::EcLevel::L,
+            ErrorCorrection::Medium => qrcode::EcLevel::M,
+            ErrorCorrection::Quartile => qrcode::EcLevel::Q,
+            ErrorCorrection::High => qrcode::EcLevel::H,
+        }
+    }
+}
+
+/// An error produced when encoding [`Data`].
+///
+/// [`Data`]: struct.Data.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The data is too long to be encoded in a QR code.
+    DataTooLong,
+
+    /// The data contains characters that cannot be encoded.
+    InvalidData,
+}
+
+impl From<qrcode::types::QrError> for Error {
+    fn from(error: qrcode::types::QrError) -> Self {
+        match error {
+            qrcode::types::QrError::DataTooLong => Error::DataTooLong,
+            _ => Error::InvalidData,
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::DataTooLong => write!(f, "data is too long"),
+            Error::InvalidData => write!(f, "data cannot be encoded"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The renderer of a [`QrCode`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`QrCode`] in your user interface.
+///
+/// [`QrCode`]: struct.QrCode.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// Draws a [`QrCode`].
+    ///
+    /// It receives:
+    /// - the bounds of the [`QrCode`], including its quiet zone
+    /// - the encoded [`Data`]
+    /// - the size of a cell
+    /// - the amount of cells of the quiet zone
+    /// - the colors of the dark and light cells
+    ///
+    /// [`QrCode`]: struct.QrCode.html
+    /// [`Data`]: struct.Data.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        data: &Data,
+        cell_size: f32,
+        quiet_zone: u16,
+        dark: Color,
+        light: Color,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<QrCode<'a>> for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn from(qr_code: QrCode<'a>) -> Element<'a, Message, Renderer> {
+        Element::new(qr_code)
+    }
+}
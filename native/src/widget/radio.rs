@@ -147,7 +147,9 @@ where
             &self.label,
             text::Renderer::default_size(renderer),
             Font::Default,
+            text::Params::default(),
             self.label_color,
+            None,
             HorizontalAlignment::Left,
             VerticalAlignment::Center,
         );
@@ -0,0 +1,390 @@
+//! Display an interactive selector of a range inside of a range of values.
+//!
+//! A [`RangeSlider`] has some local [`State`].
+//!
+//! [`RangeSlider`]: struct.RangeSlider.html
+//! [`State`]: struct.State.html
+use crate::{
+    draggable::{self, Drag},
+    input::{keyboard, ButtonState},
+    layout,
+    slider::{self, Axis, Track},
+    tree::{self, Tree},
+    Clipboard, Element, Event, Focus, Focusable, Hasher, Layout, Length, Point,
+    Widget,
+};
+
+use std::{hash::Hash, ops::RangeInclusive};
+
+/// A [`Slider`] with two handles, selecting the values between them.
+///
+/// Pressing the mouse picks the closest handle and drags it. The handles
+/// cannot cross each other.
+///
+/// When it has the focus, the keys of a [`Slider`] move the last handle that
+/// was dragged, except for `Home` and `End`, which move the lower and the
+/// upper handle to the bounds of the range, respectively.
+///
+/// [`Slider`]: ../slider/struct.Slider.html
+///
+/// # Example
+/// ```
+/// # use iced_native::RangeSlider;
+/// #
+/// pub enum Message {
+///     PriceFilterChanged(f32, f32),
+/// }
+///
+/// let (low, high) = (20.0, 80.0);
+///
+/// RangeSlider::managed(0.0..=100.0, (low, high), Message::PriceFilterChanged)
+///     .step(5.0);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct RangeSlider<'a, Message> {
+    state: tree::State<'a, State>,
+    range: RangeInclusive<f32>,
+    values: (f32, f32),
+    on_change: Box<dyn Fn(f32, f32) -> Message>,
+    width: Length,
+    height: Length,
+    axis: Axis,
+    step: Option<f32>,
+    ticks: bool,
+}
+
+impl<'a, Message> RangeSlider<'a, Message> {
+    /// Creates a new [`RangeSlider`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`RangeSlider`]
+    ///   * an inclusive range of possible values
+    ///   * the current lower and upper values of the [`RangeSlider`]
+    ///   * a function that will be called when a handle is dragged. It
+    ///     receives the new lower and upper values and must produce a
+    ///     `Message`.
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(
+        state: &'a mut State,
+        range: RangeInclusive<f32>,
+        values: (f32, f32),
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(f32, f32) -> Message,
+    {
+        Self::with_state(tree::State::Borrowed(state), range, values, on_change)
+    }
+
+    /// Creates a new [`RangeSlider`] whose local [`State`] is managed by the
+    /// runtime.
+    ///
+    /// It expects the same arguments as [`new`], except for the [`State`].
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    /// [`State`]: struct.State.html
+    /// [`new`]: #method.new
+    pub fn managed<F>(
+        range: RangeInclusive<f32>,
+        values: (f32, f32),
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(f32, f32) -> Message,
+    {
+        Self::with_state(tree::State::managed(), range, values, on_change)
+    }
+
+    fn with_state<F>(
+        state: tree::State<'a, State>,
+        range: RangeInclusive<f32>,
+        values: (f32, f32),
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(f32, f32) -> Message,
+    {
+        let clamp = |value: f32| value.max(*range.start()).min(*range.end());
+        let (low, high) = (clamp(values.0), clamp(values.1));
+
+        RangeSlider {
+            state,
+            values: (low.min(high), low.max(high)),
+            range,
+            on_change: Box::new(on_change),
+            width: Length::Fill,
+            height: Length::Fill,
+            axis: Axis::Horizontal,
+            step: None,
+            ticks: false,
+        }
+    }
+
+    /// Sets the width of a horizontal [`RangeSlider`].
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of a vertical [`RangeSlider`].
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`Axis`] of the [`RangeSlider`].
+    ///
+    /// [`Axis`]: ../slider/enum.Axis.html
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Makes the [`RangeSlider`] only produce values that are a multiple of
+    /// the given step away from the start of its range.
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = Some(step).filter(|step| *step > 0.0);
+        self
+    }
+
+    /// Sets whether the [`RangeSlider`] draws a tick mark at every step.
+    ///
+    /// It has no effect without a [`step`].
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    /// [`step`]: #method.step
+    pub fn ticks(mut self, ticks: bool) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    // Moves a handle, without letting it cross the other one
+    fn move_handle(&self, handle: Handle, value: f32) -> (f32, f32) {
+        let (low, high) = self.values;
+
+        match handle {
+            Handle::Low => (value.min(high), high),
+            Handle::High => (low, value.max(low)),
+        }
+    }
+}
+
+/// The local state of a [`RangeSlider`].
+///
+/// [`RangeSlider`]: struct.RangeSlider.html
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct State {
+    drag: draggable::State,
+    handle: Handle,
+    is_focused: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the [`RangeSlider`] is currently focused or not.
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Handle {
+    #[default]
+    Low,
+    High,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for RangeSlider<'a, Message>
+where
+    Renderer: slider::Renderer,
+{
+    fn width(&self) -> Length {
+        match self.axis {
+            Axis::Horizontal => self.width,
+            Axis::Vertical => Length::Shrink,
+        }
+    }
+
+    fn height(&self) -> Length {
+        match self.axis {
+            Axis::Horizontal => Length::Shrink,
+            Axis::Vertical => self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(slider::layout(
+            renderer,
+            limits,
+            self.axis,
+            self.width,
+            self.height,
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+        let mut state = *self.state.borrow();
+
+        let values = match event {
+            Event::Focus(focus) if layout.is_focus_target() => {
+                state.is_focused = focus == Focus::Gained;
+                None
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code,
+                state: ButtonState::Pressed,
+                ..
+            }) if state.is_focused => {
+                let handle = match key_code {
+                    keyboard::KeyCode::Home => Handle::Low,
+                    keyboard::KeyCode::End => Handle::High,
+                    _ => state.handle,
+                };
+
+                let value = match handle {
+                    Handle::Low => self.values.0,
+                    Handle::High => self.values.1,
+                };
+
+                slider::adjust(key_code, value, &self.range, self.step)
+                    .map(|value| self.move_handle(handle, value))
+            }
+            _ => match state.drag.update(event, bounds, cursor_position) {
+                Some(drag @ Drag::Started) | Some(drag @ Drag::Moved(_)) => {
+                    let value = slider::value_at(
+                        self.axis,
+                        bounds,
+                        cursor_position,
+                        &self.range,
+                        self.step,
+                    );
+
+                    if drag == Drag::Started {
+                        let (low, high) = self.values;
+
+                        // When the handles overlap, the side of the cursor
+                        // picks one
+                        state.handle = if value < low
+                            || (value - low).abs() < (value - high).abs()
+                        {
+                            Handle::Low
+                        } else {
+                            Handle::High
+                        };
+                    }
+
+                    Some(self.move_handle(state.handle, value))
+                }
+                Some(Drag::Ended) | None => None,
+            },
+        };
+
+        *self.state.borrow_mut() = state;
+
+        if let Some((low, high)) =
+            values.filter(|values| *values != self.values)
+        {
+            messages.push((self.on_change)(low, high));
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let state = self.state.borrow();
+
+        let track = Track {
+            axis: self.axis,
+            range: self.range.clone(),
+            handles: vec![self.values.0, self.values.1],
+            ticks: if self.ticks {
+                slider::ticks(&self.range, self.step)
+            } else {
+                Vec::new()
+            },
+        };
+
+        renderer.draw(
+            layout.bounds(),
+            cursor_position,
+            &track,
+            state.drag.is_dragging(),
+            state.is_focused,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<RangeSlider<'static, ()>>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.axis.hash(state);
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.state.diff(tree);
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        focusables.push(Focusable {
+            path: path.clone(),
+            is_focused: self.state.borrow().is_focused,
+            uses_tab: false,
+        });
+    }
+
+    fn captures_mouse(&self) -> bool {
+        self.state.borrow().drag.is_dragging()
+    }
+}
+
+impl<'a, Message, Renderer> From<RangeSlider<'a, Message>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: slider::Renderer,
+    Message: 'static,
+{
+    fn from(
+        range_slider: RangeSlider<'a, Message>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(range_slider)
+    }
+}
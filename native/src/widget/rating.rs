@@ -0,0 +1,414 @@
+//! Let your users rate something with a row of stars.
+//!
+//! A [`Rating`] has some local [`State`].
+//!
+//! [`Rating`]: struct.Rating.html
+//! [`State`]: struct.State.html
+use crate::{
+    input::{keyboard, mouse, ButtonState},
+    layout,
+    tree::{self, Tree},
+    Clipboard, Color, Element, Event, Focus, Focusable, Hasher, Layout, Length,
+    Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+/// A row of stars showing a score, which can be changed by clicking them.
+///
+/// Hovering a star previews the score it would set. Clicking the star of the
+/// current score clears it.
+///
+/// Once it has the keyboard focus, the arrows decrease and increase the
+/// score, `Home` and `End` set it to zero and to its maximum, and digits set
+/// it directly.
+///
+/// A [`Rating`] without an [`on_change`] function only displays its score.
+///
+/// # Example
+/// ```
+/// # use iced_native::Rating;
+/// #
+/// #[derive(Debug, Clone, Copy)]
+/// pub enum Message {
+///     Rated(u8),
+/// }
+///
+/// let score = 4;
+///
+/// let rating = Rating::managed(score).on_change(Message::Rated);
+/// ```
+///
+/// [`Rating`]: struct.Rating.html
+/// [`on_change`]: #method.on_change
+#[allow(missing_debug_implementations)]
+pub struct Rating<'a, Message> {
+    state: tree::State<'a, State>,
+    value: u8,
+    max: u8,
+    on_change: Option<Box<dyn Fn(u8) -> Message + 'a>>,
+    size: u16,
+    spacing: u16,
+    style: Style,
+}
+
+impl<'a, Message> Rating<'a, Message> {
+    /// Creates a new [`Rating`] with some local [`State`] and the current
+    /// score.
+    ///
+    /// [`Rating`]: struct.Rating.html
+    /// [`State`]: struct.State.html
+    pub fn new(state: &'a mut State, value: u8) -> Self {
+        Self::with_state(tree::State::Borrowed(state), value)
+    }
+
+    /// Creates a new [`Rating`] whose local [`State`] is managed by the
+    /// runtime.
+    ///
+    /// [`Rating`]: struct.Rating.html
+    /// [`State`]: struct.State.html
+    pub fn managed(value: u8) -> Self {
+        Self::with_state(tree::State::managed(), value)
+    }
+
+    fn with_state(state: tree::State<'a, State>, value: u8) -> Self {
+        Rating {
+            state,
+            value,
+            max: 5,
+            on_change: None,
+            size: 20,
+            spacing: 4,
+            style: Style::default(),
+        }
+    }
+
+    /// Sets the function that produces a message when the score of the
+    /// [`Rating`] is changed.
+    ///
+    /// [`Rating`]: struct.Rating.html
+    pub fn on_change<F>(mut self, on_change: F) -> Self
+    where
+        F: 'a + Fn(u8) -> Message,
+    {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Sets the maximum score of the [`Rating`], which is its amount of
+    /// stars.
+    ///
+    /// By default, it is five.
+    ///
+    /// [`Rating`]: struct.Rating.html
+    pub fn max(mut self, max: u8) -> Self {
+        self.max = max.max(1);
+        self
+    }
+
+    /// Sets the size of every star of the [`Rating`], in pixels.
+    ///
+    /// [`Rating`]: struct.Rating.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the space between the stars of the [`Rating`], in pixels.
+    ///
+    /// [`Rating`]: struct.Rating.html
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the [`Style`] of the [`Rating`].
+    ///
+    /// [`Style`]: struct.Style.html
+    /// [`Rating`]: struct.Rating.html
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn star_bounds(&self, bounds: Rectangle, index: u8) -> Rectangle {
+        let size = f32::from(self.size);
+
+        Rectangle {
+            x: bounds.x + f32::from(index) * (size + f32::from(self.spacing)),
+            y: bounds.y,
+            width: size,
+            height: size,
+        }
+    }
+
+    // The score set by clicking at the given point, if it is on a star
+    fn score_at(&self, bounds: Rectangle, point: Point) -> Option<u8> {
+        (0..self.max)
+            .find(|index| self.star_bounds(bounds, *index).contains(point))
+            .map(|index| index + 1)
+    }
+}
+
+/// The appearance of a [`Rating`].
+///
+/// [`Rating`]: struct.Rating.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The color of the stars within the score
+    pub filled: Color,
+
+    /// The color of the stars within the score previewed by the mouse
+    pub preview: Color,
+
+    /// The color of the stars past the score
+    pub empty: Color,
+
+    /// The color of the outline around the stars while the [`Rating`] has
+    /// the keyboard focus
+    ///
+    /// [`Rating`]: struct.Rating.html
+    pub focus_color: Color,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Style {
+            filled: [0.95, 0.61, 0.07].into(),
+            preview: [0.98, 0.78, 0.45].into(),
+            empty: [0.8, 0.8, 0.8].into(),
+            focus_color: [0.5, 0.6, 0.9].into(),
+        }
+    }
+}
+
+/// The local state of a [`Rating`].
+///
+/// [`Rating`]: struct.Rating.html
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct State {
+    is_focused: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns whether the [`Rating`] has the keyboard focus.
+    ///
+    /// [`Rating`]: struct.Rating.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Rating<'a, Message>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = f32::from(self.size);
+        let count = f32::from(self.max);
+
+        let size = limits.resolve(Size::new(
+            count * size + (count - 1.0) * f32::from(self.spacing),
+            size,
+        ));
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        let on_change = match &self.on_change {
+            Some(on_change) => on_change,
+            None => return,
+        };
+
+        let mut state = *self.state.borrow();
+
+        let value = match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) => self
+                .score_at(layout.bounds(), cursor_position)
+                .map(|score| if score == self.value { 0 } else { score }),
+            Event::Focus(focus) if layout.is_focus_target() => {
+                state.is_focused = focus == Focus::Gained;
+                None
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code,
+                state: ButtonState::Pressed,
+                ..
+            }) if state.is_focused => match key_code {
+                keyboard::KeyCode::Left | keyboard::KeyCode::Down => {
+                    Some(self.value.saturating_sub(1))
+                }
+                keyboard::KeyCode::Right | keyboard::KeyCode::Up => {
+                    Some(self.value.saturating_add(1).min(self.max))
+                }
+                keyboard::KeyCode::Home => Some(0),
+                keyboard::KeyCode::End => Some(self.max),
+                _ => None,
+            },
+            Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                if state.is_focused =>
+            {
+                c.to_digit(10)
+                    .map(|digit| digit as u8)
+                    .filter(|digit| *digit <= self.max)
+            }
+            _ => None,
+        };
+
+        *self.state.borrow_mut() = state;
+
+        if let Some(value) = value.filter(|value| *value != self.value) {
+            messages.push(on_change(value));
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+
+        let preview = self
+            .on_change
+            .as_ref()
+            .and_then(|_| self.score_at(bounds, cursor_position));
+
+        let stars: Vec<_> = (0..self.max)
+            .map(|index| Star {
+                bounds: self.star_bounds(bounds, index),
+                is_filled: index < self.value,
+                is_previewed: preview.is_some_and(|score| index < score),
+            })
+            .collect();
+
+        self::Renderer::draw(
+            renderer,
+            bounds,
+            &stars,
+            self.state.borrow().is_focused,
+            preview.is_some(),
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Rating<'static, ()>>().hash(state);
+
+        self.max.hash(state);
+        self.size.hash(state);
+        self.spacing.hash(state);
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.state.diff(tree);
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        if self.on_change.is_some() {
+            focusables.push(Focusable {
+                path: path.clone(),
+                is_focused: self.state.borrow().is_focused,
+                uses_tab: false,
+            });
+        }
+    }
+}
+
+/// A star of a [`Rating`], ready to be drawn.
+///
+/// [`Rating`]: struct.Rating.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Star {
+    /// The bounds of the [`Star`]
+    ///
+    /// [`Star`]: struct.Star.html
+    pub bounds: Rectangle,
+
+    /// Whether the [`Star`] is within the score
+    ///
+    /// [`Star`]: struct.Star.html
+    pub is_filled: bool,
+
+    /// Whether the [`Star`] is within the score previewed by the mouse
+    ///
+    /// [`Star`]: struct.Star.html
+    pub is_previewed: bool,
+}
+
+/// The renderer of a [`Rating`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Rating`] in your user interface.
+///
+/// [`Rating`]: struct.Rating.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// Draws a [`Rating`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Rating`]
+    ///   * the [`Star`]s to draw, from left to right
+    ///   * whether the [`Rating`] has the keyboard focus
+    ///   * whether the mouse is over a star that can be clicked
+    ///   * the [`Style`] of the [`Rating`]
+    ///
+    /// [`Rating`]: struct.Rating.html
+    /// [`Star`]: struct.Star.html
+    /// [`Style`]: struct.Style.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        stars: &[Star],
+        is_focused: bool,
+        is_mouse_over: bool,
+        style: &Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Rating<'a, Message>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(rating: Rating<'a, Message>) -> Element<'a, Message, Renderer> {
+        Element::new(rating)
+    }
+}
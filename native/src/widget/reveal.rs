@@ -0,0 +1,405 @@
+//! Reveal some content with an animated mask.
+//!
+//! A [`Reveal`] widget shows its content through a mask, like a circle
+//! growing from the point where the user clicked or an edge sweeping across
+//! it. The mask is described by a [`Transition`], which you keep in your
+//! application state.
+//!
+//! [`Reveal`]: struct.Reveal.html
+//! [`Transition`]: struct.Transition.html
+use crate::{
+    layout, overlay, Clipboard, Element, Event, Focusable, Hasher, Layout,
+    Length, Point, Rectangle, Tree, Widget,
+};
+
+use std::{
+    hash::Hash,
+    time::{Duration, Instant},
+};
+
+/// The shape of the mask of a [`Transition`].
+///
+/// [`Transition`]: struct.Transition.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mask {
+    /// A circle growing from a point until it covers the content.
+    ///
+    /// The point is relative to the top-left corner of the [`Reveal`]. The
+    /// positions produced by a [`MouseArea`] wrapping the same content can be
+    /// used as is.
+    ///
+    /// [`Reveal`]: struct.Reveal.html
+    /// [`MouseArea`]: ../mouse_area/struct.MouseArea.html
+    Circle(Point),
+
+    /// An edge sweeping across the content in a [`Direction`].
+    ///
+    /// [`Direction`]: enum.Direction.html
+    Wipe(Direction),
+}
+
+/// The direction of a [`Mask::Wipe`].
+///
+/// [`Mask::Wipe`]: enum.Mask.html#variant.Wipe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// From the left edge to the right edge.
+    Right,
+
+    /// From the right edge to the left edge.
+    Left,
+
+    /// From the top edge to the bottom edge.
+    Down,
+
+    /// From the bottom edge to the top edge.
+    Up,
+}
+
+impl Mask {
+    /// Returns the region of the given bounds that is visible through the
+    /// [`Mask`] at some progress, from `0.0` to `1.0`.
+    ///
+    /// [`Mask`]: enum.Mask.html
+    pub fn visible(&self, bounds: Rectangle, progress: f32) -> Visible {
+        let progress = progress.clamp(0.0, 1.0);
+
+        match self {
+            Mask::Circle(origin) => {
+                let center =
+                    Point::new(bounds.x + origin.x, bounds.y + origin.y);
+
+                // The circle covers the content once it reaches the farthest
+                // corner
+                let radius = [
+                    (bounds.x, bounds.y),
+                    (bounds.x + bounds.width, bounds.y),
+                    (bounds.x, bounds.y + bounds.height),
+                    (bounds.x + bounds.width, bounds.y + bounds.height),
+                ]
+                .iter()
+                .map(|(x, y)| (x - center.x).hypot(y - center.y))
+                .fold(0.0, f32::max);
+
+                Visible::Circle {
+                    center,
+                    radius: radius * progress,
+                }
+            }
+            Mask::Wipe(direction) => {
+                let width = bounds.width * progress;
+                let height = bounds.height * progress;
+
+                Visible::Rectangle(match direction {
+                    Direction::Right => Rectangle { width, ..bounds },
+                    Direction::Left => Rectangle {
+                        x: bounds.x + bounds.width - width,
+                        width,
+                        ..bounds
+                    },
+                    Direction::Down => Rectangle { height, ..bounds },
+                    Direction::Up => Rectangle {
+                        y: bounds.y + bounds.height - height,
+                        height,
+                        ..bounds
+                    },
+                })
+            }
+        }
+    }
+}
+
+/// The region visible through a [`Mask`].
+///
+/// [`Mask`]: enum.Mask.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Visible {
+    /// A circle, in absolute coordinates.
+    Circle {
+        /// The center of the circle
+        center: Point,
+
+        /// The radius of the circle
+        radius: f32,
+    },
+
+    /// A rectangle, in absolute coordinates.
+    Rectangle(Rectangle),
+}
+
+/// An ongoing reveal of some content.
+///
+/// # Example
+/// ```
+/// # use iced_native::{reveal::{Mask, Transition}, Point};
+/// #
+/// // Reveal the new content from the point the user clicked
+/// let transition = Transition::new(Mask::Circle(Point::new(120.0, 40.0)));
+///
+/// assert!(transition.is_animating());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transition {
+    mask: Mask,
+    started_at: Instant,
+    duration: Duration,
+}
+
+impl Transition {
+    /// Starts a new [`Transition`] with the given [`Mask`].
+    ///
+    /// [`Transition`]: struct.Transition.html
+    /// [`Mask`]: enum.Mask.html
+    pub fn new(mask: Mask) -> Self {
+        Transition {
+            mask,
+            started_at: Instant::now(),
+            duration: Duration::from_millis(300),
+        }
+    }
+
+    /// Sets the duration of the [`Transition`].
+    ///
+    /// [`Transition`]: struct.Transition.html
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Returns the [`Mask`] of the [`Transition`].
+    ///
+    /// [`Mask`]: enum.Mask.html
+    /// [`Transition`]: struct.Transition.html
+    pub fn mask(&self) -> Mask {
+        self.mask
+    }
+
+    /// Returns the progress of the [`Transition`], from `0.0` to `1.0`.
+    ///
+    /// The mask grows quickly at first and slows down as it covers the
+    /// content.
+    ///
+    /// [`Transition`]: struct.Transition.html
+    pub fn progress(&self) -> f32 {
+        let elapsed = self.started_at.elapsed().as_secs_f32();
+        let duration = self.duration.as_secs_f32();
+
+        if elapsed >= duration {
+            return 1.0;
+        }
+
+        // Ease out
+        let t = elapsed / duration;
+
+        1.0 - (1.0 - t) * (1.0 - t)
+    }
+
+    /// Returns whether the [`Transition`] is still animating.
+    ///
+    /// [`Transition`]: struct.Transition.html
+    pub fn is_animating(&self) -> bool {
+        self.started_at.elapsed() < self.duration
+    }
+}
+
+/// A widget that reveals its content through an animated [`Mask`].
+///
+/// The [`Reveal`] keeps redrawing itself while its [`Transition`] is
+/// animating.
+///
+/// [`Mask`]: enum.Mask.html
+/// [`Reveal`]: struct.Reveal.html
+/// [`Transition`]: struct.Transition.html
+#[allow(missing_debug_implementations)]
+pub struct Reveal<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    transition: Option<Transition>,
+}
+
+impl<'a, Message, Renderer> Reveal<'a, Message, Renderer> {
+    /// Creates a new [`Reveal`] with the given content, fully visible.
+    ///
+    /// [`Reveal`]: struct.Reveal.html
+    pub fn new<E>(content: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Reveal {
+            content: content.into(),
+            transition: None,
+        }
+    }
+
+    /// Sets the [`Transition`] revealing the content of the [`Reveal`].
+    ///
+    /// [`Transition`]: struct.Transition.html
+    /// [`Reveal`]: struct.Reveal.html
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transition = Some(transition);
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Reveal<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self.content.layout(renderer, limits);
+
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.content.widget.on_event(
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+
+        let content = self.content.draw(
+            renderer,
+            layout.children().next().unwrap(),
+            cursor_position,
+        );
+
+        let visible = match self.transition {
+            Some(transition) if transition.is_animating() => {
+                renderer.schedule_redraw(Instant::now());
+
+                Some(transition.mask.visible(bounds, transition.progress()))
+            }
+            _ => None,
+        };
+
+        self::Renderer::draw(renderer, bounds, content, visible)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Reveal<'static, (), ()>>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.content.diff(&mut tree.diff_children(&[None])[0]);
+    }
+
+    #[cfg(feature = "devtools")]
+    fn inspect(&self, layout: Layout<'_>) -> crate::inspect::Node {
+        super::inspect_children(
+            std::any::type_name::<Self>(),
+            std::slice::from_ref(&self.content),
+            &[None],
+            layout,
+        )
+    }
+
+    fn hover(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        path: &mut Vec<usize>,
+    ) {
+        super::hover_children(
+            std::slice::from_ref(&self.content),
+            layout,
+            cursor_position,
+            path,
+        );
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        super::focusables_children(
+            std::slice::from_ref(&self.content),
+            path,
+            focusables,
+        );
+    }
+
+    fn captures_mouse(&self) -> bool {
+        super::captures_mouse_children(std::slice::from_ref(&self.content))
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        super::overlay_children(std::slice::from_mut(&mut self.content), layout)
+    }
+}
+
+/// The renderer of a [`Reveal`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Reveal`] in your user interface.
+///
+/// [`Reveal`]: struct.Reveal.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// Draws a [`Reveal`].
+    ///
+    /// It receives:
+    /// - the bounds of the [`Reveal`]
+    /// - the drawn content
+    /// - the [`Visible`] region of the content, if it is being revealed
+    ///
+    /// [`Reveal`]: struct.Reveal.html
+    /// [`Visible`]: enum.Visible.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        content: Self::Output,
+        visible: Option<Visible>,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Reveal<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        reveal: Reveal<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(reveal)
+    }
+}
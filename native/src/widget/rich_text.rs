@@ -0,0 +1,492 @@
+//! Write paragraphs of text mixing different styles, like chat messages or
+//! documentation.
+//!
+//! A [`RichText`] is made of [`Span`]s. Every [`Span`] has its own font,
+//! size, and color, and lines are wrapped across all of them, like a single
+//! paragraph.
+//!
+//! [`RichText`]: struct.RichText.html
+//! [`Span`]: struct.Span.html
+use crate::{
+    font::Weight,
+    input::{mouse, ButtonState},
+    layout, text,
+    tree::{self, Tree},
+    Clipboard, Color, Element, Event, Font, Hasher, HorizontalAlignment,
+    Layout, Length, Point, Rectangle, Size, VerticalAlignment, Widget,
+};
+
+use std::hash::Hash;
+
+/// A paragraph of text made of [`Span`]s with different styles.
+///
+/// Spans can have a link. A [`RichText`] does not open anything by itself
+/// when a link is clicked. Instead, it produces a message with the link, and
+/// your application can open it with [`Command::open_url`].
+///
+/// # Example
+/// ```
+/// # use iced_native::{font::Weight, rich_text::Span, Color, RichText};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     LinkClicked(String),
+/// }
+///
+/// let message = RichText::new(vec![
+///     Span::new("hecrj").weight(Weight::Bold),
+///     Span::new(": the docs are at "),
+///     Span::new("docs.rs/iced").link("https://docs.rs/iced"),
+///     Span::new(" (updated today)").color(Color::from_rgb(0.5, 0.5, 0.5)),
+/// ])
+/// .on_link(Message::LinkClicked);
+/// ```
+///
+/// [`Span`]: struct.Span.html
+/// [`RichText`]: struct.RichText.html
+/// [`Command::open_url`]: ../../struct.Command.html#method.open_url
+#[allow(missing_debug_implementations)]
+pub struct RichText<'a, Message> {
+    state: tree::State<'a, State>,
+    spans: Vec<Span>,
+    params: text::Params,
+    width: Length,
+    height: Length,
+    horizontal_alignment: HorizontalAlignment,
+    vertical_alignment: VerticalAlignment,
+    on_link: Option<Box<dyn Fn(String) -> Message + 'a>>,
+}
+
+impl<'a, Message> RichText<'a, Message> {
+    /// Creates a new [`RichText`] with the given [`Span`]s.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`Span`]: struct.Span.html
+    pub fn new(spans: Vec<Span>) -> Self {
+        RichText {
+            state: tree::State::managed(),
+            spans,
+            params: text::Params::default(),
+            width: Length::Fill,
+            height: Length::Shrink,
+            horizontal_alignment: HorizontalAlignment::Left,
+            vertical_alignment: VerticalAlignment::Top,
+            on_link: None,
+        }
+    }
+
+    /// Adds a [`Span`] at the end of the [`RichText`].
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`RichText`]: struct.RichText.html
+    pub fn push<S: Into<Span>>(mut self, span: S) -> Self {
+        self.spans.push(span.into());
+        self
+    }
+
+    /// Sets the height of the lines of the [`RichText`], relative to the
+    /// natural line height of their tallest [`Span`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`Span`]: struct.Span.html
+    pub fn line_height(mut self, line_height: f32) -> Self {
+        self.params.line_height = line_height;
+        self
+    }
+
+    /// Sets the space added after every glyph of the [`RichText`], in
+    /// logical pixels.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn letter_spacing(mut self, letter_spacing: f32) -> Self {
+        self.params.letter_spacing = letter_spacing;
+        self
+    }
+
+    /// Sets the [`Wrap`] strategy of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`Wrap`]: ../text/enum.Wrap.html
+    pub fn wrap(mut self, wrap: text::Wrap) -> Self {
+        self.params.wrap = wrap;
+        self
+    }
+
+    /// Sets the width of the [`RichText`] boundaries.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`RichText`] boundaries.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`HorizontalAlignment`] of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`HorizontalAlignment`]: ../../enum.HorizontalAlignment.html
+    pub fn horizontal_alignment(
+        mut self,
+        alignment: HorizontalAlignment,
+    ) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the [`VerticalAlignment`] of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`VerticalAlignment`]: ../../enum.VerticalAlignment.html
+    pub fn vertical_alignment(mut self, alignment: VerticalAlignment) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Sets the function producing a message when the link of a [`Span`] is
+    /// clicked.
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn on_link<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        self.on_link = Some(Box::new(f));
+        self
+    }
+
+    fn link_at<Renderer>(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        cursor_position: Point,
+    ) -> Option<usize>
+    where
+        Renderer: self::Renderer,
+    {
+        if !bounds.contains(cursor_position) {
+            return None;
+        }
+
+        renderer
+            .span_at(
+                bounds,
+                &self.spans,
+                self.params,
+                self.horizontal_alignment,
+                self.vertical_alignment,
+                cursor_position,
+            )
+            .filter(|index| self.spans[*index].link.is_some())
+    }
+}
+
+/// A piece of a [`RichText`] with its own style.
+///
+/// [`RichText`]: struct.RichText.html
+#[derive(Debug, Clone)]
+pub struct Span {
+    /// The content of the span
+    pub content: String,
+
+    /// The size of the text of the span, or the default size of the renderer
+    pub size: Option<u16>,
+
+    /// The font of the span
+    pub font: Font,
+
+    /// The weight of the font of the span, or the weight of the font itself
+    ///
+    /// External fonts have a single weight, so it is ignored for them.
+    pub weight: Option<Weight>,
+
+    /// The color of the span, or the default color of the renderer
+    pub color: Option<Color>,
+
+    /// The link of the span, if any, like a URL
+    pub link: Option<String>,
+}
+
+impl Span {
+    /// Creates a new [`Span`] with the given content and the default style.
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn new<T: Into<String>>(content: T) -> Self {
+        Span {
+            content: content.into(),
+            size: None,
+            font: Font::Default,
+            weight: None,
+            color: None,
+            link: None,
+        }
+    }
+
+    /// Sets the size of the text of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`Font`]: ../../struct.Font.html
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the [`Weight`] of the font of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`Weight`]: ../../font/enum.Weight.html
+    pub fn weight(mut self, weight: Weight) -> Self {
+        self.weight = Some(weight);
+        self
+    }
+
+    /// Sets the [`Color`] of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`Color`]: ../../struct.Color.html
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the link of the [`Span`], produced by its [`RichText`] when the
+    /// [`Span`] is clicked.
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`RichText`]: struct.RichText.html
+    pub fn link<T: Into<String>>(mut self, link: T) -> Self {
+        self.link = Some(link.into());
+        self
+    }
+}
+
+impl From<&str> for Span {
+    fn from(content: &str) -> Span {
+        Span::new(content)
+    }
+}
+
+impl From<String> for Span {
+    fn from(content: String) -> Span {
+        Span::new(content)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    pressed_link: Option<usize>,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for RichText<'a, Message>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let (width, height) = self::Renderer::measure(
+            renderer,
+            &self.spans,
+            self.params,
+            limits.max(),
+        );
+
+        let size = limits.resolve(Size::new(width, height));
+
+        let top = match self.vertical_alignment {
+            VerticalAlignment::Top => 0.0,
+            VerticalAlignment::Center => (size.height - height) / 2.0,
+            VerticalAlignment::Bottom => size.height - height,
+        };
+
+        // The first line is aligned with the baseline of its first span
+        let (text_size, font) = self
+            .spans
+            .first()
+            .map(|span| {
+                (span.size.unwrap_or(renderer.default_size()), span.font)
+            })
+            .unwrap_or((renderer.default_size(), Font::Default));
+
+        layout::Node::new(size)
+            .with_baseline(top + renderer.baseline(text_size, font))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        if self.on_link.is_none() {
+            return;
+        }
+
+        if let Event::Mouse(mouse::Event::Input {
+            button: mouse::Button::Left,
+            state: button_state,
+        }) = event
+        {
+            let bounds = layout.bounds();
+            let link = self.link_at(renderer, bounds, cursor_position);
+
+            let mut state = self.state.borrow_mut();
+
+            match button_state {
+                ButtonState::Pressed => {
+                    state.pressed_link = link;
+                }
+                ButtonState::Released => {
+                    let pressed_link = state.pressed_link.take();
+
+                    if let Some(index) = link.filter(|_| link == pressed_link) {
+                        if let (Some(on_link), Some(link)) =
+                            (&self.on_link, &self.spans[index].link)
+                        {
+                            messages.push(on_link(link.clone()));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            cursor_position,
+            &self.spans,
+            self.params,
+            self.horizontal_alignment,
+            self.vertical_alignment,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<RichText<'static, ()>>().hash(state);
+
+        for span in &self.spans {
+            span.content.hash(state);
+            span.size.hash(state);
+            span.weight.hash(state);
+        }
+
+        self.params.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+        self.vertical_alignment.hash(state);
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.state.diff(tree);
+    }
+}
+
+/// The renderer of a [`RichText`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`RichText`] in your user interface.
+///
+/// [`RichText`]: struct.RichText.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer {
+    /// Measures the [`Span`]s of a [`RichText`] in the given bounds and
+    /// returns the minimum boundaries that can fit them.
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`RichText`]: struct.RichText.html
+    fn measure(
+        &self,
+        spans: &[Span],
+        params: text::Params,
+        bounds: Size,
+    ) -> (f32, f32);
+
+    /// Returns the index of the [`Span`] drawn at the given point of a
+    /// [`RichText`], if any.
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`RichText`]: struct.RichText.html
+    fn span_at(
+        &self,
+        bounds: Rectangle,
+        spans: &[Span],
+        params: text::Params,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+        point: Point,
+    ) -> Option<usize>;
+
+    /// Draws a [`RichText`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`RichText`]
+    ///   * the current cursor position
+    ///   * the [`Span`]s of the [`RichText`]
+    ///   * the [`Params`] of the [`RichText`]
+    ///   * the [`HorizontalAlignment`] of the [`RichText`]
+    ///   * the [`VerticalAlignment`] of the [`RichText`]
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`Span`]: struct.Span.html
+    /// [`Params`]: ../text/struct.Params.html
+    /// [`HorizontalAlignment`]: ../../enum.HorizontalAlignment.html
+    /// [`VerticalAlignment`]: ../../enum.VerticalAlignment.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        spans: &[Span],
+        params: text::Params,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<RichText<'a, Message>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'static + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        rich_text: RichText<'a, Message>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(rich_text)
+    }
+}
@@ -2,8 +2,8 @@
 use std::hash::Hash;
 
 use crate::{
-    layout, Align, Clipboard, Element, Event, Hasher, Layout, Length, Point,
-    Widget,
+    layout, overlay, Align, Clipboard, Element, Event, Focusable, Hasher,
+    Layout, Length, Point, Tree, Widget,
 };
 
 use std::u32;
@@ -23,6 +23,7 @@ pub struct Row<'a, Message, Renderer> {
     max_height: u32,
     align_items: Align,
     children: Vec<Element<'a, Message, Renderer>>,
+    keys: Vec<Option<u64>>,
 }
 
 impl<'a, Message, Renderer> Row<'a, Message, Renderer> {
@@ -39,9 +40,26 @@ impl<'a, Message, Renderer> Row<'a, Message, Renderer> {
             max_height: u32::MAX,
             align_items: Align::Start,
             children: Vec::new(),
+            keys: Vec::new(),
         }
     }
 
+    /// Creates a [`Row`] with the given keyed children.
+    ///
+    /// It is equivalent to calling [`push_keyed`] for every child.
+    ///
+    /// [`Row`]: struct.Row.html
+    /// [`push_keyed`]: #method.push_keyed
+    pub fn with_keys<K, E>(children: impl IntoIterator<Item = (K, E)>) -> Self
+    where
+        K: Hash,
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        children
+            .into_iter()
+            .fold(Self::new(), |row, (key, child)| row.push_keyed(key, child))
+    }
+
     /// Sets the horizontal spacing _between_ elements.
     ///
     /// Custom margins per element do not exist in Iced. You should use this
@@ -94,7 +112,11 @@ impl<'a, Message, Renderer> Row<'a, Message, Renderer> {
 
     /// Sets the vertical alignment of the contents of the [`Row`] .
     ///
+    /// With [`Align::Baseline`], the text of labels, text inputs, buttons,
+    /// and any other content containing text lines up.
+    ///
     /// [`Row`]: struct.Row.html
+    /// [`Align::Baseline`]: ../../enum.Align.html#variant.Baseline
     pub fn align_items(mut self, align: Align) -> Self {
         self.align_items = align;
         self
@@ -109,6 +131,26 @@ impl<'a, Message, Renderer> Row<'a, Message, Renderer> {
         E: Into<Element<'a, Message, Renderer>>,
     {
         self.children.push(child.into());
+        self.keys.push(None);
+        self
+    }
+
+    /// Adds an element to the [`Row`] identified by the given key.
+    ///
+    /// Keys identify children across different views, so they can be
+    /// matched by identity instead of by position when the children of the
+    /// [`Row`] are reordered or filtered.
+    ///
+    /// Keys should be unique among the children of the same [`Row`].
+    ///
+    /// [`Row`]: struct.Row.html
+    pub fn push_keyed<K, E>(mut self, key: K, child: E) -> Self
+    where
+        K: Hash,
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.children.push(child.into());
+        self.keys.push(Some(crate::hasher::hash(&key)));
         self
     }
 }
@@ -160,7 +202,7 @@ where
         self.children.iter_mut().zip(layout.children()).for_each(
             |(child, layout)| {
                 child.widget.on_event(
-                    event,
+                    event.clone(),
                     layout,
                     cursor_position,
                     messages,
@@ -190,10 +232,57 @@ where
         self.spacing.hash(state);
         self.spacing.hash(state);
 
-        for child in &self.children {
+        for (child, key) in self.children.iter().zip(&self.keys) {
+            key.hash(state);
             child.widget.hash_layout(state);
         }
     }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        let trees = tree.diff_children(&self.keys);
+
+        for (child, tree) in self.children.iter_mut().zip(trees) {
+            child.diff(tree);
+        }
+    }
+
+    #[cfg(feature = "devtools")]
+    fn inspect(&self, layout: Layout<'_>) -> crate::inspect::Node {
+        super::inspect_children(
+            std::any::type_name::<Self>(),
+            &self.children,
+            &self.keys,
+            layout,
+        )
+    }
+
+    fn hover(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        path: &mut Vec<usize>,
+    ) {
+        super::hover_children(&self.children, layout, cursor_position, path);
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        super::focusables_children(&self.children, path, focusables);
+    }
+
+    fn captures_mouse(&self) -> bool {
+        super::captures_mouse_children(&self.children)
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        super::overlay_children(&mut self.children, layout)
+    }
 }
 
 /// The renderer of a [`Row`].
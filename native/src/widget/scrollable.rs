@@ -1,20 +1,64 @@
-//! Navigate an endless amount of content with a scrollbar.
+//! Navigate an endless amount of content with scrollbars.
+//!
+//! A [`Scrollable`] scrolls vertically by default. It can scroll
+//! horizontally, or in both directions, too. See [`Direction`].
+//!
+//! The contents of a [`Scrollable`] can follow its scroll position through
+//! the [`Viewport`] it provides to them. This way, headers can shrink,
+//! backgrounds can move at a different pace, and toolbars can hide as the
+//! user scrolls.
+//!
+//! Your application can scroll a [`Scrollable`] to a [`Target`], like its
+//! bottom or one of its children, through its [`State`].
+//!
+//! [`Scrollable`]: struct.Scrollable.html
+//! [`Direction`]: enum.Direction.html
+//! [`Viewport`]: struct.Viewport.html
+//! [`Target`]: enum.Target.html
+//! [`State`]: struct.State.html
 use crate::{
-    column,
-    input::{mouse, ButtonState},
-    layout, Align, Clipboard, Column, Element, Event, Hasher, Layout, Length,
-    Point, Rectangle, Size, Widget,
+    column, context,
+    draggable::Axis,
+    input::{keyboard, mouse, ButtonState},
+    layout, overlay,
+    tree::{self, Tree},
+    Align, Clipboard, Color, Column, Element, Event, Focusable, Hasher, Layout,
+    Length, Point, Rectangle, Size, Vector, Widget,
 };
 
-use std::{f32, hash::Hash, u32};
+use std::{
+    cell::Cell,
+    f32,
+    hash::Hash,
+    time::{Duration, Instant},
+    u32,
+};
+
+/// The time without trackpad movement after which the fingers are considered
+/// lifted and the contents start moving on their own.
+const FLING_DELAY: Duration = Duration::from_millis(50);
+
+/// How fast a fling slows down, in seconds.
+const FLING_TIME_CONSTANT: f32 = 0.325;
+
+/// The duration of the glide of a mouse wheel scroll.
+const GLIDE_DURATION: Duration = Duration::from_millis(150);
 
-/// A widget that can vertically display an infinite amount of content with a
-/// scrollbar.
+/// A widget that can display an infinite amount of content with scrollbars.
+///
+/// By default, scrolling is kinetic: the contents glide to the position a
+/// mouse wheel scrolls to, and they keep moving for a while, slowing down,
+/// after the fingers leave a trackpad.
 #[allow(missing_debug_implementations)]
 pub struct Scrollable<'a, Message, Renderer> {
-    state: &'a mut State,
+    state: tree::State<'a, State>,
     height: Length,
     max_height: u32,
+    layout_on_scroll: bool,
+    is_kinetic: bool,
+    direction: Direction,
+    vertical_style: Style,
+    horizontal_style: Style,
     content: Column<'a, Message, Renderer>,
 }
 
@@ -24,10 +68,41 @@ impl<'a, Message, Renderer> Scrollable<'a, Message, Renderer> {
     /// [`Scrollable`]: struct.Scrollable.html
     /// [`State`]: struct.State.html
     pub fn new(state: &'a mut State) -> Self {
+        Self::with_state(tree::State::Borrowed(state))
+    }
+
+    /// Creates a new [`Scrollable`] whose [`State`] is managed by the runtime.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`State`]: struct.State.html
+    pub fn managed() -> Self {
+        Self::with_state(tree::State::managed())
+    }
+
+    /// Creates a new [`Scrollable`] whose [`State`] is shared through the
+    /// given [`Link`].
+    ///
+    /// Other widgets, like a [`MiniMap`], can use the same [`Link`] to follow
+    /// and control the scroll position of the [`Scrollable`].
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`State`]: struct.State.html
+    /// [`Link`]: ../../tree/struct.Link.html
+    /// [`MiniMap`]: ../minimap/struct.MiniMap.html
+    pub fn linked(link: &tree::Link<State>) -> Self {
+        Self::with_state(link.into())
+    }
+
+    fn with_state(state: tree::State<'a, State>) -> Self {
         Scrollable {
             state,
             height: Length::Shrink,
             max_height: u32::MAX,
+            layout_on_scroll: false,
+            is_kinetic: true,
+            direction: Direction::Vertical,
+            vertical_style: Style::default(),
+            horizontal_style: Style::default(),
             content: Column::new(),
         }
     }
@@ -90,6 +165,61 @@ impl<'a, Message, Renderer> Scrollable<'a, Message, Renderer> {
         self
     }
 
+    /// Sets whether the contents of the [`Scrollable`] should be laid out
+    /// again every time it scrolls.
+    ///
+    /// The [`Viewport`] is always available to the contents while drawing.
+    /// Enable this if their layout depends on it too, like a header that
+    /// shrinks as the user scrolls.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`Viewport`]: struct.Viewport.html
+    pub fn layout_on_scroll(mut self, layout_on_scroll: bool) -> Self {
+        self.layout_on_scroll = layout_on_scroll;
+        self
+    }
+
+    /// Sets whether the scrolling of the [`Scrollable`] is kinetic.
+    ///
+    /// When it is not, the contents move exactly as much as the mouse wheel
+    /// or the trackpad reports, and stop right away.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn kinetic(mut self, is_kinetic: bool) -> Self {
+        self.is_kinetic = is_kinetic;
+        self
+    }
+
+    /// Sets the [`Direction`] in which the [`Scrollable`] scrolls.
+    ///
+    /// The contents of a [`Scrollable`] that scrolls horizontally are laid
+    /// out with an unlimited width, so they should not fill it.
+    ///
+    /// [`Direction`]: enum.Direction.html
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the [`Style`] of the vertical scrollbar of the [`Scrollable`].
+    ///
+    /// [`Style`]: struct.Style.html
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn vertical_scrollbar(mut self, style: Style) -> Self {
+        self.vertical_style = style;
+        self
+    }
+
+    /// Sets the [`Style`] of the horizontal scrollbar of the [`Scrollable`].
+    ///
+    /// [`Style`]: struct.Style.html
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn horizontal_scrollbar(mut self, style: Style) -> Self {
+        self.horizontal_style = style;
+        self
+    }
+
     /// Adds an element to the [`Scrollable`].
     ///
     /// [`Scrollable`]: struct.Scrollable.html
@@ -100,6 +230,86 @@ impl<'a, Message, Renderer> Scrollable<'a, Message, Renderer> {
         self.content = self.content.push(child);
         self
     }
+
+    /// Adds an element to the [`Scrollable`] identified by the given key.
+    ///
+    /// Besides matching the element by identity across views, the key lets
+    /// a [`State`] scroll the element into view with [`Target::child`].
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`State`]: struct.State.html
+    /// [`Target::child`]: enum.Target.html#method.child
+    pub fn push_keyed<K, E>(mut self, key: K, child: E) -> Self
+    where
+        K: Hash,
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.content = self.content.push_keyed(key, child);
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Scrollable<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn scrollbars(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        offset: Vector<u32>,
+    ) -> Scrollbars {
+        let vertical = |bounds| {
+            if self.direction.is_vertical() {
+                renderer.scrollbar(
+                    bounds,
+                    content_bounds,
+                    offset.y,
+                    &self.vertical_style,
+                )
+            } else {
+                None
+            }
+        };
+
+        let horizontal = |bounds| {
+            if self.direction.is_horizontal() {
+                renderer.horizontal_scrollbar(
+                    bounds,
+                    content_bounds,
+                    offset.x,
+                    &self.horizontal_style,
+                )
+            } else {
+                None
+            }
+        };
+
+        // The scrollbars do not overlap in the corner
+        let horizontal_bar = horizontal(bounds);
+
+        let vertical_bar = vertical(match &horizontal_bar {
+            Some(bar) => Rectangle {
+                height: bounds.height - bar.bounds.height,
+                ..bounds
+            },
+            None => bounds,
+        });
+
+        let horizontal_bar = match (&vertical_bar, horizontal_bar) {
+            (Some(bar), Some(_)) => horizontal(Rectangle {
+                width: bounds.width - bar.bounds.width,
+                ..bounds
+            }),
+            (_, horizontal_bar) => horizontal_bar,
+        };
+
+        Scrollbars {
+            vertical: vertical_bar,
+            horizontal: horizontal_bar,
+        }
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -127,10 +337,36 @@ where
 
         let child_limits = layout::Limits::new(
             Size::new(limits.min().width, 0.0),
-            Size::new(limits.max().width, f32::INFINITY),
+            Size::new(
+                if self.direction.is_horizontal() {
+                    f32::INFINITY
+                } else {
+                    limits.max().width
+                },
+                if self.direction.is_vertical() {
+                    f32::INFINITY
+                } else {
+                    limits.max().height
+                },
+            ),
         );
 
-        let content = self.content.layout(renderer, &child_limits);
+        // The bounds are not known yet, so the ones of the last draw are used
+        let viewport = {
+            let state = self.state.borrow();
+
+            state.last_bounds().map(|(bounds, content_bounds)| {
+                Viewport::new(&state, bounds, content_bounds)
+            })
+        };
+
+        let content = match viewport {
+            Some(viewport) => context::provide(viewport, || {
+                self.content.layout(renderer, &child_limits)
+            }),
+            None => self.content.layout(renderer, &child_limits),
+        };
+
         let size = limits.resolve(content.size());
 
         layout::Node::with_children(size, vec![content])
@@ -151,46 +387,69 @@ where
         let content = layout.children().next().unwrap();
         let content_bounds = content.bounds();
 
-        // TODO: Event capture. Nested scrollables should capture scroll events.
-        if is_mouse_over {
-            match event {
-                Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
-                    match delta {
-                        mouse::ScrollDelta::Lines { y, .. } => {
-                            // TODO: Configurable speed (?)
-                            self.state.scroll(y * 60.0, bounds, content_bounds);
-                        }
-                        mouse::ScrollDelta::Pixels { y, .. } => {
-                            self.state.scroll(y, bounds, content_bounds);
-                        }
+        let column = &self.content;
+
+        self.state
+            .borrow_mut()
+            .fulfill(bounds, content_bounds, |key| {
+                column.child_bounds(key, content)
+            });
+
+        let offset = self.state.borrow().offsets(bounds, content_bounds);
+        let scrollbars =
+            self.scrollbars(renderer, bounds, content_bounds, offset);
+        let mouse_over_scrollbar = scrollbars.mouse_over(cursor_position);
+
+        let mut state = self.state.borrow_mut();
+
+        match event {
+            Event::Keyboard(keyboard::Event::Input {
+                key_code,
+                state: key_state,
+                ..
+            }) if key_code == keyboard::KeyCode::LShift
+                || key_code == keyboard::KeyCode::RShift =>
+            {
+                state.is_shift_pressed = key_state == ButtonState::Pressed;
+            }
+            // TODO: Event capture. Nested scrollables should capture scroll
+            // events.
+            Event::Mouse(mouse::Event::WheelScrolled { delta, pixels })
+                if is_mouse_over =>
+            {
+                let pixels =
+                    self.direction.project(pixels, state.is_shift_pressed);
+
+                match delta {
+                    mouse::ScrollDelta::Lines { .. } if self.is_kinetic => {
+                        state.glide(pixels, bounds, content_bounds);
+                    }
+                    mouse::ScrollDelta::Pixels { .. } if self.is_kinetic => {
+                        state.track(pixels, bounds, content_bounds);
+                    }
+                    _ => {
+                        state.scroll_by(pixels, bounds, content_bounds);
                     }
                 }
-                _ => {}
             }
+            _ => {}
         }
 
-        let offset = self.state.offset(bounds, content_bounds);
-        let scrollbar = renderer.scrollbar(bounds, content_bounds, offset);
-        let is_mouse_over_scrollbar = scrollbar
-            .as_ref()
-            .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
-            .unwrap_or(false);
-
-        if self.state.is_scroller_grabbed() {
+        if let Some((axis, grabbed_at)) = state.scroller_grabbed_at {
             match event {
                 Event::Mouse(mouse::Event::Input {
                     button: mouse::Button::Left,
                     state: ButtonState::Released,
                 }) => {
-                    self.state.scroller_grabbed_at = None;
+                    state.scroller_grabbed_at = None;
                 }
                 Event::Mouse(mouse::Event::CursorMoved { .. }) => {
-                    if let (Some(scrollbar), Some(scroller_grabbed_at)) =
-                        (scrollbar, self.state.scroller_grabbed_at)
-                    {
-                        self.state.scroll_to(
+                    if let Some(scrollbar) = scrollbars.get(axis) {
+                        state.scroll_to_along(
+                            axis,
                             scrollbar.scroll_percentage(
-                                scroller_grabbed_at,
+                                axis,
+                                grabbed_at,
                                 cursor_position,
                             ),
                             bounds,
@@ -200,27 +459,29 @@ where
                 }
                 _ => {}
             }
-        } else if is_mouse_over_scrollbar {
+        } else if let Some(axis) = mouse_over_scrollbar {
             match event {
                 Event::Mouse(mouse::Event::Input {
                     button: mouse::Button::Left,
                     state: ButtonState::Pressed,
                 }) => {
-                    if let Some(scrollbar) = scrollbar {
-                        if let Some(scroller_grabbed_at) =
-                            scrollbar.grab_scroller(cursor_position)
+                    if let Some(scrollbar) = scrollbars.get(axis) {
+                        if let Some(grabbed_at) =
+                            scrollbar.grab_scroller(axis, cursor_position)
                         {
-                            self.state.scroll_to(
+                            state.scroll_to_along(
+                                axis,
                                 scrollbar.scroll_percentage(
-                                    scroller_grabbed_at,
+                                    axis,
+                                    grabbed_at,
                                     cursor_position,
                                 ),
                                 bounds,
                                 content_bounds,
                             );
 
-                            self.state.scroller_grabbed_at =
-                                Some(scroller_grabbed_at);
+                            state.scroller_grabbed_at =
+                                Some((axis, grabbed_at));
                         }
                     }
                 }
@@ -228,11 +489,18 @@ where
             }
         }
 
-        let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
+        // A child that captures the mouse keeps following the cursor outside
+        // of the bounds
+        let is_content_captured = self.content.captures_mouse();
+
+        let cursor_position = if is_content_captured
+            || (is_mouse_over && mouse_over_scrollbar.is_none())
+        {
+            let offset = state.offsets(bounds, content_bounds);
+
             Point::new(
-                cursor_position.x,
-                cursor_position.y
-                    + self.state.offset(bounds, content_bounds) as f32,
+                cursor_position.x + offset.x as f32,
+                cursor_position.y + offset.y as f32,
             )
         } else {
             // TODO: Make `cursor_position` an `Option<Point>` so we can encode
@@ -242,14 +510,20 @@ where
             Point::new(cursor_position.x, -1.0)
         };
 
-        self.content.on_event(
-            event,
-            content,
-            cursor_position,
-            messages,
-            renderer,
-            clipboard,
-        )
+        let viewport = Viewport::new(&state, bounds, content_bounds);
+
+        drop(state);
+
+        context::provide(viewport, || {
+            self.content.on_event(
+                event,
+                content,
+                cursor_position,
+                messages,
+                renderer,
+                clipboard,
+            )
+        })
     }
 
     fn draw(
@@ -258,36 +532,58 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
     ) -> Renderer::Output {
+        let stored = self.state.borrow();
+
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
         let content_bounds = content_layout.bounds();
-        let offset = self.state.offset(bounds, content_bounds);
-        let scrollbar = renderer.scrollbar(bounds, content_bounds, offset);
+
+        stored.last_bounds.set(Some((bounds, content_bounds)));
+
+        // A pending request is only fulfilled once an event arrives
+        let fulfilled = stored.fulfilled(bounds, content_bounds, |key| {
+            self.content.child_bounds(key, content_layout)
+        });
+        let state = fulfilled.as_ref().unwrap_or(&stored);
+
+        let offset = state.offsets(bounds, content_bounds);
+        let scrollbars =
+            self.scrollbars(renderer, bounds, content_bounds, offset);
+
+        if let Some(at) = state.next_frame(Instant::now()) {
+            renderer.schedule_redraw(at);
+        }
 
         let is_mouse_over = bounds.contains(cursor_position);
-        let is_mouse_over_scrollbar = scrollbar
-            .as_ref()
-            .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
-            .unwrap_or(false);
+        let mouse_over_scrollbar = scrollbars.mouse_over(cursor_position);
 
         let content = {
-            let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
-                Point::new(cursor_position.x, cursor_position.y + offset as f32)
+            let cursor_position = if self.content.captures_mouse()
+                || (is_mouse_over && mouse_over_scrollbar.is_none())
+            {
+                Point::new(
+                    cursor_position.x + offset.x as f32,
+                    cursor_position.y + offset.y as f32,
+                )
             } else {
                 Point::new(cursor_position.x, -1.0)
             };
 
-            self.content.draw(renderer, content_layout, cursor_position)
+            let viewport = Viewport::new(state, bounds, content_bounds);
+
+            context::provide(viewport, || {
+                self.content.draw(renderer, content_layout, cursor_position)
+            })
         };
 
         self::Renderer::draw(
             renderer,
-            &self.state,
+            state,
             bounds,
             content_layout.bounds(),
             is_mouse_over,
-            is_mouse_over_scrollbar,
-            scrollbar,
+            mouse_over_scrollbar,
+            scrollbars,
             offset,
             content,
         )
@@ -298,30 +594,223 @@ where
 
         self.height.hash(state);
         self.max_height.hash(state);
+        self.direction.hash(state);
+
+        if self.layout_on_scroll {
+            let position = self.state.borrow().position(Instant::now());
+
+            position.x.to_bits().hash(state);
+            position.y.to_bits().hash(state);
+        }
 
         self.content.hash_layout(state)
     }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.state.diff(tree);
+        self.content.diff(&mut tree.diff_children(&[None])[0]);
+    }
+
+    #[cfg(feature = "devtools")]
+    fn inspect(&self, layout: Layout<'_>) -> crate::inspect::Node {
+        let content = layout.children().next().unwrap();
+
+        crate::inspect::Node {
+            children: vec![Widget::<Message, Renderer>::inspect(
+                &self.content,
+                content,
+            )],
+            ..crate::inspect::Node::new(
+                std::any::type_name::<Self>(),
+                layout.bounds(),
+            )
+        }
+    }
+
+    fn hover(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        path: &mut Vec<usize>,
+    ) {
+        let stored = self.state.borrow();
+
+        let bounds = layout.bounds();
+        let content_layout = layout.children().next().unwrap();
+        let content_bounds = content_layout.bounds();
+
+        let fulfilled = stored.fulfilled(bounds, content_bounds, |key| {
+            self.content.child_bounds(key, content_layout)
+        });
+        let state = fulfilled.as_ref().unwrap_or(&stored);
+        let offset = state.offsets(bounds, content_bounds);
+
+        let cursor_position = Point::new(
+            cursor_position.x + offset.x as f32,
+            cursor_position.y + offset.y as f32,
+        );
+
+        if content_bounds.contains(cursor_position) {
+            let viewport = Viewport::new(state, bounds, content_bounds);
+
+            path.push(0);
+
+            context::provide(viewport, || {
+                self.content.hover(content_layout, cursor_position, path)
+            });
+        }
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        path.push(0);
+        self.content.focusables(path, focusables);
+        let _ = path.pop();
+    }
+
+    fn captures_mouse(&self) -> bool {
+        self.state.borrow().is_scroller_grabbed()
+            || self.content.captures_mouse()
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let bounds = layout.bounds();
+        let content_layout = layout.children().next().unwrap();
+        let content_bounds = content_layout.bounds();
+
+        let offset = {
+            let stored = self.state.borrow();
+
+            let fulfilled = stored.fulfilled(bounds, content_bounds, |key| {
+                self.content.child_bounds(key, content_layout)
+            });
+
+            fulfilled
+                .as_ref()
+                .unwrap_or(&stored)
+                .offsets(bounds, content_bounds)
+        };
+
+        Widget::<Message, Renderer>::overlay(&mut self.content, content_layout)
+            .map(|overlay| {
+                overlay.translate(Vector::new(
+                    -(offset.x as f32),
+                    -(offset.y as f32),
+                ))
+            })
+    }
+}
+
+/// The directions in which a [`Scrollable`] can scroll.
+///
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Up and down.
+    Vertical,
+
+    /// Left and right.
+    ///
+    /// The mouse wheel scrolls horizontally too.
+    Horizontal,
+
+    /// Both up and down, and left and right.
+    ///
+    /// The mouse wheel scrolls horizontally while the shift key is pressed.
+    Both,
+}
+
+impl Direction {
+    fn is_vertical(self) -> bool {
+        self != Direction::Horizontal
+    }
+
+    fn is_horizontal(self) -> bool {
+        self != Direction::Vertical
+    }
+
+    /// Projects a scroll movement onto the [`Direction`].
+    ///
+    /// [`Direction`]: enum.Direction.html
+    fn project(self, pixels: Vector, is_shift_pressed: bool) -> Vector {
+        // Most mouse wheels only scroll vertically
+        let sideways =
+            Vector::new(if pixels.x == 0.0 { pixels.y } else { pixels.x }, 0.0);
+
+        match self {
+            Direction::Vertical => Vector::new(0.0, pixels.y),
+            Direction::Horizontal => sideways,
+            Direction::Both if is_shift_pressed => sideways,
+            Direction::Both => pixels,
+        }
+    }
+}
+
+/// The appearance of a [`Scrollbar`].
+///
+/// The renderer decides any property that is not set.
+///
+/// [`Scrollbar`]: struct.Scrollbar.html
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Style {
+    /// The thickness of the [`Scrollbar`], in pixels.
+    ///
+    /// [`Scrollbar`]: struct.Scrollbar.html
+    pub width: Option<u16>,
+
+    /// The color of the [`Scroller`].
+    ///
+    /// [`Scroller`]: struct.Scroller.html
+    pub scroller_color: Option<Color>,
+
+    /// The color of the track the [`Scroller`] moves along.
+    ///
+    /// [`Scroller`]: struct.Scroller.html
+    pub track_color: Option<Color>,
 }
 
 /// The local state of a [`Scrollable`].
 ///
 /// [`Scrollable`]: struct.Scrollable.html
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone)]
 pub struct State {
-    scroller_grabbed_at: Option<f32>,
-    offset: f32,
+    scroller_grabbed_at: Option<(Axis, f32)>,
+    offset: Vector,
+    motion: Option<Motion>,
+    request: Option<Request>,
+    is_shift_pressed: bool,
+    last_bounds: Cell<Option<(Rectangle, Rectangle)>>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State {
+            scroller_grabbed_at: None,
+            offset: Vector::new(0.0, 0.0),
+            motion: None,
+            request: None,
+            is_shift_pressed: false,
+            last_bounds: Cell::new(None),
+        }
+    }
 }
 
 impl State {
-    /// Creates a new [`State`] with the scrollbar located at the top.
+    /// Creates a new [`State`] with the scrollbars located at the top left.
     ///
     /// [`State`]: struct.State.html
     pub fn new() -> Self {
         State::default()
     }
 
-    /// Apply a scrolling offset to the current [`State`], given the bounds of
-    /// the [`Scrollable`] and its contents.
+    /// Apply a vertical scrolling offset to the current [`State`], given the
+    /// bounds of the [`Scrollable`] and its contents.
     ///
     /// [`Scrollable`]: struct.Scrollable.html
     /// [`State`]: struct.State.html
@@ -331,17 +820,25 @@ impl State {
         bounds: Rectangle,
         content_bounds: Rectangle,
     ) {
-        if bounds.height >= content_bounds.height {
-            return;
-        }
+        self.scroll_by(Vector::new(0.0, delta_y), bounds, content_bounds);
+    }
 
-        self.offset = (self.offset - delta_y)
-            .max(0.0)
-            .min((content_bounds.height - bounds.height) as f32);
+    /// Apply a horizontal scrolling offset to the current [`State`], given
+    /// the bounds of the [`Scrollable`] and its contents.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`State`]: struct.State.html
+    pub fn scroll_horizontally(
+        &mut self,
+        delta_x: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        self.scroll_by(Vector::new(delta_x, 0.0), bounds, content_bounds);
     }
 
-    /// Moves the scroll position to a relative amount, given the bounds of
-    /// the [`Scrollable`] and its contents.
+    /// Moves the vertical scroll position to a relative amount, given the
+    /// bounds of the [`Scrollable`] and its contents.
     ///
     /// `0` represents scrollbar at the top, while `1` represents scrollbar at
     /// the bottom.
@@ -354,29 +851,629 @@ impl State {
         bounds: Rectangle,
         content_bounds: Rectangle,
     ) {
-        self.offset =
+        self.settle(Instant::now());
+        self.offset.y =
             ((content_bounds.height - bounds.height) * percentage).max(0.0);
     }
 
-    /// Returns the current scrolling offset of the [`State`], given the bounds
-    /// of the [`Scrollable`] and its contents.
+    /// Moves the horizontal scroll position to a relative amount, given the
+    /// bounds of the [`Scrollable`] and its contents.
+    ///
+    /// `0` represents scrollbar at the left, while `1` represents scrollbar
+    /// at the right.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`State`]: struct.State.html
+    pub fn scroll_to_horizontally(
+        &mut self,
+        percentage: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        self.settle(Instant::now());
+        self.offset.x =
+            ((content_bounds.width - bounds.width) * percentage).max(0.0);
+    }
+
+    /// Scrolls to the given [`Target`] right away.
+    ///
+    /// Unlike [`scroll_to`], it does not need the bounds of the
+    /// [`Scrollable`]. The [`Target`] is reached the next time the
+    /// [`Scrollable`] is drawn, once its contents are laid out. This way, a
+    /// [`Target::bottom`] requested while adding an item to a list includes
+    /// the new item.
+    ///
+    /// # Example
+    /// ```
+    /// # use iced_native::scrollable::{State, Target};
+    /// #
+    /// struct Chat {
+    ///     messages: Vec<String>,
+    ///     scroll: State,
+    /// }
+    ///
+    /// impl Chat {
+    ///     fn receive(&mut self, message: String) {
+    ///         self.messages.push(message);
+    ///         self.scroll.snap_to(Target::bottom());
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`Target`]: enum.Target.html
+    /// [`Target::bottom`]: enum.Target.html#method.bottom
+    /// [`scroll_to`]: #method.scroll_to
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn snap_to(&mut self, target: Target) {
+        self.request(target, false);
+    }
+
+    /// Glides from the current scroll position to the given [`Target`], like
+    /// a mouse wheel does.
+    ///
+    /// The [`Target`] is reached as described in [`snap_to`].
+    ///
+    /// [`Target`]: enum.Target.html
+    /// [`snap_to`]: #method.snap_to
+    pub fn glide_to(&mut self, target: Target) {
+        self.request(target, true);
+    }
+
+    fn request(&mut self, target: Target, is_animated: bool) {
+        let now = Instant::now();
+
+        self.request = Some(Request {
+            target,
+            from: self.position(now),
+            requested_at: now,
+            is_animated,
+        });
+    }
+
+    /// Moves to the [`Target`] of the pending request, if any, given the
+    /// bounds of the [`Scrollable`], its contents, and its keyed children.
+    ///
+    /// The result only depends on the request and the bounds, so fulfilling
+    /// a copy of the [`State`] while drawing is consistent with fulfilling
+    /// it later.
+    ///
+    /// [`Target`]: enum.Target.html
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`State`]: struct.State.html
+    fn fulfill(
+        &mut self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        child_bounds: impl FnOnce(u64) -> Option<Rectangle>,
+    ) {
+        let request = match self.request.take() {
+            Some(request) => request,
+            None => return,
+        };
+
+        let hidden = hidden(bounds, content_bounds);
+        let from = clamp(request.from, hidden);
+
+        let target = match request.target {
+            Target::Offset(offset) => offset,
+            Target::Percentage(percentage) => {
+                Vector::new(hidden.x * percentage.x, hidden.y * percentage.y)
+            }
+            Target::Child(key) => match child_bounds(key) {
+                Some(child) => Vector::new(
+                    into_view(
+                        from.x,
+                        bounds.width,
+                        child.x - content_bounds.x,
+                        child.width,
+                    ),
+                    into_view(
+                        from.y,
+                        bounds.height,
+                        child.y - content_bounds.y,
+                        child.height,
+                    ),
+                ),
+                None => from,
+            },
+        };
+
+        self.offset = clamp(target, hidden);
+        self.motion = if request.is_animated {
+            Some(Motion::Glide {
+                from,
+                started_at: request.requested_at,
+            })
+        } else {
+            None
+        };
+    }
+
+    /// Returns a copy of the [`State`] with its pending request fulfilled, if
+    /// it has one.
+    ///
+    /// [`State`]: struct.State.html
+    fn fulfilled(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        child_bounds: impl FnOnce(u64) -> Option<Rectangle>,
+    ) -> Option<State> {
+        self.request.map(|_| {
+            let mut state = self.clone();
+            state.fulfill(bounds, content_bounds, child_bounds);
+            state
+        })
+    }
+
+    fn scroll_to_along(
+        &mut self,
+        axis: Axis,
+        percentage: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        match axis {
+            Axis::Vertical => {
+                self.scroll_to(percentage, bounds, content_bounds)
+            }
+            Axis::Horizontal => {
+                self.scroll_to_horizontally(percentage, bounds, content_bounds)
+            }
+        }
+    }
+
+    fn scroll_by(
+        &mut self,
+        delta: Vector,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        self.settle(Instant::now());
+
+        self.offset = clamp(
+            Vector::new(self.offset.x - delta.x, self.offset.y - delta.y),
+            hidden(bounds, content_bounds),
+        );
+    }
+
+    /// Scrolls by the movement of a trackpad, keeping track of its velocity
+    /// to fling the contents once the movement stops.
+    fn track(
+        &mut self,
+        delta: Vector,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        let now = Instant::now();
+
+        // The first movement of a gesture has no velocity yet
+        let velocity = match self.motion {
+            Some(Motion::Fling {
+                velocity,
+                last_moved_at,
+            }) if now < last_moved_at + FLING_DELAY => {
+                let elapsed = (now - last_moved_at).as_secs_f32().max(0.001);
+
+                Vector::new(
+                    velocity.x * 0.2 - delta.x / elapsed * 0.8,
+                    velocity.y * 0.2 - delta.y / elapsed * 0.8,
+                )
+            }
+            _ => Vector::new(0.0, 0.0),
+        };
+
+        self.scroll_by(delta, bounds, content_bounds);
+
+        self.motion = Some(Motion::Fling {
+            velocity,
+            last_moved_at: now,
+        });
+    }
+
+    /// Scrolls by the movement of a mouse wheel, gliding from the current
+    /// position to the new one.
+    ///
+    /// Consecutive movements add up while gliding.
+    fn glide(
+        &mut self,
+        delta: Vector,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        let now = Instant::now();
+        let from = clamp(self.position(now), hidden(bounds, content_bounds));
+
+        // The target of the current glide, if any, is kept
+        if let Some(Motion::Glide { .. }) = self.motion {
+            self.motion = None;
+        }
+
+        self.scroll_by(delta, bounds, content_bounds);
+
+        self.motion = Some(Motion::Glide {
+            from,
+            started_at: now,
+        });
+    }
+
+    /// Stops the current motion, keeping the position it reached, and drops
+    /// any pending request.
+    fn settle(&mut self, now: Instant) {
+        let position = self.position(now);
+
+        self.offset = Vector::new(position.x.max(0.0), position.y.max(0.0));
+        self.motion = None;
+        self.request = None;
+    }
+
+    /// Returns the scrolling offset at the given instant, without clamping it
+    /// to the contents.
+    fn position(&self, now: Instant) -> Vector {
+        match self.motion {
+            None => self.offset,
+            Some(Motion::Fling {
+                velocity,
+                last_moved_at,
+            }) => {
+                let elapsed = now
+                    .saturating_duration_since(last_moved_at + FLING_DELAY)
+                    .as_secs_f32();
+
+                let distance = FLING_TIME_CONSTANT
+                    * (1.0 - (-elapsed / FLING_TIME_CONSTANT).exp());
+
+                Vector::new(
+                    self.offset.x + velocity.x * distance,
+                    self.offset.y + velocity.y * distance,
+                )
+            }
+            Some(Motion::Glide { from, started_at }) => {
+                let progress =
+                    (now.saturating_duration_since(started_at).as_secs_f32()
+                        / GLIDE_DURATION.as_secs_f32())
+                    .min(1.0);
+
+                // Ease out cubic
+                let eased = 1.0 - (1.0 - progress).powi(3);
+
+                Vector::new(
+                    from.x + (self.offset.x - from.x) * eased,
+                    from.y + (self.offset.y - from.y) * eased,
+                )
+            }
+        }
+    }
+
+    /// Returns when the contents need to be drawn again to keep moving, if
+    /// they are moving.
+    fn next_frame(&self, now: Instant) -> Option<Instant> {
+        match self.motion? {
+            Motion::Fling {
+                velocity,
+                last_moved_at,
+            } => {
+                let starts_at = last_moved_at + FLING_DELAY;
+
+                if now < starts_at {
+                    return Some(starts_at);
+                }
+
+                let elapsed = (now - starts_at).as_secs_f32();
+                let remaining = velocity.x.abs().max(velocity.y.abs())
+                    * FLING_TIME_CONSTANT
+                    * (-elapsed / FLING_TIME_CONSTANT).exp();
+
+                // Stop once the contents would move less than half a pixel
+                if remaining > 0.5 {
+                    Some(now)
+                } else {
+                    None
+                }
+            }
+            Motion::Glide { started_at, .. } => {
+                if now < started_at + GLIDE_DURATION {
+                    Some(now)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Returns the current vertical scrolling offset of the [`State`], given
+    /// the bounds of the [`Scrollable`] and its contents.
     ///
     /// [`Scrollable`]: struct.Scrollable.html
     /// [`State`]: struct.State.html
     pub fn offset(&self, bounds: Rectangle, content_bounds: Rectangle) -> u32 {
-        let hidden_content =
-            (content_bounds.height - bounds.height).max(0.0).round() as u32;
+        self.offsets(bounds, content_bounds).y
+    }
+
+    /// Returns the current horizontal scrolling offset of the [`State`],
+    /// given the bounds of the [`Scrollable`] and its contents.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`State`]: struct.State.html
+    pub fn horizontal_offset(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> u32 {
+        self.offsets(bounds, content_bounds).x
+    }
 
-        self.offset.min(hidden_content as f32) as u32
+    fn offsets(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Vector<u32> {
+        let hidden = hidden(bounds, content_bounds);
+        let position = self.position(Instant::now());
+
+        Vector::new(
+            position.x.max(0.0).min(hidden.x.round()) as u32,
+            position.y.max(0.0).min(hidden.y.round()) as u32,
+        )
     }
 
-    /// Returns whether the scroller is currently grabbed or not.
+    /// Returns whether a scroller is currently grabbed or not.
     pub fn is_scroller_grabbed(&self) -> bool {
         self.scroller_grabbed_at.is_some()
     }
+
+    /// Returns the [`Axis`] of the scroller currently grabbed, if any.
+    ///
+    /// [`Axis`]: ../draggable/enum.Axis.html
+    pub fn grabbed_scroller(&self) -> Option<Axis> {
+        self.scroller_grabbed_at.map(|(axis, _)| axis)
+    }
+
+    /// Returns the bounds of the [`Scrollable`] and its contents the last time
+    /// it was drawn, if any.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn last_bounds(&self) -> Option<(Rectangle, Rectangle)> {
+        self.last_bounds.get()
+    }
+}
+
+/// Returns how much of the contents is hidden along each axis.
+fn hidden(bounds: Rectangle, content_bounds: Rectangle) -> Vector {
+    Vector::new(
+        (content_bounds.width - bounds.width).max(0.0),
+        (content_bounds.height - bounds.height).max(0.0),
+    )
+}
+
+/// Clamps an offset to the hidden part of some contents.
+fn clamp(offset: Vector, hidden: Vector) -> Vector {
+    Vector::new(
+        offset.x.max(0.0).min(hidden.x),
+        offset.y.max(0.0).min(hidden.y),
+    )
+}
+
+/// Returns the offset along an axis that scrolls as little as possible to
+/// show the given span of the contents.
+///
+/// The start of the span is shown if it does not fit in the viewport.
+fn into_view(offset: f32, viewport: f32, start: f32, length: f32) -> f32 {
+    if start < offset || length > viewport {
+        start
+    } else if start + length > offset + viewport {
+        start + length - viewport
+    } else {
+        offset
+    }
+}
+
+/// A position a [`Scrollable`] can be asked to scroll to.
+///
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Target {
+    /// An offset, in pixels, from the top left corner of the contents.
+    Offset(Vector),
+
+    /// A relative position along each axis, from `0` at the top left to `1`
+    /// at the bottom right.
+    Percentage(Vector),
+
+    /// The keyed child of the [`Scrollable`] with the given hash.
+    ///
+    /// The contents scroll as little as possible to show the child entirely,
+    /// or its top left corner if it does not fit. Nothing happens if there is
+    /// no such child.
+    ///
+    /// Use [`Target::child`] to hash a key.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`Target::child`]: #method.child
+    Child(u64),
+}
+
+impl Target {
+    /// Returns the [`Target`] at the top left of the contents.
+    ///
+    /// [`Target`]: enum.Target.html
+    pub fn top() -> Self {
+        Target::Percentage(Vector::new(0.0, 0.0))
+    }
+
+    /// Returns the [`Target`] at the bottom left of the contents.
+    ///
+    /// [`Target`]: enum.Target.html
+    pub fn bottom() -> Self {
+        Target::Percentage(Vector::new(0.0, 1.0))
+    }
+
+    /// Returns the [`Target`] of the child pushed with the given key using
+    /// [`Scrollable::push_keyed`].
+    ///
+    /// [`Target`]: enum.Target.html
+    /// [`Scrollable::push_keyed`]: struct.Scrollable.html#method.push_keyed
+    pub fn child<K: Hash>(key: K) -> Self {
+        Target::Child(crate::hasher::hash(&key))
+    }
+}
+
+/// A request to scroll to a [`Target`], waiting for the bounds of the
+/// [`Scrollable`].
+///
+/// [`Target`]: enum.Target.html
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug, Clone, Copy)]
+struct Request {
+    target: Target,
+    from: Vector,
+    requested_at: Instant,
+    is_animated: bool,
+}
+
+/// A motion of the contents of a [`Scrollable`] that continues over time.
+///
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug, Clone, Copy)]
+enum Motion {
+    /// The contents keep moving with the given velocity, in pixels per
+    /// second, once a trackpad stops reporting movement, and slow down
+    /// until they stop.
+    Fling {
+        velocity: Vector,
+        last_moved_at: Instant,
+    },
+
+    /// The contents move from the given offset to the current one.
+    Glide { from: Vector, started_at: Instant },
+}
+
+/// The visible part of the contents of a [`Scrollable`].
+///
+/// A [`Scrollable`] provides its [`Viewport`] to its contents while laying
+/// them out, drawing them, and handling their events. Any descendant can
+/// obtain the [`Viewport`] of its nearest [`Scrollable`] with
+/// [`context::get`].
+///
+/// # Example
+/// ```
+/// # use iced_native::{context, scrollable::Viewport};
+/// #
+/// // A background that moves at half the speed of the contents
+/// fn parallax_offset() -> f32 {
+///     context::get::<Viewport>()
+///         .map(|viewport| viewport.offset / 2.0)
+///         .unwrap_or(0.0)
+/// }
+/// ```
+///
+/// [`Scrollable`]: struct.Scrollable.html
+/// [`Viewport`]: struct.Viewport.html
+/// [`context::get`]: ../../context/fn.get.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    /// The amount of pixels the contents are scrolled vertically.
+    pub offset: f32,
+
+    /// The amount of pixels the contents are scrolled horizontally.
+    pub horizontal_offset: f32,
+
+    /// The bounds of the [`Scrollable`].
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub bounds: Rectangle,
+
+    /// The bounds of the contents of the [`Scrollable`], without scrolling.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub content_bounds: Rectangle,
+}
+
+impl Viewport {
+    fn new(
+        state: &State,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Self {
+        let offsets = state.offsets(bounds, content_bounds);
+
+        Viewport {
+            offset: offsets.y as f32,
+            horizontal_offset: offsets.x as f32,
+            bounds,
+            content_bounds,
+        }
+    }
+
+    /// Returns how far the contents are scrolled, from `0` at the top to `1`
+    /// at the bottom.
+    ///
+    /// It is `0` when the contents fit in the [`Scrollable`].
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn progress(&self) -> f32 {
+        let hidden = self.content_bounds.height - self.bounds.height;
+
+        if hidden > 0.0 {
+            (self.offset / hidden).min(1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns how far the contents are scrolled, from `0` at the left to `1`
+    /// at the right.
+    ///
+    /// It is `0` when the contents fit in the [`Scrollable`].
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn horizontal_progress(&self) -> f32 {
+        let hidden = self.content_bounds.width - self.bounds.width;
+
+        if hidden > 0.0 {
+            (self.horizontal_offset / hidden).min(1.0)
+        } else {
+            0.0
+        }
+    }
 }
 
-/// The scrollbar of a [`Scrollable`].
+/// The scrollbars of a [`Scrollable`].
+///
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug)]
+pub struct Scrollbars {
+    /// The vertical [`Scrollbar`], if the contents overflow vertically.
+    ///
+    /// [`Scrollbar`]: struct.Scrollbar.html
+    pub vertical: Option<Scrollbar>,
+
+    /// The horizontal [`Scrollbar`], if the contents overflow horizontally.
+    ///
+    /// [`Scrollbar`]: struct.Scrollbar.html
+    pub horizontal: Option<Scrollbar>,
+}
+
+impl Scrollbars {
+    fn get(&self, axis: Axis) -> Option<&Scrollbar> {
+        match axis {
+            Axis::Vertical => self.vertical.as_ref(),
+            Axis::Horizontal => self.horizontal.as_ref(),
+        }
+    }
+
+    fn mouse_over(&self, cursor_position: Point) -> Option<Axis> {
+        [Axis::Vertical, Axis::Horizontal]
+            .iter()
+            .cloned()
+            .find(|axis| match self.get(*axis) {
+                Some(scrollbar) => scrollbar.is_mouse_over(cursor_position),
+                None => false,
+            })
+    }
+}
+
+/// A scrollbar of a [`Scrollable`].
 ///
 /// [`Scrollable`]: struct.Scrollable.html
 #[derive(Debug)]
@@ -390,6 +1487,12 @@ pub struct Scrollbar {
     ///
     /// [`Scroller`]: struct.Scroller.html
     pub scroller: Scroller,
+
+    /// The [`Style`] of the [`Scrollbar`].
+    ///
+    /// [`Style`]: struct.Style.html
+    /// [`Scrollbar`]: struct.Scrollbar.html
+    pub style: Style,
 }
 
 impl Scrollbar {
@@ -397,11 +1500,19 @@ impl Scrollbar {
         self.bounds.contains(cursor_position)
     }
 
-    fn grab_scroller(&self, cursor_position: Point) -> Option<f32> {
+    fn grab_scroller(&self, axis: Axis, cursor_position: Point) -> Option<f32> {
         if self.bounds.contains(cursor_position) {
-            Some(if self.scroller.bounds.contains(cursor_position) {
-                (cursor_position.y - self.scroller.bounds.y)
-                    / self.scroller.bounds.height
+            let scroller = self.scroller.bounds;
+
+            Some(if scroller.contains(cursor_position) {
+                match axis {
+                    Axis::Vertical => {
+                        (cursor_position.y - scroller.y) / scroller.height
+                    }
+                    Axis::Horizontal => {
+                        (cursor_position.x - scroller.x) / scroller.width
+                    }
+                }
             } else {
                 0.5
             })
@@ -412,13 +1523,26 @@ impl Scrollbar {
 
     fn scroll_percentage(
         &self,
+        axis: Axis,
         grabbed_at: f32,
         cursor_position: Point,
     ) -> f32 {
-        (cursor_position.y
-            - self.bounds.y
-            - self.scroller.bounds.height * grabbed_at)
-            / (self.bounds.height - self.scroller.bounds.height)
+        let scroller = self.scroller.bounds;
+
+        match axis {
+            Axis::Vertical => {
+                (cursor_position.y
+                    - self.bounds.y
+                    - scroller.height * grabbed_at)
+                    / (self.bounds.height - scroller.height)
+            }
+            Axis::Horizontal => {
+                (cursor_position.x
+                    - self.bounds.x
+                    - scroller.width * grabbed_at)
+                    / (self.bounds.width - scroller.width)
+            }
+        }
     }
 }
 
@@ -441,16 +1565,34 @@ pub struct Scroller {
 /// [`Scrollable`]: struct.Scrollable.html
 /// [renderer]: ../../renderer/index.html
 pub trait Renderer: crate::Renderer + Sized {
-    /// Returns the [`Scrollbar`] given the bounds and content bounds of a
-    /// [`Scrollable`].
+    /// Returns the vertical [`Scrollbar`] given the bounds and content bounds
+    /// of a [`Scrollable`], its vertical offset, and the [`Style`] of the
+    /// [`Scrollbar`].
     ///
     /// [`Scrollbar`]: struct.Scrollbar.html
     /// [`Scrollable`]: struct.Scrollable.html
+    /// [`Style`]: struct.Style.html
     fn scrollbar(
         &self,
         bounds: Rectangle,
         content_bounds: Rectangle,
         offset: u32,
+        style: &Style,
+    ) -> Option<Scrollbar>;
+
+    /// Returns the horizontal [`Scrollbar`] given the bounds and content
+    /// bounds of a [`Scrollable`], its horizontal offset, and the [`Style`]
+    /// of the [`Scrollbar`].
+    ///
+    /// [`Scrollbar`]: struct.Scrollbar.html
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`Style`]: struct.Style.html
+    fn horizontal_scrollbar(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        offset: u32,
+        style: &Style,
     ) -> Option<Scrollbar>;
 
     /// Draws the [`Scrollable`].
@@ -460,12 +1602,14 @@ pub trait Renderer: crate::Renderer + Sized {
     /// - the bounds of the [`Scrollable`] widget
     /// - the bounds of the [`Scrollable`] content
     /// - whether the mouse is over the [`Scrollable`] or not
-    /// - whether the mouse is over the [`Scrollbar`] or not
-    /// - a optional [`Scrollbar`] to be rendered
-    /// - the scrolling offset
+    /// - the [`Axis`] of the [`Scrollbar`] under the mouse, if any
+    /// - the [`Scrollbars`] to be rendered
+    /// - the scrolling offset along each axis
     /// - the drawn content
     ///
+    /// [`Axis`]: ../draggable/enum.Axis.html
     /// [`Scrollbar`]: struct.Scrollbar.html
+    /// [`Scrollbars`]: struct.Scrollbars.html
     /// [`Scrollable`]: struct.Scrollable.html
     /// [`State`]: struct.State.html
     fn draw(
@@ -474,9 +1618,9 @@ pub trait Renderer: crate::Renderer + Sized {
         bounds: Rectangle,
         content_bounds: Rectangle,
         is_mouse_over: bool,
-        is_mouse_over_scrollbar: bool,
-        scrollbar: Option<Scrollbar>,
-        offset: u32,
+        mouse_over_scrollbar: Option<Axis>,
+        scrollbars: Scrollbars,
+        offset: Vector<u32>,
         content: Self::Output,
     ) -> Self::Output;
 }
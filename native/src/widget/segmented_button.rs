@@ -0,0 +1,552 @@
+//! Pick one or many options from a row of joined buttons.
+//!
+//! A [`SegmentedButton`] has some local [`State`].
+//!
+//! [`SegmentedButton`]: struct.SegmentedButton.html
+//! [`State`]: struct.State.html
+use crate::{
+    input::{keyboard, mouse, ButtonState},
+    layout, text,
+    tree::{self, Tree},
+    Clipboard, Color, Element, Event, Focus, Focusable, Font, Hasher, Layout,
+    Length, Point, Rectangle, Size, Widget,
+};
+
+use std::borrow::Cow;
+use std::hash::Hash;
+
+/// A row of joined buttons, one for every option, like a group of
+/// [`Radio`] buttons or toggles.
+///
+/// By default, a single segment is selected at a time, and pressing one
+/// produces a message selecting its option. When [`multiple`] selection is
+/// enabled, pressing a segment produces a message asking to toggle its
+/// option instead.
+///
+/// Once it has the keyboard focus, the left and right arrows highlight the
+/// previous and next segments, and `Space` or `Enter` presses the
+/// highlighted one. With a single selection, the arrows select the segment
+/// they move to right away.
+///
+/// # Example
+/// ```
+/// # use iced_native::SegmentedButton;
+/// #
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// pub enum Alignment {
+///     Left,
+///     Center,
+///     Right,
+/// }
+///
+/// impl std::fmt::Display for Alignment {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "{:?}", self)
+///     }
+/// }
+///
+/// #[derive(Debug, Clone, Copy)]
+/// pub enum Message {
+///     AlignmentSelected(Alignment),
+/// }
+///
+/// const ALIGNMENTS: [Alignment; 3] =
+///     [Alignment::Left, Alignment::Center, Alignment::Right];
+///
+/// let alignment = Alignment::Center;
+///
+/// let segmented_button = SegmentedButton::managed(
+///     &ALIGNMENTS[..],
+///     &[alignment],
+///     Message::AlignmentSelected,
+/// );
+/// ```
+///
+/// [`Radio`]: ../radio/struct.Radio.html
+/// [`multiple`]: #method.multiple
+#[allow(missing_debug_implementations)]
+pub struct SegmentedButton<'a, T, Message>
+where
+    [T]: ToOwned<Owned = Vec<T>>,
+{
+    state: tree::State<'a, State>,
+    options: Cow<'a, [T]>,
+    selected: Vec<bool>,
+    on_select: Box<dyn Fn(T) -> Message + 'a>,
+    is_multiple: bool,
+    width: Length,
+    padding: u16,
+    size: Option<u16>,
+    style: Style,
+}
+
+impl<'a, T, Message> SegmentedButton<'a, T, Message>
+where
+    T: ToString + PartialEq + Clone,
+    [T]: ToOwned<Owned = Vec<T>>,
+{
+    /// Creates a new [`SegmentedButton`].
+    ///
+    /// It expects:
+    /// - some [`State`]
+    /// - the options, one for every segment
+    /// - the selected options
+    /// - a function that produces a message when a segment is pressed
+    ///
+    /// [`SegmentedButton`]: struct.SegmentedButton.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(
+        state: &'a mut State,
+        options: impl Into<Cow<'a, [T]>>,
+        selected: &[T],
+        on_select: F,
+    ) -> Self
+    where
+        F: 'a + Fn(T) -> Message,
+    {
+        Self::with_state(
+            tree::State::Borrowed(state),
+            options.into(),
+            selected,
+            on_select,
+        )
+    }
+
+    /// Creates a new [`SegmentedButton`] whose local [`State`] is managed by
+    /// the runtime.
+    ///
+    /// [`SegmentedButton`]: struct.SegmentedButton.html
+    /// [`State`]: struct.State.html
+    pub fn managed<F>(
+        options: impl Into<Cow<'a, [T]>>,
+        selected: &[T],
+        on_select: F,
+    ) -> Self
+    where
+        F: 'a + Fn(T) -> Message,
+    {
+        Self::with_state(
+            tree::State::managed(),
+            options.into(),
+            selected,
+            on_select,
+        )
+    }
+
+    fn with_state<F>(
+        state: tree::State<'a, State>,
+        options: Cow<'a, [T]>,
+        selected: &[T],
+        on_select: F,
+    ) -> Self
+    where
+        F: 'a + Fn(T) -> Message,
+    {
+        let selected = options
+            .iter()
+            .map(|option| selected.contains(option))
+            .collect();
+
+        SegmentedButton {
+            state,
+            options,
+            selected,
+            on_select: Box::new(on_select),
+            is_multiple: false,
+            width: Length::Shrink,
+            padding: 5,
+            size: None,
+            style: Style::default(),
+        }
+    }
+
+    /// Sets whether many segments of the [`SegmentedButton`] can be selected
+    /// at the same time.
+    ///
+    /// When they can, pressing a segment asks to toggle its option.
+    ///
+    /// [`SegmentedButton`]: struct.SegmentedButton.html
+    pub fn multiple(mut self, is_multiple: bool) -> Self {
+        self.is_multiple = is_multiple;
+        self
+    }
+
+    /// Sets the width of the [`SegmentedButton`].
+    ///
+    /// Its segments always share the width equally.
+    ///
+    /// [`SegmentedButton`]: struct.SegmentedButton.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the padding around the label of every segment.
+    pub fn padding(mut self, units: u16) -> Self {
+        self.padding = units;
+        self
+    }
+
+    /// Sets the text size of the [`SegmentedButton`].
+    ///
+    /// [`SegmentedButton`]: struct.SegmentedButton.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Style`] of the [`SegmentedButton`].
+    ///
+    /// [`Style`]: struct.Style.html
+    /// [`SegmentedButton`]: struct.SegmentedButton.html
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn segment_bounds(&self, bounds: Rectangle, index: usize) -> Rectangle {
+        let width = bounds.width / self.options.len().max(1) as f32;
+
+        Rectangle {
+            x: bounds.x + index as f32 * width,
+            width,
+            ..bounds
+        }
+    }
+
+    fn segment_at(&self, bounds: Rectangle, point: Point) -> Option<usize> {
+        (0..self.options.len())
+            .find(|index| self.segment_bounds(bounds, *index).contains(point))
+    }
+
+    fn press(&self, index: usize, messages: &mut Vec<Message>) {
+        // Selecting the selected option again is pointless
+        if self.is_multiple || !self.selected[index] {
+            messages.push((self.on_select)(self.options[index].clone()));
+        }
+    }
+}
+
+/// The appearance of a [`SegmentedButton`].
+///
+/// [`SegmentedButton`]: struct.SegmentedButton.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the segments that are not selected
+    pub background: Color,
+
+    /// The background of a segment under the mouse
+    pub hovered_background: Color,
+
+    /// The background of the selected segments
+    pub selected_background: Color,
+
+    /// The color of the border around and between the segments
+    pub border_color: Color,
+
+    /// The color of the labels of the segments that are not selected
+    pub text_color: Color,
+
+    /// The color of the labels of the selected segments
+    pub selected_text_color: Color,
+
+    /// The color of the outline of the highlighted segment while the
+    /// [`SegmentedButton`] has the keyboard focus
+    ///
+    /// [`SegmentedButton`]: struct.SegmentedButton.html
+    pub focus_color: Color,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Style {
+            background: Color::WHITE,
+            hovered_background: [0.93, 0.93, 0.93].into(),
+            selected_background: [0.20, 0.60, 0.86].into(),
+            border_color: [0.7, 0.7, 0.7].into(),
+            text_color: [0.3, 0.3, 0.3].into(),
+            selected_text_color: Color::WHITE,
+            focus_color: [0.5, 0.6, 0.9].into(),
+        }
+    }
+}
+
+/// The local state of a [`SegmentedButton`].
+///
+/// [`SegmentedButton`]: struct.SegmentedButton.html
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct State {
+    highlighted: usize,
+    is_focused: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns whether the [`SegmentedButton`] has the keyboard focus.
+    ///
+    /// [`SegmentedButton`]: struct.SegmentedButton.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for SegmentedButton<'a, T, Message>
+where
+    T: ToString + PartialEq + Clone,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self.size.unwrap_or(renderer.default_size());
+        let padding = f32::from(self.padding);
+
+        let label_width = self
+            .options
+            .iter()
+            .map(|option| {
+                renderer
+                    .measure(
+                        &option.to_string(),
+                        text_size,
+                        Font::Default,
+                        text::Params::default(),
+                        Size::INFINITY,
+                    )
+                    .0
+            })
+            .fold(0.0, f32::max);
+
+        let intrinsic = Size::new(
+            (label_width + 2.0 * padding) * self.options.len() as f32,
+            f32::from(text_size) + 2.0 * padding,
+        );
+
+        let size = limits
+            .width(self.width)
+            .height(Length::Units(intrinsic.height as u16))
+            .resolve(intrinsic);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+        let last = match self.options.len().checked_sub(1) {
+            Some(last) => last,
+            None => return,
+        };
+
+        let mut state = *self.state.borrow();
+
+        match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) => {
+                if let Some(index) = self.segment_at(bounds, cursor_position) {
+                    state.highlighted = index;
+                    self.press(index, messages);
+                }
+            }
+            Event::Focus(focus) if layout.is_focus_target() => {
+                state.is_focused = focus == Focus::Gained;
+
+                // Focus lands on the selected segment, if any
+                if state.is_focused {
+                    state.highlighted = self
+                        .selected
+                        .iter()
+                        .position(|is_selected| *is_selected)
+                        .unwrap_or(0);
+                }
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code,
+                state: ButtonState::Pressed,
+                ..
+            }) if state.is_focused => {
+                let highlighted = state.highlighted.min(last);
+
+                let target = match key_code {
+                    keyboard::KeyCode::Left => highlighted.saturating_sub(1),
+                    keyboard::KeyCode::Right => (highlighted + 1).min(last),
+                    keyboard::KeyCode::Home => 0,
+                    keyboard::KeyCode::End => last,
+                    keyboard::KeyCode::Space
+                    | keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => {
+                        self.press(highlighted, messages);
+                        highlighted
+                    }
+                    _ => return,
+                };
+
+                if target != highlighted && !self.is_multiple {
+                    self.press(target, messages);
+                }
+
+                state.highlighted = target;
+            }
+            _ => {}
+        }
+
+        *self.state.borrow_mut() = state;
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let state = self.state.borrow();
+
+        let segments: Vec<_> = self
+            .options
+            .iter()
+            .enumerate()
+            .map(|(index, option)| Segment {
+                bounds: self.segment_bounds(bounds, index),
+                label: option.to_string(),
+                is_selected: self.selected[index],
+                is_highlighted: state.is_focused && state.highlighted == index,
+            })
+            .collect();
+
+        self::Renderer::draw(
+            renderer,
+            bounds,
+            cursor_position,
+            &segments,
+            self.size.unwrap_or(renderer.default_size()),
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<SegmentedButton<'static, (), ()>>().hash(state);
+
+        self.width.hash(state);
+        self.padding.hash(state);
+        self.size.hash(state);
+
+        for option in self.options.iter() {
+            option.to_string().hash(state);
+        }
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.state.diff(tree);
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        focusables.push(Focusable {
+            path: path.clone(),
+            is_focused: self.state.borrow().is_focused,
+            uses_tab: false,
+        });
+    }
+}
+
+/// A segment of a [`SegmentedButton`], ready to be drawn.
+///
+/// [`SegmentedButton`]: struct.SegmentedButton.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Segment {
+    /// The bounds of the [`Segment`]
+    ///
+    /// [`Segment`]: struct.Segment.html
+    pub bounds: Rectangle,
+
+    /// The label of the [`Segment`]
+    ///
+    /// [`Segment`]: struct.Segment.html
+    pub label: String,
+
+    /// Whether the option of the [`Segment`] is selected
+    ///
+    /// [`Segment`]: struct.Segment.html
+    pub is_selected: bool,
+
+    /// Whether the [`Segment`] is highlighted by the keyboard
+    ///
+    /// [`Segment`]: struct.Segment.html
+    pub is_highlighted: bool,
+}
+
+/// The renderer of a [`SegmentedButton`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`SegmentedButton`] in your user interface.
+///
+/// [`SegmentedButton`]: struct.SegmentedButton.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer {
+    /// Draws a [`SegmentedButton`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`SegmentedButton`]
+    ///   * the cursor position
+    ///   * the [`Segment`]s to draw, from left to right
+    ///   * the text size of the labels
+    ///   * the [`Style`] of the [`SegmentedButton`]
+    ///
+    /// [`SegmentedButton`]: struct.SegmentedButton.html
+    /// [`Segment`]: struct.Segment.html
+    /// [`Style`]: struct.Style.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        segments: &[Segment],
+        text_size: u16,
+        style: &Style,
+    ) -> Self::Output;
+}
+
+impl<'a, T, Message, Renderer> From<SegmentedButton<'a, T, Message>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + ToString + PartialEq + Clone,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        segmented_button: SegmentedButton<'a, T, Message>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(segmented_button)
+    }
+}
@@ -0,0 +1,526 @@
+//! Write some text your users can select and copy.
+//!
+//! A [`SelectableText`] has some local [`State`].
+//!
+//! [`SelectableText`]: struct.SelectableText.html
+//! [`State`]: struct.State.html
+use crate::{
+    input::{keyboard, mouse, ButtonState},
+    layout, text,
+    tree::{self, Tree},
+    widget::text_input::platform,
+    Clipboard, Color, Element, Event, Font, Hasher, HorizontalAlignment,
+    Layout, Length, Point, Rectangle, Size, VerticalAlignment, Widget,
+};
+
+use std::{hash::Hash, ops::Range};
+
+/// A paragraph of text that can be selected with the mouse or the keyboard,
+/// and copied to the clipboard.
+///
+/// Dragging selects text, and holding shift while clicking extends the
+/// selection. Once the [`SelectableText`] has been clicked, the keyboard can
+/// select all of it, extend the selection, and copy it.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::SelectableText;
+/// #
+/// let log = SelectableText::managed("error: could not find `Cargo.toml`")
+///     .size(14);
+/// ```
+///
+/// [`SelectableText`]: struct.SelectableText.html
+#[derive(Debug)]
+pub struct SelectableText<'a> {
+    state: tree::State<'a, State>,
+    content: String,
+    size: Option<u16>,
+    color: Option<Color>,
+    font: Font,
+    params: text::Params,
+    width: Length,
+    height: Length,
+    horizontal_alignment: HorizontalAlignment,
+    vertical_alignment: VerticalAlignment,
+}
+
+impl<'a> SelectableText<'a> {
+    /// Creates a new [`SelectableText`] with some local [`State`] and the
+    /// given content.
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    /// [`State`]: struct.State.html
+    pub fn new<T: Into<String>>(state: &'a mut State, content: T) -> Self {
+        Self::with_state(tree::State::Borrowed(state), content)
+    }
+
+    /// Creates a new [`SelectableText`] with the given content, whose local
+    /// [`State`] is managed by the runtime.
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    /// [`State`]: struct.State.html
+    pub fn managed<T: Into<String>>(content: T) -> Self {
+        Self::with_state(tree::State::managed(), content)
+    }
+
+    fn with_state<T: Into<String>>(
+        state: tree::State<'a, State>,
+        content: T,
+    ) -> Self {
+        SelectableText {
+            state,
+            content: content.into(),
+            size: None,
+            color: None,
+            font: Font::Default,
+            params: text::Params::default(),
+            width: Length::Fill,
+            height: Length::Shrink,
+            horizontal_alignment: HorizontalAlignment::Left,
+            vertical_alignment: VerticalAlignment::Top,
+        }
+    }
+
+    /// Sets the size of the [`SelectableText`].
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Color`] of the [`SelectableText`].
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    /// [`Color`]: ../../struct.Color.html
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the [`Font`] of the [`SelectableText`].
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    /// [`Font`]: ../../struct.Font.html
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the [`Params`] of the [`SelectableText`], like its line height
+    /// or its wrapping.
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    /// [`Params`]: ../text/struct.Params.html
+    pub fn params(mut self, params: text::Params) -> Self {
+        self.params = params;
+        self
+    }
+
+    /// Sets the width of the [`SelectableText`] boundaries.
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`SelectableText`] boundaries.
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`HorizontalAlignment`] of the [`SelectableText`].
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    /// [`HorizontalAlignment`]: ../../enum.HorizontalAlignment.html
+    pub fn horizontal_alignment(
+        mut self,
+        alignment: HorizontalAlignment,
+    ) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the [`VerticalAlignment`] of the [`SelectableText`].
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    /// [`VerticalAlignment`]: ../../enum.VerticalAlignment.html
+    pub fn vertical_alignment(mut self, alignment: VerticalAlignment) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+}
+
+/// The local state of a [`SelectableText`].
+///
+/// [`SelectableText`]: struct.SelectableText.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    anchor: usize,
+    head: usize,
+    is_dragging: bool,
+    is_focused: bool,
+    is_shift_pressed: bool,
+}
+
+impl State {
+    /// Creates a new [`State`], with nothing selected.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns the byte range of the content selected in the
+    /// [`SelectableText`], if any.
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    pub fn selection(&self) -> Option<Range<usize>> {
+        if self.anchor == self.head {
+            None
+        } else {
+            Some(self.anchor.min(self.head)..self.anchor.max(self.head))
+        }
+    }
+
+    /// Returns whether the [`SelectableText`] was the last text clicked,
+    /// and therefore receives keyboard input.
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Clears the selection of the [`SelectableText`].
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    pub fn clear_selection(&mut self) {
+        self.anchor = self.head;
+    }
+
+    fn select(&mut self, head: usize, is_extended: bool) {
+        self.head = head;
+
+        if !is_extended {
+            self.anchor = head;
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for SelectableText<'a>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let text_size = self.size.unwrap_or(renderer.default_size());
+
+        let (width, height) = text::Renderer::measure(
+            renderer,
+            &self.content,
+            text_size,
+            self.font,
+            self.params,
+            limits.max(),
+        );
+
+        let size = limits.resolve(Size::new(width, height));
+
+        let top = match self.vertical_alignment {
+            VerticalAlignment::Top => 0.0,
+            VerticalAlignment::Center => (size.height - height) / 2.0,
+            VerticalAlignment::Bottom => size.height - height,
+        };
+
+        layout::Node::new(size)
+            .with_baseline(top + renderer.baseline(text_size, self.font))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let SelectableText {
+            state,
+            content,
+            size,
+            font,
+            params,
+            horizontal_alignment,
+            vertical_alignment,
+            ..
+        } = self;
+
+        let bounds = layout.bounds();
+        let length = content.len();
+
+        let offset_at = |point| {
+            let offset = renderer.offset_at(
+                bounds,
+                content,
+                size.unwrap_or(renderer.default_size()),
+                *font,
+                *params,
+                *horizontal_alignment,
+                *vertical_alignment,
+                point,
+            );
+
+            clamp(content, offset)
+        };
+
+        let mut state = state.borrow_mut();
+
+        // The content may have changed since the selection was made
+        state.anchor = clamp(content, state.anchor);
+        state.head = clamp(content, state.head);
+
+        match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) => {
+                if bounds.contains(cursor_position) {
+                    let offset = offset_at(cursor_position);
+
+                    let is_extended =
+                        state.is_focused && state.is_shift_pressed;
+
+                    state.select(offset, is_extended);
+                    state.is_dragging = true;
+                    state.is_focused = true;
+                } else {
+                    state.clear_selection();
+                    state.is_focused = false;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { x, y })
+                if state.is_dragging =>
+            {
+                let offset = offset_at(Point::new(x, y));
+
+                state.select(offset, true);
+            }
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Released,
+            }) => {
+                state.is_dragging = false;
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code,
+                state: button_state,
+                modifiers,
+            }) => {
+                state.is_shift_pressed = modifiers.shift;
+
+                if !state.is_focused || button_state != ButtonState::Pressed {
+                    return;
+                }
+
+                let previous = content[..state.head]
+                    .chars()
+                    .next_back()
+                    .map_or(0, |c| state.head - c.len_utf8());
+
+                let next = content[state.head..]
+                    .chars()
+                    .next()
+                    .map_or(length, |c| state.head + c.len_utf8());
+
+                match key_code {
+                    keyboard::KeyCode::A
+                        if platform::is_copy_paste_modifier_pressed(
+                            modifiers,
+                        ) =>
+                    {
+                        state.anchor = 0;
+                        state.head = length;
+                    }
+                    keyboard::KeyCode::C
+                        if platform::is_copy_paste_modifier_pressed(
+                            modifiers,
+                        ) =>
+                    {
+                        let selected = state
+                            .selection()
+                            .and_then(|range| content.get(range));
+
+                        if let (Some(selected), Some(clipboard)) =
+                            (selected, clipboard)
+                        {
+                            let _ = clipboard.write(selected.to_string());
+                        }
+                    }
+                    keyboard::KeyCode::Left => {
+                        state.select(previous, modifiers.shift);
+                    }
+                    keyboard::KeyCode::Right => {
+                        state.select(next, modifiers.shift);
+                    }
+                    keyboard::KeyCode::Home => {
+                        state.select(0, modifiers.shift);
+                    }
+                    keyboard::KeyCode::End => {
+                        state.select(length, modifiers.shift);
+                    }
+                    keyboard::KeyCode::Escape => {
+                        state.clear_selection();
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let state = self.state.borrow();
+
+        let selection = state
+            .selection()
+            .filter(|range| self.content.get(range.clone()).is_some());
+
+        self::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            cursor_position,
+            &self.content,
+            self.size.unwrap_or(renderer.default_size()),
+            self.font,
+            self.params,
+            self.color,
+            self.horizontal_alignment,
+            self.vertical_alignment,
+            selection,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<SelectableText<'static>>().hash(state);
+
+        self.content.hash(state);
+        self.size.hash(state);
+        self.params.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+        self.vertical_alignment.hash(state);
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.state.diff(tree);
+    }
+
+    fn captures_mouse(&self) -> bool {
+        self.state.borrow().is_dragging
+    }
+}
+
+fn clamp(content: &str, offset: usize) -> usize {
+    let mut offset = offset.min(content.len());
+
+    while !content.is_char_boundary(offset) {
+        offset -= 1;
+    }
+
+    offset
+}
+
+/// The renderer of a [`SelectableText`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`SelectableText`] in your user interface.
+///
+/// [`SelectableText`]: struct.SelectableText.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer {
+    /// Returns the byte offset of the content of a [`SelectableText`] that
+    /// is closest to the given point, laid out like it is drawn.
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    #[allow(clippy::too_many_arguments)]
+    fn offset_at(
+        &self,
+        bounds: Rectangle,
+        content: &str,
+        size: u16,
+        font: Font,
+        params: text::Params,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+        point: Point,
+    ) -> usize;
+
+    /// Draws a [`SelectableText`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`SelectableText`]
+    ///   * the cursor position
+    ///   * the contents of the [`SelectableText`]
+    ///   * the size of the text
+    ///   * the [`Font`] of the text
+    ///   * the [`Params`] of the text
+    ///   * the color of the text
+    ///   * the [`HorizontalAlignment`] of the text
+    ///   * the [`VerticalAlignment`] of the text
+    ///   * the byte range of the selected content, if any
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    /// [`Font`]: ../../struct.Font.html
+    /// [`Params`]: ../text/struct.Params.html
+    /// [`HorizontalAlignment`]: ../../enum.HorizontalAlignment.html
+    /// [`VerticalAlignment`]: ../../enum.VerticalAlignment.html
+    #[allow(clippy::too_many_arguments)]
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        content: &str,
+        size: u16,
+        font: Font,
+        params: text::Params,
+        color: Option<Color>,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+        selection: Option<Range<usize>>,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<SelectableText<'a>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'static + self::Renderer,
+{
+    fn from(text: SelectableText<'a>) -> Element<'a, Message, Renderer> {
+        Element::new(text)
+    }
+}
@@ -5,19 +5,29 @@
 //! [`Slider`]: struct.Slider.html
 //! [`State`]: struct.State.html
 use crate::{
-    input::{mouse, ButtonState},
-    layout, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    draggable::{self, Drag},
+    input::{keyboard, ButtonState},
+    layout,
+    tree::{self, Tree},
+    Clipboard, Element, Event, Focus, Focusable, Hasher, Layout, Length, Point,
     Rectangle, Size, Widget,
 };
 
 use std::{hash::Hash, ops::RangeInclusive};
 
-/// An horizontal bar and a handle that selects a single value from a range of
-/// values.
+pub use draggable::Axis;
+
+/// A bar and a handle that selects a single value from a range of values.
+///
+/// A [`Slider`] will try to fill the horizontal space of its container, or
+/// the vertical one if its [`Axis`] is vertical.
 ///
-/// A [`Slider`] will try to fill the horizontal space of its container.
+/// When it has the focus, the arrow keys move its handle by a step,
+/// `PageUp` and `PageDown` by ten steps, and `Home` and `End` to the bounds
+/// of its range.
 ///
 /// [`Slider`]: struct.Slider.html
+/// [`Axis`]: enum.Axis.html
 ///
 /// # Example
 /// ```
@@ -25,22 +35,34 @@ use std::{hash::Hash, ops::RangeInclusive};
 /// #
 /// pub enum Message {
 ///     SliderChanged(f32),
+///     VolumeChanged(f32),
 /// }
 ///
 /// let state = &mut slider::State::new();
 /// let value = 50.0;
 ///
 /// Slider::new(state, 0.0..=100.0, value, Message::SliderChanged);
+///
+/// let volume = 0.8;
+///
+/// Slider::managed(0.0..=1.0, volume, Message::VolumeChanged)
+///     .axis(slider::Axis::Vertical)
+///     .step(0.1)
+///     .ticks(true);
 /// ```
 ///
 /// ![Slider drawn by Coffee's renderer](https://github.com/hecrj/coffee/blob/bda9818f823dfcb8a7ad0ff4940b4d4b387b5208/images/ui/slider.png?raw=true)
 #[allow(missing_debug_implementations)]
 pub struct Slider<'a, Message> {
-    state: &'a mut State,
+    state: tree::State<'a, State>,
     range: RangeInclusive<f32>,
     value: f32,
     on_change: Box<dyn Fn(f32) -> Message>,
     width: Length,
+    height: Length,
+    axis: Axis,
+    step: Option<f32>,
+    ticks: bool,
 }
 
 impl<'a, Message> Slider<'a, Message> {
@@ -62,6 +84,37 @@ impl<'a, Message> Slider<'a, Message> {
         value: f32,
         on_change: F,
     ) -> Self
+    where
+        F: 'static + Fn(f32) -> Message,
+    {
+        Self::with_state(tree::State::Borrowed(state), range, value, on_change)
+    }
+
+    /// Creates a new [`Slider`] whose local [`State`] is managed by the
+    /// runtime.
+    ///
+    /// It expects the same arguments as [`new`], except for the [`State`].
+    ///
+    /// [`Slider`]: struct.Slider.html
+    /// [`State`]: struct.State.html
+    /// [`new`]: #method.new
+    pub fn managed<F>(
+        range: RangeInclusive<f32>,
+        value: f32,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(f32) -> Message,
+    {
+        Self::with_state(tree::State::managed(), range, value, on_change)
+    }
+
+    fn with_state<F>(
+        state: tree::State<'a, State>,
+        range: RangeInclusive<f32>,
+        value: f32,
+        on_change: F,
+    ) -> Self
     where
         F: 'static + Fn(f32) -> Message,
     {
@@ -71,24 +124,72 @@ impl<'a, Message> Slider<'a, Message> {
             range,
             on_change: Box::new(on_change),
             width: Length::Fill,
+            height: Length::Fill,
+            axis: Axis::Horizontal,
+            step: None,
+            ticks: false,
         }
     }
 
-    /// Sets the width of the [`Slider`].
+    /// Sets the width of a horizontal [`Slider`].
     ///
     /// [`Slider`]: struct.Slider.html
     pub fn width(mut self, width: Length) -> Self {
         self.width = width;
         self
     }
+
+    /// Sets the height of a vertical [`Slider`].
+    ///
+    /// [`Slider`]: struct.Slider.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`Axis`] of the [`Slider`].
+    ///
+    /// The start of the range is on the left of a horizontal [`Slider`], and
+    /// at the bottom of a vertical one.
+    ///
+    /// [`Axis`]: enum.Axis.html
+    /// [`Slider`]: struct.Slider.html
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Makes the [`Slider`] only produce values that are a multiple of the
+    /// given step away from the start of its range.
+    ///
+    /// It is also the amount the arrow keys move the handle by, which is a
+    /// hundredth of the range otherwise.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = Some(step).filter(|step| *step > 0.0);
+        self
+    }
+
+    /// Sets whether the [`Slider`] draws a tick mark at every step.
+    ///
+    /// It has no effect without a [`step`].
+    ///
+    /// [`Slider`]: struct.Slider.html
+    /// [`step`]: #method.step
+    pub fn ticks(mut self, ticks: bool) -> Self {
+        self.ticks = ticks;
+        self
+    }
 }
 
 /// The local state of a [`Slider`].
 ///
 /// [`Slider`]: struct.Slider.html
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct State {
-    is_dragging: bool,
+    drag: draggable::State,
+    is_focused: bool,
 }
 
 impl State {
@@ -98,6 +199,13 @@ impl State {
     pub fn new() -> State {
         State::default()
     }
+
+    /// Returns whether the [`Slider`] is currently focused or not.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer> for Slider<'a, Message>
@@ -105,11 +213,17 @@ where
     Renderer: self::Renderer,
 {
     fn width(&self) -> Length {
-        self.width
+        match self.axis {
+            Axis::Horizontal => self.width,
+            Axis::Vertical => Length::Shrink,
+        }
     }
 
     fn height(&self) -> Length {
-        Length::Shrink
+        match self.axis {
+            Axis::Horizontal => Length::Shrink,
+            Axis::Vertical => self.height,
+        }
     }
 
     fn layout(
@@ -117,13 +231,13 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let limits = limits
-            .width(self.width)
-            .height(Length::Units(renderer.height() as u16));
-
-        let size = limits.resolve(Size::ZERO);
-
-        layout::Node::new(size)
+        layout::Node::new(self::layout(
+            renderer,
+            limits,
+            self.axis,
+            self.width,
+            self.height,
+        ))
     }
 
     fn on_event(
@@ -135,43 +249,37 @@ where
         _renderer: &Renderer,
         _clipboard: Option<&dyn Clipboard>,
     ) {
-        let mut change = || {
-            let bounds = layout.bounds();
+        let bounds = layout.bounds();
+        let mut state = *self.state.borrow();
 
-            if cursor_position.x <= bounds.x {
-                messages.push((self.on_change)(*self.range.start()));
-            } else if cursor_position.x >= bounds.x + bounds.width {
-                messages.push((self.on_change)(*self.range.end()));
-            } else {
-                let percent = (cursor_position.x - bounds.x) / bounds.width;
-                let value = (self.range.end() - self.range.start()) * percent
-                    + self.range.start();
-
-                messages.push((self.on_change)(value));
+        let value = match event {
+            Event::Focus(focus) if layout.is_focus_target() => {
+                state.is_focused = focus == Focus::Gained;
+                None
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code,
+                state: ButtonState::Pressed,
+                ..
+            }) if state.is_focused => {
+                adjust(key_code, self.value, &self.range, self.step)
             }
+            _ => match state.drag.update(event, bounds, cursor_position) {
+                Some(Drag::Started) | Some(Drag::Moved(_)) => Some(value_at(
+                    self.axis,
+                    bounds,
+                    cursor_position,
+                    &self.range,
+                    self.step,
+                )),
+                Some(Drag::Ended) | None => None,
+            },
         };
 
-        match event {
-            Event::Mouse(mouse::Event::Input {
-                button: mouse::Button::Left,
-                state,
-            }) => match state {
-                ButtonState::Pressed => {
-                    if layout.bounds().contains(cursor_position) {
-                        change();
-                        self.state.is_dragging = true;
-                    }
-                }
-                ButtonState::Released => {
-                    self.state.is_dragging = false;
-                }
-            },
-            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
-                if self.state.is_dragging {
-                    change();
-                }
-            }
-            _ => {}
+        *self.state.borrow_mut() = state;
+
+        if let Some(value) = value.filter(|value| *value != self.value) {
+            messages.push((self.on_change)(value));
         }
     }
 
@@ -181,18 +289,173 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
     ) -> Renderer::Output {
+        let state = self.state.borrow();
+
+        let track = Track {
+            axis: self.axis,
+            range: self.range.clone(),
+            handles: vec![self.value],
+            ticks: if self.ticks {
+                ticks(&self.range, self.step)
+            } else {
+                Vec::new()
+            },
+        };
+
         renderer.draw(
             layout.bounds(),
             cursor_position,
-            self.range.clone(),
-            self.value,
-            self.state.is_dragging,
+            &track,
+            state.drag.is_dragging(),
+            state.is_focused,
         )
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
         self.width.hash(state);
+        self.height.hash(state);
+        self.axis.hash(state);
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.state.diff(tree);
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        focusables.push(Focusable {
+            path: path.clone(),
+            is_focused: self.state.borrow().is_focused,
+            uses_tab: false,
+        });
     }
+
+    fn captures_mouse(&self) -> bool {
+        self.state.borrow().drag.is_dragging()
+    }
+}
+
+/// The rail of a [`Slider`] or a [`RangeSlider`], and what is placed on it.
+///
+/// [`Slider`]: struct.Slider.html
+/// [`RangeSlider`]: ../range_slider/struct.RangeSlider.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Track {
+    /// The [`Axis`] of the rail.
+    ///
+    /// [`Axis`]: enum.Axis.html
+    pub axis: Axis,
+
+    /// The range of values of the rail.
+    pub range: RangeInclusive<f32>,
+
+    /// The values of the handles, in increasing order.
+    ///
+    /// When there are two, the part of the rail between them is selected.
+    pub handles: Vec<f32>,
+
+    /// The values where a tick mark is drawn, if any.
+    pub ticks: Vec<f32>,
+}
+
+// Resolves the size of a slider, which is as thick as the renderer wants
+pub(crate) fn layout<Renderer>(
+    renderer: &Renderer,
+    limits: &layout::Limits,
+    axis: Axis,
+    width: Length,
+    height: Length,
+) -> Size
+where
+    Renderer: self::Renderer,
+{
+    let thickness = Length::Units(renderer.height() as u16);
+
+    let limits = match axis {
+        Axis::Horizontal => limits.width(width).height(thickness),
+        Axis::Vertical => limits.width(thickness).height(height),
+    };
+
+    limits.resolve(Size::ZERO)
+}
+
+// Returns the value under the cursor, snapped to the step
+pub(crate) fn value_at(
+    axis: Axis,
+    bounds: Rectangle,
+    cursor_position: Point,
+    range: &RangeInclusive<f32>,
+    step: Option<f32>,
+) -> f32 {
+    let percent = match axis {
+        Axis::Horizontal => (cursor_position.x - bounds.x) / bounds.width,
+        Axis::Vertical => {
+            (bounds.y + bounds.height - cursor_position.y) / bounds.height
+        }
+    };
+
+    let value =
+        (range.end() - range.start()) * percent.clamp(0.0, 1.0) + range.start();
+
+    snap(value, range, step)
+}
+
+// Clamps the value to the range, rounding it to the closest step
+pub(crate) fn snap(
+    value: f32,
+    range: &RangeInclusive<f32>,
+    step: Option<f32>,
+) -> f32 {
+    let (start, end) = (*range.start(), *range.end());
+
+    let value = match step {
+        Some(step) => start + ((value - start) / step).round() * step,
+        None => value,
+    };
+
+    value.max(start).min(end)
+}
+
+// Returns the value a key moves a handle to, if the key is meaningful
+pub(crate) fn adjust(
+    key_code: keyboard::KeyCode,
+    value: f32,
+    range: &RangeInclusive<f32>,
+    step: Option<f32>,
+) -> Option<f32> {
+    let step = step.unwrap_or((range.end() - range.start()) / 100.0);
+
+    let value = match key_code {
+        keyboard::KeyCode::Left | keyboard::KeyCode::Down => value - step,
+        keyboard::KeyCode::Right | keyboard::KeyCode::Up => value + step,
+        keyboard::KeyCode::PageDown => value - 10.0 * step,
+        keyboard::KeyCode::PageUp => value + 10.0 * step,
+        keyboard::KeyCode::Home => *range.start(),
+        keyboard::KeyCode::End => *range.end(),
+        _ => return None,
+    };
+
+    Some(snap(value, range, Some(step)))
+}
+
+// Returns the values of the tick marks of a range, one for every step
+pub(crate) fn ticks(
+    range: &RangeInclusive<f32>,
+    step: Option<f32>,
+) -> Vec<f32> {
+    let step = match step {
+        Some(step) => step,
+        None => return Vec::new(),
+    };
+
+    let count = ((range.end() - range.start()) / step).floor() as usize;
+
+    (0..=count)
+        .map(|i| range.start() + i as f32 * step)
+        .collect()
 }
 
 /// The renderer of a [`Slider`].
@@ -203,7 +466,10 @@ where
 /// [`Slider`]: struct.Slider.html
 /// [renderer]: ../../renderer/index.html
 pub trait Renderer: crate::Renderer {
-    /// Returns the height of the [`Slider`].
+    /// Returns the thickness of the [`Slider`].
+    ///
+    /// It is the height of a horizontal [`Slider`], and the width of a
+    /// vertical one.
     ///
     /// [`Slider`]: struct.Slider.html
     fn height(&self) -> u32;
@@ -211,22 +477,21 @@ pub trait Renderer: crate::Renderer {
     /// Draws a [`Slider`].
     ///
     /// It receives:
-    ///   * the current cursor position
     ///   * the bounds of the [`Slider`]
-    ///   * the local state of the [`Slider`]
-    ///   * the range of values of the [`Slider`]
-    ///   * the current value of the [`Slider`]
+    ///   * the current cursor position
+    ///   * the [`Track`] of the [`Slider`]
+    ///   * whether a handle is being dragged
+    ///   * whether the [`Slider`] has the focus
     ///
     /// [`Slider`]: struct.Slider.html
-    /// [`State`]: struct.State.html
-    /// [`Class`]: enum.Class.html
+    /// [`Track`]: struct.Track.html
     fn draw(
         &mut self,
         bounds: Rectangle,
         cursor_position: Point,
-        range: RangeInclusive<f32>,
-        value: f32,
+        track: &Track,
         is_dragging: bool,
+        is_focused: bool,
     ) -> Self::Output;
 }
 
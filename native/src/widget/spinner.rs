@@ -0,0 +1,168 @@
+//! Show that something is happening, without telling how long it will take.
+use crate::{
+    layout, Color, Element, Hasher, Layout, Length, Point, Rectangle, Size,
+    Widget,
+};
+
+use std::hash::Hash;
+use std::time::{Duration, Instant, SystemTime};
+
+/// An animated indicator of a task of unknown length.
+///
+/// A [`Spinner`] keeps asking its renderer to redraw while it is visible, so
+/// it animates without any messages or subscriptions. Every [`Spinner`]
+/// follows the same clock, so the ones on screen turn in sync.
+///
+/// # Example
+/// ```
+/// # use iced_native::Spinner;
+/// #
+/// let spinner = Spinner::new().size(32);
+/// ```
+///
+/// [`Spinner`]: struct.Spinner.html
+#[derive(Debug, Clone)]
+pub struct Spinner {
+    size: u16,
+    period: Duration,
+    color: Color,
+}
+
+impl Spinner {
+    /// Creates a new [`Spinner`].
+    ///
+    /// [`Spinner`]: struct.Spinner.html
+    pub fn new() -> Self {
+        Spinner {
+            size: 24,
+            period: Duration::from_millis(1000),
+            color: Color::from_rgb(0.20, 0.60, 0.86),
+        }
+    }
+
+    /// Sets the size of the [`Spinner`], in pixels.
+    ///
+    /// [`Spinner`]: struct.Spinner.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the time the [`Spinner`] takes to complete a turn.
+    ///
+    /// [`Spinner`]: struct.Spinner.html
+    pub fn period(mut self, period: Duration) -> Self {
+        self.period = period;
+        self
+    }
+
+    /// Sets the color of the [`Spinner`].
+    ///
+    /// [`Spinner`]: struct.Spinner.html
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Returns the fraction of the current turn of the [`Spinner`], between
+    /// `0.0` and `1.0`.
+    ///
+    /// [`Spinner`]: struct.Spinner.html
+    pub fn phase(&self) -> f32 {
+        let period = self.period.as_millis().max(1);
+
+        let elapsed = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+
+        (elapsed % period) as f32 / period as f32
+    }
+}
+
+impl Default for Spinner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Spinner
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Units(self.size)
+    }
+
+    fn height(&self) -> Length {
+        Length::Units(self.size)
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = limits
+            .width(Length::Units(self.size))
+            .height(Length::Units(self.size))
+            .resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.schedule_redraw(Instant::now());
+
+        self::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            self.phase(),
+            self.color,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Spinner>().hash(state);
+
+        self.size.hash(state);
+    }
+}
+
+/// The renderer of a [`Spinner`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Spinner`] in your user interface.
+///
+/// [`Spinner`]: struct.Spinner.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// Draws a [`Spinner`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Spinner`]
+    ///   * the fraction of the current turn, between `0.0` and `1.0`
+    ///   * the color of the [`Spinner`]
+    ///
+    /// [`Spinner`]: struct.Spinner.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        phase: f32,
+        color: Color,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Spinner> for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn from(spinner: Spinner) -> Element<'a, Message, Renderer> {
+        Element::new(spinner)
+    }
+}
@@ -1,9 +1,12 @@
 //! Display vector graphics in your application.
-use crate::{layout, Element, Hasher, Layout, Length, Point, Size, Widget};
+use crate::{
+    layout, Align, Color, Element, Hasher, Layout, Length, Point, Size, Widget,
+};
 
 use std::{
     hash::Hash,
     path::{Path, PathBuf},
+    sync::{Arc, Mutex},
 };
 
 /// A vector graphics image.
@@ -13,12 +16,28 @@ use std::{
 /// [`Svg`] images can have a considerable rendering cost when resized,
 /// specially when they are complex.
 ///
+/// An [`Svg`] always preserves the aspect ratio of its viewport. When it is
+/// given more space than it needs, the image is aligned inside of it.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{Color, Svg};
+/// #
+/// // A monochrome icon matching the accent color of the application
+/// let icon = Svg::new("resources/icons/search.svg")
+///     .color(Color::from_rgb(0.2, 0.4, 0.8));
+/// ```
+///
 /// [`Svg`]: struct.Svg.html
 #[derive(Debug, Clone)]
 pub struct Svg {
     handle: Handle,
     width: Length,
     height: Length,
+    horizontal_alignment: Align,
+    vertical_alignment: Align,
+    color: Option<Color>,
 }
 
 impl Svg {
@@ -31,6 +50,9 @@ impl Svg {
             handle: handle.into(),
             width: Length::Fill,
             height: Length::Fill,
+            horizontal_alignment: Align::Center,
+            vertical_alignment: Align::Center,
+            color: None,
         }
     }
 
@@ -49,6 +71,40 @@ impl Svg {
         self.height = height;
         self
     }
+
+    /// Sets the horizontal alignment of the image inside the [`Svg`].
+    ///
+    /// By default, it is centered.
+    ///
+    /// [`Svg`]: struct.Svg.html
+    pub fn align_x(mut self, alignment: Align) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the vertical alignment of the image inside the [`Svg`].
+    ///
+    /// By default, it is centered.
+    ///
+    /// [`Svg`]: struct.Svg.html
+    pub fn align_y(mut self, alignment: Align) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Tints the [`Svg`] with the given [`Color`].
+    ///
+    /// Every visible pixel of the image takes the [`Color`], keeping its
+    /// transparency. This is meant for monochrome icons, which can then
+    /// follow the colors of your application instead of needing a file for
+    /// each color.
+    ///
+    /// [`Svg`]: struct.Svg.html
+    /// [`Color`]: ../../struct.Color.html
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Svg
@@ -69,23 +125,47 @@ where
         limits: &layout::Limits,
     ) -> layout::Node {
         let (width, height) = renderer.dimensions(&self.handle);
+        let intrinsic = Size::new(width.max(1) as f32, height.max(1) as f32);
 
-        let aspect_ratio = width as f32 / height as f32;
+        let limits = limits.width(self.width).height(self.height);
+        let mut available = limits.resolve(intrinsic);
 
-        let mut size = limits
-            .width(self.width)
-            .height(self.height)
-            .resolve(Size::new(width as f32, height as f32));
+        // A shrinking side follows the other one, keeping the aspect ratio
+        if self.width == Length::Shrink && self.height != Length::Shrink {
+            available.width = limits.max().width;
+        } else if self.height == Length::Shrink && self.width != Length::Shrink
+        {
+            available.height = limits.max().height;
+        }
 
-        let viewport_aspect_ratio = size.width / size.height;
+        let scale = (available.width / intrinsic.width)
+            .min(available.height / intrinsic.height);
 
-        if viewport_aspect_ratio > aspect_ratio {
-            size.width = width as f32 * size.height / height as f32;
-        } else {
-            size.height = height as f32 * size.width / width as f32;
-        }
+        // Without bounds on both sides, the image keeps its intrinsic size
+        let scale = if scale.is_finite() { scale } else { 1.0 };
+
+        let image_size =
+            Size::new(intrinsic.width * scale, intrinsic.height * scale);
+
+        // An unbounded side, like the height inside of a scrollable, wraps
+        // the image instead of filling the whole space
+        let size = Size::new(
+            if self.width == Length::Shrink || !available.width.is_finite() {
+                image_size.width
+            } else {
+                available.width
+            },
+            if self.height == Length::Shrink || !available.height.is_finite() {
+                image_size.height
+            } else {
+                available.height
+            },
+        );
+
+        let mut image = layout::Node::new(image_size);
+        image.align(self.horizontal_alignment, self.vertical_alignment, size);
 
-        layout::Node::new(size)
+        layout::Node::with_children(size, vec![image])
     }
 
     fn draw(
@@ -94,12 +174,21 @@ where
         layout: Layout<'_>,
         _cursor_position: Point,
     ) -> Renderer::Output {
-        renderer.draw(self.handle.clone(), layout)
+        renderer.draw(
+            self.handle.clone(),
+            self.color,
+            layout.children().next().unwrap(),
+        )
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Svg>().hash(state);
+
+        self.handle.id().hash(state);
         self.width.hash(state);
         self.height.hash(state);
+        self.horizontal_alignment.hash(state);
+        self.vertical_alignment.hash(state);
     }
 }
 
@@ -110,8 +199,12 @@ where
 pub struct Handle {
     id: u64,
     path: PathBuf,
+    dimensions: Arc<Mutex<Option<Dimensions>>>,
 }
 
+// The intrinsic dimensions of an image, if they can be determined
+type Dimensions = Option<(u32, u32)>;
+
 impl Handle {
     /// Creates an SVG [`Handle`] pointing to the vector image of the given
     /// path.
@@ -128,6 +221,7 @@ impl Handle {
         Handle {
             id: hasher.finish(),
             path,
+            dimensions: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -144,6 +238,109 @@ impl Handle {
     pub fn path(&self) -> &Path {
         &self.path
     }
+
+    /// Returns the intrinsic dimensions of the vector image of the
+    /// [`Handle`], if they can be determined.
+    ///
+    /// They are read from the `width` and `height` attributes of the root
+    /// `svg` element, falling back to its `viewBox`. Relative units, like
+    /// percentages, are ignored.
+    ///
+    /// The file is only parsed the first time, and the result is shared by
+    /// all the clones of the [`Handle`].
+    ///
+    /// [`Handle`]: struct.Handle.html
+    pub fn dimensions(&self) -> Option<(u32, u32)> {
+        let mut dimensions =
+            self.dimensions.lock().expect("Lock SVG dimensions");
+
+        *dimensions.get_or_insert_with(|| read_dimensions(&self.path))
+    }
+}
+
+fn read_dimensions(path: &Path) -> Dimensions {
+    let contents = std::fs::read_to_string(path).ok()?;
+
+    let start = contents.find("<svg")?;
+    let end = start + contents[start..].find('>')?;
+    let root = &contents[start..end];
+
+    let length = |name| attribute(root, name).and_then(parse_length);
+
+    let view_box = attribute(root, "viewBox").and_then(|view_box| {
+        let values: Vec<f32> = view_box
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|value| !value.is_empty())
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .ok()?;
+
+        match values[..] {
+            [_, _, width, height] if width > 0.0 && height > 0.0 => {
+                Some((width, height))
+            }
+            _ => None,
+        }
+    });
+
+    let (width, height) = match (length("width"), length("height")) {
+        (Some(width), Some(height)) => (width, height),
+        (Some(width), None) => {
+            let (w, h) = view_box?;
+            (width, width * h / w)
+        }
+        (None, Some(height)) => {
+            let (w, h) = view_box?;
+            (height * w / h, height)
+        }
+        (None, None) => view_box?,
+    };
+
+    Some((width.round() as u32, height.round() as u32))
+}
+
+fn attribute<'a>(element: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = element;
+
+    while let Some(index) = rest.find(name) {
+        let preceded_by_space = rest[..index]
+            .chars()
+            .last()
+            .map(char::is_whitespace)
+            .unwrap_or(false);
+
+        rest = &rest[index + name.len()..];
+
+        let value = rest.trim_start();
+
+        if !preceded_by_space || !value.starts_with('=') {
+            continue;
+        }
+
+        let value = value[1..].trim_start();
+        let quote = value.chars().next()?;
+
+        if quote != '"' && quote != '\'' {
+            continue;
+        }
+
+        let value = &value[1..];
+
+        return value.find(quote).map(|end| &value[..end]);
+    }
+
+    None
+}
+
+fn parse_length(length: &str) -> Option<f32> {
+    let length = length.trim();
+    let number = length.trim_end_matches("px");
+
+    if number.ends_with(|c: char| c.is_ascii_alphabetic() || c == '%') {
+        return None;
+    }
+
+    number.parse().ok().filter(|length: &f32| *length > 0.0)
 }
 
 impl From<String> for Handle {
@@ -168,13 +365,28 @@ impl From<&str> for Handle {
 pub trait Renderer: crate::Renderer {
     /// Returns the default dimensions of an [`Svg`] located on the given path.
     ///
+    /// By default, it uses the intrinsic dimensions of the [`Handle`].
+    ///
     /// [`Svg`]: struct.Svg.html
-    fn dimensions(&self, handle: &Handle) -> (u32, u32);
+    /// [`Handle`]: struct.Handle.html
+    fn dimensions(&self, handle: &Handle) -> (u32, u32) {
+        handle.dimensions().unwrap_or((1, 1))
+    }
 
-    /// Draws an [`Svg`].
+    /// Draws an [`Svg`], tinted with the given [`Color`], if any.
+    ///
+    /// The given [`Layout`] contains the bounds of the image, already fitted
+    /// to preserve its aspect ratio.
     ///
     /// [`Svg`]: struct.Svg.html
-    fn draw(&mut self, handle: Handle, layout: Layout<'_>) -> Self::Output;
+    /// [`Color`]: ../../struct.Color.html
+    /// [`Layout`]: ../../struct.Layout.html
+    fn draw(
+        &mut self,
+        handle: Handle,
+        color: Option<Color>,
+        layout: Layout<'_>,
+    ) -> Self::Output;
 }
 
 impl<'a, Message, Renderer> From<Svg> for Element<'a, Message, Renderer>
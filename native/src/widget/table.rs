@@ -0,0 +1,1440 @@
+//! Display large grids of text with pinned headers and columns.
+//!
+//! A [`Table`] has some local [`State`].
+//!
+//! [`Table`]: struct.Table.html
+//! [`State`]: struct.State.html
+use crate::{
+    input::{keyboard, mouse, ButtonState},
+    layout, overlay,
+    tree::{self, Tree},
+    Clipboard, Element, Event, Focusable, Hasher, Layout, Length, Point,
+    Rectangle, Size, Vector, Widget,
+};
+
+use std::{
+    hash::Hash,
+    time::{Duration, Instant},
+};
+
+// The longest time between the clicks of a double click
+const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(500);
+
+// The width of the area around the right edge of a header cell that can be
+// dragged to resize its column
+const RESIZE_HANDLE_WIDTH: f32 = 8.0;
+
+// The narrowest a column can be resized to
+const MIN_COLUMN_WIDTH: f32 = 24.0;
+
+/// A grid of text that scrolls in both axes, like a spreadsheet.
+///
+/// The header row of a [`Table`] is always visible. Some leading rows and
+/// columns can also be frozen, so they stay pinned while the rest of the
+/// cells scroll beneath them.
+///
+/// Only the visible cells are produced, so a [`Table`] can hold millions of
+/// rows.
+///
+/// Cells can be edited in place. Double-clicking a cell, or pressing `F2`
+/// once it is selected, produces an [`Edit`] message. While the cell is
+/// being edited, the [`Table`] shows the editor you provide exactly over it.
+/// `Enter` commits the edit, `Escape` cancels it, and `Tab` commits it and
+/// moves to the next editable cell.
+///
+/// Your application owns the order of the rows and the width of the columns.
+/// Clicking a header asks to sort the rows by its column, and dragging the
+/// right edge of a header asks to resize its column.
+///
+/// # Example
+/// ```
+/// # use iced_native::table;
+/// #
+/// # type Table<'a, Message> =
+/// #     iced_native::Table<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone, Copy)]
+/// enum Message {
+///     CellEdited(table::Edit),
+///     Sorted(usize, table::Order),
+///     Resized(usize, u16),
+/// }
+///
+/// let prices = vec![("Apples", 1.5, 2.0), ("Pears", 2.25, 2.5)];
+/// let state = &mut table::State::new();
+///
+/// let columns = vec![
+///     table::Column::new("Fruit", 120),
+///     table::Column::new("2019", 80),
+///     table::Column::new("2020", 80),
+/// ];
+///
+/// let table: Table<'_, Message> =
+///     Table::new(state, columns, prices.len(), |row, column| {
+///         let (fruit, before, after) = prices[row];
+///
+///         match column {
+///             0 => fruit.to_string(),
+///             1 => format!("{:.2}", before),
+///             _ => format!("{:.2}", after),
+///         }
+///     })
+///     .frozen_columns(1)
+///     .sorted(0, table::Order::Ascending)
+///     .on_edit(Message::CellEdited)
+///     .on_sort(Message::Sorted)
+///     .on_resize(Message::Resized);
+/// ```
+///
+/// [`Table`]: struct.Table.html
+/// [`Edit`]: enum.Edit.html
+#[allow(missing_debug_implementations)]
+pub struct Table<'a, Message, Renderer> {
+    state: tree::State<'a, State>,
+    columns: Vec<Column>,
+    rows: usize,
+    cell: Box<dyn Fn(usize, usize) -> String + 'a>,
+    frozen_columns: usize,
+    frozen_rows: usize,
+    row_height: u16,
+    width: Length,
+    height: Length,
+    selects_rows: bool,
+    sorted: Option<(usize, Order)>,
+    on_edit: Option<Box<dyn Fn(Edit) -> Message + 'a>>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_sort: Option<Box<dyn Fn(usize, Order) -> Message + 'a>>,
+    on_resize: Option<Box<dyn Fn(usize, u16) -> Message + 'a>>,
+    editor: Option<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Table<'a, Message, Renderer> {
+    /// Creates a new [`Table`] with some local [`State`], the given columns,
+    /// and an amount of rows.
+    ///
+    /// The closure produces the contents of the cell at the given row and
+    /// column. It is only called for the visible cells.
+    ///
+    /// [`Table`]: struct.Table.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(
+        state: &'a mut State,
+        columns: Vec<Column>,
+        rows: usize,
+        cell: F,
+    ) -> Self
+    where
+        F: 'a + Fn(usize, usize) -> String,
+    {
+        Self::with_state(tree::State::Borrowed(state), columns, rows, cell)
+    }
+
+    /// Creates a new [`Table`] with the given columns, amount of rows, and
+    /// cells, whose local [`State`] is managed by the runtime.
+    ///
+    /// [`Table`]: struct.Table.html
+    /// [`State`]: struct.State.html
+    pub fn managed<F>(columns: Vec<Column>, rows: usize, cell: F) -> Self
+    where
+        F: 'a + Fn(usize, usize) -> String,
+    {
+        Self::with_state(tree::State::managed(), columns, rows, cell)
+    }
+
+    /// Creates a new [`Table`] with the given columns, amount of rows, and
+    /// cells, whose local [`State`] is shared through the given [`Link`].
+    ///
+    /// [`Table`]: struct.Table.html
+    /// [`State`]: struct.State.html
+    /// [`Link`]: ../../tree/struct.Link.html
+    pub fn linked<F>(
+        link: &tree::Link<State>,
+        columns: Vec<Column>,
+        rows: usize,
+        cell: F,
+    ) -> Self
+    where
+        F: 'a + Fn(usize, usize) -> String,
+    {
+        Self::with_state(link.into(), columns, rows, cell)
+    }
+
+    fn with_state<F>(
+        state: tree::State<'a, State>,
+        columns: Vec<Column>,
+        rows: usize,
+        cell: F,
+    ) -> Self
+    where
+        F: 'a + Fn(usize, usize) -> String,
+    {
+        Table {
+            state,
+            columns,
+            rows,
+            cell: Box::new(cell),
+            frozen_columns: 0,
+            frozen_rows: 0,
+            row_height: 28,
+            width: Length::Fill,
+            height: Length::Fill,
+            selects_rows: false,
+            sorted: None,
+            on_edit: None,
+            on_select: None,
+            on_sort: None,
+            on_resize: None,
+            editor: None,
+        }
+    }
+
+    /// Sets the width of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the height of the header and of every row of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn row_height(mut self, row_height: u16) -> Self {
+        self.row_height = row_height;
+        self
+    }
+
+    /// Sets the amount of leading columns that stay pinned to the left while
+    /// the [`Table`] scrolls horizontally.
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn frozen_columns(mut self, frozen_columns: usize) -> Self {
+        self.frozen_columns = frozen_columns.min(self.columns.len());
+        self
+    }
+
+    /// Sets the amount of leading rows that stay pinned below the header
+    /// while the [`Table`] scrolls vertically.
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn frozen_rows(mut self, frozen_rows: usize) -> Self {
+        self.frozen_rows = frozen_rows.min(self.rows);
+        self
+    }
+
+    /// Sets whether clicking a cell of the [`Table`] selects its whole row,
+    /// instead of the cell alone.
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn select_rows(mut self, selects_rows: bool) -> Self {
+        self.selects_rows = selects_rows;
+        self
+    }
+
+    /// Marks the column the rows of the [`Table`] are sorted by, and their
+    /// [`Order`].
+    ///
+    /// The header of the column shows the [`Order`]. The [`Table`] does not
+    /// sort the rows; the closure producing the cells should follow the
+    /// order.
+    ///
+    /// [`Table`]: struct.Table.html
+    /// [`Order`]: enum.Order.html
+    pub fn sorted(mut self, column: usize, order: Order) -> Self {
+        self.sorted = Some((column, order));
+        self
+    }
+
+    /// Sets the message that will be produced when the user selects a row of
+    /// the [`Table`], by clicking any of its cells.
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn on_select<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that will be produced when the user clicks the
+    /// header of a sortable column of the [`Table`].
+    ///
+    /// It receives the column and the [`Order`] the rows should be sorted
+    /// in: ascending, unless the rows are sorted by the column in ascending
+    /// order already.
+    ///
+    /// [`Table`]: struct.Table.html
+    /// [`Order`]: enum.Order.html
+    pub fn on_sort<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(usize, Order) -> Message,
+    {
+        self.on_sort = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that will be produced while the user drags the right
+    /// edge of the header of a resizable column of the [`Table`].
+    ///
+    /// It receives the column and its new width, in pixels.
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn on_resize<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(usize, u16) -> Message,
+    {
+        self.on_resize = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that will be produced when the user starts, commits,
+    /// or cancels the edit of a cell of the [`Table`].
+    ///
+    /// Cells can only be edited when this message is set.
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn on_edit<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(Edit) -> Message,
+    {
+        self.on_edit = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the editor of the cell being edited in the [`Table`].
+    ///
+    /// The editor fills the cell exactly. You will normally choose it
+    /// depending on the column of the edit: a [`TextInput`] for text, a
+    /// [`Checkbox`] for flags, and so on. A focused [`TextInput`] lets the
+    /// user type right away.
+    ///
+    /// The `Enter`, `Escape`, and `Tab` keys are handled by the [`Table`]
+    /// and never reach the editor.
+    ///
+    /// [`Table`]: struct.Table.html
+    /// [`TextInput`]: ../text_input/struct.TextInput.html
+    /// [`Checkbox`]: ../checkbox/struct.Checkbox.html
+    pub fn editor<E>(mut self, editor: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.editor = Some(editor.into());
+        self
+    }
+
+    /// Returns the bounds of a cell on the screen, given the bounds of the
+    /// [`Table`] and its current scroll position.
+    ///
+    /// The row is `None` for the header. The bounds are not clipped, so they
+    /// may lie outside of the [`Table`] or below its frozen areas.
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn cell_bounds(
+        &self,
+        bounds: Rectangle,
+        row: Option<usize>,
+        column: usize,
+    ) -> Rectangle {
+        let grid = self.grid(bounds);
+        let offset = self.state.borrow().clamped(grid.max_offset);
+
+        let x = self.columns[..column.min(self.columns.len())]
+            .iter()
+            .map(|column| f32::from(column.width))
+            .sum::<f32>();
+        let y = row.map(|row| row + 1).unwrap_or(0) as f32 * grid.row_height;
+
+        let is_scrolling_x = column >= self.frozen_columns;
+        let is_scrolling_y = match row {
+            Some(row) => row >= self.frozen_rows,
+            None => false,
+        };
+
+        Rectangle {
+            x: bounds.x + x - if is_scrolling_x { offset.x } else { 0.0 },
+            y: bounds.y + y - if is_scrolling_y { offset.y } else { 0.0 },
+            width: self
+                .columns
+                .get(column)
+                .map(|column| f32::from(column.width))
+                .unwrap_or(0.0),
+            height: grid.row_height,
+        }
+    }
+
+    /// Returns the cell of the [`Table`] under the given point, as a row and
+    /// a column, given the bounds of the [`Table`].
+    ///
+    /// The row is `None` for the header.
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn cell_at(
+        &self,
+        bounds: Rectangle,
+        point: Point,
+    ) -> Option<(Option<usize>, usize)> {
+        if !bounds.contains(point) {
+            return None;
+        }
+
+        let grid = self.grid(bounds);
+        let offset = self.state.borrow().clamped(grid.max_offset);
+
+        let x = point.x - bounds.x;
+        let y = point.y - bounds.y;
+
+        // Frozen areas do not scroll
+        let x = if x < grid.frozen_width {
+            x
+        } else {
+            x + offset.x
+        };
+        let y = if y < grid.frozen_height {
+            y
+        } else {
+            y + offset.y
+        };
+
+        let row = match (y / grid.row_height).floor() as usize {
+            0 => None,
+            row if row <= self.rows => Some(row - 1),
+            _ => return None,
+        };
+
+        let mut right = 0.0;
+
+        let column = self.columns.iter().position(|column| {
+            right += f32::from(column.width);
+
+            x < right
+        })?;
+
+        Some((row, column))
+    }
+
+    // The column whose resize handle is under the given point, if any
+    fn resize_handle_at(
+        &self,
+        bounds: Rectangle,
+        point: Point,
+    ) -> Option<usize> {
+        if self.on_resize.is_none()
+            || !bounds.contains(point)
+            || point.y - bounds.y >= f32::from(self.row_height)
+        {
+            return None;
+        }
+
+        let frozen_edge = bounds.x + self.grid(bounds).frozen_width;
+
+        (0..self.columns.len()).rev().find(|column| {
+            let cell = self.cell_bounds(bounds, None, *column);
+            let edge = cell.x + cell.width;
+
+            // Scrolling columns may be hidden beneath the frozen ones
+            let is_visible =
+                *column < self.frozen_columns || edge > frozen_edge;
+
+            self.columns[*column].resizable
+                && is_visible
+                && (point.x - edge).abs() <= RESIZE_HANDLE_WIDTH / 2.0
+        })
+    }
+
+    // The next editable cell, going forward or backward row by row
+    fn next_editable(
+        &self,
+        (row, column): (usize, usize),
+        forward: bool,
+    ) -> Option<(usize, usize)> {
+        let columns = self.columns.len();
+        let index = row * columns + column;
+
+        let is_editable =
+            |index: &usize| self.columns[index % columns].editable;
+
+        if forward {
+            (index + 1..self.rows * columns).find(is_editable)
+        } else {
+            (0..index).rev().find(is_editable)
+        }
+        .map(|index| (index / columns, index % columns))
+    }
+
+    fn start_edit(
+        &mut self,
+        bounds: Rectangle,
+        (row, column): (usize, usize),
+        messages: &mut Vec<Message>,
+    ) {
+        let on_edit = match &self.on_edit {
+            Some(on_edit) if self.columns[column].editable => on_edit,
+            _ => return,
+        };
+
+        messages.push(on_edit(Edit::Started { row, column }));
+
+        self.scroll_into_view(bounds, (row, column));
+
+        let mut state = self.state.borrow_mut();
+
+        state.selected = Some((row, column));
+        state.editing = Some((row, column));
+    }
+
+    // Scrolls the given cell into view, unless it is frozen
+    fn scroll_into_view(
+        &mut self,
+        bounds: Rectangle,
+        (row, column): (usize, usize),
+    ) {
+        let grid = self.grid(bounds);
+        let cell = self.cell_bounds(bounds, Some(row), column);
+
+        let mut state = self.state.borrow_mut();
+        let mut offset = state.clamped(grid.max_offset);
+
+        let left = bounds.x + grid.frozen_width;
+        let top = bounds.y + grid.frozen_height;
+        let right = bounds.x + bounds.width;
+        let bottom = bounds.y + bounds.height;
+
+        if column >= self.frozen_columns {
+            if cell.x < left {
+                offset.x -= left - cell.x;
+            } else if cell.x + cell.width > right {
+                offset.x += (cell.x + cell.width - right).min(cell.x - left);
+            }
+        }
+
+        if row >= self.frozen_rows {
+            if cell.y < top {
+                offset.y -= top - cell.y;
+            } else if cell.y + cell.height > bottom {
+                offset.y += (cell.y + cell.height - bottom).min(cell.y - top);
+            }
+        }
+
+        state.scroll_to(Vector::new(
+            offset.x.min(grid.max_offset.x),
+            offset.y.min(grid.max_offset.y),
+        ));
+    }
+
+    fn end_edit(&mut self, committed: bool, messages: &mut Vec<Message>) {
+        let (row, column) = match self.state.borrow_mut().editing.take() {
+            Some(cell) => cell,
+            None => return,
+        };
+
+        if let Some(on_edit) = &self.on_edit {
+            messages.push(on_edit(if committed {
+                Edit::Committed { row, column }
+            } else {
+                Edit::Cancelled { row, column }
+            }));
+        }
+    }
+
+    fn grid(&self, bounds: Rectangle) -> Grid {
+        let row_height = f32::from(self.row_height);
+
+        let frozen_width = self.columns[..self.frozen_columns]
+            .iter()
+            .map(|column| f32::from(column.width))
+            .sum::<f32>();
+
+        let content = self.content_size();
+
+        Grid {
+            bounds,
+            selected: self.state.borrow().selected,
+            row_height,
+            frozen_width: frozen_width.min(bounds.width),
+            frozen_height: (row_height * (self.frozen_rows + 1) as f32)
+                .min(bounds.height),
+            max_offset: Vector::new(
+                (content.width - bounds.width).max(0.0),
+                (content.height - bounds.height).max(0.0),
+            ),
+        }
+    }
+
+    fn content_size(&self) -> Size {
+        let width = self
+            .columns
+            .iter()
+            .map(|column| f32::from(column.width))
+            .sum();
+
+        let height = f32::from(self.row_height) * (self.rows + 1) as f32;
+
+        Size::new(width, height)
+    }
+
+    fn cells(
+        &self,
+        grid: &Grid,
+        rows: impl Iterator<Item = Option<usize>> + Clone,
+        columns: std::ops::Range<usize>,
+        visible: Rectangle,
+    ) -> Vec<Cell> {
+        let mut cells = Vec::new();
+        let mut x = self.columns[..columns.start]
+            .iter()
+            .map(|column| f32::from(column.width))
+            .sum::<f32>();
+
+        for column in columns {
+            let width = f32::from(self.columns[column].width);
+
+            if x + width > visible.x && x < visible.x + visible.width {
+                for row in rows.clone() {
+                    let y = row.map(|row| row + 1).unwrap_or(0) as f32
+                        * grid.row_height;
+
+                    cells.push(Cell {
+                        bounds: Rectangle {
+                            x: grid.bounds.x + x,
+                            y: grid.bounds.y + y,
+                            width,
+                            height: grid.row_height,
+                        },
+                        content: match row {
+                            Some(row) => (self.cell)(row, column),
+                            None => self.columns[column].header.clone(),
+                        },
+                        row,
+                        column,
+                        is_selected: match (row, grid.selected) {
+                            (Some(row), Some(selected)) => {
+                                selected.0 == row
+                                    && (self.selects_rows
+                                        || selected.1 == column)
+                            }
+                            _ => false,
+                        },
+                        sort: match (row, self.sorted) {
+                            (None, Some((sorted, order)))
+                                if sorted == column =>
+                            {
+                                Some(order)
+                            }
+                            _ => None,
+                        },
+                    });
+                }
+            }
+
+            x += width;
+
+            if x >= visible.x + visible.width {
+                break;
+            }
+        }
+
+        cells
+    }
+}
+
+/// A column of a [`Table`].
+///
+/// [`Table`]: struct.Table.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Column {
+    /// The label shown in the header of the column.
+    pub header: String,
+
+    /// The width of the column, in pixels.
+    pub width: u16,
+
+    /// Whether the cells of the column can be edited.
+    pub editable: bool,
+
+    /// Whether the rows can be sorted by the column.
+    pub sortable: bool,
+
+    /// Whether the column can be resized by dragging its header.
+    pub resizable: bool,
+}
+
+impl Column {
+    /// Creates a new editable, sortable, and resizable [`Column`] with the
+    /// given header and width.
+    ///
+    /// [`Column`]: struct.Column.html
+    pub fn new<T: Into<String>>(header: T, width: u16) -> Self {
+        Column {
+            header: header.into(),
+            width,
+            editable: true,
+            sortable: true,
+            resizable: true,
+        }
+    }
+
+    /// Sets whether the cells of the [`Column`] can be edited.
+    ///
+    /// [`Column`]: struct.Column.html
+    pub fn editable(mut self, editable: bool) -> Self {
+        self.editable = editable;
+        self
+    }
+
+    /// Sets whether the rows can be sorted by the [`Column`].
+    ///
+    /// [`Column`]: struct.Column.html
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+
+    /// Sets whether the [`Column`] can be resized by dragging its header.
+    ///
+    /// [`Column`]: struct.Column.html
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+}
+
+/// The order of the rows of a sorted [`Table`].
+///
+/// [`Table`]: struct.Table.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Order {
+    /// From the smallest value to the largest.
+    Ascending,
+
+    /// From the largest value to the smallest.
+    Descending,
+}
+
+/// A change in the edit of a cell of a [`Table`].
+///
+/// [`Table`]: struct.Table.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Edit {
+    /// The editor of a cell was opened.
+    Started {
+        /// The row of the cell
+        row: usize,
+        /// The column of the cell
+        column: usize,
+    },
+
+    /// The value of the editor should be saved to the cell.
+    Committed {
+        /// The row of the cell
+        row: usize,
+        /// The column of the cell
+        column: usize,
+    },
+
+    /// The editor was closed without saving its value.
+    Cancelled {
+        /// The row of the cell
+        row: usize,
+        /// The column of the cell
+        column: usize,
+    },
+}
+
+/// The local state of a [`Table`].
+///
+/// The same scroll position is shared by the scrolling cells and by the
+/// frozen rows and columns that follow them.
+///
+/// [`Table`]: struct.Table.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct State {
+    offset: Vector,
+    selected: Option<(usize, usize)>,
+    editing: Option<(usize, usize)>,
+    last_click: Option<(Instant, (usize, usize))>,
+    resizing: Option<Resize>,
+}
+
+impl State {
+    /// Creates a new [`State`], scrolled to the top left corner.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns the amount of pixels the [`Table`] is scrolled in each axis.
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn offset(&self) -> Vector {
+        self.offset
+    }
+
+    /// Scrolls the [`Table`] to the given offset, in pixels.
+    ///
+    /// The offset is limited to the size of the contents the next time the
+    /// [`Table`] is drawn.
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn scroll_to(&mut self, offset: Vector) {
+        self.offset = Vector::new(offset.x.max(0.0), offset.y.max(0.0));
+    }
+
+    /// Returns the row and the column of the selected cell, if any.
+    pub fn selected(&self) -> Option<(usize, usize)> {
+        self.selected
+    }
+
+    /// Returns the row and the column of the cell being edited, if any.
+    pub fn editing(&self) -> Option<(usize, usize)> {
+        self.editing
+    }
+
+    /// Returns the column being resized, if any.
+    pub fn resizing(&self) -> Option<usize> {
+        self.resizing.map(|resize| resize.column)
+    }
+
+    fn clamped(&self, max_offset: Vector) -> Vector {
+        Vector::new(
+            self.offset.x.min(max_offset.x),
+            self.offset.y.min(max_offset.y),
+        )
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State {
+            offset: Vector::new(0.0, 0.0),
+            selected: None,
+            editing: None,
+            last_click: None,
+            resizing: None,
+        }
+    }
+}
+
+// A column being resized, with the cursor position and the width of the
+// column when the drag started
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Resize {
+    column: usize,
+    origin: f32,
+    width: u16,
+}
+
+// The areas a table is split in, given its bounds.
+#[derive(Debug, Clone, Copy)]
+struct Grid {
+    bounds: Rectangle,
+    selected: Option<(usize, usize)>,
+    row_height: f32,
+    frozen_width: f32,
+    frozen_height: f32,
+    max_offset: Vector,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Table<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = limits
+            .width(self.width)
+            .height(self.height)
+            .resolve(self.content_size());
+
+        let editing = self.state.borrow().editing;
+
+        let children = match (editing, &self.editor) {
+            (Some((row, column)), Some(editor)) => {
+                let cell = self.cell_bounds(
+                    Rectangle {
+                        x: 0.0,
+                        y: 0.0,
+                        width: size.width,
+                        height: size.height,
+                    },
+                    Some(row),
+                    column,
+                );
+
+                let cell_size = Size::new(cell.width, cell.height);
+
+                let mut editor = editor.layout(
+                    renderer,
+                    &layout::Limits::new(cell_size, cell_size),
+                );
+
+                editor.bounds.x = cell.x;
+                editor.bounds.y = cell.y;
+
+                vec![editor]
+            }
+            _ => Vec::new(),
+        };
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+        let editing = self.state.borrow().editing;
+
+        if let Some(cell) = editing {
+            let editor_layout = layout.children().next();
+
+            let is_over_editor = match editor_layout {
+                Some(layout) => layout.bounds().contains(cursor_position),
+                None => false,
+            };
+
+            match event {
+                Event::Keyboard(keyboard::Event::Input {
+                    state: ButtonState::Pressed,
+                    key_code,
+                    modifiers,
+                }) => match key_code {
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => {
+                        self.end_edit(true, messages);
+                        return;
+                    }
+                    keyboard::KeyCode::Escape => {
+                        self.end_edit(false, messages);
+                        return;
+                    }
+                    keyboard::KeyCode::Tab => {
+                        self.end_edit(true, messages);
+
+                        if let Some(next) =
+                            self.next_editable(cell, !modifiers.shift)
+                        {
+                            self.start_edit(bounds, next, messages);
+                        }
+
+                        return;
+                    }
+                    _ => {}
+                },
+                Event::Mouse(mouse::Event::Input {
+                    state: ButtonState::Pressed,
+                    ..
+                }) if !is_over_editor => {
+                    // Clicking anywhere else commits the edit
+                    self.end_edit(true, messages);
+                }
+                _ => {}
+            }
+
+            if let (Some(editor), Some(editor_layout)) =
+                (&mut self.editor, editor_layout)
+            {
+                if self.state.borrow().editing.is_some() {
+                    editor.widget.on_event(
+                        event.clone(),
+                        editor_layout,
+                        cursor_position,
+                        messages,
+                        renderer,
+                        clipboard,
+                    );
+                }
+            }
+        }
+
+        let resizing = self.state.borrow().resizing;
+
+        if let Some(resize) = resizing {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                    let width = (f32::from(resize.width) + cursor_position.x
+                        - resize.origin)
+                        .max(MIN_COLUMN_WIDTH)
+                        .round() as u16;
+
+                    let is_changed = match self.columns.get(resize.column) {
+                        Some(column) => column.width != width,
+                        None => false,
+                    };
+
+                    if let (Some(on_resize), true) =
+                        (&self.on_resize, is_changed)
+                    {
+                        messages.push(on_resize(resize.column, width));
+                    }
+                }
+                Event::Mouse(mouse::Event::Input {
+                    button: mouse::Button::Left,
+                    state: ButtonState::Released,
+                }) => {
+                    self.state.borrow_mut().resizing = None;
+                }
+                _ => {}
+            }
+
+            return;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { pixels, .. })
+                if bounds.contains(cursor_position) =>
+            {
+                let Vector { x, y } = pixels;
+
+                let grid = self.grid(bounds);
+                let mut state = self.state.borrow_mut();
+                let offset = state.clamped(grid.max_offset);
+
+                state.scroll_to(Vector::new(
+                    (offset.x - x).min(grid.max_offset.x),
+                    (offset.y - y).min(grid.max_offset.y),
+                ));
+            }
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) if self.state.borrow().editing.is_none() => {
+                if let Some(column) =
+                    self.resize_handle_at(bounds, cursor_position)
+                {
+                    self.state.borrow_mut().resizing = Some(Resize {
+                        column,
+                        origin: cursor_position.x,
+                        width: self.columns[column].width,
+                    });
+
+                    return;
+                }
+
+                if let Some((None, column)) =
+                    self.cell_at(bounds, cursor_position)
+                {
+                    if let (Some(on_sort), true) =
+                        (&self.on_sort, self.columns[column].sortable)
+                    {
+                        let order = match self.sorted {
+                            Some((sorted, Order::Ascending))
+                                if sorted == column =>
+                            {
+                                Order::Descending
+                            }
+                            _ => Order::Ascending,
+                        };
+
+                        messages.push(on_sort(column, order));
+                    }
+
+                    return;
+                }
+
+                let clicked = match self.cell_at(bounds, cursor_position) {
+                    Some((Some(row), column)) => Some((row, column)),
+                    _ => None,
+                };
+
+                let now = Instant::now();
+
+                let is_double_click = {
+                    let mut state = self.state.borrow_mut();
+
+                    let is_double_click = match (state.last_click, clicked) {
+                        (Some((at, last)), Some(cell)) => {
+                            last == cell
+                                && now.duration_since(at)
+                                    < DOUBLE_CLICK_INTERVAL
+                        }
+                        _ => false,
+                    };
+
+                    state.selected = clicked;
+                    state.last_click = if is_double_click {
+                        None
+                    } else {
+                        clicked.map(|cell| (now, cell))
+                    };
+
+                    is_double_click
+                };
+
+                if let (Some(on_select), Some((row, _))) =
+                    (&self.on_select, clicked)
+                {
+                    messages.push(on_select(row));
+                }
+
+                if let (true, Some(cell)) = (is_double_click, clicked) {
+                    self.start_edit(bounds, cell, messages);
+                }
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                state: ButtonState::Pressed,
+                key_code: keyboard::KeyCode::F2,
+                ..
+            }) => {
+                let state = *self.state.borrow();
+
+                if let (Some(cell), None) = (state.selected, state.editing) {
+                    self.start_edit(bounds, cell, messages);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let grid = self.grid(bounds);
+        let offset = self.state.borrow().clamped(grid.max_offset);
+
+        let scrolling_width = bounds.width - grid.frozen_width;
+        let scrolling_height = bounds.height - grid.frozen_height;
+
+        // Rows of the scrolling area
+        let first_row = ((grid.frozen_height + offset.y) / grid.row_height)
+            .floor() as usize;
+        let last_row =
+            ((bounds.height + offset.y) / grid.row_height).ceil() as usize;
+
+        let rows = (first_row.max(self.frozen_rows + 1)..last_row)
+            .map(|row| row - 1)
+            .take_while(|row| *row < self.rows)
+            .map(Some);
+        let frozen_rows =
+            std::iter::once(None).chain((0..self.frozen_rows).map(Some));
+
+        let columns = self.frozen_columns..self.columns.len();
+        let frozen_columns = 0..self.frozen_columns;
+
+        let area = |x, y, width: f32, height: f32| Rectangle {
+            x,
+            y,
+            width: width.max(0.0),
+            height: height.max(0.0),
+        };
+
+        // The visible parts of the contents, before scrolling
+        let body = area(
+            grid.frozen_width + offset.x,
+            grid.frozen_height + offset.y,
+            scrolling_width,
+            scrolling_height,
+        );
+        let pinned = area(0.0, 0.0, grid.frozen_width, grid.frozen_height);
+
+        // The same parts, on the screen
+        let right = bounds.x + grid.frozen_width;
+        let bottom = bounds.y + grid.frozen_height;
+
+        let regions = [
+            Region {
+                bounds: area(right, bottom, scrolling_width, scrolling_height),
+                offset: round(offset),
+                cells: self.cells(&grid, rows.clone(), columns.clone(), body),
+            },
+            Region {
+                bounds: area(
+                    bounds.x,
+                    bottom,
+                    grid.frozen_width,
+                    scrolling_height,
+                ),
+                offset: round(Vector::new(0.0, offset.y)),
+                cells: self.cells(&grid, rows, frozen_columns.clone(), pinned),
+            },
+            Region {
+                bounds: area(
+                    right,
+                    bounds.y,
+                    scrolling_width,
+                    grid.frozen_height,
+                ),
+                offset: round(Vector::new(offset.x, 0.0)),
+                cells: self.cells(&grid, frozen_rows.clone(), columns, body),
+            },
+            Region {
+                bounds: area(
+                    bounds.x,
+                    bounds.y,
+                    grid.frozen_width,
+                    grid.frozen_height,
+                ),
+                offset: Vector::new(0, 0),
+                cells: self.cells(&grid, frozen_rows, frozen_columns, pinned),
+            },
+        ];
+
+        let editor = match (&self.editor, layout.children().next()) {
+            (Some(editor), Some(layout)) => {
+                Some(editor.draw(renderer, layout, cursor_position))
+            }
+            _ => None,
+        };
+
+        let is_resizing = self.state.borrow().resizing.is_some()
+            || (self.state.borrow().editing.is_none()
+                && self.resize_handle_at(bounds, cursor_position).is_some());
+
+        self::Renderer::draw(
+            renderer,
+            bounds,
+            cursor_position,
+            &regions,
+            is_resizing,
+            editor,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Table<'static, (), ()>>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.row_height.hash(state);
+        self.rows.hash(state);
+
+        for column in &self.columns {
+            column.width.hash(state);
+        }
+
+        // The editor follows its cell when the table scrolls
+        if let Some(editor) = &self.editor {
+            let table = self.state.borrow();
+
+            if table.editing.is_some() {
+                table.editing.hash(state);
+                table.offset.x.to_bits().hash(state);
+                table.offset.y.to_bits().hash(state);
+
+                editor.hash_layout(state);
+            }
+        }
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.state.diff(tree);
+
+        let editing = self.state.borrow().editing;
+
+        match (&mut self.editor, editing) {
+            (Some(editor), Some((row, column))) => {
+                // Every cell gets a fresh editor
+                let key = (row * self.columns.len() + column) as u64;
+
+                editor.diff(&mut tree.diff_children(&[Some(key)])[0]);
+            }
+            _ => {
+                let _ = tree.diff_children(&[]);
+            }
+        }
+    }
+
+    #[cfg(feature = "devtools")]
+    fn inspect(&self, layout: Layout<'_>) -> crate::inspect::Node {
+        let mut node = crate::inspect::Node::new(
+            std::any::type_name::<Self>(),
+            layout.bounds(),
+        );
+
+        if let (Some(editor), Some((row, column))) =
+            (&self.editor, self.state.borrow().editing)
+        {
+            if let Some(layout) = layout.children().next() {
+                node.children.push(crate::inspect::Node {
+                    key: Some((row * self.columns.len() + column) as u64),
+                    ..editor.inspect(layout)
+                });
+            }
+        }
+
+        node
+    }
+
+    fn hover(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        path: &mut Vec<usize>,
+    ) {
+        if let Some(editor) = &self.editor {
+            super::hover_children(
+                std::slice::from_ref(editor),
+                layout,
+                cursor_position,
+                path,
+            );
+        }
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        if let Some(editor) = &self.editor {
+            let start = focusables.len();
+
+            super::focusables_children(
+                std::slice::from_ref(editor),
+                path,
+                focusables,
+            );
+
+            // The tab key moves the editor to the next cell instead
+            for focusable in &mut focusables[start..] {
+                focusable.uses_tab = true;
+            }
+        }
+    }
+
+    fn captures_mouse(&self) -> bool {
+        if self.state.borrow().resizing.is_some() {
+            return true;
+        }
+
+        match &self.editor {
+            Some(editor) => editor.captures_mouse(),
+            None => false,
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        match (&mut self.editor, layout.children().next()) {
+            (Some(editor), Some(layout)) => editor.overlay(layout),
+            _ => None,
+        }
+    }
+}
+
+fn round(offset: Vector) -> Vector<u32> {
+    Vector::new(offset.x.round() as u32, offset.y.round() as u32)
+}
+
+/// A visible cell of a [`Table`].
+///
+/// [`Table`]: struct.Table.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cell {
+    /// The bounds of the cell before scrolling.
+    pub bounds: Rectangle,
+
+    /// The contents of the cell.
+    pub content: String,
+
+    /// The row of the cell, or `None` if it is part of the header.
+    pub row: Option<usize>,
+
+    /// The column of the cell.
+    pub column: usize,
+
+    /// Whether the cell is selected.
+    pub is_selected: bool,
+
+    /// The [`Order`] of the rows, if the cell is the header of the column
+    /// they are sorted by.
+    ///
+    /// [`Order`]: enum.Order.html
+    pub sort: Option<Order>,
+}
+
+/// An area of a [`Table`] whose cells scroll together.
+///
+/// [`Table`]: struct.Table.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Region {
+    /// The bounds of the area on the screen.
+    pub bounds: Rectangle,
+
+    /// The scroll offset of the cells of the area.
+    pub offset: Vector<u32>,
+
+    /// The visible cells of the area.
+    pub cells: Vec<Cell>,
+}
+
+/// The renderer of a [`Table`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Table`] in your user interface.
+///
+/// [`Table`]: struct.Table.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// Draws a [`Table`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Table`]
+    ///   * the current cursor position
+    ///   * the areas of the [`Table`], in drawing order: the scrolling
+    ///     cells, the frozen columns, the header and frozen rows, and the
+    ///     corner where they meet
+    ///   * whether a column is being resized, or the cursor is over the edge
+    ///     of a header that can be dragged to resize it
+    ///   * the drawn editor of the cell being edited, if any
+    ///
+    /// The cells of each [`Region`] should be clipped to its bounds and
+    /// translated by its offset. The editor should be drawn on top of them.
+    ///
+    /// [`Table`]: struct.Table.html
+    /// [`Region`]: struct.Region.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        regions: &[Region],
+        is_resizing: bool,
+        editor: Option<Self::Output>,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Table<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        table: Table<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(table)
+    }
+}
@@ -0,0 +1,914 @@
+//! Switch between pages of content with a strip of tabs.
+//!
+//! A [`Tabs`] widget has some local [`State`].
+//!
+//! [`Tabs`]: struct.Tabs.html
+//! [`State`]: struct.State.html
+use crate::{
+    event::Focus,
+    image,
+    input::{keyboard, mouse, ButtonState},
+    layout, overlay,
+    tree::{self, Tree},
+    Clipboard, Color, Element, Event, Focusable, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+// The side of the square close button of a tab
+const CLOSE_SIZE: f32 = 16.0;
+
+// The space between the close button and the right edge of its tab
+const CLOSE_MARGIN: f32 = 8.0;
+
+// How far the mouse has to move before a pressed tab starts being dragged
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// A strip of tabs on top of the content of the active one.
+///
+/// Clicking a tab selects it, and clicking its close button, or clicking it
+/// with the middle button, closes it. Tabs can be dragged along the strip to
+/// reorder them. When they do not fit, the strip scrolls horizontally with
+/// the mouse wheel.
+///
+/// Once the strip has the keyboard focus, the left and right arrows select
+/// the previous and next tabs, and `Home` and `End` select the first and
+/// last ones.
+///
+/// Your application owns the tabs and knows which one is active. The
+/// [`Tabs`] only produce messages asking to change them.
+///
+/// # Example
+/// ```
+/// # use iced_native::{tabs, Text};
+/// #
+/// # type Tabs<'a, Message> =
+/// #     iced_native::Tabs<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone, Copy)]
+/// enum Message {
+///     Selected(usize),
+///     Closed(usize),
+///     Moved(usize, usize),
+/// }
+///
+/// let documents = vec!["notes.txt", "todo.md"];
+///
+/// let tabs: Tabs<'_, Message> = Tabs::managed(
+///     documents.iter().map(|name| tabs::Tab::new(*name)).collect(),
+///     0,
+///     Text::new("The contents of notes.txt"),
+///     Message::Selected,
+/// )
+/// .on_close(Message::Closed)
+/// .on_reorder(Message::Moved);
+/// ```
+///
+/// [`Tabs`]: struct.Tabs.html
+#[allow(missing_debug_implementations)]
+pub struct Tabs<'a, Message, Renderer> {
+    state: tree::State<'a, State>,
+    tabs: Vec<Tab>,
+    active: usize,
+    content: Element<'a, Message, Renderer>,
+    on_select: Box<dyn Fn(usize) -> Message + 'a>,
+    on_close: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    tab_width: u16,
+    tab_height: u16,
+    style: Style,
+}
+
+impl<'a, Message, Renderer> Tabs<'a, Message, Renderer> {
+    /// Creates a new [`Tabs`] with some local [`State`], the tabs, the index
+    /// of the active one, its content, and the message to produce when a tab
+    /// is selected.
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    /// [`State`]: struct.State.html
+    pub fn new<E, F>(
+        state: &'a mut State,
+        tabs: Vec<Tab>,
+        active: usize,
+        content: E,
+        on_select: F,
+    ) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+        F: 'a + Fn(usize) -> Message,
+    {
+        Self::with_state(
+            tree::State::Borrowed(state),
+            tabs,
+            active,
+            content,
+            on_select,
+        )
+    }
+
+    /// Creates a new [`Tabs`] whose local [`State`] is managed by the runtime.
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    /// [`State`]: struct.State.html
+    pub fn managed<E, F>(
+        tabs: Vec<Tab>,
+        active: usize,
+        content: E,
+        on_select: F,
+    ) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+        F: 'a + Fn(usize) -> Message,
+    {
+        Self::with_state(
+            tree::State::managed(),
+            tabs,
+            active,
+            content,
+            on_select,
+        )
+    }
+
+    /// Creates a new [`Tabs`] whose local [`State`] is managed by the runtime
+    /// and shared with the given [`Link`].
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    /// [`State`]: struct.State.html
+    /// [`Link`]: ../../tree/struct.Link.html
+    pub fn linked<E, F>(
+        link: &tree::Link<State>,
+        tabs: Vec<Tab>,
+        active: usize,
+        content: E,
+        on_select: F,
+    ) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+        F: 'a + Fn(usize) -> Message,
+    {
+        Self::with_state(link.into(), tabs, active, content, on_select)
+    }
+
+    fn with_state<E, F>(
+        state: tree::State<'a, State>,
+        tabs: Vec<Tab>,
+        active: usize,
+        content: E,
+        on_select: F,
+    ) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+        F: 'a + Fn(usize) -> Message,
+    {
+        Tabs {
+            state,
+            tabs,
+            active,
+            content: content.into(),
+            on_select: Box::new(on_select),
+            on_close: None,
+            on_reorder: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            tab_width: 160,
+            tab_height: 32,
+            style: Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`Tabs`].
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Tabs`].
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the width of every tab, in pixels.
+    pub fn tab_width(mut self, tab_width: u16) -> Self {
+        self.tab_width = tab_width;
+        self
+    }
+
+    /// Sets the height of the strip of tabs, in pixels.
+    pub fn tab_height(mut self, tab_height: u16) -> Self {
+        self.tab_height = tab_height;
+        self
+    }
+
+    /// Sets the message to produce when a [`Tab`] is closed.
+    ///
+    /// Without it, no [`Tab`] shows a close button.
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub fn on_close<F>(mut self, on_close: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        self.on_close = Some(Box::new(on_close));
+        self
+    }
+
+    /// Sets the message to produce when a [`Tab`] is dragged to a new place.
+    ///
+    /// The closure receives the index the [`Tab`] is moved from and the index
+    /// it is moved to. Without it, tabs cannot be dragged.
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub fn on_reorder<F>(mut self, on_reorder: F) -> Self
+    where
+        F: 'a + Fn(usize, usize) -> Message,
+    {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Sets the [`Style`] of the [`Tabs`].
+    ///
+    /// [`Style`]: struct.Style.html
+    /// [`Tabs`]: struct.Tabs.html
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn strip(&self, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            height: f32::from(self.tab_height).min(bounds.height),
+            ..bounds
+        }
+    }
+
+    fn max_offset(&self, strip: Rectangle) -> f32 {
+        let width = self.tabs.len() as f32 * f32::from(self.tab_width);
+
+        (width - strip.width).max(0.0)
+    }
+
+    fn tab_bounds(
+        &self,
+        strip: Rectangle,
+        slot: usize,
+        offset: f32,
+    ) -> Rectangle {
+        let width = f32::from(self.tab_width);
+
+        Rectangle {
+            x: strip.x + slot as f32 * width - offset,
+            width,
+            ..strip
+        }
+    }
+
+    fn close_bounds(&self, index: usize, tab: Rectangle) -> Option<Rectangle> {
+        if self.on_close.is_none() || !self.tabs[index].closable {
+            return None;
+        }
+
+        Some(Rectangle {
+            x: tab.x + tab.width - CLOSE_MARGIN - CLOSE_SIZE,
+            y: tab.y + (tab.height - CLOSE_SIZE) / 2.0,
+            width: CLOSE_SIZE,
+            height: CLOSE_SIZE,
+        })
+    }
+
+    /// Returns the index of the tab under the given point, if any.
+    fn tab_at(
+        &self,
+        strip: Rectangle,
+        offset: f32,
+        point: Point,
+    ) -> Option<usize> {
+        if !strip.contains(point) {
+            return None;
+        }
+
+        let index = ((point.x - strip.x + offset) / f32::from(self.tab_width))
+            .floor() as usize;
+
+        if index < self.tabs.len() {
+            Some(index)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the left edge of a dragged tab, kept inside the strip.
+    fn dragged_x(
+        &self,
+        strip: Rectangle,
+        offset: f32,
+        drag: Drag,
+        x: f32,
+    ) -> f32 {
+        let first = strip.x - offset;
+        let last = first
+            + self.tabs.len().saturating_sub(1) as f32
+                * f32::from(self.tab_width);
+
+        (x - drag.grab).max(first).min(last)
+    }
+
+    /// Returns the index a dragged tab would be dropped at.
+    fn drop_index(
+        &self,
+        strip: Rectangle,
+        offset: f32,
+        drag: Drag,
+        x: f32,
+    ) -> usize {
+        let left = self.dragged_x(strip, offset, drag, x) - strip.x + offset;
+
+        ((left / f32::from(self.tab_width)).round() as usize)
+            .min(self.tabs.len().saturating_sub(1))
+    }
+
+    /// Returns the offset of the strip that keeps the active tab visible.
+    fn reveal_active(&self, strip: Rectangle, offset: f32) -> f32 {
+        let width = f32::from(self.tab_width);
+        let left = self.active as f32 * width;
+
+        let offset = if left < offset {
+            left
+        } else if left + width > offset + strip.width {
+            left + width - strip.width
+        } else {
+            offset
+        };
+
+        offset.max(0.0).min(self.max_offset(strip))
+    }
+
+    /// Returns the offset of the strip, scrolling the active tab into view
+    /// when it changed since the last event.
+    fn offset(&self, state: &State, strip: Rectangle) -> f32 {
+        if state.last_active != Some(self.active) {
+            self.reveal_active(strip, state.offset)
+        } else {
+            state.offset.min(self.max_offset(strip))
+        }
+    }
+}
+
+/// A tab of some [`Tabs`].
+///
+/// [`Tabs`]: struct.Tabs.html
+#[derive(Debug, Clone)]
+pub struct Tab {
+    /// The label of the [`Tab`]
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub label: String,
+
+    /// The icon shown before the label of the [`Tab`], if any
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub icon: Option<image::Handle>,
+
+    /// Whether the [`Tab`] can be closed
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub closable: bool,
+}
+
+impl Tab {
+    /// Creates a new closable [`Tab`] with the given label.
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub fn new<T: Into<String>>(label: T) -> Self {
+        Tab {
+            label: label.into(),
+            icon: None,
+            closable: true,
+        }
+    }
+
+    /// Sets the icon of the [`Tab`].
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub fn icon(mut self, icon: image::Handle) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Sets whether the [`Tab`] can be closed.
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+}
+
+/// The appearance of some [`Tabs`].
+///
+/// [`Tabs`]: struct.Tabs.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background of the strip of tabs
+    pub background: Color,
+
+    /// The background of the inactive tabs
+    pub tab_background: Color,
+
+    /// The background of an inactive tab under the mouse
+    pub hovered_background: Color,
+
+    /// The background of the active tab
+    pub active_background: Color,
+
+    /// The color of the labels and close buttons
+    pub text_color: Color,
+
+    /// The color of the line under the active tab while the strip has the
+    /// keyboard focus
+    pub focus_color: Color,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Style {
+            background: [0.87, 0.87, 0.87].into(),
+            tab_background: [0.93, 0.93, 0.93].into(),
+            hovered_background: [0.96, 0.96, 0.96].into(),
+            active_background: Color::WHITE,
+            text_color: [0.2, 0.2, 0.2].into(),
+            focus_color: [0.5, 0.6, 0.9].into(),
+        }
+    }
+}
+
+/// The local state of some [`Tabs`].
+///
+/// [`Tabs`]: struct.Tabs.html
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct State {
+    offset: f32,
+    last_active: Option<usize>,
+    drag: Option<Drag>,
+    is_focused: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Drag {
+    index: usize,
+    origin: f32,
+    grab: f32,
+    is_moving: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns whether the strip of tabs has the keyboard focus.
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Returns whether a tab is being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.drag.map(|drag| drag.is_moving).unwrap_or(false)
+    }
+}
+
+/// A tab, ready to be drawn in the strip of some [`Tabs`].
+///
+/// [`Tabs`]: struct.Tabs.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Header {
+    /// The index of the [`Tab`]
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub index: usize,
+
+    /// The bounds of the [`Tab`]
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub bounds: Rectangle,
+
+    /// The bounds of the close button of the [`Tab`], if it has one
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub close_bounds: Option<Rectangle>,
+
+    /// Whether the [`Tab`] is the active one
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub is_active: bool,
+
+    /// Whether the mouse is over the [`Tab`]
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub is_hovered: bool,
+
+    /// Whether the mouse is over the close button of the [`Tab`]
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub is_close_hovered: bool,
+
+    /// Whether the [`Tab`] is being dragged
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub is_dragged: bool,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Tabs<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let tab_height = f32::from(self.tab_height);
+
+        let limits = limits.width(self.width).height(self.height);
+
+        let mut content = self
+            .content
+            .layout(renderer, &limits.shrink(Size::new(0.0, tab_height)));
+        content.bounds.y = tab_height;
+
+        let size = limits.resolve(Size::new(
+            content.size().width,
+            content.size().height + tab_height,
+        ));
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let strip = self.strip(layout.bounds());
+        let offset = self.offset(&self.state.borrow(), strip);
+
+        {
+            let mut state = self.state.borrow_mut();
+            state.offset = offset;
+            state.last_active = Some(self.active);
+        }
+
+        match event {
+            Event::Focus(focus) if layout.is_focus_target() => {
+                self.state.borrow_mut().is_focused = focus == Focus::Gained;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { pixels, .. })
+                if strip.contains(cursor_position) =>
+            {
+                // Vertical wheels scroll the strip too
+                let delta = if pixels.x != 0.0 { pixels.x } else { pixels.y };
+
+                self.state.borrow_mut().offset =
+                    (offset - delta).max(0.0).min(self.max_offset(strip));
+            }
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) => {
+                self.state.borrow_mut().is_focused =
+                    strip.contains(cursor_position);
+
+                if let Some(index) = self.tab_at(strip, offset, cursor_position)
+                {
+                    let tab = self.tab_bounds(strip, index, offset);
+
+                    match (&self.on_close, self.close_bounds(index, tab)) {
+                        (Some(on_close), Some(close))
+                            if close.contains(cursor_position) =>
+                        {
+                            messages.push(on_close(index));
+                        }
+                        _ => {
+                            if index != self.active {
+                                messages.push((self.on_select)(index));
+                            }
+
+                            if self.on_reorder.is_some() {
+                                self.state.borrow_mut().drag = Some(Drag {
+                                    index,
+                                    origin: cursor_position.x,
+                                    grab: cursor_position.x - tab.x,
+                                    is_moving: false,
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Middle,
+                state: ButtonState::Pressed,
+            }) => {
+                if let Some(index) = self.tab_at(strip, offset, cursor_position)
+                {
+                    if let Some(on_close) = &self.on_close {
+                        if self.tabs[index].closable {
+                            messages.push(on_close(index));
+                        }
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let mut state = self.state.borrow_mut();
+
+                if let Some(drag) = &mut state.drag {
+                    drag.is_moving = drag.is_moving
+                        || (cursor_position.x - drag.origin).abs()
+                            > DRAG_THRESHOLD;
+                }
+            }
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Released,
+            }) => {
+                let drag = self.state.borrow_mut().drag.take();
+
+                if let (Some(drag), Some(on_reorder)) = (drag, &self.on_reorder)
+                {
+                    if drag.is_moving {
+                        let to = self.drop_index(
+                            strip,
+                            offset,
+                            drag,
+                            cursor_position.x,
+                        );
+
+                        if to != drag.index {
+                            messages.push(on_reorder(drag.index, to));
+                        }
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                state: ButtonState::Pressed,
+                key_code,
+                ..
+            }) if self.state.borrow().is_focused && !self.tabs.is_empty() => {
+                let last = self.tabs.len() - 1;
+
+                let selected = match key_code {
+                    keyboard::KeyCode::Left => self.active.saturating_sub(1),
+                    keyboard::KeyCode::Right => (self.active + 1).min(last),
+                    keyboard::KeyCode::Home => 0,
+                    keyboard::KeyCode::End => last,
+                    _ => self.active,
+                };
+
+                if selected != self.active {
+                    messages.push((self.on_select)(selected));
+                }
+            }
+            _ => {}
+        }
+
+        self.content.widget.on_event(
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let strip = self.strip(layout.bounds());
+        let state = *self.state.borrow();
+        let offset = self.offset(&state, strip);
+
+        let drag = state.drag.filter(|drag| drag.is_moving);
+        let hovered = if drag.is_some() {
+            None
+        } else {
+            self.tab_at(strip, offset, cursor_position)
+        };
+
+        // While a tab is dragged, the tabs between its old and new places
+        // move aside to make room for it
+        let target = drag.map(|drag| {
+            (
+                drag.index,
+                self.drop_index(strip, offset, drag, cursor_position.x),
+            )
+        });
+
+        let slot = |index: usize| match target {
+            Some((from, to)) if from < index && index <= to => index - 1,
+            Some((from, to)) if to <= index && index < from => index + 1,
+            _ => index,
+        };
+
+        let mut headers: Vec<Header> = (0..self.tabs.len())
+            .filter(|index| target.map(|(from, _)| from) != Some(*index))
+            .map(|index| (index, self.tab_bounds(strip, slot(index), offset)))
+            .filter(|(_, bounds)| {
+                bounds.x + bounds.width > strip.x
+                    && bounds.x < strip.x + strip.width
+            })
+            .map(|(index, bounds)| {
+                let close_bounds = self.close_bounds(index, bounds);
+
+                Header {
+                    index,
+                    bounds,
+                    close_bounds,
+                    is_active: index == self.active,
+                    is_hovered: hovered == Some(index),
+                    is_close_hovered: hovered == Some(index)
+                        && close_bounds
+                            .map(|close| close.contains(cursor_position))
+                            .unwrap_or(false),
+                    is_dragged: false,
+                }
+            })
+            .collect();
+
+        // The dragged tab goes last, so it is drawn above the others
+        if let Some(drag) = drag {
+            let bounds = Rectangle {
+                x: self.dragged_x(strip, offset, drag, cursor_position.x),
+                ..self.tab_bounds(strip, drag.index, offset)
+            };
+
+            headers.push(Header {
+                index: drag.index,
+                bounds,
+                close_bounds: self.close_bounds(drag.index, bounds),
+                is_active: drag.index == self.active,
+                is_hovered: true,
+                is_close_hovered: false,
+                is_dragged: true,
+            });
+        }
+
+        let content = self.content.draw(
+            renderer,
+            layout.children().next().unwrap(),
+            cursor_position,
+        );
+
+        self::Renderer::draw(
+            renderer,
+            strip,
+            cursor_position,
+            &self.tabs,
+            &headers,
+            state.is_focused,
+            &self.style,
+            content,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Tabs<'static, (), ()>>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.tab_height.hash(state);
+        self.content.hash_layout(state);
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.state.diff(tree);
+        self.content.diff(&mut tree.diff_children(&[None])[0]);
+    }
+
+    #[cfg(feature = "devtools")]
+    fn inspect(&self, layout: Layout<'_>) -> crate::inspect::Node {
+        super::inspect_children(
+            std::any::type_name::<Self>(),
+            std::slice::from_ref(&self.content),
+            &[None],
+            layout,
+        )
+    }
+
+    fn hover(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        path: &mut Vec<usize>,
+    ) {
+        let content = layout.children().next().unwrap();
+
+        if content.bounds().contains(cursor_position) {
+            path.push(0);
+
+            self.content.hover(content, cursor_position, path);
+        }
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        focusables.push(Focusable {
+            path: path.clone(),
+            is_focused: self.state.borrow().is_focused,
+            uses_tab: false,
+        });
+
+        path.push(0);
+        self.content.focusables(path, focusables);
+        let _ = path.pop();
+    }
+
+    fn captures_mouse(&self) -> bool {
+        self.state.borrow().drag.is_some() || self.content.captures_mouse()
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        super::overlay_children(std::slice::from_mut(&mut self.content), layout)
+    }
+}
+
+/// The renderer of some [`Tabs`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use [`Tabs`] in your user interface.
+///
+/// [`Tabs`]: struct.Tabs.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// Draws some [`Tabs`].
+    ///
+    /// It receives:
+    ///   * the bounds of the strip of tabs
+    ///   * the cursor position
+    ///   * all the tabs
+    ///   * the [`Header`] of every visible tab, in drawing order
+    ///   * whether the strip has the keyboard focus
+    ///   * the [`Style`] of the [`Tabs`]
+    ///   * the drawn content of the active tab
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    /// [`Header`]: struct.Header.html
+    /// [`Style`]: struct.Style.html
+    #[allow(clippy::too_many_arguments)]
+    fn draw(
+        &mut self,
+        strip: Rectangle,
+        cursor_position: Point,
+        tabs: &[Tab],
+        headers: &[Header],
+        is_focused: bool,
+        style: &Style,
+        content: Self::Output,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Tabs<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        tabs: Tabs<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(tabs)
+    }
+}
@@ -1,11 +1,13 @@
 //! Write some text for your users to read.
 use crate::{
     layout, Color, Element, Font, Hasher, HorizontalAlignment, Layout, Length,
-    Point, Rectangle, Size, VerticalAlignment, Widget,
+    Point, Rectangle, Shadow, Size, VerticalAlignment, Widget,
 };
 
 use std::hash::Hash;
 
+pub use iced_core::text::{Decoration, Params, Wrap};
+
 /// A paragraph of text.
 ///
 /// # Example
@@ -24,7 +26,9 @@ pub struct Text {
     content: String,
     size: Option<u16>,
     color: Option<Color>,
+    shadow: Option<Shadow>,
     font: Font,
+    params: Params,
     width: Length,
     height: Length,
     horizontal_alignment: HorizontalAlignment,
@@ -40,7 +44,9 @@ impl Text {
             content: label.into(),
             size: None,
             color: None,
+            shadow: None,
             font: Font::Default,
+            params: Params::default(),
             width: Length::Fill,
             height: Length::Shrink,
             horizontal_alignment: HorizontalAlignment::Left,
@@ -65,6 +71,17 @@ impl Text {
         self
     }
 
+    /// Sets the [`Shadow`] of the [`Text`].
+    ///
+    /// A shadow keeps text legible over busy backgrounds, like images.
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`Shadow`]: ../../struct.Shadow.html
+    pub fn shadow(mut self, shadow: Shadow) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
     /// Sets the [`Font`] of the [`Text`].
     ///
     /// [`Text`]: struct.Text.html
@@ -74,6 +91,43 @@ impl Text {
         self
     }
 
+    /// Sets the height of the lines of the [`Text`], relative to the natural
+    /// line height of its [`Font`].
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`Font`]: ../../struct.Font.html
+    pub fn line_height(mut self, line_height: f32) -> Self {
+        self.params.line_height = line_height;
+        self
+    }
+
+    /// Sets the space added after every glyph of the [`Text`], in logical
+    /// pixels.
+    ///
+    /// [`Text`]: struct.Text.html
+    pub fn letter_spacing(mut self, letter_spacing: f32) -> Self {
+        self.params.letter_spacing = letter_spacing;
+        self
+    }
+
+    /// Sets the [`Wrap`] strategy of the [`Text`].
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`Wrap`]: enum.Wrap.html
+    pub fn wrap(mut self, wrap: Wrap) -> Self {
+        self.params.wrap = wrap;
+        self
+    }
+
+    /// Sets the [`Decoration`] of the [`Text`], like an underline.
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`Decoration`]: struct.Decoration.html
+    pub fn decoration(mut self, decoration: Decoration) -> Self {
+        self.params.decoration = decoration;
+        self
+    }
+
     /// Sets the width of the [`Text`] boundaries.
     ///
     /// [`Text`]: struct.Text.html
@@ -131,16 +185,28 @@ where
     ) -> layout::Node {
         let limits = limits.width(self.width).height(self.height);
 
-        let size = self.size.unwrap_or(renderer.default_size());
+        let text_size = self.size.unwrap_or(renderer.default_size());
 
         let bounds = limits.max();
 
-        let (width, height) =
-            renderer.measure(&self.content, size, self.font, bounds);
+        let (width, height) = renderer.measure(
+            &self.content,
+            text_size,
+            self.font,
+            self.params,
+            bounds,
+        );
 
         let size = limits.resolve(Size::new(width, height));
 
+        let top = match self.vertical_alignment {
+            VerticalAlignment::Top => 0.0,
+            VerticalAlignment::Center => (size.height - height) / 2.0,
+            VerticalAlignment::Bottom => size.height - height,
+        };
+
         layout::Node::new(size)
+            .with_baseline(top + renderer.baseline(text_size, self.font))
     }
 
     fn draw(
@@ -154,7 +220,9 @@ where
             &self.content,
             self.size.unwrap_or(renderer.default_size()),
             self.font,
+            self.params,
             self.color,
+            self.shadow,
             self.horizontal_alignment,
             self.vertical_alignment,
         )
@@ -163,8 +231,10 @@ where
     fn hash_layout(&self, state: &mut Hasher) {
         self.content.hash(state);
         self.size.hash(state);
+        self.params.hash(state);
         self.width.hash(state);
         self.height.hash(state);
+        self.vertical_alignment.hash(state);
     }
 }
 
@@ -191,29 +261,45 @@ pub trait Renderer: crate::Renderer {
         content: &str,
         size: u16,
         font: Font,
+        params: Params,
         bounds: Size,
     ) -> (f32, f32);
 
+    /// Returns the distance from the top of a line of [`Text`] to its
+    /// baseline.
+    ///
+    /// [`Text`]: struct.Text.html
+    fn baseline(&self, size: u16, font: Font) -> f32;
+
     /// Draws a [`Text`] fragment.
     ///
     /// It receives:
     ///   * the bounds of the [`Text`]
     ///   * the contents of the [`Text`]
     ///   * the size of the [`Text`]
+    ///   * the [`Font`] of the [`Text`]
+    ///   * the [`Params`] of the [`Text`]
     ///   * the color of the [`Text`]
+    ///   * the [`Shadow`] of the [`Text`], if any
     ///   * the [`HorizontalAlignment`] of the [`Text`]
     ///   * the [`VerticalAlignment`] of the [`Text`]
     ///
     /// [`Text`]: struct.Text.html
+    /// [`Font`]: ../../struct.Font.html
+    /// [`Params`]: struct.Params.html
+    /// [`Shadow`]: ../../struct.Shadow.html
     /// [`HorizontalAlignment`]: enum.HorizontalAlignment.html
     /// [`VerticalAlignment`]: enum.VerticalAlignment.html
+    #[allow(clippy::too_many_arguments)]
     fn draw(
         &mut self,
         bounds: Rectangle,
         content: &str,
         size: u16,
         font: Font,
+        params: Params,
         color: Option<Color>,
+        shadow: Option<Shadow>,
         horizontal_alignment: HorizontalAlignment,
         vertical_alignment: VerticalAlignment,
     ) -> Self::Output;
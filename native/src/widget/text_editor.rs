@@ -0,0 +1,1383 @@
+//! Edit multi-line text, like the source code of a program.
+//!
+//! A [`TextEditor`] has some local [`State`]. Its lines can be styled by a
+//! [`Highlighter`], like the syntax highlighter of a programming language.
+//!
+//! [`TextEditor`]: struct.TextEditor.html
+//! [`State`]: struct.State.html
+//! [`Highlighter`]: trait.Highlighter.html
+use crate::{
+    font::Style,
+    input::{ime, keyboard, mouse, ButtonState},
+    layout, overlay, rich_text, text,
+    tree::{self, Tree},
+    widget::text_input::{self, platform, Suggestions},
+    Clipboard, Color, Element, Event, Focus, Focusable, Font, Hasher, Layout,
+    Length, Point, Rectangle, Size, Widget,
+};
+
+use std::{cell::RefCell, hash::Hash, ops::Range};
+
+/// The text inserted when pressing the tab key in a [`TextEditor`].
+///
+/// [`TextEditor`]: struct.TextEditor.html
+const INDENTATION: &str = "    ";
+
+const MISSPELLING_COLOR: Color = Color {
+    r: 0.9,
+    g: 0.2,
+    b: 0.2,
+    a: 1.0,
+};
+
+/// A field to edit multi-line text.
+///
+/// The [`TextEditor`] grows vertically to fit all of its lines. Put it
+/// inside of a [`Scrollable`] to edit long texts.
+///
+/// # Example
+/// ```
+/// # use iced_native::{text_editor, TextEditor};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     CodeChanged(String),
+/// }
+///
+/// let mut state = text_editor::State::new();
+/// let code = "fn main() {\n    println!(\"Hello, world!\");\n}";
+///
+/// let editor =
+///     TextEditor::new(&mut state, code, Message::CodeChanged).padding(10);
+/// ```
+///
+/// [`TextEditor`]: struct.TextEditor.html
+/// [`Scrollable`]: ../scrollable/struct.Scrollable.html
+#[allow(missing_debug_implementations)]
+pub struct TextEditor<'a, Message> {
+    state: tree::State<'a, State>,
+    lines: Vec<String>,
+    width: Length,
+    padding: u16,
+    size: Option<u16>,
+    font: Font,
+    highlighter: Option<&'a dyn Highlighter>,
+    on_change: Box<dyn Fn(String) -> Message + 'a>,
+    misspellings: Vec<Misspelling>,
+}
+
+impl<'a, Message> TextEditor<'a, Message> {
+    /// Creates a new [`TextEditor`].
+    ///
+    /// It expects:
+    /// - some [`State`]
+    /// - the current text
+    /// - a function that produces a message when the text changes
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(state: &'a mut State, text: &str, on_change: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        Self::with_state(tree::State::Borrowed(state), text, on_change)
+    }
+
+    /// Creates a new [`TextEditor`] whose local [`State`] is managed by the
+    /// runtime.
+    ///
+    /// It expects the same arguments as [`new`], except for the [`State`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    /// [`State`]: struct.State.html
+    /// [`new`]: #method.new
+    pub fn managed<F>(text: &str, on_change: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        Self::with_state(tree::State::managed(), text, on_change)
+    }
+
+    fn with_state<F>(
+        state: tree::State<'a, State>,
+        text: &str,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        TextEditor {
+            state,
+            lines: text.split('\n').map(String::from).collect(),
+            width: Length::Fill,
+            padding: 0,
+            size: None,
+            font: Font::Default,
+            highlighter: None,
+            on_change: Box::new(on_change),
+            misspellings: Vec::new(),
+        }
+    }
+
+    /// Sets the width of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the padding of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn padding(mut self, units: u16) -> Self {
+        self.padding = units;
+        self
+    }
+
+    /// Sets the text size of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`TextEditor`], normally a monospaced one
+    /// for code.
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    /// [`Font`]: ../../struct.Font.html
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the [`Highlighter`] that styles the lines of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    /// [`Highlighter`]: trait.Highlighter.html
+    pub fn highlighter(mut self, highlighter: &'a dyn Highlighter) -> Self {
+        self.highlighter = Some(highlighter);
+        self
+    }
+
+    /// Sets the [`Misspelling`]s of the current text of the [`TextEditor`],
+    /// usually produced by an external spell checker.
+    ///
+    /// Misspelled words are underlined, and right-clicking them opens a menu
+    /// with their suggestions. Choosing a suggestion replaces the word and
+    /// produces a change message.
+    ///
+    /// [`Misspelling`]: struct.Misspelling.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn misspellings(mut self, misspellings: Vec<Misspelling>) -> Self {
+        self.misspellings = misspellings;
+        self
+    }
+}
+
+/// A styler of the lines of a [`TextEditor`], like a syntax highlighter.
+///
+/// Lines are highlighted one at a time, from top to bottom. Anything the
+/// highlighting of a line depends on from the lines above it, like being
+/// inside of a block comment, is carried to it in a context number.
+///
+/// The [`TextEditor`] remembers the highlights of every line and only
+/// highlights a line again when its content or its context changes. Call
+/// [`State::rehighlight`] when the highlighter itself changes, like when
+/// switching the language or the theme.
+///
+/// [`TextEditor`]: struct.TextEditor.html
+/// [`State::rehighlight`]: struct.State.html#method.rehighlight
+pub trait Highlighter {
+    /// Returns the [`Highlight`]s of a line.
+    ///
+    /// The `context` starts with the context at the end of the previous
+    /// line, or `0` for the first one, and must be left with the context at
+    /// the end of this one.
+    ///
+    /// Overlapping highlights and highlights outside of the line, or not
+    /// aligned to its characters, are ignored.
+    ///
+    /// [`Highlight`]: struct.Highlight.html
+    fn highlight_line(&self, line: &str, context: &mut u64) -> Vec<Highlight>;
+}
+
+/// The style of a range of a line in a [`TextEditor`].
+///
+/// [`TextEditor`]: struct.TextEditor.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Highlight {
+    /// The byte range of the line that is styled
+    pub range: Range<usize>,
+
+    /// The [`Format`] of the range
+    ///
+    /// [`Format`]: struct.Format.html
+    pub format: Format,
+}
+
+/// The format of some highlighted text.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Format {
+    /// The color of the text, or the default color of the renderer
+    pub color: Option<Color>,
+
+    /// Whether the text is italic
+    ///
+    /// Only a [`Font::System`] has italic faces, so it is ignored for other
+    /// fonts.
+    ///
+    /// [`Font::System`]: ../../enum.Font.html#variant.System
+    pub italic: bool,
+
+    /// The color of the background of the text, if any
+    pub background: Option<Color>,
+}
+
+/// A position in the text of a [`TextEditor`].
+///
+/// [`TextEditor`]: struct.TextEditor.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Position {
+    /// The index of the line
+    pub line: usize,
+
+    /// The byte offset in the line
+    pub column: usize,
+}
+
+/// A misspelled range of a line of a [`TextEditor`].
+///
+/// [`TextEditor`]: struct.TextEditor.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Misspelling {
+    /// The index of the line
+    pub line: usize,
+
+    /// The misspelled byte range of the line
+    pub range: Range<usize>,
+
+    /// The suggested replacements for the misspelled range
+    pub suggestions: Vec<String>,
+}
+
+impl Misspelling {
+    /// Creates a new [`Misspelling`] of the given byte range of a line, with
+    /// some suggestions.
+    ///
+    /// [`Misspelling`]: struct.Misspelling.html
+    pub fn new(
+        line: usize,
+        range: Range<usize>,
+        suggestions: Vec<String>,
+    ) -> Self {
+        Misspelling {
+            line,
+            range,
+            suggestions,
+        }
+    }
+}
+
+/// A line of a [`TextEditor`], laid out and highlighted.
+///
+/// [`TextEditor`]: struct.TextEditor.html
+#[derive(Debug, Clone)]
+pub struct Line {
+    /// The bounds of the line
+    pub bounds: Rectangle,
+
+    /// The spans of text of the line, styled by its highlights
+    pub spans: Vec<rich_text::Span>,
+
+    /// The backgrounds of the highlights of the line, with their bounds
+    pub backgrounds: Vec<(Rectangle, Color)>,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for TextEditor<'a, Message>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let padding = self.padding as f32;
+        let size = self.size.unwrap_or(text::Renderer::default_size(renderer));
+        let line_height = renderer.line_height(size, self.font);
+
+        let limits = limits.pad(padding).width(self.width);
+
+        let mut text = layout::Node::new(
+            limits
+                .resolve(Size::new(0.0, line_height * self.lines.len() as f32)),
+        );
+        text.bounds.x = padding;
+        text.bounds.y = padding;
+
+        layout::Node::with_children(text.size().pad(padding), vec![text])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let TextEditor {
+            state,
+            lines,
+            size,
+            font,
+            highlighter,
+            on_change,
+            misspellings,
+            ..
+        } = self;
+
+        let mut state = state.borrow_mut();
+        let size = size.unwrap_or(text::Renderer::default_size(renderer));
+        let text_bounds = layout.children().next().unwrap().bounds();
+
+        // The text may have changed since the last event
+        state.cursor = clamp(lines, state.cursor);
+        state.anchor = clamp(lines, state.anchor);
+
+        let position_at = |state: &State, lines: &[String], point| {
+            position_at(
+                renderer,
+                lines,
+                &highlights(state, lines, *highlighter),
+                size,
+                *font,
+                text_bounds,
+                point,
+            )
+        };
+
+        let mut is_changed = false;
+
+        match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Right,
+                state: ButtonState::Pressed,
+            }) if layout.bounds().contains(cursor_position) => {
+                let position = position_at(&state, lines, cursor_position);
+
+                let misspelling = misspellings.iter().find(|misspelling| {
+                    misspelling.line == position.line
+                        && misspelling.range.start <= position.column
+                        && position.column <= misspelling.range.end
+                        && lines[position.line]
+                            .get(misspelling.range.clone())
+                            .is_some()
+                });
+
+                // The menu opens below the start of the misspelled word
+                state.menu = misspelling.map(|misspelling| {
+                    let highlights = highlights(&state, lines, *highlighter);
+                    let (spans, _) = spans(
+                        &lines[misspelling.line],
+                        &highlights[misspelling.line],
+                        size,
+                        *font,
+                    );
+
+                    let bounds = layout.bounds();
+                    let line_height = renderer.line_height(size, *font);
+
+                    // The start of the word, relative to the editor
+                    let start = Rectangle {
+                        x: text_bounds.x - bounds.x
+                            + renderer.caret_position(
+                                &spans,
+                                misspelling.range.start,
+                            ),
+                        y: text_bounds.y - bounds.y
+                            + misspelling.line as f32 * line_height,
+                        width: 0.0,
+                        height: line_height,
+                    };
+
+                    (misspelling.line, misspelling.range.clone(), start)
+                });
+            }
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) => {
+                if layout.bounds().contains(cursor_position) {
+                    let position = position_at(&state, lines, cursor_position);
+                    let is_extended =
+                        state.is_focused && state.is_shift_pressed;
+
+                    state.move_to(position, is_extended);
+                    state.is_focused = true;
+                    state.is_dragging = true;
+                } else {
+                    state.is_focused = false;
+                    state.preedit = None;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { x, y })
+                if state.is_dragging =>
+            {
+                let position = position_at(&state, lines, Point::new(x, y));
+
+                state.move_to(position, true);
+            }
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Released,
+            }) => {
+                state.is_dragging = false;
+            }
+            Event::Focus(focus) if layout.is_focus_target() => {
+                state.is_focused = focus == Focus::Gained;
+                state.is_dragging = false;
+                state.preedit = None;
+                state.menu = None;
+            }
+            Event::Ime(ime::Event::Preedit(content)) if state.is_focused => {
+                // A composition replaces the selection, like typing does
+                if state.preedit.is_none()
+                    && !content.is_empty()
+                    && state.selection().is_some()
+                {
+                    replace(&mut state, lines, "");
+
+                    is_changed = true;
+                }
+
+                state.preedit = if content.is_empty() {
+                    None
+                } else {
+                    Some(content)
+                };
+            }
+            Event::Ime(ime::Event::Commit(content)) if state.is_focused => {
+                state.preedit = None;
+
+                replace(&mut state, lines, &content.replace("\r\n", "\n"));
+
+                is_changed = true;
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                if state.is_focused && !c.is_control() =>
+            {
+                replace(&mut state, lines, c.encode_utf8(&mut [0; 4]));
+
+                is_changed = true;
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code,
+                state: button_state,
+                modifiers,
+            }) => {
+                state.is_shift_pressed = modifiers.shift;
+
+                if !state.is_focused || button_state != ButtonState::Pressed {
+                    return;
+                }
+
+                let is_command =
+                    platform::is_copy_paste_modifier_pressed(modifiers);
+                let cursor = state.cursor;
+
+                match key_code {
+                    keyboard::KeyCode::Enter => {
+                        // New lines keep the indentation of the current one
+                        let line = &lines[cursor.line][..cursor.column];
+                        let indentation: String = line
+                            .chars()
+                            .take_while(|c| *c == ' ' || *c == '\t')
+                            .collect();
+
+                        replace(
+                            &mut state,
+                            lines,
+                            &format!("\n{}", indentation),
+                        );
+
+                        is_changed = true;
+                    }
+                    keyboard::KeyCode::Tab => {
+                        replace(&mut state, lines, INDENTATION);
+
+                        is_changed = true;
+                    }
+                    keyboard::KeyCode::Backspace => {
+                        if state.selection().is_none() {
+                            state.anchor = previous(lines, cursor);
+                        }
+
+                        is_changed = state.selection().is_some();
+                        replace(&mut state, lines, "");
+                    }
+                    keyboard::KeyCode::Delete => {
+                        if state.selection().is_none() {
+                            state.anchor = next(lines, cursor);
+                        }
+
+                        is_changed = state.selection().is_some();
+                        replace(&mut state, lines, "");
+                    }
+                    keyboard::KeyCode::Left => {
+                        let position = match state.selection() {
+                            Some((start, _)) if !modifiers.shift => start,
+                            _ => previous(lines, cursor),
+                        };
+
+                        state.move_to(position, modifiers.shift);
+                    }
+                    keyboard::KeyCode::Right => {
+                        let position = match state.selection() {
+                            Some((_, end)) if !modifiers.shift => end,
+                            _ => next(lines, cursor),
+                        };
+
+                        state.move_to(position, modifiers.shift);
+                    }
+                    keyboard::KeyCode::Up => {
+                        let position = if cursor.line > 0 {
+                            clamp(
+                                lines,
+                                Position {
+                                    line: cursor.line - 1,
+                                    ..cursor
+                                },
+                            )
+                        } else {
+                            Position::default()
+                        };
+
+                        state.move_to(position, modifiers.shift);
+                    }
+                    keyboard::KeyCode::Down => {
+                        let position = clamp(
+                            lines,
+                            if cursor.line + 1 < lines.len() {
+                                Position {
+                                    line: cursor.line + 1,
+                                    ..cursor
+                                }
+                            } else {
+                                end(lines)
+                            },
+                        );
+
+                        state.move_to(position, modifiers.shift);
+                    }
+                    keyboard::KeyCode::Home => {
+                        state.move_to(
+                            Position {
+                                line: cursor.line,
+                                column: 0,
+                            },
+                            modifiers.shift,
+                        );
+                    }
+                    keyboard::KeyCode::End => {
+                        state.move_to(
+                            Position {
+                                line: cursor.line,
+                                column: lines[cursor.line].len(),
+                            },
+                            modifiers.shift,
+                        );
+                    }
+                    keyboard::KeyCode::A if is_command => {
+                        state.anchor = Position::default();
+                        state.cursor = end(lines);
+                    }
+                    keyboard::KeyCode::C | keyboard::KeyCode::X
+                        if is_command =>
+                    {
+                        if let (Some((start, end)), Some(clipboard)) =
+                            (state.selection(), clipboard)
+                        {
+                            let is_written = clipboard
+                                .write(text(lines, start, end))
+                                .is_ok();
+
+                            // Cutting never loses text the clipboard refused
+                            if key_code == keyboard::KeyCode::X && is_written {
+                                replace(&mut state, lines, "");
+
+                                is_changed = true;
+                            }
+                        }
+                    }
+                    keyboard::KeyCode::V if is_command => {
+                        if let Some(content) =
+                            clipboard.and_then(|clipboard| clipboard.content())
+                        {
+                            replace(
+                                &mut state,
+                                lines,
+                                &content.replace("\r\n", "\n"),
+                            );
+
+                            is_changed = true;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+
+        if is_changed {
+            messages.push((on_change)(lines.join("\n")));
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let state = self.state.borrow();
+
+        let bounds = layout.bounds();
+        let text_bounds = layout.children().next().unwrap().bounds();
+
+        let size = self.size.unwrap_or(text::Renderer::default_size(renderer));
+        let line_height = renderer.line_height(size, self.font);
+
+        let cursor = clamp(&self.lines, state.cursor);
+        let anchor = clamp(&self.lines, state.anchor);
+
+        let preedit = state.preedit.as_deref().filter(|_| state.is_focused);
+
+        let lines: Vec<Line> = self
+            .lines
+            .iter()
+            .zip(highlights(&state, &self.lines, self.highlighter))
+            .enumerate()
+            .map(|(i, (line, highlights))| {
+                let (mut spans, mut backgrounds) =
+                    spans(line, &highlights, size, self.font);
+
+                let mut misspelled: Vec<Range<usize>> = self
+                    .misspellings
+                    .iter()
+                    .filter(|misspelling| {
+                        misspelling.line == i
+                            && misspelling.range.start < misspelling.range.end
+                            && line.get(misspelling.range.clone()).is_some()
+                    })
+                    .map(|misspelling| misspelling.range.clone())
+                    .collect();
+
+                let composition =
+                    preedit.filter(|_| i == cursor.line).map(|preedit| {
+                        compose(
+                            &mut spans,
+                            backgrounds
+                                .iter_mut()
+                                .map(|(range, _)| range)
+                                .chain(misspelled.iter_mut()),
+                            cursor.column,
+                            preedit,
+                        );
+
+                        cursor.column..cursor.column + preedit.len()
+                    });
+
+                let bounds = Rectangle {
+                    x: text_bounds.x,
+                    y: text_bounds.y + i as f32 * line_height,
+                    width: text_bounds.width,
+                    height: line_height,
+                };
+
+                let mut backgrounds: Vec<(Rectangle, Color)> = backgrounds
+                    .into_iter()
+                    .map(|(range, color)| {
+                        let left = renderer.caret_position(&spans, range.start);
+                        let right = renderer.caret_position(&spans, range.end);
+
+                        (
+                            Rectangle {
+                                x: bounds.x + left,
+                                width: right - left,
+                                ..bounds
+                            },
+                            color,
+                        )
+                    })
+                    .collect();
+
+                // Misspelled words are underlined in red
+                for range in misspelled {
+                    let left = renderer.caret_position(&spans, range.start);
+                    let right = renderer.caret_position(&spans, range.end);
+
+                    backgrounds.push((
+                        Rectangle {
+                            x: bounds.x + left,
+                            y: bounds.y + bounds.height - 1.0,
+                            width: right - left,
+                            height: 1.0,
+                        },
+                        MISSPELLING_COLOR,
+                    ));
+                }
+
+                // The composed text is underlined
+                if let Some(range) = composition {
+                    let left = renderer.caret_position(&spans, range.start);
+                    let right = renderer.caret_position(&spans, range.end);
+
+                    backgrounds.push((
+                        Rectangle {
+                            x: bounds.x + left,
+                            y: bounds.y + bounds.height - 1.0,
+                            width: right - left,
+                            height: 1.0,
+                        },
+                        Color::BLACK,
+                    ));
+                }
+
+                Line {
+                    bounds,
+                    spans,
+                    backgrounds,
+                }
+            })
+            .collect();
+
+        let selection: Vec<Rectangle> = (cursor.line.min(anchor.line)
+            ..=cursor.line.max(anchor.line))
+            .filter(|_| cursor != anchor)
+            .map(|i| {
+                let (start, end) = (cursor.min(anchor), cursor.max(anchor));
+                let line = &lines[i];
+
+                let left = if i == start.line {
+                    renderer.caret_position(&line.spans, start.column)
+                } else {
+                    0.0
+                };
+
+                // Selected line breaks are shown as a bit of extra space
+                let right = if i == end.line {
+                    renderer.caret_position(&line.spans, end.column)
+                } else {
+                    renderer.caret_position(&line.spans, self.lines[i].len())
+                        + line_height / 4.0
+                };
+
+                Rectangle {
+                    x: line.bounds.x + left,
+                    width: right - left,
+                    ..line.bounds
+                }
+            })
+            .collect();
+
+        let caret = if state.is_focused {
+            let line = &lines[cursor.line];
+
+            let caret = Rectangle {
+                x: line.bounds.x
+                    + renderer.caret_position(&line.spans, cursor.column),
+                width: 1.0,
+                ..line.bounds
+            };
+
+            // The candidate window stays at the start of the composition,
+            // while the caret follows the composed text
+            renderer.set_ime_caret(caret);
+
+            Some(match preedit {
+                Some(preedit) => Rectangle {
+                    x: line.bounds.x
+                        + renderer.caret_position(
+                            &line.spans,
+                            cursor.column + preedit.len(),
+                        ),
+                    ..caret
+                },
+                None => caret,
+            })
+        } else {
+            None
+        };
+
+        self::Renderer::draw(
+            renderer,
+            bounds,
+            cursor_position,
+            &lines,
+            &selection,
+            caret,
+            state.is_focused,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<TextEditor<'static, ()>>().hash(state);
+
+        self.lines.len().hash(state);
+        self.width.hash(state);
+        self.padding.hash(state);
+        self.size.hash(state);
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.state.diff(tree);
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        focusables.push(Focusable {
+            path: path.clone(),
+            is_focused: self.state.borrow().is_focused,
+            uses_tab: true,
+        });
+    }
+
+    fn captures_mouse(&self) -> bool {
+        self.state.borrow().is_dragging
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let TextEditor {
+            state,
+            lines,
+            size,
+            on_change,
+            misspellings,
+            ..
+        } = self;
+
+        let (line, range, start) = state.borrow().menu.clone()?;
+
+        let misspelling = misspellings
+            .iter()
+            .find(|misspelling| {
+                misspelling.line == line && misspelling.range == range
+            })
+            .filter(|misspelling| !misspelling.suggestions.is_empty())?;
+
+        let bounds = layout.bounds();
+
+        Some(overlay::Element::new(
+            Point::new(bounds.x + start.x, bounds.y + start.y + start.height),
+            Box::new(Suggestions::new(
+                &misspelling.suggestions,
+                *size,
+                start.height,
+                move |suggestion, messages| {
+                    let mut state = state.borrow_mut();
+
+                    let (line, range) = match state.menu.take() {
+                        Some((line, range, _)) => (line, range),
+                        None => return,
+                    };
+
+                    // The text may have changed since the menu was opened
+                    let is_valid = lines
+                        .get(line)
+                        .and_then(|content| content.get(range.clone()))
+                        .is_some();
+
+                    if let Some(suggestion) = suggestion.filter(|_| is_valid) {
+                        state.anchor = Position {
+                            line,
+                            column: range.start,
+                        };
+                        state.cursor = Position {
+                            line,
+                            column: range.end,
+                        };
+
+                        replace(&mut state, lines, suggestion);
+                        state.is_focused = true;
+
+                        messages.push(on_change(lines.join("\n")));
+                    }
+                },
+            )),
+        ))
+    }
+}
+
+/// The renderer of a [`TextEditor`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`TextEditor`] in your user interface.
+///
+/// [`TextEditor`]: struct.TextEditor.html
+/// [renderer]: ../../renderer/index.html
+///
+/// It also draws the menu of suggestions of a [`Misspelling`], like a
+/// [`TextInput`] does.
+///
+/// [`Misspelling`]: struct.Misspelling.html
+/// [`TextInput`]: ../text_input/struct.TextInput.html
+pub trait Renderer: rich_text::Renderer + text_input::Renderer {
+    /// Returns the height of the lines of a [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    fn line_height(&self, size: u16, font: Font) -> f32;
+
+    /// Returns the horizontal position of the caret before the given byte
+    /// offset of a line of a [`TextEditor`], relative to the start of the
+    /// line.
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    fn caret_position(&self, spans: &[rich_text::Span], offset: usize) -> f32;
+
+    /// Returns the byte offset of the caret closest to the given horizontal
+    /// position in a line of a [`TextEditor`], relative to the start of the
+    /// line.
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    fn offset_at(&self, spans: &[rich_text::Span], x: f32) -> usize;
+
+    /// Draws a [`TextEditor`].
+    ///
+    /// It receives:
+    /// - the bounds of the [`TextEditor`]
+    /// - the cursor position
+    /// - the [`Line`]s of the [`TextEditor`]
+    /// - the rectangles covering the selected text
+    /// - the bounds of the caret, if the [`TextEditor`] is focused
+    /// - whether the [`TextEditor`] is focused
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    /// [`Line`]: struct.Line.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        lines: &[Line],
+        selection: &[Rectangle],
+        caret: Option<Rectangle>,
+        is_focused: bool,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<TextEditor<'a, Message>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'static + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        text_editor: TextEditor<'a, Message>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(text_editor)
+    }
+}
+
+/// The state of a [`TextEditor`].
+///
+/// [`TextEditor`]: struct.TextEditor.html
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    cursor: Position,
+    anchor: Position,
+    is_focused: bool,
+    is_dragging: bool,
+    is_shift_pressed: bool,
+    preedit: Option<String>,
+    menu: Option<(usize, Range<usize>, Rectangle)>,
+    highlights: RefCell<Vec<Option<Highlighted>>>,
+}
+
+impl State {
+    /// Creates a new [`State`], representing an unfocused [`TextEditor`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`State`], representing a focused [`TextEditor`] with
+    /// the cursor at the start of its text.
+    ///
+    /// [`State`]: struct.State.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn focused() -> Self {
+        State {
+            is_focused: true,
+            ..Self::default()
+        }
+    }
+
+    /// Returns whether the [`TextEditor`] is currently focused or not.
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Returns the [`Position`] of the cursor of the [`TextEditor`].
+    ///
+    /// [`Position`]: struct.Position.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn cursor(&self) -> Position {
+        self.cursor
+    }
+
+    /// Returns the start and the end of the text selected in the
+    /// [`TextEditor`], if any.
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn selection(&self) -> Option<(Position, Position)> {
+        if self.cursor == self.anchor {
+            None
+        } else {
+            Some((self.cursor.min(self.anchor), self.cursor.max(self.anchor)))
+        }
+    }
+
+    /// Returns the text being composed with an input method editor in the
+    /// [`TextEditor`], if any.
+    ///
+    /// It is not part of the text yet, but it is displayed at the cursor.
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn preedit(&self) -> Option<&str> {
+        self.preedit.as_deref()
+    }
+
+    /// Forgets the highlights of every line of the [`TextEditor`], so they
+    /// are highlighted again by its current [`Highlighter`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    /// [`Highlighter`]: trait.Highlighter.html
+    pub fn rehighlight(&mut self) {
+        self.highlights.get_mut().clear();
+    }
+
+    fn move_to(&mut self, position: Position, is_extended: bool) {
+        self.cursor = position;
+
+        if !is_extended {
+            self.anchor = position;
+        }
+    }
+
+    /// Keeps the remembered highlights aligned with their lines after some
+    /// lines are replaced by others.
+    fn shift_highlights(&mut self, line: usize, removed: usize, added: usize) {
+        let highlights = self.highlights.get_mut();
+
+        if line < highlights.len() {
+            let end = (line + removed).min(highlights.len());
+
+            let _ = highlights.splice(line..end, vec![None; added]);
+        }
+    }
+}
+
+/// The highlights of a line, remembered with everything they depend on.
+#[derive(Debug, Clone)]
+struct Highlighted {
+    line: String,
+    context: u64,
+    highlights: Vec<Highlight>,
+    end_context: u64,
+}
+
+fn highlights(
+    state: &State,
+    lines: &[String],
+    highlighter: Option<&dyn Highlighter>,
+) -> Vec<Vec<Highlight>> {
+    let highlighter = match highlighter {
+        Some(highlighter) => highlighter,
+        None => return vec![Vec::new(); lines.len()],
+    };
+
+    let mut remembered = state.highlights.borrow_mut();
+    remembered.resize(lines.len(), None);
+
+    let mut context = 0;
+
+    lines
+        .iter()
+        .zip(remembered.iter_mut())
+        .map(|(line, remembered)| {
+            let is_outdated = match remembered {
+                Some(remembered) => {
+                    remembered.context != context || remembered.line != *line
+                }
+                None => true,
+            };
+
+            if is_outdated {
+                let start = context;
+                let highlights = highlighter.highlight_line(line, &mut context);
+
+                *remembered = Some(Highlighted {
+                    line: line.clone(),
+                    context: start,
+                    highlights,
+                    end_context: context,
+                });
+            }
+
+            match remembered {
+                Some(remembered) => {
+                    context = remembered.end_context;
+                    remembered.highlights.clone()
+                }
+                None => Vec::new(),
+            }
+        })
+        .collect()
+}
+
+/// Splits a line in spans styled by its highlights, and returns them with
+/// the byte ranges of the backgrounds of the highlights.
+fn spans(
+    line: &str,
+    highlights: &[Highlight],
+    size: u16,
+    font: Font,
+) -> (Vec<rich_text::Span>, Vec<(Range<usize>, Color)>) {
+    let mut highlights = highlights.to_vec();
+    highlights.sort_by_key(|highlight| highlight.range.start);
+
+    let span = |range: Range<usize>| {
+        rich_text::Span::new(&line[range]).size(size).font(font)
+    };
+
+    let mut spans = Vec::new();
+    let mut backgrounds = Vec::new();
+    let mut start = 0;
+
+    for Highlight { range, format } in highlights {
+        if range.start < start
+            || range.start >= range.end
+            || line.get(range.clone()).is_none()
+        {
+            continue;
+        }
+
+        if range.start > start {
+            spans.push(span(start..range.start));
+        }
+
+        let mut highlighted = span(range.clone());
+        highlighted.color = format.color;
+
+        if format.italic {
+            highlighted.font = italic(font);
+        }
+
+        if let Some(background) = format.background {
+            backgrounds.push((range.clone(), background));
+        }
+
+        spans.push(highlighted);
+        start = range.end;
+    }
+
+    if start < line.len() || spans.is_empty() {
+        spans.push(span(start..line.len()));
+    }
+
+    (spans, backgrounds)
+}
+
+/// Inserts the text being composed with an input method editor in the spans
+/// of a line at the given byte offset, shifting the given ranges after it.
+fn compose<'a>(
+    spans: &mut Vec<rich_text::Span>,
+    ranges: impl Iterator<Item = &'a mut Range<usize>>,
+    offset: usize,
+    preedit: &str,
+) {
+    let mut start = 0;
+
+    for i in 0..spans.len() {
+        let end = start + spans[i].content.len();
+
+        if offset <= end {
+            let mut composed = spans[i].clone();
+            composed.content = String::from(preedit);
+
+            let mut tail = spans[i].clone();
+            tail.content = spans[i].content.split_off(offset - start);
+
+            let _ = spans.splice(i + 1..i + 1, vec![composed, tail]);
+            break;
+        }
+
+        start = end;
+    }
+
+    for range in ranges {
+        if range.start >= offset {
+            range.start += preedit.len();
+        }
+
+        if range.end > offset {
+            range.end += preedit.len();
+        }
+    }
+}
+
+fn italic(font: Font) -> Font {
+    match font {
+        Font::System { family, weight, .. } => Font::System {
+            family,
+            weight,
+            style: Style::Italic,
+        },
+        font => font,
+    }
+}
+
+fn position_at<Renderer: self::Renderer>(
+    renderer: &Renderer,
+    lines: &[String],
+    highlights: &[Vec<Highlight>],
+    size: u16,
+    font: Font,
+    text_bounds: Rectangle,
+    point: Point,
+) -> Position {
+    let line_height = renderer.line_height(size, font);
+
+    let line = (((point.y - text_bounds.y) / line_height).max(0.0) as usize)
+        .min(lines.len() - 1);
+
+    let (spans, _) = spans(&lines[line], &highlights[line], size, font);
+
+    Position {
+        line,
+        column: renderer.offset_at(&spans, point.x - text_bounds.x),
+    }
+}
+
+fn clamp(lines: &[String], position: Position) -> Position {
+    let line = position.line.min(lines.len() - 1);
+    let content = &lines[line];
+
+    let mut column = position.column.min(content.len());
+
+    while !content.is_char_boundary(column) {
+        column -= 1;
+    }
+
+    Position { line, column }
+}
+
+fn end(lines: &[String]) -> Position {
+    Position {
+        line: lines.len() - 1,
+        column: lines[lines.len() - 1].len(),
+    }
+}
+
+fn previous(lines: &[String], position: Position) -> Position {
+    let content = &lines[position.line];
+
+    match content[..position.column].chars().next_back() {
+        Some(c) => Position {
+            column: position.column - c.len_utf8(),
+            ..position
+        },
+        None if position.line > 0 => Position {
+            line: position.line - 1,
+            column: lines[position.line - 1].len(),
+        },
+        None => position,
+    }
+}
+
+fn next(lines: &[String], position: Position) -> Position {
+    let content = &lines[position.line];
+
+    match content[position.column..].chars().next() {
+        Some(c) => Position {
+            column: position.column + c.len_utf8(),
+            ..position
+        },
+        None if position.line + 1 < lines.len() => Position {
+            line: position.line + 1,
+            column: 0,
+        },
+        None => position,
+    }
+}
+
+fn text(lines: &[String], start: Position, end: Position) -> String {
+    if start.line == end.line {
+        lines[start.line][start.column..end.column].to_string()
+    } else {
+        let mut text = lines[start.line][start.column..].to_string();
+
+        for line in &lines[start.line + 1..end.line] {
+            text.push('\n');
+            text.push_str(line);
+        }
+
+        text.push('\n');
+        text.push_str(&lines[end.line][..end.column]);
+        text
+    }
+}
+
+/// Replaces the selected text, or inserts at the cursor, and moves the
+/// cursor after the new text.
+fn replace(state: &mut State, lines: &mut Vec<String>, text: &str) {
+    let (start, end) =
+        state.selection().unwrap_or((state.cursor, state.cursor));
+
+    let head = &lines[start.line][..start.column];
+    let tail = &lines[end.line][end.column..];
+
+    let mut replacement: Vec<String> =
+        text.split('\n').map(String::from).collect();
+    let last = replacement.len() - 1;
+
+    let cursor = Position {
+        line: start.line + last,
+        column: if last == 0 {
+            start.column + replacement[last].len()
+        } else {
+            replacement[last].len()
+        },
+    };
+
+    replacement[0].insert_str(0, head);
+    replacement[last].push_str(tail);
+
+    let _ = lines.splice(start.line..=end.line, replacement);
+
+    state.shift_highlights(start.line, end.line - start.line + 1, last + 1);
+    state.move_to(cursor, false);
+}
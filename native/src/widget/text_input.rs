@@ -5,12 +5,24 @@
 //! [`TextInput`]: struct.TextInput.html
 //! [`State`]: struct.State.html
 use crate::{
-    input::{keyboard, mouse, ButtonState},
-    layout, Clipboard, Element, Event, Hasher, Layout, Length, Point,
-    Rectangle, Size, Widget,
+    input::{ime, keyboard, mouse, ButtonState},
+    layout, overlay,
+    tree::{self, Tree},
+    Clipboard, Color, Element, Event, Focus, Focusable, Hasher, Layout, Length,
+    Point, Rectangle, Size, Vector, Widget,
 };
+use std::ops::Range;
+use std::time::{Duration, Instant};
 use unicode_segmentation::UnicodeSegmentation;
 
+// The longest time between the clicks of a double click
+const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(500);
+
+type Mask = Box<dyn Fn(usize, &str) -> String>;
+
+type OnPress<'a, Message> =
+    Box<dyn FnMut(Option<&str>, &mut Vec<Message>) + 'a>;
+
 /// A field that can be filled with text.
 ///
 /// # Example
@@ -36,16 +48,18 @@ use unicode_segmentation::UnicodeSegmentation;
 /// ![Text input drawn by `iced_wgpu`](https://github.com/hecrj/iced/blob/7760618fb112074bc40b148944521f312152012a/docs/images/text_input.png?raw=true)
 #[allow(missing_debug_implementations)]
 pub struct TextInput<'a, Message> {
-    state: &'a mut State,
+    state: tree::State<'a, State>,
     placeholder: String,
     value: Value,
-    is_secure: bool,
+    mask: Option<Mask>,
     width: Length,
     max_width: Length,
     padding: u16,
     size: Option<u16>,
+    caret: Option<Caret>,
     on_change: Box<dyn Fn(String) -> Message>,
     on_submit: Option<Message>,
+    misspellings: Vec<Misspelling>,
 }
 
 impl<'a, Message> TextInput<'a, Message> {
@@ -65,6 +79,61 @@ impl<'a, Message> TextInput<'a, Message> {
         value: &str,
         on_change: F,
     ) -> Self
+    where
+        F: 'static + Fn(String) -> Message,
+    {
+        Self::with_state(
+            tree::State::Borrowed(state),
+            placeholder,
+            value,
+            on_change,
+        )
+    }
+
+    /// Creates a new [`TextInput`] whose local [`State`] is managed by the
+    /// runtime.
+    ///
+    /// It expects the same arguments as [`new`], except for the [`State`].
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    /// [`State`]: struct.State.html
+    /// [`new`]: #method.new
+    pub fn managed<F>(placeholder: &str, value: &str, on_change: F) -> Self
+    where
+        F: 'static + Fn(String) -> Message,
+    {
+        Self::with_state(tree::State::managed(), placeholder, value, on_change)
+    }
+
+    /// Creates a new [`TextInput`] whose local [`State`] is shared through
+    /// the given [`Link`].
+    ///
+    /// This lets your application set the initial [`State`] of the
+    /// [`TextInput`], like a [`State::focused`] one, while only borrowing
+    /// itself to be viewed.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    /// [`State`]: struct.State.html
+    /// [`State::focused`]: struct.State.html#method.focused
+    /// [`Link`]: ../../tree/struct.Link.html
+    pub fn linked<F>(
+        link: &tree::Link<State>,
+        placeholder: &str,
+        value: &str,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(String) -> Message,
+    {
+        Self::with_state(link.into(), placeholder, value, on_change)
+    }
+
+    fn with_state<F>(
+        state: tree::State<'a, State>,
+        placeholder: &str,
+        value: &str,
+        on_change: F,
+    ) -> Self
     where
         F: 'static + Fn(String) -> Message,
     {
@@ -72,21 +141,40 @@ impl<'a, Message> TextInput<'a, Message> {
             state,
             placeholder: String::from(placeholder),
             value: Value::new(value),
-            is_secure: false,
+            mask: None,
             width: Length::Fill,
             max_width: Length::Shrink,
             padding: 0,
             size: None,
+            caret: None,
             on_change: Box::new(on_change),
             on_submit: None,
+            misspellings: Vec::new(),
         }
     }
 
     /// Converts the [`TextInput`] into a secure password input.
     ///
+    /// Every grapheme of the value is displayed as a dot ('•').
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn password(self) -> Self {
+        self.mask(|_, _| String::from("•"))
+    }
+
+    /// Masks the value of the [`TextInput`] with the given function.
+    ///
+    /// The function receives the index and the content of every grapheme of
+    /// the value and returns what should be displayed in its place. The real
+    /// value is kept as is, but a masked [`TextInput`] does not reveal it: it
+    /// cannot be copied and words are not selected or jumped over.
+    ///
     /// [`TextInput`]: struct.TextInput.html
-    pub fn password(mut self) -> Self {
-        self.is_secure = true;
+    pub fn mask<F>(mut self, mask: F) -> Self
+    where
+        F: 'static + Fn(usize, &str) -> String,
+    {
+        self.mask = Some(Box::new(mask));
         self
     }
 
@@ -122,6 +210,17 @@ impl<'a, Message> TextInput<'a, Message> {
         self
     }
 
+    /// Sets the [`Caret`] of the [`TextInput`].
+    ///
+    /// By default, the [`Caret`] of the renderer is used.
+    ///
+    /// [`Caret`]: struct.Caret.html
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn caret(mut self, caret: Caret) -> Self {
+        self.caret = Some(caret);
+        self
+    }
+
     /// Sets the message that should be produced when the [`TextInput`] is
     /// focused and the enter key is pressed.
     ///
@@ -130,6 +229,149 @@ impl<'a, Message> TextInput<'a, Message> {
         self.on_submit = Some(message);
         self
     }
+
+    /// Sets the [`Misspelling`]s of the current value of the [`TextInput`],
+    /// usually produced by an external spell checker.
+    ///
+    /// Misspelled words are underlined, and right-clicking them opens a menu
+    /// with their suggestions. Choosing a suggestion replaces the word and
+    /// produces a change message.
+    ///
+    /// Misspellings are ignored in password inputs.
+    ///
+    /// [`Misspelling`]: struct.Misspelling.html
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn misspellings(mut self, misspellings: Vec<Misspelling>) -> Self {
+        self.misspellings = misspellings;
+        self
+    }
+
+    /// Returns whether the [`TextInput`] has the focus.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub(crate) fn is_focused(&self) -> bool {
+        self.state.borrow().is_focused
+    }
+
+    /// Replaces the text of the [`TextInput`].
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub(crate) fn set_value(&mut self, value: &str) {
+        self.value = Value::new(value);
+    }
+}
+
+/// The appearance of the caret of a [`TextInput`].
+///
+/// [`TextInput`]: struct.TextInput.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Caret {
+    /// The shape of the caret
+    pub shape: CaretShape,
+
+    /// The width of a [`CaretShape::Bar`], in pixels
+    ///
+    /// [`CaretShape::Bar`]: enum.CaretShape.html#variant.Bar
+    pub width: f32,
+
+    /// The color of the caret
+    pub color: Color,
+
+    /// The time the caret stays visible, and then hidden, while blinking
+    ///
+    /// The caret does not blink while the user is typing or moving it, nor
+    /// when this is `None`.
+    pub blink_interval: Option<Duration>,
+}
+
+impl Default for Caret {
+    fn default() -> Self {
+        Caret {
+            shape: CaretShape::Bar,
+            width: 1.0,
+            color: Color::BLACK,
+            blink_interval: Some(Duration::from_millis(530)),
+        }
+    }
+}
+
+/// The shape of a [`Caret`].
+///
+/// [`Caret`]: struct.Caret.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CaretShape {
+    /// A thin vertical bar between characters.
+    Bar,
+
+    /// A block covering the character after the caret, like in terminals.
+    ///
+    /// The block is drawn below the text, so it should have a light or
+    /// translucent color.
+    Block,
+}
+
+/// A misspelled range of the value of a [`TextInput`].
+///
+/// [`TextInput`]: struct.TextInput.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Misspelling {
+    /// The misspelled graphemes of the value.
+    pub range: Range<usize>,
+
+    /// The suggested replacements for the misspelled graphemes.
+    pub suggestions: Vec<String>,
+}
+
+impl Misspelling {
+    /// Creates a new [`Misspelling`] of the given range of graphemes, with
+    /// some suggestions.
+    ///
+    /// [`Misspelling`]: struct.Misspelling.html
+    pub fn new(range: Range<usize>, suggestions: Vec<String>) -> Self {
+        Misspelling { range, suggestions }
+    }
+}
+
+/// The menu showing the suggestions of a [`Misspelling`].
+///
+/// [`Misspelling`]: struct.Misspelling.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Menu<'a> {
+    /// The bounds of the [`Menu`].
+    ///
+    /// [`Menu`]: struct.Menu.html
+    pub bounds: Rectangle,
+
+    /// The suggestions to show, from top to bottom.
+    pub suggestions: &'a [String],
+
+    /// The height of each suggestion.
+    pub item_height: f32,
+
+    /// The text size of the suggestions.
+    pub text_size: u16,
+}
+
+impl<'a> Menu<'a> {
+    /// Returns the bounds of the suggestion with the given index.
+    pub fn item_bounds(&self, index: usize) -> Rectangle {
+        Rectangle {
+            y: self.bounds.y + index as f32 * self.item_height,
+            height: self.item_height,
+            ..self.bounds
+        }
+    }
+
+    /// Returns the index of the suggestion at the given point, if any.
+    pub fn item_at(&self, point: Point) -> Option<usize> {
+        if !self.bounds.contains(point) {
+            return None;
+        }
+
+        let index = ((point.y - self.bounds.y) / self.item_height) as usize;
+
+        Some(index.min(self.suggestions.len().saturating_sub(1)))
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer> for TextInput<'a, Message>
@@ -158,11 +400,13 @@ where
             .width(self.width)
             .height(Length::Units(text_size));
 
-        let mut text = layout::Node::new(limits.resolve(Size::ZERO));
+        let mut text = layout::Node::new(limits.resolve(Size::ZERO))
+            .with_baseline(renderer.baseline(text_size));
         text.bounds.x = padding;
         text.bounds.y = padding;
 
         layout::Node::with_children(text.size().pad(padding), vec![text])
+            .inherit_baseline()
     }
 
     fn on_event(
@@ -174,7 +418,56 @@ where
         renderer: &Renderer,
         clipboard: Option<&dyn Clipboard>,
     ) {
+        let mut state = self.state.borrow_mut();
+
+        if let Event::Keyboard(keyboard::Event::Input { modifiers, .. }) = event
+        {
+            state.is_shift_pressed = modifiers.shift;
+        }
+
         match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Right,
+                state: ButtonState::Pressed,
+            }) if layout.bounds().contains(cursor_position) => {
+                let bounds = layout.bounds();
+                let text_bounds = layout.children().next().unwrap().bounds();
+                let size = self.size.unwrap_or(renderer.default_size());
+
+                let offset =
+                    renderer.offset(text_bounds, size, &self.value, &state);
+
+                let index = find_cursor_position(
+                    renderer,
+                    cursor_position.x - text_bounds.x + offset,
+                    &self.value,
+                    size,
+                    0,
+                    self.value.len(),
+                );
+
+                let value = &self.value;
+                let is_masked = self.mask.is_some();
+
+                let misspelling = misspelling_at(&self.misspellings, index)
+                    .filter(|_| !is_masked);
+
+                // The menu opens below the start of the misspelled word
+                state.menu = misspelling.map(|misspelling| {
+                    let start = renderer.measure_value(
+                        &value.until(misspelling.range.start).to_string(),
+                        size,
+                    );
+
+                    (
+                        misspelling.range.clone(),
+                        Vector::new(
+                            text_bounds.x - bounds.x + start - offset,
+                            bounds.height,
+                        ),
+                    )
+                });
+            }
             Event::Mouse(mouse::Event::Input {
                 button: mouse::Button::Left,
                 state: ButtonState::Pressed,
@@ -182,49 +475,88 @@ where
                 let is_clicked = layout.bounds().contains(cursor_position);
 
                 if is_clicked {
-                    let text_layout = layout.children().next().unwrap();
-                    let target = cursor_position.x - text_layout.bounds().x;
+                    let index = grapheme_at(
+                        renderer,
+                        layout,
+                        self.size.unwrap_or(renderer.default_size()),
+                        &masked(&self.value, &self.mask),
+                        &state,
+                        cursor_position.x,
+                    );
+
+                    let now = Instant::now();
+
+                    let clicks = match state.last_click {
+                        Some((at, last, clicks))
+                            if last == index
+                                && now.duration_since(at)
+                                    < DOUBLE_CLICK_INTERVAL =>
+                        {
+                            clicks % 3 + 1
+                        }
+                        _ => 1,
+                    };
 
-                    if target > 0.0 {
-                        let value = if self.is_secure {
-                            self.value.secure()
-                        } else {
-                            self.value.clone()
-                        };
+                    state.last_click = Some((now, index, clicks));
 
-                        let size = self.size.unwrap_or(renderer.default_size());
+                    match clicks {
+                        1 => {
+                            let is_extended =
+                                state.is_focused && state.is_shift_pressed;
 
-                        let offset = renderer.offset(
-                            text_layout.bounds(),
-                            size,
-                            &value,
-                            &self.state,
-                        );
+                            state.move_to(&self.value, index, is_extended);
+                            state.is_dragging = true;
+                        }
+                        // The words of a secure input are not revealed
+                        2 if self.mask.is_none() => {
+                            let word = self.value.word_at(index);
 
-                        self.state.cursor_position = find_cursor_position(
-                            renderer,
-                            target + offset,
-                            &value,
-                            size,
-                            0,
-                            self.value.len(),
-                        );
-                    } else {
-                        self.state.cursor_position = 0;
+                            state.select(word.start, word.end);
+                        }
+                        _ => {
+                            state.select(0, self.value.len());
+                        }
                     }
                 }
 
-                self.state.is_focused = is_clicked;
+                if !is_clicked {
+                    state.preedit = None;
+                }
+
+                state.is_focused = is_clicked;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { x, .. })
+                if state.is_dragging =>
+            {
+                let index = grapheme_at(
+                    renderer,
+                    layout,
+                    self.size.unwrap_or(renderer.default_size()),
+                    &masked(&self.value, &self.mask),
+                    &state,
+                    x,
+                );
+
+                state.move_to(&self.value, index, true);
+            }
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Released,
+            }) => {
+                state.is_dragging = false;
             }
             Event::Keyboard(keyboard::Event::CharacterReceived(c))
-                if self.state.is_focused
-                    && self.state.is_pasting.is_none()
+                if state.is_focused
+                    && state.is_pasting.is_none()
                     && !c.is_control() =>
             {
-                let cursor_position = self.state.cursor_position(&self.value);
+                state.record(&self.value, Edit::Insert);
+                state.delete_selection(&mut self.value);
+
+                let cursor_position = state.cursor_position(&self.value);
 
                 self.value.insert(cursor_position, c);
-                self.state.move_cursor_right(&self.value);
+                state.move_cursor_right(&self.value);
 
                 let message = (self.on_change)(self.value.to_string());
                 messages.push(message);
@@ -233,111 +565,263 @@ where
                 key_code,
                 state: ButtonState::Pressed,
                 modifiers,
-            }) if self.state.is_focused => match key_code {
-                keyboard::KeyCode::Enter => {
-                    if let Some(on_submit) = self.on_submit.clone() {
-                        messages.push(on_submit);
+            }) if state.is_focused => {
+                let is_command =
+                    platform::is_copy_paste_modifier_pressed(modifiers);
+                let is_jump = platform::is_jump_modifier_pressed(modifiers)
+                    && self.mask.is_none();
+
+                let cursor_position = state.cursor_position(&self.value);
+
+                match key_code {
+                    keyboard::KeyCode::Enter => {
+                        if let Some(on_submit) = self.on_submit.clone() {
+                            messages.push(on_submit);
+                        }
                     }
-                }
-                keyboard::KeyCode::Backspace => {
-                    let cursor_position =
-                        self.state.cursor_position(&self.value);
+                    keyboard::KeyCode::Backspace => {
+                        let start = if is_jump {
+                            self.value.previous_start_of_word(cursor_position)
+                        } else {
+                            cursor_position.saturating_sub(1)
+                        };
 
-                    if cursor_position > 0 {
-                        self.state.move_cursor_left(&self.value);
+                        let range = state
+                            .selection(&self.value)
+                            .unwrap_or(start..cursor_position);
 
-                        let _ = self.value.remove(cursor_position - 1);
+                        if !range.is_empty() {
+                            state.record(&self.value, Edit::Delete);
+                            self.value.replace(range.clone(), Value::new(""));
+                            state.move_cursor_to(range.start);
 
-                        let message = (self.on_change)(self.value.to_string());
-                        messages.push(message);
+                            let message =
+                                (self.on_change)(self.value.to_string());
+                            messages.push(message);
+                        }
                     }
-                }
-                keyboard::KeyCode::Delete => {
-                    let cursor_position =
-                        self.state.cursor_position(&self.value);
+                    keyboard::KeyCode::Delete => {
+                        let end = if is_jump {
+                            self.value.next_end_of_word(cursor_position)
+                        } else {
+                            (cursor_position + 1).min(self.value.len())
+                        };
 
-                    if cursor_position < self.value.len() {
-                        let _ = self.value.remove(cursor_position);
+                        let range = state
+                            .selection(&self.value)
+                            .unwrap_or(cursor_position..end);
 
-                        let message = (self.on_change)(self.value.to_string());
-                        messages.push(message);
+                        if !range.is_empty() {
+                            state.record(&self.value, Edit::Delete);
+                            self.value.replace(range.clone(), Value::new(""));
+                            state.move_cursor_to(range.start);
+
+                            let message =
+                                (self.on_change)(self.value.to_string());
+                            messages.push(message);
+                        }
                     }
-                }
-                keyboard::KeyCode::Left => {
-                    if platform::is_jump_modifier_pressed(modifiers)
-                        && !self.is_secure
-                    {
-                        self.state.move_cursor_left_by_words(&self.value);
-                    } else {
-                        self.state.move_cursor_left(&self.value);
+                    keyboard::KeyCode::Left => {
+                        let index = match state.selection(&self.value) {
+                            _ if is_jump => self
+                                .value
+                                .previous_start_of_word(cursor_position),
+                            Some(selection) if !modifiers.shift => {
+                                selection.start
+                            }
+                            _ => cursor_position.saturating_sub(1),
+                        };
+
+                        state.move_to(&self.value, index, modifiers.shift);
                     }
-                }
-                keyboard::KeyCode::Right => {
-                    if platform::is_jump_modifier_pressed(modifiers)
-                        && !self.is_secure
+                    keyboard::KeyCode::Right => {
+                        let index = match state.selection(&self.value) {
+                            _ if is_jump => {
+                                self.value.next_end_of_word(cursor_position)
+                            }
+                            Some(selection) if !modifiers.shift => {
+                                selection.end
+                            }
+                            _ => (cursor_position + 1).min(self.value.len()),
+                        };
+
+                        state.move_to(&self.value, index, modifiers.shift);
+                    }
+                    keyboard::KeyCode::Home => {
+                        state.move_to(&self.value, 0, modifiers.shift);
+                    }
+                    keyboard::KeyCode::End => {
+                        state.move_to(
+                            &self.value,
+                            self.value.len(),
+                            modifiers.shift,
+                        );
+                    }
+                    keyboard::KeyCode::A if is_command => {
+                        state.select(0, self.value.len());
+                    }
+                    keyboard::KeyCode::C | keyboard::KeyCode::X
+                        if is_command && self.mask.is_none() =>
                     {
-                        self.state.move_cursor_right_by_words(&self.value);
-                    } else {
-                        self.state.move_cursor_right(&self.value);
+                        if let (Some(selection), Some(clipboard)) =
+                            (state.selection(&self.value), clipboard)
+                        {
+                            let is_written = clipboard
+                                .write(
+                                    self.value
+                                        .select(selection.start, selection.end)
+                                        .to_string(),
+                                )
+                                .is_ok();
+
+                            // Cutting never loses text the clipboard refused
+                            if key_code == keyboard::KeyCode::X && is_written {
+                                state.record(&self.value, Edit::Other);
+                                state.delete_selection(&mut self.value);
+
+                                let message =
+                                    (self.on_change)(self.value.to_string());
+                                messages.push(message);
+                            }
+                        }
                     }
-                }
-                keyboard::KeyCode::Home => {
-                    self.state.cursor_position = 0;
-                }
-                keyboard::KeyCode::End => {
-                    self.state.move_cursor_to_end(&self.value);
-                }
-                keyboard::KeyCode::V => {
-                    if platform::is_copy_paste_modifier_pressed(modifiers) {
-                        if let Some(clipboard) = clipboard {
-                            let content = match self.state.is_pasting.take() {
-                                Some(content) => content,
-                                None => {
-                                    let content: String = clipboard
-                                        .content()
-                                        .unwrap_or(String::new())
-                                        .chars()
-                                        .filter(|c| !c.is_control())
-                                        .collect();
-
-                                    Value::new(&content)
-                                }
-                            };
-
-                            let cursor_position =
-                                self.state.cursor_position(&self.value);
-
-                            self.value
-                                .insert_many(cursor_position, content.clone());
-
-                            self.state.move_cursor_right_by_amount(
-                                &self.value,
-                                content.len(),
-                            );
-                            self.state.is_pasting = Some(content);
+                    keyboard::KeyCode::Z | keyboard::KeyCode::Y
+                        if is_command =>
+                    {
+                        let is_redo =
+                            key_code == keyboard::KeyCode::Y || modifiers.shift;
 
+                        let is_changed = if is_redo {
+                            state.redo(&mut self.value)
+                        } else {
+                            state.undo(&mut self.value)
+                        };
+
+                        if is_changed {
                             let message =
                                 (self.on_change)(self.value.to_string());
                             messages.push(message);
                         }
-                    } else {
-                        self.state.is_pasting = None;
                     }
+                    keyboard::KeyCode::V => {
+                        if is_command {
+                            if let Some(clipboard) = clipboard {
+                                let content = match state.is_pasting.take() {
+                                    Some(content) => content,
+                                    None => {
+                                        let content: String = clipboard
+                                            .content()
+                                            .unwrap_or(String::new())
+                                            .chars()
+                                            .filter(|c| !c.is_control())
+                                            .collect();
+
+                                        Value::new(&content)
+                                    }
+                                };
+
+                                state.record(&self.value, Edit::Other);
+                                state.delete_selection(&mut self.value);
+
+                                let cursor_position =
+                                    state.cursor_position(&self.value);
+
+                                self.value.insert_many(
+                                    cursor_position,
+                                    content.clone(),
+                                );
+
+                                state.move_cursor_right_by_amount(
+                                    &self.value,
+                                    content.len(),
+                                );
+                                state.is_pasting = Some(content);
+
+                                let message =
+                                    (self.on_change)(self.value.to_string());
+                                messages.push(message);
+                            }
+                        } else {
+                            state.is_pasting = None;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Event::Focus(focus) if layout.is_focus_target() => match focus {
+                // The whole value is selected when tabbing into the input
+                Focus::Gained => {
+                    state.is_focused = true;
+                    state.select(0, self.value.len());
+                    state.last_activity = Some(Instant::now());
+                }
+                Focus::Lost => {
+                    state.is_focused = false;
+                    state.is_dragging = false;
+                    state.preedit = None;
+                    state.menu = None;
                 }
-                _ => {}
             },
+            // The composed text of a masked input is not revealed either
+            Event::Ime(ime::Event::Preedit(ref content))
+                if state.is_focused && self.mask.is_none() =>
+            {
+                // A composition replaces the selection, like typing does
+                if state.preedit.is_none()
+                    && !content.is_empty()
+                    && state.selection(&self.value).is_some()
+                {
+                    state.record(&self.value, Edit::Other);
+                    state.delete_selection(&mut self.value);
+
+                    messages.push((self.on_change)(self.value.to_string()));
+                }
+
+                state.preedit = if content.is_empty() {
+                    None
+                } else {
+                    Some(content.clone())
+                };
+            }
+            Event::Ime(ime::Event::Commit(ref content)) if state.is_focused => {
+                let content = Value::new(content);
+
+                state.preedit = None;
+                state.record(&self.value, Edit::Insert);
+                state.delete_selection(&mut self.value);
+
+                let cursor_position = state.cursor_position(&self.value);
+
+                self.value.insert_many(cursor_position, content.clone());
+                state.move_cursor_right_by_amount(&self.value, content.len());
+
+                messages.push((self.on_change)(self.value.to_string()));
+            }
             Event::Keyboard(keyboard::Event::Input {
                 key_code,
                 state: ButtonState::Released,
                 ..
             }) => match key_code {
                 keyboard::KeyCode::V => {
-                    self.state.is_pasting = None;
+                    state.is_pasting = None;
                 }
                 _ => {}
             },
             _ => {}
         }
+
+        // The caret stays visible while the user is typing or moving it
+        match event {
+            Event::Keyboard(_)
+            | Event::Ime(_)
+            | Event::Mouse(mouse::Event::Input {
+                state: ButtonState::Pressed,
+                ..
+            }) if state.is_focused => {
+                state.last_activity = Some(Instant::now());
+            }
+            _ => {}
+        }
     }
 
     fn draw(
@@ -346,18 +830,67 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
     ) -> Renderer::Output {
+        let state = self.state.borrow();
+
         let bounds = layout.bounds();
         let text_bounds = layout.children().next().unwrap().bounds();
 
-        if self.is_secure {
+        let caret = if state.is_focused() {
+            let caret = self.caret.unwrap_or_else(|| renderer.default_caret());
+
+            match (caret.blink_interval, state.last_activity) {
+                (Some(interval), Some(last_activity)) => {
+                    let interval = interval.as_millis().max(1);
+                    let phases =
+                        last_activity.elapsed().as_millis() / interval + 1;
+
+                    // Wake up when the caret needs to blink, instead of
+                    // redrawing continuously
+                    renderer.schedule_redraw(
+                        last_activity
+                            + Duration::from_millis((phases * interval) as u64),
+                    );
+
+                    if phases % 2 == 1 {
+                        Some(caret)
+                    } else {
+                        None
+                    }
+                }
+                _ => Some(caret),
+            }
+        } else {
+            None
+        };
+
+        if state.is_focused() {
+            let size = self.size.unwrap_or(renderer.default_size());
+            let value = masked(&self.value, &self.mask);
+
+            let offset = renderer.offset(text_bounds, size, &value, &state);
+            let x = renderer.measure_value(
+                &value.until(state.cursor_position(&value)).to_string(),
+                size,
+            );
+
+            renderer.set_ime_caret(Rectangle {
+                x: text_bounds.x + x - offset,
+                width: 1.0,
+                ..text_bounds
+            });
+        }
+
+        if let Some(mask) = &self.mask {
             renderer.draw(
                 bounds,
                 text_bounds,
                 cursor_position,
                 self.size.unwrap_or(renderer.default_size()),
                 &self.placeholder,
-                &self.value.secure(),
-                &self.state,
+                &self.value.mask(mask),
+                &state,
+                caret,
+                &[],
             )
         } else {
             renderer.draw(
@@ -367,7 +900,9 @@ where
                 self.size.unwrap_or(renderer.default_size()),
                 &self.placeholder,
                 &self.value,
-                &self.state,
+                &state,
+                caret,
+                &self.misspellings,
             )
         }
     }
@@ -382,6 +917,82 @@ where
         self.padding.hash(state);
         self.size.hash(state);
     }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.state.diff(tree);
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        focusables.push(Focusable {
+            path: path.clone(),
+            is_focused: self.state.borrow().is_focused,
+            uses_tab: false,
+        });
+    }
+
+    fn captures_mouse(&self) -> bool {
+        self.state.borrow().is_dragging
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if self.mask.is_some() {
+            return None;
+        }
+
+        let TextInput {
+            state,
+            value,
+            size,
+            on_change,
+            misspellings,
+            ..
+        } = self;
+
+        let (range, position) = state.borrow().menu.clone()?;
+
+        let misspelling = misspellings
+            .iter()
+            .find(|misspelling| misspelling.range == range)
+            .filter(|misspelling| !misspelling.suggestions.is_empty())?;
+
+        let bounds = layout.bounds();
+
+        Some(overlay::Element::new(
+            Point::new(bounds.x + position.x, bounds.y + position.y),
+            Box::new(Suggestions::new(
+                &misspelling.suggestions,
+                *size,
+                bounds.height,
+                move |suggestion, messages| {
+                    let mut state = state.borrow_mut();
+
+                    let range = match state.menu.take() {
+                        Some((range, _)) => range,
+                        None => return,
+                    };
+
+                    if let Some(suggestion) = suggestion {
+                        let replacement = Value::new(suggestion);
+                        let end = range.start + replacement.len();
+
+                        state.record(value, Edit::Other);
+                        value.replace(range, replacement);
+                        state.move_cursor_to(end);
+                        state.is_focused = true;
+
+                        messages.push(on_change(value.to_string()));
+                    }
+                },
+            )),
+        ))
+    }
 }
 
 /// The renderer of a [`TextInput`].
@@ -397,11 +1008,23 @@ pub trait Renderer: crate::Renderer + Sized {
     /// [`TextInput`]: struct.TextInput.html
     fn default_size(&self) -> u16;
 
+    /// Returns the default [`Caret`] of the [`TextInput`].
+    ///
+    /// [`Caret`]: struct.Caret.html
+    /// [`TextInput`]: struct.TextInput.html
+    fn default_caret(&self) -> Caret;
+
     /// Returns the width of the value of the [`TextInput`].
     ///
     /// [`TextInput`]: struct.TextInput.html
     fn measure_value(&self, value: &str, size: u16) -> f32;
 
+    /// Returns the distance from the top of the text of the [`TextInput`]
+    /// to the baseline of its value.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    fn baseline(&self, size: u16) -> f32;
+
     /// Returns the current horizontal offset of the value of the
     /// [`TextInput`].
     ///
@@ -427,10 +1050,15 @@ pub trait Renderer: crate::Renderer + Sized {
     /// - the placeholder to show when the value is empty
     /// - the current [`Value`]
     /// - the current [`State`]
+    /// - the [`Caret`] to draw, if it is currently visible
+    /// - the [`Misspelling`]s of the [`Value`], which should be underlined
     ///
     /// [`TextInput`]: struct.TextInput.html
     /// [`Value`]: struct.Value.html
     /// [`State`]: struct.State.html
+    /// [`Caret`]: struct.Caret.html
+    /// [`Misspelling`]: struct.Misspelling.html
+    #[allow(clippy::too_many_arguments)]
     fn draw(
         &mut self,
         bounds: Rectangle,
@@ -440,6 +1068,21 @@ pub trait Renderer: crate::Renderer + Sized {
         placeholder: &str,
         value: &Value,
         state: &State,
+        caret: Option<Caret>,
+        misspellings: &[Misspelling],
+    ) -> Self::Output;
+
+    /// Draws the [`Menu`] of suggestions of a [`Misspelling`].
+    ///
+    /// The [`Menu`] floats over the user interface, so it is drawn on its
+    /// own, after the rest of the widgets.
+    ///
+    /// [`Menu`]: struct.Menu.html
+    /// [`Misspelling`]: struct.Misspelling.html
+    fn draw_menu(
+        &mut self,
+        cursor_position: Point,
+        menu: &Menu<'_>,
     ) -> Self::Output;
 }
 
@@ -463,7 +1106,17 @@ where
 pub struct State {
     is_focused: bool,
     is_pasting: Option<Value>,
+    is_dragging: bool,
+    is_shift_pressed: bool,
     cursor_position: usize,
+    selection_anchor: Option<usize>,
+    last_click: Option<(Instant, usize, usize)>,
+    undo_stack: Vec<Snapshot>,
+    redo_stack: Vec<Snapshot>,
+    last_edit: Option<Edit>,
+    menu: Option<(Range<usize>, Vector)>,
+    last_activity: Option<Instant>,
+    preedit: Option<String>,
     // TODO: Add stateful horizontal scrolling offset
 }
 
@@ -483,8 +1136,9 @@ impl State {
 
         Self {
             is_focused: true,
-            is_pasting: None,
             cursor_position: usize::MAX,
+            last_activity: Some(Instant::now()),
+            ..Self::default()
         }
     }
 
@@ -502,17 +1156,30 @@ impl State {
         self.cursor_position.min(value.len())
     }
 
-    /// Moves the cursor of a [`TextInput`] to the left.
+    /// Returns the range of graphemes selected in a [`TextInput`], if any.
     ///
     /// [`TextInput`]: struct.TextInput.html
-    pub(crate) fn move_cursor_left(&mut self, value: &Value) {
-        let current = self.cursor_position(value);
+    pub fn selection(&self, value: &Value) -> Option<Range<usize>> {
+        let cursor_position = self.cursor_position(value);
+        let anchor = self.selection_anchor?.min(value.len());
 
-        if current > 0 {
-            self.cursor_position = current - 1;
+        if anchor == cursor_position {
+            None
+        } else {
+            Some(anchor.min(cursor_position)..anchor.max(cursor_position))
         }
     }
 
+    /// Returns the text being composed with an input method editor in a
+    /// [`TextInput`], if any.
+    ///
+    /// It is not part of the value yet, but it is displayed at the cursor.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn preedit(&self) -> Option<&str> {
+        self.preedit.as_deref()
+    }
+
     /// Moves the cursor of a [`TextInput`] to the right.
     ///
     /// [`TextInput`]: struct.TextInput.html
@@ -533,29 +1200,152 @@ impl State {
         }
     }
 
-    /// Moves the cursor of a [`TextInput`] to the previous start of a word.
+    /// Moves the cursor of a [`TextInput`] to the given grapheme `index`
+    /// after an edit, clearing the selection.
     ///
     /// [`TextInput`]: struct.TextInput.html
-    pub(crate) fn move_cursor_left_by_words(&mut self, value: &Value) {
-        let current = self.cursor_position(value);
+    pub(crate) fn move_cursor_to(&mut self, index: usize) {
+        self.cursor_position = index;
+        self.selection_anchor = None;
+    }
 
-        self.cursor_position = value.previous_start_of_word(current);
+    /// Moves the cursor of a [`TextInput`] to the given grapheme `index`,
+    /// extending the selection to it or clearing it.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub(crate) fn move_to(
+        &mut self,
+        value: &Value,
+        index: usize,
+        is_extended: bool,
+    ) {
+        if !is_extended {
+            self.selection_anchor = None;
+        } else if self.selection_anchor.is_none() {
+            self.selection_anchor = Some(self.cursor_position(value));
+        }
+
+        self.cursor_position = index;
+        self.last_edit = None;
     }
 
-    /// Moves the cursor of a [`TextInput`] to the next end of a word.
+    /// Selects the graphemes of a [`TextInput`] between `start` and `end`,
+    /// leaving the cursor at the `end`.
     ///
     /// [`TextInput`]: struct.TextInput.html
-    pub(crate) fn move_cursor_right_by_words(&mut self, value: &Value) {
-        let current = self.cursor_position(value);
+    pub(crate) fn select(&mut self, start: usize, end: usize) {
+        self.selection_anchor = Some(start);
+        self.cursor_position = end;
+        self.last_edit = None;
+    }
+
+    /// Removes the selected graphemes of a [`TextInput`], if any, leaving the
+    /// cursor where they were.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub(crate) fn delete_selection(&mut self, value: &mut Value) {
+        if let Some(selection) = self.selection(value) {
+            value.replace(selection.clone(), Value::new(""));
+            self.cursor_position = selection.start;
+        }
+
+        self.selection_anchor = None;
+    }
+
+    /// Remembers the current value of a [`TextInput`] before an edit, so it
+    /// can be undone.
+    ///
+    /// Consecutive edits of the same kind, like typing a word, are undone
+    /// together.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    fn record(&mut self, value: &Value, edit: Edit) {
+        let is_grouped = edit != Edit::Other
+            && self.last_edit == Some(edit)
+            && self.selection(value).is_none();
+
+        if !is_grouped {
+            self.undo_stack.push(Snapshot::new(value, self));
+
+            if self.undo_stack.len() > HISTORY_LIMIT {
+                let _ = self.undo_stack.remove(0);
+            }
+        }
 
-        self.cursor_position = value.next_end_of_word(current);
+        self.redo_stack.clear();
+        self.last_edit = Some(edit);
     }
 
-    /// Moves the cursor of a [`TextInput`] to the end.
+    /// Restores the value of a [`TextInput`] before the last edit, and
+    /// returns whether there was one.
     ///
     /// [`TextInput`]: struct.TextInput.html
-    pub(crate) fn move_cursor_to_end(&mut self, value: &Value) {
-        self.cursor_position = value.len();
+    fn undo(&mut self, value: &mut Value) -> bool {
+        match self.undo_stack.pop() {
+            Some(snapshot) => {
+                self.redo_stack.push(Snapshot::new(value, self));
+                self.restore(value, snapshot);
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Restores the value of a [`TextInput`] before the last undo, and
+    /// returns whether there was one.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    fn redo(&mut self, value: &mut Value) -> bool {
+        match self.redo_stack.pop() {
+            Some(snapshot) => {
+                self.undo_stack.push(Snapshot::new(value, self));
+                self.restore(value, snapshot);
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn restore(&mut self, value: &mut Value, snapshot: Snapshot) {
+        *value = Value::new(&snapshot.value);
+
+        self.move_cursor_to(snapshot.cursor_position);
+        self.last_edit = None;
+    }
+}
+
+/// The most edits of a [`TextInput`] that can be undone.
+///
+/// [`TextInput`]: struct.TextInput.html
+const HISTORY_LIMIT: usize = 100;
+
+/// The kind of an edit of a [`TextInput`].
+///
+/// [`TextInput`]: struct.TextInput.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Edit {
+    Insert,
+    Delete,
+    Other,
+}
+
+/// The value of a [`TextInput`] at some point of its history.
+///
+/// [`TextInput`]: struct.TextInput.html
+#[derive(Debug, Clone)]
+struct Snapshot {
+    value: String,
+    cursor_position: usize,
+}
+
+impl Snapshot {
+    fn new(value: &Value, state: &State) -> Self {
+        Snapshot {
+            value: value.to_string(),
+            cursor_position: state.cursor_position(value),
+        }
     }
 }
 
@@ -642,6 +1432,40 @@ impl Value {
         Self { graphemes }
     }
 
+    /// Returns a new [`Value`] containing the graphemes from `start` until
+    /// `end`.
+    ///
+    /// [`Value`]: struct.Value.html
+    pub fn select(&self, start: usize, end: usize) -> Self {
+        let end = end.min(self.len());
+        let graphemes = self.graphemes[start.min(end)..end].to_vec();
+
+        Self { graphemes }
+    }
+
+    /// Returns the range of graphemes of the word at the given grapheme
+    /// `index`, or of the whitespace or punctuation between words.
+    ///
+    /// [`Value`]: struct.Value.html
+    pub fn word_at(&self, index: usize) -> Range<usize> {
+        let string = self.to_string();
+        let mut start = 0;
+
+        for word in UnicodeSegmentation::split_word_bounds(&string as &str) {
+            let end =
+                start + UnicodeSegmentation::graphemes(word, true).count();
+
+            // The end of the value belongs to its last word
+            if index < end || end == self.len() {
+                return start..end;
+            }
+
+            start = end;
+        }
+
+        start..start
+    }
+
     /// Converts the [`Value`] into a `String`.
     ///
     /// [`Value`]: struct.Value.html
@@ -666,6 +1490,17 @@ impl Value {
             .splice(index..index, value.graphemes.drain(..));
     }
 
+    /// Replaces the graphemes in the given `range` with the graphemes of
+    /// another [`Value`].
+    ///
+    /// [`Value`]: struct.Value.html
+    pub fn replace(&mut self, range: Range<usize>, mut value: Value) {
+        let start = range.start.min(self.len());
+        let end = range.end.clamp(start, self.len());
+
+        let _ = self.graphemes.splice(start..end, value.graphemes.drain(..));
+    }
+
     /// Removes the grapheme at the given `index`.
     ///
     /// [`Value`]: struct.Value.html
@@ -678,14 +1513,208 @@ impl Value {
     ///
     /// [`Value`]: struct.Value.html
     pub fn secure(&self) -> Self {
+        self.mask(&|_, _| String::from("•"))
+    }
+
+    /// Returns a new [`Value`] with every grapheme replaced with the result
+    /// of the given function, which receives its index and its content.
+    ///
+    /// [`Value`]: struct.Value.html
+    pub fn mask(&self, mask: &dyn Fn(usize, &str) -> String) -> Self {
         Self {
-            graphemes: std::iter::repeat(String::from("•"))
-                .take(self.graphemes.len())
+            graphemes: self
+                .graphemes
+                .iter()
+                .enumerate()
+                .map(|(i, grapheme)| mask(i, grapheme))
                 .collect(),
         }
     }
 }
 
+/// Returns the [`Value`] as it is displayed by a [`TextInput`].
+///
+/// [`Value`]: struct.Value.html
+/// [`TextInput`]: struct.TextInput.html
+fn masked(value: &Value, mask: &Option<Mask>) -> Value {
+    match mask {
+        Some(mask) => value.mask(mask),
+        None => value.clone(),
+    }
+}
+
+const MENU_PADDING: f32 = 5.0;
+
+fn misspelling_at(
+    misspellings: &[Misspelling],
+    index: usize,
+) -> Option<&Misspelling> {
+    misspellings.iter().find(|misspelling| {
+        misspelling.range.start <= index && index <= misspelling.range.end
+    })
+}
+
+/// The menu of suggestions of a misspelled word, floating below it.
+///
+/// It is shared by the text widgets that accept misspellings. Any mouse
+/// press calls `on_press` with the suggestion under the cursor, if any, so
+/// the owner can close the menu and replace the word.
+pub(crate) struct Suggestions<'a, Message> {
+    suggestions: &'a [String],
+    size: Option<u16>,
+    line_height: f32,
+    on_press: OnPress<'a, Message>,
+}
+
+impl<'a, Message> Suggestions<'a, Message> {
+    /// Creates the menu of some suggestions for a misspelled word of the
+    /// given line height, placed right below it.
+    pub(crate) fn new<F>(
+        suggestions: &'a [String],
+        size: Option<u16>,
+        line_height: f32,
+        on_press: F,
+    ) -> Self
+    where
+        F: 'a + FnMut(Option<&str>, &mut Vec<Message>),
+    {
+        Suggestions {
+            suggestions,
+            size,
+            line_height,
+            on_press: Box::new(on_press),
+        }
+    }
+
+    fn menu(&self, bounds: Rectangle, size: u16) -> Menu<'a> {
+        Menu {
+            bounds,
+            suggestions: self.suggestions,
+            item_height: f32::from(size) + 2.0 * MENU_PADDING,
+            text_size: size,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Suggestions<'a, Message>
+where
+    Renderer: self::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let size = self.size.unwrap_or(renderer.default_size());
+
+        let width = self
+            .suggestions
+            .iter()
+            .map(|suggestion| renderer.measure_value(suggestion, size))
+            .fold(0.0, f32::max)
+            + 2.0 * MENU_PADDING;
+
+        let height = (f32::from(size) + 2.0 * MENU_PADDING)
+            * self.suggestions.len() as f32;
+
+        // Open upwards when the menu does not fit below the word, as long
+        // as it fits above
+        let above = position.y - self.line_height - height;
+
+        let y = if position.y + height > bounds.height && above >= 0.0 {
+            above
+        } else {
+            position.y
+        };
+
+        let mut node = layout::Node::new(Size::new(width, height));
+        node.move_to(Point::new(
+            position.x.min(bounds.width - width).max(0.0),
+            y,
+        ));
+
+        node
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let size = self.size.unwrap_or(renderer.default_size());
+        let menu = self.menu(layout.bounds(), size);
+
+        renderer.draw_menu(cursor_position, &menu)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> overlay::Status {
+        match event {
+            Event::Mouse(mouse::Event::Input {
+                state: ButtonState::Pressed,
+                ..
+            }) => {
+                let size = self.size.unwrap_or(renderer.default_size());
+                let menu = self.menu(layout.bounds(), size);
+
+                let suggestion = menu
+                    .item_at(cursor_position)
+                    .map(|index| self.suggestions[index].as_str());
+
+                (self.on_press)(suggestion, messages);
+
+                if suggestion.is_some() {
+                    overlay::Status::Captured
+                } else {
+                    overlay::Status::Ignored
+                }
+            }
+            _ => overlay::Status::Ignored,
+        }
+    }
+}
+
+/// Returns the grapheme index of the caret closest to the given horizontal
+/// position in a [`TextInput`].
+///
+/// [`TextInput`]: struct.TextInput.html
+fn grapheme_at<Renderer: self::Renderer>(
+    renderer: &Renderer,
+    layout: Layout<'_>,
+    size: u16,
+    value: &Value,
+    state: &State,
+    x: f32,
+) -> usize {
+    let text_bounds = layout.children().next().unwrap().bounds();
+    let target = x - text_bounds.x;
+
+    if target > 0.0 {
+        let offset = renderer.offset(text_bounds, size, value, state);
+
+        find_cursor_position(
+            renderer,
+            target + offset,
+            value,
+            size,
+            0,
+            value.len(),
+        )
+    } else {
+        0
+    }
+}
+
 // TODO: Reduce allocations
 fn find_cursor_position<Renderer: self::Renderer>(
     renderer: &Renderer,
@@ -739,7 +1768,7 @@ fn find_cursor_position<Renderer: self::Renderer>(
     }
 }
 
-mod platform {
+pub(crate) mod platform {
     use crate::input::keyboard;
 
     pub fn is_jump_modifier_pressed(
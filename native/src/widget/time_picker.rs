@@ -0,0 +1,744 @@
+//! Let your users pick a time of the day.
+//!
+//! A [`TimePicker`] has some local [`State`].
+//!
+//! [`TimePicker`]: struct.TimePicker.html
+//! [`State`]: struct.State.html
+use crate::{
+    date_picker::Locale,
+    input::{keyboard, mouse, ButtonState},
+    layout, text,
+    tree::{self, Tree},
+    Clipboard, Element, Event, Focus, Focusable, Font, Hasher, Layout, Length,
+    Point, Rectangle, Size, Widget,
+};
+
+use std::fmt;
+use std::hash::Hash;
+
+const MINUTES_PER_DAY: i32 = 24 * 60;
+
+/// A field showing the hour and minute of a [`Time`], letting your users
+/// change them.
+///
+/// Clicking the hour, the minute, or the period of the day selects it. The
+/// selected part changes with the up and down arrows or the mouse wheel, and
+/// the left and right arrows select the part next to it. Digits can also be
+/// typed directly, moving on to the minute once the hour is complete.
+///
+/// Hours are shown from 1 to 12, followed by the period of the day, if the
+/// [`Locale`] uses a 12-hour clock.
+///
+/// # Example
+/// ```
+/// # use iced_native::date_picker::Locale;
+/// # use iced_native::time_picker::{self, Time};
+/// # use iced_native::TimePicker;
+/// #
+/// #[derive(Debug)]
+/// enum Message {
+///     AlarmChanged(Time),
+/// }
+///
+/// let mut state = time_picker::State::new();
+/// let alarm = Time::new(7, 30).unwrap();
+///
+/// let time_picker =
+///     TimePicker::new(&mut state, alarm, Message::AlarmChanged)
+///         .step(15)
+///         .locale(Locale::DE);
+/// ```
+///
+/// [`Time`]: struct.Time.html
+/// [`Locale`]: ../date_picker/struct.Locale.html
+#[allow(missing_debug_implementations)]
+pub struct TimePicker<'a, Message> {
+    state: tree::State<'a, State>,
+    value: Time,
+    on_change: Box<dyn Fn(Time) -> Message + 'a>,
+    step: u8,
+    min: Option<Time>,
+    max: Option<Time>,
+    locale: Locale,
+    size: Option<u16>,
+    padding: u16,
+}
+
+impl<'a, Message> TimePicker<'a, Message> {
+    /// Creates a new [`TimePicker`] with some local [`State`], the current
+    /// [`Time`], and a function producing a message when it changes.
+    ///
+    /// [`TimePicker`]: struct.TimePicker.html
+    /// [`State`]: struct.State.html
+    /// [`Time`]: struct.Time.html
+    pub fn new<F>(state: &'a mut State, value: Time, on_change: F) -> Self
+    where
+        F: 'a + Fn(Time) -> Message,
+    {
+        Self::with_state(tree::State::Borrowed(state), value, on_change)
+    }
+
+    /// Creates a new [`TimePicker`] whose local [`State`] is managed by the
+    /// runtime.
+    ///
+    /// [`TimePicker`]: struct.TimePicker.html
+    /// [`State`]: struct.State.html
+    pub fn managed<F>(value: Time, on_change: F) -> Self
+    where
+        F: 'a + Fn(Time) -> Message,
+    {
+        Self::with_state(tree::State::managed(), value, on_change)
+    }
+
+    fn with_state<F>(
+        state: tree::State<'a, State>,
+        value: Time,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'a + Fn(Time) -> Message,
+    {
+        TimePicker {
+            state,
+            value,
+            on_change: Box::new(on_change),
+            step: 1,
+            min: None,
+            max: None,
+            locale: Locale::default(),
+            size: None,
+            padding: 5,
+        }
+    }
+
+    /// Sets the amount of minutes added or removed when the minute changes
+    /// with the arrows or the mouse wheel.
+    ///
+    /// Typed minutes are kept as they are.
+    pub fn step(mut self, minutes: u8) -> Self {
+        self.step = minutes.clamp(1, 30);
+        self
+    }
+
+    /// Sets the earliest [`Time`] that can be picked.
+    ///
+    /// [`Time`]: struct.Time.html
+    pub fn min(mut self, time: Time) -> Self {
+        self.min = Some(time);
+        self
+    }
+
+    /// Sets the latest [`Time`] that can be picked.
+    ///
+    /// [`Time`]: struct.Time.html
+    pub fn max(mut self, time: Time) -> Self {
+        self.max = Some(time);
+        self
+    }
+
+    /// Sets the [`Locale`] of the [`TimePicker`], deciding whether it uses a
+    /// 12-hour clock.
+    ///
+    /// [`Locale`]: ../date_picker/struct.Locale.html
+    /// [`TimePicker`]: struct.TimePicker.html
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Sets the text size of the [`TimePicker`].
+    ///
+    /// [`TimePicker`]: struct.TimePicker.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the padding of the [`TimePicker`].
+    ///
+    /// [`TimePicker`]: struct.TimePicker.html
+    pub fn padding(mut self, units: u16) -> Self {
+        self.padding = units;
+        self
+    }
+
+    fn segments(&self) -> &'static [Segment] {
+        if self.locale.is_12_hour {
+            &[Segment::Hour, Segment::Minute, Segment::Period]
+        } else {
+            &[Segment::Hour, Segment::Minute]
+        }
+    }
+
+    fn hours(&self) -> (u8, u8) {
+        if self.locale.is_12_hour {
+            (1, 12)
+        } else {
+            (0, 23)
+        }
+    }
+
+    fn clamp(&self, time: Time) -> Time {
+        let time = self.min.map_or(time, |min| time.max(min));
+
+        self.max.map_or(time, |max| time.min(max))
+    }
+
+    // The changed value after moving a segment up or down
+    fn adjust(&self, segment: Segment, direction: i32) -> Time {
+        let minutes = self.value.minutes();
+
+        match segment {
+            Segment::Hour => Time::from_minutes(minutes + direction * 60),
+            Segment::Minute => {
+                let step = i32::from(self.step);
+                let minute = i32::from(self.value.minute);
+
+                // Moving snaps the minute to a multiple of the step
+                let target = if direction > 0 {
+                    (minute / step + 1) * step
+                } else if minute % step != 0 {
+                    minute / step * step
+                } else {
+                    minute - step
+                };
+
+                Time {
+                    minute: if target >= 60 {
+                        0
+                    } else if target < 0 {
+                        (59 / step * step) as u8
+                    } else {
+                        target as u8
+                    },
+                    ..self.value
+                }
+            }
+            Segment::Period => Time::from_minutes(minutes + 12 * 60),
+        }
+    }
+
+    // The changed value after typing a digit, if it is part of a number
+    // for the segment, along with whether the segment is complete
+    fn type_digit(
+        &self,
+        segment: Segment,
+        typed: Option<u8>,
+        digit: u8,
+    ) -> Option<(Time, bool)> {
+        let (low, high) = match segment {
+            Segment::Hour => self.hours(),
+            Segment::Minute => (0, 59),
+            Segment::Period => return None,
+        };
+
+        // A digit that cannot follow the typed one starts a new number
+        let (number, is_complete) = match typed {
+            Some(first) if first * 10 + digit <= high => {
+                (first * 10 + digit, true)
+            }
+            _ => (digit, digit * 10 > high),
+        };
+
+        if number < low {
+            return if is_complete {
+                None
+            } else {
+                Some((self.value, false))
+            };
+        }
+
+        let time = match segment {
+            Segment::Hour if self.locale.is_12_hour => Time {
+                hour: number % 12 + if self.value.hour >= 12 { 12 } else { 0 },
+                ..self.value
+            },
+            Segment::Hour => Time {
+                hour: number,
+                ..self.value
+            },
+            _ => Time {
+                minute: number,
+                ..self.value
+            },
+        };
+
+        Some((time, is_complete))
+    }
+
+    fn fields<Renderer>(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+    ) -> (Vec<(Segment, Rectangle)>, Rectangle)
+    where
+        Renderer: self::Renderer,
+    {
+        let text_size = self.size.unwrap_or(renderer.default_size());
+        let padding = f32::from(self.padding);
+
+        let measure = |content: &str| {
+            let (width, _) = renderer.measure(
+                content,
+                text_size,
+                Font::Default,
+                text::Params::default(),
+                Size::INFINITY,
+            );
+
+            width
+        };
+
+        let digits = measure("00");
+        let separator_width = measure(":");
+
+        let mut x = bounds.x + padding;
+        let y = bounds.y + padding;
+        let height = f32::from(text_size);
+
+        let mut fields = Vec::with_capacity(3);
+        let mut separator = Rectangle::default();
+
+        for segment in self.segments() {
+            let width = match segment {
+                Segment::Period => {
+                    // The period is separated by a space instead
+                    x += f32::from(text_size) / 2.0;
+
+                    self.locale
+                        .periods
+                        .iter()
+                        .map(|period| measure(period))
+                        .fold(0.0, f32::max)
+                }
+                _ => digits,
+            };
+
+            fields.push((
+                *segment,
+                Rectangle {
+                    x,
+                    y,
+                    width,
+                    height,
+                },
+            ));
+
+            x += width;
+
+            if *segment == Segment::Hour {
+                separator = Rectangle {
+                    x,
+                    y,
+                    width: separator_width,
+                    height,
+                };
+
+                x += separator_width;
+            }
+        }
+
+        (fields, separator)
+    }
+
+    fn content(&self, segment: Segment) -> String {
+        match segment {
+            Segment::Hour if self.locale.is_12_hour => {
+                format!("{:02}", (self.value.hour + 11) % 12 + 1)
+            }
+            Segment::Hour => format!("{:02}", self.value.hour),
+            Segment::Minute => format!("{:02}", self.value.minute),
+            Segment::Period => self.locale.periods
+                [usize::from(self.value.hour >= 12)]
+            .to_owned(),
+        }
+    }
+}
+
+/// The local state of a [`TimePicker`].
+///
+/// [`TimePicker`]: struct.TimePicker.html
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct State {
+    selected: Option<Segment>,
+    typed: Option<u8>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the [`TimePicker`] has the focus.
+    ///
+    /// [`TimePicker`]: struct.TimePicker.html
+    pub fn is_focused(&self) -> bool {
+        self.selected.is_some()
+    }
+
+    fn select(&mut self, segment: Option<Segment>) {
+        self.selected = segment;
+        self.typed = None;
+    }
+}
+
+// A part of the time that can be selected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Segment {
+    Hour,
+    Minute,
+    Period,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for TimePicker<'a, Message>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        _limits: &layout::Limits,
+    ) -> layout::Node {
+        let (fields, _) = self.fields(renderer, Rectangle::default());
+        let padding = f32::from(self.padding);
+
+        let (_, last) = fields.last().unwrap();
+
+        layout::Node::new(Size::new(
+            last.x + last.width + padding,
+            last.height + 2.0 * padding,
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+        let (fields, _) = self.fields(renderer, bounds);
+
+        let hovered = fields
+            .iter()
+            .find(|(_, field)| {
+                field.x <= cursor_position.x
+                    && cursor_position.x < field.x + field.width
+            })
+            .map(|(segment, _)| *segment)
+            .filter(|_| bounds.contains(cursor_position));
+
+        let mut state = *self.state.borrow();
+        let mut value = None;
+
+        match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) => {
+                if bounds.contains(cursor_position) {
+                    state.select(
+                        hovered.or(state.selected).or(Some(Segment::Hour)),
+                    );
+                } else {
+                    state.select(None);
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { pixels, .. }) => {
+                if let Some(segment) = hovered {
+                    if pixels.y != 0.0 {
+                        let direction = if pixels.y > 0.0 { 1 } else { -1 };
+
+                        value = Some(self.adjust(segment, direction));
+                    }
+                }
+            }
+            Event::Focus(focus) if layout.is_focus_target() => {
+                state.select(match focus {
+                    Focus::Gained => Some(Segment::Hour),
+                    Focus::Lost => None,
+                });
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code,
+                state: ButtonState::Pressed,
+                ..
+            }) => {
+                if let Some(segment) = state.selected {
+                    let segments = self.segments();
+                    let index = segments
+                        .iter()
+                        .position(|candidate| *candidate == segment)
+                        .unwrap_or(0);
+
+                    match key_code {
+                        keyboard::KeyCode::Up => {
+                            value = Some(self.adjust(segment, 1));
+                            state.typed = None;
+                        }
+                        keyboard::KeyCode::Down => {
+                            value = Some(self.adjust(segment, -1));
+                            state.typed = None;
+                        }
+                        keyboard::KeyCode::Left if index > 0 => {
+                            state.select(Some(segments[index - 1]));
+                        }
+                        keyboard::KeyCode::Right
+                            if index + 1 < segments.len() =>
+                        {
+                            state.select(Some(segments[index + 1]));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(c)) => {
+                match (state.selected, c.to_digit(10)) {
+                    (Some(Segment::Period), _) => {
+                        let is_after_noon = self.value.hour >= 12;
+
+                        let period =
+                            self.locale.periods.iter().position(|period| {
+                                period.chars().next().map(|first| {
+                                    first.to_lowercase().eq(c.to_lowercase())
+                                }) == Some(true)
+                            });
+
+                        if let Some(period) = period {
+                            if (period == 1) != is_after_noon {
+                                value = Some(self.adjust(Segment::Period, 1));
+                            }
+                        }
+                    }
+                    (Some(segment), Some(digit)) => {
+                        match self.type_digit(segment, state.typed, digit as u8)
+                        {
+                            Some((time, true)) => {
+                                value = Some(time);
+
+                                let segments = self.segments();
+
+                                state.select(
+                                    segments
+                                        .iter()
+                                        .skip_while(|candidate| {
+                                            **candidate != segment
+                                        })
+                                        .nth(1)
+                                        .copied()
+                                        .or(Some(segment)),
+                                );
+                            }
+                            Some((time, false)) => {
+                                value = Some(time);
+                                state.typed = Some(digit as u8);
+                            }
+                            None => {
+                                state.typed = None;
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+
+        *self.state.borrow_mut() = state;
+
+        if let Some(time) = value.map(|time| self.clamp(time)) {
+            if time != self.value {
+                messages.push((self.on_change)(time));
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let text_size = self.size.unwrap_or(renderer.default_size());
+        let state = self.state.borrow();
+
+        let (fields, separator) = self.fields(renderer, bounds);
+
+        let fields: Vec<Field> = fields
+            .into_iter()
+            .map(|(segment, bounds)| Field {
+                bounds,
+                content: self.content(segment),
+                is_selected: state.selected == Some(segment),
+            })
+            .collect();
+
+        self::Renderer::draw(
+            renderer,
+            bounds,
+            cursor_position,
+            &fields,
+            separator,
+            text_size,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<TimePicker<'static, ()>>().hash(state);
+
+        self.size.hash(state);
+        self.padding.hash(state);
+        self.locale.is_12_hour.hash(state);
+        self.locale.periods.hash(state);
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.state.diff(tree);
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        focusables.push(Focusable {
+            path: path.clone(),
+            is_focused: self.state.borrow().is_focused(),
+            uses_tab: false,
+        });
+    }
+}
+
+/// A time of the day, with a precision of minutes.
+///
+/// Times are ordered chronologically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time {
+    /// The hour of the [`Time`], from `0` to `23`
+    ///
+    /// [`Time`]: struct.Time.html
+    pub hour: u8,
+
+    /// The minute of the [`Time`], from `0` to `59`
+    ///
+    /// [`Time`]: struct.Time.html
+    pub minute: u8,
+}
+
+impl Time {
+    /// Midnight, the start of the day.
+    pub const MIDNIGHT: Time = Time { hour: 0, minute: 0 };
+
+    /// Creates a new [`Time`], if it exists.
+    ///
+    /// [`Time`]: struct.Time.html
+    pub fn new(hour: u8, minute: u8) -> Option<Time> {
+        if hour < 24 && minute < 60 {
+            Some(Time { hour, minute })
+        } else {
+            None
+        }
+    }
+
+    // The amount of minutes since midnight
+    fn minutes(self) -> i32 {
+        i32::from(self.hour) * 60 + i32::from(self.minute)
+    }
+
+    // Wraps around midnight
+    fn from_minutes(minutes: i32) -> Time {
+        let minutes = minutes.rem_euclid(MINUTES_PER_DAY);
+
+        Time {
+            hour: (minutes / 60) as u8,
+            minute: (minutes % 60) as u8,
+        }
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+/// A part of a [`TimePicker`], ready to be drawn.
+///
+/// [`TimePicker`]: struct.TimePicker.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    /// The bounds of the [`Field`].
+    ///
+    /// [`Field`]: struct.Field.html
+    pub bounds: Rectangle,
+
+    /// The content of the [`Field`], like the hour or the period of the
+    /// day.
+    ///
+    /// [`Field`]: struct.Field.html
+    pub content: String,
+
+    /// Whether the [`Field`] is selected.
+    ///
+    /// [`Field`]: struct.Field.html
+    pub is_selected: bool,
+}
+
+/// The renderer of a [`TimePicker`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`TimePicker`] in your user interface.
+///
+/// [`TimePicker`]: struct.TimePicker.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer {
+    /// Draws a [`TimePicker`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`TimePicker`]
+    ///   * the cursor position
+    ///   * the [`Field`] of the hour, the minute, and the period of the day,
+    ///     if shown
+    ///   * the bounds of the separator between the hour and the minute
+    ///   * the text size
+    ///
+    /// [`TimePicker`]: struct.TimePicker.html
+    /// [`Field`]: struct.Field.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        fields: &[Field],
+        separator: Rectangle,
+        text_size: u16,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<TimePicker<'a, Message>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'static + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        time_picker: TimePicker<'a, Message>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(time_picker)
+    }
+}
@@ -0,0 +1,799 @@
+//! Arrange items that span ranges of time in tracks.
+//!
+//! A [`Timeline`] has some local [`State`].
+//!
+//! [`Timeline`]: struct.Timeline.html
+//! [`State`]: struct.State.html
+use crate::{
+    input::{mouse, ButtonState},
+    layout,
+    tree::{self, Tree},
+    Clipboard, Color, Element, Event, Hasher, Layout, Length, Point, Rectangle,
+    Size, Vector, Widget,
+};
+
+use std::hash::Hash;
+
+const EDGE_WIDTH: f32 = 6.0;
+const SNAP_DISTANCE: f32 = 8.0;
+const MIN_TICK_SPACING: f32 = 80.0;
+
+/// A horizontal time axis with tracks of items that can be selected, moved,
+/// and resized.
+///
+/// The time axis can be zoomed with the mouse wheel and panned by dragging
+/// the empty space between items or by scrolling horizontally. Only the items
+/// inside the visible range of time are drawn, so a [`Timeline`] can hold
+/// lots of them.
+///
+/// Time is measured in arbitrary units, like seconds or days. Moving and
+/// resizing items snaps them to the edges of nearby items and, optionally, to
+/// a grid.
+///
+/// # Example
+/// ```
+/// # use iced_native::{timeline, Color, Timeline};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Selected(timeline::Id),
+///     Moved(timeline::Id, f32),
+///     Resized(timeline::Id, f32, f32),
+/// }
+///
+/// let state = &mut timeline::State::new();
+///
+/// let video = timeline::Track::new("Video")
+///     .push(timeline::Item::new(0.0, 4.5, "Intro"))
+///     .push(timeline::Item::new(4.5, 12.0, "Interview"));
+///
+/// let audio = timeline::Track::new("Audio").push(
+///     timeline::Item::new(0.0, 12.0, "Music").color(Color::from_rgb(0.2, 0.6, 0.3)),
+/// );
+///
+/// let timeline = Timeline::new(state, vec![video, audio])
+///     .snap(0.5)
+///     .on_select(Message::Selected)
+///     .on_move(Message::Moved)
+///     .on_resize(Message::Resized);
+/// ```
+///
+/// [`Timeline`]: struct.Timeline.html
+#[allow(missing_debug_implementations)]
+pub struct Timeline<'a, Message> {
+    state: tree::State<'a, State>,
+    tracks: Vec<Track>,
+    selection: Option<Id>,
+    width: Length,
+    height: Length,
+    track_height: u16,
+    snap: Option<f32>,
+    on_select: Option<Box<dyn Fn(Id) -> Message>>,
+    on_move: Option<Box<dyn Fn(Id, f32) -> Message>>,
+    #[allow(clippy::type_complexity)]
+    on_resize: Option<Box<dyn Fn(Id, f32, f32) -> Message>>,
+}
+
+impl<'a, Message> Timeline<'a, Message> {
+    /// Creates a new [`Timeline`] with some local [`State`] and the given
+    /// tracks.
+    ///
+    /// [`Timeline`]: struct.Timeline.html
+    /// [`State`]: struct.State.html
+    pub fn new(state: &'a mut State, tracks: Vec<Track>) -> Self {
+        Self::with_state(tree::State::Borrowed(state), tracks)
+    }
+
+    /// Creates a new [`Timeline`] with the given tracks, whose local
+    /// [`State`] is managed by the runtime.
+    ///
+    /// [`Timeline`]: struct.Timeline.html
+    /// [`State`]: struct.State.html
+    pub fn managed(tracks: Vec<Track>) -> Self {
+        Self::with_state(tree::State::managed(), tracks)
+    }
+
+    fn with_state(state: tree::State<'a, State>, tracks: Vec<Track>) -> Self {
+        Timeline {
+            state,
+            tracks,
+            selection: None,
+            width: Length::Fill,
+            height: Length::Shrink,
+            track_height: 40,
+            snap: None,
+            on_select: None,
+            on_move: None,
+            on_resize: None,
+        }
+    }
+
+    /// Sets the width of the [`Timeline`].
+    ///
+    /// [`Timeline`]: struct.Timeline.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Timeline`].
+    ///
+    /// By default, it fits all of its tracks.
+    ///
+    /// [`Timeline`]: struct.Timeline.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the height of each track of the [`Timeline`].
+    ///
+    /// [`Timeline`]: struct.Timeline.html
+    pub fn track_height(mut self, track_height: u16) -> Self {
+        self.track_height = track_height;
+        self
+    }
+
+    /// Sets the selected item of the [`Timeline`].
+    ///
+    /// [`Timeline`]: struct.Timeline.html
+    pub fn selection(mut self, selection: Option<Id>) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Snaps moved and resized items to a grid with the given step, in units
+    /// of time.
+    pub fn snap(mut self, step: f32) -> Self {
+        self.snap = Some(step).filter(|step| *step > 0.0);
+        self
+    }
+
+    /// Sets the message that should be produced when an item is pressed.
+    pub fn on_select<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(Id) -> Message,
+    {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that should be produced when an item is dragged.
+    ///
+    /// It receives the new start of the item.
+    pub fn on_move<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(Id, f32) -> Message,
+    {
+        self.on_move = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that should be produced when an edge of an item is
+    /// dragged.
+    ///
+    /// It receives the new start and end of the item.
+    pub fn on_resize<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(Id, f32, f32) -> Message,
+    {
+        self.on_resize = Some(Box::new(f));
+        self
+    }
+
+    fn item(&self, id: Id) -> Option<&Item> {
+        self.tracks.get(id.track)?.items.get(id.item)
+    }
+
+    fn item_at(
+        &self,
+        bounds: Rectangle,
+        ruler_height: f32,
+        state: &State,
+        position: Point,
+    ) -> Option<(Id, Grab)> {
+        let lane = (position.y - bounds.y - ruler_height)
+            / f32::from(self.track_height);
+
+        if lane < 0.0 {
+            return None;
+        }
+
+        let track = lane as usize;
+        let items = &self.tracks.get(track)?.items;
+
+        items.iter().enumerate().rev().find_map(|(index, item)| {
+            let start = state.x_of(bounds, item.start);
+            let end = state.x_of(bounds, item.end);
+
+            if position.x < start - EDGE_WIDTH / 2.0
+                || position.x > end + EDGE_WIDTH / 2.0
+            {
+                return None;
+            }
+
+            let grab = if (position.x - start).abs() <= EDGE_WIDTH / 2.0 {
+                Grab::Start
+            } else if (position.x - end).abs() <= EDGE_WIDTH / 2.0 {
+                Grab::End
+            } else if position.x > start && position.x < end {
+                Grab::Body {
+                    offset: state.time_at(bounds, position.x) - item.start,
+                }
+            } else {
+                return None;
+            };
+
+            Some((Id { track, item: index }, grab))
+        })
+    }
+
+    fn snap_time(&self, time: f32, zoom: f32, dragged: Id) -> (f32, f32) {
+        let edges = self.tracks.iter().enumerate().flat_map(|(track, t)| {
+            t.items
+                .iter()
+                .enumerate()
+                .filter(move |(item, _)| Id { track, item: *item } != dragged)
+                .flat_map(|(_, item)| vec![item.start, item.end])
+        });
+
+        let closest_edge =
+            edges.fold(None, |closest: Option<f32>, edge| match closest {
+                Some(closest)
+                    if (closest - time).abs() <= (edge - time).abs() =>
+                {
+                    Some(closest)
+                }
+                _ => Some(edge),
+            });
+
+        let snapped = match closest_edge {
+            Some(edge) if (edge - time).abs() * zoom <= SNAP_DISTANCE => edge,
+            _ => match self.snap {
+                Some(step) => (time / step).round() * step,
+                None => time,
+            },
+        };
+
+        (snapped, (snapped - time).abs())
+    }
+}
+
+/// The identifier of an item of a [`Timeline`].
+///
+/// [`Timeline`]: struct.Timeline.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Id {
+    /// The index of the track of the item.
+    pub track: usize,
+
+    /// The index of the item in its track.
+    pub item: usize,
+}
+
+/// A row of items of a [`Timeline`].
+///
+/// [`Timeline`]: struct.Timeline.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Track {
+    /// The label of the track.
+    pub label: String,
+
+    /// The items of the track.
+    pub items: Vec<Item>,
+}
+
+impl Track {
+    /// Creates a new empty [`Track`] with the given label.
+    ///
+    /// [`Track`]: struct.Track.html
+    pub fn new<T: Into<String>>(label: T) -> Self {
+        Track {
+            label: label.into(),
+            items: Vec::new(),
+        }
+    }
+
+    /// Adds an [`Item`] to the [`Track`].
+    ///
+    /// [`Item`]: struct.Item.html
+    /// [`Track`]: struct.Track.html
+    pub fn push(mut self, item: Item) -> Self {
+        self.items.push(item);
+        self
+    }
+}
+
+/// Something that spans a range of time in a [`Track`].
+///
+/// [`Track`]: struct.Track.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    /// The start of the item.
+    pub start: f32,
+
+    /// The end of the item.
+    pub end: f32,
+
+    /// The label of the item.
+    pub label: String,
+
+    /// The color of the item.
+    pub color: Color,
+}
+
+impl Item {
+    /// Creates a new [`Item`] spanning the given range of time.
+    ///
+    /// [`Item`]: struct.Item.html
+    pub fn new<T: Into<String>>(start: f32, end: f32, label: T) -> Self {
+        Item {
+            start: start.min(end),
+            end: start.max(end),
+            label: label.into(),
+            color: Color::from_rgb(0.20, 0.60, 0.86),
+        }
+    }
+
+    /// Sets the color of the [`Item`].
+    ///
+    /// [`Item`]: struct.Item.html
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = color.into();
+        self
+    }
+}
+
+/// The local state of a [`Timeline`].
+///
+/// [`Timeline`]: struct.Timeline.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct State {
+    offset: f32,
+    zoom: f32,
+    interaction: Interaction,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Interaction {
+    Idle,
+    Panning { cursor_x: f32, offset: f32 },
+    Dragging { id: Id, grab: Grab },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Grab {
+    Start,
+    End,
+    Body { offset: f32 },
+}
+
+impl State {
+    /// Creates a new [`State`], showing the time axis from `0` with `100`
+    /// pixels per unit of time.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns the time at the left edge of the [`Timeline`].
+    ///
+    /// [`Timeline`]: struct.Timeline.html
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+
+    /// Returns the amount of pixels per unit of time of the [`Timeline`].
+    ///
+    /// [`Timeline`]: struct.Timeline.html
+    pub fn zoom(&self) -> f32 {
+        self.zoom
+    }
+
+    /// Scrolls the [`Timeline`] so the given time is at its left edge.
+    ///
+    /// [`Timeline`]: struct.Timeline.html
+    pub fn scroll_to(&mut self, offset: f32) {
+        self.offset = offset.max(0.0);
+    }
+
+    /// Sets the amount of pixels per unit of time of the [`Timeline`].
+    ///
+    /// [`Timeline`]: struct.Timeline.html
+    pub fn set_zoom(&mut self, zoom: f32) {
+        self.zoom = zoom.clamp(0.001, 100_000.0);
+    }
+
+    /// Returns whether an item or the time axis is being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.interaction != Interaction::Idle
+    }
+
+    fn x_of(&self, bounds: Rectangle, time: f32) -> f32 {
+        bounds.x + (time - self.offset) * self.zoom
+    }
+
+    fn time_at(&self, bounds: Rectangle, x: f32) -> f32 {
+        self.offset + (x - bounds.x) / self.zoom
+    }
+
+    fn zoom_at(&mut self, bounds: Rectangle, x: f32, factor: f32) {
+        let anchor = self.time_at(bounds, x);
+
+        self.set_zoom(self.zoom * factor);
+        self.scroll_to(anchor - (x - bounds.x) / self.zoom);
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State {
+            offset: 0.0,
+            zoom: 100.0,
+            interaction: Interaction::Idle,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Timeline<'a, Message>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content_height = f32::from(renderer.ruler_height())
+            + f32::from(self.track_height) * self.tracks.len() as f32;
+
+        let size = limits
+            .width(self.width)
+            .height(self.height)
+            .resolve(Size::new(0.0, content_height));
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+        let ruler_height = f32::from(renderer.ruler_height());
+        let mut state = *self.state.borrow();
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { pixels, .. })
+                if bounds.contains(cursor_position) =>
+            {
+                let Vector { x, y } = pixels;
+
+                if y != 0.0 {
+                    state.zoom_at(bounds, cursor_position.x, 1.002f32.powf(y));
+                }
+
+                state.scroll_to(state.offset - x / state.zoom);
+            }
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) if bounds.contains(cursor_position) => {
+                match self.item_at(
+                    bounds,
+                    ruler_height,
+                    &state,
+                    cursor_position,
+                ) {
+                    Some((id, grab)) => {
+                        if let Some(on_select) = &self.on_select {
+                            messages.push(on_select(id));
+                        }
+
+                        state.interaction = Interaction::Dragging { id, grab };
+                    }
+                    None => {
+                        state.interaction = Interaction::Panning {
+                            cursor_x: cursor_position.x,
+                            offset: state.offset,
+                        };
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Released,
+            }) => {
+                state.interaction = Interaction::Idle;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                match state.interaction {
+                    Interaction::Idle => {}
+                    Interaction::Panning { cursor_x, offset } => {
+                        state.scroll_to(
+                            offset
+                                - (cursor_position.x - cursor_x) / state.zoom,
+                        );
+                    }
+                    Interaction::Dragging { id, grab } => {
+                        if let Some(item) = self.item(id) {
+                            let time = state.time_at(bounds, cursor_position.x);
+                            let zoom = state.zoom;
+
+                            match grab {
+                                Grab::Body { offset } => {
+                                    if let Some(on_move) = &self.on_move {
+                                        let length = item.end - item.start;
+                                        let start = time - offset;
+
+                                        let (by_start, start_distance) =
+                                            self.snap_time(start, zoom, id);
+                                        let (by_end, end_distance) = self
+                                            .snap_time(
+                                                start + length,
+                                                zoom,
+                                                id,
+                                            );
+
+                                        let start =
+                                            if start_distance <= end_distance {
+                                                by_start
+                                            } else {
+                                                by_end - length
+                                            };
+
+                                        messages.push(on_move(id, start));
+                                    }
+                                }
+                                Grab::Start => {
+                                    if let Some(on_resize) = &self.on_resize {
+                                        let (start, _) =
+                                            self.snap_time(time, zoom, id);
+
+                                        messages.push(on_resize(
+                                            id,
+                                            start.min(item.end),
+                                            item.end,
+                                        ));
+                                    }
+                                }
+                                Grab::End => {
+                                    if let Some(on_resize) = &self.on_resize {
+                                        let (end, _) =
+                                            self.snap_time(time, zoom, id);
+
+                                        messages.push(on_resize(
+                                            id,
+                                            item.start,
+                                            end.max(item.start),
+                                        ));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        *self.state.borrow_mut() = state;
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let state = self.state.borrow();
+        let bounds = layout.bounds();
+        let ruler_height = f32::from(renderer.ruler_height());
+        let track_height = f32::from(self.track_height);
+
+        let visible_start = state.offset;
+        let visible_end = state.time_at(bounds, bounds.x + bounds.width);
+
+        let step = tick_step(MIN_TICK_SPACING / state.zoom);
+        let decimals = (-step.log10()).ceil().max(0.0) as usize;
+
+        let ticks: Vec<Tick> = ((visible_start / step).ceil() as i64
+            ..=(visible_end / step).floor() as i64)
+            .map(|i| {
+                let time = i as f32 * step;
+
+                Tick {
+                    x: state.x_of(bounds, time),
+                    label: format!("{:.*}", decimals, time),
+                }
+            })
+            .collect();
+
+        let visible_tracks = ((bounds.height - ruler_height).max(0.0)
+            / track_height)
+            .ceil() as usize;
+
+        let mut lanes = Vec::new();
+        let mut spans = Vec::new();
+
+        for (track_index, track) in
+            self.tracks.iter().enumerate().take(visible_tracks)
+        {
+            let y = bounds.y + ruler_height + track_index as f32 * track_height;
+
+            lanes.push(Lane {
+                bounds: Rectangle {
+                    x: bounds.x,
+                    y,
+                    width: bounds.width,
+                    height: track_height,
+                },
+                label: &track.label,
+            });
+
+            for (item_index, item) in track.items.iter().enumerate() {
+                if item.end < visible_start || item.start > visible_end {
+                    continue;
+                }
+
+                let start = state.x_of(bounds, item.start);
+                let end = state.x_of(bounds, item.end);
+
+                spans.push(Span {
+                    bounds: Rectangle {
+                        x: start,
+                        y,
+                        width: end - start,
+                        height: track_height,
+                    },
+                    item,
+                    is_selected: self.selection
+                        == Some(Id {
+                            track: track_index,
+                            item: item_index,
+                        }),
+                });
+            }
+        }
+
+        renderer.draw(
+            bounds,
+            cursor_position,
+            &ticks,
+            &lanes,
+            &spans,
+            state.is_dragging(),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Timeline<'static, ()>>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.track_height.hash(state);
+        self.tracks.len().hash(state);
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.state.diff(tree);
+    }
+
+    fn captures_mouse(&self) -> bool {
+        self.state.borrow().is_dragging()
+    }
+}
+
+fn tick_step(min_step: f32) -> f32 {
+    let magnitude = 10f32.powf(min_step.log10().floor());
+
+    [1.0, 2.0, 5.0, 10.0]
+        .iter()
+        .map(|factor| factor * magnitude)
+        .find(|step| *step >= min_step)
+        .unwrap_or(10.0 * magnitude)
+}
+
+/// A mark on the time axis of a [`Timeline`].
+///
+/// [`Timeline`]: struct.Timeline.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tick {
+    /// The horizontal position of the mark.
+    pub x: f32,
+
+    /// The time of the mark, formatted.
+    pub label: String,
+}
+
+/// A visible track of a [`Timeline`].
+///
+/// [`Timeline`]: struct.Timeline.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lane<'a> {
+    /// The bounds of the track.
+    pub bounds: Rectangle,
+
+    /// The label of the track.
+    pub label: &'a str,
+}
+
+/// A visible item of a [`Timeline`].
+///
+/// [`Timeline`]: struct.Timeline.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Span<'a> {
+    /// The bounds of the item, which may overflow the [`Timeline`].
+    ///
+    /// [`Timeline`]: struct.Timeline.html
+    pub bounds: Rectangle,
+
+    /// The item.
+    pub item: &'a Item,
+
+    /// Whether the item is selected.
+    pub is_selected: bool,
+}
+
+/// The renderer of a [`Timeline`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Timeline`] in your user interface.
+///
+/// [`Timeline`]: struct.Timeline.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// Returns the height of the time axis of a [`Timeline`], drawn above its
+    /// tracks.
+    ///
+    /// [`Timeline`]: struct.Timeline.html
+    fn ruler_height(&self) -> u16;
+
+    /// Draws a [`Timeline`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Timeline`]
+    ///   * the current cursor position
+    ///   * the marks of the visible time axis
+    ///   * the visible tracks
+    ///   * the visible items
+    ///   * whether an item or the time axis is being dragged
+    ///
+    /// Anything outside of the bounds of the [`Timeline`] should be clipped.
+    ///
+    /// [`Timeline`]: struct.Timeline.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        ticks: &[Tick],
+        lanes: &[Lane<'_>],
+        spans: &[Span<'_>],
+        is_dragging: bool,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Timeline<'a, Message>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+    Message: 'static,
+{
+    fn from(timeline: Timeline<'a, Message>) -> Element<'a, Message, Renderer> {
+        Element::new(timeline)
+    }
+}
@@ -0,0 +1,532 @@
+//! Stack short-lived notifications on top of some content.
+//!
+//! The runtime wraps the user interface of every application in a
+//! [`Toasts`] widget, showing the [`Toast`] of every [`Command::toast`].
+//!
+//! [`Toasts`]: struct.Toasts.html
+//! [`Toast`]: ../../toast/struct.Toast.html
+//! [`Command::toast`]: ../../struct.Command.html#method.toast
+use crate::{
+    input::{mouse, ButtonState},
+    layout, overlay, text,
+    toast::{Level, Toast},
+    Clipboard, Element, Event, Focusable, Font, Hasher, Layout, Length, Point,
+    Rectangle, Size, Tree, Widget,
+};
+
+use std::hash::Hash;
+use std::time::Instant;
+
+// The distance between the toasts and the edges of the content
+const MARGIN: f32 = 16.0;
+
+// The space around the contents of a toast
+const PADDING: f32 = 12.0;
+
+// The space between the title, the body, and the progress bar of a toast
+const SPACING: f32 = 4.0;
+
+const PROGRESS_HEIGHT: f32 = 4.0;
+
+/// The toasts that are currently shown, along with the instant they were
+/// shown at.
+///
+/// [`Toasts`] keep their [`Queue`] between frames, so it has to be owned by
+/// whoever builds the user interface.
+///
+/// [`Toasts`]: struct.Toasts.html
+/// [`Queue`]: struct.Queue.html
+#[derive(Debug)]
+pub struct Queue<Message> {
+    toasts: Vec<(Toast<Message>, Instant)>,
+}
+
+impl<Message> Queue<Message> {
+    /// Creates an empty [`Queue`].
+    ///
+    /// [`Queue`]: struct.Queue.html
+    pub fn new() -> Self {
+        Queue { toasts: Vec::new() }
+    }
+
+    /// Shows a [`Toast`], on top of the others.
+    ///
+    /// If a [`Toast`] with the same identifier is shown already, it is
+    /// replaced in place and its timer starts over.
+    ///
+    /// [`Toast`]: ../../toast/struct.Toast.html
+    pub fn push(&mut self, toast: Toast<Message>) {
+        let now = Instant::now();
+
+        let existing = toast.id.and_then(|id| {
+            self.toasts
+                .iter_mut()
+                .find(|(shown, _)| shown.id == Some(id))
+        });
+
+        match existing {
+            Some(shown) => *shown = (toast, now),
+            None => self.toasts.push((toast, now)),
+        }
+    }
+
+    /// Removes the toasts whose duration elapsed at the given instant.
+    pub fn prune(&mut self, now: Instant) {
+        self.toasts
+            .retain(|(toast, shown_at)| is_visible(toast, *shown_at, now));
+    }
+
+    /// Returns true if there are no toasts in the [`Queue`].
+    ///
+    /// [`Queue`]: struct.Queue.html
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+
+    /// Returns an iterator over the toasts in the [`Queue`], from the oldest
+    /// one.
+    ///
+    /// [`Queue`]: struct.Queue.html
+    pub fn iter(&self) -> impl Iterator<Item = &Toast<Message>> {
+        self.toasts.iter().map(|(toast, _)| toast)
+    }
+}
+
+impl<Message> Default for Queue<Message> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn is_visible<Message>(
+    toast: &Toast<Message>,
+    shown_at: Instant,
+    now: Instant,
+) -> bool {
+    toast
+        .duration
+        .is_none_or(|duration| now < shown_at + duration)
+}
+
+/// An element showing the toasts of a [`Queue`] on top of its content.
+///
+/// The toasts stack in the bottom right corner, with the newest one at the
+/// bottom. Each one disappears once its duration elapses. Clicking a toast
+/// dismisses it and produces its message, if any.
+///
+/// # Example
+/// ```
+/// # use iced_native::{toasts::Queue, Text, Toast};
+/// #
+/// # type Toasts<'a, Message> =
+/// #     iced_native::Toasts<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug)]
+/// enum Message {
+///     ShowDownloads,
+/// }
+///
+/// let mut queue = Queue::new();
+///
+/// queue.push(
+///     Toast::new("Download finished")
+///         .body("iced.zip")
+///         .on_click(Message::ShowDownloads),
+/// );
+///
+/// let toasts = Toasts::new(&mut queue, Text::new("Downloads"));
+/// ```
+///
+/// [`Queue`]: struct.Queue.html
+#[allow(missing_debug_implementations)]
+pub struct Toasts<'a, Message, Renderer> {
+    queue: &'a mut Queue<Message>,
+    content: Element<'a, Message, Renderer>,
+    width: u16,
+    max_visible: usize,
+}
+
+impl<'a, Message, Renderer> Toasts<'a, Message, Renderer> {
+    /// Creates new [`Toasts`] showing the given [`Queue`] on top of some
+    /// content.
+    ///
+    /// [`Toasts`]: struct.Toasts.html
+    /// [`Queue`]: struct.Queue.html
+    pub fn new<E>(queue: &'a mut Queue<Message>, content: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Toasts {
+            queue,
+            content: content.into(),
+            width: 320,
+            max_visible: 5,
+        }
+    }
+
+    /// Sets the width of every toast.
+    pub fn width(mut self, units: u16) -> Self {
+        self.width = units;
+        self
+    }
+
+    /// Sets the maximum amount of toasts shown at once.
+    ///
+    /// The newest toasts are shown first. The rest are shown as soon as
+    /// there is room for them.
+    pub fn max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = max_visible;
+        self
+    }
+
+    // Stacks the visible toasts from the bottom of the content, returning
+    // their index in the queue along with their card
+    fn cards(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        now: Instant,
+    ) -> Vec<(usize, Card<'_>)>
+    where
+        Renderer: self::Renderer,
+    {
+        let text_size = f32::from(renderer.default_size());
+        let width = f32::from(self.width).min(bounds.width - 2.0 * MARGIN);
+        let x = bounds.x + bounds.width - MARGIN - width;
+
+        let mut bottom = bounds.y + bounds.height - MARGIN;
+
+        self.queue
+            .toasts
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, (toast, shown_at))| is_visible(toast, *shown_at, now))
+            .take(self.max_visible)
+            .map(|(index, (toast, _))| {
+                let text_width = width - 2.0 * PADDING;
+
+                let body_height = toast.body.as_ref().map(|body| {
+                    let (_, height) = renderer.measure(
+                        body,
+                        renderer.default_size(),
+                        Font::Default,
+                        text::Params::default(),
+                        Size::new(text_width, f32::INFINITY),
+                    );
+
+                    height
+                });
+
+                let height = 2.0 * PADDING
+                    + text_size
+                    + body_height.map_or(0.0, |height| SPACING + height)
+                    + toast
+                        .progress
+                        .map_or(0.0, |_| 2.0 * SPACING + PROGRESS_HEIGHT);
+
+                let bounds = Rectangle {
+                    x,
+                    y: bottom - height,
+                    width,
+                    height,
+                };
+
+                bottom = bounds.y - SPACING * 2.0;
+
+                let title_bounds = Rectangle {
+                    x: bounds.x + PADDING,
+                    y: bounds.y + PADDING,
+                    width: text_width,
+                    height: text_size,
+                };
+
+                let body = toast.body.as_ref().zip(body_height).map(
+                    |(body, height)| {
+                        (
+                            body.as_str(),
+                            Rectangle {
+                                y: title_bounds.y + text_size + SPACING,
+                                height,
+                                ..title_bounds
+                            },
+                        )
+                    },
+                );
+
+                let progress = toast.progress.map(|progress| {
+                    (
+                        progress,
+                        Rectangle {
+                            y: bounds.y + bounds.height
+                                - PADDING
+                                - PROGRESS_HEIGHT,
+                            height: PROGRESS_HEIGHT,
+                            ..title_bounds
+                        },
+                    )
+                });
+
+                (
+                    index,
+                    Card {
+                        bounds,
+                        level: toast.level,
+                        title: &toast.title,
+                        title_bounds,
+                        body,
+                        progress,
+                    },
+                )
+            })
+            .collect()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Toasts<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self.content.layout(renderer, limits);
+
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let now = Instant::now();
+
+        self.queue.prune(now);
+
+        let hovered = self
+            .cards(renderer, layout.bounds(), now)
+            .into_iter()
+            .find(|(_, card)| card.bounds.contains(cursor_position))
+            .map(|(index, _)| index);
+
+        let cursor_position = match hovered {
+            Some(index) => {
+                match event {
+                    Event::Mouse(mouse::Event::Input {
+                        button: mouse::Button::Left,
+                        state: ButtonState::Pressed,
+                    }) => {
+                        let (toast, _) = self.queue.toasts.remove(index);
+
+                        messages.extend(toast.on_click);
+
+                        return;
+                    }
+                    // Clicks and scrolls over a toast never reach the
+                    // content under it
+                    Event::Mouse(mouse::Event::Input { .. })
+                    | Event::Mouse(mouse::Event::WheelScrolled { .. }) => {
+                        return;
+                    }
+                    _ => {}
+                }
+
+                Point::new(-1.0, -1.0)
+            }
+            None => cursor_position,
+        };
+
+        self.content.widget.on_event(
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let now = Instant::now();
+
+        let cards: Vec<Card<'_>> = self
+            .cards(renderer, layout.bounds(), now)
+            .into_iter()
+            .map(|(_, card)| card)
+            .collect();
+
+        let is_over_toast = cards
+            .iter()
+            .any(|card| card.bounds.contains(cursor_position));
+
+        let content = self.content.draw(
+            renderer,
+            layout.children().next().unwrap(),
+            if is_over_toast {
+                Point::new(-1.0, -1.0)
+            } else {
+                cursor_position
+            },
+        );
+
+        // Wake up when the next toast has to disappear
+        let next_expiry = self
+            .queue
+            .toasts
+            .iter()
+            .filter_map(|(toast, shown_at)| {
+                toast.duration.map(|duration| *shown_at + duration)
+            })
+            .filter(|at| *at > now)
+            .min();
+
+        if let Some(at) = next_expiry {
+            renderer.schedule_redraw(at);
+        }
+
+        self::Renderer::draw(renderer, cursor_position, content, &cards)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Toasts<'static, (), ()>>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.content.diff(&mut tree.diff_children(&[None])[0]);
+    }
+
+    #[cfg(feature = "devtools")]
+    fn inspect(&self, layout: Layout<'_>) -> crate::inspect::Node {
+        super::inspect_children(
+            std::any::type_name::<Self>(),
+            std::slice::from_ref(&self.content),
+            &[None],
+            layout,
+        )
+    }
+
+    fn hover(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        path: &mut Vec<usize>,
+    ) {
+        super::hover_children(
+            std::slice::from_ref(&self.content),
+            layout,
+            cursor_position,
+            path,
+        );
+    }
+
+    fn focusables(
+        &self,
+        path: &mut Vec<usize>,
+        focusables: &mut Vec<Focusable>,
+    ) {
+        super::focusables_children(
+            std::slice::from_ref(&self.content),
+            path,
+            focusables,
+        );
+    }
+
+    fn captures_mouse(&self) -> bool {
+        super::captures_mouse_children(std::slice::from_ref(&self.content))
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        super::overlay_children(std::slice::from_mut(&mut self.content), layout)
+    }
+}
+
+/// A toast, ready to be drawn.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Card<'a> {
+    /// The bounds of the [`Card`].
+    ///
+    /// [`Card`]: struct.Card.html
+    pub bounds: Rectangle,
+
+    /// The [`Level`] of the toast.
+    ///
+    /// [`Level`]: ../../toast/enum.Level.html
+    pub level: Level,
+
+    /// The title of the toast.
+    pub title: &'a str,
+
+    /// The bounds of the title.
+    pub title_bounds: Rectangle,
+
+    /// The body of the toast and its bounds, if any.
+    pub body: Option<(&'a str, Rectangle)>,
+
+    /// The progress of the toast and the bounds of its progress bar, if any.
+    pub progress: Option<(f32, Rectangle)>,
+}
+
+/// The renderer of [`Toasts`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use [`Toasts`] in your user interface.
+///
+/// [`Toasts`]: struct.Toasts.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer {
+    /// Draws the toasts on top of some content.
+    ///
+    /// It receives:
+    ///   * the cursor position
+    ///   * the drawn content
+    ///   * the [`Card`] of every visible toast, from the newest one
+    ///
+    /// The toasts must be drawn above any text of the content.
+    ///
+    /// [`Card`]: struct.Card.html
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        content: Self::Output,
+        cards: &[Card<'_>],
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Toasts<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'static + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        toasts: Toasts<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(toasts)
+    }
+}
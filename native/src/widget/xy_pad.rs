@@ -0,0 +1,405 @@
+//! Control two values at once by dragging a point around a pad.
+//!
+//! An [`XYPad`] has some local [`State`].
+//!
+//! [`XYPad`]: struct.XYPad.html
+//! [`State`]: struct.State.html
+use crate::{
+    draggable::{self, Drag},
+    input::keyboard,
+    layout,
+    tree::{self, Tree},
+    Clipboard, Element, Event, Hasher, Layout, Length, Point, Rectangle, Size,
+    Vector, Widget,
+};
+
+use std::{hash::Hash, ops::RangeInclusive};
+
+/// A square area where dragging selects two continuous values, one per axis,
+/// like the cutoff and resonance of a filter.
+///
+/// The horizontal value grows to the right and the vertical value grows
+/// upwards. Pressing the pad jumps to the position of the cursor. While
+/// shift is held, the point instead follows a fraction of the movement of
+/// the cursor, for fine adjustments.
+///
+/// The drag continues after the cursor leaves the [`XYPad`], until the mouse
+/// button is released.
+///
+/// [`XYPad`]: struct.XYPad.html
+///
+/// # Example
+/// ```
+/// # use iced_native::{xy_pad, XYPad};
+/// #
+/// pub enum Message {
+///     FilterChanged(f32, f32),
+/// }
+///
+/// let state = &mut xy_pad::State::new();
+/// let (cutoff, resonance) = (440.0, 0.5);
+///
+/// XYPad::new(
+///     state,
+///     20.0..=20_000.0,
+///     0.0..=1.0,
+///     (cutoff, resonance),
+///     Message::FilterChanged,
+/// )
+/// .step(1.0, 0.01);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct XYPad<'a, Message> {
+    state: tree::State<'a, State>,
+    x_range: RangeInclusive<f32>,
+    y_range: RangeInclusive<f32>,
+    value: (f32, f32),
+    step: Option<(f32, f32)>,
+    fine_factor: f32,
+    on_change: Box<dyn Fn(f32, f32) -> Message>,
+    width: Length,
+    height: Length,
+}
+
+impl<'a, Message> XYPad<'a, Message> {
+    /// Creates a new [`XYPad`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`XYPad`]
+    ///   * an inclusive range of possible horizontal values
+    ///   * an inclusive range of possible vertical values
+    ///   * the current horizontal and vertical values of the [`XYPad`]
+    ///   * a function that will be called when the [`XYPad`] is dragged.
+    ///     It receives the new horizontal and vertical values and must
+    ///     produce a `Message`.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(
+        state: &'a mut State,
+        x_range: RangeInclusive<f32>,
+        y_range: RangeInclusive<f32>,
+        value: (f32, f32),
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(f32, f32) -> Message,
+    {
+        Self::with_state(
+            tree::State::Borrowed(state),
+            x_range,
+            y_range,
+            value,
+            on_change,
+        )
+    }
+
+    /// Creates a new [`XYPad`] whose local [`State`] is managed by the
+    /// runtime.
+    ///
+    /// It expects the same arguments as [`new`], except for the [`State`].
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`State`]: struct.State.html
+    /// [`new`]: #method.new
+    pub fn managed<F>(
+        x_range: RangeInclusive<f32>,
+        y_range: RangeInclusive<f32>,
+        value: (f32, f32),
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(f32, f32) -> Message,
+    {
+        Self::with_state(
+            tree::State::managed(),
+            x_range,
+            y_range,
+            value,
+            on_change,
+        )
+    }
+
+    fn with_state<F>(
+        state: tree::State<'a, State>,
+        x_range: RangeInclusive<f32>,
+        y_range: RangeInclusive<f32>,
+        (x, y): (f32, f32),
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(f32, f32) -> Message,
+    {
+        XYPad {
+            state,
+            value: (clamp(x, &x_range), clamp(y, &y_range)),
+            x_range,
+            y_range,
+            step: None,
+            fine_factor: 0.1,
+            on_change: Box::new(on_change),
+            width: Length::Units(200),
+            height: Length::Units(200),
+        }
+    }
+
+    /// Snaps the values of the [`XYPad`] to multiples of the given steps,
+    /// counting from the start of each range.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn step(mut self, x: f32, y: f32) -> Self {
+        self.step = Some((x, y));
+        self
+    }
+
+    /// Sets the fraction of the movement of the cursor the point follows
+    /// while shift is held.
+    ///
+    /// By default, it is `0.1`.
+    pub fn fine_factor(mut self, fine_factor: f32) -> Self {
+        self.fine_factor = fine_factor;
+        self
+    }
+
+    /// Sets the width of the [`XYPad`].
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`XYPad`].
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    fn value_at(&self, bounds: Rectangle, position: Point) -> (f32, f32) {
+        let x = (position.x - bounds.x) / bounds.width.max(1.0);
+        let y = 1.0 - (position.y - bounds.y) / bounds.height.max(1.0);
+
+        (
+            clamp(lerp(&self.x_range, x), &self.x_range),
+            clamp(lerp(&self.y_range, y), &self.y_range),
+        )
+    }
+
+    fn nudge(
+        &self,
+        bounds: Rectangle,
+        (x, y): (f32, f32),
+        delta: Vector,
+    ) -> (f32, f32) {
+        let span = |range: &RangeInclusive<f32>| range.end() - range.start();
+
+        let dx = delta.x / bounds.width.max(1.0) * span(&self.x_range);
+        let dy = -delta.y / bounds.height.max(1.0) * span(&self.y_range);
+
+        (
+            clamp(x + dx * self.fine_factor, &self.x_range),
+            clamp(y + dy * self.fine_factor, &self.y_range),
+        )
+    }
+
+    fn snap(&self, (x, y): (f32, f32)) -> (f32, f32) {
+        match self.step {
+            Some((x_step, y_step)) => (
+                snap(x, x_step, &self.x_range),
+                snap(y, y_step, &self.y_range),
+            ),
+            None => (x, y),
+        }
+    }
+}
+
+fn lerp(range: &RangeInclusive<f32>, t: f32) -> f32 {
+    range.start() + (range.end() - range.start()) * t
+}
+
+fn clamp(value: f32, range: &RangeInclusive<f32>) -> f32 {
+    value.max(*range.start()).min(*range.end())
+}
+
+fn snap(value: f32, step: f32, range: &RangeInclusive<f32>) -> f32 {
+    if step <= 0.0 {
+        return value;
+    }
+
+    let steps = ((value - range.start()) / step).round();
+
+    clamp(range.start() + steps * step, range)
+}
+
+/// The local state of an [`XYPad`].
+///
+/// [`XYPad`]: struct.XYPad.html
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct State {
+    drag: draggable::State,
+    is_fine: bool,
+
+    // The unsnapped value during a drag, so fine adjustments smaller than a
+    // step still add up
+    value: Option<(f32, f32)>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for XYPad<'a, Message>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+
+        let (drag, is_fine, last_value) = {
+            let mut state = self.state.borrow_mut();
+
+            if let Event::Keyboard(keyboard::Event::Input {
+                modifiers, ..
+            }) = event
+            {
+                state.is_fine = modifiers.shift;
+            }
+
+            let drag = state.drag.update(event, bounds, cursor_position);
+
+            (drag, state.is_fine, state.value)
+        };
+
+        let value = match drag {
+            Some(Drag::Started) if is_fine => Some(self.value),
+            Some(Drag::Started) => Some(self.value_at(bounds, cursor_position)),
+            Some(Drag::Moved(delta)) => match last_value {
+                Some(value) if is_fine => {
+                    Some(self.nudge(bounds, value, delta))
+                }
+                _ => Some(self.value_at(bounds, cursor_position)),
+            },
+            Some(Drag::Ended) => None,
+            None => return,
+        };
+
+        self.state.borrow_mut().value = value;
+
+        if let Some(value) = value {
+            let (x, y) = self.snap(value);
+
+            if (x, y) != self.value {
+                messages.push((self.on_change)(x, y));
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let state = self.state.borrow();
+
+        renderer.draw(
+            layout.bounds(),
+            cursor_position,
+            self.x_range.clone(),
+            self.y_range.clone(),
+            self.value,
+            state.drag.is_dragging(),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<XYPad<'static, ()>>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.state.diff(tree);
+    }
+
+    fn captures_mouse(&self) -> bool {
+        self.state.borrow().drag.is_dragging()
+    }
+}
+
+/// The renderer of an [`XYPad`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use an [`XYPad`] in your user interface.
+///
+/// [`XYPad`]: struct.XYPad.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// Draws an [`XYPad`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`XYPad`]
+    ///   * the current cursor position
+    ///   * the range of horizontal values of the [`XYPad`]
+    ///   * the range of vertical values of the [`XYPad`]
+    ///   * the current horizontal and vertical values of the [`XYPad`]
+    ///   * whether the [`XYPad`] is being dragged
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        x_range: RangeInclusive<f32>,
+        y_range: RangeInclusive<f32>,
+        value: (f32, f32),
+        is_dragging: bool,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<XYPad<'a, Message>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+    Message: 'static,
+{
+    fn from(xy_pad: XYPad<'a, Message>) -> Element<'a, Message, Renderer> {
+        Element::new(xy_pad)
+    }
+}
@@ -19,7 +19,7 @@ use crate::{Command, Element, Settings, Subscription};
 /// before](index.html#overview). We just need to fill in the gaps:
 ///
 /// ```no_run
-/// use iced::{button, Application, Button, Column, Command, Element, Settings, Text};
+/// use iced::{Application, Button, Column, Command, Element, Settings, Text};
 ///
 /// pub fn main() {
 ///     Counter::run(Settings::default())
@@ -28,8 +28,6 @@ use crate::{Command, Element, Settings, Subscription};
 /// #[derive(Default)]
 /// struct Counter {
 ///     value: i32,
-///     increment_button: button::State,
-///     decrement_button: button::State,
 /// }
 ///
 /// #[derive(Debug, Clone, Copy)]
@@ -62,17 +60,17 @@ use crate::{Command, Element, Settings, Subscription};
 ///         Command::none()
 ///     }
 ///
-///     fn view(&mut self) -> Element<Message> {
+///     fn view(&self) -> Element<Message> {
 ///         Column::new()
 ///             .push(
-///                 Button::new(&mut self.increment_button, Text::new("Increment"))
+///                 Button::managed(Text::new("Increment"))
 ///                     .on_press(Message::IncrementPressed),
 ///             )
 ///             .push(
 ///                 Text::new(self.value.to_string()).size(50),
 ///             )
 ///             .push(
-///                 Button::new(&mut self.decrement_button, Text::new("Decrement"))
+///                 Button::managed(Text::new("Decrement"))
 ///                     .on_press(Message::DecrementPressed),
 ///             )
 ///             .into()
@@ -135,8 +133,42 @@ pub trait Application: Sized {
     ///
     /// These widgets can produce __messages__ based on user interaction.
     ///
+    /// The runtime keeps the local state of widgets created with `managed`,
+    /// like a `Button::managed`, so the [`Application`] only needs to be
+    /// borrowed to be viewed.
+    ///
     /// [`Application`]: trait.Application.html
-    fn view(&mut self) -> Element<'_, Self::Message>;
+    fn view(&self) -> Element<'_, Self::Message>;
+
+    /// Returns the keyboard shortcuts of the [`Application`] for its current
+    /// state.
+    ///
+    /// A key press matching a [`Hotkey`] produces its message before any
+    /// widget sees it. For instance, `Hotkey::new(KeyCode::S,
+    /// Message::Save).command()` saves on `Ctrl+S`, or `Cmd+S` on macOS.
+    ///
+    /// By default, it returns no hotkeys. It is only available on native
+    /// platforms.
+    ///
+    /// [`Application`]: trait.Application.html
+    /// [`Hotkey`]: struct.Hotkey.html
+    #[cfg(not(target_arch = "wasm32"))]
+    fn hotkeys(&self) -> Vec<crate::Hotkey<Self::Message>> {
+        Vec::new()
+    }
+
+    /// Receives a snapshot of the widgets of the [`Application`] every time
+    /// they are drawn, with their bounds after layout.
+    ///
+    /// Development tools can compare the snapshots of consecutive frames to
+    /// show what changed.
+    ///
+    /// By default, it does nothing. It is only available with the `devtools`
+    /// feature on native platforms.
+    ///
+    /// [`Application`]: trait.Application.html
+    #[cfg(all(feature = "devtools", not(target_arch = "wasm32")))]
+    fn inspect(&mut self, _snapshot: crate::inspect::Node) {}
 
     /// Runs the [`Application`].
     ///
@@ -186,9 +218,18 @@ where
         self.0.subscription()
     }
 
-    fn view(&mut self) -> Element<'_, Self::Message> {
+    fn view(&self) -> Element<'_, Self::Message> {
         self.0.view()
     }
+
+    fn hotkeys(&self) -> Vec<crate::Hotkey<Self::Message>> {
+        self.0.hotkeys()
+    }
+
+    #[cfg(feature = "devtools")]
+    fn inspect(&mut self, snapshot: crate::inspect::Node) {
+        self.0.inspect(snapshot)
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -51,15 +51,9 @@
 //! We start by modelling the __state__ of our application:
 //!
 //! ```
-//! use iced::button;
-//!
 //! struct Counter {
 //!     // The counter value
 //!     value: i32,
-//!
-//!     // The local state of the two buttons
-//!     increment_button: button::State,
-//!     decrement_button: button::State,
 //! }
 //! ```
 //!
@@ -78,15 +72,9 @@
 //! __view logic__:
 //!
 //! ```
-//! # use iced::button;
-//! #
 //! # struct Counter {
 //! #     // The counter value
 //! #     value: i32,
-//! #
-//! #     // The local state of the two buttons
-//! #     increment_button: button::State,
-//! #     decrement_button: button::State,
 //! # }
 //! #
 //! # #[derive(Debug, Clone, Copy)]
@@ -98,13 +86,13 @@
 //! use iced::{Button, Column, Text};
 //!
 //! impl Counter {
-//!     pub fn view(&mut self) -> Column<Message> {
+//!     pub fn view(&self) -> Column<Message> {
 //!         // We use a column: a simple vertical layout
 //!         Column::new()
 //!             .push(
 //!                 // The increment button. We tell it to produce an
 //!                 // `IncrementPressed` message when pressed
-//!                 Button::new(&mut self.increment_button, Text::new("+"))
+//!                 Button::managed(Text::new("+"))
 //!                     .on_press(Message::IncrementPressed),
 //!             )
 //!             .push(
@@ -114,7 +102,7 @@
 //!             .push(
 //!                 // The decrement button. We tell it to produce a
 //!                 // `DecrementPressed` message when pressed
-//!                 Button::new(&mut self.decrement_button, Text::new("-"))
+//!                 Button::managed(Text::new("-"))
 //!                     .on_press(Message::DecrementPressed),
 //!             )
 //!     }
@@ -125,15 +113,9 @@
 //! our __state__ accordingly in our __update logic__:
 //!
 //! ```
-//! # use iced::button;
-//! #
 //! # struct Counter {
 //! #     // The counter value
 //! #     value: i32,
-//! #
-//! #     // The local state of the two buttons
-//! #     increment_button: button::State,
-//! #     decrement_button: button::State,
 //! # }
 //! #
 //! # #[derive(Debug, Clone, Copy)]
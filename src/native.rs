@@ -1,8 +1,12 @@
 pub use iced_winit::{
-    Align, Background, Color, Command, Font, HorizontalAlignment, Length,
-    Space, Subscription, VerticalAlignment,
+    context, dialog, font, hotkey, input, toast, Align, Background, Color,
+    Command, Dialog, Font, Gradient, HorizontalAlignment, Hotkey, Length,
+    MouseCursor, Shadow, Space, Subscription, Toast, Vector, VerticalAlignment,
 };
 
+#[cfg(feature = "devtools")]
+pub use iced_winit::inspect;
+
 pub mod widget {
     //! Display information and interactive controls in your application.
     //!
@@ -40,18 +44,213 @@ pub mod widget {
         pub use iced_winit::button::State;
     }
 
+    pub mod cached {
+        //! Reuse the output of static content between frames.
+        //!
+        //! A [`Cached`] widget has some local [`State`].
+        //!
+        //! [`Cached`]: type.Cached.html
+        //! [`State`]: type.State.html
+
+        /// An element that stores the output of its content and reuses it
+        /// while the content does not change.
+        ///
+        /// This is an alias of an `iced_native` cached widget with a default
+        /// `Renderer`.
+        pub type Cached<'a, Message> =
+            iced_winit::Cached<'a, Message, iced_wgpu::Renderer>;
+
+        /// The local state of a [`Cached`] widget.
+        ///
+        /// [`Cached`]: type.Cached.html
+        pub type State = iced_winit::cached::State<(
+            iced_wgpu::Primitive,
+            iced_winit::MouseCursor,
+        )>;
+    }
+
+    pub mod custom_layout {
+        //! Position elements with your own layout logic.
+
+        /// A container that lets you measure and arrange its children
+        /// yourself.
+        ///
+        /// This is an alias of an `iced_native` custom layout with a default
+        /// `Renderer`.
+        pub type CustomLayout<'a, Message> =
+            iced_winit::CustomLayout<'a, Message, iced_wgpu::Renderer>;
+
+        pub use iced_winit::custom_layout::Child;
+    }
+
+    pub mod draggable {
+        //! Let your users drag things around and resize them.
+        //!
+        //! A [`Draggable`] has some local [`State`].
+        //!
+        //! [`Draggable`]: type.Draggable.html
+        //! [`State`]: struct.State.html
+
+        /// An element that can be dragged with the mouse.
+        ///
+        /// This is an alias of an `iced_native` draggable with a default
+        /// `Renderer`.
+        pub type Draggable<'a, Message> =
+            iced_winit::Draggable<'a, Message, iced_wgpu::Renderer>;
+
+        pub use iced_winit::draggable::{Axis, Drag, State};
+    }
+
+    pub mod mouse_area {
+        //! Listen to any mouse interaction with some content.
+        //!
+        //! A [`MouseArea`] has some local [`State`].
+        //!
+        //! [`MouseArea`]: type.MouseArea.html
+        //! [`State`]: struct.State.html
+
+        /// An element that reports the mouse interactions with its content.
+        ///
+        /// This is an alias of an `iced_native` mouse area with a default
+        /// `Renderer`.
+        pub type MouseArea<'a, Message> =
+            iced_winit::MouseArea<'a, Message, iced_wgpu::Renderer>;
+
+        pub use iced_winit::mouse_area::State;
+    }
+
+    pub mod modal {
+        //! Show some content in a dialog, blocking the rest of the user
+        //! interface.
+
+        /// An element that can show a dialog on top of its content.
+        ///
+        /// This is an alias of an `iced_native` modal with a default
+        /// `Renderer`.
+        pub type Modal<'a, Message> =
+            iced_winit::Modal<'a, Message, iced_wgpu::Renderer>;
+    }
+
+    pub mod minimap {
+        //! Display a scaled-down overview of scrollable content.
+        //!
+        //! A [`MiniMap`] has some local [`State`].
+        //!
+        //! [`MiniMap`]: type.MiniMap.html
+        //! [`State`]: struct.State.html
+
+        /// A scaled-down overview of the content of a scrollable.
+        ///
+        /// This is an alias of an `iced_native` minimap with a default
+        /// `Renderer`.
+        pub type MiniMap<'a> = iced_winit::MiniMap<'a, iced_wgpu::Renderer>;
+
+        pub use iced_winit::{minimap::State, tree::Link};
+    }
+
     pub mod scrollable {
-        //! Navigate an endless amount of content with a scrollbar.
+        //! Navigate an endless amount of content with scrollbars.
 
-        /// A widget that can vertically display an infinite amount of content
-        /// with a scrollbar.
+        /// A widget that can display an infinite amount of content with
+        /// scrollbars.
         ///
         /// This is an alias of an `iced_native` scrollable with a default
         /// `Renderer`.
         pub type Scrollable<'a, Message> =
             iced_winit::Scrollable<'a, Message, iced_wgpu::Renderer>;
 
-        pub use iced_winit::scrollable::State;
+        pub use iced_winit::scrollable::{
+            Direction, State, Style, Target, Viewport,
+        };
+    }
+
+    pub mod badge {
+        //! Attach a small count bubble to the corner of some content.
+
+        /// A bubble displaying a short label on a corner of some content.
+        ///
+        /// This is an alias of an `iced_native` badge with a default
+        /// `Renderer`.
+        pub type Badge<'a, Message> =
+            iced_winit::Badge<'a, Message, iced_wgpu::Renderer>;
+
+        pub use iced_winit::badge::Corner;
+    }
+
+    pub mod navigation {
+        //! Navigate between the pages of your application.
+
+        /// A widget that displays the current page of a navigation stack.
+        ///
+        /// This is an alias of an `iced_native` navigation with a default
+        /// `Renderer`.
+        pub type Navigation<'a, Message> =
+            iced_winit::Navigation<'a, Message, iced_wgpu::Renderer>;
+
+        pub use iced_winit::navigation::{
+            Animation, Direction, Stack, Transition,
+        };
+    }
+
+    pub mod reveal {
+        //! Reveal some content with an animated mask.
+
+        /// A widget that reveals its content through an animated mask.
+        ///
+        /// This is an alias of an `iced_native` reveal with a default
+        /// `Renderer`.
+        pub type Reveal<'a, Message> =
+            iced_winit::Reveal<'a, Message, iced_wgpu::Renderer>;
+
+        pub use iced_winit::reveal::{Direction, Mask, Transition, Visible};
+    }
+
+    #[cfg(feature = "qr_code")]
+    pub mod qr_code {
+        //! Encode and display information in a QR code.
+        pub use iced_winit::qr_code::{Data, Error, ErrorCorrection, QrCode};
+    }
+
+    #[cfg(feature = "markdown")]
+    pub mod markdown {
+        //! Display documents written in Markdown.
+        pub use iced_winit::markdown::{Content, Markdown};
+    }
+
+    pub mod text {
+        //! Write some text for your users to read.
+        pub use iced_winit::text::{Decoration, Params, Text, Wrap};
+    }
+
+    pub mod rich_text {
+        //! Write paragraphs of text mixing different styles, like chat
+        //! messages or documentation.
+        pub use iced_winit::rich_text::{RichText, Span};
+    }
+
+    pub mod selectable_text {
+        //! Write some text your users can select and copy.
+        //!
+        //! A [`SelectableText`] has some local [`State`].
+        //!
+        //! [`SelectableText`]: struct.SelectableText.html
+        //! [`State`]: struct.State.html
+        pub use iced_winit::selectable_text::{SelectableText, State};
+    }
+
+    pub mod text_editor {
+        //! Edit multi-line text, like the source code of a program.
+        //!
+        //! A [`TextEditor`] has some local [`State`]. Its lines can be styled
+        //! by a [`Highlighter`], like the syntax highlighter of a programming
+        //! language.
+        //!
+        //! [`TextEditor`]: struct.TextEditor.html
+        //! [`State`]: struct.State.html
+        //! [`Highlighter`]: trait.Highlighter.html
+        pub use iced_winit::text_editor::{
+            Format, Highlight, Highlighter, Line, Position, State, TextEditor,
+        };
     }
 
     pub mod text_input {
@@ -61,7 +260,73 @@ pub mod widget {
         //!
         //! [`TextInput`]: struct.TextInput.html
         //! [`State`]: struct.State.html
-        pub use iced_winit::text_input::{State, TextInput};
+        pub use iced_winit::{
+            text_input::{Caret, CaretShape, State, TextInput},
+            tree::Link,
+        };
+    }
+
+    pub mod combo_box {
+        //! Pick an option from a list filtered by what is typed, or type your
+        //! own.
+        //!
+        //! A [`ComboBox`] has some local [`State`].
+        //!
+        //! [`ComboBox`]: struct.ComboBox.html
+        //! [`State`]: struct.State.html
+        pub use iced_winit::combo_box::{fuzzy, ComboBox, State};
+    }
+
+    pub mod menu_bar {
+        //! Offer the commands of your application in a bar of dropdown menus.
+        //!
+        //! A [`MenuBar`] has some local [`State`].
+        //!
+        //! [`MenuBar`]: struct.MenuBar.html
+        //! [`State`]: struct.State.html
+        pub use iced_winit::menu_bar::{Item, Menu, MenuBar, State};
+    }
+
+    pub mod date_picker {
+        //! Let your users pick a date from a calendar.
+        //!
+        //! A [`DatePicker`] has some local [`State`].
+        //!
+        //! [`DatePicker`]: struct.DatePicker.html
+        //! [`State`]: struct.State.html
+        pub use iced_winit::date_picker::{
+            Date, DatePicker, Locale, State, Weekday,
+        };
+    }
+
+    pub mod time_picker {
+        //! Let your users pick a time of the day.
+        //!
+        //! A [`TimePicker`] has some local [`State`].
+        //!
+        //! [`TimePicker`]: struct.TimePicker.html
+        //! [`State`]: struct.State.html
+        pub use iced_winit::time_picker::{State, Time, TimePicker};
+    }
+
+    pub mod number_input {
+        //! Let your users type a number or change it in steps.
+        //!
+        //! A [`NumberInput`] has some local [`State`].
+        //!
+        //! [`NumberInput`]: struct.NumberInput.html
+        //! [`State`]: struct.State.html
+        pub use iced_winit::number_input::{Number, NumberInput, State};
+    }
+
+    pub mod link {
+        //! Point your users to other places, like a website.
+        //!
+        //! A [`Link`] has some local [`State`].
+        //!
+        //! [`Link`]: struct.Link.html
+        //! [`State`]: struct.State.html
+        pub use iced_winit::link::{Link, State};
     }
 
     pub mod slider {
@@ -72,12 +337,111 @@ pub mod widget {
         //!
         //! [`Slider`]: struct.Slider.html
         //! [`State`]: struct.State.html
-        pub use iced_winit::slider::{Slider, State};
+        pub use iced_winit::slider::{Axis, Slider, State};
+    }
+
+    pub mod range_slider {
+        //! Display an interactive selector of a range inside of a range of
+        //! values.
+        //!
+        //! A [`RangeSlider`] has some local [`State`].
+        //!
+        //! [`RangeSlider`]: struct.RangeSlider.html
+        //! [`State`]: struct.State.html
+        pub use iced_winit::range_slider::{RangeSlider, State};
+    }
+
+    pub mod segmented_button {
+        //! Pick one or many options from a row of joined buttons.
+        //!
+        //! A [`SegmentedButton`] has some local [`State`].
+        //!
+        //! [`SegmentedButton`]: struct.SegmentedButton.html
+        //! [`State`]: struct.State.html
+        pub use iced_winit::segmented_button::{SegmentedButton, State, Style};
+    }
+
+    pub mod rating {
+        //! Let your users rate something with a row of stars.
+        //!
+        //! A [`Rating`] has some local [`State`].
+        //!
+        //! [`Rating`]: struct.Rating.html
+        //! [`State`]: struct.State.html
+        pub use iced_winit::rating::{Rating, State, Style};
+    }
+
+    pub mod timeline {
+        //! Arrange items that span ranges of time in tracks.
+        //!
+        //! A [`Timeline`] has some local [`State`].
+        //!
+        //! [`Timeline`]: struct.Timeline.html
+        //! [`State`]: struct.State.html
+        pub use iced_winit::timeline::{Id, Item, State, Timeline, Track};
+    }
+
+    pub mod knob {
+        //! Control a value by turning a rotary knob.
+        //!
+        //! A [`Knob`] has some local [`State`].
+        //!
+        //! [`Knob`]: struct.Knob.html
+        //! [`State`]: struct.State.html
+        pub use iced_winit::knob::{Knob, State};
+    }
+
+    pub mod xy_pad {
+        //! Control two values at once by dragging a point around a pad.
+        //!
+        //! An [`XYPad`] has some local [`State`].
+        //!
+        //! [`XYPad`]: struct.XYPad.html
+        //! [`State`]: struct.State.html
+        pub use iced_winit::xy_pad::{State, XYPad};
+    }
+
+    pub mod table {
+        //! Display large grids of text with pinned headers and columns.
+        //!
+        //! A [`Table`] has some local [`State`].
+        //!
+        //! [`Table`]: struct.Table.html
+        //! [`State`]: struct.State.html
+
+        /// A grid of text with pinned headers and columns.
+        ///
+        /// This is an alias of an `iced_native` table with a default
+        /// `Renderer`.
+        pub type Table<'a, Message> =
+            iced_winit::Table<'a, Message, iced_wgpu::Renderer>;
+
+        pub use iced_winit::table::{Column, Edit, Order, State};
+    }
+
+    pub mod tabs {
+        //! Switch between pages of content with a strip of tabs.
+        //!
+        //! A [`Tabs`] widget has some local [`State`].
+        //!
+        //! [`Tabs`]: struct.Tabs.html
+        //! [`State`]: struct.State.html
+
+        /// A strip of tabs on top of the content of the active one.
+        ///
+        /// This is an alias of an `iced_native` tabs widget with a default
+        /// `Renderer`.
+        pub type Tabs<'a, Message> =
+            iced_winit::Tabs<'a, Message, iced_wgpu::Renderer>;
+
+        pub use iced_winit::tabs::{State, Style, Tab};
     }
 
     pub mod image {
         //! Display images in your user interface.
-        pub use iced_winit::image::{Handle, Image};
+        pub use iced_winit::image::{
+            Filter, Fit, Handle, Image, Sampling, Slices,
+        };
     }
 
     pub mod svg {
@@ -85,12 +449,31 @@ pub mod widget {
         pub use iced_winit::svg::{Handle, Svg};
     }
 
-    pub use iced_winit::{Checkbox, Radio, Text};
+    pub use iced_winit::{
+        Avatar, CacheStats, Checkbox, ProgressBar, Radio, Spinner,
+    };
+
+    #[cfg(feature = "markdown")]
+    #[doc(no_inline)]
+    pub use markdown::Markdown;
+
+    #[cfg(feature = "qr_code")]
+    #[doc(no_inline)]
+    pub use qr_code::QrCode;
 
     #[doc(no_inline)]
     pub use {
-        button::Button, image::Image, scrollable::Scrollable, slider::Slider,
-        svg::Svg, text_input::TextInput,
+        badge::Badge, button::Button, cached::Cached, combo_box::ComboBox,
+        custom_layout::CustomLayout, date_picker::DatePicker,
+        draggable::Draggable, image::Image, knob::Knob, link::Link,
+        menu_bar::MenuBar, minimap::MiniMap, modal::Modal,
+        mouse_area::MouseArea, navigation::Navigation,
+        number_input::NumberInput, range_slider::RangeSlider, rating::Rating,
+        reveal::Reveal, rich_text::RichText, scrollable::Scrollable,
+        segmented_button::SegmentedButton, selectable_text::SelectableText,
+        slider::Slider, svg::Svg, table::Table, tabs::Tabs, text::Text,
+        text_input::TextInput, time_picker::TimePicker, timeline::Timeline,
+        xy_pad::XYPad,
     };
 
     /// A container that distributes its contents vertically.
@@ -99,6 +482,28 @@ pub mod widget {
     pub type Column<'a, Message> =
         iced_winit::Column<'a, Message, iced_wgpu::Renderer>;
 
+    /// A vertical list that only builds, lays out, and draws its visible
+    /// rows.
+    ///
+    /// This is an alias of an `iced_native` lazy column with a default
+    /// `Renderer`.
+    pub type LazyColumn<'a, Message> =
+        iced_winit::LazyColumn<'a, Message, iced_wgpu::Renderer>;
+
+    /// A container that keeps its content at a fixed ratio between its width
+    /// and its height.
+    ///
+    /// This is an alias of an `iced_native` aspect ratio with a default
+    /// `Renderer`.
+    pub type AspectRatio<'a, Message> =
+        iced_winit::AspectRatio<'a, Message, iced_wgpu::Renderer>;
+
+    /// A container that distributes its contents in rows and columns.
+    ///
+    /// This is an alias of an `iced_native` grid with a default `Renderer`.
+    pub type Grid<'a, Message> =
+        iced_winit::Grid<'a, Message, iced_wgpu::Renderer>;
+
     /// A container that distributes its contents horizontally.
     ///
     /// This is an alias of an `iced_native` row with a default `Renderer`.
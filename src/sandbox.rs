@@ -19,7 +19,7 @@ use crate::{Application, Command, Element, Settings, Subscription};
 /// to remove the use of [`Command`]:
 ///
 /// ```no_run
-/// use iced::{button, Button, Column, Element, Sandbox, Settings, Text};
+/// use iced::{Button, Column, Element, Sandbox, Settings, Text};
 ///
 /// pub fn main() {
 ///     Counter::run(Settings::default())
@@ -28,8 +28,6 @@ use crate::{Application, Command, Element, Settings, Subscription};
 /// #[derive(Default)]
 /// struct Counter {
 ///     value: i32,
-///     increment_button: button::State,
-///     decrement_button: button::State,
 /// }
 ///
 /// #[derive(Debug, Clone, Copy)]
@@ -60,17 +58,17 @@ use crate::{Application, Command, Element, Settings, Subscription};
 ///         }
 ///     }
 ///
-///     fn view(&mut self) -> Element<Message> {
+///     fn view(&self) -> Element<Message> {
 ///         Column::new()
 ///             .push(
-///                 Button::new(&mut self.increment_button, Text::new("Increment"))
+///                 Button::managed(Text::new("Increment"))
 ///                     .on_press(Message::IncrementPressed),
 ///             )
 ///             .push(
 ///                 Text::new(self.value.to_string()).size(50),
 ///             )
 ///             .push(
-///                 Button::new(&mut self.decrement_button, Text::new("Decrement"))
+///                 Button::managed(Text::new("Decrement"))
 ///                     .on_press(Message::DecrementPressed),
 ///             )
 ///             .into()
@@ -110,8 +108,12 @@ pub trait Sandbox {
     ///
     /// These widgets can produce __messages__ based on user interaction.
     ///
+    /// The runtime keeps the local state of widgets created with `managed`,
+    /// like a `Button::managed`, so the [`Sandbox`] only needs to be
+    /// borrowed to be viewed.
+    ///
     /// [`Sandbox`]: trait.Sandbox.html
-    fn view(&mut self) -> Element<'_, Self::Message>;
+    fn view(&self) -> Element<'_, Self::Message>;
 
     /// Runs the [`Sandbox`].
     ///
@@ -153,7 +155,7 @@ where
         Subscription::none()
     }
 
-    fn view(&mut self) -> Element<'_, T::Message> {
+    fn view(&self) -> Element<'_, T::Message> {
         T::view(self)
     }
 }
@@ -1,7 +1,7 @@
 //! Configure your application.
 
 /// The settings of an application.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Settings {
     /// The [`Window`] settings.
     ///
@@ -9,6 +9,105 @@ pub struct Settings {
     ///
     /// [`Window`]: struct.Window.html
     pub window: Window,
+
+    /// Whether consecutive cursor movements received in the same frame
+    /// should be delivered as a single movement to the latest position.
+    ///
+    /// Disable it if your application needs every intermediate position of
+    /// the cursor, like a drawing tool.
+    ///
+    /// By default, it is enabled. It will be ignored on the Web.
+    pub coalesce_cursor_moves: bool,
+
+    /// The [`Scroll`] settings.
+    ///
+    /// They will be ignored on the Web.
+    ///
+    /// [`Scroll`]: struct.Scroll.html
+    pub scroll: Scroll,
+
+    /// The [`TextRendering`] settings
+    ///
+    /// [`TextRendering`]: struct.TextRendering.html
+    pub text_rendering: TextRendering,
+
+    /// The schemes of the URLs that commands are allowed to open.
+    ///
+    /// URLs with any other scheme are dropped without being opened. Add the
+    /// schemes your application needs, like `file`, explicitly.
+    ///
+    /// By default, only `http`, `https`, and `mailto` URLs are opened. It
+    /// will be ignored on the Web.
+    pub url_schemes: &'static [&'static str],
+}
+
+impl Default for Settings {
+    fn default() -> Settings {
+        Settings {
+            window: Window::default(),
+            coalesce_cursor_moves: true,
+            scroll: Scroll::default(),
+            text_rendering: TextRendering::default(),
+            url_schemes: &["http", "https", "mailto"],
+        }
+    }
+}
+
+/// The scroll settings of an application.
+///
+/// Mouse wheels scroll by lines on most platforms, while trackpads scroll by
+/// pixels. Tune these settings if scrolling feels too slow or too fast.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Scroll {
+    /// The logical pixels scrolled per line.
+    ///
+    /// By default, it is `60.0`.
+    pub line_height: f32,
+
+    /// The factor applied to every scroll movement.
+    ///
+    /// By default, it is `1.0`.
+    pub multiplier: f32,
+}
+
+impl Default for Scroll {
+    fn default() -> Scroll {
+        Scroll {
+            line_height: 60.0,
+            multiplier: 1.0,
+        }
+    }
+}
+
+/// How the glyphs of text are rasterized.
+///
+/// Small text on a screen with a low DPI looks blurry with the default
+/// grayscale antialiasing. Subpixel antialiasing and hinting make it look
+/// sharper, like the text of most native toolkits.
+///
+/// It is ignored on the Web, where the browser rasterizes text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TextRendering {
+    /// How the edges of glyphs are smoothed.
+    pub antialiasing: TextAntialiasing,
+
+    /// Whether the baseline of every line of text is snapped to the pixel
+    /// grid.
+    ///
+    /// By default, it is disabled.
+    pub hinting: bool,
+}
+
+/// How the edges of glyphs are smoothed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextAntialiasing {
+    /// Every pixel is covered by a shade of the color of the text.
+    #[default]
+    Grayscale,
+
+    /// The red, green, and blue subpixels of every pixel are covered
+    /// separately, for screens with subpixels in RGB order.
+    SubpixelRgb,
 }
 
 /// The window settings of an application.
@@ -44,6 +143,23 @@ impl From<Settings> for iced_winit::Settings {
                 decorations: settings.window.decorations,
                 platform_specific: Default::default(),
             },
+            coalesce_cursor_moves: settings.coalesce_cursor_moves,
+            scroll: iced_winit::settings::Scroll {
+                line_height: settings.scroll.line_height,
+                multiplier: settings.scroll.multiplier,
+            },
+            text_rendering: iced_winit::settings::TextRendering {
+                antialiasing: match settings.text_rendering.antialiasing {
+                    TextAntialiasing::Grayscale => {
+                        iced_winit::settings::TextAntialiasing::Grayscale
+                    }
+                    TextAntialiasing::SubpixelRgb => {
+                        iced_winit::settings::TextAntialiasing::SubpixelRgb
+                    }
+                },
+                hinting: settings.text_rendering.hinting,
+            },
+            url_schemes: settings.url_schemes,
         }
     }
 }
@@ -72,7 +72,8 @@ pub use dodrio;
 pub use element::Element;
 pub use hasher::Hasher;
 pub use iced_core::{
-    Align, Background, Color, Command, Font, HorizontalAlignment, Length,
+    dialog, font, text, toast, Align, Background, Color, Command, Dialog, Font,
+    Gradient, HorizontalAlignment, Length, Shadow, Toast, Vector,
     VerticalAlignment,
 };
 pub use style::Style;
@@ -190,10 +191,12 @@ where
             let mut instance = self.clone();
 
             let future = future.map(move |message| {
-                instance.update(message);
+                if let Some(message) = message {
+                    instance.update(message);
 
-                if let Some(ref vdom) = *instance.vdom.borrow() {
-                    vdom.schedule_render();
+                    if let Some(ref vdom) = *instance.vdom.borrow() {
+                        vdom.schedule_render();
+                    }
                 }
             });
 
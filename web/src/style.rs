@@ -156,6 +156,7 @@ pub fn color(Color { r, g, b, a }: Color) -> String {
 pub fn align(align: Align) -> &'static str {
     match align {
         Align::Start => "flex-start",
+        Align::Baseline => "baseline",
         Align::Center => "center",
         Align::End => "flex-end",
     }
@@ -39,6 +39,22 @@ impl<'a, Message> Button<'a, Message> {
     /// [`Button`]: struct.Button.html
     /// [`State`]: struct.State.html
     pub fn new<E>(_state: &'a mut State, content: E) -> Self
+    where
+        E: Into<Element<'a, Message>>,
+    {
+        Self::managed(content)
+    }
+
+    /// Creates a new [`Button`] with the given content, without any
+    /// [`State`].
+    ///
+    /// The web keeps the state of a [`Button`] in the DOM, so this is the same
+    /// as [`new`]. It matches the native API.
+    ///
+    /// [`Button`]: struct.Button.html
+    /// [`State`]: struct.State.html
+    /// [`new`]: #method.new
+    pub fn managed<E>(content: E) -> Self
     where
         E: Into<Element<'a, Message>>,
     {
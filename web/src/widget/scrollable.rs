@@ -17,6 +17,18 @@ impl<'a, Message> Scrollable<'a, Message> {
     /// [`Scrollable`]: struct.Scrollable.html
     /// [`State`]: struct.State.html
     pub fn new(_state: &'a mut State) -> Self {
+        Self::managed()
+    }
+
+    /// Creates a new [`Scrollable`] without any [`State`].
+    ///
+    /// The web keeps the state of a [`Scrollable`] in the DOM, so this is the
+    /// same as [`new`]. It matches the native API.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`State`]: struct.State.html
+    /// [`new`]: #method.new
+    pub fn managed() -> Self {
         use std::u32;
 
         Scrollable {
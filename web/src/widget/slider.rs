@@ -7,7 +7,7 @@
 use crate::{style, Bus, Element, Length, Widget};
 
 use dodrio::bumpalo;
-use std::{ops::RangeInclusive, rc::Rc};
+use std::{marker::PhantomData, ops::RangeInclusive, rc::Rc};
 
 /// An horizontal bar and a handle that selects a single value from a range of
 /// values.
@@ -33,7 +33,7 @@ use std::{ops::RangeInclusive, rc::Rc};
 /// ![Slider drawn by Coffee's renderer](https://github.com/hecrj/coffee/blob/bda9818f823dfcb8a7ad0ff4940b4d4b387b5208/images/ui/slider.png?raw=true)
 #[allow(missing_debug_implementations)]
 pub struct Slider<'a, Message> {
-    _state: &'a mut State,
+    _state: PhantomData<&'a State>,
     range: RangeInclusive<f32>,
     value: f32,
     on_change: Rc<Box<dyn Fn(f32) -> Message>>,
@@ -54,7 +54,26 @@ impl<'a, Message> Slider<'a, Message> {
     /// [`Slider`]: struct.Slider.html
     /// [`State`]: struct.State.html
     pub fn new<F>(
-        state: &'a mut State,
+        _state: &'a mut State,
+        range: RangeInclusive<f32>,
+        value: f32,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(f32) -> Message,
+    {
+        Self::managed(range, value, on_change)
+    }
+
+    /// Creates a new [`Slider`] without any [`State`].
+    ///
+    /// The web keeps the state of a [`Slider`] in the DOM, so this is the same
+    /// as [`new`]. It matches the native API.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    /// [`State`]: struct.State.html
+    /// [`new`]: #method.new
+    pub fn managed<F>(
         range: RangeInclusive<f32>,
         value: f32,
         on_change: F,
@@ -63,7 +82,7 @@ impl<'a, Message> Slider<'a, Message> {
         F: 'static + Fn(f32) -> Message,
     {
         Slider {
-            _state: state,
+            _state: PhantomData,
             value: value.max(*range.start()).min(*range.end()),
             range,
             on_change: Rc::new(Box::new(on_change)),
@@ -1,6 +1,6 @@
 use crate::{
-    style, Bus, Color, Element, Font, HorizontalAlignment, Length,
-    VerticalAlignment, Widget,
+    font, style, text, Bus, Color, Element, Font, HorizontalAlignment, Length,
+    Shadow, VerticalAlignment, Widget,
 };
 use dodrio::bumpalo;
 
@@ -19,7 +19,9 @@ pub struct Text {
     content: String,
     size: Option<u16>,
     color: Option<Color>,
+    shadow: Option<Shadow>,
     font: Font,
+    params: text::Params,
     width: Length,
     height: Length,
     horizontal_alignment: HorizontalAlignment,
@@ -35,7 +37,9 @@ impl Text {
             content: label.into(),
             size: None,
             color: None,
+            shadow: None,
             font: Font::Default,
+            params: text::Params::default(),
             width: Length::Fill,
             height: Length::Shrink,
             horizontal_alignment: HorizontalAlignment::Left,
@@ -60,6 +64,15 @@ impl Text {
         self
     }
 
+    /// Sets the [`Shadow`] of the [`Text`].
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`Shadow`]: ../../struct.Shadow.html
+    pub fn shadow(mut self, shadow: Shadow) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
     /// Sets the [`Font`] of the [`Text`].
     ///
     /// [`Text`]: struct.Text.html
@@ -69,6 +82,43 @@ impl Text {
         self
     }
 
+    /// Sets the height of the lines of the [`Text`], relative to the natural
+    /// line height of its [`Font`].
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`Font`]: ../../struct.Font.html
+    pub fn line_height(mut self, line_height: f32) -> Self {
+        self.params.line_height = line_height;
+        self
+    }
+
+    /// Sets the space added after every glyph of the [`Text`], in logical
+    /// pixels.
+    ///
+    /// [`Text`]: struct.Text.html
+    pub fn letter_spacing(mut self, letter_spacing: f32) -> Self {
+        self.params.letter_spacing = letter_spacing;
+        self
+    }
+
+    /// Sets the [`Wrap`] strategy of the [`Text`].
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`Wrap`]: ../text/enum.Wrap.html
+    pub fn wrap(mut self, wrap: text::Wrap) -> Self {
+        self.params.wrap = wrap;
+        self
+    }
+
+    /// Sets the [`Decoration`] of the [`Text`], like an underline.
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`Decoration`]: ../text/struct.Decoration.html
+    pub fn decoration(mut self, decoration: text::Decoration) -> Self {
+        self.params.decoration = decoration;
+        self
+    }
+
     /// Sets the width of the [`Text`] boundaries.
     ///
     /// [`Text`]: struct.Text.html
@@ -128,14 +178,92 @@ impl<'a, Message> Widget<Message> for Text {
             HorizontalAlignment::Right => "right",
         };
 
+        let text_shadow = match self.shadow {
+            Some(shadow) => format!(
+                "{}px {}px {}px {}",
+                shadow.offset.x,
+                shadow.offset.y,
+                shadow.blur_radius,
+                style::color(shadow.color)
+            ),
+            None => String::from("none"),
+        };
+
+        // Only system fonts can be referenced by name in the browser
+        let font = match self.font {
+            Font::System {
+                family,
+                weight,
+                style,
+            } => format!(
+                "font-family: \"{}\"; font-weight: {}; font-style: {}; ",
+                family,
+                weight.value(),
+                match style {
+                    font::Style::Normal => "normal",
+                    font::Style::Italic => "italic",
+                    font::Style::Oblique => "oblique",
+                }
+            ),
+            _ => String::new(),
+        };
+
+        // `normal` is roughly 1.2 times the size of most fonts
+        let line_height = self.params.line_height * 1.2;
+
+        let (white_space, word_break) = match self.params.wrap {
+            text::Wrap::Word => ("normal", "normal"),
+            text::Wrap::Character => ("normal", "break-all"),
+            text::Wrap::None => ("pre", "normal"),
+        };
+
+        let decoration = &self.params.decoration;
+
+        let text_decoration = if decoration.is_empty() {
+            String::from("none")
+        } else {
+            let lines: Vec<&str> = [
+                (decoration.underline, "underline"),
+                (decoration.strikethrough, "line-through"),
+                (decoration.overline, "overline"),
+            ]
+            .iter()
+            .filter(|(is_drawn, _)| *is_drawn)
+            .map(|(_, line)| *line)
+            .collect();
+
+            format!(
+                "{} {} {}",
+                lines.join(" "),
+                decoration
+                    .color
+                    .map(style::color)
+                    .unwrap_or_else(|| String::from("currentcolor")),
+                decoration
+                    .thickness
+                    .map(|thickness| format!("{}px", thickness))
+                    .unwrap_or_else(|| String::from("auto")),
+            )
+        };
+
         let style = bumpalo::format!(
             in bump,
-            "width: {}; height: {}; font-size: {}px; color: {}; text-align: {}",
+            "{}line-height: {}; letter-spacing: {}px; white-space: {}; \
+            word-break: {}; width: {}; height: {}; font-size: {}px; \
+            color: {}; text-align: {}; text-shadow: {}; \
+            text-decoration: {}",
+            font,
+            line_height,
+            self.params.letter_spacing,
+            white_space,
+            word_break,
             width,
             height,
             self.size.unwrap_or(20),
             color,
-            text_align
+            text_align,
+            text_shadow,
+            text_decoration
         );
 
         // TODO: Complete styling
@@ -5,7 +5,7 @@
 //! [`TextInput`]: struct.TextInput.html
 //! [`State`]: struct.State.html
 use crate::{bumpalo, style, Bus, Element, Length, Style, Widget};
-use std::rc::Rc;
+use std::{marker::PhantomData, rc::Rc};
 
 /// A field that can be filled with text.
 ///
@@ -29,7 +29,7 @@ use std::rc::Rc;
 /// ```
 #[allow(missing_debug_implementations)]
 pub struct TextInput<'a, Message> {
-    _state: &'a mut State,
+    _state: PhantomData<&'a State>,
     placeholder: String,
     value: String,
     width: Length,
@@ -52,16 +52,31 @@ impl<'a, Message> TextInput<'a, Message> {
     /// [`TextInput`]: struct.TextInput.html
     /// [`State`]: struct.State.html
     pub fn new<F>(
-        state: &'a mut State,
+        _state: &'a mut State,
         placeholder: &str,
         value: &str,
         on_change: F,
     ) -> Self
+    where
+        F: 'static + Fn(String) -> Message,
+    {
+        Self::managed(placeholder, value, on_change)
+    }
+
+    /// Creates a new [`TextInput`] without any [`State`].
+    ///
+    /// The web keeps the state of a [`TextInput`] in the DOM, so this is the
+    /// same as [`new`]. It matches the native API.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    /// [`State`]: struct.State.html
+    /// [`new`]: #method.new
+    pub fn managed<F>(placeholder: &str, value: &str, on_change: F) -> Self
     where
         F: 'static + Fn(String) -> Message,
     {
         Self {
-            _state: state,
+            _state: PhantomData,
             placeholder: String::from(placeholder),
             value: String::from(value),
             width: Length::Fill,
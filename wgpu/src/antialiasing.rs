@@ -0,0 +1,572 @@
+use crate::renderer::TRANSPARENT;
+use iced_native::Rectangle;
+
+/// A strategy to smooth the edges of the shapes drawn by the [`Renderer`].
+///
+/// Quads already have smooth rounded corners, but their straight edges look
+/// jagged once they are rotated or scaled by a `Primitive::Transform`.
+///
+/// [`Renderer`]: struct.Renderer.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Antialiasing {
+    /// Multisample antialiasing of quads, with 2 samples per pixel.
+    MSAAx2,
+
+    /// Multisample antialiasing of quads, with 4 samples per pixel.
+    ///
+    /// Every adapter supports it.
+    MSAAx4,
+
+    /// Multisample antialiasing of quads, with 8 samples per pixel.
+    ///
+    /// Not every adapter supports it. Prefer [`MSAAx4`] unless you know the
+    /// hardware your application runs on.
+    ///
+    /// [`MSAAx4`]: #variant.MSAAx4
+    MSAAx8,
+
+    /// Fast approximate antialiasing, a post-processing pass over the whole
+    /// frame.
+    ///
+    /// It is cheaper than multisampling and smooths every edge, including
+    /// images and custom primitives, but it blurs text slightly.
+    FXAA,
+}
+
+impl Antialiasing {
+    /// Returns the amount of samples per pixel of the [`Antialiasing`]
+    /// strategy.
+    ///
+    /// [`Antialiasing`]: enum.Antialiasing.html
+    pub fn sample_count(self) -> u32 {
+        match self {
+            Antialiasing::MSAAx2 => 2,
+            Antialiasing::MSAAx4 => 4,
+            Antialiasing::MSAAx8 => 8,
+            Antialiasing::FXAA => 1,
+        }
+    }
+
+    /// Returns whether the [`Antialiasing`] strategy works on every adapter.
+    ///
+    /// [`Antialiasing`]: enum.Antialiasing.html
+    pub fn is_portable(self) -> bool {
+        self != Antialiasing::MSAAx8
+    }
+}
+
+// Quads drawn with many samples per pixel, then resolved and composited
+#[derive(Debug)]
+pub struct Msaa {
+    format: wgpu::TextureFormat,
+    sample_count: u32,
+    blit: Blit,
+    targets: Option<Targets>,
+}
+
+#[derive(Debug)]
+struct Targets {
+    width: u16,
+    height: u16,
+    _attachment: wgpu::Texture,
+    attachment_view: wgpu::TextureView,
+    _resolve: wgpu::Texture,
+    resolve_view: wgpu::TextureView,
+    bind_group: wgpu::BindGroup,
+}
+
+impl Msaa {
+    pub fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        sample_count: u32,
+    ) -> Self {
+        // The resolved quads have their alpha premultiplied already
+        let blit = Blit::new(
+            device,
+            format,
+            include_bytes!("shader/blit.frag.spv"),
+            wgpu::BlendDescriptor {
+                src_factor: wgpu::BlendFactor::One,
+                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                operation: wgpu::BlendOperation::Add,
+            },
+        );
+
+        Msaa {
+            format,
+            sample_count,
+            blit,
+            targets: None,
+        }
+    }
+
+    /// Returns a cleared multisampled attachment and the texture it resolves
+    /// to, with the given size.
+    pub fn targets(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        width: u16,
+        height: u16,
+    ) -> (&wgpu::TextureView, &wgpu::TextureView) {
+        let is_outdated = match &self.targets {
+            Some(targets) => targets.width != width || targets.height != height,
+            None => true,
+        };
+
+        if is_outdated {
+            let attachment =
+                texture(device, self.format, width, height, self.sample_count);
+            let resolve = texture(device, self.format, width, height, 1);
+
+            let attachment_view = attachment.create_default_view();
+            let resolve_view = resolve.create_default_view();
+            let bind_group = self.blit.bind(device, &resolve_view);
+
+            self.targets = Some(Targets {
+                width,
+                height,
+                _attachment: attachment,
+                attachment_view,
+                _resolve: resolve,
+                resolve_view,
+                bind_group,
+            });
+        }
+
+        let targets = self.targets.as_ref().unwrap();
+
+        let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            color_attachments: &[wgpu::RenderPassColorAttachmentDescriptor {
+                attachment: &targets.attachment_view,
+                resolve_target: None,
+                load_op: wgpu::LoadOp::Clear,
+                store_op: wgpu::StoreOp::Store,
+                clear_color: TRANSPARENT,
+            }],
+            depth_stencil_attachment: None,
+        });
+
+        (&targets.attachment_view, &targets.resolve_view)
+    }
+
+    /// Draws the resolved quads over the target, inside of the given bounds.
+    pub fn composite(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+        bounds: Rectangle<u32>,
+    ) {
+        if let Some(targets) = &self.targets {
+            self.blit.draw(
+                encoder,
+                &targets.bind_group,
+                target,
+                wgpu::LoadOp::Load,
+                bounds,
+            );
+        }
+    }
+}
+
+// The whole frame drawn into a texture, then smoothed into the target
+#[derive(Debug)]
+pub struct Fxaa {
+    format: wgpu::TextureFormat,
+    blit: Blit,
+    source: Option<Source>,
+}
+
+#[derive(Debug)]
+struct Source {
+    width: u16,
+    height: u16,
+    _texture: wgpu::Texture,
+    view: wgpu::TextureView,
+    bind_group: wgpu::BindGroup,
+}
+
+impl Fxaa {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+        let blit = Blit::new(
+            device,
+            format,
+            include_bytes!("shader/fxaa.frag.spv"),
+            wgpu::BlendDescriptor {
+                src_factor: wgpu::BlendFactor::One,
+                dst_factor: wgpu::BlendFactor::Zero,
+                operation: wgpu::BlendOperation::Add,
+            },
+        );
+
+        Fxaa {
+            format,
+            blit,
+            source: None,
+        }
+    }
+
+    /// Returns the texture the frame should be drawn into, with the given
+    /// size.
+    pub fn source(
+        &mut self,
+        device: &wgpu::Device,
+        width: u16,
+        height: u16,
+    ) -> &wgpu::TextureView {
+        let is_outdated = match &self.source {
+            Some(source) => source.width != width || source.height != height,
+            None => true,
+        };
+
+        if is_outdated {
+            let texture = texture(device, self.format, width, height, 1);
+            let view = texture.create_default_view();
+            let bind_group = self.blit.bind(device, &view);
+
+            self.source = Some(Source {
+                width,
+                height,
+                _texture: texture,
+                view,
+                bind_group,
+            });
+        }
+
+        &self.source.as_ref().unwrap().view
+    }
+
+    /// Smooths the drawn frame into the target, replacing its contents.
+    pub fn apply(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+    ) {
+        if let Some(source) = &self.source {
+            self.blit.draw(
+                encoder,
+                &source.bind_group,
+                target,
+                wgpu::LoadOp::Clear,
+                Rectangle {
+                    x: 0,
+                    y: 0,
+                    width: u32::from(source.width),
+                    height: u32::from(source.height),
+                },
+            );
+        }
+    }
+}
+
+// Text drawn three times wider than the target, then composited with a
+// coverage for each subpixel
+#[derive(Debug)]
+pub struct Subpixel {
+    format: wgpu::TextureFormat,
+    coverage: Blit,
+    color: Blit,
+    source: Option<SubpixelSource>,
+}
+
+#[derive(Debug)]
+struct SubpixelSource {
+    width: u16,
+    height: u16,
+    _texture: wgpu::Texture,
+    view: wgpu::TextureView,
+    coverage_bind_group: wgpu::BindGroup,
+    color_bind_group: wgpu::BindGroup,
+}
+
+impl Subpixel {
+    /// The widest target supported, so the source fits in the maximum
+    /// texture size of every adapter.
+    pub const MAX_WIDTH: u16 = 8192 / 3;
+
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+        // Without dual-source blending, every subpixel is blended with its
+        // own coverage in two passes: the first one darkens the target by the
+        // coverage, and the second one adds the color of the text
+        let coverage = Blit::new(
+            device,
+            format,
+            include_bytes!("shader/subpixel_coverage.frag.spv"),
+            wgpu::BlendDescriptor {
+                src_factor: wgpu::BlendFactor::Zero,
+                dst_factor: wgpu::BlendFactor::OneMinusSrcColor,
+                operation: wgpu::BlendOperation::Add,
+            },
+        );
+
+        let color = Blit::new(
+            device,
+            format,
+            include_bytes!("shader/subpixel_color.frag.spv"),
+            wgpu::BlendDescriptor {
+                src_factor: wgpu::BlendFactor::One,
+                dst_factor: wgpu::BlendFactor::One,
+                operation: wgpu::BlendOperation::Add,
+            },
+        );
+
+        Subpixel {
+            format,
+            coverage,
+            color,
+            source: None,
+        }
+    }
+
+    /// Returns a cleared texture three times wider than a target with the
+    /// given size, for the text to be drawn into.
+    pub fn source(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        width: u16,
+        height: u16,
+    ) -> &wgpu::TextureView {
+        let is_outdated = match &self.source {
+            Some(source) => source.width != width || source.height != height,
+            None => true,
+        };
+
+        if is_outdated {
+            let texture = texture(device, self.format, width * 3, height, 1);
+            let view = texture.create_default_view();
+            let coverage_bind_group = self.coverage.bind(device, &view);
+            let color_bind_group = self.color.bind(device, &view);
+
+            self.source = Some(SubpixelSource {
+                width,
+                height,
+                _texture: texture,
+                view,
+                coverage_bind_group,
+                color_bind_group,
+            });
+        }
+
+        let source = self.source.as_ref().unwrap();
+
+        let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            color_attachments: &[wgpu::RenderPassColorAttachmentDescriptor {
+                attachment: &source.view,
+                resolve_target: None,
+                load_op: wgpu::LoadOp::Clear,
+                store_op: wgpu::StoreOp::Store,
+                clear_color: TRANSPARENT,
+            }],
+            depth_stencil_attachment: None,
+        });
+
+        &source.view
+    }
+
+    /// Blends the drawn text over the target, inside of the given bounds.
+    pub fn composite(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+        bounds: Rectangle<u32>,
+    ) {
+        if let Some(source) = &self.source {
+            self.coverage.draw(
+                encoder,
+                &source.coverage_bind_group,
+                target,
+                wgpu::LoadOp::Load,
+                bounds,
+            );
+
+            self.color.draw(
+                encoder,
+                &source.color_bind_group,
+                target,
+                wgpu::LoadOp::Load,
+                bounds,
+            );
+        }
+    }
+}
+
+// A pipeline drawing a texture over the whole target
+#[derive(Debug)]
+pub struct Blit {
+    pipeline: wgpu::RenderPipeline,
+    layout: wgpu::BindGroupLayout,
+    sampler: wgpu::Sampler,
+}
+
+impl Blit {
+    pub fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        fragment_shader: &[u8],
+        blend: wgpu::BlendDescriptor,
+    ) -> Self {
+        let layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                bindings: &[
+                    wgpu::BindGroupLayoutBinding {
+                        binding: 0,
+                        visibility: wgpu::ShaderStage::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler,
+                    },
+                    wgpu::BindGroupLayoutBinding {
+                        binding: 1,
+                        visibility: wgpu::ShaderStage::FRAGMENT,
+                        ty: wgpu::BindingType::SampledTexture {
+                            multisampled: false,
+                            dimension: wgpu::TextureViewDimension::D2,
+                        },
+                    },
+                ],
+            });
+
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            mipmap_filter: wgpu::FilterMode::Nearest,
+            lod_min_clamp: -100.0,
+            lod_max_clamp: 100.0,
+            compare_function: wgpu::CompareFunction::Always,
+        });
+
+        let pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                bind_group_layouts: &[&layout],
+            });
+
+        let vs = include_bytes!("shader/fullscreen.vert.spv");
+        let vs_module = device.create_shader_module(
+            &wgpu::read_spirv(std::io::Cursor::new(&vs[..]))
+                .expect("Read fullscreen vertex shader as SPIR-V"),
+        );
+
+        let fs_module = device.create_shader_module(
+            &wgpu::read_spirv(std::io::Cursor::new(fragment_shader))
+                .expect("Read antialiasing fragment shader as SPIR-V"),
+        );
+
+        let pipeline =
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                layout: &pipeline_layout,
+                vertex_stage: wgpu::ProgrammableStageDescriptor {
+                    module: &vs_module,
+                    entry_point: "main",
+                },
+                fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
+                    module: &fs_module,
+                    entry_point: "main",
+                }),
+                rasterization_state: Some(wgpu::RasterizationStateDescriptor {
+                    front_face: wgpu::FrontFace::Cw,
+                    cull_mode: wgpu::CullMode::None,
+                    depth_bias: 0,
+                    depth_bias_slope_scale: 0.0,
+                    depth_bias_clamp: 0.0,
+                }),
+                primitive_topology: wgpu::PrimitiveTopology::TriangleList,
+                color_states: &[wgpu::ColorStateDescriptor {
+                    format,
+                    color_blend: blend.clone(),
+                    alpha_blend: blend,
+                    write_mask: wgpu::ColorWrite::ALL,
+                }],
+                depth_stencil_state: None,
+                index_format: wgpu::IndexFormat::Uint16,
+                vertex_buffers: &[],
+                sample_count: 1,
+                sample_mask: !0,
+                alpha_to_coverage_enabled: false,
+            });
+
+        Blit {
+            pipeline,
+            layout,
+            sampler,
+        }
+    }
+
+    pub fn bind(
+        &self,
+        device: &wgpu::Device,
+        view: &wgpu::TextureView,
+    ) -> wgpu::BindGroup {
+        device.create_bind_group(&wgpu::BindGroupDescriptor {
+            layout: &self.layout,
+            bindings: &[
+                wgpu::Binding {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Sampler(&self.sampler),
+                },
+                wgpu::Binding {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(view),
+                },
+            ],
+        })
+    }
+
+    pub fn draw(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        bind_group: &wgpu::BindGroup,
+        target: &wgpu::TextureView,
+        load_op: wgpu::LoadOp,
+        bounds: Rectangle<u32>,
+    ) {
+        let mut render_pass =
+            encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                color_attachments: &[
+                    wgpu::RenderPassColorAttachmentDescriptor {
+                        attachment: target,
+                        resolve_target: None,
+                        load_op,
+                        store_op: wgpu::StoreOp::Store,
+                        clear_color: TRANSPARENT,
+                    },
+                ],
+                depth_stencil_attachment: None,
+            });
+
+        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_bind_group(0, bind_group, &[]);
+        render_pass.set_scissor_rect(
+            bounds.x,
+            bounds.y,
+            bounds.width,
+            bounds.height,
+        );
+        render_pass.draw(0..3, 0..1);
+    }
+}
+
+pub fn texture(
+    device: &wgpu::Device,
+    format: wgpu::TextureFormat,
+    width: u16,
+    height: u16,
+    sample_count: u32,
+) -> wgpu::Texture {
+    device.create_texture(&wgpu::TextureDescriptor {
+        size: wgpu::Extent3d {
+            width: u32::from(width),
+            height: u32::from(height),
+            depth: 1,
+        },
+        array_layer_count: 1,
+        mip_level_count: 1,
+        sample_count,
+        dimension: wgpu::TextureDimension::D2,
+        format,
+        usage: wgpu::TextureUsage::OUTPUT_ATTACHMENT
+            | wgpu::TextureUsage::SAMPLED,
+    })
+}
@@ -0,0 +1,133 @@
+use crate::Primitive;
+
+/// A pool of allocations taken from old primitives.
+///
+/// Building a [`Primitive`] allocates: groups need a `Vec`, clips and
+/// transformations need a `Box`, and text needs a `String`. Most of them
+/// look alike from one frame to the next, so the [`Renderer`] keeps the
+/// allocations of the last replaced primitive and hands them back while the
+/// next one is built.
+///
+/// The [`Arena`] is double-buffered: the primitive on the screen stays
+/// intact, and only the one it replaced is taken apart. Recycled values are
+/// always cleared, so primitives built from the [`Arena`] never see stale
+/// contents.
+///
+/// [`Primitive`]: enum.Primitive.html
+/// [`Renderer`]: struct.Renderer.html
+/// [`Arena`]: struct.Arena.html
+#[derive(Debug)]
+pub struct Arena {
+    groups: Vec<Vec<Primitive>>,
+    boxes: Vec<Box<Primitive>>,
+    strings: Vec<String>,
+    limit: usize,
+}
+
+impl Arena {
+    /// The amount of allocations of each kind kept by default.
+    pub const DEFAULT_LIMIT: usize = 4096;
+
+    /// Creates a new [`Arena`] keeping up to `limit` allocations of each
+    /// kind.
+    ///
+    /// A limit of `0` disables recycling.
+    ///
+    /// [`Arena`]: struct.Arena.html
+    pub fn new(limit: usize) -> Self {
+        Arena {
+            groups: Vec::new(),
+            boxes: Vec::new(),
+            strings: Vec::new(),
+            limit,
+        }
+    }
+
+    /// Returns the amount of allocations of each kind the [`Arena`] keeps.
+    ///
+    /// [`Arena`]: struct.Arena.html
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Sets the amount of allocations of each kind the [`Arena`] keeps,
+    /// freeing any allocation above it.
+    ///
+    /// [`Arena`]: struct.Arena.html
+    pub fn set_limit(&mut self, limit: usize) {
+        self.limit = limit;
+
+        self.groups.truncate(limit);
+        self.boxes.truncate(limit);
+        self.strings.truncate(limit);
+    }
+
+    /// Returns an empty list of primitives, to build a `Primitive::Group`.
+    pub fn group(&mut self) -> Vec<Primitive> {
+        self.groups.pop().unwrap_or_default()
+    }
+
+    /// Puts a primitive in a box, to build a `Primitive::Clip` or any other
+    /// primitive wrapping some content.
+    pub fn boxed(&mut self, primitive: Primitive) -> Box<Primitive> {
+        match self.boxes.pop() {
+            Some(mut boxed) => {
+                *boxed = primitive;
+                boxed
+            }
+            None => Box::new(primitive),
+        }
+    }
+
+    /// Copies some text into a `String`, to build a `Primitive::Text`.
+    pub fn string(&mut self, content: &str) -> String {
+        let mut string = self.strings.pop().unwrap_or_default();
+        string.push_str(content);
+        string
+    }
+
+    /// Takes a primitive apart, keeping its allocations for later.
+    ///
+    /// Cached primitives are shared with other frames, so they are left
+    /// untouched.
+    pub fn recycle(&mut self, primitive: Primitive) {
+        if self.limit == 0 {
+            return;
+        }
+
+        match primitive {
+            Primitive::Group { mut primitives } => {
+                for primitive in primitives.drain(..) {
+                    self.recycle(primitive);
+                }
+
+                if self.groups.len() < self.limit {
+                    self.groups.push(primitives);
+                }
+            }
+            Primitive::Text { mut content, .. } => {
+                if self.strings.len() < self.limit {
+                    content.clear();
+                    self.strings.push(content);
+                }
+            }
+            Primitive::Clip { mut content, .. }
+            | Primitive::CircularClip { mut content, .. }
+            | Primitive::Transform { mut content, .. }
+            | Primitive::Transparency { mut content, .. } => {
+                self.recycle(std::mem::take(&mut *content));
+
+                if self.boxes.len() < self.limit {
+                    self.boxes.push(content);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Default for Arena {
+    fn default() -> Self {
+        Arena::new(Self::DEFAULT_LIMIT)
+    }
+}
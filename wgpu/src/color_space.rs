@@ -0,0 +1,92 @@
+use iced_native::Color;
+
+/// The color space of the targets of the [`Renderer`].
+///
+/// It decides the format of the swap chain, of offscreen textures, and of
+/// every pipeline drawing into them, together with how colors are blended.
+///
+/// [`Renderer`]: struct.Renderer.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorSpace {
+    /// sRGB, with colors blended in linear light.
+    ///
+    /// Blending is physically correct, but thin antialiased edges, like the
+    /// ones of text, look lighter than in most other toolkits.
+    ///
+    /// Targets use the `Bgra8UnormSrgb` format.
+    Srgb,
+
+    /// sRGB, with colors blended as they are encoded.
+    ///
+    /// This is how browsers and most other toolkits blend colors, so
+    /// gradients, text, and antialiased edges look like they do there.
+    ///
+    /// Targets use the `Bgra8Unorm` format.
+    GammaSrgb,
+
+    /// Extended linear sRGB, also known as scRGB, with colors blended in
+    /// linear light.
+    ///
+    /// Components can go below `0.0` and above `1.0`, so colors outside of
+    /// the sRGB gamut can be displayed on wide-gamut and HDR screens. The
+    /// surface must support it.
+    ///
+    /// Targets use the `Rgba16Float` format.
+    ExtendedLinearSrgb,
+}
+
+impl ColorSpace {
+    /// Returns the [`ColorSpace`] matching the given texture format, if any.
+    ///
+    /// Use it to pick the [`ColorSpace`] of the format preferred by a
+    /// surface.
+    ///
+    /// [`ColorSpace`]: enum.ColorSpace.html
+    pub fn from_format(format: wgpu::TextureFormat) -> Option<Self> {
+        match format {
+            wgpu::TextureFormat::Bgra8UnormSrgb
+            | wgpu::TextureFormat::Rgba8UnormSrgb => Some(ColorSpace::Srgb),
+            wgpu::TextureFormat::Bgra8Unorm
+            | wgpu::TextureFormat::Rgba8Unorm => Some(ColorSpace::GammaSrgb),
+            wgpu::TextureFormat::Rgba16Float => {
+                Some(ColorSpace::ExtendedLinearSrgb)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the texture format of the targets of the [`ColorSpace`].
+    ///
+    /// [`ColorSpace`]: enum.ColorSpace.html
+    pub fn format(self) -> wgpu::TextureFormat {
+        match self {
+            ColorSpace::Srgb => wgpu::TextureFormat::Bgra8UnormSrgb,
+            ColorSpace::GammaSrgb => wgpu::TextureFormat::Bgra8Unorm,
+            ColorSpace::ExtendedLinearSrgb => wgpu::TextureFormat::Rgba16Float,
+        }
+    }
+
+    /// Returns whether colors are blended in linear light.
+    ///
+    /// When they are not, pipelines must write encoded sRGB values.
+    pub fn is_linear(self) -> bool {
+        self != ColorSpace::GammaSrgb
+    }
+
+    /// Converts a [`Color`] into the values pipelines should write.
+    ///
+    /// [`Color`]: ../iced_native/struct.Color.html
+    pub(crate) fn encode(self, color: Color) -> [f32; 4] {
+        if self.is_linear() {
+            color.into_linear()
+        } else {
+            [color.r, color.g, color.b, color.a]
+        }
+    }
+}
+
+impl Default for ColorSpace {
+    fn default() -> Self {
+        ColorSpace::Srgb
+    }
+}
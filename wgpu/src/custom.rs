@@ -0,0 +1,173 @@
+//! Draw primitives with your own [`wgpu`] pipelines.
+//!
+//! A [`Primitive::Custom`] lets a [`Program`] record its own render passes
+//! while the renderer flushes its layers. This is useful to embed 3D
+//! viewports, shader effects, or video frames in a user interface.
+//!
+//! Pipelines are expensive to create, so a [`Program`] should create them
+//! once and keep them in the [`Storage`] of the renderer.
+//!
+//! # Example
+//! ```ignore
+//! #[derive(Debug)]
+//! struct Scene {
+//!     camera: Camera,
+//! }
+//!
+//! impl custom::Program for Scene {
+//!     fn draw(
+//!         &self,
+//!         storage: &mut custom::Storage,
+//!         device: &mut wgpu::Device,
+//!         encoder: &mut wgpu::CommandEncoder,
+//!         target: &wgpu::TextureView,
+//!         format: wgpu::TextureFormat,
+//!         viewport: custom::Viewport,
+//!     ) {
+//!         let pipeline = storage
+//!             .get_or_insert_with(|| ScenePipeline::new(device, format));
+//!
+//!         pipeline.draw(device, encoder, target, viewport, &self.camera);
+//!     }
+//! }
+//!
+//! let primitive = Primitive::Custom {
+//!     bounds,
+//!     program: Arc::new(Scene { camera }),
+//! };
+//! ```
+//!
+//! [`wgpu`]: https://github.com/gfx-rs/wgpu-rs
+//! [`Primitive::Custom`]: ../enum.Primitive.html#variant.Custom
+//! [`Program`]: trait.Program.html
+//! [`Storage`]: struct.Storage.html
+use iced_native::Rectangle;
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    fmt,
+    time::Duration,
+};
+
+/// The drawing logic of a [`Primitive::Custom`].
+///
+/// [`Primitive::Custom`]: ../enum.Primitive.html#variant.Custom
+pub trait Program: fmt::Debug + Send + Sync {
+    /// Records the commands drawing the primitive.
+    ///
+    /// It receives:
+    /// - the [`Storage`] of the renderer, where pipelines can be kept
+    ///   between frames
+    /// - the device and the command encoder of the renderer
+    /// - the texture being drawn and its format
+    /// - the [`Viewport`] of the primitive
+    ///
+    /// The commands run after anything drawn before the primitive, and before
+    /// anything drawn after it. The load operation of your render passes
+    /// should be `Load`, or the rest of the user interface will be erased.
+    ///
+    /// [`Storage`]: struct.Storage.html
+    /// [`Viewport`]: struct.Viewport.html
+    fn draw(
+        &self,
+        storage: &mut Storage,
+        device: &mut wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+        format: wgpu::TextureFormat,
+        viewport: Viewport,
+    );
+
+    /// Receives the time spent recording the commands of the primitive in
+    /// the last frame.
+    ///
+    /// Use it to keep frame statistics per primitive, like the time each of
+    /// your viewports takes to draw. Only the CPU time of [`draw`] is
+    /// measured; the GPU runs the commands later, once the frame is
+    /// submitted. A primitive drawn many times in a frame receives one call
+    /// per instance.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`draw`]: #tymethod.draw
+    fn drawn(&self, _duration: Duration) {}
+}
+
+/// The region of the target where a [`Program`] draws.
+///
+/// [`Program`]: trait.Program.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    /// The bounds of the primitive, in physical pixels.
+    pub bounds: Rectangle,
+
+    /// The visible region of the target, in physical pixels.
+    ///
+    /// You should use it as the scissor rectangle of your render passes, so
+    /// the primitive does not overflow scrollables and other clips.
+    pub clip: Rectangle<u32>,
+
+    /// The width and the height of the target, in physical pixels.
+    pub target_size: (u32, u32),
+
+    /// The amount of physical pixels per logical pixel.
+    pub scale_factor: f32,
+}
+
+/// The resources of every [`Program`], kept by the renderer between frames.
+///
+/// Each type of value is stored once.
+///
+/// [`Program`]: trait.Program.html
+#[derive(Default)]
+pub struct Storage {
+    values: HashMap<TypeId, Box<dyn Any>>,
+}
+
+impl Storage {
+    /// Returns whether a value of type `T` is stored.
+    pub fn has<T: 'static>(&self) -> bool {
+        self.values.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Stores a value, replacing any other value of the same type.
+    pub fn store<T: 'static>(&mut self, value: T) {
+        let _ = self.values.insert(TypeId::of::<T>(), Box::new(value));
+    }
+
+    /// Returns the stored value of type `T`, if any.
+    pub fn get<T: 'static>(&self) -> Option<&T> {
+        self.values
+            .get(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_ref())
+    }
+
+    /// Returns the stored value of type `T` mutably, if any.
+    pub fn get_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        self.values
+            .get_mut(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_mut())
+    }
+
+    /// Returns the stored value of type `T`, storing the result of the given
+    /// closure first if there is none.
+    pub fn get_or_insert_with<T: 'static>(
+        &mut self,
+        f: impl FnOnce() -> T,
+    ) -> &mut T {
+        self.values
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::new(f()))
+            .downcast_mut()
+            .expect("Downcast stored value")
+    }
+}
+
+impl fmt::Debug for Storage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Storage")
+            .field("values", &self.values.len())
+            .finish()
+    }
+}
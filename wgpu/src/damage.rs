@@ -0,0 +1,303 @@
+//! Find the regions of the screen that change between two frames.
+use crate::Primitive;
+use iced_native::{Font, Rectangle};
+
+use std::sync::Arc;
+
+/// Compares two primitives and returns the regions that differ between them.
+///
+/// The regions are given in the coordinates of the target, before scaling by
+/// its DPI. An empty list means both primitives draw exactly the same.
+pub fn regions(previous: &Primitive, current: &Primitive) -> Vec<Rectangle> {
+    let mut regions = Vec::new();
+
+    diff(previous, current, &mut regions);
+
+    regions
+}
+
+fn diff(
+    previous: &Primitive,
+    current: &Primitive,
+    regions: &mut Vec<Rectangle>,
+) {
+    match (previous, current) {
+        (Primitive::None, Primitive::None) => {}
+        (
+            Primitive::Group { primitives: a },
+            Primitive::Group { primitives: b },
+        ) if a.len() == b.len() => {
+            for (a, b) in a.iter().zip(b) {
+                diff(a, b, regions);
+            }
+        }
+        (
+            Primitive::Clip {
+                bounds,
+                offset,
+                content: a,
+            },
+            Primitive::Clip {
+                bounds: current_bounds,
+                offset: current_offset,
+                content: b,
+            },
+        ) if bounds == current_bounds && offset == current_offset => {
+            let mut damaged = Vec::new();
+            diff(a, b, &mut damaged);
+
+            regions.extend(damaged.into_iter().filter_map(|region| {
+                intersection(
+                    Rectangle {
+                        x: region.x - offset.x as f32,
+                        y: region.y - offset.y as f32,
+                        ..region
+                    },
+                    *bounds,
+                )
+            }));
+        }
+        (
+            Primitive::CircularClip {
+                center,
+                radius,
+                content: a,
+            },
+            Primitive::CircularClip {
+                center: current_center,
+                radius: current_radius,
+                content: b,
+            },
+        ) if center == current_center && radius == current_radius => {
+            let mut damaged = Vec::new();
+            diff(a, b, &mut damaged);
+
+            regions.extend(
+                damaged.into_iter().filter_map(|region| {
+                    intersection(region, current.bounds()?)
+                }),
+            );
+        }
+        (
+            Primitive::Transform {
+                transformation,
+                content: a,
+            },
+            Primitive::Transform {
+                transformation: current_transformation,
+                content: b,
+            },
+        ) if transformation == current_transformation => {
+            let mut damaged = Vec::new();
+            diff(a, b, &mut damaged);
+
+            regions.extend(
+                damaged
+                    .into_iter()
+                    .map(|region| transformation.transform_bounds(region)),
+            );
+        }
+        (
+            Primitive::Transparency { alpha, content: a },
+            Primitive::Transparency {
+                alpha: current_alpha,
+                content: b,
+            },
+        ) if alpha == current_alpha => {
+            diff(a, b, regions);
+        }
+        (Primitive::Cached { cache: a }, Primitive::Cached { cache: b }) => {
+            // Reusing the same cache is the cheapest way to tell that nothing
+            // changed
+            if !Arc::ptr_eq(a, b) {
+                diff(a, b, regions);
+            }
+        }
+        _ if is_same_leaf(previous, current) => {}
+        _ => {
+            regions.extend(previous.bounds());
+            regions.extend(current.bounds());
+        }
+    }
+}
+
+fn is_same_leaf(previous: &Primitive, current: &Primitive) -> bool {
+    match (previous, current) {
+        (
+            Primitive::Text {
+                content,
+                bounds,
+                color,
+                size,
+                font,
+                params,
+                horizontal_alignment,
+                vertical_alignment,
+            },
+            Primitive::Text {
+                content: current_content,
+                bounds: current_bounds,
+                color: current_color,
+                size: current_size,
+                font: current_font,
+                params: current_params,
+                horizontal_alignment: current_horizontal_alignment,
+                vertical_alignment: current_vertical_alignment,
+            },
+        ) => {
+            content == current_content
+                && bounds == current_bounds
+                && color == current_color
+                && size == current_size
+                && is_same_font(*font, *current_font)
+                && params == current_params
+                && horizontal_alignment == current_horizontal_alignment
+                && vertical_alignment == current_vertical_alignment
+        }
+        (
+            Primitive::Paragraph {
+                spans,
+                bounds,
+                params,
+                horizontal_alignment,
+                vertical_alignment,
+            },
+            Primitive::Paragraph {
+                spans: current_spans,
+                bounds: current_bounds,
+                params: current_params,
+                horizontal_alignment: current_horizontal_alignment,
+                vertical_alignment: current_vertical_alignment,
+            },
+        ) => {
+            spans.len() == current_spans.len()
+                && spans.iter().zip(current_spans).all(|(a, b)| {
+                    a.content == b.content
+                        && a.size == b.size
+                        && is_same_font(a.font, b.font)
+                        && a.weight == b.weight
+                        && a.color == b.color
+                })
+                && bounds == current_bounds
+                && params == current_params
+                && horizontal_alignment == current_horizontal_alignment
+                && vertical_alignment == current_vertical_alignment
+        }
+        (
+            Primitive::Quad {
+                bounds,
+                background,
+                border_radius,
+                shadow,
+            },
+            Primitive::Quad {
+                bounds: current_bounds,
+                background: current_background,
+                border_radius: current_border_radius,
+                shadow: current_shadow,
+            },
+        ) => {
+            bounds == current_bounds
+                && background == current_background
+                && border_radius == current_border_radius
+                && shadow == current_shadow
+        }
+        (
+            Primitive::Image {
+                handle,
+                bounds,
+                border_radius,
+                fit,
+            },
+            Primitive::Image {
+                handle: current_handle,
+                bounds: current_bounds,
+                border_radius: current_border_radius,
+                fit: current_fit,
+            },
+        ) => {
+            handle.id() == current_handle.id()
+                && bounds == current_bounds
+                && border_radius == current_border_radius
+                && fit == current_fit
+        }
+        (
+            Primitive::NinePatch {
+                handle,
+                bounds,
+                slices,
+            },
+            Primitive::NinePatch {
+                handle: current_handle,
+                bounds: current_bounds,
+                slices: current_slices,
+            },
+        ) => {
+            handle.id() == current_handle.id()
+                && bounds == current_bounds
+                && slices == current_slices
+        }
+        (
+            Primitive::Svg {
+                handle,
+                bounds,
+                border_radius,
+                color,
+            },
+            Primitive::Svg {
+                handle: current_handle,
+                bounds: current_bounds,
+                border_radius: current_border_radius,
+                color: current_color,
+            },
+        ) => {
+            handle.id() == current_handle.id()
+                && bounds == current_bounds
+                && border_radius == current_border_radius
+                && color == current_color
+        }
+        _ => false,
+    }
+}
+
+fn is_same_font(a: Font, b: Font) -> bool {
+    match (a, b) {
+        (Font::Default, Font::Default) => true,
+        // Comparing the names avoids comparing the bytes of the fonts
+        (Font::External { name: a, .. }, Font::External { name: b, .. }) => {
+            a == b
+        }
+        (
+            Font::System {
+                family: a,
+                weight: a_weight,
+                style: a_style,
+            },
+            Font::System {
+                family: b,
+                weight: b_weight,
+                style: b_style,
+            },
+        ) => a == b && a_weight == b_weight && a_style == b_style,
+        _ => false,
+    }
+}
+
+fn intersection(a: Rectangle, b: Rectangle) -> Option<Rectangle> {
+    let x = a.x.max(b.x);
+    let y = a.y.max(b.y);
+
+    let width = (a.x + a.width).min(b.x + b.width) - x;
+    let height = (a.y + a.height).min(b.y + b.height) - y;
+
+    if width > 0.0 && height > 0.0 {
+        Some(Rectangle {
+            x,
+            y,
+            width,
+            height,
+        })
+    } else {
+        None
+    }
+}
@@ -0,0 +1,31 @@
+/// The vertical metrics of a font at some size, in logical pixels.
+///
+/// They are the same metrics the [`Renderer`] uses to lay out and draw text,
+/// so custom text widgets can use them to align their content with the
+/// baseline or to size their lines.
+///
+/// [`Renderer`]: struct.Renderer.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FontMetrics {
+    /// The distance from the baseline to the top of the tallest glyphs.
+    pub ascent: f32,
+
+    /// The distance from the baseline to the bottom of the lowest glyphs. It
+    /// is negative.
+    pub descent: f32,
+
+    /// The extra space the font recommends between two lines.
+    pub line_gap: f32,
+}
+
+impl FontMetrics {
+    /// Returns the height of a line of text, from the top of one line to the
+    /// top of the next one.
+    ///
+    /// It does not include the line height multiplier of a [`text::Params`].
+    ///
+    /// [`text::Params`]: ../iced_native/text/struct.Params.html
+    pub fn line_height(&self) -> f32 {
+        self.ascent - self.descent + self.line_gap
+    }
+}
@@ -0,0 +1,36 @@
+/// The configuration of the cache of rasterized glyphs of the [`Renderer`].
+///
+/// Glyphs are rasterized once and kept in a texture, the atlas. When the
+/// atlas is full, the glyphs used least recently are evicted first. The
+/// atlas only grows when the glyphs of a single frame do not fit, and growing
+/// rasterizes every glyph again. Apps showing many different glyphs at once,
+/// like CJK text, should start with a bigger atlas.
+///
+/// [`Renderer`]: struct.Renderer.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GlyphCache {
+    /// The initial width and height of the atlas, in pixels.
+    pub size: (u32, u32),
+
+    /// How far, in pixels, a glyph can move from the position it was
+    /// rasterized at before it is rasterized again.
+    ///
+    /// Glyphs are positioned with subpixel precision. A larger tolerance
+    /// reuses more glyphs when text moves, like while a window is resized,
+    /// at the cost of slightly less accurate positions.
+    pub position_tolerance: f32,
+
+    /// How much the scale of a glyph can change before it is rasterized
+    /// again, relative to the scale it was rasterized at.
+    pub scale_tolerance: f32,
+}
+
+impl Default for GlyphCache {
+    fn default() -> Self {
+        GlyphCache {
+            size: (2048, 2048),
+            position_tolerance: 0.1,
+            scale_tolerance: 0.1,
+        }
+    }
+}
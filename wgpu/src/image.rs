@@ -2,10 +2,10 @@ mod raster;
 #[cfg(feature = "svg")]
 mod vector;
 
-use crate::Transformation;
-use iced_native::{image, svg, Rectangle};
+use crate::{texture, ColorSpace, Transformation};
+use iced_native::{cache_stats, image, svg, Color, Rectangle};
 
-use std::{cell::RefCell, mem};
+use std::{cell::RefCell, mem, rc::Rc, time::Instant};
 
 #[derive(Debug)]
 pub struct Pipeline {
@@ -14,28 +14,19 @@ pub struct Pipeline {
     vector_cache: RefCell<vector::Cache>,
 
     pipeline: wgpu::RenderPipeline,
+    layout: wgpu::PipelineLayout,
+    encode_srgb: bool,
     uniforms: wgpu::Buffer,
     vertices: wgpu::Buffer,
     indices: wgpu::Buffer,
     instances: wgpu::Buffer,
-    constants: wgpu::BindGroup,
+    linear_constants: wgpu::BindGroup,
+    nearest_constants: wgpu::BindGroup,
     texture_layout: wgpu::BindGroupLayout,
 }
 
 impl Pipeline {
-    pub fn new(device: &wgpu::Device) -> Self {
-        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
-            address_mode_u: wgpu::AddressMode::ClampToEdge,
-            address_mode_v: wgpu::AddressMode::ClampToEdge,
-            address_mode_w: wgpu::AddressMode::ClampToEdge,
-            mag_filter: wgpu::FilterMode::Linear,
-            min_filter: wgpu::FilterMode::Linear,
-            mipmap_filter: wgpu::FilterMode::Linear,
-            lod_min_clamp: -100.0,
-            lod_max_clamp: 100.0,
-            compare_function: wgpu::CompareFunction::Always,
-        });
-
+    pub fn new(device: &wgpu::Device, color_space: ColorSpace) -> Self {
         let constant_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
                 bindings: &[
@@ -63,7 +54,20 @@ impl Pipeline {
             )
             .fill_from_slice(&[uniforms]);
 
-        let constant_bind_group =
+        // Each filter needs its own sampler
+        let constants = |filter| {
+            let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+                address_mode_u: wgpu::AddressMode::ClampToEdge,
+                address_mode_v: wgpu::AddressMode::ClampToEdge,
+                address_mode_w: wgpu::AddressMode::ClampToEdge,
+                mag_filter: filter,
+                min_filter: filter,
+                mipmap_filter: filter,
+                lod_min_clamp: -100.0,
+                lod_max_clamp: 100.0,
+                compare_function: wgpu::CompareFunction::Always,
+            });
+
             device.create_bind_group(&wgpu::BindGroupDescriptor {
                 layout: &constant_layout,
                 bindings: &[
@@ -79,7 +83,11 @@ impl Pipeline {
                         resource: wgpu::BindingResource::Sampler(&sampler),
                     },
                 ],
-            });
+            })
+        };
+
+        let linear_constants = constants(wgpu::FilterMode::Linear);
+        let nearest_constants = constants(wgpu::FilterMode::Nearest);
 
         let texture_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
@@ -98,84 +106,7 @@ impl Pipeline {
                 bind_group_layouts: &[&constant_layout, &texture_layout],
             });
 
-        let vs = include_bytes!("shader/image.vert.spv");
-        let vs_module = device.create_shader_module(
-            &wgpu::read_spirv(std::io::Cursor::new(&vs[..]))
-                .expect("Read image vertex shader as SPIR-V"),
-        );
-
-        let fs = include_bytes!("shader/image.frag.spv");
-        let fs_module = device.create_shader_module(
-            &wgpu::read_spirv(std::io::Cursor::new(&fs[..]))
-                .expect("Read image fragment shader as SPIR-V"),
-        );
-
-        let pipeline =
-            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-                layout: &layout,
-                vertex_stage: wgpu::ProgrammableStageDescriptor {
-                    module: &vs_module,
-                    entry_point: "main",
-                },
-                fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
-                    module: &fs_module,
-                    entry_point: "main",
-                }),
-                rasterization_state: Some(wgpu::RasterizationStateDescriptor {
-                    front_face: wgpu::FrontFace::Cw,
-                    cull_mode: wgpu::CullMode::None,
-                    depth_bias: 0,
-                    depth_bias_slope_scale: 0.0,
-                    depth_bias_clamp: 0.0,
-                }),
-                primitive_topology: wgpu::PrimitiveTopology::TriangleList,
-                color_states: &[wgpu::ColorStateDescriptor {
-                    format: wgpu::TextureFormat::Bgra8UnormSrgb,
-                    color_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::SrcAlpha,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
-                    },
-                    alpha_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::One,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
-                    },
-                    write_mask: wgpu::ColorWrite::ALL,
-                }],
-                depth_stencil_state: None,
-                index_format: wgpu::IndexFormat::Uint16,
-                vertex_buffers: &[
-                    wgpu::VertexBufferDescriptor {
-                        stride: mem::size_of::<Vertex>() as u64,
-                        step_mode: wgpu::InputStepMode::Vertex,
-                        attributes: &[wgpu::VertexAttributeDescriptor {
-                            shader_location: 0,
-                            format: wgpu::VertexFormat::Float2,
-                            offset: 0,
-                        }],
-                    },
-                    wgpu::VertexBufferDescriptor {
-                        stride: mem::size_of::<Instance>() as u64,
-                        step_mode: wgpu::InputStepMode::Instance,
-                        attributes: &[
-                            wgpu::VertexAttributeDescriptor {
-                                shader_location: 1,
-                                format: wgpu::VertexFormat::Float2,
-                                offset: 0,
-                            },
-                            wgpu::VertexAttributeDescriptor {
-                                shader_location: 2,
-                                format: wgpu::VertexFormat::Float2,
-                                offset: 4 * 2,
-                            },
-                        ],
-                    },
-                ],
-                sample_count: 1,
-                sample_mask: !0,
-                alpha_to_coverage_enabled: false,
-            });
+        let pipeline = render_pipeline(device, &layout, color_space.format());
 
         let vertices = device
             .create_buffer_mapped(QUAD_VERTS.len(), wgpu::BufferUsage::VERTEX)
@@ -196,15 +127,30 @@ impl Pipeline {
             vector_cache: RefCell::new(vector::Cache::new()),
 
             pipeline,
+            layout,
+            encode_srgb: !color_space.is_linear(),
             uniforms: uniforms_buffer,
             vertices,
             indices,
             instances,
-            constants: constant_bind_group,
+            linear_constants,
+            nearest_constants,
             texture_layout,
         }
     }
 
+    /// Rebuilds the pipeline for the given color space, keeping the cached
+    /// images.
+    pub fn set_color_space(
+        &mut self,
+        device: &wgpu::Device,
+        color_space: ColorSpace,
+    ) {
+        self.pipeline =
+            render_pipeline(device, &self.layout, color_space.format());
+        self.encode_srgb = !color_space.is_linear();
+    }
+
     pub fn dimensions(&self, handle: &image::Handle) -> (u32, u32) {
         let mut cache = self.raster_cache.borrow_mut();
         let memory = cache.load(&handle);
@@ -220,6 +166,21 @@ impl Pipeline {
         svg.viewport_dimensions()
     }
 
+    /// Binds a texture drawn by the renderer, so it can be drawn as an image.
+    pub fn bind_texture(
+        &self,
+        device: &wgpu::Device,
+        view: &wgpu::TextureView,
+    ) -> Rc<wgpu::BindGroup> {
+        Rc::new(device.create_bind_group(&wgpu::BindGroupDescriptor {
+            layout: &self.texture_layout,
+            bindings: &[wgpu::Binding {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureView(view),
+            }],
+        }))
+    }
+
     pub fn draw(
         &mut self,
         device: &mut wgpu::Device,
@@ -229,7 +190,10 @@ impl Pipeline {
         bounds: Rectangle<u32>,
         target: &wgpu::TextureView,
         _scale: f32,
-    ) {
+    ) -> Option<Instant> {
+        let now = Instant::now();
+        let mut next_frame_at: Option<Instant> = None;
+
         let uniforms_buffer = device
             .create_buffer_mapped(1, wgpu::BufferUsage::COPY_SRC)
             .fill_from_slice(&[Uniforms {
@@ -249,20 +213,48 @@ impl Pipeline {
         //
         // [1]: https://github.com/nical/guillotiere
         for image in instances {
+            let filter = match &image.handle {
+                Handle::Raster(handle) => handle.sampling().filter,
+                Handle::Vector(..) => image::Filter::Linear,
+                // Textures drawn by the renderer match the screen
+                Handle::Texture(_) => image::Filter::Nearest,
+                Handle::View(_) => image::Filter::Linear,
+            };
+
+            // Sampling sRGB textures decodes their colors, so they must be
+            // encoded again when blending encoded colors. Textures drawn by
+            // the renderer already have the format of the target.
+            let encode_srgb = self.encode_srgb
+                && match &image.handle {
+                    Handle::Texture(_) => false,
+                    _ => true,
+                };
+
             let uploaded_texture = match &image.handle {
                 Handle::Raster(handle) => {
                     let mut cache = self.raster_cache.borrow_mut();
                     let memory = cache.load(&handle);
 
-                    memory.upload(device, encoder, &self.texture_layout)
+                    if let Some(at) = memory.next_frame_at(now) {
+                        next_frame_at =
+                            Some(next_frame_at.map_or(at, |next| next.min(at)));
+                    }
+
+                    memory.upload(
+                        device,
+                        encoder,
+                        &self.texture_layout,
+                        handle.sampling().mipmaps,
+                    )
                 }
-                Handle::Vector(_handle) => {
+                Handle::Vector(_handle, _color) => {
                     #[cfg(feature = "svg")]
                     {
                         let mut cache = self.vector_cache.borrow_mut();
 
                         cache.upload(
                             _handle,
+                            *_color,
                             image.scale,
                             _scale,
                             device,
@@ -274,6 +266,10 @@ impl Pipeline {
                     #[cfg(not(feature = "svg"))]
                     None
                 }
+                Handle::Texture(bind_group) => Some(bind_group.clone()),
+                Handle::View(handle) => {
+                    Some(self.bind_texture(device, handle.view()))
+                }
             };
 
             if let Some(texture) = uploaded_texture {
@@ -282,6 +278,12 @@ impl Pipeline {
                     .fill_from_slice(&[Instance {
                         _position: image.position,
                         _scale: image.scale,
+                        _opacity: image.opacity,
+                        _texture_position: image.texture_position,
+                        _texture_scale: image.texture_scale,
+                        _border_radius: image.border_radius,
+                        _tiles: image.tiles,
+                        _encode_srgb: if encode_srgb { 1.0 } else { 0.0 },
                     }]);
 
                 encoder.copy_buffer_to_buffer(
@@ -314,7 +316,14 @@ impl Pipeline {
                     );
 
                     render_pass.set_pipeline(&self.pipeline);
-                    render_pass.set_bind_group(0, &self.constants, &[]);
+                    render_pass.set_bind_group(
+                        0,
+                        match filter {
+                            image::Filter::Linear => &self.linear_constants,
+                            image::Filter::Nearest => &self.nearest_constants,
+                        },
+                        &[],
+                    );
                     render_pass.set_bind_group(1, &texture, &[]);
                     render_pass.set_index_buffer(&self.indices, 0);
                     render_pass.set_vertex_buffers(
@@ -336,6 +345,31 @@ impl Pipeline {
                 }
             }
         }
+
+        next_frame_at
+    }
+
+    pub fn collect_stats(&self, stats: &mut cache_stats::Stats) {
+        self.raster_cache.borrow().collect_stats(stats);
+
+        #[cfg(feature = "svg")]
+        self.vector_cache.borrow().collect_stats(stats);
+    }
+
+    pub fn device_bytes(&self) -> u64 {
+        let bytes = self.raster_cache.borrow().device_bytes();
+
+        #[cfg(feature = "svg")]
+        let bytes = bytes + self.vector_cache.borrow().device_bytes();
+
+        bytes
+    }
+
+    pub fn clear_cache(&mut self) {
+        self.raster_cache.borrow_mut().clear();
+
+        #[cfg(feature = "svg")]
+        self.vector_cache.borrow_mut().clear();
     }
 
     pub fn trim_cache(&mut self) {
@@ -346,15 +380,140 @@ impl Pipeline {
     }
 }
 
+fn render_pipeline(
+    device: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    format: wgpu::TextureFormat,
+) -> wgpu::RenderPipeline {
+    let vs = include_bytes!("shader/image.vert.spv");
+    let vs_module = device.create_shader_module(
+        &wgpu::read_spirv(std::io::Cursor::new(&vs[..]))
+            .expect("Read image vertex shader as SPIR-V"),
+    );
+
+    let fs = include_bytes!("shader/image.frag.spv");
+    let fs_module = device.create_shader_module(
+        &wgpu::read_spirv(std::io::Cursor::new(&fs[..]))
+            .expect("Read image fragment shader as SPIR-V"),
+    );
+
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        layout,
+        vertex_stage: wgpu::ProgrammableStageDescriptor {
+            module: &vs_module,
+            entry_point: "main",
+        },
+        fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
+            module: &fs_module,
+            entry_point: "main",
+        }),
+        rasterization_state: Some(wgpu::RasterizationStateDescriptor {
+            front_face: wgpu::FrontFace::Cw,
+            cull_mode: wgpu::CullMode::None,
+            depth_bias: 0,
+            depth_bias_slope_scale: 0.0,
+            depth_bias_clamp: 0.0,
+        }),
+        primitive_topology: wgpu::PrimitiveTopology::TriangleList,
+        color_states: &[wgpu::ColorStateDescriptor {
+            format,
+            color_blend: wgpu::BlendDescriptor {
+                src_factor: wgpu::BlendFactor::SrcAlpha,
+                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                operation: wgpu::BlendOperation::Add,
+            },
+            alpha_blend: wgpu::BlendDescriptor {
+                src_factor: wgpu::BlendFactor::One,
+                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                operation: wgpu::BlendOperation::Add,
+            },
+            write_mask: wgpu::ColorWrite::ALL,
+        }],
+        depth_stencil_state: None,
+        index_format: wgpu::IndexFormat::Uint16,
+        vertex_buffers: &[
+            wgpu::VertexBufferDescriptor {
+                stride: mem::size_of::<Vertex>() as u64,
+                step_mode: wgpu::InputStepMode::Vertex,
+                attributes: &[wgpu::VertexAttributeDescriptor {
+                    shader_location: 0,
+                    format: wgpu::VertexFormat::Float2,
+                    offset: 0,
+                }],
+            },
+            wgpu::VertexBufferDescriptor {
+                stride: mem::size_of::<Instance>() as u64,
+                step_mode: wgpu::InputStepMode::Instance,
+                attributes: &[
+                    wgpu::VertexAttributeDescriptor {
+                        shader_location: 1,
+                        format: wgpu::VertexFormat::Float2,
+                        offset: 0,
+                    },
+                    wgpu::VertexAttributeDescriptor {
+                        shader_location: 2,
+                        format: wgpu::VertexFormat::Float2,
+                        offset: 4 * 2,
+                    },
+                    wgpu::VertexAttributeDescriptor {
+                        shader_location: 3,
+                        format: wgpu::VertexFormat::Float,
+                        offset: 4 * 4,
+                    },
+                    wgpu::VertexAttributeDescriptor {
+                        shader_location: 4,
+                        format: wgpu::VertexFormat::Float2,
+                        offset: 4 * 5,
+                    },
+                    wgpu::VertexAttributeDescriptor {
+                        shader_location: 5,
+                        format: wgpu::VertexFormat::Float2,
+                        offset: 4 * 7,
+                    },
+                    wgpu::VertexAttributeDescriptor {
+                        shader_location: 6,
+                        format: wgpu::VertexFormat::Float,
+                        offset: 4 * 9,
+                    },
+                    wgpu::VertexAttributeDescriptor {
+                        shader_location: 7,
+                        format: wgpu::VertexFormat::Float2,
+                        offset: 4 * 10,
+                    },
+                    wgpu::VertexAttributeDescriptor {
+                        shader_location: 8,
+                        format: wgpu::VertexFormat::Float,
+                        offset: 4 * 12,
+                    },
+                ],
+            },
+        ],
+        sample_count: 1,
+        sample_mask: !0,
+        alpha_to_coverage_enabled: false,
+    })
+}
+
 pub struct Image {
     pub handle: Handle,
     pub position: [f32; 2],
     pub scale: [f32; 2],
+    pub opacity: f32,
+
+    /// The region of the texture to draw, in normalized coordinates.
+    pub texture_position: [f32; 2],
+    pub texture_scale: [f32; 2],
+
+    /// The amount of times the region of the texture repeats.
+    pub tiles: [f32; 2],
+    pub border_radius: f32,
 }
 
 pub enum Handle {
     Raster(image::Handle),
-    Vector(svg::Handle),
+    Vector(svg::Handle, Option<Color>),
+    Texture(Rc<wgpu::BindGroup>),
+    View(texture::Handle),
 }
 
 #[repr(C)]
@@ -385,6 +544,12 @@ const QUAD_VERTS: [Vertex; 4] = [
 struct Instance {
     _position: [f32; 2],
     _scale: [f32; 2],
+    _opacity: f32,
+    _texture_position: [f32; 2],
+    _texture_scale: [f32; 2],
+    _border_radius: f32,
+    _tiles: [f32; 2],
+    _encode_srgb: f32,
 }
 
 #[repr(C)]
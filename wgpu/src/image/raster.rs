@@ -1,7 +1,12 @@
-use iced_native::image;
+mod animation;
+
+use animation::Animation;
+use iced_native::{cache_stats, image};
 use std::{
     collections::{HashMap, HashSet},
+    path::Path,
     rc::Rc,
+    time::Instant,
 };
 
 #[derive(Debug)]
@@ -12,6 +17,7 @@ pub enum Memory {
         width: u32,
         height: u32,
     },
+    Animated(Animation),
     NotFound,
     Invalid,
 }
@@ -21,31 +27,47 @@ impl Memory {
         match self {
             Memory::Host(image) => image.dimensions(),
             Memory::Device { width, height, .. } => (*width, *height),
+            Memory::Animated(animation) => animation.dimensions(),
             Memory::NotFound => (1, 1),
             Memory::Invalid => (1, 1),
         }
     }
 
+    /// Returns the instant the image changes next, if it is animated.
+    pub fn next_frame_at(&self, now: Instant) -> Option<Instant> {
+        match self {
+            Memory::Animated(animation) => animation.position(now).1,
+            _ => None,
+        }
+    }
+
     pub fn upload(
         &mut self,
         device: &wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
         texture_layout: &wgpu::BindGroupLayout,
+        mipmaps: bool,
     ) -> Option<Rc<wgpu::BindGroup>> {
         match self {
             Memory::Host(image) => {
                 let (width, height) = image.dimensions();
 
-                let extent = wgpu::Extent3d {
-                    width,
-                    height,
-                    depth: 1,
+                // Every level halves the size of the previous one, down to a
+                // single pixel
+                let mip_level_count = if mipmaps {
+                    32 - width.max(height).leading_zeros()
+                } else {
+                    1
                 };
 
                 let texture = device.create_texture(&wgpu::TextureDescriptor {
-                    size: extent,
+                    size: wgpu::Extent3d {
+                        width,
+                        height,
+                        depth: 1,
+                    },
                     array_layer_count: 1,
-                    mip_level_count: 1,
+                    mip_level_count,
                     sample_count: 1,
                     dimension: wgpu::TextureDimension::D2,
                     format: wgpu::TextureFormat::Bgra8UnormSrgb,
@@ -53,37 +75,24 @@ impl Memory {
                         | wgpu::TextureUsage::SAMPLED,
                 });
 
-                let temp_buf = {
-                    let flat_samples = image.as_flat_samples();
-                    let slice = flat_samples.as_slice();
+                upload_level(device, encoder, &texture, image, 0);
 
-                    device
-                        .create_buffer_mapped(
-                            slice.len(),
-                            wgpu::BufferUsage::COPY_SRC,
-                        )
-                        .fill_from_slice(slice)
-                };
+                for level in 1..mip_level_count {
+                    let level_image = ::image::imageops::resize(
+                        image,
+                        (width >> level).max(1),
+                        (height >> level).max(1),
+                        ::image::imageops::FilterType::Triangle,
+                    );
 
-                encoder.copy_buffer_to_texture(
-                    wgpu::BufferCopyView {
-                        buffer: &temp_buf,
-                        offset: 0,
-                        row_pitch: 4 * width as u32,
-                        image_height: height as u32,
-                    },
-                    wgpu::TextureCopyView {
-                        texture: &texture,
-                        array_layer: 0,
-                        mip_level: 0,
-                        origin: wgpu::Origin3d {
-                            x: 0.0,
-                            y: 0.0,
-                            z: 0.0,
-                        },
-                    },
-                    extent,
-                );
+                    upload_level(
+                        device,
+                        encoder,
+                        &texture,
+                        &level_image,
+                        level,
+                    );
+                }
 
                 let bind_group =
                     device.create_bind_group(&wgpu::BindGroupDescriptor {
@@ -107,16 +116,71 @@ impl Memory {
                 Some(bind_group)
             }
             Memory::Device { bind_group, .. } => Some(bind_group.clone()),
+            Memory::Animated(animation) => {
+                // Every frame is uploaded once, the first time it is shown
+                let (index, _) = animation.position(Instant::now());
+
+                animation.frame_mut(index).upload(
+                    device,
+                    encoder,
+                    texture_layout,
+                    mipmaps,
+                )
+            }
             Memory::NotFound => None,
             Memory::Invalid => None,
         }
     }
 }
 
+fn upload_level(
+    device: &wgpu::Device,
+    encoder: &mut wgpu::CommandEncoder,
+    texture: &wgpu::Texture,
+    image: &::image::ImageBuffer<::image::Bgra<u8>, Vec<u8>>,
+    level: u32,
+) {
+    let (width, height) = image.dimensions();
+
+    let temp_buf = {
+        let flat_samples = image.as_flat_samples();
+        let slice = flat_samples.as_slice();
+
+        device
+            .create_buffer_mapped(slice.len(), wgpu::BufferUsage::COPY_SRC)
+            .fill_from_slice(slice)
+    };
+
+    encoder.copy_buffer_to_texture(
+        wgpu::BufferCopyView {
+            buffer: &temp_buf,
+            offset: 0,
+            row_pitch: 4 * width as u32,
+            image_height: height as u32,
+        },
+        wgpu::TextureCopyView {
+            texture,
+            array_layer: 0,
+            mip_level: level,
+            origin: wgpu::Origin3d {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+            },
+        },
+        wgpu::Extent3d {
+            width,
+            height,
+            depth: 1,
+        },
+    );
+}
+
 #[derive(Debug)]
 pub struct Cache {
     map: HashMap<u64, Memory>,
     hits: HashSet<u64>,
+    evicted: usize,
 }
 
 impl Cache {
@@ -124,6 +188,7 @@ impl Cache {
         Self {
             map: HashMap::new(),
             hits: HashSet::new(),
+            evicted: 0,
         }
     }
 
@@ -134,14 +199,26 @@ impl Cache {
 
         let memory = match handle.data() {
             image::Data::Path(path) => {
-                if let Ok(image) = ::image::open(path) {
+                let animation = if may_be_animated(path) {
+                    std::fs::read(path)
+                        .ok()
+                        .and_then(|bytes| Animation::decode(&bytes))
+                } else {
+                    None
+                };
+
+                if let Some(animation) = animation {
+                    Memory::Animated(animation)
+                } else if let Ok(image) = ::image::open(path) {
                     Memory::Host(image.to_bgra())
                 } else {
                     Memory::NotFound
                 }
             }
             image::Data::Bytes(bytes) => {
-                if let Ok(image) = ::image::load_from_memory(&bytes) {
+                if let Some(animation) = Animation::decode(&bytes) {
+                    Memory::Animated(animation)
+                } else if let Ok(image) = ::image::load_from_memory(&bytes) {
                     Memory::Host(image.to_bgra())
                 } else {
                     Memory::Invalid
@@ -155,9 +232,50 @@ impl Cache {
 
     pub fn trim(&mut self) {
         let hits = &self.hits;
+        let cached = self.map.len();
 
         self.map.retain(|k, _| hits.contains(k));
         self.hits.clear();
+
+        self.evicted += cached - self.map.len();
+    }
+
+    pub fn collect_stats(&self, stats: &mut cache_stats::Stats) {
+        for memory in self.map.values() {
+            match memory {
+                // Every frame counts as an image
+                Memory::Animated(animation) => {
+                    for frame in animation.frames() {
+                        collect_stats(frame, stats);
+                    }
+                }
+                _ => collect_stats(memory, stats),
+            }
+        }
+
+        stats.evicted_images += self.evicted;
+    }
+
+    /// Evicts every cached image, even the ones used in the current frame.
+    pub fn clear(&mut self) {
+        self.evicted += self.map.len();
+
+        self.map.clear();
+        self.hits.clear();
+    }
+
+    /// Returns the amount of pixel data of the cached images that live in
+    /// device memory, in bytes.
+    pub fn device_bytes(&self) -> u64 {
+        self.map
+            .values()
+            .map(|memory| match memory {
+                Memory::Animated(animation) => {
+                    animation.frames().map(device_bytes).sum()
+                }
+                _ => device_bytes(memory),
+            })
+            .sum()
     }
 
     fn get(&mut self, handle: &image::Handle) -> Option<&mut Memory> {
@@ -174,3 +292,35 @@ impl Cache {
         self.map.contains_key(&handle.id())
     }
 }
+
+fn device_bytes(memory: &Memory) -> u64 {
+    match memory {
+        Memory::Device { width, height, .. } => {
+            4 * u64::from(*width) * u64::from(*height)
+        }
+        _ => 0,
+    }
+}
+
+fn collect_stats(memory: &Memory, stats: &mut cache_stats::Stats) {
+    match memory {
+        Memory::Host(_) => {}
+        Memory::Device { .. } => stats.uploaded_images += 1,
+        Memory::Animated(_) | Memory::NotFound | Memory::Invalid => return,
+    }
+
+    let (width, height) = memory.dimensions();
+
+    stats.images += 1;
+    stats.image_bytes += 4 * u64::from(width) * u64::from(height);
+}
+
+// Only GIFs and PNGs can be animated, so we avoid reading other files twice
+fn may_be_animated(path: &Path) -> bool {
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some(extension) => ["gif", "png", "apng"]
+            .iter()
+            .any(|animated| extension.eq_ignore_ascii_case(animated)),
+        None => false,
+    }
+}
@@ -0,0 +1,389 @@
+use super::Memory;
+
+use std::time::{Duration, Instant};
+
+type Bgra = ::image::ImageBuffer<::image::Bgra<u8>, Vec<u8>>;
+
+// Browsers show frames with tiny delays for longer, as many files rely on it
+const MIN_DELAY: Duration = Duration::from_millis(20);
+const FALLBACK_DELAY: Duration = Duration::from_millis(100);
+
+/// The frames of an animated image, like a GIF or an APNG, played from the
+/// moment it was decoded.
+#[derive(Debug)]
+pub struct Animation {
+    frames: Vec<Frame>,
+    plays: u32,
+    started: Instant,
+}
+
+#[derive(Debug)]
+struct Frame {
+    memory: Memory,
+    delay: Duration,
+}
+
+impl Animation {
+    /// Decodes the frames of an animated image.
+    ///
+    /// Returns `None` if the image is not animated.
+    pub fn decode(bytes: &[u8]) -> Option<Animation> {
+        let (frames, plays) = match ::image::guess_format(bytes).ok()? {
+            ::image::ImageFormat::GIF => (gif(bytes)?, 0),
+            ::image::ImageFormat::PNG => apng(bytes)?,
+            _ => return None,
+        };
+
+        if frames.len() < 2 {
+            return None;
+        }
+
+        let frames = frames
+            .into_iter()
+            .map(|(image, delay)| Frame {
+                memory: Memory::Host(image),
+                delay: if delay < MIN_DELAY {
+                    FALLBACK_DELAY
+                } else {
+                    delay
+                },
+            })
+            .collect();
+
+        Some(Animation {
+            frames,
+            plays,
+            started: Instant::now(),
+        })
+    }
+
+    pub fn dimensions(&self) -> (u32, u32) {
+        self.frames[0].memory.dimensions()
+    }
+
+    pub fn frames(&self) -> impl Iterator<Item = &Memory> {
+        self.frames.iter().map(|frame| &frame.memory)
+    }
+
+    /// Returns the index of the frame to show at the given instant, and the
+    /// instant the next frame should be shown, if the animation continues.
+    pub fn position(&self, now: Instant) -> (usize, Option<Instant>) {
+        let last = self.frames.len() - 1;
+
+        let duration: Duration =
+            self.frames.iter().map(|frame| frame.delay).sum();
+
+        let elapsed = now.duration_since(self.started).as_nanos();
+        let loop_length = duration.as_nanos().max(1);
+
+        if self.plays > 0 && elapsed / loop_length >= u128::from(self.plays) {
+            return (last, None);
+        }
+
+        let mut remaining =
+            Duration::from_nanos((elapsed % loop_length) as u64);
+        let mut next_frame_at = now - remaining;
+
+        for (index, frame) in self.frames.iter().enumerate() {
+            next_frame_at += frame.delay;
+
+            if remaining < frame.delay {
+                return (index, Some(next_frame_at));
+            }
+
+            remaining -= frame.delay;
+        }
+
+        (last, Some(next_frame_at))
+    }
+
+    pub fn frame_mut(&mut self, index: usize) -> &mut Memory {
+        &mut self.frames[index].memory
+    }
+}
+
+fn gif(bytes: &[u8]) -> Option<Vec<(Bgra, Duration)>> {
+    use ::image::AnimationDecoder;
+
+    let decoder =
+        ::image::gif::Decoder::new(std::io::Cursor::new(bytes)).ok()?;
+
+    let frames = decoder.into_frames().collect_frames().ok()?;
+
+    Some(
+        frames
+            .into_iter()
+            .map(|frame| {
+                let delay = frame.delay().to_integer();
+                let image =
+                    ::image::DynamicImage::ImageRgba8(frame.into_buffer());
+
+                (image.to_bgra(), Duration::from_millis(u64::from(delay)))
+            })
+            .collect(),
+    )
+}
+
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+/// Decodes the frames of an APNG, along with the amount of times it should
+/// be played.
+///
+/// Every frame is rebuilt as a standalone PNG, decoded, and composited over
+/// the previous ones, following the disposal and blending operations of the
+/// frame.
+fn apng(bytes: &[u8]) -> Option<(Vec<(Bgra, Duration)>, u32)> {
+    if !bytes.starts_with(PNG_SIGNATURE) {
+        return None;
+    }
+
+    let mut header = None;
+    let mut plays = None;
+    let mut shared = Vec::new();
+    let mut controls = Vec::new();
+    let mut data: Vec<Vec<u8>> = Vec::new();
+    let mut is_data_found = false;
+
+    let mut rest = &bytes[PNG_SIGNATURE.len()..];
+
+    while rest.len() >= 12 {
+        let length = read_u32(&rest[0..4]) as usize;
+
+        if rest.len() < 12 + length {
+            return None;
+        }
+
+        let kind = &rest[4..8];
+        let chunk = &rest[8..8 + length];
+
+        rest = &rest[12 + length..];
+
+        match kind {
+            b"IHDR" if chunk.len() == 13 => header = Some(chunk),
+            b"acTL" if chunk.len() == 8 => plays = Some(read_u32(&chunk[4..8])),
+            b"fcTL" if chunk.len() == 26 => {
+                controls.push(Control::parse(chunk));
+                data.push(Vec::new());
+            }
+            b"IDAT" => {
+                is_data_found = true;
+
+                // The default image is only part of the animation when a
+                // frame control precedes it
+                if let Some(data) = data.last_mut() {
+                    data.extend_from_slice(chunk);
+                }
+            }
+            b"fdAT" if chunk.len() >= 4 => {
+                is_data_found = true;
+
+                if let Some(data) = data.last_mut() {
+                    data.extend_from_slice(&chunk[4..]);
+                }
+            }
+            b"IEND" => break,
+            _ if !is_data_found => {
+                // Chunks like the palette apply to every frame
+                shared.push((kind, chunk));
+            }
+            _ => {}
+        }
+    }
+
+    let header = header?;
+    let plays = plays?;
+
+    let width = read_u32(&header[0..4]);
+    let height = read_u32(&header[4..8]);
+
+    let mut canvas = ::image::RgbaImage::new(width, height);
+    let mut frames = Vec::with_capacity(controls.len());
+
+    for (control, data) in controls.iter().zip(data) {
+        if data.is_empty() {
+            continue;
+        }
+
+        let mut png = Vec::from(PNG_SIGNATURE);
+
+        let mut frame_header = Vec::from(header);
+        frame_header[0..4].copy_from_slice(&control.width.to_be_bytes());
+        frame_header[4..8].copy_from_slice(&control.height.to_be_bytes());
+
+        write_chunk(&mut png, b"IHDR", &frame_header);
+
+        for (kind, chunk) in &shared {
+            write_chunk(&mut png, kind, chunk);
+        }
+
+        write_chunk(&mut png, b"IDAT", &data);
+        write_chunk(&mut png, b"IEND", &[]);
+
+        let image = ::image::load_from_memory_with_format(
+            &png,
+            ::image::ImageFormat::PNG,
+        )
+        .ok()?
+        .to_rgba();
+
+        let previous = match control.dispose {
+            Dispose::Previous => Some(canvas.clone()),
+            _ => None,
+        };
+
+        for (x, y, pixel) in image.enumerate_pixels() {
+            let x = control.x + x;
+            let y = control.y + y;
+
+            if x >= width || y >= height {
+                continue;
+            }
+
+            let target = canvas.get_pixel_mut(x, y);
+
+            *target = match control.blend {
+                Blend::Source => *pixel,
+                Blend::Over => over(*pixel, *target),
+            };
+        }
+
+        frames.push((
+            ::image::DynamicImage::ImageRgba8(canvas.clone()).to_bgra(),
+            control.delay,
+        ));
+
+        match control.dispose {
+            Dispose::None => {}
+            Dispose::Background => {
+                let right = (control.x + control.width).min(width);
+                let bottom = (control.y + control.height).min(height);
+
+                for y in control.y..bottom {
+                    for x in control.x..right {
+                        canvas.put_pixel(x, y, ::image::Rgba([0, 0, 0, 0]));
+                    }
+                }
+            }
+            Dispose::Previous => {
+                if let Some(previous) = previous {
+                    canvas = previous;
+                }
+            }
+        }
+    }
+
+    Some((frames, plays))
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Control {
+    width: u32,
+    height: u32,
+    x: u32,
+    y: u32,
+    delay: Duration,
+    dispose: Dispose,
+    blend: Blend,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Dispose {
+    None,
+    Background,
+    Previous,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Blend {
+    Source,
+    Over,
+}
+
+impl Control {
+    fn parse(chunk: &[u8]) -> Control {
+        let numerator = u64::from(u16::from_be_bytes([chunk[20], chunk[21]]));
+        let denominator = match u16::from_be_bytes([chunk[22], chunk[23]]) {
+            // A denominator of 0 means hundredths of a second
+            0 => 100,
+            denominator => u64::from(denominator),
+        };
+
+        Control {
+            width: read_u32(&chunk[4..8]),
+            height: read_u32(&chunk[8..12]),
+            x: read_u32(&chunk[12..16]),
+            y: read_u32(&chunk[16..20]),
+            delay: Duration::from_millis(numerator * 1000 / denominator),
+            dispose: match chunk[24] {
+                1 => Dispose::Background,
+                2 => Dispose::Previous,
+                _ => Dispose::None,
+            },
+            blend: match chunk[25] {
+                1 => Blend::Over,
+                _ => Blend::Source,
+            },
+        }
+    }
+}
+
+fn over(
+    ::image::Rgba(source): ::image::Rgba<u8>,
+    ::image::Rgba(target): ::image::Rgba<u8>,
+) -> ::image::Rgba<u8> {
+    let source_alpha = f32::from(source[3]) / 255.0;
+    let target_alpha = f32::from(target[3]) / 255.0 * (1.0 - source_alpha);
+    let alpha = source_alpha + target_alpha;
+
+    if alpha == 0.0 {
+        return ::image::Rgba([0, 0, 0, 0]);
+    }
+
+    let blend = |i: usize| {
+        ((f32::from(source[i]) * source_alpha
+            + f32::from(target[i]) * target_alpha)
+            / alpha)
+            .round() as u8
+    };
+
+    ::image::Rgba([blend(0), blend(1), blend(2), (alpha * 255.0).round() as u8])
+}
+
+fn read_u32(bytes: &[u8]) -> u32 {
+    u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+
+    let start = png.len();
+
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+
+    let crc = crc32(&png[start..]);
+
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut table = [0u32; 256];
+
+    for (n, entry) in table.iter_mut().enumerate() {
+        let mut c = n as u32;
+
+        for _ in 0..8 {
+            c = if c & 1 != 0 {
+                0xEDB8_8320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+        }
+
+        *entry = c;
+    }
+
+    !bytes.iter().fold(!0u32, |crc, byte| {
+        table[((crc ^ u32::from(*byte)) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
@@ -1,4 +1,4 @@
-use iced_native::svg;
+use iced_native::{cache_stats, svg, Color};
 use std::{
     collections::{HashMap, HashSet},
     rc::Rc,
@@ -28,12 +28,16 @@ impl std::fmt::Debug for Svg {
     }
 }
 
+/// A rasterization of an SVG: its id, its size, and its tint, if any.
+type Key = (u64, u32, u32, Option<[u8; 4]>);
+
 #[derive(Debug)]
 pub struct Cache {
     svgs: HashMap<u64, Svg>,
-    rasterized: HashMap<(u64, u32, u32), Rc<wgpu::BindGroup>>,
+    rasterized: HashMap<Key, Rc<wgpu::BindGroup>>,
     svg_hits: HashSet<u64>,
-    rasterized_hits: HashSet<(u64, u32, u32)>,
+    rasterized_hits: HashSet<Key>,
+    evicted: usize,
 }
 
 impl Cache {
@@ -43,6 +47,7 @@ impl Cache {
             rasterized: HashMap::new(),
             svg_hits: HashSet::new(),
             rasterized_hits: HashSet::new(),
+            evicted: 0,
         }
     }
 
@@ -65,6 +70,7 @@ impl Cache {
     pub fn upload(
         &mut self,
         handle: &svg::Handle,
+        color: Option<Color>,
         [width, height]: [f32; 2],
         scale: f32,
         device: &wgpu::Device,
@@ -78,13 +84,28 @@ impl Cache {
             (scale * height).round() as u32,
         );
 
+        // Tints are quantized, so colors that look the same share a texture
+        let tint = color.map(|color| {
+            let channel =
+                |value: f32| (value.max(0.0).min(1.0) * 255.0).round() as u8;
+
+            [
+                channel(color.r),
+                channel(color.g),
+                channel(color.b),
+                channel(color.a),
+            ]
+        });
+
+        let key = (id, width, height, tint);
+
         // TODO: Optimize!
         // We currently rerasterize the SVG when its size changes. This is slow
         // as heck. A GPU rasterizer like `pathfinder` may perform better.
         // It would be cool to be able to smooth resize the `svg` example.
-        if let Some(bind_group) = self.rasterized.get(&(id, width, height)) {
+        if let Some(bind_group) = self.rasterized.get(&key) {
             let _ = self.svg_hits.insert(id);
-            let _ = self.rasterized_hits.insert((id, width, height));
+            let _ = self.rasterized_hits.insert(key);
 
             return Some(bind_group.clone());
         }
@@ -128,6 +149,10 @@ impl Cache {
                         &mut canvas,
                     );
 
+                    if let Some(tint) = tint {
+                        apply_tint(canvas.get_data_mut(), tint);
+                    }
+
                     let slice = canvas.get_data();
 
                     device
@@ -171,12 +196,10 @@ impl Cache {
 
                 let bind_group = Rc::new(bind_group);
 
-                let _ = self
-                    .rasterized
-                    .insert((id, width, height), bind_group.clone());
+                let _ = self.rasterized.insert(key, bind_group.clone());
 
                 let _ = self.svg_hits.insert(id);
-                let _ = self.rasterized_hits.insert((id, width, height));
+                let _ = self.rasterized_hits.insert(key);
 
                 Some(bind_group)
             }
@@ -187,10 +210,60 @@ impl Cache {
     pub fn trim(&mut self) {
         let svg_hits = &self.svg_hits;
         let rasterized_hits = &self.rasterized_hits;
+        let rasterized = self.rasterized.len();
 
         self.svgs.retain(|k, _| svg_hits.contains(k));
         self.rasterized.retain(|k, _| rasterized_hits.contains(k));
         self.svg_hits.clear();
         self.rasterized_hits.clear();
+
+        self.evicted += rasterized - self.rasterized.len();
+    }
+
+    /// Evicts every cached SVG and rasterization, even the ones used in the
+    /// current frame.
+    pub fn clear(&mut self) {
+        self.evicted += self.rasterized.len();
+
+        self.svgs.clear();
+        self.rasterized.clear();
+        self.svg_hits.clear();
+        self.rasterized_hits.clear();
+    }
+
+    /// Returns the amount of pixel data of the rasterizations, which live in
+    /// device memory, in bytes.
+    pub fn device_bytes(&self) -> u64 {
+        self.rasterized
+            .keys()
+            .map(|(_, width, height, _)| {
+                4 * u64::from(*width) * u64::from(*height)
+            })
+            .sum()
+    }
+
+    pub fn collect_stats(&self, stats: &mut cache_stats::Stats) {
+        for (_, width, height, _) in self.rasterized.keys() {
+            stats.images += 1;
+            stats.uploaded_images += 1;
+            stats.image_bytes += 4 * u64::from(*width) * u64::from(*height);
+        }
+
+        stats.evicted_images += self.evicted;
+    }
+}
+
+/// Replaces the color of every pixel with the given tint, keeping its alpha.
+///
+/// Pixels are premultiplied ARGB, like `raqote` produces them.
+fn apply_tint(pixels: &mut [u32], [r, g, b, a]: [u8; 4]) {
+    for pixel in pixels {
+        let alpha = (*pixel >> 24) * u32::from(a) / 255;
+        let premultiply = |channel: u8| u32::from(channel) * alpha / 255;
+
+        *pixel = alpha << 24
+            | premultiply(r) << 16
+            | premultiply(g) << 8
+            | premultiply(b);
     }
 }
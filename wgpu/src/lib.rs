@@ -9,31 +9,52 @@
 //! incoming [WebGPU API].
 //!
 //! Currently, `iced_wgpu` supports the following primitives:
-//! - Text, which is rendered using [`wgpu_glyph`]. No shaping at all.
+//! - Text, which is shaped with [`rustybuzz`], reordered for bidirectional
+//!   scripts, and rendered using [`wgpu_glyph`].
 //! - Quads or rectangles, with rounded borders and a solid background color.
 //! - Images, lazily loaded from the filesystem.
 //! - Clip areas, useful to implement scrollables or hide overflowing content.
+//! - Custom primitives, drawn by your own pipelines.
+//! - Textures you own, like frames of video.
 //!
 //! [Iced]: https://github.com/hecrj/iced
 //! [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
 //! [`wgpu`]: https://github.com/gfx-rs/wgpu-rs
 //! [WebGPU API]: https://gpuweb.github.io/gpuweb/
 //! [`wgpu_glyph`]: https://github.com/hecrj/wgpu_glyph
+//! [`rustybuzz`]: https://github.com/RazrFalcon/rustybuzz
 #![deny(missing_docs)]
 #![deny(missing_debug_implementations)]
 #![deny(unused_results)]
 #![deny(unsafe_code)]
 #![deny(rust_2018_idioms)]
+mod antialiasing;
+mod arena;
+mod color_space;
+mod damage;
+mod font_metrics;
+mod glyph_cache;
 mod image;
 mod primitive;
 mod quad;
 mod renderer;
 mod text;
 mod transformation;
+mod warmup;
+
+pub mod custom;
+pub mod pdf;
+pub mod texture;
 
 pub(crate) use crate::image::Image;
 pub(crate) use quad::Quad;
-pub(crate) use transformation::Transformation;
+pub use transformation::Transformation;
 
-pub use primitive::Primitive;
-pub use renderer::{Renderer, Target};
+pub use antialiasing::Antialiasing;
+pub use arena::Arena;
+pub use color_space::ColorSpace;
+pub use font_metrics::FontMetrics;
+pub use glyph_cache::GlyphCache;
+pub use primitive::{Primitive, Span};
+pub use renderer::{Offscreen, Renderer, Target};
+pub use warmup::Warmup;
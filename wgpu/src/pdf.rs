@@ -0,0 +1,969 @@
+//! Export primitives to paginated PDF documents.
+//!
+//! The export keeps the vector nature of the primitives: quads become paths
+//! and text stays selectable text. This makes it a good fit for printing
+//! reports, invoices, and similar documents.
+//!
+//! To export a widget tree, lay it out with the width of the page content
+//! and draw it with a [`UserInterface`]:
+//!
+//! ```ignore
+//! let mut user_interface = UserInterface::build(
+//!     Container::new(invoice.view())
+//!         .width(Length::Units(pdf::Settings::A4.content_width() as u16)),
+//!     Cache::default(),
+//!     &mut renderer,
+//! );
+//!
+//! let (primitive, _) = user_interface.draw(&mut renderer);
+//!
+//! std::fs::write("invoice.pdf", pdf::export(&primitive, &pdf::Settings::A4)?)?;
+//! ```
+//!
+//! Raster images are embedded in the document. For now, text is always set
+//! in Helvetica, so it can only contain characters of Latin-1. Paragraphs
+//! take the size and color of their first span. SVGs, textures, nine-patches,
+//! and custom primitives cannot be exported yet.
+//!
+//! Instead of leaving out what it cannot export, the export fails with an
+//! [`Error`].
+//!
+//! [`UserInterface`]: ../../iced_native/struct.UserInterface.html
+//! [`Error`]: enum.Error.html
+use crate::Primitive;
+use iced_native::{
+    image, text, Background, Color, HorizontalAlignment, Rectangle, Size,
+    VerticalAlignment,
+};
+
+use std::fmt::Write;
+
+/// The layout of the pages of a PDF document.
+///
+/// All the measurements are in points, which are mapped one-to-one to the
+/// logical pixels of the primitives.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Settings {
+    /// The size of each page
+    pub page_size: Size,
+
+    /// The blank space around the content of each page
+    pub margin: f32,
+}
+
+impl Settings {
+    /// An A4 page with a margin of half an inch.
+    pub const A4: Settings = Settings {
+        page_size: Size {
+            width: 595.0,
+            height: 842.0,
+        },
+        margin: 36.0,
+    };
+
+    /// A US Letter page with a margin of half an inch.
+    pub const LETTER: Settings = Settings {
+        page_size: Size {
+            width: 612.0,
+            height: 792.0,
+        },
+        margin: 36.0,
+    };
+
+    /// Returns the width available for content on each page.
+    pub fn content_width(&self) -> f32 {
+        (self.page_size.width - 2.0 * self.margin).max(1.0)
+    }
+
+    /// Returns the height available for content on each page.
+    pub fn content_height(&self) -> f32 {
+        (self.page_size.height - 2.0 * self.margin).max(1.0)
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Settings {
+        Settings::A4
+    }
+}
+
+/// An error produced when exporting a [`Primitive`] as a PDF document.
+///
+/// [`Primitive`]: ../enum.Primitive.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The [`Primitive`] cannot be exported, like an SVG. It contains the
+    /// kind of the [`Primitive`].
+    ///
+    /// [`Primitive`]: ../enum.Primitive.html
+    UnsupportedPrimitive(&'static str),
+
+    /// Some text contains a character outside of Latin-1.
+    UnsupportedCharacter(char),
+
+    /// An image could not be loaded or decoded.
+    InvalidImage,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::UnsupportedPrimitive(kind) => {
+                write!(f, "{} primitives cannot be exported", kind)
+            }
+            Error::UnsupportedCharacter(c) => {
+                write!(f, "character {:?} cannot be exported", c)
+            }
+            Error::InvalidImage => write!(f, "image cannot be loaded"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Exports a [`Primitive`] as a PDF document.
+///
+/// The content is split vertically into as many pages as needed. Content
+/// crossing the bottom of a page is cut, and continues on the next one.
+///
+/// It fails if the [`Primitive`] contains anything that cannot be exported.
+///
+/// [`Primitive`]: ../enum.Primitive.html
+pub fn export(
+    primitive: &Primitive,
+    settings: &Settings,
+) -> Result<Vec<u8>, Error> {
+    let content_height = settings.content_height();
+
+    let bottom = primitive
+        .bounds()
+        .map(|bounds| bounds.y + bounds.height)
+        .unwrap_or(0.0);
+
+    let pages = ((bottom / content_height).ceil() as usize).max(1);
+
+    let mut alphas = Vec::new();
+    let mut images = Vec::new();
+
+    let contents = (0..pages)
+        .map(|page| -> Result<String, Error> {
+            let top = page as f32 * content_height;
+
+            let mut content = Content {
+                stream: String::new(),
+                alphas: &mut alphas,
+                images: &mut images,
+            };
+
+            // Clip to the margins and flip the y axis, so the content can be
+            // drawn from the top-left corner of the page
+            content.push(format_args!(
+                "{} {} {} {} re W n\n",
+                number(settings.margin),
+                number(settings.margin),
+                number(settings.content_width()),
+                number(content_height),
+            ));
+
+            content.push(format_args!(
+                "1 0 0 -1 {} {} cm\n",
+                number(settings.margin),
+                number(settings.page_size.height - settings.margin + top),
+            ));
+
+            content.draw(
+                primitive,
+                1.0,
+                Some(Rectangle {
+                    x: 0.0,
+                    y: top,
+                    width: settings.content_width(),
+                    height: content_height,
+                }),
+            )?;
+
+            Ok(content.stream)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(write_document(&contents, &alphas, &images, settings))
+}
+
+struct Content<'a> {
+    stream: String,
+    alphas: &'a mut Vec<f32>,
+    images: &'a mut Vec<Embedded>,
+}
+
+impl<'a> Content<'a> {
+    fn push(&mut self, arguments: std::fmt::Arguments<'_>) {
+        let _ = self.stream.write_fmt(arguments);
+    }
+
+    fn draw(
+        &mut self,
+        primitive: &Primitive,
+        opacity: f32,
+        visible: Option<Rectangle>,
+    ) -> Result<(), Error> {
+        let is_visible = |bounds: &Rectangle| {
+            visible.map_or(true, |visible| intersects(&visible, bounds))
+        };
+
+        match primitive {
+            Primitive::None => {}
+            Primitive::Group { primitives } => {
+                for primitive in primitives {
+                    self.draw(primitive, opacity, visible)?;
+                }
+            }
+            Primitive::Text {
+                content,
+                bounds,
+                color,
+                size,
+                params,
+                horizontal_alignment,
+                vertical_alignment,
+                ..
+            } => {
+                if !is_visible(bounds) {
+                    return Ok(());
+                }
+
+                self.fill(*color, opacity);
+
+                let rules = self.text(
+                    content,
+                    *bounds,
+                    *size,
+                    *params,
+                    *horizontal_alignment,
+                    *vertical_alignment,
+                )?;
+
+                if !rules.is_empty() {
+                    let decoration = params.decoration;
+
+                    self.fill(decoration.color.unwrap_or(*color), opacity);
+
+                    for rule in rules {
+                        self.rectangle(rule, 0.0);
+                    }
+                }
+            }
+            Primitive::Paragraph {
+                spans,
+                bounds,
+                params,
+                horizontal_alignment,
+                vertical_alignment,
+            } => {
+                let first = match spans.first() {
+                    Some(first) if is_visible(bounds) => first,
+                    _ => return Ok(()),
+                };
+
+                let content: String =
+                    spans.iter().map(|span| span.content.as_str()).collect();
+
+                self.fill(first.color, opacity);
+
+                let rules = self.text(
+                    &content,
+                    *bounds,
+                    first.size,
+                    *params,
+                    *horizontal_alignment,
+                    *vertical_alignment,
+                )?;
+
+                if !rules.is_empty() {
+                    let decoration = params.decoration;
+
+                    self.fill(decoration.color.unwrap_or(first.color), opacity);
+
+                    for rule in rules {
+                        self.rectangle(rule, 0.0);
+                    }
+                }
+            }
+            Primitive::Quad {
+                bounds,
+                background,
+                border_radius,
+                shadow,
+            } => {
+                if !is_visible(bounds) {
+                    return Ok(());
+                }
+
+                // Shadows are drawn sharp, since PDF has no blur
+                if let Some(shadow) = shadow {
+                    self.fill(shadow.color, opacity);
+                    self.rectangle(
+                        Rectangle {
+                            x: bounds.x + shadow.offset.x,
+                            y: bounds.y + shadow.offset.y,
+                            ..*bounds
+                        },
+                        f32::from(*border_radius),
+                    );
+                }
+
+                match background {
+                    Background::Color(color) => self.fill(*color, opacity),
+                }
+
+                self.rectangle(*bounds, f32::from(*border_radius));
+            }
+            Primitive::Image {
+                handle,
+                bounds,
+                border_radius,
+                fit,
+            } => {
+                if !is_visible(bounds) {
+                    return Ok(());
+                }
+
+                self.image(
+                    handle,
+                    *bounds,
+                    f32::from(*border_radius),
+                    *fit,
+                    opacity,
+                )?;
+            }
+            Primitive::Texture { .. } => {
+                return Err(Error::UnsupportedPrimitive("texture"));
+            }
+            Primitive::NinePatch { .. } => {
+                return Err(Error::UnsupportedPrimitive("nine-patch"));
+            }
+            Primitive::Svg { .. } => {
+                return Err(Error::UnsupportedPrimitive("SVG"));
+            }
+            Primitive::Custom { .. } => {
+                return Err(Error::UnsupportedPrimitive("custom"));
+            }
+            Primitive::Clip {
+                bounds,
+                offset,
+                content,
+            } => {
+                if !is_visible(bounds) {
+                    return Ok(());
+                }
+
+                let offset_x = offset.x as f32;
+                let offset_y = offset.y as f32;
+
+                self.push(format_args!(
+                    "q {} {} {} {} re W n 1 0 0 1 {} {} cm\n",
+                    number(bounds.x),
+                    number(bounds.y),
+                    number(bounds.width),
+                    number(bounds.height),
+                    number(-offset_x),
+                    number(-offset_y),
+                ));
+
+                self.draw(
+                    content,
+                    opacity,
+                    visible.map(|visible| Rectangle {
+                        x: visible.x + offset_x,
+                        y: visible.y + offset_y,
+                        ..visible
+                    }),
+                )?;
+
+                self.push(format_args!("Q\n"));
+            }
+            Primitive::CircularClip {
+                center,
+                radius,
+                content,
+            } => {
+                let circle = Rectangle {
+                    x: center.x - radius,
+                    y: center.y - radius,
+                    width: radius * 2.0,
+                    height: radius * 2.0,
+                };
+
+                if *radius <= 0.0 || !is_visible(&circle) {
+                    return Ok(());
+                }
+
+                self.push(format_args!("q\n"));
+                self.path(circle, *radius);
+                self.push(format_args!("W n\n"));
+
+                self.draw(content, opacity, visible)?;
+
+                self.push(format_args!("Q\n"));
+            }
+            Primitive::Transform {
+                transformation,
+                content,
+            } => {
+                let m = transformation.as_ref();
+
+                self.push(format_args!(
+                    "q {} {} {} {} {} {} cm\n",
+                    number(m[0]),
+                    number(m[1]),
+                    number(m[4]),
+                    number(m[5]),
+                    number(m[12]),
+                    number(m[13]),
+                ));
+
+                // The visible area is not tracked through transformations
+                self.draw(content, opacity, None)?;
+
+                self.push(format_args!("Q\n"));
+            }
+            Primitive::Transparency { alpha, content } => {
+                let opacity = opacity * alpha.max(0.0).min(1.0);
+
+                if opacity > 0.0 {
+                    self.draw(content, opacity, visible)?;
+                }
+            }
+            Primitive::Cached { cache } => {
+                self.draw(cache, opacity, visible)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn fill(&mut self, color: Color, opacity: f32) {
+        let state = self.graphics_state(color.a * opacity);
+
+        self.push(format_args!(
+            "/GS{} gs {} {} {} rg\n",
+            state,
+            number(color.r),
+            number(color.g),
+            number(color.b),
+        ));
+    }
+
+    fn graphics_state(&mut self, alpha: f32) -> usize {
+        // Transparency needs a graphics state per alpha value
+        match self.alphas.iter().position(|a| *a == alpha) {
+            Some(state) => state,
+            None => {
+                self.alphas.push(alpha);
+                self.alphas.len() - 1
+            }
+        }
+    }
+
+    fn image(
+        &mut self,
+        handle: &image::Handle,
+        bounds: Rectangle,
+        radius: f32,
+        fit: image::Fit,
+        opacity: f32,
+    ) -> Result<(), Error> {
+        let index = match self
+            .images
+            .iter()
+            .position(|image| image.id == handle.id())
+        {
+            Some(index) => index,
+            None => {
+                self.images.push(Embedded::load(handle)?);
+                self.images.len() - 1
+            }
+        };
+
+        let width = self.images[index].width as f32;
+        let height = self.images[index].height as f32;
+
+        let centered = |scale: f32| {
+            let (width, height) = (width * scale, height * scale);
+
+            vec![Rectangle {
+                x: bounds.x + (bounds.width - width) / 2.0,
+                y: bounds.y + (bounds.height - height) / 2.0,
+                width,
+                height,
+            }]
+        };
+
+        let scale_x = bounds.width / width;
+        let scale_y = bounds.height / height;
+
+        // Every placement is clipped to the bounds of the image
+        let placements = match fit {
+            image::Fit::Fill => vec![bounds],
+            image::Fit::Contain => centered(scale_x.min(scale_y)),
+            image::Fit::Cover => centered(scale_x.max(scale_y)),
+            image::Fit::Tile => {
+                let columns = scale_x.ceil() as usize;
+                let rows = scale_y.ceil() as usize;
+
+                (0..rows)
+                    .flat_map(|row| {
+                        (0..columns).map(move |column| Rectangle {
+                            x: bounds.x + column as f32 * width,
+                            y: bounds.y + row as f32 * height,
+                            width,
+                            height,
+                        })
+                    })
+                    .collect()
+            }
+        };
+
+        let state = self.graphics_state(opacity);
+
+        self.push(format_args!("q /GS{} gs\n", state));
+        self.path(bounds, radius);
+        self.push(format_args!("W n\n"));
+
+        // An image fills the unit square, with its first row at the top.
+        // The y axis is flipped back, so the image is upright.
+        for placement in placements {
+            self.push(format_args!(
+                "q {} 0 0 {} {} {} cm /Im{} Do Q\n",
+                number(placement.width),
+                number(-placement.height),
+                number(placement.x),
+                number(placement.y + placement.height),
+                index,
+            ));
+        }
+
+        self.push(format_args!("Q\n"));
+
+        Ok(())
+    }
+
+    fn rectangle(&mut self, bounds: Rectangle, radius: f32) {
+        self.path(bounds, radius);
+        self.push(format_args!("f\n"));
+    }
+
+    fn path(&mut self, bounds: Rectangle, radius: f32) {
+        let radius = radius.min(bounds.width / 2.0).min(bounds.height / 2.0);
+
+        if radius <= 0.0 {
+            self.push(format_args!(
+                "{} {} {} {} re\n",
+                number(bounds.x),
+                number(bounds.y),
+                number(bounds.width),
+                number(bounds.height),
+            ));
+
+            return;
+        }
+
+        // The distance of the control points of a cubic Bézier curve that
+        // approximates a quarter of a circle
+        let k = radius * 0.552_284_8;
+
+        let left = bounds.x;
+        let top = bounds.y;
+        let right = bounds.x + bounds.width;
+        let bottom = bounds.y + bounds.height;
+
+        self.push(format_args!(
+            "{} {} m\n",
+            number(left + radius),
+            number(top)
+        ));
+
+        self.line_to(right - radius, top);
+        self.curve_to(
+            (right - radius + k, top),
+            (right, top + radius - k),
+            (right, top + radius),
+        );
+        self.line_to(right, bottom - radius);
+        self.curve_to(
+            (right, bottom - radius + k),
+            (right - radius + k, bottom),
+            (right - radius, bottom),
+        );
+        self.line_to(left + radius, bottom);
+        self.curve_to(
+            (left + radius - k, bottom),
+            (left, bottom - radius + k),
+            (left, bottom - radius),
+        );
+        self.line_to(left, top + radius);
+        self.curve_to(
+            (left, top + radius - k),
+            (left + radius - k, top),
+            (left + radius, top),
+        );
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.push(format_args!("{} {} l\n", number(x), number(y)));
+    }
+
+    fn curve_to(&mut self, a: (f32, f32), b: (f32, f32), c: (f32, f32)) {
+        self.push(format_args!(
+            "{} {} {} {} {} {} c\n",
+            number(a.0),
+            number(a.1),
+            number(b.0),
+            number(b.1),
+            number(c.0),
+            number(c.1),
+        ));
+    }
+
+    fn text(
+        &mut self,
+        content: &str,
+        bounds: Rectangle,
+        size: f32,
+        params: text::Params,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+    ) -> Result<Vec<Rectangle>, Error> {
+        let decoration = params.decoration;
+        let thickness = decoration.thickness.unwrap_or(size / 16.0);
+        let mut rules = Vec::new();
+
+        let line_height = size * LINE_HEIGHT * params.line_height;
+        let lines = wrap(content, size, params, bounds.width);
+        let height = line_height * lines.len() as f32;
+
+        let top = match vertical_alignment {
+            VerticalAlignment::Top => bounds.y,
+            VerticalAlignment::Center => {
+                bounds.y + (bounds.height - height) / 2.0
+            }
+            VerticalAlignment::Bottom => bounds.y + bounds.height - height,
+        };
+
+        for (i, line) in lines.iter().enumerate() {
+            let width = measure(line, size, params.letter_spacing);
+
+            let x = match horizontal_alignment {
+                HorizontalAlignment::Left => bounds.x,
+                HorizontalAlignment::Center => {
+                    bounds.x + (bounds.width - width) / 2.0
+                }
+                HorizontalAlignment::Right => bounds.x + bounds.width - width,
+            };
+
+            let baseline = top
+                + line_height * i as f32
+                + (line_height - size * LINE_HEIGHT) / 2.0
+                + size * ASCENT;
+
+            // The text matrix flips the y axis back, so glyphs are upright
+            self.push(format_args!(
+                "BT /F1 {} Tf {} Tc 1 0 0 -1 {} {} Tm ({}) Tj ET\n",
+                number(size),
+                number(params.letter_spacing),
+                number(x),
+                number(baseline),
+                escape(line)?,
+            ));
+
+            let offsets = [
+                (decoration.underline, size * DESCENT / 2.0),
+                (decoration.strikethrough, -size * ASCENT * 0.3),
+                (decoration.overline, -size * ASCENT),
+            ];
+
+            for (_, offset) in offsets.iter().filter(|(is_drawn, _)| *is_drawn)
+            {
+                rules.push(Rectangle {
+                    x,
+                    y: baseline + offset - thickness / 2.0,
+                    width,
+                    height: thickness,
+                });
+            }
+        }
+
+        Ok(rules)
+    }
+}
+
+/// A raster image embedded in the document, with its pixels encoded as
+/// hexadecimal strings.
+struct Embedded {
+    id: u64,
+    width: u32,
+    height: u32,
+    color: String,
+    alpha: String,
+}
+
+impl Embedded {
+    fn load(handle: &image::Handle) -> Result<Embedded, Error> {
+        let image = match handle.data() {
+            image::Data::Path(path) => ::image::open(path),
+            image::Data::Bytes(bytes) => ::image::load_from_memory(bytes),
+        }
+        .map_err(|_| Error::InvalidImage)?
+        .to_rgba();
+
+        let (width, height) = image.dimensions();
+
+        if width == 0 || height == 0 {
+            return Err(Error::InvalidImage);
+        }
+
+        let pixels = (width * height) as usize;
+
+        let mut color = String::with_capacity(pixels * 6 + 1);
+        let mut alpha = String::with_capacity(pixels * 2 + 1);
+
+        for pixel in image.into_raw().chunks(4) {
+            for channel in &pixel[..3] {
+                let _ = write!(color, "{:02x}", channel);
+            }
+
+            let _ = write!(alpha, "{:02x}", pixel[3]);
+        }
+
+        // The end of the data of the ASCIIHexDecode filter
+        color.push('>');
+        alpha.push('>');
+
+        Ok(Embedded {
+            id: handle.id(),
+            width,
+            height,
+            color,
+            alpha,
+        })
+    }
+}
+
+const LINE_HEIGHT: f32 = 1.2;
+const ASCENT: f32 = 0.9;
+const DESCENT: f32 = 0.2;
+
+/// The widths of the printable ASCII characters in Helvetica, in thousandths
+/// of the font size.
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278,
+    278, 556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584,
+    584, 556, 1015, 667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556,
+    833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 278,
+    278, 278, 469, 556, 333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222,
+    500, 222, 833, 556, 556, 556, 556, 333, 500, 278, 556, 500, 722, 500, 500,
+    500, 334, 260, 334, 584,
+];
+
+fn measure(line: &str, size: f32, letter_spacing: f32) -> f32 {
+    line.chars()
+        .map(|c| {
+            let width = match c as u32 {
+                code @ 32..=126 => HELVETICA_WIDTHS[(code - 32) as usize],
+                _ => 556,
+            };
+
+            f32::from(width) * size / 1000.0 + letter_spacing
+        })
+        .sum()
+}
+
+fn wrap(
+    content: &str,
+    size: f32,
+    params: text::Params,
+    max_width: f32,
+) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    for paragraph in content.lines() {
+        let mut line = String::new();
+
+        let pieces: Vec<String> = match params.wrap {
+            text::Wrap::Word => {
+                paragraph.split(' ').map(String::from).collect()
+            }
+            text::Wrap::Character => {
+                paragraph.chars().map(String::from).collect()
+            }
+            text::Wrap::None => {
+                lines.push(paragraph.to_string());
+                continue;
+            }
+        };
+
+        for piece in pieces {
+            let candidate = match params.wrap {
+                text::Wrap::Word if !line.is_empty() => {
+                    format!("{} {}", line, piece)
+                }
+                _ => format!("{}{}", line, piece),
+            };
+
+            let width = measure(&candidate, size, params.letter_spacing);
+
+            if !line.is_empty() && width > max_width {
+                lines.push(std::mem::replace(&mut line, piece));
+            } else {
+                line = candidate;
+            }
+        }
+
+        lines.push(line);
+    }
+
+    lines
+}
+
+fn escape(line: &str) -> Result<String, Error> {
+    line.chars()
+        .map(|c| match c {
+            '\\' | '(' | ')' => Ok(format!("\\{}", c)),
+            ' '..='~' => Ok(c.to_string()),
+            // Latin-1 maps directly to the WinAnsi encoding of the font
+            '\u{a0}'..='\u{ff}' => Ok(format!("\\{:03o}", c as u32)),
+            _ => Err(Error::UnsupportedCharacter(c)),
+        })
+        .collect()
+}
+
+fn number(n: f32) -> String {
+    let n = format!("{:.3}", n);
+    let n = n.trim_end_matches('0').trim_end_matches('.');
+
+    if n == "-0" {
+        String::from("0")
+    } else {
+        n.to_string()
+    }
+}
+
+fn write_document(
+    contents: &[String],
+    alphas: &[f32],
+    images: &[Embedded],
+    settings: &Settings,
+) -> Vec<u8> {
+    // Objects 1 to 4 are the catalog, the page tree, the font, and the
+    // shared resources. Each page is followed by its content, and each image
+    // by its alpha channel, after the pages.
+    let page = |i: usize| 5 + 2 * i;
+    let image = |i: usize| page(contents.len()) + 2 * i;
+
+    let mut objects = vec![
+        String::from("<< /Type /Catalog /Pages 2 0 R >>"),
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            (0..contents.len())
+                .map(|i| format!("{} 0 R", page(i)))
+                .collect::<Vec<_>>()
+                .join(" "),
+            contents.len(),
+        ),
+        String::from(
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica \
+             /Encoding /WinAnsiEncoding >>",
+        ),
+        format!(
+            "<< /Font << /F1 3 0 R >> /ExtGState << {} >> \
+             /XObject << {} >> >>",
+            alphas
+                .iter()
+                .enumerate()
+                .map(|(i, alpha)| format!(
+                    "/GS{} << /ca {} >>",
+                    i,
+                    number(*alpha)
+                ))
+                .collect::<Vec<_>>()
+                .join(" "),
+            (0..images.len())
+                .map(|i| format!("/Im{} {} 0 R", i, image(i)))
+                .collect::<Vec<_>>()
+                .join(" "),
+        ),
+    ];
+
+    for (i, content) in contents.iter().enumerate() {
+        objects.push(format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+             /Resources 4 0 R /Contents {} 0 R >>",
+            number(settings.page_size.width),
+            number(settings.page_size.height),
+            page(i) + 1,
+        ));
+
+        objects.push(format!(
+            "<< /Length {} >>\nstream\n{}endstream",
+            content.len(),
+            content
+        ));
+    }
+
+    for (i, embedded) in images.iter().enumerate() {
+        objects.push(format!(
+            "<< /Type /XObject /Subtype /Image /Width {} /Height {} \
+             /ColorSpace /DeviceRGB /BitsPerComponent 8 \
+             /Filter /ASCIIHexDecode /SMask {} 0 R /Length {} >>\n\
+             stream\n{}\nendstream",
+            embedded.width,
+            embedded.height,
+            image(i) + 1,
+            embedded.color.len(),
+            embedded.color,
+        ));
+
+        objects.push(format!(
+            "<< /Type /XObject /Subtype /Image /Width {} /Height {} \
+             /ColorSpace /DeviceGray /BitsPerComponent 8 \
+             /Filter /ASCIIHexDecode /Length {} >>\nstream\n{}\nendstream",
+            embedded.width,
+            embedded.height,
+            embedded.alpha.len(),
+            embedded.alpha,
+        ));
+    }
+
+    let mut document = String::from("%PDF-1.4\n");
+    let mut offsets = Vec::with_capacity(objects.len());
+
+    for (i, object) in objects.iter().enumerate() {
+        offsets.push(document.len());
+
+        let _ = write!(document, "{} 0 obj\n{}\nendobj\n", i + 1, object);
+    }
+
+    let xref = document.len();
+
+    let _ = write!(document, "xref\n0 {}\n", objects.len() + 1);
+    document.push_str("0000000000 65535 f \n");
+
+    for offset in offsets {
+        let _ = write!(document, "{:010} 00000 n \n", offset);
+    }
+
+    let _ = write!(
+        document,
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+        objects.len() + 1,
+        xref
+    );
+
+    document.into_bytes()
+}
+
+fn intersects(a: &Rectangle, b: &Rectangle) -> bool {
+    a.x < b.x + b.width
+        && b.x < a.x + a.width
+        && a.y < b.y + b.height
+        && b.y < a.y + a.height
+}
@@ -1,8 +1,11 @@
+use crate::{custom, texture, Transformation};
 use iced_native::{
-    image, svg, Background, Color, Font, HorizontalAlignment, Rectangle,
-    Vector, VerticalAlignment,
+    font::Weight, image, svg, text, Background, Color, Font,
+    HorizontalAlignment, Point, Rectangle, Shadow, Vector, VerticalAlignment,
 };
 
+use std::sync::Arc;
+
 /// A rendering primitive.
 #[derive(Debug, Clone)]
 pub enum Primitive {
@@ -25,11 +28,28 @@ pub enum Primitive {
         size: f32,
         /// The font of the text
         font: Font,
+        /// The line height, letter spacing, and wrapping of the text
+        params: text::Params,
         /// The horizontal alignment of the text
         horizontal_alignment: HorizontalAlignment,
         /// The vertical alignment of the text
         vertical_alignment: VerticalAlignment,
     },
+    /// A paragraph of text made of spans with different styles
+    ///
+    /// Lines are wrapped across the spans, like a single text.
+    Paragraph {
+        /// The spans of the paragraph
+        spans: Vec<Span>,
+        /// The bounds of the paragraph
+        bounds: Rectangle,
+        /// The line height, letter spacing, and wrapping of the paragraph
+        params: text::Params,
+        /// The horizontal alignment of the paragraph
+        horizontal_alignment: HorizontalAlignment,
+        /// The vertical alignment of the paragraph
+        vertical_alignment: VerticalAlignment,
+    },
     /// A quad primitive
     Quad {
         /// The bounds of the quad
@@ -38,6 +58,8 @@ pub enum Primitive {
         background: Background,
         /// The border radius of the quad
         border_radius: u16,
+        /// The shadow cast by the quad, if any
+        shadow: Option<Shadow>,
     },
     /// An image primitive
     Image {
@@ -45,6 +67,34 @@ pub enum Primitive {
         handle: image::Handle,
         /// The bounds of the image
         bounds: Rectangle,
+        /// The border radius of the image, used to round its corners
+        border_radius: u16,
+        /// The way the image fills its bounds
+        fit: image::Fit,
+    },
+    /// A texture owned by the user, like a frame of video
+    ///
+    /// It is drawn like an image, but it is never uploaded nor cached. It is
+    /// considered to change every frame.
+    Texture {
+        /// The handle of the texture
+        handle: texture::Handle,
+        /// The bounds of the texture
+        bounds: Rectangle,
+        /// The border radius of the texture, used to round its corners
+        border_radius: u16,
+        /// The way the texture fills its bounds
+        fit: image::Fit,
+    },
+    /// An image sliced into a 3x3 grid, with fixed corners and stretched
+    /// edges and center
+    NinePatch {
+        /// The handle of the image
+        handle: image::Handle,
+        /// The bounds of the nine-patch
+        bounds: Rectangle,
+        /// The lines slicing the image, in pixels of the image
+        slices: image::Slices,
     },
     /// An SVG primitive
     Svg {
@@ -53,6 +103,10 @@ pub enum Primitive {
 
         /// The bounds of the viewport
         bounds: Rectangle,
+        /// The border radius of the viewport, used to round its corners
+        border_radius: u16,
+        /// The color replacing the colors of the SVG, keeping its alpha
+        color: Option<Color>,
     },
     /// A clip primitive
     Clip {
@@ -63,6 +117,161 @@ pub enum Primitive {
         /// The content of the clip
         content: Box<Primitive>,
     },
+    /// A primitive only visible inside of a circle, like a reveal effect
+    ///
+    /// The content is drawn into a separate texture first, so the clip is
+    /// more expensive than a rectangular one.
+    CircularClip {
+        /// The center of the circle
+        center: Point,
+        /// The radius of the circle
+        radius: f32,
+        /// The content of the clip
+        content: Box<Primitive>,
+    },
+    /// A primitive drawn with a transformation, like a rotation or a scale
+    ///
+    /// The transformation is applied at render time, in the same coordinate
+    /// system as the content. Clips inside of the content are transformed
+    /// too, but clipping is always axis-aligned: a rotated clip keeps the
+    /// content inside of its bounding box.
+    Transform {
+        /// The transformation of the content
+        transformation: Transformation,
+        /// The transformed content
+        content: Box<Primitive>,
+    },
+    /// A primitive drawn by a custom [`Program`], like a 3D viewport
+    ///
+    /// The primitive is not affected by any transparency.
+    ///
+    /// [`Program`]: custom/trait.Program.html
+    Custom {
+        /// The bounds of the primitive
+        bounds: Rectangle,
+        /// The program drawing the primitive
+        program: Arc<dyn custom::Program>,
+    },
+    /// A primitive drawn with some transparency, like a fading widget
+    Transparency {
+        /// The opacity of the content, from `0.0` to `1.0`
+        alpha: f32,
+        /// The content of the transparency
+        content: Box<Primitive>,
+    },
+    /// A cached primitive, shared between frames
+    ///
+    /// The same cache can be drawn many times in a frame, each time inside
+    /// its own [`Transform`], to compose a scene out of reusable parts. Only
+    /// the `Arc` is cloned for every instance.
+    ///
+    /// ```
+    /// use iced_native::{Background, Color, Point, Rectangle};
+    /// use iced_wgpu::{Primitive, Transformation};
+    /// use std::sync::Arc;
+    ///
+    /// let symbol = Arc::new(Primitive::Quad {
+    ///     bounds: Rectangle {
+    ///         x: 0.0,
+    ///         y: 0.0,
+    ///         width: 10.0,
+    ///         height: 10.0,
+    ///     },
+    ///     background: Background::Color(Color::BLACK),
+    ///     border_radius: 5,
+    ///     shadow: None,
+    /// });
+    ///
+    /// let positions = [Point::new(0.0, 0.0), Point::new(20.0, 0.0)];
+    ///
+    /// let instances = positions.iter().map(|position| Primitive::Transform {
+    ///     transformation: Transformation::translate(position.x, position.y),
+    ///     content: Box::new(Primitive::Cached {
+    ///         cache: symbol.clone(),
+    ///     }),
+    /// });
+    ///
+    /// let scene = Primitive::Group {
+    ///     primitives: instances.collect(),
+    /// };
+    /// ```
+    ///
+    /// [`Transform`]: #variant.Transform
+    Cached {
+        /// The cached primitive
+        cache: Arc<Primitive>,
+    },
+}
+
+impl Primitive {
+    /// Returns the smallest rectangle containing everything the primitive
+    /// draws, if anything.
+    pub(crate) fn bounds(&self) -> Option<Rectangle> {
+        match self {
+            Primitive::None => None,
+            Primitive::Group { primitives } => primitives
+                .iter()
+                .filter_map(Primitive::bounds)
+                .fold(None, |a, b| {
+                    Some(match a {
+                        Some(a) => union(a, b),
+                        None => b,
+                    })
+                }),
+            Primitive::Text { bounds, .. } => Some(*bounds),
+            Primitive::Paragraph { bounds, .. } => Some(*bounds),
+            Primitive::Quad { bounds, shadow, .. } => Some(match shadow {
+                Some(shadow) => union(
+                    *bounds,
+                    Rectangle {
+                        x: bounds.x + shadow.offset.x - shadow.blur_radius,
+                        y: bounds.y + shadow.offset.y - shadow.blur_radius,
+                        width: bounds.width + shadow.blur_radius * 2.0,
+                        height: bounds.height + shadow.blur_radius * 2.0,
+                    },
+                ),
+                None => *bounds,
+            }),
+            Primitive::Image { bounds, .. } => Some(*bounds),
+            Primitive::Texture { bounds, .. } => Some(*bounds),
+            Primitive::NinePatch { bounds, .. } => Some(*bounds),
+            Primitive::Svg { bounds, .. } => Some(*bounds),
+            Primitive::Clip { bounds, .. } => Some(*bounds),
+            Primitive::CircularClip { center, radius, .. } => Some(Rectangle {
+                x: center.x - radius,
+                y: center.y - radius,
+                width: radius * 2.0,
+                height: radius * 2.0,
+            }),
+            Primitive::Transform {
+                transformation,
+                content,
+            } => content
+                .bounds()
+                .map(|content| transformation.transform_bounds(content)),
+            Primitive::Custom { bounds, .. } => Some(*bounds),
+            Primitive::Transparency { content, .. } => content.bounds(),
+            Primitive::Cached { cache } => cache.bounds(),
+        }
+    }
+}
+
+/// A piece of a [`Primitive::Paragraph`] with its own style.
+///
+/// [`Primitive::Paragraph`]: enum.Primitive.html#variant.Paragraph
+#[derive(Debug, Clone)]
+pub struct Span {
+    /// The contents of the span
+    pub content: String,
+    /// The size of the text of the span
+    pub size: f32,
+    /// The font of the span
+    pub font: Font,
+    /// The weight of the font of the span, if it overrides the one of the
+    /// font
+    pub weight: Option<Weight>,
+    /// The color of the span
+    pub color: Color,
 }
 
 impl Default for Primitive {
@@ -70,3 +279,15 @@ impl Default for Primitive {
         Primitive::None
     }
 }
+
+fn union(a: Rectangle, b: Rectangle) -> Rectangle {
+    let x = a.x.min(b.x);
+    let y = a.y.min(b.y);
+
+    Rectangle {
+        x,
+        y,
+        width: (a.x + a.width).max(b.x + b.width) - x,
+        height: (a.y + a.height).max(b.y + b.height) - y,
+    }
+}
@@ -14,7 +14,11 @@ pub struct Pipeline {
 }
 
 impl Pipeline {
-    pub fn new(device: &mut wgpu::Device) -> Pipeline {
+    pub fn new(
+        device: &mut wgpu::Device,
+        format: wgpu::TextureFormat,
+        sample_count: u32,
+    ) -> Pipeline {
         let constant_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
                 bindings: &[wgpu::BindGroupLayoutBinding {
@@ -79,7 +83,7 @@ impl Pipeline {
                 }),
                 primitive_topology: wgpu::PrimitiveTopology::TriangleList,
                 color_states: &[wgpu::ColorStateDescriptor {
-                    format: wgpu::TextureFormat::Bgra8UnormSrgb,
+                    format,
                     color_blend: wgpu::BlendDescriptor {
                         src_factor: wgpu::BlendFactor::SrcAlpha,
                         dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
@@ -128,10 +132,15 @@ impl Pipeline {
                                 format: wgpu::VertexFormat::Float,
                                 offset: 4 * (2 + 2 + 4),
                             },
+                            wgpu::VertexAttributeDescriptor {
+                                shader_location: 5,
+                                format: wgpu::VertexFormat::Float,
+                                offset: 4 * (2 + 2 + 4 + 1),
+                            },
                         ],
                     },
                 ],
-                sample_count: 1,
+                sample_count,
                 sample_mask: !0,
                 alpha_to_coverage_enabled: false,
             });
@@ -168,6 +177,7 @@ impl Pipeline {
         scale: f32,
         bounds: Rectangle<u32>,
         target: &wgpu::TextureView,
+        resolve_target: Option<&wgpu::TextureView>,
     ) {
         let uniforms = Uniforms::new(transformation, scale);
 
@@ -208,7 +218,7 @@ impl Pipeline {
                         color_attachments: &[
                             wgpu::RenderPassColorAttachmentDescriptor {
                                 attachment: target,
-                                resolve_target: None,
+                                resolve_target,
                                 load_op: wgpu::LoadOp::Load,
                                 store_op: wgpu::StoreOp::Store,
                                 clear_color: wgpu::Color {
@@ -278,6 +288,7 @@ pub struct Quad {
     pub scale: [f32; 2],
     pub color: [f32; 4],
     pub border_radius: f32,
+    pub blur_radius: f32,
 }
 
 impl Quad {
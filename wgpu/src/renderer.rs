@@ -1,17 +1,30 @@
-use crate::{image, quad, text, Image, Primitive, Quad, Transformation};
+use crate::{
+    antialiasing, custom, damage, image, quad, text, texture, Antialiasing,
+    Arena, ColorSpace, FontMetrics, GlyphCache, Image, Primitive, Quad,
+    Transformation, Warmup,
+};
 use iced_native::{
-    renderer::{Debugger, Windowed},
-    Background, Color, Layout, MouseCursor, Point, Rectangle, Vector, Widget,
+    image::Fit,
+    memory,
+    renderer::{Debugger, TextAntialiasing, TextRendering, Windowed},
+    Background, Color, Font, Layout, MouseCursor, Point, Rectangle, Size,
+    Vector, Widget,
 };
 
+use std::{sync::Arc, time::Instant};
 use wgpu::{
     Adapter, BackendBit, CommandEncoderDescriptor, Device, DeviceDescriptor,
     Extensions, Limits, PowerPreference, Queue, RequestAdapterOptions,
 };
 
+mod backbuffer;
+mod offscreen;
 mod target;
 mod widget;
 
+use backbuffer::Backbuffer;
+
+pub use offscreen::Offscreen;
 pub use target::Target;
 
 /// A [`wgpu`] renderer.
@@ -24,26 +37,96 @@ pub struct Renderer {
     quad_pipeline: quad::Pipeline,
     image_pipeline: crate::image::Pipeline,
     text_pipeline: text::Pipeline,
+    glyph_cache: GlyphCache,
+    is_atlas_inspected: bool,
+    custom_storage: custom::Storage,
+    arena: Arena,
+    color_space: ColorSpace,
+    antialiasing: Option<Antialiasing>,
+    msaa: Option<antialiasing::Msaa>,
+    fxaa: Option<antialiasing::Fxaa>,
+    backbuffer: Option<Backbuffer>,
+    animated: Vec<Rectangle<u32>>,
+    text_rendering: TextRendering,
+    subpixel: Option<antialiasing::Subpixel>,
+    opacity: f32,
+    viewport: (u16, u16, f32),
+    masked: Vec<Offscreen>,
+    scheduled_redraw: Option<Instant>,
+    ime_caret: Option<Rectangle>,
+    memory_budget: Option<u64>,
+    memory_pressure: Option<memory::Pressure>,
+    is_over_budget: bool,
+    allocation_failed: bool,
 }
 
 struct Layer<'a> {
     bounds: Rectangle<u32>,
     offset: Vector<u32>,
+    transformation: Transformation,
     quads: Vec<Quad>,
     images: Vec<Image>,
-    text: Vec<wgpu_glyph::Section<'a>>,
+    programs: Vec<(Rectangle, Arc<dyn custom::Program>)>,
+    text: Vec<(wgpu_glyph::VariedSection<'a>, iced_native::text::Params)>,
 }
 
 impl<'a> Layer<'a> {
-    pub fn new(bounds: Rectangle<u32>, offset: Vector<u32>) -> Self {
+    pub fn new(
+        bounds: Rectangle<u32>,
+        offset: Vector<u32>,
+        transformation: Transformation,
+    ) -> Self {
         Self {
             bounds,
             offset,
+            transformation,
             quads: Vec::new(),
             images: Vec::new(),
+            programs: Vec::new(),
             text: Vec::new(),
         }
     }
+
+    fn is_empty(&self) -> bool {
+        self.quads.is_empty()
+            && self.images.is_empty()
+            && self.programs.is_empty()
+            && self.text.is_empty()
+    }
+
+    fn can_merge(&self, next: &Layer<'_>) -> bool {
+        // Quads are drawn before images, and images before text. Merging must
+        // not draw the content of the next layer below the content of this
+        // one.
+        let is_ordered = (next.quads.is_empty()
+            || (self.images.is_empty() && self.text.is_empty()))
+            && (next.images.is_empty() || self.text.is_empty());
+
+        // Quads and text are offset when pushed, but images are offset when
+        // flushed
+        let is_offset_compatible = self.images.is_empty()
+            || next.images.is_empty()
+            || self.offset == next.offset;
+
+        // Custom programs are always drawn in their own layer
+        let is_custom = !self.programs.is_empty() || !next.programs.is_empty();
+
+        self.bounds == next.bounds
+            && self.transformation == next.transformation
+            && !is_custom
+            && is_ordered
+            && is_offset_compatible
+    }
+
+    fn merge(&mut self, next: Layer<'a>) {
+        if self.images.is_empty() {
+            self.offset = next.offset;
+        }
+
+        self.quads.extend(next.quads);
+        self.images.extend(next.images);
+        self.text.extend(next.text);
+    }
 }
 
 impl Renderer {
@@ -61,9 +144,19 @@ impl Renderer {
             limits: Limits { max_bind_groups: 2 },
         });
 
-        let text_pipeline = text::Pipeline::new(&mut device);
-        let quad_pipeline = quad::Pipeline::new(&mut device);
-        let image_pipeline = crate::image::Pipeline::new(&mut device);
+        let color_space = ColorSpace::default();
+        let glyph_cache = GlyphCache::default();
+
+        let text_pipeline = text::Pipeline::new(
+            &mut device,
+            color_space.format(),
+            glyph_cache,
+            false,
+        );
+        let quad_pipeline =
+            quad::Pipeline::new(&mut device, color_space.format(), 1);
+        let image_pipeline =
+            crate::image::Pipeline::new(&mut device, color_space);
 
         Self {
             device,
@@ -71,12 +164,34 @@ impl Renderer {
             quad_pipeline,
             image_pipeline,
             text_pipeline,
+            glyph_cache,
+            is_atlas_inspected: false,
+            custom_storage: custom::Storage::default(),
+            arena: Arena::default(),
+            color_space,
+            antialiasing: None,
+            msaa: None,
+            fxaa: None,
+            backbuffer: None,
+            animated: Vec::new(),
+            text_rendering: TextRendering::default(),
+            subpixel: None,
+            opacity: 1.0,
+            viewport: (0, 0, 1.0),
+            masked: Vec::new(),
+            scheduled_redraw: None,
+            ime_caret: None,
+            memory_budget: None,
+            memory_pressure: None,
+            is_over_budget: false,
+            allocation_failed: false,
         }
     }
 
     fn draw<T: AsRef<str>>(
         &mut self,
         (primitive, mouse_cursor): &(Primitive, MouseCursor),
+        damage: Option<&[Rectangle]>,
         overlay: &[T],
         target: &mut Target,
     ) -> MouseCursor {
@@ -84,54 +199,762 @@ impl Renderer {
 
         let (width, height) = target.dimensions();
         let dpi = target.dpi();
-        let transformation = target.transformation();
-        let frame = target.next_frame();
+        let clear_color = wgpu::Color {
+            r: 1.0,
+            g: 1.0,
+            b: 1.0,
+            a: 1.0,
+        };
+
+        self.viewport = (width, height, dpi);
+
+        let mut backbuffer = match self.backbuffer.take() {
+            Some(backbuffer) => backbuffer,
+            None => Backbuffer::new(&self.device, self.color_space.format()),
+        };
 
         let mut encoder = self
             .device
             .create_command_encoder(&CommandEncoderDescriptor { todo: 0 });
 
-        let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            color_attachments: &[wgpu::RenderPassColorAttachmentDescriptor {
-                attachment: &frame.view,
-                resolve_target: None,
-                load_op: wgpu::LoadOp::Clear,
-                store_op: wgpu::StoreOp::Store,
-                clear_color: wgpu::Color {
-                    r: 1.0,
-                    g: 1.0,
-                    b: 1.0,
-                    a: 1.0,
-                },
-            }],
-            depth_stencil_attachment: None,
-        });
+        let is_new = backbuffer.resize(
+            &self.device,
+            &mut encoder,
+            width,
+            height,
+            clear_color,
+        );
 
-        let mut layers = Vec::new();
+        self.queue.submit(&[encoder.finish()]);
 
-        layers.push(Layer::new(
-            Rectangle {
-                x: 0,
-                y: 0,
-                width: u32::from(width),
-                height: u32::from(height),
-            },
-            Vector::new(0, 0),
-        ));
+        let animated = std::mem::replace(&mut self.animated, Vec::new());
 
-        self.draw_primitive(primitive, &mut layers);
-        self.draw_overlay(overlay, &mut layers);
+        // The overlay changes on every frame, and smoothing only a region
+        // would need the unsmoothed frame, so both are drawn whole
+        let regions = match damage {
+            Some(damage)
+                if !is_new && overlay.is_empty() && self.fxaa.is_none() =>
+            {
+                Some(damaged_regions(damage, animated, dpi, (width, height)))
+            }
+            _ => None,
+        };
 
-        for layer in layers {
-            self.flush(dpi, transformation, &layer, &mut encoder, &frame.view);
+        if regions.as_ref().map_or(true, |regions| !regions.is_empty()) {
+            self.render(
+                primitive,
+                overlay,
+                Layer::new(
+                    Rectangle {
+                        x: 0,
+                        y: 0,
+                        width: u32::from(width),
+                        height: u32::from(height),
+                    },
+                    Vector::new(0, 0),
+                    Transformation::identity(),
+                ),
+                backbuffer.view().expect("Backbuffer frame"),
+                clear_color,
+                regions
+                    .as_ref()
+                    .map(|regions| (&backbuffer, regions.as_slice())),
+            );
         }
 
+        let frame = target.next_frame(&self.device, self.color_space.format());
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&CommandEncoderDescriptor { todo: 0 });
+
+        // TODO: Pass the damaged regions along when presenting, so
+        // compositors only update those. The swap chains of wgpu 0.4 can only
+        // present whole frames.
+        backbuffer.present(&mut encoder, &frame.view);
+
         self.queue.submit(&[encoder.finish()]);
+        self.backbuffer = Some(backbuffer);
+
         self.image_pipeline.trim_cache();
+        self.check_memory();
 
         *mouse_cursor
     }
 
+    /// Draws a [`Primitive`] into a new [`Offscreen`] texture, instead of the
+    /// swap chain of a window.
+    ///
+    /// The `width` and `height` of the texture are in physical pixels, while
+    /// the primitive is scaled by the given `dpi`. The texture starts fully
+    /// transparent, which makes it easy to compose.
+    ///
+    /// This is useful to capture widgets as thumbnails, to mirror them into
+    /// other surfaces, or to apply post-processing effects.
+    ///
+    /// [`Primitive`]: enum.Primitive.html
+    /// [`Offscreen`]: struct.Offscreen.html
+    pub fn draw_to_texture(
+        &mut self,
+        primitive: &Primitive,
+        width: u16,
+        height: u16,
+        dpi: f32,
+    ) -> Offscreen {
+        log::debug!("Drawing offscreen");
+
+        let offscreen = Offscreen::new(
+            &self.device,
+            self.color_space.format(),
+            width,
+            height,
+        );
+        let overlay: &[&str] = &[];
+
+        self.viewport = (width, height, dpi);
+
+        self.render(
+            primitive,
+            overlay,
+            Layer::new(
+                Rectangle {
+                    x: 0,
+                    y: 0,
+                    width: u32::from(width),
+                    height: u32::from(height),
+                },
+                Vector::new(0, 0),
+                Transformation::identity(),
+            ),
+            offscreen.view(),
+            TRANSPARENT,
+            None,
+        );
+
+        self.image_pipeline.trim_cache();
+        self.check_memory();
+
+        offscreen
+    }
+
+    /// Draws a [`Primitive`] offscreen and reads back its pixels.
+    ///
+    /// The pixels are returned as 8-bit RGBA values, in sRGB, row by row from
+    /// the top. They are ready to be encoded as a PNG, or compared in visual
+    /// regression tests.
+    ///
+    /// The `width` and `height` are in physical pixels, while the primitive
+    /// is scaled by the given `dpi`. Anything not covered by the primitive is
+    /// transparent.
+    ///
+    /// [`Primitive`]: enum.Primitive.html
+    pub fn screenshot(
+        &mut self,
+        primitive: &Primitive,
+        width: u16,
+        height: u16,
+        dpi: f32,
+    ) -> Vec<u8> {
+        let offscreen = self.draw_to_texture(primitive, width, height, dpi);
+
+        offscreen.read(&self.device, &mut self.queue)
+    }
+
+    /// Returns the [`Device`] used by the [`Renderer`].
+    ///
+    /// It can be used to create resources that work together with the
+    /// textures of [`Offscreen`] targets.
+    ///
+    /// [`Device`]: https://docs.rs/wgpu/0.4.0/wgpu/struct.Device.html
+    /// [`Renderer`]: struct.Renderer.html
+    /// [`Offscreen`]: struct.Offscreen.html
+    pub fn device(&self) -> &Device {
+        &self.device
+    }
+
+    /// Returns the [`Queue`] used by the [`Renderer`].
+    ///
+    /// It can be used to update the textures drawn by a
+    /// [`Primitive::Texture`].
+    ///
+    /// [`Queue`]: https://docs.rs/wgpu/0.4.0/wgpu/struct.Queue.html
+    /// [`Renderer`]: struct.Renderer.html
+    /// [`Primitive::Texture`]: enum.Primitive.html#variant.Texture
+    pub fn queue(&mut self) -> &mut Queue {
+        &mut self.queue
+    }
+
+    /// Replaces the planes of a [`Nv12`] frame of video and converts them,
+    /// so the next frame drawing its [`Handle`] shows the new picture.
+    ///
+    /// The luma plane must have a byte per pixel, and the chroma plane two
+    /// bytes per block of 2x2 pixels, row by row with no padding.
+    ///
+    /// # Panics
+    /// If the sizes of the planes do not match the dimensions of the frame.
+    ///
+    /// [`Nv12`]: texture/struct.Nv12.html
+    /// [`Handle`]: texture/struct.Handle.html
+    pub fn upload_nv12(
+        &mut self,
+        frame: &texture::Nv12,
+        luma: &[u8],
+        chroma: &[u8],
+    ) {
+        let mut encoder = self
+            .device
+            .create_command_encoder(&CommandEncoderDescriptor { todo: 0 });
+
+        frame.upload(&self.device, &mut encoder, luma, chroma);
+
+        self.queue.submit(&[encoder.finish()]);
+    }
+
+    /// Returns the [`Antialiasing`] strategy of the [`Renderer`], if any.
+    ///
+    /// [`Antialiasing`]: enum.Antialiasing.html
+    /// [`Renderer`]: struct.Renderer.html
+    pub fn antialiasing(&self) -> Option<Antialiasing> {
+        self.antialiasing
+    }
+
+    /// Changes the [`Antialiasing`] strategy of the [`Renderer`].
+    ///
+    /// The pipelines affected by the strategy are rebuilt, so you should not
+    /// call this every frame.
+    ///
+    /// [`Antialiasing`]: enum.Antialiasing.html
+    /// [`Renderer`]: struct.Renderer.html
+    pub fn set_antialiasing(&mut self, antialiasing: Option<Antialiasing>) {
+        if self.antialiasing == antialiasing {
+            return;
+        }
+
+        let sample_count = antialiasing.map_or(1, Antialiasing::sample_count);
+        let previous = self.antialiasing.map_or(1, Antialiasing::sample_count);
+
+        if sample_count != previous {
+            self.quad_pipeline = quad::Pipeline::new(
+                &mut self.device,
+                self.color_space.format(),
+                sample_count,
+            );
+        }
+
+        self.antialiasing = antialiasing;
+        self.rebuild_antialiasing();
+    }
+
+    /// Returns how the [`Renderer`] rasterizes the glyphs of text.
+    ///
+    /// [`Renderer`]: struct.Renderer.html
+    pub fn text_rendering(&self) -> TextRendering {
+        self.text_rendering
+    }
+
+    /// Changes how the [`Renderer`] rasterizes the glyphs of text.
+    ///
+    /// With subpixel antialiasing, the text of every layer is drawn into a
+    /// texture three times wider than the target, and then blended over it
+    /// with a different coverage for each subpixel. Targets wider than 2730
+    /// physical pixels exceed the maximum texture size of some adapters, so
+    /// their text falls back to grayscale antialiasing.
+    ///
+    /// [`Renderer`]: struct.Renderer.html
+    pub fn set_text_rendering(&mut self, text_rendering: TextRendering) {
+        if self.text_rendering == text_rendering {
+            return;
+        }
+
+        self.text_rendering = text_rendering;
+        self.text_pipeline.set_hinting(text_rendering.hinting);
+        self.rebuild_antialiasing();
+    }
+
+    /// Returns the [`ColorSpace`] of the [`Renderer`].
+    ///
+    /// [`ColorSpace`]: enum.ColorSpace.html
+    /// [`Renderer`]: struct.Renderer.html
+    pub fn color_space(&self) -> ColorSpace {
+        self.color_space
+    }
+
+    /// Changes the [`ColorSpace`] of the [`Renderer`].
+    ///
+    /// Every pipeline drawing into the targets is rebuilt, together with the
+    /// swap chain of the next [`Target`] drawn, so you should not call this
+    /// every frame. The resources of custom primitives are dropped, as their
+    /// pipelines may not match the new format.
+    ///
+    /// [`ColorSpace`]: enum.ColorSpace.html
+    /// [`Renderer`]: struct.Renderer.html
+    /// [`Target`]: struct.Target.html
+    pub fn set_color_space(&mut self, color_space: ColorSpace) {
+        if self.color_space == color_space {
+            return;
+        }
+
+        let format = color_space.format();
+        let sample_count =
+            self.antialiasing.map_or(1, Antialiasing::sample_count);
+
+        self.color_space = color_space;
+        self.rebuild_text_pipeline();
+
+        self.quad_pipeline =
+            quad::Pipeline::new(&mut self.device, format, sample_count);
+        self.image_pipeline
+            .set_color_space(&self.device, color_space);
+        self.custom_storage = custom::Storage::default();
+        self.backbuffer = None;
+
+        self.rebuild_antialiasing();
+    }
+
+    /// Returns the configuration of the [`GlyphCache`] of the [`Renderer`].
+    ///
+    /// [`GlyphCache`]: struct.GlyphCache.html
+    /// [`Renderer`]: struct.Renderer.html
+    pub fn glyph_cache(&self) -> GlyphCache {
+        self.glyph_cache
+    }
+
+    /// Changes the configuration of the [`GlyphCache`] of the [`Renderer`].
+    ///
+    /// The cache is rebuilt empty, so you should configure it before drawing
+    /// anything.
+    ///
+    /// [`GlyphCache`]: struct.GlyphCache.html
+    /// [`Renderer`]: struct.Renderer.html
+    pub fn set_glyph_cache(&mut self, glyph_cache: GlyphCache) {
+        if self.glyph_cache == glyph_cache {
+            return;
+        }
+
+        self.glyph_cache = glyph_cache;
+        self.rebuild_text_pipeline();
+    }
+
+    /// Returns the budget of device memory of the caches of the [`Renderer`],
+    /// in bytes, if any.
+    ///
+    /// [`Renderer`]: struct.Renderer.html
+    pub fn memory_budget(&self) -> Option<u64> {
+        self.memory_budget
+    }
+
+    /// Changes the budget of device memory of the caches of the
+    /// [`Renderer`], in bytes.
+    ///
+    /// The device memory used by cached images, SVGs, and glyphs is estimated
+    /// after every frame. When it exceeds the budget, the caches are purged
+    /// and a [`memory::Pressure`] is reported to the runtime, which delivers
+    /// it to [`subscription::memory_pressure`]. It is reported again only
+    /// after the usage drops below the budget, so a frame that alone exceeds
+    /// it does not purge the caches over and over.
+    ///
+    /// A failed allocation of device memory is always reported, with or
+    /// without a budget. By default, there is no budget.
+    ///
+    /// [`Renderer`]: struct.Renderer.html
+    /// [`memory::Pressure`]: ../iced_native/memory/struct.Pressure.html
+    /// [`subscription::memory_pressure`]: ../iced_native/subscription/fn.memory_pressure.html
+    pub fn set_memory_budget(&mut self, budget: Option<u64>) {
+        self.memory_budget = budget;
+        self.is_over_budget = false;
+    }
+
+    /// Rasterizes the glyphs of the given content ahead of time, so the
+    /// first frame showing them does not stutter.
+    ///
+    /// Glyphs are cached per size and font. The `size` is the one of the
+    /// text in your widgets, and the `dpi` the one of the target the text
+    /// will be drawn into.
+    ///
+    /// This is useful before opening a large document, or with the whole
+    /// charset of a language. Glyphs can still be evicted later, when the
+    /// [`GlyphCache`] is full.
+    ///
+    /// [`GlyphCache`]: struct.GlyphCache.html
+    pub fn warm_glyph_cache(
+        &mut self,
+        content: &str,
+        size: u16,
+        font: Font,
+        dpi: f32,
+    ) {
+        let target =
+            Offscreen::new(&self.device, self.color_space.format(), 1, 1);
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&CommandEncoderDescriptor { todo: 0 });
+
+        let result = self.text_pipeline.warm(
+            &mut self.device,
+            &mut encoder,
+            target.view(),
+            content,
+            f32::from(size) * self.text_pipeline.scale() * dpi,
+            font,
+        );
+
+        self.queue.submit(&[encoder.finish()]);
+
+        if let Err(error) = result {
+            log::error!("Failed to rasterize glyphs: {}", error);
+
+            self.allocation_failed = true;
+            self.check_memory();
+        }
+    }
+
+    /// Prepares the resources of a [`Warmup`] before the first frame, so
+    /// the window does not open blank or stutter while they load.
+    ///
+    /// The `dpi` is the one of the target the resources will be drawn into.
+    /// Pipelines are built when the [`Renderer`] is created, but some
+    /// drivers only finish compiling them when they are first used, so this
+    /// also draws with them once, offscreen.
+    ///
+    /// Images and SVGs stay cached until the end of the next frame, like any
+    /// other image. Prepare them right before the frame showing them.
+    ///
+    /// [`Warmup`]: struct.Warmup.html
+    /// [`Renderer`]: struct.Renderer.html
+    pub fn warmup(&mut self, warmup: &Warmup, dpi: f32) {
+        for (content, size, font) in &warmup.text {
+            self.warm_glyph_cache(content, *size, *font, dpi);
+        }
+
+        let pixel = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 1.0,
+            height: 1.0,
+        };
+
+        let mut primitives = vec![Primitive::Quad {
+            bounds: pixel,
+            background: Background::Color(Color {
+                a: 0.0,
+                ..Color::BLACK
+            }),
+            border_radius: 0,
+            shadow: None,
+        }];
+
+        primitives.extend(warmup.images.iter().map(|handle| {
+            Primitive::Image {
+                handle: handle.clone(),
+                bounds: pixel,
+                border_radius: 0,
+                fit: Fit::Fill,
+            }
+        }));
+
+        primitives.extend(warmup.svgs.iter().map(|(handle, size)| {
+            Primitive::Svg {
+                handle: handle.clone(),
+                bounds: Rectangle {
+                    x: 0.0,
+                    y: 0.0,
+                    width: size.width,
+                    height: size.height,
+                },
+                border_radius: 0,
+                color: None,
+            }
+        }));
+
+        let target =
+            Offscreen::new(&self.device, self.color_space.format(), 1, 1);
+        let primitive = Primitive::Group { primitives };
+        let overlay: &[&str] = &[];
+
+        // Nothing is trimmed afterwards, so the images survive until the end
+        // of the next frame
+        self.viewport = (1, 1, dpi);
+
+        self.render(
+            &primitive,
+            overlay,
+            Layer::new(
+                Rectangle {
+                    x: 0,
+                    y: 0,
+                    width: 1,
+                    height: 1,
+                },
+                Vector::new(0, 0),
+                Transformation::identity(),
+            ),
+            target.view(),
+            TRANSPARENT,
+            None,
+        );
+    }
+
+    /// Returns the estimated device memory used by the caches, in bytes.
+    fn memory_usage(&self) -> u64 {
+        let (width, height) = self.glyph_cache.size;
+
+        // The glyph cache starts with its configured size
+        let glyphs = (u64::from(width) * u64::from(height))
+            .max(self.text_pipeline.cache_bytes());
+
+        self.image_pipeline.device_bytes() + glyphs
+    }
+
+    fn check_memory(&mut self) {
+        let usage = self.memory_usage();
+
+        let is_over_budget = match self.memory_budget {
+            Some(budget) => usage > budget,
+            None => false,
+        };
+
+        let cause = if self.allocation_failed {
+            Some(memory::Cause::AllocationFailed)
+        } else if is_over_budget && !self.is_over_budget {
+            Some(memory::Cause::Budget)
+        } else {
+            None
+        };
+
+        self.allocation_failed = false;
+        self.is_over_budget = is_over_budget;
+
+        if let Some(cause) = cause {
+            self.trim_memory();
+
+            let used = self.memory_usage();
+
+            log::warn!(
+                "Memory pressure ({:?}): caches trimmed from {} to {} bytes",
+                cause,
+                usage,
+                used
+            );
+
+            self.memory_pressure = Some(memory::Pressure {
+                cause,
+                used,
+                budget: self.memory_budget,
+            });
+        }
+    }
+
+    fn trim_memory(&mut self) {
+        self.image_pipeline.clear_cache();
+
+        let (width, height) = self.glyph_cache.size;
+
+        // A glyph cache that has grown can only shrink by starting over
+        if self.text_pipeline.cache_bytes()
+            > u64::from(width) * u64::from(height)
+        {
+            self.rebuild_text_pipeline();
+        }
+    }
+
+    fn rebuild_text_pipeline(&mut self) {
+        let mut text_pipeline = text::Pipeline::new(
+            &mut self.device,
+            self.color_space.format(),
+            self.glyph_cache,
+            self.is_atlas_inspected,
+        );
+
+        text_pipeline.set_scale(self.text_pipeline.scale());
+        text_pipeline.set_hinting(self.text_pipeline.hinting());
+
+        self.text_pipeline = text_pipeline;
+    }
+
+    fn rebuild_antialiasing(&mut self) {
+        let format = self.color_space.format();
+
+        self.msaa = match self.antialiasing {
+            Some(antialiasing) if antialiasing.sample_count() > 1 => {
+                Some(antialiasing::Msaa::new(
+                    &self.device,
+                    format,
+                    antialiasing.sample_count(),
+                ))
+            }
+            _ => None,
+        };
+
+        self.fxaa = match self.antialiasing {
+            Some(Antialiasing::FXAA) => {
+                Some(antialiasing::Fxaa::new(&self.device, format))
+            }
+            _ => None,
+        };
+
+        self.subpixel = match self.text_rendering.antialiasing {
+            TextAntialiasing::SubpixelRgb => {
+                Some(antialiasing::Subpixel::new(&self.device, format))
+            }
+            TextAntialiasing::Grayscale => None,
+        };
+    }
+
+    /// Returns the [`Arena`] reusing the allocations of old primitives.
+    ///
+    /// Use it to configure how many allocations are kept, or to build the
+    /// primitives of your own widgets with fewer allocations.
+    ///
+    /// [`Arena`]: struct.Arena.html
+    pub fn arena(&mut self) -> &mut Arena {
+        &mut self.arena
+    }
+
+    /// Measures the given content with the given size and font, wrapping it
+    /// to fit the given bounds, in logical pixels.
+    ///
+    /// It lays out the content exactly like a [`Text`] widget does, and
+    /// shares its measurement cache, so measuring the same content again is
+    /// cheap. Use [`text::Renderer::measure`] to measure text with custom
+    /// [`text::Params`].
+    ///
+    /// [`Text`]: ../iced_native/widget/text/struct.Text.html
+    /// [`text::Renderer::measure`]: ../iced_native/widget/text/trait.Renderer.html#tymethod.measure
+    /// [`text::Params`]: ../iced_native/text/struct.Params.html
+    pub fn measure_text(
+        &self,
+        content: &str,
+        size: u16,
+        font: Font,
+        bounds: Size,
+    ) -> Size {
+        let (width, height) = self.text_pipeline.measure(
+            content,
+            f32::from(size),
+            font,
+            iced_native::text::Params::default(),
+            bounds,
+        );
+
+        Size::new(width, height)
+    }
+
+    /// Returns the [`FontMetrics`] of the given font at the given size.
+    ///
+    /// Like measurements, they take the text scale of the [`Renderer`] into
+    /// account.
+    ///
+    /// [`FontMetrics`]: struct.FontMetrics.html
+    /// [`Renderer`]: struct.Renderer.html
+    pub fn font_metrics(&self, size: u16, font: Font) -> FontMetrics {
+        let metrics = self.text_pipeline.v_metrics(f32::from(size), font);
+
+        FontMetrics {
+            ascent: metrics.ascent,
+            descent: metrics.descent,
+            line_gap: metrics.line_gap,
+        }
+    }
+
+    // Without damage, the target is cleared and drawn whole. Otherwise, the
+    // target is the frame of the backbuffer, and only the damaged regions,
+    // in physical pixels, are cleared and drawn again.
+    fn render<'a, T: AsRef<str>>(
+        &mut self,
+        primitive: &'a Primitive,
+        overlay: &'a [T],
+        root: Layer<'a>,
+        target: &wgpu::TextureView,
+        clear_color: wgpu::Color,
+        damage: Option<(&Backbuffer, &[Rectangle<u32>])>,
+    ) {
+        let (width, height, dpi) = self.viewport;
+        let transformation = Transformation::orthographic(width, height);
+
+        // Masked content drawn during this render must outlive its commands
+        let masked = self.masked.len();
+
+        // Nested renders, like masks, are smoothed together with the rest
+        let mut fxaa = self.fxaa.take();
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&CommandEncoderDescriptor { todo: 0 });
+
+        let frame = match &mut fxaa {
+            Some(fxaa) => fxaa.source(&self.device, width, height),
+            None => target,
+        };
+
+        if damage.is_none() {
+            let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                color_attachments: &[
+                    wgpu::RenderPassColorAttachmentDescriptor {
+                        attachment: frame,
+                        resolve_target: None,
+                        load_op: wgpu::LoadOp::Clear,
+                        store_op: wgpu::StoreOp::Store,
+                        clear_color,
+                    },
+                ],
+                depth_stencil_attachment: None,
+            });
+        }
+
+        let mut layers = vec![root];
+
+        self.draw_primitive(primitive, &mut layers);
+        self.draw_overlay(overlay, &mut layers);
+
+        let layers = merge(layers);
+
+        match damage {
+            Some((backbuffer, regions)) => {
+                // Regions are cleared and drawn one after the other, so
+                // content in overlapping regions is not blended twice
+                for region in regions {
+                    backbuffer.clear(&mut encoder, *region);
+
+                    for layer in &layers {
+                        if let Some(bounds) =
+                            intersection(layer.bounds * dpi, *region)
+                        {
+                            self.flush(
+                                dpi,
+                                transformation,
+                                layer,
+                                bounds,
+                                &mut encoder,
+                                frame,
+                            );
+                        }
+                    }
+                }
+            }
+            None => {
+                for layer in &layers {
+                    self.flush(
+                        dpi,
+                        transformation,
+                        layer,
+                        layer.bounds * dpi,
+                        &mut encoder,
+                        frame,
+                    );
+                }
+            }
+        }
+
+        if let Some(fxaa) = &fxaa {
+            fxaa.apply(&mut encoder, target);
+        }
+
+        self.queue.submit(&[encoder.finish()]);
+        self.masked.truncate(masked);
+        self.fxaa = fxaa;
+    }
+
     fn draw_primitive<'a>(
         &mut self,
         primitive: &'a Primitive,
@@ -142,7 +965,6 @@ impl Renderer {
         match primitive {
             Primitive::None => {}
             Primitive::Group { primitives } => {
-                // TODO: Inspect a bit and regroup (?)
                 for primitive in primitives {
                     self.draw_primitive(primitive, layers)
                 }
@@ -153,70 +975,103 @@ impl Renderer {
                 size,
                 color,
                 font,
+                params,
                 horizontal_alignment,
                 vertical_alignment,
             } => {
-                let x = match horizontal_alignment {
-                    iced_native::HorizontalAlignment::Left => bounds.x,
-                    iced_native::HorizontalAlignment::Center => {
-                        bounds.x + bounds.width / 2.0
-                    }
-                    iced_native::HorizontalAlignment::Right => {
-                        bounds.x + bounds.width
-                    }
-                };
+                let (screen_position, layout) = align_text(
+                    *bounds,
+                    *horizontal_alignment,
+                    *vertical_alignment,
+                );
 
-                let y = match vertical_alignment {
-                    iced_native::VerticalAlignment::Top => bounds.y,
-                    iced_native::VerticalAlignment::Center => {
-                        bounds.y + bounds.height / 2.0
-                    }
-                    iced_native::VerticalAlignment::Bottom => {
-                        bounds.y + bounds.height
-                    }
+                let section = wgpu_glyph::VariedSection {
+                    screen_position: (
+                        screen_position.0 - layer.offset.x as f32,
+                        screen_position.1 - layer.offset.y as f32,
+                    ),
+                    bounds: (bounds.width, bounds.height),
+                    layout,
+                    text: vec![self.text_pipeline.span(
+                        content,
+                        *size,
+                        *font,
+                        None,
+                        fade(self.color_space.encode(*color), self.opacity),
+                    )],
+                    ..Default::default()
                 };
 
-                layer.text.push(wgpu_glyph::Section {
-                    text: &content,
+                self.push_text(layer, section, *params, *color);
+            }
+            Primitive::Paragraph {
+                spans,
+                bounds,
+                params,
+                horizontal_alignment,
+                vertical_alignment,
+            } => {
+                let (screen_position, layout) = align_text(
+                    *bounds,
+                    *horizontal_alignment,
+                    *vertical_alignment,
+                );
+
+                let section = wgpu_glyph::VariedSection {
                     screen_position: (
-                        x - layer.offset.x as f32,
-                        y - layer.offset.y as f32,
+                        screen_position.0 - layer.offset.x as f32,
+                        screen_position.1 - layer.offset.y as f32,
                     ),
                     bounds: (bounds.width, bounds.height),
-                    scale: wgpu_glyph::Scale { x: *size, y: *size },
-                    color: color.into_linear(),
-                    font_id: self.text_pipeline.find_font(*font),
-                    layout: wgpu_glyph::Layout::default()
-                        .h_align(match horizontal_alignment {
-                            iced_native::HorizontalAlignment::Left => {
-                                wgpu_glyph::HorizontalAlign::Left
-                            }
-                            iced_native::HorizontalAlignment::Center => {
-                                wgpu_glyph::HorizontalAlign::Center
-                            }
-                            iced_native::HorizontalAlignment::Right => {
-                                wgpu_glyph::HorizontalAlign::Right
-                            }
+                    layout,
+                    text: spans
+                        .iter()
+                        .map(|span| {
+                            self.text_pipeline.span(
+                                &span.content,
+                                span.size,
+                                span.font,
+                                span.weight,
+                                fade(
+                                    self.color_space.encode(span.color),
+                                    self.opacity,
+                                ),
+                            )
                         })
-                        .v_align(match vertical_alignment {
-                            iced_native::VerticalAlignment::Top => {
-                                wgpu_glyph::VerticalAlign::Top
-                            }
-                            iced_native::VerticalAlignment::Center => {
-                                wgpu_glyph::VerticalAlign::Center
-                            }
-                            iced_native::VerticalAlignment::Bottom => {
-                                wgpu_glyph::VerticalAlign::Bottom
-                            }
-                        }),
+                        .collect(),
                     ..Default::default()
-                })
+                };
+
+                // Decorations take the color of the first span
+                let color =
+                    spans.first().map_or(Color::BLACK, |span| span.color);
+
+                self.push_text(layer, section, *params, color);
             }
             Primitive::Quad {
                 bounds,
                 background,
                 border_radius,
+                shadow,
             } => {
+                // The shadow is a blurred quad drawn right before the quad
+                // casting it
+                if let Some(shadow) = shadow {
+                    layer.quads.push(Quad {
+                        position: [
+                            bounds.x + shadow.offset.x - layer.offset.x as f32,
+                            bounds.y + shadow.offset.y - layer.offset.y as f32,
+                        ],
+                        scale: [bounds.width, bounds.height],
+                        color: fade(
+                            self.color_space.encode(shadow.color),
+                            self.opacity,
+                        ),
+                        border_radius: *border_radius as f32,
+                        blur_radius: shadow.blur_radius,
+                    });
+                }
+
                 // TODO: Move some of this computations to the GPU (?)
                 layer.quads.push(Quad {
                     position: [
@@ -225,23 +1080,129 @@ impl Renderer {
                     ],
                     scale: [bounds.width, bounds.height],
                     color: match background {
-                        Background::Color(color) => color.into_linear(),
+                        Background::Color(color) => {
+                            fade(self.color_space.encode(*color), self.opacity)
+                        }
                     },
                     border_radius: *border_radius as f32,
+                    blur_radius: 0.0,
                 });
             }
-            Primitive::Image { handle, bounds } => {
-                layer.images.push(Image {
+            Primitive::Image {
+                handle,
+                bounds,
+                border_radius,
+                fit,
+            } => {
+                let mut instance = Image {
                     handle: image::Handle::Raster(handle.clone()),
                     position: [bounds.x, bounds.y],
                     scale: [bounds.width, bounds.height],
-                });
+                    opacity: self.opacity,
+                    texture_position: [0.0, 0.0],
+                    texture_scale: [1.0, 1.0],
+                    tiles: [1.0, 1.0],
+                    border_radius: *border_radius as f32,
+                };
+
+                if *fit != Fit::Fill {
+                    let dimensions = self.image_pipeline.dimensions(handle);
+
+                    apply_fit(&mut instance, *bounds, dimensions, *fit);
+                }
+
+                layer.images.push(instance);
+            }
+            Primitive::Texture {
+                handle,
+                bounds,
+                border_radius,
+                fit,
+            } => {
+                let mut instance = Image {
+                    handle: image::Handle::View(handle.clone()),
+                    position: [bounds.x, bounds.y],
+                    scale: [bounds.width, bounds.height],
+                    opacity: self.opacity,
+                    texture_position: [0.0, 0.0],
+                    texture_scale: [1.0, 1.0],
+                    tiles: [1.0, 1.0],
+                    border_radius: *border_radius as f32,
+                };
+
+                apply_fit(&mut instance, *bounds, handle.dimensions(), *fit);
+
+                layer.images.push(instance);
             }
-            Primitive::Svg { handle, bounds } => {
+            Primitive::NinePatch {
+                handle,
+                bounds,
+                slices,
+            } => {
+                let (width, height) = self.image_pipeline.dimensions(handle);
+                let (width, height) = (width as f32, height as f32);
+
+                let columns = nine_patch_cuts(
+                    width,
+                    bounds.width,
+                    slices.left,
+                    slices.right,
+                );
+
+                let rows = nine_patch_cuts(
+                    height,
+                    bounds.height,
+                    slices.top,
+                    slices.bottom,
+                );
+
+                for &((y, source_height), (target_y, target_height)) in &rows {
+                    for &((x, source_width), (target_x, target_width)) in
+                        &columns
+                    {
+                        let is_visible = source_width > 0.0
+                            && source_height > 0.0
+                            && target_width > 0.0
+                            && target_height > 0.0;
+
+                        if !is_visible {
+                            continue;
+                        }
+
+                        layer.images.push(Image {
+                            handle: image::Handle::Raster(handle.clone()),
+                            position: [
+                                bounds.x + target_x,
+                                bounds.y + target_y,
+                            ],
+                            scale: [target_width, target_height],
+                            opacity: self.opacity,
+                            texture_position: [x / width, y / height],
+                            texture_scale: [
+                                source_width / width,
+                                source_height / height,
+                            ],
+                            tiles: [1.0, 1.0],
+                            border_radius: 0.0,
+                        });
+                    }
+                }
+            }
+            Primitive::Svg {
+                handle,
+                bounds,
+                border_radius,
+                color,
+            } => {
                 layer.images.push(Image {
-                    handle: image::Handle::Vector(handle.clone()),
+                    handle: image::Handle::Vector(handle.clone(), *color),
                     position: [bounds.x, bounds.y],
                     scale: [bounds.width, bounds.height],
+                    opacity: self.opacity,
+                    texture_position: [0.0, 0.0],
+                    texture_scale: [1.0, 1.0],
+                    tiles: [1.0, 1.0],
+                    border_radius: *border_radius as f32,
                 });
             }
             Primitive::Clip {
@@ -249,10 +1210,22 @@ impl Renderer {
                 offset,
                 content,
             } => {
-                let x = bounds.x - layer.offset.x as f32;
-                let y = bounds.y - layer.offset.y as f32;
-                let width = (bounds.width + x).min(bounds.width);
-                let height = (bounds.height + y).min(bounds.height);
+                // Clips inside of a transformation are transformed too. The
+                // scissor rectangle of a layer is axis-aligned, so a rotated
+                // clip becomes its bounding box.
+                let Rectangle {
+                    x,
+                    y,
+                    width,
+                    height,
+                } = layer.transformation.transform_bounds(Rectangle {
+                    x: bounds.x - layer.offset.x as f32,
+                    y: bounds.y - layer.offset.y as f32,
+                    ..*bounds
+                });
+
+                let width = (width + x).min(width);
+                let height = (height + y).min(height);
 
                 // Only draw visible content on-screen
                 // TODO: Also, check for parent layer bounds to avoid further
@@ -266,16 +1239,234 @@ impl Renderer {
                             height: height.ceil() as u32,
                         },
                         layer.offset + *offset,
+                        layer.transformation,
                     );
 
-                    let new_layer = Layer::new(layer.bounds, layer.offset);
+                    let new_layer = Layer::new(
+                        layer.bounds,
+                        layer.offset,
+                        layer.transformation,
+                    );
 
                     layers.push(clip_layer);
                     self.draw_primitive(content, layers);
                     layers.push(new_layer);
                 }
             }
+            Primitive::Transform {
+                transformation,
+                content,
+            } => {
+                // The content is offset before being transformed, so the
+                // transformation is moved to the offset coordinate system
+                let offset =
+                    Vector::new(layer.offset.x as f32, layer.offset.y as f32);
+
+                let transformed_layer = Layer::new(
+                    layer.bounds,
+                    layer.offset,
+                    layer.transformation
+                        * Transformation::translate(-offset.x, -offset.y)
+                        * *transformation
+                        * Transformation::translate(offset.x, offset.y),
+                );
+
+                let new_layer = Layer::new(
+                    layer.bounds,
+                    layer.offset,
+                    layer.transformation,
+                );
+
+                layers.push(transformed_layer);
+                self.draw_primitive(content, layers);
+                layers.push(new_layer);
+            }
+            Primitive::Transparency { alpha, content } => {
+                // Instead of compositing the content in a separate texture,
+                // its opacity is multiplied through every pipeline. As a
+                // consequence, overlapping content shows through.
+                let opacity = self.opacity;
+
+                self.opacity *= alpha.clamp(0.0, 1.0);
+
+                if self.opacity > 0.0 {
+                    self.draw_primitive(content, layers);
+                }
+
+                self.opacity = opacity;
+            }
+            Primitive::CircularClip {
+                center,
+                radius,
+                content,
+            } => {
+                if *radius <= 0.0 || self.opacity <= 0.0 {
+                    return;
+                }
+
+                // There is no way to clip a circle in our pipelines. Instead,
+                // the content is drawn into its own texture, which is then
+                // drawn as a round image on top of the layer.
+                let (width, height, dpi) = self.viewport;
+                let (bounds, offset, transformation) =
+                    (layer.bounds, layer.offset, layer.transformation);
+
+                let offscreen = Offscreen::new(
+                    &self.device,
+                    self.color_space.format(),
+                    width,
+                    height,
+                );
+                let overlay: &[&str] = &[];
+                let opacity = self.opacity;
+
+                self.opacity = 1.0;
+                self.render(
+                    content,
+                    overlay,
+                    Layer::new(bounds, offset, transformation),
+                    offscreen.view(),
+                    TRANSPARENT,
+                    None,
+                );
+                self.opacity = opacity;
+
+                let circle = Rectangle {
+                    x: center.x - radius,
+                    y: center.y - radius,
+                    width: radius * 2.0,
+                    height: radius * 2.0,
+                };
+
+                // The region of the texture under the circle, which matches
+                // the screen
+                let region = transformation.transform_bounds(Rectangle {
+                    x: circle.x - offset.x as f32,
+                    y: circle.y - offset.y as f32,
+                    ..circle
+                });
+
+                let mut masked_layer =
+                    Layer::new(bounds, offset, transformation);
+
+                masked_layer.images.push(Image {
+                    handle: image::Handle::Texture(
+                        self.image_pipeline
+                            .bind_texture(&self.device, offscreen.view()),
+                    ),
+                    position: [circle.x, circle.y],
+                    scale: [circle.width, circle.height],
+                    opacity,
+                    texture_position: [
+                        region.x * dpi / f32::from(width),
+                        region.y * dpi / f32::from(height),
+                    ],
+                    texture_scale: [
+                        region.width * dpi / f32::from(width),
+                        region.height * dpi / f32::from(height),
+                    ],
+                    tiles: [1.0, 1.0],
+                    border_radius: *radius,
+                });
+
+                self.masked.push(offscreen);
+
+                layers.push(masked_layer);
+                layers.push(Layer::new(bounds, offset, transformation));
+            }
+            Primitive::Custom { bounds, program } => {
+                let mut custom_layer = Layer::new(
+                    layer.bounds,
+                    layer.offset,
+                    layer.transformation,
+                );
+
+                custom_layer.programs.push((
+                    Rectangle {
+                        x: bounds.x - layer.offset.x as f32,
+                        y: bounds.y - layer.offset.y as f32,
+                        ..*bounds
+                    },
+                    program.clone(),
+                ));
+
+                let new_layer = Layer::new(
+                    layer.bounds,
+                    layer.offset,
+                    layer.transformation,
+                );
+
+                layers.push(custom_layer);
+                layers.push(new_layer);
+            }
+            Primitive::Cached { cache } => {
+                self.draw_primitive(cache, layers);
+            }
+        }
+    }
+
+    /// Pushes a section of text to a layer, with the lines of its
+    /// decoration.
+    ///
+    /// The decoration takes the given color, unless it has its own.
+    fn push_text<'a>(
+        &self,
+        layer: &mut Layer<'a>,
+        section: wgpu_glyph::VariedSection<'a>,
+        params: iced_native::text::Params,
+        color: Color,
+    ) {
+        let text_scale = self.text_pipeline.scale();
+
+        let params = iced_native::text::Params {
+            letter_spacing: params.letter_spacing * text_scale,
+            ..params
+        };
+
+        let decoration = params.decoration;
+
+        if !decoration.is_empty() {
+            let color = fade(
+                self.color_space.encode(decoration.color.unwrap_or(color)),
+                self.opacity,
+            );
+
+            // Lines are at least a physical pixel thick
+            let min_thickness = 1.0 / self.viewport.2;
+
+            for line in self.text_pipeline.extents(&section, params) {
+                if line.width <= 0.0 {
+                    continue;
+                }
+
+                let thickness = decoration
+                    .thickness
+                    .map(|thickness| thickness * text_scale)
+                    .unwrap_or((line.ascent - line.descent) / 16.0)
+                    .max(min_thickness);
+
+                let rules = [
+                    (decoration.underline, line.baseline - line.descent / 2.0),
+                    (
+                        decoration.strikethrough,
+                        line.baseline - line.ascent * 0.3,
+                    ),
+                    (decoration.overline, line.baseline - line.ascent),
+                ];
+
+                for (_, y) in rules.iter().filter(|(is_drawn, _)| *is_drawn) {
+                    layer.quads.push(Quad {
+                        position: [line.x, y - thickness / 2.0],
+                        scale: [line.width, thickness],
+                        color,
+                        border_radius: 0.0,
+                        blur_radius: 0.0,
+                    });
+                }
+            }
         }
+
+        layer.text.push((section, params));
     }
 
     fn draw_overlay<'a, T: AsRef<str>>(
@@ -284,65 +1475,111 @@ impl Renderer {
         layers: &mut Vec<Layer<'a>>,
     ) {
         let first = layers.first().unwrap();
-        let mut overlay = Layer::new(first.bounds, Vector::new(0, 0));
+        let mut overlay = Layer::new(
+            first.bounds,
+            Vector::new(0, 0),
+            Transformation::identity(),
+        );
 
         let font_id = self.text_pipeline.overlay_font();
         let scale = wgpu_glyph::Scale { x: 20.0, y: 20.0 };
 
         for (i, line) in lines.iter().enumerate() {
-            overlay.text.push(wgpu_glyph::Section {
-                text: line.as_ref(),
-                screen_position: (11.0, 11.0 + 25.0 * i as f32),
-                color: [0.9, 0.9, 0.9, 1.0],
-                scale,
-                font_id,
-                ..wgpu_glyph::Section::default()
-            });
+            overlay.text.push((
+                wgpu_glyph::VariedSection {
+                    screen_position: (11.0, 11.0 + 25.0 * i as f32),
+                    text: vec![wgpu_glyph::SectionText {
+                        text: line.as_ref(),
+                        color: [0.9, 0.9, 0.9, 1.0],
+                        scale,
+                        font_id,
+                    }],
+                    ..wgpu_glyph::VariedSection::default()
+                },
+                iced_native::text::Params::default(),
+            ));
 
-            overlay.text.push(wgpu_glyph::Section {
-                text: line.as_ref(),
-                screen_position: (10.0, 10.0 + 25.0 * i as f32),
-                color: [0.0, 0.0, 0.0, 1.0],
-                scale,
-                font_id,
-                ..wgpu_glyph::Section::default()
-            });
+            overlay.text.push((
+                wgpu_glyph::VariedSection {
+                    screen_position: (10.0, 10.0 + 25.0 * i as f32),
+                    text: vec![wgpu_glyph::SectionText {
+                        text: line.as_ref(),
+                        color: [0.0, 0.0, 0.0, 1.0],
+                        scale,
+                        font_id,
+                    }],
+                    ..wgpu_glyph::VariedSection::default()
+                },
+                iced_native::text::Params::default(),
+            ));
         }
 
         layers.push(overlay);
     }
 
+    // Draws the layer into the target, clipped to the given bounds in
+    // physical pixels
     fn flush(
         &mut self,
         dpi: f32,
         transformation: Transformation,
         layer: &Layer<'_>,
+        bounds: Rectangle<u32>,
         encoder: &mut wgpu::CommandEncoder,
         target: &wgpu::TextureView,
     ) {
-        let bounds = layer.bounds * dpi;
+        // Quads and text are positioned in physical pixels, so the
+        // transformation of the layer is applied in logical ones
+        let physical_transformation = transformation
+            * Transformation::scale(dpi, dpi)
+            * layer.transformation
+            * Transformation::scale(1.0 / dpi, 1.0 / dpi);
 
         if layer.quads.len() > 0 {
-            self.quad_pipeline.draw(
-                &mut self.device,
-                encoder,
-                &layer.quads,
-                transformation,
-                dpi,
-                bounds,
-                target,
-            );
+            match &mut self.msaa {
+                Some(msaa) => {
+                    let (width, height, _) = self.viewport;
+                    let (attachment, resolve) =
+                        msaa.targets(&self.device, encoder, width, height);
+
+                    self.quad_pipeline.draw(
+                        &mut self.device,
+                        encoder,
+                        &layer.quads,
+                        physical_transformation,
+                        dpi,
+                        bounds,
+                        attachment,
+                        Some(resolve),
+                    );
+
+                    msaa.composite(encoder, target, bounds);
+                }
+                None => {
+                    self.quad_pipeline.draw(
+                        &mut self.device,
+                        encoder,
+                        &layer.quads,
+                        physical_transformation,
+                        dpi,
+                        bounds,
+                        target,
+                        None,
+                    );
+                }
+            }
         }
 
         if layer.images.len() > 0 {
             let translated_and_scaled = transformation
                 * Transformation::scale(dpi, dpi)
+                * layer.transformation
                 * Transformation::translate(
                     -(layer.offset.x as f32),
                     -(layer.offset.y as f32),
                 );
 
-            self.image_pipeline.draw(
+            let next_frame_at = self.image_pipeline.draw(
                 &mut self.device,
                 encoder,
                 &layer.images,
@@ -351,55 +1588,109 @@ impl Renderer {
                 target,
                 dpi,
             );
-        }
 
-        if layer.text.len() > 0 {
-            for text in layer.text.iter() {
-                // Target physical coordinates directly to avoid blurry text
-                let text = wgpu_glyph::Section {
-                    // TODO: We `round` here to avoid rerasterizing text when
-                    // its position changes slightly. This can make text feel a
-                    // bit "jumpy". We may be able to do better once we improve
-                    // our text rendering/caching pipeline.
-                    screen_position: (
-                        (text.screen_position.0 * dpi).round(),
-                        (text.screen_position.1 * dpi).round(),
-                    ),
-                    // TODO: Fix precision issues with some DPI factors.
-                    //
-                    // The `ceil` here can cause some words to render on the
-                    // same line when they should not.
-                    //
-                    // Ideally, `wgpu_glyph` should be able to compute layout
-                    // using logical positions, and then apply the proper
-                    // DPI scaling. This would ensure that both measuring and
-                    // rendering follow the same layout rules.
-                    bounds: (
-                        (text.bounds.0 * dpi).ceil(),
-                        (text.bounds.1 * dpi).ceil(),
-                    ),
-                    scale: wgpu_glyph::Scale {
-                        x: text.scale.x * dpi,
-                        y: text.scale.y * dpi,
-                    },
-                    ..*text
-                };
+            // Animated images need to be drawn again when their frame changes,
+            // even if nothing else is damaged
+            if let Some(at) = next_frame_at {
+                iced_native::Renderer::schedule_redraw(self, at);
 
-                self.text_pipeline.queue(text);
+                self.animated.push(layer.bounds * dpi);
             }
+        }
+
+        for (program_bounds, program) in &layer.programs {
+            let program_bounds =
+                layer.transformation.transform_bounds(*program_bounds);
+            let (width, height, _) = self.viewport;
+            let started_at = Instant::now();
 
-            self.text_pipeline.draw_queued(
+            program.draw(
+                &mut self.custom_storage,
                 &mut self.device,
                 encoder,
                 target,
-                transformation,
-                wgpu_glyph::Region {
-                    x: bounds.x,
-                    y: bounds.y,
-                    width: bounds.width,
-                    height: bounds.height,
+                self.color_space.format(),
+                custom::Viewport {
+                    bounds: Rectangle {
+                        x: program_bounds.x * dpi,
+                        y: program_bounds.y * dpi,
+                        width: program_bounds.width * dpi,
+                        height: program_bounds.height * dpi,
+                    },
+                    clip: bounds,
+                    target_size: (u32::from(width), u32::from(height)),
+                    scale_factor: dpi,
                 },
             );
+
+            program.drawn(started_at.elapsed());
+        }
+
+        if layer.text.len() > 0 {
+            let (width, height, _) = self.viewport;
+
+            // Subpixels are laid out horizontally, so the text is drawn
+            // three times wider and squeezed back into the target
+            let subpixel = match &mut self.subpixel {
+                Some(subpixel)
+                    if width <= antialiasing::Subpixel::MAX_WIDTH =>
+                {
+                    Some(subpixel)
+                }
+                _ => None,
+            };
+
+            let stretch = if subpixel.is_some() { 3.0 } else { 1.0 };
+
+            for (text, params) in layer.text.iter() {
+                // Lay out in logical coordinates, like `measure` does, and
+                // scale the glyphs to physical pixels
+                self.text_pipeline
+                    .queue(text, *params, (dpi * stretch, dpi));
+            }
+
+            let result = match subpixel {
+                Some(subpixel) => {
+                    let source =
+                        subpixel.source(&self.device, encoder, width, height);
+
+                    let result = self.text_pipeline.draw_queued(
+                        &mut self.device,
+                        encoder,
+                        source,
+                        physical_transformation
+                            * Transformation::scale(1.0 / 3.0, 1.0),
+                        wgpu_glyph::Region {
+                            x: bounds.x * 3,
+                            y: bounds.y,
+                            width: bounds.width * 3,
+                            height: bounds.height,
+                        },
+                    );
+
+                    subpixel.composite(encoder, target, bounds);
+
+                    result
+                }
+                None => self.text_pipeline.draw_queued(
+                    &mut self.device,
+                    encoder,
+                    target,
+                    physical_transformation,
+                    wgpu_glyph::Region {
+                        x: bounds.x,
+                        y: bounds.y,
+                        width: bounds.width,
+                        height: bounds.height,
+                    },
+                ),
+            };
+
+            if let Err(error) = result {
+                log::error!("Failed to draw text: {}", error);
+
+                self.allocation_failed = true;
+            }
         }
     }
 }
@@ -417,6 +1708,44 @@ impl iced_native::Renderer for Renderer {
 
         node
     }
+
+    fn schedule_redraw(&mut self, at: Instant) {
+        self.scheduled_redraw = Some(match self.scheduled_redraw {
+            Some(scheduled) => scheduled.min(at),
+            None => at,
+        });
+    }
+
+    fn set_ime_caret(&mut self, caret: Rectangle) {
+        self.ime_caret = Some(caret);
+    }
+
+    fn overlay(
+        &mut self,
+        (base_primitive, base_cursor): (Primitive, MouseCursor),
+        (overlay_primitive, overlay_cursor): (Primitive, MouseCursor),
+        overlay_bounds: Rectangle,
+    ) -> Self::Output {
+        (
+            Primitive::Group {
+                primitives: vec![
+                    base_primitive,
+                    // Clipping starts a new layer, drawn after every layer of
+                    // the base
+                    Primitive::Clip {
+                        bounds: overlay_bounds,
+                        offset: Vector::new(0, 0),
+                        content: Box::new(overlay_primitive),
+                    },
+                ],
+            },
+            if overlay_cursor > MouseCursor::Idle {
+                overlay_cursor
+            } else {
+                base_cursor
+            },
+        )
+    }
 }
 
 impl Windowed for Renderer {
@@ -426,13 +1755,50 @@ impl Windowed for Renderer {
         Self::new()
     }
 
+    fn set_text_scale(&mut self, scale: f32) {
+        self.text_pipeline.set_scale(scale);
+    }
+
+    fn set_text_rendering(&mut self, text_rendering: TextRendering) {
+        Renderer::set_text_rendering(self, text_rendering);
+    }
+
     fn draw<T: AsRef<str>>(
         &mut self,
         output: &Self::Output,
+        damage: Option<&[Rectangle]>,
         overlay: &[T],
         target: &mut Target,
     ) -> MouseCursor {
-        self.draw(output, overlay, target)
+        self.draw(output, damage, overlay, target)
+    }
+
+    fn damage(
+        &self,
+        (previous, _): &Self::Output,
+        (current, _): &Self::Output,
+    ) -> Vec<Rectangle> {
+        damage::regions(previous, current)
+    }
+
+    fn mouse_cursor(&self, (_, mouse_cursor): &Self::Output) -> MouseCursor {
+        *mouse_cursor
+    }
+
+    fn next_redraw(&mut self) -> Option<Instant> {
+        self.scheduled_redraw.take()
+    }
+
+    fn ime_caret(&mut self) -> Option<Rectangle> {
+        self.ime_caret.take()
+    }
+
+    fn memory_pressure(&mut self) -> Option<memory::Pressure> {
+        self.memory_pressure.take()
+    }
+
+    fn recycle(&mut self, (primitive, _): Self::Output) {
+        self.arena.recycle(primitive);
     }
 }
 
@@ -464,9 +1830,245 @@ fn explain_layout(
         bounds: layout.bounds(),
         background: Background::Color([0.0, 0.0, 0.0, 0.05].into()),
         border_radius: 0,
+        shadow: None,
     });
 
     for child in layout.children() {
         explain_layout(child, color, primitives);
     }
 }
+
+// Past this many regions, drawing the layers again for each of them costs
+// more than drawing their union once
+const MAX_DAMAGED_REGIONS: usize = 8;
+
+/// Converts damaged regions to physical pixels inside of the viewport, and
+/// adds the regions of animated images drawn in the last frame.
+fn damaged_regions(
+    damage: &[Rectangle],
+    animated: Vec<Rectangle<u32>>,
+    dpi: f32,
+    (width, height): (u16, u16),
+) -> Vec<Rectangle<u32>> {
+    let mut regions: Vec<Rectangle<u32>> = damage
+        .iter()
+        .filter_map(|region| {
+            // Antialiased edges can bleed into the surrounding pixels
+            let left = ((region.x * dpi).floor() - 1.0).max(0.0);
+            let top = ((region.y * dpi).floor() - 1.0).max(0.0);
+            let right = (((region.x + region.width) * dpi).ceil() + 1.0)
+                .min(f32::from(width));
+            let bottom = (((region.y + region.height) * dpi).ceil() + 1.0)
+                .min(f32::from(height));
+
+            if right > left && bottom > top {
+                Some(Rectangle {
+                    x: left as u32,
+                    y: top as u32,
+                    width: (right - left) as u32,
+                    height: (bottom - top) as u32,
+                })
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    regions.extend(animated);
+
+    if regions.len() > MAX_DAMAGED_REGIONS {
+        let left = regions.iter().map(|region| region.x).min().unwrap_or(0);
+        let top = regions.iter().map(|region| region.y).min().unwrap_or(0);
+        let right = regions
+            .iter()
+            .map(|region| region.x + region.width)
+            .max()
+            .unwrap_or(0);
+        let bottom = regions
+            .iter()
+            .map(|region| region.y + region.height)
+            .max()
+            .unwrap_or(0);
+
+        vec![Rectangle {
+            x: left,
+            y: top,
+            width: right - left,
+            height: bottom - top,
+        }]
+    } else {
+        regions
+    }
+}
+
+fn intersection(
+    a: Rectangle<u32>,
+    b: Rectangle<u32>,
+) -> Option<Rectangle<u32>> {
+    let x = a.x.max(b.x);
+    let y = a.y.max(b.y);
+    let right = (a.x + a.width).min(b.x + b.width);
+    let bottom = (a.y + a.height).min(b.y + b.height);
+
+    if right > x && bottom > y {
+        Some(Rectangle {
+            x,
+            y,
+            width: right - x,
+            height: bottom - y,
+        })
+    } else {
+        None
+    }
+}
+
+/// Drops empty layers and merges consecutive ones that can be drawn
+/// together, reducing the amount of render passes.
+///
+/// Every clip, for instance, pushes an additional layer to continue drawing
+/// after it. Most of these layers end up empty or compatible with the next
+/// one.
+fn merge(layers: Vec<Layer<'_>>) -> Vec<Layer<'_>> {
+    let mut merged: Vec<Layer<'_>> = Vec::with_capacity(layers.len());
+
+    for layer in layers {
+        if layer.is_empty() {
+            continue;
+        }
+
+        match merged.last_mut() {
+            Some(last) if last.can_merge(&layer) => last.merge(layer),
+            _ => merged.push(layer),
+        }
+    }
+
+    merged
+}
+
+/// Fits the texture of an image in its bounds, keeping its aspect ratio.
+fn apply_fit(
+    instance: &mut Image,
+    bounds: Rectangle,
+    (width, height): (u32, u32),
+    fit: Fit,
+) {
+    let (width, height) = (width as f32, height as f32);
+
+    let scale_x = bounds.width / width;
+    let scale_y = bounds.height / height;
+
+    match fit {
+        Fit::Fill => {}
+        Fit::Contain => {
+            let scale = scale_x.min(scale_y);
+            let size = [width * scale, height * scale];
+
+            instance.position = [
+                bounds.x + (bounds.width - size[0]) / 2.0,
+                bounds.y + (bounds.height - size[1]) / 2.0,
+            ];
+            instance.scale = size;
+        }
+        Fit::Cover => {
+            let scale = scale_x.max(scale_y);
+
+            // The visible region of the image, centered
+            let region = [scale_x / scale, scale_y / scale];
+
+            instance.texture_position =
+                [(1.0 - region[0]) / 2.0, (1.0 - region[1]) / 2.0];
+            instance.texture_scale = region;
+        }
+        Fit::Tile => {
+            instance.tiles = [scale_x, scale_y];
+        }
+    }
+}
+
+pub(crate) const TRANSPARENT: wgpu::Color = wgpu::Color {
+    r: 0.0,
+    g: 0.0,
+    b: 0.0,
+    a: 0.0,
+};
+
+/// Returns the screen position and the layout of some text aligned inside of
+/// the given bounds.
+fn align_text(
+    bounds: Rectangle,
+    horizontal_alignment: iced_native::HorizontalAlignment,
+    vertical_alignment: iced_native::VerticalAlignment,
+) -> (
+    (f32, f32),
+    wgpu_glyph::Layout<wgpu_glyph::BuiltInLineBreaker>,
+) {
+    let (x, h_align) = match horizontal_alignment {
+        iced_native::HorizontalAlignment::Left => {
+            (bounds.x, wgpu_glyph::HorizontalAlign::Left)
+        }
+        iced_native::HorizontalAlignment::Center => (
+            bounds.x + bounds.width / 2.0,
+            wgpu_glyph::HorizontalAlign::Center,
+        ),
+        iced_native::HorizontalAlignment::Right => {
+            (bounds.x + bounds.width, wgpu_glyph::HorizontalAlign::Right)
+        }
+    };
+
+    let (y, v_align) = match vertical_alignment {
+        iced_native::VerticalAlignment::Top => {
+            (bounds.y, wgpu_glyph::VerticalAlign::Top)
+        }
+        iced_native::VerticalAlignment::Center => (
+            bounds.y + bounds.height / 2.0,
+            wgpu_glyph::VerticalAlign::Center,
+        ),
+        iced_native::VerticalAlignment::Bottom => {
+            (bounds.y + bounds.height, wgpu_glyph::VerticalAlign::Bottom)
+        }
+    };
+
+    (
+        (x, y),
+        wgpu_glyph::Layout::default()
+            .h_align(h_align)
+            .v_align(v_align),
+    )
+}
+
+fn fade([r, g, b, a]: [f32; 4], opacity: f32) -> [f32; 4] {
+    [r, g, b, a * opacity]
+}
+
+/// Splits one axis of a nine-patch into its three segments, returning the
+/// start and length of each segment in the image and in the target.
+///
+/// The corners keep their size in the image, unless the target is too small
+/// to fit both of them. Then, they shrink proportionally.
+fn nine_patch_cuts(
+    image: f32,
+    target: f32,
+    start: u16,
+    end: u16,
+) -> [((f32, f32), (f32, f32)); 3] {
+    let start = f32::from(start).min(image);
+    let end = f32::from(end).min(image - start);
+
+    let shrink = if start + end > target {
+        target / (start + end)
+    } else {
+        1.0
+    };
+
+    let target_start = start * shrink;
+    let target_end = end * shrink;
+
+    [
+        ((0.0, start), (0.0, target_start)),
+        (
+            (start, image - start - end),
+            (target_start, target - target_start - target_end),
+        ),
+        ((image - end, end), (target - target_end, target_end)),
+    ]
+}
@@ -0,0 +1,147 @@
+use crate::antialiasing::{self, Blit};
+use iced_native::Rectangle;
+
+// The last frame drawn into a window. Swap chain images do not keep their
+// contents, so the frame is kept here and copied into the swap chain, and
+// only its damaged regions need to be drawn again.
+#[derive(Debug)]
+pub struct Backbuffer {
+    format: wgpu::TextureFormat,
+    blit: Blit,
+    frame: Option<Frame>,
+}
+
+#[derive(Debug)]
+struct Frame {
+    width: u16,
+    height: u16,
+    _texture: wgpu::Texture,
+    view: wgpu::TextureView,
+    bind_group: wgpu::BindGroup,
+    _fill: wgpu::Texture,
+    _fill_view: wgpu::TextureView,
+    fill_bind_group: wgpu::BindGroup,
+}
+
+impl Backbuffer {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+        let blit = Blit::new(
+            device,
+            format,
+            include_bytes!("../shader/blit.frag.spv"),
+            wgpu::BlendDescriptor {
+                src_factor: wgpu::BlendFactor::One,
+                dst_factor: wgpu::BlendFactor::Zero,
+                operation: wgpu::BlendOperation::Add,
+            },
+        );
+
+        Backbuffer {
+            format,
+            blit,
+            frame: None,
+        }
+    }
+
+    /// Makes sure the frame has the given size, and returns whether it was
+    /// created again, losing its contents.
+    ///
+    /// The damaged regions of the frame are cleared with the given color.
+    pub fn resize(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        width: u16,
+        height: u16,
+        clear_color: wgpu::Color,
+    ) -> bool {
+        let is_outdated = match &self.frame {
+            Some(frame) => frame.width != width || frame.height != height,
+            None => true,
+        };
+
+        if is_outdated {
+            let texture =
+                antialiasing::texture(device, self.format, width, height, 1);
+            let view = texture.create_default_view();
+            let bind_group = self.blit.bind(device, &view);
+
+            // A single pixel of the clear color, stretched over the regions
+            // to clear
+            let fill = antialiasing::texture(device, self.format, 1, 1, 1);
+            let fill_view = fill.create_default_view();
+            let fill_bind_group = self.blit.bind(device, &fill_view);
+
+            let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                color_attachments: &[
+                    wgpu::RenderPassColorAttachmentDescriptor {
+                        attachment: &fill_view,
+                        resolve_target: None,
+                        load_op: wgpu::LoadOp::Clear,
+                        store_op: wgpu::StoreOp::Store,
+                        clear_color,
+                    },
+                ],
+                depth_stencil_attachment: None,
+            });
+
+            self.frame = Some(Frame {
+                width,
+                height,
+                _texture: texture,
+                view,
+                bind_group,
+                _fill: fill,
+                _fill_view: fill_view,
+                fill_bind_group,
+            });
+        }
+
+        is_outdated
+    }
+
+    /// Returns the frame to draw into.
+    pub fn view(&self) -> Option<&wgpu::TextureView> {
+        self.frame.as_ref().map(|frame| &frame.view)
+    }
+
+    /// Fills the given region of the frame with the clear color, replacing
+    /// what was drawn there.
+    pub fn clear(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        region: Rectangle<u32>,
+    ) {
+        if let Some(frame) = &self.frame {
+            self.blit.draw(
+                encoder,
+                &frame.fill_bind_group,
+                &frame.view,
+                wgpu::LoadOp::Load,
+                region,
+            );
+        }
+    }
+
+    /// Copies the whole frame into the target, replacing its contents.
+    pub fn present(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+    ) {
+        if let Some(frame) = &self.frame {
+            self.blit.draw(
+                encoder,
+                &frame.bind_group,
+                target,
+                wgpu::LoadOp::Clear,
+                Rectangle {
+                    x: 0,
+                    y: 0,
+                    width: u32::from(frame.width),
+                    height: u32::from(frame.height),
+                },
+            );
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use iced_native::Color;
+
+use std::{cell::RefCell, rc::Rc};
+
+/// A texture that primitives were drawn into, instead of a window.
+///
+/// The texture can be sampled, used as a render attachment, or copied to a
+/// buffer using the [`Device`] of the [`Renderer`] that produced it.
+///
+/// [`Device`]: struct.Renderer.html#method.device
+/// [`Renderer`]: struct.Renderer.html
+#[derive(Debug)]
+pub struct Offscreen {
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+    format: wgpu::TextureFormat,
+    width: u16,
+    height: u16,
+}
+
+impl Offscreen {
+    pub(crate) fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        width: u16,
+        height: u16,
+    ) -> Self {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            size: wgpu::Extent3d {
+                width: u32::from(width),
+                height: u32::from(height),
+                depth: 1,
+            },
+            array_layer_count: 1,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsage::OUTPUT_ATTACHMENT
+                | wgpu::TextureUsage::SAMPLED
+                | wgpu::TextureUsage::COPY_SRC,
+        });
+
+        let view = texture.create_default_view();
+
+        Offscreen {
+            texture,
+            view,
+            format,
+            width,
+            height,
+        }
+    }
+
+    /// Returns the texture of the [`Offscreen`] target.
+    ///
+    /// Its format is the one of the [`ColorSpace`] of the [`Renderer`] that
+    /// drew it.
+    ///
+    /// [`ColorSpace`]: enum.ColorSpace.html
+    /// [`Renderer`]: struct.Renderer.html
+    ///
+    /// [`Offscreen`]: struct.Offscreen.html
+    pub fn texture(&self) -> &wgpu::Texture {
+        &self.texture
+    }
+
+    /// Returns a view of the whole texture of the [`Offscreen`] target.
+    ///
+    /// [`Offscreen`]: struct.Offscreen.html
+    pub fn view(&self) -> &wgpu::TextureView {
+        &self.view
+    }
+
+    /// Returns the dimensions of the [`Offscreen`] target, in physical
+    /// pixels.
+    ///
+    /// [`Offscreen`]: struct.Offscreen.html
+    pub fn dimensions(&self) -> (u16, u16) {
+        (self.width, self.height)
+    }
+
+    /// Copies the texture to memory, as RGBA pixels from top to bottom.
+    pub(crate) fn read(
+        &self,
+        device: &wgpu::Device,
+        queue: &mut wgpu::Queue,
+    ) -> Vec<u8> {
+        let width = u32::from(self.width);
+        let height = u32::from(self.height);
+
+        let bytes_per_pixel = match self.format {
+            wgpu::TextureFormat::Rgba16Float => 8,
+            _ => 4,
+        };
+
+        // Rows of a buffer copy must be aligned to 256 bytes
+        let row_size = bytes_per_pixel * width;
+        let row_pitch = (row_size + 255) / 256 * 256;
+        let size = u64::from(row_pitch * height);
+
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            size,
+            usage: wgpu::BufferUsage::MAP_READ | wgpu::BufferUsage::COPY_DST,
+        });
+
+        let mut encoder =
+            device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                todo: 0,
+            });
+
+        encoder.copy_texture_to_buffer(
+            wgpu::TextureCopyView {
+                texture: &self.texture,
+                array_layer: 0,
+                mip_level: 0,
+                origin: wgpu::Origin3d {
+                    x: 0.0,
+                    y: 0.0,
+                    z: 0.0,
+                },
+            },
+            wgpu::BufferCopyView {
+                buffer: &buffer,
+                offset: 0,
+                row_pitch,
+                image_height: height,
+            },
+            wgpu::Extent3d {
+                width,
+                height,
+                depth: 1,
+            },
+        );
+
+        queue.submit(&[encoder.finish()]);
+
+        let pixels = Rc::new(RefCell::new(Vec::new()));
+        let result = pixels.clone();
+        let format = self.format;
+
+        buffer.map_read_async(
+            0,
+            size,
+            move |mapping: wgpu::BufferMapAsyncResult<&[u8]>| {
+                if let Ok(mapping) = mapping {
+                    let mut pixels = result.borrow_mut();
+
+                    pixels.reserve((4 * width * height) as usize);
+
+                    for row in mapping.data.chunks(row_pitch as usize) {
+                        let row = &row[..row_size as usize];
+
+                        match format {
+                            wgpu::TextureFormat::Rgba16Float => {
+                                for rgba in row.chunks(8) {
+                                    pixels
+                                        .extend_from_slice(&encode_half(rgba));
+                                }
+                            }
+                            _ => {
+                                for bgra in row.chunks(4) {
+                                    pixels.extend_from_slice(&[
+                                        bgra[2], bgra[1], bgra[0], bgra[3],
+                                    ]);
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+        );
+
+        // Wait for the copy and the mapping to finish
+        device.poll(true);
+
+        pixels.replace(Vec::new())
+    }
+}
+
+/// Encodes a pixel of extended linear sRGB, made of four little-endian
+/// half-precision floats, into RGBA8 sRGB.
+///
+/// Components outside of the sRGB gamut are clamped.
+fn encode_half(rgba: &[u8]) -> [u8; 4] {
+    let component =
+        |i: usize| decode_half(u16::from_le_bytes([rgba[i], rgba[i + 1]]));
+
+    let color = Color::from_linear([
+        component(0).max(0.0).min(1.0),
+        component(2).max(0.0).min(1.0),
+        component(4).max(0.0).min(1.0),
+        component(6).max(0.0).min(1.0),
+    ]);
+
+    let byte = |u: f32| (u * 255.0).round() as u8;
+
+    [byte(color.r), byte(color.g), byte(color.b), byte(color.a)]
+}
+
+fn decode_half(bits: u16) -> f32 {
+    let sign = if bits & 0x8000 == 0 { 1.0 } else { -1.0 };
+    let exponent = i32::from((bits >> 10) & 0x1f);
+    let mantissa = f32::from(bits & 0x3ff);
+
+    sign * match exponent {
+        0 => mantissa * 2f32.powi(-24),
+        0x1f if mantissa == 0.0 => f32::INFINITY,
+        0x1f => f32::NAN,
+        _ => (1.0 + mantissa / 1024.0) * 2f32.powi(exponent - 15),
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Renderer, Transformation};
+use crate::Renderer;
 
 use raw_window_handle::HasRawWindowHandle;
 
@@ -9,7 +9,7 @@ pub struct Target {
     width: u16,
     height: u16,
     dpi: f32,
-    transformation: Transformation,
+    format: wgpu::TextureFormat,
     swap_chain: wgpu::SwapChain,
 }
 
@@ -22,11 +22,24 @@ impl Target {
         self.dpi
     }
 
-    pub(crate) fn transformation(&self) -> Transformation {
-        self.transformation
-    }
+    /// Returns the next frame of the swap chain, recreating it first if its
+    /// format changed.
+    pub(crate) fn next_frame(
+        &mut self,
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+    ) -> wgpu::SwapChainOutput<'_> {
+        if self.format != format {
+            self.format = format;
+            self.swap_chain = new_swap_chain(
+                &self.surface,
+                format,
+                self.width,
+                self.height,
+                device,
+            );
+        }
 
-    pub(crate) fn next_frame(&mut self) -> wgpu::SwapChainOutput<'_> {
         self.swap_chain.get_next_texture()
     }
 }
@@ -42,15 +55,16 @@ impl iced_native::renderer::Target for Target {
         renderer: &Renderer,
     ) -> Target {
         let surface = wgpu::Surface::create(window);
+        let format = renderer.color_space.format();
         let swap_chain =
-            new_swap_chain(&surface, width, height, &renderer.device);
+            new_swap_chain(&surface, format, width, height, &renderer.device);
 
         Target {
             surface,
             width,
             height,
             dpi,
-            transformation: Transformation::orthographic(width, height),
+            format,
             swap_chain,
         }
     }
@@ -65,14 +79,20 @@ impl iced_native::renderer::Target for Target {
         self.width = width;
         self.height = height;
         self.dpi = dpi;
-        self.transformation = Transformation::orthographic(width, height);
-        self.swap_chain =
-            new_swap_chain(&self.surface, width, height, &renderer.device);
+        self.format = renderer.color_space.format();
+        self.swap_chain = new_swap_chain(
+            &self.surface,
+            self.format,
+            width,
+            height,
+            &renderer.device,
+        );
     }
 }
 
 fn new_swap_chain(
     surface: &wgpu::Surface,
+    format: wgpu::TextureFormat,
     width: u16,
     height: u16,
     device: &wgpu::Device,
@@ -81,7 +101,7 @@ fn new_swap_chain(
         &surface,
         &wgpu::SwapChainDescriptor {
             usage: wgpu::TextureUsage::OUTPUT_ATTACHMENT,
-            format: wgpu::TextureFormat::Bgra8UnormSrgb,
+            format,
             width: u32::from(width),
             height: u32::from(height),
             present_mode: wgpu::PresentMode::Vsync,
@@ -1,14 +1,67 @@
+mod avatar;
+mod badge;
 mod button;
+mod cache_stats;
+mod cached;
 mod checkbox;
 mod column;
+mod combo_box;
+mod date_picker;
+mod dialogs;
+mod draggable;
 mod image;
+mod knob;
+mod lazy_column;
+mod link;
+mod menu_bar;
+mod minimap;
+mod modal;
+mod mouse_area;
+mod navigation;
+mod number_input;
+mod progress_bar;
 mod radio;
+mod rating;
+mod reveal;
+mod rich_text;
 mod row;
 mod scrollable;
+mod segmented_button;
+mod selectable_text;
 mod slider;
+mod snap;
 mod space;
+mod spinner;
+mod table;
+mod tabs;
 mod text;
+mod text_editor;
 mod text_input;
+mod time_picker;
+mod timeline;
+mod toasts;
+mod xy_pad;
 
 #[cfg(feature = "svg")]
 mod svg;
+
+#[cfg(feature = "qr_code")]
+mod qr_code;
+
+use crate::Primitive;
+use iced_native::{Rectangle, Vector};
+
+/// Draws some content on top of everything drawn before it, including text,
+/// clipped to the given bounds.
+///
+/// A layer draws all of its quads before its text, so a quad never covers
+/// the text of its own layer. Clipping starts a new layer, drawn after the
+/// current one. Use this for content floating over the rest of a widget, like
+/// a badge. Content floating over other widgets should be an overlay instead.
+fn on_top(bounds: Rectangle, content: Primitive) -> Primitive {
+    Primitive::Clip {
+        bounds,
+        offset: Vector::new(0, 0),
+        content: Box::new(content),
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{Primitive, Renderer};
+use iced_native::{
+    avatar, image, text, Background, Color, Font, HorizontalAlignment,
+    MouseCursor, Rectangle, VerticalAlignment,
+};
+
+impl avatar::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        image: Option<image::Handle>,
+        initials: &str,
+        background: Color,
+        foreground: Color,
+    ) -> Self::Output {
+        let primitive = match image {
+            Some(handle) => Primitive::Image {
+                handle,
+                bounds,
+                border_radius: (bounds.width / 2.0) as u16,
+                fit: image::Fit::Cover,
+            },
+            None => Primitive::Group {
+                primitives: vec![
+                    Primitive::Quad {
+                        bounds,
+                        background: Background::Color(background),
+                        border_radius: (bounds.width / 2.0) as u16,
+                        shadow: None,
+                    },
+                    Primitive::Text {
+                        content: initials.to_string(),
+                        bounds,
+                        color: foreground,
+                        size: bounds.height * 0.4,
+                        font: Font::Default,
+                        params: text::Params::default(),
+                        horizontal_alignment: HorizontalAlignment::Center,
+                        vertical_alignment: VerticalAlignment::Center,
+                    },
+                ],
+            },
+        };
+
+        (primitive, MouseCursor::OutOfBounds)
+    }
+}
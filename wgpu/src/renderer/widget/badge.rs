@@ -0,0 +1,46 @@
+use super::on_top;
+use crate::{Primitive, Renderer};
+use iced_native::{
+    badge, text, Background, Color, Font, HorizontalAlignment, Rectangle,
+    VerticalAlignment,
+};
+
+impl badge::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        (content, mouse_cursor): Self::Output,
+        bounds: Rectangle,
+        label: &str,
+        text_size: u16,
+        background: Color,
+        foreground: Color,
+    ) -> Self::Output {
+        let bubble = Primitive::Group {
+            primitives: vec![
+                Primitive::Quad {
+                    bounds,
+                    background: Background::Color(background),
+                    border_radius: (bounds.height / 2.0) as u16,
+                    shadow: None,
+                },
+                Primitive::Text {
+                    content: label.to_string(),
+                    bounds,
+                    color: foreground,
+                    size: f32::from(text_size),
+                    font: Font::Default,
+                    params: text::Params::default(),
+                    horizontal_alignment: HorizontalAlignment::Center,
+                    vertical_alignment: VerticalAlignment::Center,
+                },
+            ],
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![content, on_top(bounds, bubble)],
+            },
+            mouse_cursor,
+        )
+    }
+}
@@ -1,19 +1,16 @@
 use crate::{Primitive, Renderer};
-use iced_native::{button, Background, MouseCursor, Point, Rectangle};
+use iced_native::{button, Background, MouseCursor, Rectangle, Shadow, Vector};
 
 impl button::Renderer for Renderer {
     fn draw(
         &mut self,
         bounds: Rectangle,
-        cursor_position: Point,
+        is_mouse_over: bool,
         is_pressed: bool,
         background: Option<Background>,
         border_radius: u16,
         (content, _): Self::Output,
     ) -> Self::Output {
-        let is_mouse_over = bounds.contains(cursor_position);
-
-        // TODO: Render proper shadows
         // TODO: Make hovering and pressed styles configurable
         let shadow_offset = if is_mouse_over {
             if is_pressed {
@@ -30,21 +27,17 @@ impl button::Renderer for Renderer {
                 None => content,
                 Some(background) => Primitive::Group {
                     primitives: vec![
-                        Primitive::Quad {
-                            bounds: Rectangle {
-                                x: bounds.x + 1.0,
-                                y: bounds.y + shadow_offset,
-                                ..bounds
-                            },
-                            background: Background::Color(
-                                [0.0, 0.0, 0.0, 0.5].into(),
-                            ),
-                            border_radius,
-                        },
                         Primitive::Quad {
                             bounds,
                             background,
                             border_radius,
+                            shadow: Some(
+                                Shadow::new(
+                                    [0.0, 0.0, 0.0, 0.5].into(),
+                                    Vector::new(1.0, shadow_offset),
+                                )
+                                .blur_radius(shadow_offset),
+                            ),
                         },
                         content,
                     ],
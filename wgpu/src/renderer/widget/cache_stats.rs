@@ -0,0 +1,163 @@
+use crate::{Primitive, Renderer};
+use iced_native::{
+    cache_stats, image::Fit, text, Background, Color, Font,
+    HorizontalAlignment, MouseCursor, Rectangle, Vector, VerticalAlignment,
+};
+use wgpu::CommandEncoderDescriptor;
+
+const TEXT_SIZE: f32 = 14.0;
+const PADDING: f32 = 8.0;
+const OUTLINE: f32 = 1.0;
+
+impl cache_stats::Renderer for Renderer {
+    fn stats(&self) -> cache_stats::Stats {
+        let mut stats = cache_stats::Stats::default();
+
+        self.text_pipeline.collect_stats(&mut stats);
+        self.image_pipeline.collect_stats(&mut stats);
+
+        stats
+    }
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        stats: &cache_stats::Stats,
+    ) -> Self::Output {
+        let background = Primitive::Quad {
+            bounds,
+            background: Background::Color([0.15, 0.15, 0.15].into()),
+            border_radius: 4,
+            shadow: None,
+        };
+
+        // The glyph page is drawn to scale, as big as the height allows
+        let (page_width, page_height) = stats.glyph_page;
+        let page_size = bounds.height - 2.0 * PADDING;
+        let page_scale = page_size / page_width.max(page_height).max(1) as f32;
+
+        let page_bounds = Rectangle {
+            x: bounds.x + PADDING,
+            y: bounds.y + PADDING,
+            width: page_width as f32 * page_scale,
+            height: page_height as f32 * page_scale,
+        };
+
+        // The glyph cache is only copied once it is inspected, so the atlas
+        // is enabled the first time the view is drawn, while both are empty
+        if !self.is_atlas_inspected {
+            self.is_atlas_inspected = true;
+            self.rebuild_text_pipeline();
+        }
+
+        let mut primitives = vec![background];
+        let mut packed = 0;
+
+        if let Some(mut atlas) = self.text_pipeline.atlas() {
+            let mut encoder = self
+                .device
+                .create_command_encoder(&CommandEncoderDescriptor { todo: 0 });
+
+            let handle = atlas.texture(&self.device, &mut encoder);
+
+            self.queue.submit(&[encoder.finish()]);
+
+            primitives.push(Primitive::Texture {
+                handle,
+                bounds: page_bounds,
+                border_radius: 0,
+                fit: Fit::Fill,
+            });
+
+            let outlines = atlas.allocations().iter().flat_map(|allocation| {
+                outline(Rectangle {
+                    x: page_bounds.x + allocation.x as f32 * page_scale,
+                    y: page_bounds.y + allocation.y as f32 * page_scale,
+                    width: allocation.width as f32 * page_scale,
+                    height: allocation.height as f32 * page_scale,
+                })
+            });
+
+            // Quads are drawn below the images of their layer, so the
+            // outlines need a layer of their own
+            primitives.push(Primitive::Clip {
+                bounds: page_bounds,
+                offset: Vector::new(0, 0),
+                content: Box::new(Primitive::Group {
+                    primitives: outlines.collect(),
+                }),
+            });
+
+            packed = atlas.allocations().len();
+        }
+
+        let lines = [
+            format!("Glyphs: {}x{}", page_width, page_height),
+            format!("Resizes: {}", stats.glyph_page_resizes),
+            format!("Packed: {} glyphs", packed),
+            format!(
+                "Images: {} ({} uploaded)",
+                stats.images, stats.uploaded_images
+            ),
+            format!("Memory: {} KiB", stats.image_bytes / 1024),
+            format!("Evicted: {}", stats.evicted_images),
+        ];
+
+        let text_x = bounds.x + page_size + 2.0 * PADDING;
+
+        let text = lines.iter().enumerate().map(|(i, line)| Primitive::Text {
+            content: line.clone(),
+            bounds: Rectangle {
+                x: text_x,
+                y: bounds.y + PADDING + i as f32 * TEXT_SIZE * 1.2,
+                width: (bounds.x + bounds.width - text_x - PADDING).max(0.0),
+                height: TEXT_SIZE,
+            },
+            color: Color::WHITE,
+            size: TEXT_SIZE,
+            font: Font::Default,
+            params: text::Params::default(),
+            horizontal_alignment: HorizontalAlignment::Left,
+            vertical_alignment: VerticalAlignment::Top,
+        });
+
+        primitives.extend(text);
+
+        (Primitive::Group { primitives }, MouseCursor::OutOfBounds)
+    }
+}
+
+// Outlines the given bounds with thin quads, as quads have no border
+fn outline(bounds: Rectangle) -> Vec<Primitive> {
+    let edges = [
+        (bounds.x, bounds.y, bounds.width, OUTLINE),
+        (
+            bounds.x,
+            bounds.y + bounds.height - OUTLINE,
+            bounds.width,
+            OUTLINE,
+        ),
+        (bounds.x, bounds.y, OUTLINE, bounds.height),
+        (
+            bounds.x + bounds.width - OUTLINE,
+            bounds.y,
+            OUTLINE,
+            bounds.height,
+        ),
+    ];
+
+    edges
+        .iter()
+        .map(|&(x, y, width, height)| Primitive::Quad {
+            bounds: Rectangle {
+                x,
+                y,
+                width,
+                height,
+            },
+            background: Background::Color([0.3, 0.5, 0.8].into()),
+            border_radius: 0,
+            shadow: None,
+        })
+        .collect()
+}
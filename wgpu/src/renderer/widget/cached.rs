@@ -0,0 +1,20 @@
+use crate::{Primitive, Renderer};
+use iced_native::cached;
+
+use std::sync::Arc;
+
+impl cached::Renderer for Renderer {
+    fn cache(
+        &mut self,
+        (primitive, mouse_cursor): Self::Output,
+    ) -> Self::Output {
+        let primitive = match primitive {
+            Primitive::Cached { cache } => Primitive::Cached { cache },
+            primitive => Primitive::Cached {
+                cache: Arc::new(primitive),
+            },
+        };
+
+        (primitive, mouse_cursor)
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{Primitive, Renderer};
 use iced_native::{
-    checkbox, Background, HorizontalAlignment, MouseCursor, Rectangle,
+    checkbox, text, Background, HorizontalAlignment, MouseCursor, Rectangle,
     VerticalAlignment,
 };
 
@@ -23,6 +23,7 @@ impl checkbox::Renderer for Renderer {
                 bounds,
                 background: Background::Color([0.6, 0.6, 0.6].into()),
                 border_radius: 6,
+                shadow: None,
             },
             Primitive::Quad {
                 bounds: Rectangle {
@@ -40,6 +41,7 @@ impl checkbox::Renderer for Renderer {
                     .into(),
                 ),
                 border_radius: 5,
+                shadow: None,
             },
         );
 
@@ -49,6 +51,7 @@ impl checkbox::Renderer for Renderer {
                     let check = Primitive::Text {
                         content: crate::text::CHECKMARK_ICON.to_string(),
                         font: crate::text::BUILTIN_ICONS,
+                        params: text::Params::default(),
                         size: bounds.height * 0.7,
                         bounds: bounds,
                         color: [0.3, 0.3, 0.3].into(),
@@ -0,0 +1,76 @@
+use crate::{Primitive, Renderer};
+use iced_native::{
+    combo_box, text, Background, Color, Font, HorizontalAlignment, MouseCursor,
+    Point, Rectangle, VerticalAlignment,
+};
+
+const PADDING: f32 = 5.0;
+
+impl combo_box::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        menu: &combo_box::Menu,
+    ) -> Self::Output {
+        let mut primitives = vec![
+            Primitive::Quad {
+                bounds: menu.bounds,
+                background: Background::Color([0.7, 0.7, 0.7].into()),
+                border_radius: 0,
+                shadow: None,
+            },
+            Primitive::Quad {
+                bounds: Rectangle {
+                    x: menu.bounds.x + 1.0,
+                    y: menu.bounds.y + 1.0,
+                    width: menu.bounds.width - 2.0,
+                    height: menu.bounds.height - 2.0,
+                },
+                background: Background::Color(Color::WHITE),
+                border_radius: 0,
+                shadow: None,
+            },
+        ];
+
+        for (i, option) in menu.options.iter().enumerate() {
+            let bounds = menu.item_bounds(i);
+
+            if menu.highlighted == Some(i) {
+                primitives.push(Primitive::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + 1.0,
+                        width: bounds.width - 2.0,
+                        ..bounds
+                    },
+                    background: Background::Color([0.85, 0.91, 0.98].into()),
+                    border_radius: 0,
+                    shadow: None,
+                });
+            }
+
+            primitives.push(Primitive::Text {
+                content: option.clone(),
+                bounds: Rectangle {
+                    x: bounds.x + PADDING,
+                    width: (bounds.width - 2.0 * PADDING).max(0.0),
+                    ..bounds
+                },
+                color: [0.3, 0.3, 0.3].into(),
+                size: f32::from(menu.text_size),
+                font: Font::Default,
+                params: text::Params::default(),
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+        }
+
+        (
+            Primitive::Group { primitives },
+            if menu.bounds.contains(cursor_position) {
+                MouseCursor::Pointer
+            } else {
+                MouseCursor::OutOfBounds
+            },
+        )
+    }
+}
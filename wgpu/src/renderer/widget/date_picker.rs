@@ -0,0 +1,168 @@
+use crate::{Primitive, Renderer};
+use iced_native::{
+    date_picker, text, Background, Color, Font, HorizontalAlignment,
+    MouseCursor, Point, Rectangle, VerticalAlignment,
+};
+
+const ACCENT: Color = Color {
+    r: 0.2,
+    g: 0.5,
+    b: 0.9,
+    a: 1.0,
+};
+
+impl date_picker::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        calendar: &date_picker::Calendar<'_>,
+        text_size: u16,
+    ) -> Self::Output {
+        let size = f32::from(text_size);
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let mut mouse_cursor = if is_mouse_over {
+            MouseCursor::Idle
+        } else {
+            MouseCursor::OutOfBounds
+        };
+
+        let mut primitives = vec![
+            Primitive::Quad {
+                bounds,
+                background: Background::Color(
+                    if calendar.is_focused {
+                        [0.5, 0.5, 0.5]
+                    } else {
+                        [0.7, 0.7, 0.7]
+                    }
+                    .into(),
+                ),
+                border_radius: 5,
+                shadow: None,
+            },
+            Primitive::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + 1.0,
+                    y: bounds.y + 1.0,
+                    width: bounds.width - 2.0,
+                    height: bounds.height - 2.0,
+                },
+                background: Background::Color(Color::WHITE),
+                border_radius: 4,
+                shadow: None,
+            },
+            centered(
+                calendar.title.clone(),
+                calendar.title_bounds,
+                [0.2, 0.2, 0.2].into(),
+                size,
+            ),
+        ];
+
+        let arrows = calendar
+            .previous
+            .map(|bounds| (bounds, "\u{25C2}"))
+            .into_iter()
+            .chain(calendar.next.map(|bounds| (bounds, "\u{25B8}")));
+
+        for (arrow, content) in arrows {
+            let is_hovered = arrow.contains(cursor_position);
+
+            if is_hovered {
+                mouse_cursor = MouseCursor::Pointer;
+            }
+
+            primitives.push(centered(
+                content.to_owned(),
+                arrow,
+                if is_hovered {
+                    ACCENT
+                } else {
+                    [0.4, 0.4, 0.4].into()
+                },
+                size,
+            ));
+        }
+
+        for (weekday, bounds) in &calendar.weekdays {
+            primitives.push(centered(
+                weekday.to_string(),
+                *bounds,
+                [0.5, 0.5, 0.5].into(),
+                size * 0.8,
+            ));
+        }
+
+        for day in &calendar.days {
+            let is_hovered =
+                day.is_enabled && day.bounds.contains(cursor_position);
+
+            if is_hovered {
+                mouse_cursor = MouseCursor::Pointer;
+            }
+
+            let background = if day.is_selected {
+                Some(ACCENT)
+            } else if day.is_highlighted {
+                Some([0.85, 0.91, 0.98].into())
+            } else if is_hovered {
+                Some([0.93, 0.93, 0.93].into())
+            } else {
+                None
+            };
+
+            if let Some(background) = background {
+                primitives.push(Primitive::Quad {
+                    bounds: Rectangle {
+                        x: day.bounds.x + 2.0,
+                        y: day.bounds.y + 2.0,
+                        width: day.bounds.width - 4.0,
+                        height: day.bounds.height - 4.0,
+                    },
+                    background: Background::Color(background),
+                    border_radius: (day.bounds.width / 2.0 - 2.0) as u16,
+                    shadow: None,
+                });
+            }
+
+            let color: Color = if day.is_selected {
+                Color::WHITE
+            } else if !day.is_enabled {
+                [0.8, 0.8, 0.8].into()
+            } else if day.is_outside {
+                [0.6, 0.6, 0.6].into()
+            } else {
+                [0.2, 0.2, 0.2].into()
+            };
+
+            primitives.push(centered(
+                day.date.day.to_string(),
+                day.bounds,
+                color,
+                size,
+            ));
+        }
+
+        (Primitive::Group { primitives }, mouse_cursor)
+    }
+}
+
+fn centered(
+    content: String,
+    bounds: Rectangle,
+    color: Color,
+    size: f32,
+) -> Primitive {
+    Primitive::Text {
+        content,
+        bounds,
+        color,
+        size,
+        font: Font::Default,
+        params: text::Params::default(),
+        horizontal_alignment: HorizontalAlignment::Center,
+        vertical_alignment: VerticalAlignment::Center,
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{Primitive, Renderer};
+use iced_native::{dialogs, Background, Color, Rectangle, Shadow, Vector};
+
+impl dialogs::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        (content, mouse_cursor): Self::Output,
+    ) -> Self::Output {
+        (
+            Primitive::Group {
+                primitives: vec![
+                    Primitive::Quad {
+                        bounds,
+                        background: Background::Color(Color::WHITE),
+                        border_radius: 8,
+                        shadow: Some(Shadow {
+                            color: Color {
+                                a: 0.3,
+                                ..Color::BLACK
+                            },
+                            offset: Vector::new(0.0, 4.0),
+                            blur_radius: 16.0,
+                        }),
+                    },
+                    content,
+                ],
+            },
+            mouse_cursor,
+        )
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{Primitive, Renderer};
+use iced_native::{draggable, MouseCursor, Point, Rectangle};
+
+impl draggable::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        is_dragging: bool,
+        axis: Option<draggable::Axis>,
+        (content, mouse_cursor): (Primitive, MouseCursor),
+    ) -> Self::Output {
+        let drag_cursor = |is_dragging| match axis {
+            Some(draggable::Axis::Horizontal) => {
+                MouseCursor::ResizingHorizontally
+            }
+            Some(draggable::Axis::Vertical) => MouseCursor::ResizingVertically,
+            None if is_dragging => MouseCursor::Grabbing,
+            None => MouseCursor::Grab,
+        };
+
+        // Interactive content, like a button, keeps its own cursor until the
+        // drag starts
+        let mouse_cursor = if is_dragging {
+            drag_cursor(true)
+        } else if bounds.contains(cursor_position) {
+            match mouse_cursor {
+                MouseCursor::OutOfBounds | MouseCursor::Idle => {
+                    drag_cursor(false)
+                }
+                _ => mouse_cursor,
+            }
+        } else {
+            mouse_cursor
+        };
+
+        (content, mouse_cursor)
+    }
+}
@@ -9,12 +9,15 @@ impl image::Renderer for Renderer {
     fn draw(
         &mut self,
         handle: image::Handle,
+        fit: image::Fit,
         layout: Layout<'_>,
     ) -> Self::Output {
         (
             Primitive::Image {
                 handle,
                 bounds: layout.bounds(),
+                border_radius: 0,
+                fit,
             },
             MouseCursor::OutOfBounds,
         )
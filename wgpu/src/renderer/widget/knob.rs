@@ -0,0 +1,116 @@
+use crate::{Primitive, Renderer};
+use iced_native::{knob, Background, Color, MouseCursor, Point, Rectangle};
+
+use std::f32::consts::PI;
+
+// The knob turns from 7 to 5 o'clock, leaving a gap at the bottom
+const SWEEP: f32 = 1.5 * PI;
+const TICKS: usize = 21;
+const TICK_RADIUS: f32 = 1.5;
+const INDICATOR_RADIUS: f32 = 3.0;
+
+const ACCENT: Color = Color {
+    r: 0.2,
+    g: 0.5,
+    b: 1.0,
+    a: 1.0,
+};
+
+impl knob::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        range: std::ops::RangeInclusive<f32>,
+        value: f32,
+        is_dragging: bool,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let (start, end) = range.into_inner();
+        let percent = if end > start {
+            (value - start) / (end - start)
+        } else {
+            0.0
+        };
+
+        let center = Point::new(
+            bounds.x + bounds.width / 2.0,
+            bounds.y + bounds.height / 2.0,
+        );
+        let radius = bounds.width.min(bounds.height) / 2.0;
+
+        // Angles grow clockwise from the bottom of the knob, because the y
+        // axis points down
+        let point_at = |percent: f32, distance: f32| {
+            let angle = PI / 2.0 + (PI - SWEEP) / 2.0 + percent * SWEEP;
+
+            Point::new(
+                center.x + distance * angle.cos(),
+                center.y + distance * angle.sin(),
+            )
+        };
+
+        let dot =
+            |position: Point, radius: f32, color: Color| Primitive::Quad {
+                bounds: Rectangle {
+                    x: position.x - radius,
+                    y: position.y - radius,
+                    width: radius * 2.0,
+                    height: radius * 2.0,
+                },
+                background: Background::Color(color),
+                border_radius: radius.ceil() as u16,
+                shadow: None,
+            };
+
+        // The track is drawn as ticks around the knob, lit up to the value
+        let ticks = (0..TICKS).map(|i| {
+            let tick = i as f32 / (TICKS - 1) as f32;
+
+            dot(
+                point_at(tick, radius - TICK_RADIUS),
+                TICK_RADIUS,
+                if tick <= percent {
+                    ACCENT
+                } else {
+                    Color::from_rgb(0.8, 0.8, 0.8)
+                },
+            )
+        });
+
+        let body_radius = (radius - TICK_RADIUS * 2.0 - 3.0).max(0.0);
+        let shade = if is_dragging {
+            0.85
+        } else if is_mouse_over {
+            0.90
+        } else {
+            0.95
+        };
+
+        let (body_border, body) = (
+            dot(center, body_radius + 1.0, Color::from_rgb(0.6, 0.6, 0.6)),
+            dot(center, body_radius, Color::from_rgb(shade, shade, shade)),
+        );
+
+        let indicator = dot(
+            point_at(percent, (body_radius - INDICATOR_RADIUS - 3.0).max(0.0)),
+            INDICATOR_RADIUS,
+            Color::from_rgb(0.3, 0.3, 0.3),
+        );
+
+        let mut primitives: Vec<Primitive> = ticks.collect();
+        primitives.extend(vec![body_border, body, indicator]);
+
+        (
+            Primitive::Group { primitives },
+            if is_dragging {
+                MouseCursor::Grabbing
+            } else if is_mouse_over {
+                MouseCursor::Grab
+            } else {
+                MouseCursor::OutOfBounds
+            },
+        )
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{Primitive, Renderer};
+use iced_native::{lazy_column, MouseCursor};
+
+impl lazy_column::Renderer for Renderer {
+    fn draw(&mut self, rows: Vec<Self::Output>) -> Self::Output {
+        let mut mouse_cursor = MouseCursor::OutOfBounds;
+        let mut primitives = self.arena.group();
+
+        for (primitive, new_mouse_cursor) in rows {
+            if new_mouse_cursor > mouse_cursor {
+                mouse_cursor = new_mouse_cursor;
+            }
+
+            primitives.push(primitive);
+        }
+
+        (Primitive::Group { primitives }, mouse_cursor)
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{Primitive, Renderer};
+use iced_native::{
+    link, text, Color, Font, HorizontalAlignment, MouseCursor, Point,
+    Rectangle, VerticalAlignment,
+};
+
+// TODO: Make the colors of links configurable
+pub(super) const COLOR: Color = Color {
+    r: 0.0,
+    g: 0.4,
+    b: 0.8,
+    a: 1.0,
+};
+
+const VISITED_COLOR: Color = Color {
+    r: 0.4,
+    g: 0.2,
+    b: 0.6,
+    a: 1.0,
+};
+
+impl link::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        content: &str,
+        size: u16,
+        font: Font,
+        is_visited: bool,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let color = if is_visited { VISITED_COLOR } else { COLOR };
+
+        let decoration = if is_mouse_over {
+            text::Decoration::UNDERLINE
+        } else {
+            text::Decoration::default()
+        };
+
+        (
+            Primitive::Text {
+                content: content.to_string(),
+                size: f32::from(size),
+                bounds,
+                color,
+                font,
+                params: text::Params {
+                    decoration,
+                    ..text::Params::default()
+                },
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Top,
+            },
+            if is_mouse_over {
+                MouseCursor::Pointer
+            } else {
+                MouseCursor::OutOfBounds
+            },
+        )
+    }
+}
@@ -0,0 +1,252 @@
+use super::on_top;
+use crate::{Primitive, Renderer};
+use iced_native::{
+    menu_bar, text, text_input, Background, Color, Font, HorizontalAlignment,
+    MouseCursor, Point, Rectangle, VerticalAlignment,
+};
+
+// Matches the padding of the layout of the menu bar
+const PADDING: f32 = 8.0;
+
+impl menu_bar::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        titles: &[menu_bar::Title<'_>],
+        text_size: u16,
+    ) -> Self::Output {
+        let mut primitives = vec![Primitive::Quad {
+            bounds,
+            background: Background::Color([0.95, 0.95, 0.95].into()),
+            border_radius: 0,
+            shadow: None,
+        }];
+
+        for title in titles {
+            let is_hovered = title.bounds.contains(cursor_position);
+
+            if title.is_open || is_hovered {
+                primitives.push(Primitive::Quad {
+                    bounds: title.bounds,
+                    background: Background::Color(if title.is_open {
+                        [0.85, 0.91, 0.98].into()
+                    } else {
+                        [0.9, 0.9, 0.9].into()
+                    }),
+                    border_radius: 0,
+                    shadow: None,
+                });
+            }
+
+            label(
+                self,
+                &mut primitives,
+                title.label,
+                Rectangle {
+                    x: title.bounds.x + PADDING,
+                    width: (title.bounds.width - 2.0 * PADDING).max(0.0),
+                    ..title.bounds
+                },
+                text_size,
+                [0.2, 0.2, 0.2].into(),
+            );
+        }
+
+        let is_over_title = titles
+            .iter()
+            .any(|title| title.bounds.contains(cursor_position));
+
+        (
+            Primitive::Group { primitives },
+            if is_over_title {
+                MouseCursor::Pointer
+            } else {
+                MouseCursor::OutOfBounds
+            },
+        )
+    }
+
+    fn menus(
+        &mut self,
+        cursor_position: Point,
+        panels: &[menu_bar::Panel<'_>],
+        text_size: u16,
+    ) -> Self::Output {
+        let mut layers = Vec::with_capacity(panels.len());
+        let mut mouse_cursor = MouseCursor::OutOfBounds;
+
+        for panel in panels {
+            let mut primitives = vec![
+                Primitive::Quad {
+                    bounds: panel.bounds,
+                    background: Background::Color([0.7, 0.7, 0.7].into()),
+                    border_radius: 0,
+                    shadow: None,
+                },
+                Primitive::Quad {
+                    bounds: Rectangle {
+                        x: panel.bounds.x + 1.0,
+                        y: panel.bounds.y + 1.0,
+                        width: panel.bounds.width - 2.0,
+                        height: panel.bounds.height - 2.0,
+                    },
+                    background: Background::Color(Color::WHITE),
+                    border_radius: 0,
+                    shadow: None,
+                },
+            ];
+
+            for entry in &panel.entries {
+                let bounds = entry.bounds;
+
+                if entry.is_separator {
+                    primitives.push(Primitive::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + PADDING,
+                            y: (bounds.y + bounds.height / 2.0).floor(),
+                            width: (bounds.width - 2.0 * PADDING).max(0.0),
+                            height: 1.0,
+                        },
+                        background: Background::Color(
+                            [0.85, 0.85, 0.85].into(),
+                        ),
+                        border_radius: 0,
+                        shadow: None,
+                    });
+
+                    continue;
+                }
+
+                if entry.is_highlighted {
+                    primitives.push(Primitive::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + 1.0,
+                            width: bounds.width - 2.0,
+                            ..bounds
+                        },
+                        background: Background::Color(
+                            [0.85, 0.91, 0.98].into(),
+                        ),
+                        border_radius: 0,
+                        shadow: None,
+                    });
+                }
+
+                if entry.is_enabled && bounds.contains(cursor_position) {
+                    mouse_cursor = MouseCursor::Pointer;
+                }
+
+                let color: Color = if entry.is_enabled {
+                    [0.2, 0.2, 0.2].into()
+                } else {
+                    [0.6, 0.6, 0.6].into()
+                };
+
+                let content = Rectangle {
+                    x: bounds.x + PADDING,
+                    width: (bounds.width - 2.0 * PADDING).max(0.0),
+                    ..bounds
+                };
+
+                label(
+                    self,
+                    &mut primitives,
+                    entry.label,
+                    content,
+                    text_size,
+                    color,
+                );
+
+                let hint = match entry.shortcut {
+                    Some(shortcut) => Some(shortcut),
+                    None if entry.has_submenu => Some("\u{25B8}"),
+                    None => None,
+                };
+
+                if let Some(hint) = hint {
+                    primitives.push(Primitive::Text {
+                        content: hint.to_owned(),
+                        bounds: content,
+                        color: if entry.is_enabled {
+                            [0.5, 0.5, 0.5].into()
+                        } else {
+                            color
+                        },
+                        size: f32::from(text_size),
+                        font: Font::Default,
+                        params: text::Params::default(),
+                        horizontal_alignment: HorizontalAlignment::Right,
+                        vertical_alignment: VerticalAlignment::Center,
+                    });
+                }
+            }
+
+            if panel.bounds.contains(cursor_position)
+                && mouse_cursor == MouseCursor::OutOfBounds
+            {
+                mouse_cursor = MouseCursor::Idle;
+            }
+
+            // Every menu covers the text of the menu it opened from
+            layers.push(on_top(panel.bounds, Primitive::Group { primitives }));
+        }
+
+        (Primitive::Group { primitives: layers }, mouse_cursor)
+    }
+}
+
+// Draws a label, underlining its mnemonic
+fn label(
+    renderer: &Renderer,
+    primitives: &mut Vec<Primitive>,
+    label: &menu_bar::Label,
+    bounds: Rectangle,
+    size: u16,
+    color: Color,
+) {
+    primitives.push(Primitive::Text {
+        content: label.text.clone(),
+        bounds,
+        color,
+        size: f32::from(size),
+        font: Font::Default,
+        params: text::Params::default(),
+        horizontal_alignment: HorizontalAlignment::Left,
+        vertical_alignment: VerticalAlignment::Center,
+    });
+
+    let mnemonic = label.mnemonic.and_then(|offset| {
+        label.text[offset..].chars().next().map(|c| (offset, c))
+    });
+
+    if let Some((offset, mnemonic)) = mnemonic {
+        let x = text_input::Renderer::measure_value(
+            renderer,
+            &label.text[..offset],
+            size,
+        );
+
+        // The mnemonic is drawn again, invisible, only for its underline
+        primitives.push(Primitive::Text {
+            content: mnemonic.to_string(),
+            bounds: Rectangle {
+                x: bounds.x + x,
+                width: (bounds.width - x).max(0.0),
+                ..bounds
+            },
+            color: Color { a: 0.0, ..color },
+            size: f32::from(size),
+            font: Font::Default,
+            params: text::Params {
+                decoration: text::Decoration {
+                    color: Some(color),
+                    ..text::Decoration::UNDERLINE
+                },
+                ..text::Params::default()
+            },
+            horizontal_alignment: HorizontalAlignment::Left,
+            vertical_alignment: VerticalAlignment::Center,
+        });
+    }
+}
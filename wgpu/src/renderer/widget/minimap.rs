@@ -0,0 +1,330 @@
+use super::on_top;
+use crate::{Primitive, Renderer, Span};
+use iced_native::{
+    image, minimap, text, Background, Color, MouseCursor, Point, Rectangle,
+    Shadow, Vector,
+};
+
+impl minimap::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        content: Option<Self::Output>,
+        projection: Option<minimap::Projection>,
+        is_dragging: bool,
+    ) -> Self::Output {
+        let background = Primitive::Quad {
+            bounds,
+            background: Background::Color([0.95, 0.95, 0.95].into()),
+            border_radius: 0,
+            shadow: None,
+        };
+
+        let projection = match projection {
+            Some(projection) => projection,
+            None => return (background, MouseCursor::OutOfBounds),
+        };
+
+        let content = content
+            .map(|(primitive, _)| {
+                scale(
+                    &primitive,
+                    &Transformation {
+                        from: Point::new(
+                            projection.content.x,
+                            projection.content.y,
+                        ),
+                        to: Point::new(
+                            projection.target.x,
+                            projection.target.y,
+                        ),
+                        scale: projection.scale,
+                    },
+                )
+            })
+            .unwrap_or(Primitive::None);
+
+        let viewport = Primitive::Quad {
+            bounds: projection.viewport,
+            background: Background::Color(Color {
+                a: if is_dragging { 0.3 } else { 0.2 },
+                ..Color::BLACK
+            }),
+            border_radius: 2,
+            shadow: None,
+        };
+
+        let is_mouse_over_viewport =
+            projection.viewport.contains(cursor_position);
+
+        (
+            Primitive::Group {
+                primitives: vec![
+                    background,
+                    Primitive::Clip {
+                        bounds,
+                        offset: Vector::new(0, 0),
+                        content: Box::new(content),
+                    },
+                    on_top(bounds, viewport),
+                ],
+            },
+            if is_dragging {
+                MouseCursor::Grabbing
+            } else if is_mouse_over_viewport {
+                MouseCursor::Grab
+            } else if bounds.contains(cursor_position) {
+                MouseCursor::Pointer
+            } else {
+                MouseCursor::OutOfBounds
+            },
+        )
+    }
+}
+
+struct Transformation {
+    from: Point,
+    to: Point,
+    scale: f32,
+}
+
+impl Transformation {
+    fn apply(&self, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            x: self.to.x + (bounds.x - self.from.x) * self.scale,
+            y: self.to.y + (bounds.y - self.from.y) * self.scale,
+            width: bounds.width * self.scale,
+            height: bounds.height * self.scale,
+        }
+    }
+
+    fn projection(&self) -> crate::Transformation {
+        use crate::Transformation as T;
+
+        T::translate(self.to.x, self.to.y)
+            * T::scale(self.scale, self.scale)
+            * T::translate(-self.from.x, -self.from.y)
+    }
+
+    // Moves a transformation of the content to the scaled coordinate system
+    fn conjugate(
+        &self,
+        transformation: crate::Transformation,
+    ) -> crate::Transformation {
+        use crate::Transformation as T;
+
+        let projection = self.projection();
+
+        let inverse = T::translate(self.from.x, self.from.y)
+            * T::scale(1.0 / self.scale, 1.0 / self.scale)
+            * T::translate(-self.to.x, -self.to.y);
+
+        projection * transformation * inverse
+    }
+}
+
+fn scale_params(
+    params: text::Params,
+    transformation: &Transformation,
+) -> text::Params {
+    text::Params {
+        letter_spacing: params.letter_spacing * transformation.scale,
+        decoration: text::Decoration {
+            thickness: params
+                .decoration
+                .thickness
+                .map(|thickness| thickness * transformation.scale),
+            ..params.decoration
+        },
+        ..params
+    }
+}
+
+fn scale(primitive: &Primitive, transformation: &Transformation) -> Primitive {
+    match primitive {
+        Primitive::None => Primitive::None,
+        Primitive::Group { primitives } => Primitive::Group {
+            primitives: primitives
+                .iter()
+                .map(|primitive| scale(primitive, transformation))
+                .collect(),
+        },
+        Primitive::Text {
+            content,
+            bounds,
+            color,
+            size,
+            font,
+            params,
+            horizontal_alignment,
+            vertical_alignment,
+        } => {
+            let size = size * transformation.scale;
+
+            // Text this small is not readable and only adds noise
+            if size < 1.0 {
+                return Primitive::None;
+            }
+
+            Primitive::Text {
+                content: content.clone(),
+                bounds: transformation.apply(*bounds),
+                color: *color,
+                size,
+                font: *font,
+                params: scale_params(*params, transformation),
+                horizontal_alignment: *horizontal_alignment,
+                vertical_alignment: *vertical_alignment,
+            }
+        }
+        Primitive::Paragraph {
+            spans,
+            bounds,
+            params,
+            horizontal_alignment,
+            vertical_alignment,
+        } => {
+            let spans: Vec<_> = spans
+                .iter()
+                .map(|span| Span {
+                    size: span.size * transformation.scale,
+                    ..span.clone()
+                })
+                .collect();
+
+            if spans.iter().all(|span| span.size < 1.0) {
+                return Primitive::None;
+            }
+
+            Primitive::Paragraph {
+                spans,
+                bounds: transformation.apply(*bounds),
+                params: scale_params(*params, transformation),
+                horizontal_alignment: *horizontal_alignment,
+                vertical_alignment: *vertical_alignment,
+            }
+        }
+        Primitive::Quad {
+            bounds,
+            background,
+            border_radius,
+            shadow,
+        } => Primitive::Quad {
+            bounds: transformation.apply(*bounds),
+            background: *background,
+            border_radius: (f32::from(*border_radius) * transformation.scale)
+                as u16,
+            shadow: shadow.map(|shadow| Shadow {
+                offset: Vector::new(
+                    shadow.offset.x * transformation.scale,
+                    shadow.offset.y * transformation.scale,
+                ),
+                blur_radius: shadow.blur_radius * transformation.scale,
+                ..shadow
+            }),
+        },
+        Primitive::Image {
+            fit: image::Fit::Tile,
+            ..
+        } => {
+            // Tiles keep the size of the image, so we scale them with a
+            // transformation instead
+            Primitive::Transform {
+                transformation: transformation.projection(),
+                content: Box::new(primitive.clone()),
+            }
+        }
+        Primitive::Image {
+            handle,
+            bounds,
+            border_radius,
+            fit,
+        } => Primitive::Image {
+            handle: handle.clone(),
+            bounds: transformation.apply(*bounds),
+            border_radius: (f32::from(*border_radius) * transformation.scale)
+                as u16,
+            fit: *fit,
+        },
+        Primitive::Texture {
+            handle,
+            bounds,
+            border_radius,
+            fit,
+        } => Primitive::Texture {
+            handle: handle.clone(),
+            bounds: transformation.apply(*bounds),
+            border_radius: (f32::from(*border_radius) * transformation.scale)
+                as u16,
+            fit: *fit,
+        },
+        Primitive::NinePatch {
+            handle,
+            bounds,
+            slices,
+        } => Primitive::NinePatch {
+            handle: handle.clone(),
+            bounds: transformation.apply(*bounds),
+            slices: *slices,
+        },
+        Primitive::Svg {
+            handle,
+            bounds,
+            border_radius,
+            color,
+        } => Primitive::Svg {
+            handle: handle.clone(),
+            bounds: transformation.apply(*bounds),
+            border_radius: (f32::from(*border_radius) * transformation.scale)
+                as u16,
+            color: *color,
+        },
+        Primitive::Clip {
+            bounds,
+            offset,
+            content,
+        } => Primitive::Clip {
+            bounds: transformation.apply(*bounds),
+            offset: Vector::new(
+                (offset.x as f32 * transformation.scale).round() as u32,
+                (offset.y as f32 * transformation.scale).round() as u32,
+            ),
+            content: Box::new(scale(content, transformation)),
+        },
+        Primitive::CircularClip {
+            center,
+            radius,
+            content,
+        } => {
+            let center = transformation.apply(Rectangle {
+                x: center.x,
+                y: center.y,
+                width: 0.0,
+                height: 0.0,
+            });
+
+            Primitive::CircularClip {
+                center: Point::new(center.x, center.y),
+                radius: radius * transformation.scale,
+                content: Box::new(scale(content, transformation)),
+            }
+        }
+        Primitive::Transform {
+            transformation: content_transformation,
+            content,
+        } => Primitive::Transform {
+            transformation: transformation.conjugate(*content_transformation),
+            content: Box::new(scale(content, transformation)),
+        },
+        Primitive::Custom { bounds, program } => Primitive::Custom {
+            bounds: transformation.apply(*bounds),
+            program: program.clone(),
+        },
+        Primitive::Transparency { alpha, content } => Primitive::Transparency {
+            alpha: *alpha,
+            content: Box::new(scale(content, transformation)),
+        },
+        Primitive::Cached { cache } => scale(cache, transformation),
+    }
+}
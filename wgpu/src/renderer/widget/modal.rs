@@ -0,0 +1,26 @@
+use crate::{Primitive, Renderer};
+use iced_native::{modal, Background, Color, Rectangle};
+
+impl modal::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        backdrop: Color,
+        (dialog, mouse_cursor): Self::Output,
+    ) -> Self::Output {
+        (
+            Primitive::Group {
+                primitives: vec![
+                    Primitive::Quad {
+                        bounds,
+                        background: Background::Color(backdrop),
+                        border_radius: 0,
+                        shadow: None,
+                    },
+                    dialog,
+                ],
+            },
+            mouse_cursor,
+        )
+    }
+}
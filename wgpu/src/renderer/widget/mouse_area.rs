@@ -0,0 +1,12 @@
+use crate::{Primitive, Renderer};
+use iced_native::{mouse_area, MouseCursor};
+
+impl mouse_area::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        mouse_cursor: Option<MouseCursor>,
+        (content, content_mouse_cursor): (Primitive, MouseCursor),
+    ) -> Self::Output {
+        (content, mouse_cursor.unwrap_or(content_mouse_cursor))
+    }
+}
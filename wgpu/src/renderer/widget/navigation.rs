@@ -0,0 +1,50 @@
+use crate::{Primitive, Renderer};
+use iced_native::{
+    navigation::{self, Animation, Transition},
+    MouseCursor, Rectangle, Vector,
+};
+
+impl navigation::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        (current, mouse_cursor): Self::Output,
+        leaving: Option<(Self::Output, Transition)>,
+    ) -> Self::Output {
+        let primitive = match leaving {
+            None => current,
+            Some(((leaving, _), transition)) => {
+                let progress = transition.progress.clamp(0.0, 1.0);
+
+                match transition.animation {
+                    Animation::None => current,
+                    Animation::Slide => Primitive::Group {
+                        primitives: vec![clip(bounds, leaving), current],
+                    },
+                    Animation::Fade => Primitive::Group {
+                        primitives: vec![
+                            Primitive::Transparency {
+                                alpha: 1.0 - progress,
+                                content: Box::new(leaving),
+                            },
+                            Primitive::Transparency {
+                                alpha: progress,
+                                content: Box::new(current),
+                            },
+                        ],
+                    },
+                }
+            }
+        };
+
+        (clip(bounds, primitive), mouse_cursor)
+    }
+}
+
+fn clip(bounds: Rectangle, content: Primitive) -> Primitive {
+    Primitive::Clip {
+        bounds,
+        offset: Vector::new(0, 0),
+        content: Box::new(content),
+    }
+}
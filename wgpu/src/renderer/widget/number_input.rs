@@ -0,0 +1,122 @@
+use crate::{Primitive, Renderer};
+use iced_native::{
+    number_input, text, Background, Color, Font, HorizontalAlignment,
+    MouseCursor, Point, Rectangle, VerticalAlignment,
+};
+
+const INVALID: Color = Color {
+    r: 0.9,
+    g: 0.2,
+    b: 0.2,
+    a: 1.0,
+};
+
+impl number_input::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        (input, input_mouse_cursor): Self::Output,
+        buttons: &number_input::Buttons,
+        is_valid: bool,
+    ) -> Self::Output {
+        let mut primitives = vec![input];
+        let mut mouse_cursor = input_mouse_cursor;
+
+        if !is_valid {
+            let input_bounds = Rectangle {
+                width: bounds.width - buttons.increase.width,
+                ..bounds
+            };
+
+            primitives.extend(outline(input_bounds).into_iter().map(
+                |bounds| Primitive::Quad {
+                    bounds,
+                    background: Background::Color(INVALID),
+                    border_radius: 0,
+                    shadow: None,
+                },
+            ));
+        }
+
+        let spin = [
+            (buttons.increase, "\u{25B4}", buttons.can_increase),
+            (buttons.decrease, "\u{25BE}", buttons.can_decrease),
+        ];
+
+        for (button, arrow, is_enabled) in spin.iter().copied() {
+            let is_hovered = button.contains(cursor_position);
+
+            if is_hovered && is_enabled {
+                mouse_cursor = MouseCursor::Pointer;
+            }
+
+            primitives.push(Primitive::Quad {
+                bounds: button,
+                background: Background::Color([0.7, 0.7, 0.7].into()),
+                border_radius: 0,
+                shadow: None,
+            });
+
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: button.x,
+                    y: button.y + 1.0,
+                    width: button.width - 1.0,
+                    height: button.height - 2.0,
+                },
+                background: Background::Color(
+                    if is_hovered && is_enabled {
+                        [0.9, 0.9, 0.9]
+                    } else {
+                        [0.97, 0.97, 0.97]
+                    }
+                    .into(),
+                ),
+                border_radius: 0,
+                shadow: None,
+            });
+
+            primitives.push(Primitive::Text {
+                content: arrow.to_owned(),
+                bounds: button,
+                color: if is_enabled {
+                    [0.3, 0.3, 0.3]
+                } else {
+                    [0.75, 0.75, 0.75]
+                }
+                .into(),
+                size: button.height,
+                font: Font::Default,
+                params: text::Params::default(),
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+        }
+
+        (Primitive::Group { primitives }, mouse_cursor)
+    }
+}
+
+fn outline(bounds: Rectangle) -> [Rectangle; 4] {
+    [
+        Rectangle {
+            height: 1.0,
+            ..bounds
+        },
+        Rectangle {
+            y: bounds.y + bounds.height - 1.0,
+            height: 1.0,
+            ..bounds
+        },
+        Rectangle {
+            width: 1.0,
+            ..bounds
+        },
+        Rectangle {
+            x: bounds.x + bounds.width - 1.0,
+            width: 1.0,
+            ..bounds
+        },
+    ]
+}
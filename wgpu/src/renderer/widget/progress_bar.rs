@@ -0,0 +1,55 @@
+use crate::{Primitive, Renderer};
+use iced_native::{
+    progress_bar, text, Background, Color, Font, HorizontalAlignment,
+    MouseCursor, Rectangle, VerticalAlignment,
+};
+
+impl progress_bar::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        progress: f32,
+        label: Option<&str>,
+        text_size: u16,
+    ) -> Self::Output {
+        let border_radius = (bounds.height / 2.0).min(5.0) as u16;
+
+        let mut primitives = vec![Primitive::Quad {
+            bounds,
+            background: Background::Color([0.9, 0.9, 0.9].into()),
+            border_radius,
+            shadow: None,
+        }];
+
+        if progress > 0.0 {
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    width: bounds.width * progress,
+                    ..bounds
+                },
+                background: Background::Color([0.20, 0.60, 0.86].into()),
+                border_radius,
+                shadow: None,
+            });
+        }
+
+        if let Some(label) = label {
+            primitives.push(Primitive::Text {
+                content: label.to_owned(),
+                bounds,
+                color: if progress > 0.5 {
+                    Color::WHITE
+                } else {
+                    [0.3, 0.3, 0.3].into()
+                },
+                size: f32::from(text_size),
+                font: Font::Default,
+                params: text::Params::default(),
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+        }
+
+        (Primitive::Group { primitives }, MouseCursor::OutOfBounds)
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{Primitive, Renderer};
+use iced_native::{qr_code, Background, Color, MouseCursor, Rectangle};
+
+impl qr_code::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        data: &qr_code::Data,
+        cell_size: f32,
+        quiet_zone: u16,
+        dark: Color,
+        light: Color,
+    ) -> Self::Output {
+        let origin_x = bounds.x + f32::from(quiet_zone) * cell_size;
+        let origin_y = bounds.y + f32::from(quiet_zone) * cell_size;
+
+        let mut primitives = vec![Primitive::Quad {
+            bounds,
+            background: Background::Color(light),
+            border_radius: 0,
+            shadow: None,
+        }];
+
+        let quad = |run: &Run, bottom: usize| Primitive::Quad {
+            bounds: Rectangle {
+                x: origin_x + run.start as f32 * cell_size,
+                y: origin_y + run.top as f32 * cell_size,
+                width: (run.end - run.start) as f32 * cell_size,
+                height: (bottom - run.top) as f32 * cell_size,
+            },
+            background: Background::Color(dark),
+            border_radius: 0,
+            shadow: None,
+        };
+
+        // Contiguous dark cells in a row are merged into a single run, which
+        // keeps growing downwards while the rows below have the same run. This
+        // keeps the amount of instances low, as most codes have thousands of
+        // dark cells.
+        let mut runs: Vec<Run> = Vec::new();
+
+        for (y, row) in data.rows().enumerate() {
+            let mut next = Vec::with_capacity(runs.len());
+            let mut x = 0;
+
+            while x < row.len() {
+                if !row[x] {
+                    x += 1;
+                    continue;
+                }
+
+                let start = x;
+
+                while x < row.len() && row[x] {
+                    x += 1;
+                }
+
+                let run = match runs
+                    .iter()
+                    .position(|run| run.start == start && run.end == x)
+                {
+                    Some(index) => runs.swap_remove(index),
+                    None => Run {
+                        start,
+                        end: x,
+                        top: y,
+                    },
+                };
+
+                next.push(run);
+            }
+
+            // The runs left did not continue in this row
+            primitives.extend(runs.iter().map(|run| quad(run, y)));
+
+            runs = next;
+        }
+
+        primitives.extend(runs.iter().map(|run| quad(run, data.width())));
+
+        (Primitive::Group { primitives }, MouseCursor::OutOfBounds)
+    }
+}
+
+// A rectangle of dark cells, spanning the columns from `start` to `end` and
+// the rows from `top` to wherever it stops
+struct Run {
+    start: usize,
+    end: usize,
+    top: usize,
+}
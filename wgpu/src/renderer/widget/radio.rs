@@ -21,6 +21,7 @@ impl radio::Renderer for Renderer {
                 bounds,
                 background: Background::Color([0.6, 0.6, 0.6].into()),
                 border_radius: (SIZE / 2.0) as u16,
+                shadow: None,
             },
             Primitive::Quad {
                 bounds: Rectangle {
@@ -38,6 +39,7 @@ impl radio::Renderer for Renderer {
                     .into(),
                 ),
                 border_radius: (SIZE / 2.0 - 1.0) as u16,
+                shadow: None,
             },
         );
 
@@ -53,6 +55,7 @@ impl radio::Renderer for Renderer {
                         },
                         background: Background::Color([0.3, 0.3, 0.3].into()),
                         border_radius: (DOT_SIZE / 2.0) as u16,
+                        shadow: None,
                     };
 
                     vec![radio_border, radio_box, radio_circle, label]
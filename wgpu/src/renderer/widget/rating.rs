@@ -0,0 +1,76 @@
+use crate::{Primitive, Renderer};
+use iced_native::{
+    rating, text, Background, Font, HorizontalAlignment, MouseCursor,
+    Rectangle, VerticalAlignment,
+};
+
+impl rating::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        stars: &[rating::Star],
+        is_focused: bool,
+        is_mouse_over: bool,
+        style: &rating::Style,
+    ) -> Self::Output {
+        let mut primitives = Vec::with_capacity(stars.len() + 2);
+
+        if is_focused {
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: bounds.x - 2.0,
+                    y: bounds.y - 2.0,
+                    width: bounds.width + 4.0,
+                    height: bounds.height + 4.0,
+                },
+                background: Background::Color(style.focus_color),
+                border_radius: 4,
+                shadow: None,
+            });
+
+            primitives.push(Primitive::Quad {
+                bounds,
+                background: Background::Color([1.0, 1.0, 1.0].into()),
+                border_radius: 3,
+                shadow: None,
+            });
+        }
+
+        // While the mouse previews a score, it replaces the current one
+        let is_previewing = stars.iter().any(|star| star.is_previewed);
+
+        primitives.extend(stars.iter().map(|star| {
+            let color = if is_previewing {
+                if star.is_previewed {
+                    style.preview
+                } else {
+                    style.empty
+                }
+            } else if star.is_filled {
+                style.filled
+            } else {
+                style.empty
+            };
+
+            Primitive::Text {
+                content: String::from("\u{2605}"),
+                bounds: star.bounds,
+                color,
+                size: star.bounds.height,
+                font: Font::Default,
+                params: text::Params::default(),
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+            }
+        }));
+
+        (
+            Primitive::Group { primitives },
+            if is_mouse_over {
+                MouseCursor::Pointer
+            } else {
+                MouseCursor::OutOfBounds
+            },
+        )
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{Primitive, Renderer};
+use iced_native::{
+    reveal::{self, Visible},
+    Rectangle, Vector,
+};
+
+impl reveal::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        (content, mouse_cursor): Self::Output,
+        visible: Option<Visible>,
+    ) -> Self::Output {
+        let primitive = match visible {
+            None => content,
+            Some(Visible::Rectangle(bounds)) => Primitive::Clip {
+                bounds,
+                offset: Vector::new(0, 0),
+                content: Box::new(content),
+            },
+            Some(Visible::Circle { center, radius }) => {
+                Primitive::CircularClip {
+                    center,
+                    radius,
+                    content: Box::new(content),
+                }
+            }
+        };
+
+        (primitive, mouse_cursor)
+    }
+}
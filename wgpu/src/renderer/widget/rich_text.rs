@@ -0,0 +1,125 @@
+use super::link;
+use crate::{Primitive, Renderer, Span};
+use iced_native::{
+    rich_text, text, Color, HorizontalAlignment, MouseCursor, Point, Rectangle,
+    Size, VerticalAlignment,
+};
+
+impl rich_text::Renderer for Renderer {
+    fn measure(
+        &self,
+        spans: &[rich_text::Span],
+        params: text::Params,
+        bounds: Size,
+    ) -> (f32, f32) {
+        self.text_pipeline.measure_spans(
+            &section_text(self, spans),
+            params,
+            bounds,
+        )
+    }
+
+    fn span_at(
+        &self,
+        bounds: Rectangle,
+        spans: &[rich_text::Span],
+        params: text::Params,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+        point: Point,
+    ) -> Option<usize> {
+        // Laid out exactly like the primitive, so the hit glyphs are the
+        // drawn ones
+        let (screen_position, layout) = crate::renderer::align_text(
+            bounds,
+            horizontal_alignment,
+            vertical_alignment,
+        );
+
+        let section = wgpu_glyph::VariedSection {
+            screen_position,
+            bounds: (bounds.width, bounds.height),
+            layout,
+            text: section_text(self, spans),
+            ..Default::default()
+        };
+
+        self.text_pipeline.span_at(&section, params, point)
+    }
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        spans: &[rich_text::Span],
+        params: text::Params,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+    ) -> Self::Output {
+        let default_size = text::Renderer::default_size(self);
+
+        let is_mouse_over_link = bounds.contains(cursor_position)
+            && rich_text::Renderer::span_at(
+                self,
+                bounds,
+                spans,
+                params,
+                horizontal_alignment,
+                vertical_alignment,
+                cursor_position,
+            )
+            .map_or(false, |index| spans[index].link.is_some());
+
+        let arena = &mut self.arena;
+
+        let spans = spans
+            .iter()
+            .map(|span| Span {
+                content: arena.string(&span.content),
+                size: f32::from(span.size.unwrap_or(default_size)),
+                font: span.font,
+                weight: span.weight,
+                color: span.color.unwrap_or(if span.link.is_some() {
+                    link::COLOR
+                } else {
+                    Color::BLACK
+                }),
+            })
+            .collect();
+
+        (
+            Primitive::Paragraph {
+                spans,
+                bounds,
+                params,
+                horizontal_alignment,
+                vertical_alignment,
+            },
+            if is_mouse_over_link {
+                MouseCursor::Pointer
+            } else {
+                MouseCursor::OutOfBounds
+            },
+        )
+    }
+}
+
+pub(super) fn section_text<'a>(
+    renderer: &Renderer,
+    spans: &'a [rich_text::Span],
+) -> Vec<wgpu_glyph::SectionText<'a>> {
+    let default_size = text::Renderer::default_size(renderer);
+
+    spans
+        .iter()
+        .map(|span| {
+            renderer.text_pipeline.span(
+                &span.content,
+                f32::from(span.size.unwrap_or(default_size)),
+                span.font,
+                span.weight,
+                [0.0, 0.0, 0.0, 1.0],
+            )
+        })
+        .collect()
+}
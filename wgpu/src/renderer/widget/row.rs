@@ -9,25 +9,19 @@ impl row::Renderer for Renderer {
         cursor_position: Point,
     ) -> Self::Output {
         let mut mouse_cursor = MouseCursor::OutOfBounds;
+        let mut primitives = self.arena.group();
 
-        (
-            Primitive::Group {
-                primitives: children
-                    .iter()
-                    .zip(layout.children())
-                    .map(|(child, layout)| {
-                        let (primitive, new_mouse_cursor) =
-                            child.draw(self, layout, cursor_position);
+        for (child, layout) in children.iter().zip(layout.children()) {
+            let (primitive, new_mouse_cursor) =
+                child.draw(self, layout, cursor_position);
 
-                        if new_mouse_cursor > mouse_cursor {
-                            mouse_cursor = new_mouse_cursor;
-                        }
+            if new_mouse_cursor > mouse_cursor {
+                mouse_cursor = new_mouse_cursor;
+            }
 
-                        primitive
-                    })
-                    .collect(),
-            },
-            mouse_cursor,
-        )
+            primitives.push(primitive);
+        }
+
+        (Primitive::Group { primitives }, mouse_cursor)
     }
 }
@@ -1,5 +1,7 @@
 use crate::{Primitive, Renderer};
-use iced_native::{scrollable, Background, MouseCursor, Rectangle, Vector};
+use iced_native::{
+    draggable::Axis, scrollable, Background, MouseCursor, Rectangle, Vector,
+};
 
 const SCROLLBAR_WIDTH: u16 = 10;
 const SCROLLBAR_MARGIN: u16 = 2;
@@ -10,13 +12,16 @@ impl scrollable::Renderer for Renderer {
         bounds: Rectangle,
         content_bounds: Rectangle,
         offset: u32,
+        style: &scrollable::Style,
     ) -> Option<scrollable::Scrollbar> {
         if content_bounds.height > bounds.height {
+            let width = style.width.unwrap_or(SCROLLBAR_WIDTH);
+
             let scrollbar_bounds = Rectangle {
                 x: bounds.x + bounds.width
-                    - f32::from(SCROLLBAR_WIDTH + 2 * SCROLLBAR_MARGIN),
+                    - f32::from(width + 2 * SCROLLBAR_MARGIN),
                 y: bounds.y,
-                width: f32::from(SCROLLBAR_WIDTH + 2 * SCROLLBAR_MARGIN),
+                width: f32::from(width + 2 * SCROLLBAR_MARGIN),
                 height: bounds.height,
             };
 
@@ -36,6 +41,49 @@ impl scrollable::Renderer for Renderer {
                 scroller: scrollable::Scroller {
                     bounds: scroller_bounds,
                 },
+                style: *style,
+            })
+        } else {
+            None
+        }
+    }
+
+    fn horizontal_scrollbar(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        offset: u32,
+        style: &scrollable::Style,
+    ) -> Option<scrollable::Scrollbar> {
+        if content_bounds.width > bounds.width {
+            let width = style.width.unwrap_or(SCROLLBAR_WIDTH);
+
+            let scrollbar_bounds = Rectangle {
+                x: bounds.x,
+                y: bounds.y + bounds.height
+                    - f32::from(width + 2 * SCROLLBAR_MARGIN),
+                width: bounds.width,
+                height: f32::from(width + 2 * SCROLLBAR_MARGIN),
+            };
+
+            let ratio = bounds.width / content_bounds.width;
+            let scrollbar_width = bounds.width * ratio;
+            let x_offset = offset as f32 * ratio;
+
+            let scroller_bounds = Rectangle {
+                x: scrollbar_bounds.x + x_offset,
+                y: scrollbar_bounds.y + f32::from(SCROLLBAR_MARGIN),
+                width: scrollbar_width,
+                height: scrollbar_bounds.height
+                    - f32::from(2 * SCROLLBAR_MARGIN),
+            };
+
+            Some(scrollable::Scrollbar {
+                bounds: scrollbar_bounds,
+                scroller: scrollable::Scroller {
+                    bounds: scroller_bounds,
+                },
+                style: *style,
             })
         } else {
             None
@@ -48,58 +96,56 @@ impl scrollable::Renderer for Renderer {
         bounds: Rectangle,
         _content_bounds: Rectangle,
         is_mouse_over: bool,
-        is_mouse_over_scrollbar: bool,
-        scrollbar: Option<scrollable::Scrollbar>,
-        offset: u32,
+        mouse_over_scrollbar: Option<Axis>,
+        scrollbars: scrollable::Scrollbars,
+        offset: Vector<u32>,
         (content, mouse_cursor): Self::Output,
     ) -> Self::Output {
         let clip = Primitive::Clip {
             bounds,
-            offset: Vector::new(0, offset),
-            content: Box::new(content),
+            offset,
+            content: self.arena.boxed(content),
         };
 
-        (
-            if let Some(scrollbar) = scrollbar {
-                if is_mouse_over || state.is_scroller_grabbed() {
-                    let scroller = Primitive::Quad {
+        let mut primitives = vec![clip];
+
+        if is_mouse_over || state.is_scroller_grabbed() {
+            let bars = [
+                (Axis::Vertical, scrollbars.vertical),
+                (Axis::Horizontal, scrollbars.horizontal),
+            ];
+
+            for (axis, scrollbar) in bars.iter() {
+                if let Some(scrollbar) = scrollbar {
+                    let is_active = mouse_over_scrollbar == Some(*axis)
+                        || state.grabbed_scroller() == Some(*axis);
+
+                    if is_active {
+                        primitives.push(track(*axis, scrollbar));
+                    }
+
+                    primitives.push(Primitive::Quad {
                         bounds: scrollbar.scroller.bounds,
                         background: Background::Color(
-                            [0.0, 0.0, 0.0, 0.7].into(),
+                            scrollbar
+                                .style
+                                .scroller_color
+                                .unwrap_or_else(|| [0.0, 0.0, 0.0, 0.7].into()),
                         ),
                         border_radius: 5,
-                    };
-
-                    if is_mouse_over_scrollbar || state.is_scroller_grabbed() {
-                        let scrollbar = Primitive::Quad {
-                            bounds: Rectangle {
-                                x: scrollbar.bounds.x
-                                    + f32::from(SCROLLBAR_MARGIN),
-                                width: scrollbar.bounds.width
-                                    - f32::from(2 * SCROLLBAR_MARGIN),
-                                ..scrollbar.bounds
-                            },
-                            background: Background::Color(
-                                [0.0, 0.0, 0.0, 0.3].into(),
-                            ),
-                            border_radius: 5,
-                        };
-
-                        Primitive::Group {
-                            primitives: vec![clip, scrollbar, scroller],
-                        }
-                    } else {
-                        Primitive::Group {
-                            primitives: vec![clip, scroller],
-                        }
-                    }
-                } else {
-                    clip
+                        shadow: None,
+                    });
                 }
+            }
+        }
+
+        (
+            if primitives.len() == 1 {
+                primitives.pop().unwrap()
             } else {
-                clip
+                Primitive::Group { primitives }
             },
-            if is_mouse_over_scrollbar || state.is_scroller_grabbed() {
+            if mouse_over_scrollbar.is_some() || state.is_scroller_grabbed() {
                 MouseCursor::Idle
             } else {
                 mouse_cursor
@@ -107,3 +153,32 @@ impl scrollable::Renderer for Renderer {
         )
     }
 }
+
+fn track(axis: Axis, scrollbar: &scrollable::Scrollbar) -> Primitive {
+    let margin = f32::from(SCROLLBAR_MARGIN);
+
+    let bounds = match axis {
+        Axis::Vertical => Rectangle {
+            x: scrollbar.bounds.x + margin,
+            width: scrollbar.bounds.width - 2.0 * margin,
+            ..scrollbar.bounds
+        },
+        Axis::Horizontal => Rectangle {
+            y: scrollbar.bounds.y + margin,
+            height: scrollbar.bounds.height - 2.0 * margin,
+            ..scrollbar.bounds
+        },
+    };
+
+    Primitive::Quad {
+        bounds,
+        background: Background::Color(
+            scrollbar
+                .style
+                .track_color
+                .unwrap_or_else(|| [0.0, 0.0, 0.0, 0.3].into()),
+        ),
+        border_radius: 5,
+        shadow: None,
+    }
+}
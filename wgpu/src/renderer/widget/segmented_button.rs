@@ -0,0 +1,92 @@
+use crate::{Primitive, Renderer};
+use iced_native::{
+    segmented_button, text, Background, Font, HorizontalAlignment, MouseCursor,
+    Point, Rectangle, VerticalAlignment,
+};
+
+impl segmented_button::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        segments: &[segmented_button::Segment],
+        text_size: u16,
+        style: &segmented_button::Style,
+    ) -> Self::Output {
+        let mut mouse_cursor = MouseCursor::OutOfBounds;
+
+        let mut primitives = vec![Primitive::Quad {
+            bounds,
+            background: Background::Color(style.border_color),
+            border_radius: 5,
+            shadow: None,
+        }];
+
+        for (index, segment) in segments.iter().enumerate() {
+            let is_hovered = segment.bounds.contains(cursor_position);
+
+            if is_hovered {
+                mouse_cursor = MouseCursor::Pointer;
+            }
+
+            // Segments share the border between them
+            let inner = Rectangle {
+                x: segment.bounds.x + if index == 0 { 1.0 } else { 0.0 },
+                y: segment.bounds.y + 1.0,
+                width: segment.bounds.width
+                    - if index == 0 { 2.0 } else { 1.0 },
+                height: segment.bounds.height - 2.0,
+            };
+
+            let background = if segment.is_selected {
+                style.selected_background
+            } else if is_hovered {
+                style.hovered_background
+            } else {
+                style.background
+            };
+
+            if segment.is_highlighted {
+                primitives.push(Primitive::Quad {
+                    bounds: inner,
+                    background: Background::Color(style.focus_color),
+                    border_radius: 4,
+                    shadow: None,
+                });
+            }
+
+            primitives.push(Primitive::Quad {
+                bounds: if segment.is_highlighted {
+                    Rectangle {
+                        x: inner.x + 2.0,
+                        y: inner.y + 2.0,
+                        width: inner.width - 4.0,
+                        height: inner.height - 4.0,
+                    }
+                } else {
+                    inner
+                },
+                background: Background::Color(background),
+                border_radius: 4,
+                shadow: None,
+            });
+
+            primitives.push(Primitive::Text {
+                content: segment.label.clone(),
+                bounds: segment.bounds,
+                color: if segment.is_selected {
+                    style.selected_text_color
+                } else {
+                    style.text_color
+                },
+                size: f32::from(text_size),
+                font: Font::Default,
+                params: text::Params::default(),
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+        }
+
+        (Primitive::Group { primitives }, mouse_cursor)
+    }
+}
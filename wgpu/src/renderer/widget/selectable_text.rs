@@ -0,0 +1,130 @@
+use crate::{Primitive, Renderer};
+use iced_native::{
+    selectable_text, text, Background, Color, Font, HorizontalAlignment,
+    MouseCursor, Point, Rectangle, VerticalAlignment,
+};
+
+use std::ops::Range;
+
+pub(super) const SELECTION_COLOR: Color = Color {
+    r: 0.2,
+    g: 0.5,
+    b: 1.0,
+    a: 0.3,
+};
+
+impl selectable_text::Renderer for Renderer {
+    fn offset_at(
+        &self,
+        bounds: Rectangle,
+        content: &str,
+        size: u16,
+        font: Font,
+        params: text::Params,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+        point: Point,
+    ) -> usize {
+        let section = section(
+            self,
+            bounds,
+            content,
+            size,
+            font,
+            horizontal_alignment,
+            vertical_alignment,
+        );
+
+        self.text_pipeline.offset_at(&section, params, point)
+    }
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        content: &str,
+        size: u16,
+        font: Font,
+        params: text::Params,
+        color: Option<Color>,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+        selection: Option<Range<usize>>,
+    ) -> Self::Output {
+        let mut primitives: Vec<Primitive> = selection
+            .map(|range| {
+                // Laid out exactly like the primitive, so the highlight
+                // covers the drawn glyphs
+                let section = section(
+                    self,
+                    bounds,
+                    content,
+                    size,
+                    font,
+                    horizontal_alignment,
+                    vertical_alignment,
+                );
+
+                self.text_pipeline.selection(&section, params, range)
+            })
+            .unwrap_or_default()
+            .into_iter()
+            .map(|bounds| Primitive::Quad {
+                bounds,
+                background: Background::Color(SELECTION_COLOR),
+                border_radius: 0,
+                shadow: None,
+            })
+            .collect();
+
+        primitives.push(Primitive::Text {
+            content: self.arena.string(content),
+            size: f32::from(size),
+            bounds,
+            color: color.unwrap_or(Color::BLACK),
+            font,
+            params,
+            horizontal_alignment,
+            vertical_alignment,
+        });
+
+        (
+            Primitive::Group { primitives },
+            if bounds.contains(cursor_position) {
+                MouseCursor::Text
+            } else {
+                MouseCursor::OutOfBounds
+            },
+        )
+    }
+}
+
+fn section<'a>(
+    renderer: &Renderer,
+    bounds: Rectangle,
+    content: &'a str,
+    size: u16,
+    font: Font,
+    horizontal_alignment: HorizontalAlignment,
+    vertical_alignment: VerticalAlignment,
+) -> wgpu_glyph::VariedSection<'a> {
+    let (screen_position, layout) = crate::renderer::align_text(
+        bounds,
+        horizontal_alignment,
+        vertical_alignment,
+    );
+
+    wgpu_glyph::VariedSection {
+        screen_position,
+        bounds: (bounds.width, bounds.height),
+        layout,
+        text: vec![renderer.text_pipeline.span(
+            content,
+            f32::from(size),
+            font,
+            None,
+            [0.0, 0.0, 0.0, 1.0],
+        )],
+        ..Default::default()
+    }
+}
@@ -1,8 +1,12 @@
 use crate::{Primitive, Renderer};
-use iced_native::{slider, Background, Color, MouseCursor, Point, Rectangle};
+use iced_native::{
+    slider::{self, Axis},
+    Background, Color, MouseCursor, Point, Rectangle,
+};
 
 const HANDLE_WIDTH: f32 = 8.0;
 const HANDLE_HEIGHT: f32 = 22.0;
+const TICK_LENGTH: f32 = 3.0;
 
 impl slider::Renderer for Renderer {
     fn height(&self) -> u32 {
@@ -13,60 +17,96 @@ impl slider::Renderer for Renderer {
         &mut self,
         bounds: Rectangle,
         cursor_position: Point,
-        range: std::ops::RangeInclusive<f32>,
-        value: f32,
+        track: &slider::Track,
         is_dragging: bool,
+        is_focused: bool,
     ) -> Self::Output {
         let is_mouse_over = bounds.contains(cursor_position);
 
-        let rail_y = bounds.y + (bounds.height / 2.0).round();
+        // Lay everything out horizontally, then flip it for vertical sliders
+        let rail = Rail::new(track.axis, bounds);
 
-        let (rail_top, rail_bottom) = (
+        let (range_start, range_end) =
+            (*track.range.start(), *track.range.end());
+
+        let offset = |value: f32| {
+            let span = range_end - range_start;
+
+            if span > 0.0 {
+                (rail.length - HANDLE_WIDTH) * ((value - range_start) / span)
+            } else {
+                0.0
+            }
+        };
+
+        let rail_y = (rail.thickness / 2.0).round();
+
+        let mut primitives = vec![
             Primitive::Quad {
-                bounds: Rectangle {
-                    x: bounds.x,
-                    y: rail_y,
-                    width: bounds.width,
-                    height: 2.0,
-                },
+                bounds: rail.place(0.0, rail_y, rail.length, 2.0),
                 background: Color::from_rgb(0.6, 0.6, 0.6).into(),
                 border_radius: 0,
+                shadow: None,
             },
             Primitive::Quad {
-                bounds: Rectangle {
-                    x: bounds.x,
-                    y: rail_y + 2.0,
-                    width: bounds.width,
-                    height: 2.0,
-                },
+                bounds: rail.place(0.0, rail_y + 2.0, rail.length, 2.0),
                 background: Background::Color(Color::WHITE),
                 border_radius: 0,
+                shadow: None,
             },
-        );
+        ];
 
-        let (range_start, range_end) = range.into_inner();
+        if let [first, .., last] = track.handles[..] {
+            let start = offset(first) + HANDLE_WIDTH / 2.0;
+            let end = offset(last) + HANDLE_WIDTH / 2.0;
 
-        let handle_offset = (bounds.width - HANDLE_WIDTH)
-            * ((value - range_start) / (range_end - range_start).max(1.0));
+            primitives.push(Primitive::Quad {
+                bounds: rail.place(start, rail_y, end - start, 4.0),
+                background: Color::from_rgb(0.20, 0.60, 0.86).into(),
+                border_radius: 0,
+                shadow: None,
+            });
+        }
 
-        let (handle_border, handle) = (
-            Primitive::Quad {
-                bounds: Rectangle {
-                    x: bounds.x + handle_offset.round() - 1.0,
-                    y: rail_y - HANDLE_HEIGHT / 2.0 - 1.0,
-                    width: HANDLE_WIDTH + 2.0,
-                    height: HANDLE_HEIGHT + 2.0,
-                },
-                background: Color::from_rgb(0.6, 0.6, 0.6).into(),
+        primitives.extend(track.ticks.iter().map(|tick| Primitive::Quad {
+            bounds: rail.place(
+                (offset(*tick) + HANDLE_WIDTH / 2.0).round(),
+                rail_y + HANDLE_HEIGHT / 2.0 + 1.0,
+                1.0,
+                TICK_LENGTH,
+            ),
+            background: Color::from_rgb(0.6, 0.6, 0.6).into(),
+            border_radius: 0,
+            shadow: None,
+        }));
+
+        for value in &track.handles {
+            let handle_offset = offset(*value).round();
+
+            primitives.push(Primitive::Quad {
+                bounds: rail.place(
+                    handle_offset - 1.0,
+                    rail_y - HANDLE_HEIGHT / 2.0 - 1.0,
+                    HANDLE_WIDTH + 2.0,
+                    HANDLE_HEIGHT + 2.0,
+                ),
+                background: if is_focused {
+                    Color::from_rgb(0.20, 0.60, 0.86)
+                } else {
+                    Color::from_rgb(0.6, 0.6, 0.6)
+                }
+                .into(),
                 border_radius: 5,
-            },
-            Primitive::Quad {
-                bounds: Rectangle {
-                    x: bounds.x + handle_offset.round(),
-                    y: rail_y - HANDLE_HEIGHT / 2.0,
-                    width: HANDLE_WIDTH,
-                    height: HANDLE_HEIGHT,
-                },
+                shadow: None,
+            });
+
+            primitives.push(Primitive::Quad {
+                bounds: rail.place(
+                    handle_offset,
+                    rail_y - HANDLE_HEIGHT / 2.0,
+                    HANDLE_WIDTH,
+                    HANDLE_HEIGHT,
+                ),
                 background: Background::Color(
                     if is_dragging {
                         [0.85, 0.85, 0.85]
@@ -78,13 +118,12 @@ impl slider::Renderer for Renderer {
                     .into(),
                 ),
                 border_radius: 4,
-            },
-        );
+                shadow: None,
+            });
+        }
 
         (
-            Primitive::Group {
-                primitives: vec![rail_top, rail_bottom, handle_border, handle],
-            },
+            Primitive::Group { primitives },
             if is_dragging {
                 MouseCursor::Grabbing
             } else if is_mouse_over {
@@ -95,3 +134,52 @@ impl slider::Renderer for Renderer {
         )
     }
 }
+
+// The bounds of a slider, seen as if it was horizontal
+struct Rail {
+    axis: Axis,
+    bounds: Rectangle,
+    length: f32,
+    thickness: f32,
+}
+
+impl Rail {
+    fn new(axis: Axis, bounds: Rectangle) -> Self {
+        let (length, thickness) = match axis {
+            Axis::Horizontal => (bounds.width, bounds.height),
+            Axis::Vertical => (bounds.height, bounds.width),
+        };
+
+        Rail {
+            axis,
+            bounds,
+            length,
+            thickness,
+        }
+    }
+
+    // Places a rectangle given along the rail and across it. Vertical rails
+    // start at the bottom.
+    fn place(
+        &self,
+        along: f32,
+        across: f32,
+        length: f32,
+        size: f32,
+    ) -> Rectangle {
+        match self.axis {
+            Axis::Horizontal => Rectangle {
+                x: self.bounds.x + along,
+                y: self.bounds.y + across,
+                width: length,
+                height: size,
+            },
+            Axis::Vertical => Rectangle {
+                x: self.bounds.x + across,
+                y: self.bounds.y + self.bounds.height - along - length,
+                width: size,
+                height: length,
+            },
+        }
+    }
+}
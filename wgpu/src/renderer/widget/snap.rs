@@ -0,0 +1,47 @@
+use crate::{Primitive, Renderer};
+use iced_native::{snap, Background, Color, MouseCursor};
+
+const GUIDE_THICKNESS: f32 = 1.0;
+
+const GUIDE_COLOR: Color = Color {
+    r: 0.9,
+    g: 0.2,
+    b: 0.6,
+    a: 1.0,
+};
+
+const PREVIEW_COLOR: Color = Color {
+    r: 0.2,
+    g: 0.5,
+    b: 1.0,
+    a: 0.2,
+};
+
+impl snap::Renderer for Renderer {
+    fn guide_thickness(&self) -> f32 {
+        GUIDE_THICKNESS
+    }
+
+    fn draw_snap(&mut self, snap: &snap::Snap) -> Self::Output {
+        let preview = Primitive::Quad {
+            bounds: snap.bounds,
+            background: Background::Color(PREVIEW_COLOR),
+            border_radius: 0,
+            shadow: None,
+        };
+
+        let guides = snap.guides.iter().map(|guide| Primitive::Quad {
+            bounds: guide.bounds(GUIDE_THICKNESS),
+            background: Background::Color(GUIDE_COLOR),
+            border_radius: 0,
+            shadow: None,
+        });
+
+        (
+            Primitive::Group {
+                primitives: std::iter::once(preview).chain(guides).collect(),
+            },
+            MouseCursor::OutOfBounds,
+        )
+    }
+}
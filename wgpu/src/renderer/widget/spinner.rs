@@ -0,0 +1,50 @@
+use crate::{Primitive, Renderer};
+use iced_native::{spinner, Background, Color, MouseCursor, Rectangle};
+
+use std::f32::consts::PI;
+
+// The amount of dots around the circle of a spinner
+const DOTS: usize = 8;
+
+impl spinner::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        phase: f32,
+        color: Color,
+    ) -> Self::Output {
+        let size = bounds.width.min(bounds.height);
+        let dot = size / 5.0;
+        let radius = (size - dot) / 2.0;
+
+        let center_x = bounds.x + bounds.width / 2.0;
+        let center_y = bounds.y + bounds.height / 2.0;
+
+        // The leading dot moves along the circle, the rest fade behind it
+        let head = phase * DOTS as f32;
+
+        let primitives = (0..DOTS)
+            .map(|i| {
+                let angle = 2.0 * PI * i as f32 / DOTS as f32 - PI / 2.0;
+                let behind = (head - i as f32).rem_euclid(DOTS as f32);
+
+                Primitive::Quad {
+                    bounds: Rectangle {
+                        x: center_x + radius * angle.cos() - dot / 2.0,
+                        y: center_y + radius * angle.sin() - dot / 2.0,
+                        width: dot,
+                        height: dot,
+                    },
+                    background: Background::Color(Color {
+                        a: color.a * (1.0 - behind / DOTS as f32).max(0.15),
+                        ..color
+                    }),
+                    border_radius: (dot / 2.0) as u16,
+                    shadow: None,
+                }
+            })
+            .collect();
+
+        (Primitive::Group { primitives }, MouseCursor::OutOfBounds)
+    }
+}
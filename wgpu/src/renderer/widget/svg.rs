@@ -1,5 +1,5 @@
 use crate::{Primitive, Renderer};
-use iced_native::{svg, Layout, MouseCursor};
+use iced_native::{svg, Color, Layout, MouseCursor};
 
 impl svg::Renderer for Renderer {
     fn dimensions(&self, handle: &svg::Handle) -> (u32, u32) {
@@ -9,12 +9,15 @@ impl svg::Renderer for Renderer {
     fn draw(
         &mut self,
         handle: svg::Handle,
+        color: Option<Color>,
         layout: Layout<'_>,
     ) -> Self::Output {
         (
             Primitive::Svg {
                 handle,
                 bounds: layout.bounds(),
+                border_radius: 0,
+                color,
             },
             MouseCursor::OutOfBounds,
         )
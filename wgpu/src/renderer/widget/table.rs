@@ -0,0 +1,124 @@
+use crate::{Primitive, Renderer};
+use iced_native::{
+    table, text, Background, Color, Font, HorizontalAlignment, MouseCursor,
+    Point, Rectangle, VerticalAlignment,
+};
+
+const TEXT_SIZE: f32 = 14.0;
+const PADDING: f32 = 8.0;
+
+impl table::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        regions: &[table::Region],
+        is_resizing: bool,
+        editor: Option<Self::Output>,
+    ) -> Self::Output {
+        let background = Primitive::Quad {
+            bounds,
+            background: Background::Color([0.85, 0.85, 0.85].into()),
+            border_radius: 0,
+            shadow: None,
+        };
+
+        let mut primitives = self.arena.group();
+        primitives.push(background);
+
+        for region in regions {
+            let mut cells = self.arena.group();
+            cells.reserve(region.cells.len() * 2);
+
+            for cell in &region.cells {
+                let is_header = cell.row.is_none();
+
+                // The background of the table peeks through the gap between
+                // cells, drawing the grid lines
+                cells.push(Primitive::Quad {
+                    bounds: Rectangle {
+                        width: cell.bounds.width - 1.0,
+                        height: cell.bounds.height - 1.0,
+                        ..cell.bounds
+                    },
+                    background: Background::Color(match cell.row {
+                        _ if cell.is_selected => [0.85, 0.91, 0.98].into(),
+                        None => [0.93, 0.93, 0.93].into(),
+                        Some(row) if row % 2 == 1 => [0.98, 0.98, 0.98].into(),
+                        Some(_) => Color::WHITE,
+                    }),
+                    border_radius: 0,
+                    shadow: None,
+                });
+
+                cells.push(Primitive::Text {
+                    content: self.arena.string(&cell.content),
+                    bounds: Rectangle {
+                        x: cell.bounds.x + PADDING,
+                        width: (cell.bounds.width - 2.0 * PADDING).max(0.0),
+                        ..cell.bounds
+                    },
+                    color: if is_header {
+                        [0.3, 0.3, 0.3].into()
+                    } else {
+                        Color::BLACK
+                    },
+                    size: TEXT_SIZE,
+                    font: Font::Default,
+                    params: text::Params::default(),
+                    horizontal_alignment: HorizontalAlignment::Left,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
+
+                if let Some(order) = cell.sort {
+                    cells.push(Primitive::Text {
+                        content: self.arena.string(match order {
+                            table::Order::Ascending => "\u{2191}",
+                            table::Order::Descending => "\u{2193}",
+                        }),
+                        bounds: Rectangle {
+                            width: (cell.bounds.width - PADDING).max(0.0),
+                            ..cell.bounds
+                        },
+                        color: [0.3, 0.3, 0.3].into(),
+                        size: TEXT_SIZE,
+                        font: Font::Default,
+                        params: text::Params::default(),
+                        horizontal_alignment: HorizontalAlignment::Right,
+                        vertical_alignment: VerticalAlignment::Center,
+                    });
+                }
+            }
+
+            primitives.push(Primitive::Clip {
+                bounds: region.bounds,
+                offset: region.offset,
+                content: self
+                    .arena
+                    .boxed(Primitive::Group { primitives: cells }),
+            });
+        }
+
+        let mouse_cursor = match editor {
+            Some((editor, mouse_cursor)) => {
+                primitives.push(editor);
+
+                mouse_cursor
+            }
+            None => MouseCursor::OutOfBounds,
+        };
+
+        (
+            Primitive::Group { primitives },
+            if is_resizing {
+                MouseCursor::ResizingHorizontally
+            } else if mouse_cursor > MouseCursor::Idle {
+                mouse_cursor
+            } else if bounds.contains(cursor_position) {
+                MouseCursor::Idle
+            } else {
+                MouseCursor::OutOfBounds
+            },
+        )
+    }
+}
@@ -0,0 +1,169 @@
+use crate::{Primitive, Renderer};
+use iced_native::{
+    image, tabs, text, Background, Font, HorizontalAlignment, MouseCursor,
+    Point, Rectangle, Vector, VerticalAlignment,
+};
+
+const TEXT_SIZE: f32 = 14.0;
+const PADDING: f32 = 10.0;
+const ICON_SIZE: f32 = 16.0;
+const FOCUS_WIDTH: f32 = 2.0;
+
+impl tabs::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        strip: Rectangle,
+        cursor_position: Point,
+        tabs: &[tabs::Tab],
+        headers: &[tabs::Header],
+        is_focused: bool,
+        style: &tabs::Style,
+        (content, mouse_cursor): Self::Output,
+    ) -> Self::Output {
+        let mut primitives = self.arena.group();
+        primitives.push(content);
+        primitives.push(Primitive::Quad {
+            bounds: strip,
+            background: Background::Color(style.background),
+            border_radius: 0,
+            shadow: None,
+        });
+
+        let mut strip_primitives = self.arena.group();
+        strip_primitives.reserve(headers.len() * 4);
+
+        for header in headers {
+            let tab = &tabs[header.index];
+            let bounds = header.bounds;
+
+            // The background of the strip peeks through the gap between tabs
+            strip_primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    width: bounds.width - 1.0,
+                    ..bounds
+                },
+                background: Background::Color(if header.is_active {
+                    style.active_background
+                } else if header.is_hovered {
+                    style.hovered_background
+                } else {
+                    style.tab_background
+                }),
+                border_radius: 0,
+                shadow: None,
+            });
+
+            let mut label_x = bounds.x + PADDING;
+
+            if let Some(icon) = &tab.icon {
+                strip_primitives.push(Primitive::Image {
+                    handle: icon.clone(),
+                    bounds: Rectangle {
+                        x: label_x,
+                        y: bounds.y + (bounds.height - ICON_SIZE) / 2.0,
+                        width: ICON_SIZE,
+                        height: ICON_SIZE,
+                    },
+                    border_radius: 0,
+                    fit: image::Fit::Contain,
+                });
+
+                label_x += ICON_SIZE + PADDING / 2.0;
+            }
+
+            let label_right = header
+                .close_bounds
+                .map(|close| close.x)
+                .unwrap_or(bounds.x + bounds.width - PADDING);
+
+            strip_primitives.push(Primitive::Clip {
+                bounds: Rectangle {
+                    x: label_x,
+                    width: (label_right - label_x).max(0.0),
+                    ..bounds
+                },
+                offset: Vector::new(0, 0),
+                content: self.arena.boxed(Primitive::Text {
+                    content: self.arena.string(&tab.label),
+                    bounds: Rectangle {
+                        x: label_x,
+                        width: (label_right - label_x).max(0.0),
+                        ..bounds
+                    },
+                    color: style.text_color,
+                    size: TEXT_SIZE,
+                    font: Font::Default,
+                    params: text::Params::default(),
+                    horizontal_alignment: HorizontalAlignment::Left,
+                    vertical_alignment: VerticalAlignment::Center,
+                }),
+            });
+
+            if let Some(close) = header.close_bounds {
+                if header.is_close_hovered {
+                    strip_primitives.push(Primitive::Quad {
+                        bounds: close,
+                        background: Background::Color(style.background),
+                        border_radius: 3,
+                        shadow: None,
+                    });
+                }
+
+                // Closing inactive tabs is less common, so their buttons
+                // only show up under the mouse
+                if header.is_active || header.is_hovered {
+                    strip_primitives.push(Primitive::Text {
+                        content: self.arena.string("\u{00D7}"),
+                        bounds: close,
+                        color: style.text_color,
+                        size: TEXT_SIZE,
+                        font: Font::Default,
+                        params: text::Params::default(),
+                        horizontal_alignment: HorizontalAlignment::Center,
+                        vertical_alignment: VerticalAlignment::Center,
+                    });
+                }
+            }
+
+            if is_focused && header.is_active {
+                strip_primitives.push(Primitive::Quad {
+                    bounds: Rectangle {
+                        y: bounds.y + bounds.height - FOCUS_WIDTH,
+                        height: FOCUS_WIDTH,
+                        ..bounds
+                    },
+                    background: Background::Color(style.focus_color),
+                    border_radius: 0,
+                    shadow: None,
+                });
+            }
+        }
+
+        primitives.push(Primitive::Clip {
+            bounds: strip,
+            offset: Vector::new(0, 0),
+            content: self.arena.boxed(Primitive::Group {
+                primitives: strip_primitives,
+            }),
+        });
+
+        let is_dragging = headers.iter().any(|header| header.is_dragged);
+        let is_over_tab = headers.iter().any(|header| {
+            strip.contains(cursor_position)
+                && header.bounds.contains(cursor_position)
+        });
+
+        (
+            Primitive::Group { primitives },
+            if is_dragging {
+                MouseCursor::Grabbing
+            } else if is_over_tab {
+                MouseCursor::Pointer
+            } else if strip.contains(cursor_position) {
+                MouseCursor::Idle
+            } else {
+                mouse_cursor
+            },
+        )
+    }
+}
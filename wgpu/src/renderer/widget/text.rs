@@ -1,7 +1,7 @@
 use crate::{Primitive, Renderer};
 use iced_native::{
-    text, Color, Font, HorizontalAlignment, MouseCursor, Rectangle, Size,
-    VerticalAlignment,
+    text, Color, Font, HorizontalAlignment, MouseCursor, Rectangle, Shadow,
+    Size, VerticalAlignment,
 };
 
 use std::f32;
@@ -19,10 +19,20 @@ impl text::Renderer for Renderer {
         content: &str,
         size: u16,
         font: Font,
+        params: text::Params,
         bounds: Size,
     ) -> (f32, f32) {
-        self.text_pipeline
-            .measure(content, f32::from(size), font, bounds)
+        self.text_pipeline.measure(
+            content,
+            f32::from(size),
+            font,
+            params,
+            bounds,
+        )
+    }
+
+    fn baseline(&self, size: u16, font: Font) -> f32 {
+        self.text_pipeline.v_metrics(f32::from(size), font).ascent
     }
 
     fn draw(
@@ -31,21 +41,41 @@ impl text::Renderer for Renderer {
         content: &str,
         size: u16,
         font: Font,
+        params: text::Params,
         color: Option<Color>,
+        shadow: Option<Shadow>,
         horizontal_alignment: HorizontalAlignment,
         vertical_alignment: VerticalAlignment,
     ) -> Self::Output {
-        (
-            Primitive::Text {
-                content: content.to_string(),
-                size: f32::from(size),
-                bounds,
-                color: color.unwrap_or(Color::BLACK),
-                font,
-                horizontal_alignment,
-                vertical_alignment,
+        let mut text = |bounds, color| Primitive::Text {
+            content: self.arena.string(content),
+            size: f32::from(size),
+            bounds,
+            color,
+            font,
+            params,
+            horizontal_alignment,
+            vertical_alignment,
+        };
+
+        let primitive = match shadow {
+            // TODO: Blur the shadow once we have a blur pass
+            Some(shadow) => Primitive::Group {
+                primitives: vec![
+                    text(
+                        Rectangle {
+                            x: bounds.x + shadow.offset.x,
+                            y: bounds.y + shadow.offset.y,
+                            ..bounds
+                        },
+                        shadow.color,
+                    ),
+                    text(bounds, color.unwrap_or(Color::BLACK)),
+                ],
             },
-            MouseCursor::OutOfBounds,
-        )
+            None => text(bounds, color.unwrap_or(Color::BLACK)),
+        };
+
+        (primitive, MouseCursor::OutOfBounds)
     }
 }
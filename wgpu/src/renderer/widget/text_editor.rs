@@ -0,0 +1,185 @@
+use super::{rich_text::section_text, selectable_text::SELECTION_COLOR};
+use crate::{Primitive, Renderer, Span};
+use iced_native::{
+    rich_text, text, text_editor, Background, Color, Font, HorizontalAlignment,
+    MouseCursor, Point, Rectangle, Vector, VerticalAlignment,
+};
+
+use std::f32;
+
+const TEXT_COLOR: Color = Color {
+    r: 0.3,
+    g: 0.3,
+    b: 0.3,
+    a: 1.0,
+};
+
+impl text_editor::Renderer for Renderer {
+    fn line_height(&self, size: u16, font: Font) -> f32 {
+        let metrics = self.text_pipeline.v_metrics(f32::from(size), font);
+
+        metrics.ascent - metrics.descent + metrics.line_gap
+    }
+
+    fn caret_position(&self, spans: &[rich_text::Span], offset: usize) -> f32 {
+        if offset == 0 {
+            return 0.0;
+        }
+
+        // The selection covers the advance of trailing whitespace, unlike
+        // the measured bounds of the glyphs
+        self.text_pipeline
+            .selection(&section(self, spans), params(), 0..offset)
+            .iter()
+            .map(|rectangle| rectangle.x + rectangle.width)
+            .fold(0.0, f32::max)
+    }
+
+    fn offset_at(&self, spans: &[rich_text::Span], x: f32) -> usize {
+        self.text_pipeline.offset_at(
+            &section(self, spans),
+            params(),
+            Point::new(x, 0.0),
+        )
+    }
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        lines: &[text_editor::Line],
+        selection: &[Rectangle],
+        caret: Option<Rectangle>,
+        is_focused: bool,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+        let default_size = text::Renderer::default_size(self);
+
+        let border = Primitive::Quad {
+            bounds,
+            background: Background::Color(
+                if is_mouse_over || is_focused {
+                    [0.5, 0.5, 0.5]
+                } else {
+                    [0.7, 0.7, 0.7]
+                }
+                .into(),
+            ),
+            border_radius: 5,
+            shadow: None,
+        };
+
+        let editor = Primitive::Quad {
+            bounds: Rectangle {
+                x: bounds.x + 1.0,
+                y: bounds.y + 1.0,
+                width: bounds.width - 2.0,
+                height: bounds.height - 2.0,
+            },
+            background: Background::Color(Color::WHITE),
+            border_radius: 4,
+            shadow: None,
+        };
+
+        let quad = |bounds, color| Primitive::Quad {
+            bounds,
+            background: Background::Color(color),
+            border_radius: 0,
+            shadow: None,
+        };
+
+        let mut content: Vec<Primitive> = lines
+            .iter()
+            .flat_map(|line| line.backgrounds.iter())
+            .map(|(bounds, color)| quad(*bounds, *color))
+            .chain(
+                selection
+                    .iter()
+                    .map(|bounds| quad(*bounds, SELECTION_COLOR)),
+            )
+            .collect();
+
+        let arena = &mut self.arena;
+
+        content.extend(lines.iter().map(|line| {
+            Primitive::Paragraph {
+                spans: line
+                    .spans
+                    .iter()
+                    .map(|span| Span {
+                        content: arena.string(&span.content),
+                        size: f32::from(span.size.unwrap_or(default_size)),
+                        font: span.font,
+                        weight: span.weight,
+                        color: span.color.unwrap_or(TEXT_COLOR),
+                    })
+                    .collect(),
+                bounds: line.bounds,
+                params: params(),
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Top,
+            }
+        }));
+
+        if let Some(caret) = caret {
+            content.push(quad(caret, Color::BLACK));
+        }
+
+        // Long lines are cut at the border instead of overflowing
+        let content = Primitive::Clip {
+            bounds: Rectangle {
+                x: bounds.x + 1.0,
+                y: bounds.y + 1.0,
+                width: bounds.width - 2.0,
+                height: bounds.height - 2.0,
+            },
+            offset: Vector::new(0, 0),
+            content: self.arena.boxed(Primitive::Group {
+                primitives: content,
+            }),
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![border, editor, content],
+            },
+            if is_mouse_over {
+                MouseCursor::Text
+            } else {
+                MouseCursor::OutOfBounds
+            },
+        )
+    }
+}
+
+fn params() -> text::Params {
+    text::Params {
+        wrap: text::Wrap::None,
+        ..text::Params::default()
+    }
+}
+
+/// Lays out a line of a text editor at the origin, like it is drawn.
+fn section<'a>(
+    renderer: &Renderer,
+    spans: &'a [rich_text::Span],
+) -> wgpu_glyph::VariedSection<'a> {
+    let (screen_position, layout) = crate::renderer::align_text(
+        Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: f32::INFINITY,
+            height: f32::INFINITY,
+        },
+        HorizontalAlignment::Left,
+        VerticalAlignment::Top,
+    );
+
+    wgpu_glyph::VariedSection {
+        screen_position,
+        bounds: (f32::INFINITY, f32::INFINITY),
+        layout,
+        text: section_text(renderer, spans),
+        ..Default::default()
+    }
+}
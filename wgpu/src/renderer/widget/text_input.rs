@@ -1,10 +1,11 @@
+use super::selectable_text::SELECTION_COLOR;
 use crate::{Primitive, Renderer};
 
 use iced_native::{
-    text_input, Background, Color, Font, HorizontalAlignment, MouseCursor,
-    Point, Rectangle, Size, Vector, VerticalAlignment,
+    text, text_input, Background, Color, Font, HorizontalAlignment,
+    MouseCursor, Point, Rectangle, Size, Vector, VerticalAlignment,
 };
-use std::f32;
+use std::{f32, ops::Range};
 
 impl text_input::Renderer for Renderer {
     fn default_size(&self) -> u16 {
@@ -12,11 +13,16 @@ impl text_input::Renderer for Renderer {
         20
     }
 
+    fn default_caret(&self) -> text_input::Caret {
+        text_input::Caret::default()
+    }
+
     fn measure_value(&self, value: &str, size: u16) -> f32 {
         let (mut width, _) = self.text_pipeline.measure(
             value,
             f32::from(size),
             Font::Default,
+            text::Params::default(),
             Size::INFINITY,
         );
 
@@ -30,6 +36,16 @@ impl text_input::Renderer for Renderer {
         width
     }
 
+    fn baseline(&self, size: u16) -> f32 {
+        let metrics =
+            self.text_pipeline.v_metrics(f32::from(size), Font::Default);
+
+        // The value is drawn vertically centered in the text bounds
+        let line_height = metrics.ascent - metrics.descent + metrics.line_gap;
+
+        (f32::from(size) - line_height) / 2.0 + metrics.ascent
+    }
+
     fn offset(
         &self,
         text_bounds: Rectangle,
@@ -38,12 +54,19 @@ impl text_input::Renderer for Renderer {
         state: &text_input::State,
     ) -> f32 {
         if state.is_focused() {
+            let composition = compose(value, state);
+
+            let (value, cursor_index) = match &composition {
+                Some((composed, range)) => (composed, range.end),
+                None => (value, state.cursor_position(value)),
+            };
+
             let (_, offset) = measure_cursor_and_scroll_offset(
                 self,
                 text_bounds,
                 value,
                 size,
-                state.cursor_position(value),
+                cursor_index,
             );
 
             offset
@@ -61,9 +84,18 @@ impl text_input::Renderer for Renderer {
         placeholder: &str,
         value: &text_input::Value,
         state: &text_input::State,
+        caret: Option<text_input::Caret>,
+        misspellings: &[text_input::Misspelling],
     ) -> Self::Output {
         let is_mouse_over = bounds.contains(cursor_position);
 
+        let composition = compose(value, state);
+
+        let (value, cursor_index) = match &composition {
+            Some((composed, range)) => (composed, range.end),
+            None => (value, state.cursor_position(value)),
+        };
+
         let border = Primitive::Quad {
             bounds,
             background: Background::Color(
@@ -75,6 +107,7 @@ impl text_input::Renderer for Renderer {
                 .into(),
             ),
             border_radius: 5,
+            shadow: None,
         };
 
         let input = Primitive::Quad {
@@ -86,6 +119,7 @@ impl text_input::Renderer for Renderer {
             },
             background: Background::Color(Color::WHITE),
             border_radius: 4,
+            shadow: None,
         };
 
         let text = value.to_string();
@@ -103,6 +137,7 @@ impl text_input::Renderer for Renderer {
             }
             .into(),
             font: Font::Default,
+            params: text::Params::default(),
             bounds: Rectangle {
                 width: f32::INFINITY,
                 ..text_bounds
@@ -112,30 +147,145 @@ impl text_input::Renderer for Renderer {
             vertical_alignment: VerticalAlignment::Center,
         };
 
+        let renderer: &Self = self;
+
+        let underlines = misspellings
+            .iter()
+            .filter(|misspelling| misspelling.range.end <= value.len())
+            .flat_map(|misspelling| {
+                let start = text_input::Renderer::measure_value(
+                    renderer,
+                    &value.until(misspelling.range.start).to_string(),
+                    size,
+                );
+                let end = text_input::Renderer::measure_value(
+                    renderer,
+                    &value.until(misspelling.range.end).to_string(),
+                    size,
+                );
+
+                squiggle(
+                    text_bounds.x + start,
+                    text_bounds.x + end,
+                    text_bounds.y + text_bounds.height,
+                )
+            });
+
+        // The text being composed is underlined
+        let composition_underline = composition.as_ref().map(|(_, range)| {
+            let start = text_input::Renderer::measure_value(
+                renderer,
+                &value.until(range.start).to_string(),
+                size,
+            );
+            let end = text_input::Renderer::measure_value(
+                renderer,
+                &value.until(range.end).to_string(),
+                size,
+            );
+
+            Primitive::Quad {
+                bounds: Rectangle {
+                    x: text_bounds.x + start,
+                    y: text_bounds.y + text_bounds.height - 1.0,
+                    width: end - start,
+                    height: 1.0,
+                },
+                background: Background::Color([0.3, 0.3, 0.3].into()),
+                border_radius: 0,
+                shadow: None,
+            }
+        });
+
+        let text_value = Primitive::Group {
+            primitives: std::iter::once(text_value)
+                .chain(underlines)
+                .chain(composition_underline)
+                .collect(),
+        };
+
         let (contents_primitive, offset) = if state.is_focused() {
             let (text_value_width, offset) = measure_cursor_and_scroll_offset(
                 self,
                 text_bounds,
                 value,
                 size,
-                state.cursor_position(value),
+                cursor_index,
             );
 
-            let cursor = Primitive::Quad {
-                bounds: Rectangle {
-                    x: text_bounds.x + text_value_width,
-                    y: text_bounds.y,
-                    width: 1.0,
-                    height: text_bounds.height,
-                },
-                background: Background::Color(Color::BLACK),
-                border_radius: 0,
+            let highlight = state.selection(value).map(|selection| {
+                let start = text_input::Renderer::measure_value(
+                    self,
+                    &value.until(selection.start).to_string(),
+                    size,
+                );
+                let end = text_input::Renderer::measure_value(
+                    self,
+                    &value.until(selection.end).to_string(),
+                    size,
+                );
+
+                Primitive::Quad {
+                    bounds: Rectangle {
+                        x: text_bounds.x + start,
+                        y: text_bounds.y,
+                        width: end - start,
+                        height: text_bounds.height,
+                    },
+                    background: Background::Color(SELECTION_COLOR),
+                    border_radius: 0,
+                    shadow: None,
+                }
+            });
+
+            let mut primitives = match caret {
+                Some(caret) => {
+                    let width = match caret.shape {
+                        text_input::CaretShape::Bar => caret.width,
+                        text_input::CaretShape::Block
+                            if cursor_index < value.len() =>
+                        {
+                            text_input::Renderer::measure_value(
+                                self,
+                                &value.until(cursor_index + 1).to_string(),
+                                size,
+                            ) - text_value_width
+                        }
+                        text_input::CaretShape::Block => f32::from(size) / 2.0,
+                    };
+
+                    let caret_primitive = Primitive::Quad {
+                        bounds: Rectangle {
+                            x: text_bounds.x + text_value_width,
+                            y: text_bounds.y,
+                            width,
+                            height: text_bounds.height,
+                        },
+                        background: Background::Color(caret.color),
+                        border_radius: 0,
+                        shadow: None,
+                    };
+
+                    // A block is drawn below the character it covers
+                    match caret.shape {
+                        text_input::CaretShape::Bar => {
+                            vec![text_value, caret_primitive]
+                        }
+                        text_input::CaretShape::Block => {
+                            vec![caret_primitive, text_value]
+                        }
+                    }
+                }
+                None => vec![text_value],
             };
 
+            // The selection is drawn below the text
+            if let Some(highlight) = highlight {
+                primitives.insert(0, highlight);
+            }
+
             (
-                Primitive::Group {
-                    primitives: vec![text_value, cursor],
-                },
+                Primitive::Group { primitives },
                 Vector::new(offset as u32, 0),
             )
         } else {
@@ -159,6 +309,109 @@ impl text_input::Renderer for Renderer {
             },
         )
     }
+
+    fn draw_menu(
+        &mut self,
+        cursor_position: Point,
+        menu: &text_input::Menu<'_>,
+    ) -> Self::Output {
+        let mut primitives = vec![
+            Primitive::Quad {
+                bounds: menu.bounds,
+                background: Background::Color([0.7, 0.7, 0.7].into()),
+                border_radius: 0,
+                shadow: None,
+            },
+            Primitive::Quad {
+                bounds: Rectangle {
+                    x: menu.bounds.x + 1.0,
+                    y: menu.bounds.y + 1.0,
+                    width: menu.bounds.width - 2.0,
+                    height: menu.bounds.height - 2.0,
+                },
+                background: Background::Color(Color::WHITE),
+                border_radius: 0,
+                shadow: None,
+            },
+        ];
+
+        let hovered = menu.item_at(cursor_position);
+
+        for (i, suggestion) in menu.suggestions.iter().enumerate() {
+            let bounds = menu.item_bounds(i);
+
+            if hovered == Some(i) {
+                primitives.push(Primitive::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + 1.0,
+                        width: bounds.width - 2.0,
+                        ..bounds
+                    },
+                    background: Background::Color([0.9, 0.9, 0.9].into()),
+                    border_radius: 0,
+                    shadow: None,
+                });
+            }
+
+            primitives.push(Primitive::Text {
+                content: suggestion.clone(),
+                bounds,
+                color: [0.3, 0.3, 0.3].into(),
+                size: f32::from(menu.text_size),
+                font: Font::Default,
+                params: text::Params::default(),
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+        }
+
+        (
+            Primitive::Group { primitives },
+            if hovered.is_some() {
+                MouseCursor::Pointer
+            } else {
+                MouseCursor::OutOfBounds
+            },
+        )
+    }
+}
+
+/// Returns the value of a text input with the text being composed with an
+/// input method editor inserted at its cursor, along with the range of the
+/// composed graphemes.
+fn compose(
+    value: &text_input::Value,
+    state: &text_input::State,
+) -> Option<(text_input::Value, Range<usize>)> {
+    let preedit = text_input::Value::new(state.preedit()?);
+    let cursor_index = state.cursor_position(value);
+
+    let range = cursor_index..cursor_index + preedit.len();
+
+    let mut composed = value.clone();
+    composed.insert_many(cursor_index, preedit);
+
+    Some((composed, range))
+}
+
+fn squiggle(start: f32, end: f32, y: f32) -> Vec<Primitive> {
+    const STEP: f32 = 2.0;
+
+    let steps = ((end - start) / STEP).ceil().max(0.0) as usize;
+
+    (0..steps)
+        .map(|i| Primitive::Quad {
+            bounds: Rectangle {
+                x: start + i as f32 * STEP,
+                y: if i % 2 == 0 { y - 2.0 } else { y - 1.0 },
+                width: STEP,
+                height: 1.0,
+            },
+            background: Background::Color([0.9, 0.2, 0.2].into()),
+            border_radius: 0,
+            shadow: None,
+        })
+        .collect()
 }
 
 fn measure_cursor_and_scroll_offset(
@@ -0,0 +1,86 @@
+use crate::{Primitive, Renderer};
+use iced_native::{
+    text, time_picker, Background, Color, Font, HorizontalAlignment,
+    MouseCursor, Point, Rectangle, VerticalAlignment,
+};
+
+impl time_picker::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        fields: &[time_picker::Field],
+        separator: Rectangle,
+        text_size: u16,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+        let is_focused = fields.iter().any(|field| field.is_selected);
+
+        let mut primitives = vec![
+            Primitive::Quad {
+                bounds,
+                background: Background::Color(
+                    if is_mouse_over || is_focused {
+                        [0.5, 0.5, 0.5]
+                    } else {
+                        [0.7, 0.7, 0.7]
+                    }
+                    .into(),
+                ),
+                border_radius: 5,
+                shadow: None,
+            },
+            Primitive::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + 1.0,
+                    y: bounds.y + 1.0,
+                    width: bounds.width - 2.0,
+                    height: bounds.height - 2.0,
+                },
+                background: Background::Color(Color::WHITE),
+                border_radius: 4,
+                shadow: None,
+            },
+            label(":".to_owned(), separator, text_size),
+        ];
+
+        for field in fields {
+            if field.is_selected {
+                primitives.push(Primitive::Quad {
+                    bounds: field.bounds,
+                    background: Background::Color([0.85, 0.91, 0.98].into()),
+                    border_radius: 2,
+                    shadow: None,
+                });
+            }
+
+            primitives.push(label(
+                field.content.clone(),
+                field.bounds,
+                text_size,
+            ));
+        }
+
+        (
+            Primitive::Group { primitives },
+            if is_mouse_over {
+                MouseCursor::Text
+            } else {
+                MouseCursor::OutOfBounds
+            },
+        )
+    }
+}
+
+fn label(content: String, bounds: Rectangle, size: u16) -> Primitive {
+    Primitive::Text {
+        content,
+        bounds,
+        color: [0.3, 0.3, 0.3].into(),
+        size: f32::from(size),
+        font: Font::Default,
+        params: text::Params::default(),
+        horizontal_alignment: HorizontalAlignment::Center,
+        vertical_alignment: VerticalAlignment::Center,
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{Primitive, Renderer};
+use iced_native::{
+    text, timeline, Background, Color, Font, HorizontalAlignment, MouseCursor,
+    Point, Rectangle, Vector, VerticalAlignment,
+};
+
+const RULER_HEIGHT: u16 = 24;
+const TEXT_SIZE: f32 = 14.0;
+const PADDING: f32 = 6.0;
+
+impl timeline::Renderer for Renderer {
+    fn ruler_height(&self) -> u16 {
+        RULER_HEIGHT
+    }
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        ticks: &[timeline::Tick],
+        lanes: &[timeline::Lane<'_>],
+        spans: &[timeline::Span<'_>],
+        is_dragging: bool,
+    ) -> Self::Output {
+        let ruler_height = f32::from(RULER_HEIGHT);
+        let mut primitives = Vec::new();
+
+        primitives.push(Primitive::Quad {
+            bounds: Rectangle {
+                height: ruler_height,
+                ..bounds
+            },
+            background: Background::Color([0.95, 0.95, 0.95].into()),
+            border_radius: 0,
+            shadow: None,
+        });
+
+        for (i, lane) in lanes.iter().enumerate() {
+            primitives.push(Primitive::Quad {
+                bounds: lane.bounds,
+                background: Background::Color(if i % 2 == 0 {
+                    Color::WHITE
+                } else {
+                    [0.97, 0.97, 0.97].into()
+                }),
+                border_radius: 0,
+                shadow: None,
+            });
+        }
+
+        for tick in ticks {
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: tick.x.round(),
+                    y: bounds.y,
+                    width: 1.0,
+                    height: bounds.height,
+                },
+                background: Background::Color([0.85, 0.85, 0.85].into()),
+                border_radius: 0,
+                shadow: None,
+            });
+
+            primitives.push(Primitive::Text {
+                content: tick.label.clone(),
+                bounds: Rectangle {
+                    x: tick.x + PADDING / 2.0,
+                    y: bounds.y,
+                    width: f32::INFINITY,
+                    height: ruler_height,
+                },
+                color: [0.4, 0.4, 0.4].into(),
+                size: TEXT_SIZE,
+                font: Font::Default,
+                params: text::Params::default(),
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+        }
+
+        for lane in lanes {
+            primitives.push(Primitive::Text {
+                content: lane.label.to_string(),
+                bounds: Rectangle {
+                    x: lane.bounds.x + PADDING,
+                    y: lane.bounds.y + PADDING / 2.0,
+                    width: lane.bounds.width - 2.0 * PADDING,
+                    height: TEXT_SIZE,
+                },
+                color: [0.6, 0.6, 0.6].into(),
+                size: TEXT_SIZE * 0.8,
+                font: Font::Default,
+                params: text::Params::default(),
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Top,
+            });
+        }
+
+        let mut is_mouse_over_item = false;
+
+        for span in spans {
+            let item_bounds = Rectangle {
+                x: span.bounds.x,
+                y: span.bounds.y + PADDING * 2.0,
+                width: span.bounds.width.max(2.0),
+                height: span.bounds.height - PADDING * 3.0,
+            };
+
+            is_mouse_over_item |= item_bounds.contains(cursor_position);
+
+            if span.is_selected {
+                primitives.push(Primitive::Quad {
+                    bounds: Rectangle {
+                        x: item_bounds.x - 2.0,
+                        y: item_bounds.y - 2.0,
+                        width: item_bounds.width + 4.0,
+                        height: item_bounds.height + 4.0,
+                    },
+                    background: Background::Color([0.1, 0.1, 0.1].into()),
+                    border_radius: 5,
+                    shadow: None,
+                });
+            }
+
+            primitives.push(Primitive::Quad {
+                bounds: item_bounds,
+                background: Background::Color(span.item.color),
+                border_radius: 3,
+                shadow: None,
+            });
+
+            // Keep the label visible while the item is partially scrolled
+            let label_x = item_bounds.x.max(bounds.x) + PADDING;
+
+            primitives.push(Primitive::Text {
+                content: span.item.label.clone(),
+                bounds: Rectangle {
+                    x: label_x,
+                    y: item_bounds.y,
+                    width: (item_bounds.x + item_bounds.width
+                        - label_x
+                        - PADDING)
+                        .max(0.0),
+                    height: item_bounds.height,
+                },
+                color: Color::WHITE,
+                size: TEXT_SIZE,
+                font: Font::Default,
+                params: text::Params::default(),
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+        }
+
+        (
+            Primitive::Clip {
+                bounds,
+                offset: Vector::new(0, 0),
+                content: Box::new(Primitive::Group { primitives }),
+            },
+            if is_dragging {
+                MouseCursor::Grabbing
+            } else if is_mouse_over_item {
+                MouseCursor::Grab
+            } else if bounds.contains(cursor_position) {
+                MouseCursor::Idle
+            } else {
+                MouseCursor::OutOfBounds
+            },
+        )
+    }
+}
@@ -0,0 +1,127 @@
+use super::on_top;
+use crate::{Primitive, Renderer};
+use iced_native::{
+    text, toast, toasts, Background, Color, Font, HorizontalAlignment,
+    MouseCursor, Point, Rectangle, Shadow, Vector, VerticalAlignment,
+};
+
+// The width of the stripe showing the level of a toast
+const STRIPE_WIDTH: f32 = 4.0;
+
+const SHADOW_BLUR: f32 = 8.0;
+
+impl toasts::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        (content, mouse_cursor): Self::Output,
+        cards: &[toasts::Card<'_>],
+    ) -> Self::Output {
+        let mut primitives = Vec::with_capacity(cards.len() + 1);
+        let mut mouse_cursor = mouse_cursor;
+
+        primitives.push(content);
+
+        for card in cards {
+            let accent = accent(card.level);
+
+            let mut card_primitives = vec![
+                Primitive::Quad {
+                    bounds: card.bounds,
+                    background: Background::Color(Color::WHITE),
+                    border_radius: 4,
+                    shadow: Some(Shadow {
+                        color: Color {
+                            a: 0.2,
+                            ..Color::BLACK
+                        },
+                        offset: Vector::new(0.0, 2.0),
+                        blur_radius: SHADOW_BLUR,
+                    }),
+                },
+                Primitive::Quad {
+                    bounds: Rectangle {
+                        width: STRIPE_WIDTH,
+                        ..card.bounds
+                    },
+                    background: Background::Color(accent),
+                    border_radius: 0,
+                    shadow: None,
+                },
+                Primitive::Text {
+                    content: card.title.to_owned(),
+                    bounds: card.title_bounds,
+                    color: [0.2, 0.2, 0.2].into(),
+                    size: card.title_bounds.height,
+                    font: Font::Default,
+                    params: text::Params::default(),
+                    horizontal_alignment: HorizontalAlignment::Left,
+                    vertical_alignment: VerticalAlignment::Top,
+                },
+            ];
+
+            if let Some((body, bounds)) = card.body {
+                card_primitives.push(Primitive::Text {
+                    content: body.to_owned(),
+                    bounds,
+                    color: [0.4, 0.4, 0.4].into(),
+                    size: card.title_bounds.height,
+                    font: Font::Default,
+                    params: text::Params::default(),
+                    horizontal_alignment: HorizontalAlignment::Left,
+                    vertical_alignment: VerticalAlignment::Top,
+                });
+            }
+
+            if let Some((progress, bounds)) = card.progress {
+                card_primitives.push(Primitive::Quad {
+                    bounds,
+                    background: Background::Color([0.9, 0.9, 0.9].into()),
+                    border_radius: 2,
+                    shadow: None,
+                });
+
+                if progress > 0.0 {
+                    card_primitives.push(Primitive::Quad {
+                        bounds: Rectangle {
+                            width: bounds.width * progress,
+                            ..bounds
+                        },
+                        background: Background::Color(accent),
+                        border_radius: 2,
+                        shadow: None,
+                    });
+                }
+            }
+
+            if card.bounds.contains(cursor_position) {
+                mouse_cursor = MouseCursor::Pointer;
+            }
+
+            // Every toast covers the text of the content, leaving room for
+            // its shadow
+            primitives.push(on_top(
+                Rectangle {
+                    x: card.bounds.x - SHADOW_BLUR,
+                    y: card.bounds.y - SHADOW_BLUR,
+                    width: card.bounds.width + 2.0 * SHADOW_BLUR,
+                    height: card.bounds.height + 2.0 * SHADOW_BLUR,
+                },
+                Primitive::Group {
+                    primitives: card_primitives,
+                },
+            ));
+        }
+
+        (Primitive::Group { primitives }, mouse_cursor)
+    }
+}
+
+fn accent(level: toast::Level) -> Color {
+    match level {
+        toast::Level::Info => [0.2, 0.5, 0.9].into(),
+        toast::Level::Success => [0.2, 0.7, 0.3].into(),
+        toast::Level::Warning => [0.95, 0.65, 0.1].into(),
+        toast::Level::Error => [0.85, 0.2, 0.2].into(),
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{Primitive, Renderer};
+use iced_native::{
+    xy_pad, Background, Color, MouseCursor, Point, Rectangle, Vector,
+};
+
+const HANDLE_RADIUS: f32 = 7.0;
+
+impl xy_pad::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        x_range: std::ops::RangeInclusive<f32>,
+        y_range: std::ops::RangeInclusive<f32>,
+        (x, y): (f32, f32),
+        is_dragging: bool,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let percent = |value: f32, range: std::ops::RangeInclusive<f32>| {
+            let (start, end) = range.into_inner();
+
+            if end > start {
+                (value - start) / (end - start)
+            } else {
+                0.0
+            }
+        };
+
+        let handle_x = (bounds.x + bounds.width * percent(x, x_range)).round();
+        let handle_y =
+            (bounds.y + bounds.height * (1.0 - percent(y, y_range))).round();
+
+        let (border, background) = (
+            Primitive::Quad {
+                bounds,
+                background: Color::from_rgb(0.6, 0.6, 0.6).into(),
+                border_radius: 5,
+                shadow: None,
+            },
+            Primitive::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + 1.0,
+                    y: bounds.y + 1.0,
+                    width: bounds.width - 2.0,
+                    height: bounds.height - 2.0,
+                },
+                background: Background::Color([0.95, 0.95, 0.95].into()),
+                border_radius: 4,
+                shadow: None,
+            },
+        );
+
+        let line_color = Color::from_rgb(0.6, 0.6, 0.6);
+
+        let (horizontal_line, vertical_line) = (
+            Primitive::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + 1.0,
+                    y: handle_y,
+                    width: bounds.width - 2.0,
+                    height: 1.0,
+                },
+                background: line_color.into(),
+                border_radius: 0,
+                shadow: None,
+            },
+            Primitive::Quad {
+                bounds: Rectangle {
+                    x: handle_x,
+                    y: bounds.y + 1.0,
+                    width: 1.0,
+                    height: bounds.height - 2.0,
+                },
+                background: line_color.into(),
+                border_radius: 0,
+                shadow: None,
+            },
+        );
+
+        let (handle_border, handle) = (
+            Primitive::Quad {
+                bounds: Rectangle {
+                    x: handle_x - HANDLE_RADIUS - 1.0,
+                    y: handle_y - HANDLE_RADIUS - 1.0,
+                    width: (HANDLE_RADIUS + 1.0) * 2.0,
+                    height: (HANDLE_RADIUS + 1.0) * 2.0,
+                },
+                background: Color::from_rgb(0.6, 0.6, 0.6).into(),
+                border_radius: HANDLE_RADIUS as u16 + 1,
+                shadow: None,
+            },
+            Primitive::Quad {
+                bounds: Rectangle {
+                    x: handle_x - HANDLE_RADIUS,
+                    y: handle_y - HANDLE_RADIUS,
+                    width: HANDLE_RADIUS * 2.0,
+                    height: HANDLE_RADIUS * 2.0,
+                },
+                background: Background::Color(
+                    if is_dragging {
+                        [0.85, 0.85, 0.85]
+                    } else if is_mouse_over {
+                        [0.90, 0.90, 0.90]
+                    } else {
+                        [0.95, 0.95, 0.95]
+                    }
+                    .into(),
+                ),
+                border_radius: HANDLE_RADIUS as u16,
+                shadow: None,
+            },
+        );
+
+        (
+            Primitive::Clip {
+                bounds,
+                offset: Vector::new(0, 0),
+                content: Box::new(Primitive::Group {
+                    primitives: vec![
+                        border,
+                        background,
+                        horizontal_line,
+                        vertical_line,
+                        handle_border,
+                        handle,
+                    ],
+                }),
+            },
+            if is_dragging {
+                MouseCursor::Grabbing
+            } else if is_mouse_over {
+                MouseCursor::Grab
+            } else {
+                MouseCursor::OutOfBounds
+            },
+        )
+    }
+}
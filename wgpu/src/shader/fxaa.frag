@@ -0,0 +1,63 @@
+#version 450
+
+layout(location = 0) in vec2 v_Uv;
+
+layout(set = 0, binding = 0) uniform sampler u_Sampler;
+layout(set = 0, binding = 1) uniform texture2D u_Texture;
+
+layout(location = 0) out vec4 o_Color;
+
+const float REDUCE_MIN = 1.0 / 128.0;
+const float REDUCE_MUL = 1.0 / 8.0;
+const float SPAN_MAX = 8.0;
+
+vec4 color_at(vec2 uv) {
+    return texture(sampler2D(u_Texture, u_Sampler), uv);
+}
+
+// The texture holds linear colors, but edges are found in perceived luma
+float luma(vec4 color) {
+    return sqrt(dot(color.rgb, vec3(0.299, 0.587, 0.114)));
+}
+
+void main() {
+    vec2 texel = 1.0 / vec2(textureSize(sampler2D(u_Texture, u_Sampler), 0));
+
+    vec4 center = color_at(v_Uv);
+
+    float nw = luma(color_at(v_Uv + vec2(-1.0, -1.0) * texel));
+    float ne = luma(color_at(v_Uv + vec2(1.0, -1.0) * texel));
+    float sw = luma(color_at(v_Uv + vec2(-1.0, 1.0) * texel));
+    float se = luma(color_at(v_Uv + vec2(1.0, 1.0) * texel));
+    float m = luma(center);
+
+    float luma_min = min(m, min(min(nw, ne), min(sw, se)));
+    float luma_max = max(m, max(max(nw, ne), max(sw, se)));
+
+    // Blur along the edge, never across it
+    vec2 direction = vec2(-((nw + ne) - (sw + se)), (nw + sw) - (ne + se));
+
+    float reduce = max((nw + ne + sw + se) * 0.25 * REDUCE_MUL, REDUCE_MIN);
+    float scale = 1.0 / (min(abs(direction.x), abs(direction.y)) + reduce);
+
+    direction = clamp(
+        direction * scale,
+        vec2(-SPAN_MAX, -SPAN_MAX),
+        vec2(SPAN_MAX, SPAN_MAX)
+    ) * texel;
+
+    vec4 near = 0.5 * (
+        color_at(v_Uv + direction * (1.0 / 3.0 - 0.5)) +
+        color_at(v_Uv + direction * (2.0 / 3.0 - 0.5))
+    );
+
+    vec4 far = near * 0.5 + 0.25 * (
+        color_at(v_Uv + direction * -0.5) +
+        color_at(v_Uv + direction * 0.5)
+    );
+
+    float far_luma = luma(far);
+
+    // Sampling too far crosses other edges
+    o_Color = (far_luma < luma_min || far_luma > luma_max) ? near : far;
+}
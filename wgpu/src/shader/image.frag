@@ -1,12 +1,65 @@
 #version 450
 
-layout(location = 0) in vec2 v_Uv;
+layout(location = 0) in vec2 v_Tile;
+layout(location = 1) in float v_Opacity;
+layout(location = 2) in vec2 v_FragPos;
+layout(location = 3) in vec2 v_Scale;
+layout(location = 4) in float v_BorderRadius;
+layout(location = 5) in vec2 v_TexturePos;
+layout(location = 6) in vec2 v_TextureScale;
+layout(location = 7) in float v_EncodeSrgb;
 
 layout(set = 0, binding = 1) uniform sampler u_Sampler;
 layout(set = 1, binding = 0) uniform texture2D u_Texture;
 
 layout(location = 0) out vec4 o_Color;
 
+// The signed distance from a point to the edge of a rounded rectangle
+float rounded_distance(in vec2 frag_coord, in vec2 size, float radius)
+{
+    vec2 half_size = size / 2.0;
+    float r = min(radius, min(half_size.x, half_size.y));
+
+    vec2 q = abs(frag_coord - half_size) - half_size + vec2(r, r);
+
+    return length(max(q, vec2(0.0, 0.0))) + min(max(q.x, q.y), 0.0) - r;
+}
+
+// As described in:
+// https://en.wikipedia.org/wiki/SRGB#The_forward_transformation_(CIE_XYZ_to_sRGB)
+vec3 encode_srgb(in vec3 linear)
+{
+    vec3 lower = linear * 12.92;
+    vec3 higher = 1.055 * pow(linear, vec3(1.0 / 2.4)) - 0.055;
+
+    return mix(higher, lower, vec3(lessThan(linear, vec3(0.0031308))));
+}
+
 void main() {
-    o_Color = texture(sampler2D(u_Texture, u_Sampler), v_Uv);
+    vec2 uv = v_TexturePos + fract(v_Tile) * v_TextureScale;
+
+    // The gradients of the unwrapped coordinates avoid picking the smallest
+    // mipmap at the seams between tiles
+    vec2 unwrapped = v_Tile * v_TextureScale;
+
+    vec4 color = textureGrad(
+        sampler2D(u_Texture, u_Sampler),
+        uv,
+        dFdx(unwrapped),
+        dFdy(unwrapped)
+    );
+
+    float d = rounded_distance(v_FragPos, v_Scale, v_BorderRadius);
+
+    // Smooth over a single pixel on screen, whatever the scale
+    float s = max(fwidth(d), 0.0001) * 0.5;
+
+    // Sharp images are left untouched, so their edges are not smoothed
+    float radius_alpha =
+        v_BorderRadius > 0.0 ? 1.0 - smoothstep(-s, s, d) : 1.0;
+
+    // Colors are blended as they are encoded in some color spaces
+    vec3 rgb = v_EncodeSrgb > 0.5 ? encode_srgb(color.rgb) : color.rgb;
+
+    o_Color = vec4(rgb, color.a * v_Opacity * radius_alpha);
 }
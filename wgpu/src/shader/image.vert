@@ -3,15 +3,38 @@
 layout(location = 0) in vec2 v_Pos;
 layout(location = 1) in vec2 i_Pos;
 layout(location = 2) in vec2 i_Scale;
+layout(location = 3) in float i_Opacity;
+layout(location = 4) in vec2 i_TexturePos;
+layout(location = 5) in vec2 i_TextureScale;
+layout(location = 6) in float i_BorderRadius;
+layout(location = 7) in vec2 i_Tiles;
+layout(location = 8) in float i_EncodeSrgb;
 
 layout (set = 0, binding = 0) uniform Globals {
     mat4 u_Transform;
 };
 
-layout(location = 0) out vec2 o_Uv;
+layout(location = 0) out vec2 o_Tile;
+layout(location = 1) out float o_Opacity;
+layout(location = 2) out vec2 o_FragPos;
+layout(location = 3) out vec2 o_Scale;
+layout(location = 4) out float o_BorderRadius;
+layout(location = 5) out vec2 o_TexturePos;
+layout(location = 6) out vec2 o_TextureScale;
+layout(location = 7) out float o_EncodeSrgb;
 
 void main() {
-    o_Uv = v_Pos;
+    // The position in tiles, which repeat the region of the texture
+    o_Tile = v_Pos * i_Tiles;
+    o_TexturePos = i_TexturePos;
+    o_TextureScale = i_TextureScale;
+    o_Opacity = i_Opacity;
+    o_EncodeSrgb = i_EncodeSrgb;
+
+    // The position inside of the image, used to round its corners
+    o_FragPos = v_Pos * i_Scale;
+    o_Scale = i_Scale;
+    o_BorderRadius = i_BorderRadius;
 
     mat4 i_Transform = mat4(
         vec4(i_Scale.x, 0.0, 0.0, 0.0),
@@ -5,6 +5,7 @@ layout(location = 1) in vec2 i_Pos;
 layout(location = 2) in vec2 i_Scale;
 layout(location = 3) in vec4 i_Color;
 layout(location = 4) in float i_BorderRadius;
+layout(location = 5) in float i_BlurRadius;
 
 layout (set = 0, binding = 0) uniform Globals {
     mat4 u_Transform;
@@ -15,22 +16,34 @@ layout(location = 0) out vec4 o_Color;
 layout(location = 1) out vec2 o_Pos;
 layout(location = 2) out vec2 o_Scale;
 layout(location = 3) out float o_BorderRadius;
+layout(location = 4) out float o_BlurRadius;
+layout(location = 5) out vec2 o_FragPos;
 
 void main() {
     vec2 p_Pos = i_Pos * u_Scale;
     vec2 p_Scale = i_Scale  * u_Scale;
+    float p_BlurRadius = i_BlurRadius * u_Scale;
+
+    // Blurred quads spill out of their bounds
+    vec2 p_Spill = vec2(p_BlurRadius + 0.5, p_BlurRadius + 0.5);
 
     mat4 i_Transform = mat4(
-        vec4(p_Scale.x + 1.0, 0.0, 0.0, 0.0),
-        vec4(0.0, p_Scale.y + 1.0, 0.0, 0.0),
+        vec4(p_Scale.x + 2.0 * p_Spill.x, 0.0, 0.0, 0.0),
+        vec4(0.0, p_Scale.y + 2.0 * p_Spill.y, 0.0, 0.0),
         vec4(0.0, 0.0, 1.0, 0.0),
-        vec4(p_Pos - vec2(0.5, 0.5), 0.0, 1.0)
+        vec4(p_Pos - p_Spill, 0.0, 1.0)
     );
 
     o_Color = i_Color;
     o_Pos = p_Pos;
     o_Scale = p_Scale;
     o_BorderRadius = i_BorderRadius * u_Scale;
+    o_BlurRadius = p_BlurRadius;
+
+    // The position before the transformation of the layer, so quads keep
+    // their shape when rotated or scaled
+    vec4 p_FragPos = i_Transform * vec4(v_Pos, 0.0, 1.0);
+    o_FragPos = p_FragPos.xy;
 
-    gl_Position = u_Transform * i_Transform * vec4(v_Pos, 0.0, 1.0);
+    gl_Position = u_Transform * p_FragPos;
 }
@@ -0,0 +1,45 @@
+#version 450
+
+layout(location = 0) in vec2 v_Uv;
+
+layout(set = 0, binding = 0) uniform sampler u_Sampler;
+layout(set = 0, binding = 1) uniform texture2D u_Texture;
+
+layout(location = 0) out vec4 o_Color;
+
+// The text is drawn three times wider than the target, so every pixel has a
+// texel for each of its red, green, and blue subpixels
+vec4 subpixel(int offset) {
+    ivec2 size = textureSize(sampler2D(u_Texture, u_Sampler), 0);
+    int x = clamp(int(gl_FragCoord.x) * 3 + offset, 0, size.x - 1);
+
+    return texelFetch(
+        sampler2D(u_Texture, u_Sampler),
+        ivec2(x, int(gl_FragCoord.y)),
+        0
+    );
+}
+
+void main() {
+    vec4 a = subpixel(-2);
+    vec4 b = subpixel(-1);
+    vec4 c = subpixel(0);
+    vec4 d = subpixel(1);
+    vec4 e = subpixel(2);
+    vec4 f = subpixel(3);
+    vec4 g = subpixel(4);
+
+    // Same filter as the coverage pass
+    vec3 coverage = vec3(
+        a.a + 2.0 * b.a + 3.0 * c.a + 2.0 * d.a + e.a,
+        b.a + 2.0 * c.a + 3.0 * d.a + 2.0 * e.a + f.a,
+        c.a + 2.0 * d.a + 3.0 * e.a + 2.0 * f.a + g.a
+    ) / 9.0;
+
+    // The texels have their alpha premultiplied
+    vec4 pixel = c + d + e;
+    vec3 color = pixel.rgb / max(pixel.a, 0.0001);
+
+    // Adds the color of the text to the darkened target
+    o_Color = vec4(color * coverage, 0.0);
+}
@@ -0,0 +1,42 @@
+#version 450
+
+layout(location = 0) in vec2 v_Uv;
+
+layout(set = 0, binding = 0) uniform sampler u_Sampler;
+layout(set = 0, binding = 1) uniform texture2D u_Texture;
+
+layout(location = 0) out vec4 o_Color;
+
+// The text is drawn three times wider than the target, so every pixel has a
+// texel for each of its red, green, and blue subpixels
+float coverage(int offset) {
+    ivec2 size = textureSize(sampler2D(u_Texture, u_Sampler), 0);
+    int x = clamp(int(gl_FragCoord.x) * 3 + offset, 0, size.x - 1);
+
+    return texelFetch(
+        sampler2D(u_Texture, u_Sampler),
+        ivec2(x, int(gl_FragCoord.y)),
+        0
+    ).a;
+}
+
+void main() {
+    float a = coverage(-2);
+    float b = coverage(-1);
+    float c = coverage(0);
+    float d = coverage(1);
+    float e = coverage(2);
+    float f = coverage(3);
+    float g = coverage(4);
+
+    // A low-pass filter spreads the coverage of every subpixel over its
+    // neighbors, reducing color fringes
+    vec3 filtered = vec3(
+        a + 2.0 * b + 3.0 * c + 2.0 * d + e,
+        b + 2.0 * c + 3.0 * d + 2.0 * e + f,
+        c + 2.0 * d + 3.0 * e + 2.0 * f + g
+    ) / 9.0;
+
+    // Darkens the target by the coverage of each subpixel
+    o_Color = vec4(filtered, 0.0);
+}
@@ -0,0 +1,34 @@
+#version 450
+
+layout(location = 0) in vec2 v_Uv;
+
+layout(set = 0, binding = 0) uniform sampler u_Sampler;
+layout(set = 0, binding = 1) uniform texture2D u_Luma;
+layout(set = 0, binding = 2) uniform texture2D u_Chroma;
+
+layout(location = 0) out vec4 o_Color;
+
+// Undoes the transfer function of sRGB, since the target encodes it again
+vec3 to_linear(vec3 color) {
+    vec3 low = color / 12.92;
+    vec3 high = pow((color + 0.055) / 1.055, vec3(2.4));
+
+    return mix(low, high, step(vec3(0.04045), color));
+}
+
+void main() {
+    float y = texture(sampler2D(u_Luma, u_Sampler), v_Uv).r;
+    vec2 cbcr = texture(sampler2D(u_Chroma, u_Sampler), v_Uv).rg;
+
+    // BT.709, limited range
+    float luma = (y - 16.0 / 255.0) * (255.0 / 219.0);
+    vec2 chroma = (cbcr - 128.0 / 255.0) * (255.0 / 224.0);
+
+    vec3 color = vec3(
+        luma + 1.5748 * chroma.y,
+        luma - 0.1873 * chroma.x - 0.4681 * chroma.y,
+        luma + 1.8556 * chroma.x
+    );
+
+    o_Color = vec4(to_linear(clamp(color, 0.0, 1.0)), 1.0);
+}
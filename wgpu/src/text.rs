@@ -1,8 +1,18 @@
+mod atlas;
 mod font;
+mod shaper;
 
-use crate::Transformation;
+pub use atlas::Atlas;
 
-use std::{cell::RefCell, collections::HashMap};
+use crate::{GlyphCache, Transformation};
+
+use shaper::{Face, Shaper};
+
+use std::{
+    borrow::Cow,
+    cell::{RefCell, RefMut},
+    collections::HashMap,
+};
 
 pub const BUILTIN_ICONS: iced_native::Font = iced_native::Font::External {
     name: "iced_wgpu icons",
@@ -13,21 +23,47 @@ pub const CHECKMARK_ICON: char = '\u{F00C}';
 
 const FALLBACK_FONT: &[u8] = include_bytes!("../fonts/Lato-Regular.ttf");
 
+const DEFAULT_FAMILIES: &[font::Family] =
+    &[font::Family::SansSerif, font::Family::Serif];
+
+const MEASURE_CACHE_SIZE: (u32, u32) = (256, 256);
+
 #[derive(Debug)]
 pub struct Pipeline {
     draw_brush: RefCell<wgpu_glyph::GlyphBrush<'static, ()>>,
     draw_font_map: RefCell<HashMap<String, wgpu_glyph::FontId>>,
+    system_font_map: RefCell<HashMap<SystemFont, wgpu_glyph::FontId>>,
+    default_font_map:
+        RefCell<HashMap<iced_native::font::Weight, wgpu_glyph::FontId>>,
+
+    // The face of every font of the brushes, indexed by `FontId`, parsed
+    // once for shaping
+    faces: RefCell<Vec<Face>>,
 
     measure_brush: RefCell<glyph_brush::GlyphBrush<'static, ()>>,
+    measure_cache_size: (u32, u32),
+    measure_cache_resizes: u32,
+    atlas: Option<RefCell<Atlas>>,
+    scale: f32,
+    hinting: bool,
 }
 
 impl Pipeline {
-    pub fn new(device: &mut wgpu::Device) -> Self {
+    /// Creates a new text pipeline drawing into targets of the given format.
+    ///
+    /// With `atlas`, a copy of the glyph cache is kept in memory, so it can
+    /// be inspected. Every glyph is rasterized twice then.
+    pub fn new(
+        device: &mut wgpu::Device,
+        format: wgpu::TextureFormat,
+        cache: GlyphCache,
+        atlas: bool,
+    ) -> Self {
         // TODO: Font customization
         let font_source = font::Source::new();
 
         let default_font = font_source
-            .load(&[font::Family::SansSerif, font::Family::Serif])
+            .load(DEFAULT_FAMILIES)
             .unwrap_or_else(|_| FALLBACK_FONT.to_vec());
 
         let load_glyph_brush = |font: Vec<u8>| {
@@ -38,26 +74,50 @@ impl Pipeline {
 
             Ok((
                 builder,
-                glyph_brush::GlyphBrushBuilder::using_font_bytes(font).build(),
+                glyph_brush::GlyphBrushBuilder::using_font_bytes(font.clone())
+                    .initial_cache_size(MEASURE_CACHE_SIZE)
+                    .build(),
+                font,
             ))
         };
 
-        let (brush_builder, measure_brush) = load_glyph_brush(default_font)
-            .unwrap_or_else(|_: wgpu_glyph::rusttype::Error| {
-                log::warn!("System font failed to load. Falling back to embedded font...");
+        let (brush_builder, measure_brush, font) = load_glyph_brush(
+            default_font,
+        )
+        .unwrap_or_else(|_: wgpu_glyph::rusttype::Error| {
+            log::warn!(
+                "System font failed to load. Falling back to embedded font..."
+            );
 
-                load_glyph_brush(FALLBACK_FONT.to_vec()).expect("Load fallback font")
-            });
+            load_glyph_brush(FALLBACK_FONT.to_vec())
+                .expect("Load fallback font")
+        });
 
         let draw_brush = brush_builder
-            .initial_cache_size((2048, 2048))
-            .build(device, wgpu::TextureFormat::Bgra8UnormSrgb);
+            .initial_cache_size(cache.size)
+            .gpu_cache_position_tolerance(cache.position_tolerance)
+            .gpu_cache_scale_tolerance(cache.scale_tolerance)
+            .build(device, format);
+
+        let atlas = if atlas {
+            Some(RefCell::new(Atlas::new(font.clone(), cache)))
+        } else {
+            None
+        };
 
         Pipeline {
             draw_brush: RefCell::new(draw_brush),
             draw_font_map: RefCell::new(HashMap::new()),
+            system_font_map: RefCell::new(HashMap::new()),
+            default_font_map: RefCell::new(HashMap::new()),
+            faces: RefCell::new(vec![Face::new(Cow::Owned(font))]),
 
             measure_brush: RefCell::new(measure_brush),
+            measure_cache_size: MEASURE_CACHE_SIZE,
+            measure_cache_resizes: 0,
+            atlas,
+            scale: 1.0,
+            hinting: false,
         }
     }
 
@@ -65,8 +125,44 @@ impl Pipeline {
         wgpu_glyph::FontId(0)
     }
 
-    pub fn queue(&mut self, section: wgpu_glyph::Section<'_>) {
-        self.draw_brush.borrow_mut().queue(section);
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    pub fn set_scale(&mut self, scale: f32) {
+        self.scale = scale;
+    }
+
+    pub fn hinting(&self) -> bool {
+        self.hinting
+    }
+
+    /// Sets whether queued text is snapped to the pixel grid. Measurements
+    /// are not affected, since they are in logical pixels.
+    pub fn set_hinting(&mut self, hinting: bool) {
+        self.hinting = hinting;
+    }
+
+    /// Queues a section laid out in logical pixels, to be drawn scaled by
+    /// the given horizontal and vertical factors.
+    pub fn queue(
+        &mut self,
+        section: &wgpu_glyph::VariedSection<'_>,
+        params: iced_native::text::Params,
+        scale_factor: (f32, f32),
+    ) {
+        let faces = self.faces.borrow();
+        let shaper = Shaper::new(section.layout, params, &faces)
+            .hinting(self.hinting)
+            .scale_factor(scale_factor.0, scale_factor.1);
+
+        self.draw_brush
+            .borrow_mut()
+            .queue_custom_layout(section, &shaper);
+
+        if let Some(atlas) = &self.atlas {
+            atlas.borrow_mut().queue(section, &shaper);
+        }
     }
 
     pub fn draw_queued(
@@ -76,7 +172,11 @@ impl Pipeline {
         target: &wgpu::TextureView,
         transformation: Transformation,
         region: wgpu_glyph::Region,
-    ) {
+    ) -> Result<(), String> {
+        if let Some(atlas) = &self.atlas {
+            atlas.borrow_mut().process();
+        }
+
         self.draw_brush
             .borrow_mut()
             .draw_queued_with_transform_and_scissoring(
@@ -86,7 +186,48 @@ impl Pipeline {
                 transformation.into(),
                 region,
             )
-            .expect("Draw text");
+    }
+
+    /// Rasterizes the glyphs of the given content into the draw cache, by
+    /// drawing them invisibly into the given target.
+    pub fn warm(
+        &mut self,
+        device: &mut wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+        content: &str,
+        size: f32,
+        font: iced_native::Font,
+    ) -> Result<(), String> {
+        let font_id = self.find_font(font);
+
+        self.queue(
+            &wgpu_glyph::VariedSection {
+                text: vec![wgpu_glyph::SectionText {
+                    text: content,
+                    scale: wgpu_glyph::Scale { x: size, y: size },
+                    font_id,
+                    color: [0.0, 0.0, 0.0, 0.0],
+                }],
+                bounds: (f32::INFINITY, f32::INFINITY),
+                ..Default::default()
+            },
+            iced_native::text::Params::default(),
+            (1.0, 1.0),
+        );
+
+        self.draw_queued(
+            device,
+            encoder,
+            target,
+            Transformation::identity(),
+            wgpu_glyph::Region {
+                x: 0,
+                y: 0,
+                width: 1,
+                height: 1,
+            },
+        )
     }
 
     pub fn measure(
@@ -94,22 +235,46 @@ impl Pipeline {
         content: &str,
         size: f32,
         font: iced_native::Font,
+        params: iced_native::text::Params,
         bounds: iced_native::Size,
     ) -> (f32, f32) {
-        use wgpu_glyph::GlyphCruncher;
+        let span = self.span(content, size, font, None, [0.0, 0.0, 0.0, 1.0]);
 
-        let wgpu_glyph::FontId(font_id) = self.find_font(font);
+        self.measure_spans(&[span], params, bounds)
+    }
 
-        let section = wgpu_glyph::Section {
-            text: content,
-            scale: wgpu_glyph::Scale { x: size, y: size },
+    /// Measures some spans, wrapped like a single paragraph, in the given
+    /// bounds.
+    ///
+    /// Like the spans, the [`Params`] are in logical pixels.
+    ///
+    /// [`Params`]: ../../iced_native/text/struct.Params.html
+    pub fn measure_spans(
+        &self,
+        spans: &[wgpu_glyph::SectionText<'_>],
+        params: iced_native::text::Params,
+        bounds: iced_native::Size,
+    ) -> (f32, f32) {
+        use wgpu_glyph::GlyphCruncher;
+
+        let section = wgpu_glyph::VariedSection {
+            text: spans.to_vec(),
             bounds: (bounds.width, bounds.height),
-            font_id: wgpu_glyph::FontId(font_id),
             ..Default::default()
         };
 
-        if let Some(bounds) =
-            self.measure_brush.borrow_mut().glyph_bounds(section)
+        let params = iced_native::text::Params {
+            letter_spacing: params.letter_spacing * self.scale,
+            ..params
+        };
+
+        let faces = self.faces.borrow();
+        let shaper = Shaper::new(section.layout, params, &faces);
+
+        if let Some(bounds) = self
+            .measure_brush
+            .borrow_mut()
+            .glyph_bounds_custom_layout(&section, &shaper)
         {
             (bounds.width().ceil(), bounds.height().ceil())
         } else {
@@ -117,6 +282,104 @@ impl Pipeline {
         }
     }
 
+    /// Returns a span of some text in logical pixels, with the font of the
+    /// given weight, if any.
+    pub fn span<'a>(
+        &self,
+        content: &'a str,
+        size: f32,
+        font: iced_native::Font,
+        weight: Option<iced_native::font::Weight>,
+        color: [f32; 4],
+    ) -> wgpu_glyph::SectionText<'a> {
+        let size = size * self.scale;
+
+        wgpu_glyph::SectionText {
+            text: content,
+            scale: wgpu_glyph::Scale { x: size, y: size },
+            color,
+            font_id: match weight {
+                Some(weight) => self.find_weighted_font(font, weight),
+                None => self.find_font(font),
+            },
+        }
+    }
+
+    /// Lays out the lines of a section like they are drawn, and returns the
+    /// [`Extent`] of each of them.
+    ///
+    /// [`Extent`]: shaper/struct.Extent.html
+    pub fn extents(
+        &self,
+        section: &wgpu_glyph::VariedSection<'_>,
+        params: iced_native::text::Params,
+    ) -> Vec<shaper::Extent> {
+        use wgpu_glyph::GlyphCruncher;
+
+        let faces = self.faces.borrow();
+        let shaper = Shaper::new(section.layout, params, &faces);
+        let font_map = self.measure_brush.borrow().fonts().to_vec();
+
+        shaper.extents(
+            &font_map,
+            &glyph_brush::SectionGeometry {
+                screen_position: section.screen_position,
+                bounds: section.bounds,
+            },
+            &section.text,
+        )
+    }
+
+    /// Lays out a section like it is drawn, and returns the index of the
+    /// span of the section drawn at the given point, if any.
+    ///
+    /// Like the section, the [`Params`] are in logical pixels.
+    ///
+    /// [`Params`]: ../../iced_native/text/struct.Params.html
+    pub fn span_at(
+        &self,
+        section: &wgpu_glyph::VariedSection<'_>,
+        params: iced_native::text::Params,
+        point: iced_native::Point,
+    ) -> Option<usize> {
+        use wgpu_glyph::GlyphCruncher;
+
+        let params = iced_native::text::Params {
+            letter_spacing: params.letter_spacing * self.scale,
+            ..params
+        };
+
+        let faces = self.faces.borrow();
+        let shaper = Shaper::new(section.layout, params, &faces);
+        let font_map = self.measure_brush.borrow().fonts().to_vec();
+
+        shaper.section_at(
+            &font_map,
+            &glyph_brush::SectionGeometry {
+                screen_position: section.screen_position,
+                bounds: section.bounds,
+            },
+            &section.text,
+            (point.x, point.y),
+        )
+    }
+
+    pub fn v_metrics(
+        &self,
+        size: f32,
+        font: iced_native::Font,
+    ) -> wgpu_glyph::rusttype::VMetrics {
+        use wgpu_glyph::GlyphCruncher;
+
+        let wgpu_glyph::FontId(font_id) = self.find_font(font);
+        let size = size * self.scale;
+
+        let glyph_brush = self.measure_brush.borrow();
+
+        glyph_brush.fonts()[font_id]
+            .v_metrics(wgpu_glyph::Scale { x: size, y: size })
+    }
+
     pub fn space_width(&self, size: f32) -> f32 {
         use wgpu_glyph::GlyphCruncher;
 
@@ -125,6 +388,8 @@ impl Pipeline {
         // TODO: Select appropriate font
         let font = &glyph_brush.fonts()[0];
 
+        let size = size * self.scale;
+
         font.glyph(' ')
             .scaled(wgpu_glyph::Scale { x: size, y: size })
             .h_metrics()
@@ -150,11 +415,47 @@ impl Pipeline {
                     self.measure_brush
                         .borrow_mut()
                         .resize_texture(width, height);
+
+                    self.measure_cache_size = suggested;
+                    self.measure_cache_resizes += 1;
                 }
             }
         }
     }
 
+    /// Returns the size the glyph cache has grown to, in bytes.
+    ///
+    /// Like the stats, it is estimated from the measurement cache. Glyphs are
+    /// stored with a single byte of coverage per pixel.
+    pub fn cache_bytes(&self) -> u64 {
+        let (width, height) = self.measure_cache_size;
+
+        u64::from(width) * u64::from(height)
+    }
+
+    pub fn collect_stats(&self, stats: &mut iced_native::cache_stats::Stats) {
+        // The glyph cache of the draw brush is private to `wgpu_glyph`. Its
+        // copy is exact, but it only exists while the atlas is inspected.
+        // Otherwise, the measurement cache should grow in the same way.
+        match &self.atlas {
+            Some(atlas) => {
+                let atlas = atlas.borrow();
+
+                stats.glyph_page = atlas.dimensions();
+                stats.glyph_page_resizes = atlas.resizes();
+            }
+            None => {
+                stats.glyph_page = self.measure_cache_size;
+                stats.glyph_page_resizes = self.measure_cache_resizes;
+            }
+        }
+    }
+
+    /// Returns the copy of the glyph cache, if the pipeline keeps one.
+    pub fn atlas(&self) -> Option<RefMut<'_, Atlas>> {
+        self.atlas.as_ref().map(RefCell::borrow_mut)
+    }
+
     pub fn find_font(&self, font: iced_native::Font) -> wgpu_glyph::FontId {
         match font {
             iced_native::Font::Default => wgpu_glyph::FontId(0),
@@ -163,11 +464,7 @@ impl Pipeline {
                     return *font_id;
                 }
 
-                // TODO: Find a way to share font data
-                let _ = self.measure_brush.borrow_mut().add_font_bytes(bytes);
-
-                let font_id =
-                    self.draw_brush.borrow_mut().add_font_bytes(bytes);
+                let font_id = self.add_font(Cow::Borrowed(bytes));
 
                 let _ = self
                     .draw_font_map
@@ -176,6 +473,181 @@ impl Pipeline {
 
                 font_id
             }
+            iced_native::Font::System {
+                family,
+                weight,
+                style,
+            } => {
+                let key = (family, weight, style);
+
+                if let Some(font_id) = self.system_font_map.borrow().get(&key) {
+                    return *font_id;
+                }
+
+                // Missing families are remembered too, so the system is only
+                // searched once
+                let font_id = match font::Source::new()
+                    .load_face(family, weight, style)
+                {
+                    Ok(bytes) => self.add_font(Cow::Owned(bytes)),
+                    Err(_) => {
+                        log::warn!(
+                            "Font family {:?} is not installed. Falling back \
+                            to the default font...",
+                            family
+                        );
+
+                        wgpu_glyph::FontId(0)
+                    }
+                };
+
+                let _ = self.system_font_map.borrow_mut().insert(key, font_id);
+
+                font_id
+            }
+        }
+    }
+
+    /// Lays out a section like it is drawn, and returns the byte offset of
+    /// the caret closest to the given point.
+    ///
+    /// Like the section, the [`Params`] are in logical pixels.
+    ///
+    /// [`Params`]: ../../iced_native/text/struct.Params.html
+    pub fn offset_at(
+        &self,
+        section: &wgpu_glyph::VariedSection<'_>,
+        params: iced_native::text::Params,
+        point: iced_native::Point,
+    ) -> usize {
+        use wgpu_glyph::GlyphCruncher;
+
+        let params = iced_native::text::Params {
+            letter_spacing: params.letter_spacing * self.scale,
+            ..params
+        };
+
+        let faces = self.faces.borrow();
+        let shaper = Shaper::new(section.layout, params, &faces);
+        let font_map = self.measure_brush.borrow().fonts().to_vec();
+
+        shaper.offset_at(
+            &font_map,
+            &glyph_brush::SectionGeometry {
+                screen_position: section.screen_position,
+                bounds: section.bounds,
+            },
+            &section.text,
+            (point.x, point.y),
+        )
+    }
+
+    /// Lays out a section like it is drawn, and returns the rectangles
+    /// covering the glyphs of the given byte range.
+    ///
+    /// Like the section, the [`Params`] are in logical pixels.
+    ///
+    /// [`Params`]: ../../iced_native/text/struct.Params.html
+    pub fn selection(
+        &self,
+        section: &wgpu_glyph::VariedSection<'_>,
+        params: iced_native::text::Params,
+        range: std::ops::Range<usize>,
+    ) -> Vec<iced_native::Rectangle> {
+        use wgpu_glyph::GlyphCruncher;
+
+        let params = iced_native::text::Params {
+            letter_spacing: params.letter_spacing * self.scale,
+            ..params
+        };
+
+        let faces = self.faces.borrow();
+        let shaper = Shaper::new(section.layout, params, &faces);
+        let font_map = self.measure_brush.borrow().fonts().to_vec();
+
+        shaper
+            .selection(
+                &font_map,
+                &glyph_brush::SectionGeometry {
+                    screen_position: section.screen_position,
+                    bounds: section.bounds,
+                },
+                &section.text,
+                range,
+            )
+            .into_iter()
+            .map(|rectangle| iced_native::Rectangle {
+                x: rectangle.min.x,
+                y: rectangle.min.y,
+                width: rectangle.width(),
+                height: rectangle.height(),
+            })
+            .collect()
+    }
+
+    /// Finds the face of a font closest to the given weight.
+    ///
+    /// External fonts have a single face, so the weight is ignored for them.
+    pub fn find_weighted_font(
+        &self,
+        font: iced_native::Font,
+        weight: iced_native::font::Weight,
+    ) -> wgpu_glyph::FontId {
+        match font {
+            iced_native::Font::System { family, style, .. } => {
+                self.find_font(iced_native::Font::System {
+                    family,
+                    weight,
+                    style,
+                })
+            }
+            iced_native::Font::Default
+                if weight != iced_native::font::Weight::Normal =>
+            {
+                if let Some(font_id) =
+                    self.default_font_map.borrow().get(&weight)
+                {
+                    return *font_id;
+                }
+
+                let font_id = match font::Source::new()
+                    .load_weight(DEFAULT_FAMILIES, weight)
+                {
+                    Ok(bytes) => self.add_font(Cow::Owned(bytes)),
+                    Err(_) => wgpu_glyph::FontId(0),
+                };
+
+                let _ =
+                    self.default_font_map.borrow_mut().insert(weight, font_id);
+
+                font_id
+            }
+            _ => self.find_font(font),
         }
     }
+
+    fn add_font(&self, bytes: Cow<'static, [u8]>) -> wgpu_glyph::FontId {
+        // TODO: Find a way to share font data
+        let _ = self
+            .measure_brush
+            .borrow_mut()
+            .add_font_bytes(bytes.to_vec());
+
+        let font_id =
+            self.draw_brush.borrow_mut().add_font_bytes(bytes.to_vec());
+
+        if let Some(atlas) = &self.atlas {
+            atlas.borrow_mut().add_font(bytes.to_vec());
+        }
+
+        self.faces.borrow_mut().push(Face::new(bytes));
+
+        font_id
+    }
 }
+
+type SystemFont = (
+    &'static str,
+    iced_native::font::Weight,
+    iced_native::font::Style,
+);
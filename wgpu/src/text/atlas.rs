@@ -0,0 +1,231 @@
+use crate::{texture, GlyphCache};
+use glyph_brush::{rusttype::Rect, BrushError, GlyphBrush, VariedSection};
+use iced_native::Rectangle;
+
+use super::shaper::Shaper;
+
+/// A copy of the glyph cache of the draw brush, kept in memory so it can be
+/// inspected.
+///
+/// The texture of `wgpu_glyph` is private. Instead, the same glyphs are
+/// queued into a brush with the same configuration, which packs them in the
+/// same places.
+#[derive(Debug)]
+pub struct Atlas {
+    brush: GlyphBrush<'static, ()>,
+    width: u32,
+    height: u32,
+    coverage: Vec<u8>,
+    allocations: Vec<Rectangle<u32>>,
+    resizes: u32,
+    version: u64,
+    texture: Option<Texture>,
+}
+
+#[derive(Debug)]
+struct Texture {
+    version: u64,
+    _texture: wgpu::Texture,
+    handle: texture::Handle,
+}
+
+impl Atlas {
+    pub fn new(font: Vec<u8>, cache: GlyphCache) -> Self {
+        let brush = glyph_brush::GlyphBrushBuilder::using_font_bytes(font)
+            .initial_cache_size(cache.size)
+            .gpu_cache_position_tolerance(cache.position_tolerance)
+            .gpu_cache_scale_tolerance(cache.scale_tolerance)
+            .build();
+
+        let (width, height) = cache.size;
+
+        Atlas {
+            brush,
+            width,
+            height,
+            coverage: vec![0; (width * height) as usize],
+            allocations: Vec::new(),
+            resizes: 0,
+            version: 0,
+            texture: None,
+        }
+    }
+
+    /// Returns the dimensions of the atlas, in pixels.
+    pub fn dimensions(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// Returns the coverage of every pixel of the atlas, row by row.
+    pub fn coverage(&self) -> &[u8] {
+        &self.coverage
+    }
+
+    /// Returns the regions of the atlas holding a glyph.
+    pub fn allocations(&self) -> &[Rectangle<u32>] {
+        &self.allocations
+    }
+
+    /// Returns the amount of times the atlas has grown.
+    pub fn resizes(&self) -> u32 {
+        self.resizes
+    }
+
+    /// Returns a number that changes every time the atlas does.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Returns a texture showing the coverage of the atlas, in grayscale.
+    ///
+    /// The texture is only uploaded again when the atlas changes.
+    pub fn texture(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+    ) -> texture::Handle {
+        match &self.texture {
+            Some(texture) if texture.version == self.version => {
+                return texture.handle.clone();
+            }
+            _ => {}
+        }
+
+        let extent = wgpu::Extent3d {
+            width: self.width,
+            height: self.height,
+            depth: 1,
+        };
+
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            size: extent,
+            array_layer_count: 1,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            usage: wgpu::TextureUsage::COPY_DST | wgpu::TextureUsage::SAMPLED,
+        });
+
+        let mut pixels = Vec::with_capacity(self.coverage.len() * 4);
+
+        for &coverage in &self.coverage {
+            pixels.extend_from_slice(&[coverage, coverage, coverage, 255]);
+        }
+
+        let buffer = device
+            .create_buffer_mapped(pixels.len(), wgpu::BufferUsage::COPY_SRC)
+            .fill_from_slice(&pixels);
+
+        encoder.copy_buffer_to_texture(
+            wgpu::BufferCopyView {
+                buffer: &buffer,
+                offset: 0,
+                row_pitch: 4 * self.width,
+                image_height: self.height,
+            },
+            wgpu::TextureCopyView {
+                texture: &texture,
+                array_layer: 0,
+                mip_level: 0,
+                origin: wgpu::Origin3d {
+                    x: 0.0,
+                    y: 0.0,
+                    z: 0.0,
+                },
+            },
+            extent,
+        );
+
+        let handle = texture::Handle::new(
+            std::sync::Arc::new(texture.create_default_view()),
+            self.width,
+            self.height,
+        );
+
+        self.texture = Some(Texture {
+            version: self.version,
+            _texture: texture,
+            handle: handle.clone(),
+        });
+
+        handle
+    }
+
+    pub fn add_font(&mut self, bytes: Vec<u8>) {
+        let _ = self.brush.add_font_bytes(bytes);
+    }
+
+    pub fn queue(&mut self, section: &VariedSection<'_>, shaper: &Shaper<'_>) {
+        self.brush.queue_custom_layout(section, shaper);
+    }
+
+    /// Packs the queued glyphs, like the draw brush does when drawing them.
+    pub fn process(&mut self) {
+        loop {
+            let Atlas {
+                brush,
+                width,
+                coverage,
+                allocations,
+                version,
+                ..
+            } = self;
+
+            let result = brush.process_queued(
+                |rect, data| {
+                    upload(*width, coverage, rect, data);
+
+                    let allocation = Rectangle {
+                        x: rect.min.x,
+                        y: rect.min.y,
+                        width: rect.width(),
+                        height: rect.height(),
+                    };
+
+                    // When the cache is full, glyphs are packed again from
+                    // scratch, over the glyphs they replace
+                    allocations.retain(|other| !overlaps(other, &allocation));
+                    allocations.push(allocation);
+
+                    *version += 1;
+                },
+                |_| (),
+            );
+
+            match result {
+                Ok(_) => break,
+                Err(BrushError::TextureTooSmall { suggested }) => {
+                    let (width, height) = suggested;
+
+                    self.brush.resize_texture(width, height);
+
+                    self.width = width;
+                    self.height = height;
+                    self.coverage = vec![0; (width * height) as usize];
+                    self.allocations.clear();
+                    self.resizes += 1;
+                    self.version += 1;
+                }
+            }
+        }
+    }
+}
+
+fn upload(width: u32, coverage: &mut [u8], rect: Rect<u32>, data: &[u8]) {
+    let row = rect.width() as usize;
+
+    for (y, line) in data.chunks(row).enumerate() {
+        let start =
+            ((rect.min.y as usize + y) * width as usize) + rect.min.x as usize;
+
+        coverage[start..start + row].copy_from_slice(line);
+    }
+}
+
+fn overlaps(a: &Rectangle<u32>, b: &Rectangle<u32>) -> bool {
+    a.x < b.x + b.width
+        && b.x < a.x + a.width
+        && a.y < b.y + b.height
+        && b.y < a.y + a.height
+}
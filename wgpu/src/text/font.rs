@@ -2,6 +2,9 @@ pub use font_kit::{
     error::SelectionError as LoadError, family_name::FamilyName as Family,
 };
 
+use font_kit::properties::{self, Properties};
+use iced_native::font::{Style, Weight};
+
 pub struct Source {
     raw: font_kit::source::SystemSource,
 }
@@ -14,10 +17,51 @@ impl Source {
     }
 
     pub fn load(&self, families: &[Family]) -> Result<Vec<u8>, LoadError> {
-        let font = self.raw.select_best_match(
+        self.load_with(families, &Properties::default())
+    }
+
+    /// Loads the face of the first installed family of the list closest to
+    /// the given weight.
+    pub fn load_weight(
+        &self,
+        families: &[Family],
+        weight: Weight,
+    ) -> Result<Vec<u8>, LoadError> {
+        self.load_with(
             families,
-            &font_kit::properties::Properties::default(),
-        )?;
+            Properties::new()
+                .weight(properties::Weight(f32::from(weight.value()))),
+        )
+    }
+
+    /// Loads the face of an installed family closest to the given weight and
+    /// style.
+    pub fn load_face(
+        &self,
+        family: &str,
+        weight: Weight,
+        style: Style,
+    ) -> Result<Vec<u8>, LoadError> {
+        let style = match style {
+            Style::Normal => properties::Style::Normal,
+            Style::Italic => properties::Style::Italic,
+            Style::Oblique => properties::Style::Oblique,
+        };
+
+        self.load_with(
+            &[Family::Title(String::from(family))],
+            Properties::new()
+                .weight(properties::Weight(f32::from(weight.value())))
+                .style(style),
+        )
+    }
+
+    fn load_with(
+        &self,
+        families: &[Family],
+        properties: &Properties,
+    ) -> Result<Vec<u8>, LoadError> {
+        let font = self.raw.select_best_match(families, properties)?;
 
         match font {
             font_kit::handle::Handle::Path { path, .. } => {
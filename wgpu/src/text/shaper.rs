@@ -0,0 +1,848 @@
+use glyph_brush::{
+    rusttype::{point, Font, GlyphId, PositionedGlyph, Rect, Scale},
+    BuiltInLineBreaker, FontId, FontMap, GlyphPositioner, HorizontalAlign,
+    Layout, SectionGeometry, SectionText, VerticalAlign,
+};
+use iced_native::text::{Params, Wrap};
+use unicode_bidi::{BidiInfo, ParagraphInfo};
+
+use std::{
+    borrow::Cow,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Range,
+};
+
+/// A [`GlyphPositioner`] that shapes text with `rustybuzz` and reorders it
+/// with the Unicode bidirectional algorithm.
+///
+/// Shaping applies the kerning, ligatures, and contextual forms of the font,
+/// which complex scripts like Arabic and Devanagari need to be readable.
+/// Lines are wrapped following the [`Params`] of the text, and then reordered,
+/// so right-to-left runs read correctly inside left-to-right text and vice
+/// versa.
+///
+/// Text is laid out in logical pixels, exactly like it is measured, and only
+/// then scaled to the physical pixels it is drawn at. This way, lines wrap at
+/// the same words with any scale factor.
+///
+/// [`Params`]: ../../iced_native/text/struct.Params.html
+/// [`GlyphPositioner`]: https://docs.rs/glyph_brush_layout/0.1/glyph_brush_layout/trait.GlyphPositioner.html
+pub struct Shaper<'a> {
+    layout: Layout<BuiltInLineBreaker>,
+    params: Params,
+    faces: &'a [Face],
+    hinting: bool,
+    scale_factor: (f32, f32),
+}
+
+impl<'a> Shaper<'a> {
+    /// Creates a [`Shaper`] aligning text like the given layout, and spacing
+    /// and wrapping its lines with the given [`Params`].
+    ///
+    /// The letter spacing of the [`Params`] must be in the same units as the
+    /// sections of the text.
+    ///
+    /// The `faces` are the [`Face`]s of the fonts of the brush, indexed by
+    /// their `FontId`.
+    ///
+    /// [`Shaper`]: struct.Shaper.html
+    /// [`Params`]: ../../iced_native/text/struct.Params.html
+    /// [`Face`]: struct.Face.html
+    pub fn new(
+        layout: Layout<BuiltInLineBreaker>,
+        params: Params,
+        faces: &'a [Face],
+    ) -> Self {
+        Shaper {
+            layout,
+            params,
+            faces,
+            hinting: false,
+            scale_factor: (1.0, 1.0),
+        }
+    }
+
+    /// Sets the horizontal and vertical factors converting the laid out
+    /// glyphs into the pixels they are drawn at.
+    ///
+    /// By default, glyphs are drawn in the same units they are laid out in.
+    pub fn scale_factor(mut self, x: f32, y: f32) -> Self {
+        self.scale_factor = (x, y);
+        self
+    }
+
+    /// Sets whether the baseline of every line is snapped to a whole pixel
+    /// once scaled.
+    pub fn hinting(mut self, hinting: bool) -> Self {
+        self.hinting = hinting;
+        self
+    }
+}
+
+// The fonts of a brush never change, so the layout and the parameters are
+// enough to tell two shapers apart
+impl Hash for Shaper<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.layout.hash(state);
+        self.params.hash(state);
+        self.hinting.hash(state);
+        self.scale_factor.0.to_bits().hash(state);
+        self.scale_factor.1.to_bits().hash(state);
+    }
+}
+
+impl GlyphPositioner for Shaper<'_> {
+    fn calculate_glyphs<'font, F: FontMap<'font>>(
+        &self,
+        font_map: &F,
+        geometry: &SectionGeometry,
+        sections: &[SectionText<'_>],
+    ) -> Vec<(PositionedGlyph<'font>, [f32; 4], FontId)> {
+        let (scale_x, scale_y) = self.scale_factor;
+        let mut glyphs = Vec::new();
+
+        for (line, x, baseline) in self.arrange(font_map, geometry, sections) {
+            for glyph in line.glyphs {
+                let section = &sections[glyph.section];
+
+                let positioned = font_map
+                    .font(section.font_id)
+                    .glyph(glyph.id)
+                    .scaled(Scale {
+                        x: section.scale.x * scale_x,
+                        y: section.scale.y * scale_y,
+                    })
+                    .positioned(point(
+                        (x + glyph.x) * scale_x,
+                        (baseline + glyph.y) * scale_y,
+                    ));
+
+                glyphs.push((positioned, section.color, section.font_id));
+            }
+        }
+
+        glyphs
+    }
+
+    fn bounds_rect(&self, geometry: &SectionGeometry) -> Rect<f32> {
+        let (scale_x, scale_y) = self.scale_factor;
+        let bounds = self.layout.bounds_rect(geometry);
+
+        Rect {
+            min: point(bounds.min.x * scale_x, bounds.min.y * scale_y),
+            max: point(bounds.max.x * scale_x, bounds.max.y * scale_y),
+        }
+    }
+}
+
+/// The position and metrics of a line of text laid out by a [`Shaper`].
+///
+/// [`Shaper`]: struct.Shaper.html
+#[derive(Debug, Clone, Copy)]
+pub struct Extent {
+    /// The horizontal position of the start of the line.
+    pub x: f32,
+
+    /// The vertical position of the baseline of the line.
+    pub baseline: f32,
+
+    /// The width of the line, without its trailing whitespace.
+    pub width: f32,
+
+    /// The distance from the baseline to the top of the tallest glyphs.
+    pub ascent: f32,
+
+    /// The distance from the baseline to the bottom of the lowest glyphs. It
+    /// is negative.
+    pub descent: f32,
+}
+
+impl Shaper<'_> {
+    /// Returns the [`Extent`] of every line of the given text, laid out like
+    /// it is drawn.
+    ///
+    /// [`Extent`]: struct.Extent.html
+    pub fn extents<'font, F: FontMap<'font>>(
+        &self,
+        font_map: &F,
+        geometry: &SectionGeometry,
+        sections: &[SectionText<'_>],
+    ) -> Vec<Extent> {
+        self.arrange(font_map, geometry, sections)
+            .into_iter()
+            .map(|(line, x, baseline)| Extent {
+                x,
+                baseline,
+                width: line.width,
+                ascent: line.ascent,
+                descent: line.descent,
+            })
+            .collect()
+    }
+
+    /// Returns the index of the section with a glyph at the given point, if
+    /// any, laid out like it is drawn.
+    ///
+    /// The glyphs of a line are hit between the top of its tallest glyphs and
+    /// the bottom of its lowest ones, until the start of the next glyph.
+    pub fn section_at<'font, F: FontMap<'font>>(
+        &self,
+        font_map: &F,
+        geometry: &SectionGeometry,
+        sections: &[SectionText<'_>],
+        (x, y): (f32, f32),
+    ) -> Option<usize> {
+        self.arrange(font_map, geometry, sections)
+            .into_iter()
+            .filter(|(line, start, baseline)| {
+                y >= baseline - line.ascent
+                    && y < baseline - line.descent
+                    && x >= *start
+                    && x < start + line.width
+            })
+            .find_map(|(line, start, _)| {
+                line.glyphs
+                    .iter()
+                    .rev()
+                    .find(|glyph| start + glyph.x <= x)
+                    .map(|glyph| glyph.section)
+            })
+    }
+
+    /// Returns the byte offset in the text of the caret closest to the given
+    /// point, laid out like it is drawn.
+    ///
+    /// Points above or below the text hit its first or last line.
+    pub fn offset_at<'font, F: FontMap<'font>>(
+        &self,
+        font_map: &F,
+        geometry: &SectionGeometry,
+        sections: &[SectionText<'_>],
+        (x, y): (f32, f32),
+    ) -> usize {
+        let distance = |(line, _, baseline): &&(Line, f32, f32)| {
+            let top = baseline - line.ascent;
+            let bottom = baseline - line.descent;
+
+            (top - y).max(y - bottom).max(0.0)
+        };
+
+        let arranged = self.arrange(font_map, geometry, sections);
+
+        let closest = arranged.iter().min_by(|a, b| {
+            distance(a)
+                .partial_cmp(&distance(b))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let (line, start, _) = match closest {
+            Some(closest) => closest,
+            None => return 0,
+        };
+
+        line.glyphs
+            .iter()
+            .flat_map(Glyph::carets)
+            .map(|(caret, offset)| ((start + caret - x).abs(), offset))
+            .min_by(|(a, _), (b, _)| {
+                a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|(_, offset)| offset)
+            .unwrap_or(line.start)
+    }
+
+    /// Returns the rectangles covering the glyphs of the given byte range of
+    /// the text, laid out like it is drawn, with one rectangle for every
+    /// contiguous stretch of a line.
+    ///
+    /// The rectangles take the full height of their lines, leading included,
+    /// so the selection of consecutive lines has no gaps.
+    pub fn selection<'font, F: FontMap<'font>>(
+        &self,
+        font_map: &F,
+        geometry: &SectionGeometry,
+        sections: &[SectionText<'_>],
+        range: Range<usize>,
+    ) -> Vec<Rect<f32>> {
+        let mut rectangles = Vec::new();
+
+        for (line, start, baseline) in
+            self.arrange(font_map, geometry, sections)
+        {
+            let height = line.height() * self.params.line_height;
+            let top = baseline - line.ascent - (height - line.height()) / 2.0;
+
+            let mut stretches: Vec<(f32, f32)> = line
+                .glyphs
+                .iter()
+                .filter(|glyph| range.contains(&glyph.cluster.0))
+                .map(|glyph| {
+                    (start + glyph.pen, start + glyph.pen + glyph.advance)
+                })
+                .collect();
+
+            stretches.sort_by(|(a, _), (b, _)| {
+                a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal)
+            });
+
+            let mut current: Option<(f32, f32)> = None;
+
+            for (left, right) in stretches {
+                current = match current {
+                    // Glyphs touching or overlapping join the same stretch
+                    Some((current_left, current_right))
+                        if left <= current_right + 0.5 =>
+                    {
+                        Some((current_left, current_right.max(right)))
+                    }
+                    Some((current_left, current_right)) => {
+                        rectangles.push(Rect {
+                            min: point(current_left, top),
+                            max: point(current_right, top + height),
+                        });
+
+                        Some((left, right))
+                    }
+                    None => Some((left, right)),
+                };
+            }
+
+            if let Some((left, right)) = current {
+                rectangles.push(Rect {
+                    min: point(left, top),
+                    max: point(right, top + height),
+                });
+            }
+        }
+
+        rectangles
+    }
+
+    /// Lays out the lines of some text and returns each of them with the
+    /// position of its start and its baseline.
+    fn arrange<'font, F: FontMap<'font>>(
+        &self,
+        font_map: &F,
+        geometry: &SectionGeometry,
+        sections: &[SectionText<'_>],
+    ) -> Vec<(Line, f32, f32)> {
+        let (h_align, v_align, max_width) = match self.layout {
+            Layout::SingleLine {
+                h_align, v_align, ..
+            } => (h_align, v_align, f32::INFINITY),
+            Layout::Wrap {
+                h_align, v_align, ..
+            } => (h_align, v_align, geometry.bounds.0),
+        };
+
+        let max_width = match self.params.wrap {
+            Wrap::None => f32::INFINITY,
+            Wrap::Word | Wrap::Character => max_width,
+        };
+
+        let text = Text::new(sections);
+        let bidi = BidiInfo::new(&text.content, None);
+
+        let lines: Vec<Line> = bidi
+            .paragraphs
+            .iter()
+            .flat_map(|paragraph| {
+                self.wrap(font_map, &text, &bidi, paragraph, max_width)
+                    .into_iter()
+                    .map(move |range| (paragraph, range))
+            })
+            .map(|(paragraph, range)| {
+                self.line(font_map, &text, &bidi, paragraph, range)
+            })
+            .collect();
+
+        let line_height = |line: &Line| line.height() * self.params.line_height;
+        let height: f32 = lines.iter().map(line_height).sum();
+
+        let (x, mut y) = geometry.screen_position;
+
+        y -= match v_align {
+            VerticalAlign::Top => 0.0,
+            VerticalAlign::Center => height / 2.0,
+            VerticalAlign::Bottom => height,
+        };
+
+        let mut arranged = Vec::with_capacity(lines.len());
+
+        for line in lines {
+            let x = x - match h_align {
+                HorizontalAlign::Left => 0.0,
+                HorizontalAlign::Center => line.width / 2.0,
+                HorizontalAlign::Right => line.width,
+            };
+
+            // The leading is split evenly above and below the line
+            let height = line_height(&line);
+            let baseline = y + (height - line.height()) / 2.0 + line.ascent;
+
+            // Glyphs are rasterized with subpixel precision. Snapping the
+            // baseline keeps horizontal stems from being smeared over two
+            // rows of pixels.
+            let baseline = if self.hinting {
+                let scale_y = self.scale_factor.1;
+
+                (baseline * scale_y).round() / scale_y
+            } else {
+                baseline
+            };
+
+            arranged.push((line, x, baseline));
+
+            y += height;
+        }
+
+        arranged
+    }
+
+    /// Splits a paragraph into the byte ranges of its lines, breaking after
+    /// whitespace, or before any character, when a segment would overflow
+    /// the given width.
+    fn wrap<'font, F: FontMap<'font>>(
+        &self,
+        font_map: &F,
+        text: &Text<'_>,
+        bidi: &BidiInfo<'_>,
+        paragraph: &ParagraphInfo,
+        max_width: f32,
+    ) -> Vec<Range<usize>> {
+        let content = &text.content;
+        let end = paragraph.range.start
+            + content[paragraph.range.clone()]
+                .trim_end_matches(|c| c == '\n' || c == '\r')
+                .len();
+
+        let mut lines = Vec::new();
+        let mut start = paragraph.range.start;
+        let mut width = 0.0;
+
+        let advance = |range: Range<usize>| {
+            let is_rtl = bidi.levels[range.start].is_rtl();
+
+            self.shape(font_map, text, range, is_rtl).1
+        };
+
+        let mut segment_start = start;
+
+        while segment_start < end {
+            let word_end = match self.params.wrap {
+                Wrap::Character => content[segment_start..end]
+                    .chars()
+                    .next()
+                    .filter(|c| !c.is_whitespace())
+                    .map(|c| segment_start + c.len_utf8())
+                    .unwrap_or(segment_start),
+                Wrap::Word | Wrap::None => content[segment_start..end]
+                    .find(char::is_whitespace)
+                    .map(|i| segment_start + i)
+                    .unwrap_or(end),
+            };
+
+            let segment_end = content[word_end..end]
+                .find(|c: char| !c.is_whitespace())
+                .map(|i| word_end + i)
+                .unwrap_or(end);
+
+            let word = advance(segment_start..word_end);
+
+            if width + word > max_width && segment_start > start {
+                lines.push(start..segment_start);
+
+                start = segment_start;
+                width = 0.0;
+            }
+
+            width += word;
+
+            if segment_end > word_end {
+                width += advance(word_end..segment_end);
+            }
+
+            segment_start = segment_end;
+        }
+
+        lines.push(start..end);
+        lines
+    }
+
+    /// Shapes the runs of a line in visual order.
+    fn line<'font, F: FontMap<'font>>(
+        &self,
+        font_map: &F,
+        text: &Text<'_>,
+        bidi: &BidiInfo<'_>,
+        paragraph: &ParagraphInfo,
+        range: Range<usize>,
+    ) -> Line {
+        let mut line = Line {
+            glyphs: Vec::new(),
+            start: range.start,
+            width: 0.0,
+            ascent: 0.0,
+            descent: 0.0,
+            line_gap: 0.0,
+        };
+
+        // Empty lines still take the height of their text
+        let first = text.section_at(range.start);
+        let last = text.section_at(range.end.max(range.start + 1) - 1);
+
+        for section in first..=last {
+            let metrics = font_map
+                .font(text.sections[section].font_id)
+                .v_metrics(text.sections[section].scale);
+
+            line.ascent = line.ascent.max(metrics.ascent);
+            line.descent = line.descent.min(metrics.descent);
+            line.line_gap = line.line_gap.max(metrics.line_gap);
+        }
+
+        // Trailing whitespace does not count when aligning a line
+        let end = range.start
+            + text.content[range.clone()]
+                .trim_end_matches(char::is_whitespace)
+                .len();
+
+        if end == range.start {
+            return line;
+        }
+
+        let (levels, runs) = bidi.visual_runs(paragraph, range.start..end);
+
+        for run in runs {
+            let is_rtl = levels[run.start].is_rtl();
+            let (glyphs, advance) = self.shape(font_map, text, run, is_rtl);
+
+            line.glyphs.extend(glyphs.into_iter().map(|glyph| Glyph {
+                x: line.width + glyph.x,
+                pen: line.width + glyph.pen,
+                ..glyph
+            }));
+
+            line.width += advance;
+        }
+
+        line
+    }
+
+    /// Shapes a run of text with a single direction, which can span many
+    /// sections, and returns its glyphs, from left to right, and its advance.
+    fn shape<'font, F: FontMap<'font>>(
+        &self,
+        font_map: &F,
+        text: &Text<'_>,
+        range: Range<usize>,
+        is_rtl: bool,
+    ) -> (Vec<Glyph>, f32) {
+        let run_end = range.end;
+        let mut pieces: Vec<_> = text.pieces(range).collect();
+
+        // The first section of a right-to-left run is its rightmost one
+        if is_rtl {
+            pieces.reverse();
+        }
+
+        let mut glyphs = Vec::new();
+        let mut advance = 0.0;
+
+        for (section, range) in pieces {
+            let font_id = text.sections[section].font_id;
+            let font = font_map.font(font_id);
+            let scale = text.sections[section].scale;
+            let content = &text.content[range.clone()];
+
+            let spacing = self.params.letter_spacing;
+
+            let shaped = self
+                .faces
+                .get(font_id.0)
+                .and_then(|face| face.0.as_ref())
+                .map(|face| shape(font, face, content, scale, spacing, is_rtl));
+
+            let (piece, piece_advance) = shaped.unwrap_or_else(|| {
+                fallback(font, content, scale, spacing, is_rtl)
+            });
+
+            glyphs.extend(piece.into_iter().map(|glyph| Glyph {
+                section,
+                x: advance + glyph.x,
+                pen: advance + glyph.pen,
+                cluster: (
+                    range.start + glyph.cluster.0,
+                    range.start + glyph.cluster.1,
+                ),
+                ..glyph
+            }));
+
+            advance += piece_advance;
+        }
+
+        // A cluster ends where the next one in the text starts
+        let mut starts: Vec<usize> =
+            glyphs.iter().map(|glyph| glyph.cluster.0).collect();
+
+        starts.sort_unstable();
+        starts.dedup();
+
+        for glyph in &mut glyphs {
+            let next = match starts.binary_search(&glyph.cluster.0) {
+                Ok(i) => starts.get(i + 1),
+                Err(i) => starts.get(i),
+            };
+
+            glyph.cluster.1 =
+                next.copied().unwrap_or(run_end).max(glyph.cluster.1);
+        }
+
+        (glyphs, advance)
+    }
+}
+
+/// A font parsed for shaping.
+///
+/// Parsing a font is expensive, so it is done once, when the font is added to
+/// the brushes.
+pub struct Face(Option<rustybuzz::Face<'static>>);
+
+impl Face {
+    /// Parses the bytes of a font.
+    ///
+    /// Fonts are never removed from the brushes, so the bytes are leaked to
+    /// let the [`Face`] borrow them for as long as the application runs.
+    ///
+    /// [`Face`]: struct.Face.html
+    pub fn new(bytes: Cow<'static, [u8]>) -> Self {
+        let bytes: &'static [u8] = match bytes {
+            Cow::Borrowed(bytes) => bytes,
+            Cow::Owned(bytes) => Box::leak(bytes.into_boxed_slice()),
+        };
+
+        Face(rustybuzz::Face::from_slice(bytes, 0))
+    }
+}
+
+impl fmt::Debug for Face {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Face")
+            .field(&self.0.as_ref().map(|_| "rustybuzz::Face"))
+            .finish()
+    }
+}
+
+/// The sections of some text, joined together.
+struct Text<'a> {
+    content: String,
+    sections: &'a [SectionText<'a>],
+    ends: Vec<usize>,
+}
+
+impl<'a> Text<'a> {
+    fn new(sections: &'a [SectionText<'a>]) -> Self {
+        let mut content = String::new();
+        let mut ends = Vec::with_capacity(sections.len());
+
+        for section in sections {
+            content.push_str(section.text);
+            ends.push(content.len());
+        }
+
+        Text {
+            content,
+            sections,
+            ends,
+        }
+    }
+
+    fn section_at(&self, index: usize) -> usize {
+        self.ends
+            .iter()
+            .position(|end| index < *end)
+            .unwrap_or_else(|| self.ends.len().max(1) - 1)
+    }
+
+    /// Splits a range of the content into the ranges of each section.
+    fn pieces(
+        &self,
+        range: Range<usize>,
+    ) -> impl Iterator<Item = (usize, Range<usize>)> + '_ {
+        self.ends
+            .iter()
+            .enumerate()
+            .filter_map(move |(section, end)| {
+                let start = if section == 0 {
+                    0
+                } else {
+                    self.ends[section - 1]
+                };
+
+                let start = start.max(range.start);
+                let end = (*end).min(range.end);
+
+                if start < end {
+                    Some((section, start..end))
+                } else {
+                    None
+                }
+            })
+    }
+}
+
+struct Line {
+    glyphs: Vec<Glyph>,
+    start: usize,
+    width: f32,
+    ascent: f32,
+    descent: f32,
+    line_gap: f32,
+}
+
+impl Line {
+    fn height(&self) -> f32 {
+        self.ascent - self.descent + self.line_gap
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Glyph {
+    id: GlyphId,
+    section: usize,
+    x: f32,
+    y: f32,
+
+    // The position of the pen before the glyph, without its offset, and the
+    // space it advances
+    pen: f32,
+    advance: f32,
+
+    // The byte range of the characters the glyph was shaped from
+    cluster: (usize, usize),
+    is_rtl: bool,
+}
+
+impl Glyph {
+    /// Returns the carets at the left and right edges of the glyph, as their
+    /// horizontal position and their byte offset in the text.
+    fn carets(&self) -> [(f32, usize); 2] {
+        let (start, end) = self.cluster;
+        let (left, right) = if self.is_rtl {
+            (end, start)
+        } else {
+            (start, end)
+        };
+
+        [(self.pen, left), (self.pen + self.advance, right)]
+    }
+}
+
+/// Shapes some text with a single font and direction, adding the given
+/// spacing after every glyph that advances.
+///
+/// It returns the glyphs, from left to right, with their positions and
+/// clusters relative to the start of the text, and the advance of the text.
+/// The end of the clusters is left for the caller to find.
+fn shape(
+    font: &Font<'_>,
+    face: &rustybuzz::Face<'_>,
+    content: &str,
+    scale: Scale,
+    spacing: f32,
+    is_rtl: bool,
+) -> (Vec<Glyph>, f32) {
+    let mut buffer = rustybuzz::UnicodeBuffer::new();
+    buffer.push_str(content);
+    buffer.set_direction(if is_rtl {
+        rustybuzz::Direction::RightToLeft
+    } else {
+        rustybuzz::Direction::LeftToRight
+    });
+    buffer.guess_segment_properties();
+
+    let output = rustybuzz::shape(face, &[], buffer);
+
+    // `rusttype` scales fonts by the height of their ascent and descent,
+    // instead of by their units per em
+    let metrics = font.v_metrics_unscaled();
+    let height = metrics.ascent - metrics.descent;
+    let (scale_x, scale_y) = (scale.x / height, scale.y / height);
+
+    let mut glyphs = Vec::with_capacity(output.len());
+    let mut advance = 0.0;
+
+    for (info, position) in
+        output.glyph_infos().iter().zip(output.glyph_positions())
+    {
+        // Marks do not advance, so they stay on top of their base glyph
+        let glyph_advance = if position.x_advance != 0 {
+            position.x_advance as f32 * scale_x + spacing
+        } else {
+            0.0
+        };
+
+        glyphs.push(Glyph {
+            id: GlyphId(info.glyph_id as u16),
+            section: 0,
+            x: advance + position.x_offset as f32 * scale_x,
+            y: -position.y_offset as f32 * scale_y,
+            pen: advance,
+            advance: glyph_advance,
+            cluster: (info.cluster as usize, info.cluster as usize),
+            is_rtl,
+        });
+
+        advance += glyph_advance;
+    }
+
+    (glyphs, advance)
+}
+
+/// Lays out some text one character at a time, with the kerning of the font
+/// and the given spacing, when it cannot be shaped.
+fn fallback(
+    font: &Font<'_>,
+    content: &str,
+    scale: Scale,
+    spacing: f32,
+    is_rtl: bool,
+) -> (Vec<Glyph>, f32) {
+    let mut glyphs = Vec::new();
+    let mut advance = 0.0;
+    let mut previous = None;
+
+    for (index, c) in content.char_indices() {
+        let glyph = font.glyph(c).scaled(scale);
+        let id = glyph.id();
+
+        if let Some(previous) = previous {
+            advance += font.pair_kerning(scale, previous, id);
+        }
+
+        let advance_width = glyph.h_metrics().advance_width;
+
+        let glyph_advance = if advance_width > 0.0 {
+            advance_width + spacing
+        } else {
+            0.0
+        };
+
+        glyphs.push(Glyph {
+            id,
+            section: 0,
+            x: advance,
+            y: 0.0,
+            pen: advance,
+            advance: glyph_advance,
+            cluster: (index, index + c.len_utf8()),
+            is_rtl,
+        });
+
+        advance += glyph_advance;
+        previous = Some(id);
+    }
+
+    (glyphs, advance)
+}
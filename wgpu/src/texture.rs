@@ -0,0 +1,380 @@
+//! Display textures that change every frame, like video.
+//!
+//! A [`Primitive::Texture`] draws a texture you own, like an image, but
+//! without going through the image cache. Nothing is uploaded or cached by
+//! the renderer: you update the texture and it shows up in the next frame.
+//!
+//! Decoders often produce frames in the NV12 format. A [`Nv12`] frame keeps
+//! its planes in the GPU and converts them to a texture that can be drawn.
+//!
+//! # Example
+//! ```ignore
+//! let frame = texture::Nv12::new(renderer.device(), 1920, 1080);
+//!
+//! // Every time the decoder produces a frame
+//! renderer.upload_nv12(&frame, &decoded.luma, &decoded.chroma);
+//!
+//! let primitive = Primitive::Texture {
+//!     handle: frame.handle(),
+//!     bounds,
+//!     border_radius: 0,
+//!     fit: image::Fit::Contain,
+//! };
+//! ```
+//!
+//! [`Primitive::Texture`]: ../enum.Primitive.html#variant.Texture
+//! [`Nv12`]: struct.Nv12.html
+use std::sync::Arc;
+
+/// A texture that can be drawn by a [`Primitive::Texture`].
+///
+/// The texture must be created with the [`Device`] of the [`Renderer`] and
+/// the `SAMPLED` usage. Its color is read as sRGB when its format is sRGB,
+/// like `Bgra8UnormSrgb`, and as linear otherwise.
+///
+/// [`Primitive::Texture`]: ../enum.Primitive.html#variant.Texture
+/// [`Device`]: ../struct.Renderer.html#method.device
+/// [`Renderer`]: ../struct.Renderer.html
+#[derive(Debug, Clone)]
+pub struct Handle {
+    view: Arc<wgpu::TextureView>,
+    width: u32,
+    height: u32,
+}
+
+impl Handle {
+    /// Creates a [`Handle`] from a view of a texture and its dimensions, in
+    /// pixels.
+    ///
+    /// [`Handle`]: struct.Handle.html
+    pub fn new(view: Arc<wgpu::TextureView>, width: u32, height: u32) -> Self {
+        Handle {
+            view,
+            width,
+            height,
+        }
+    }
+
+    /// Returns the dimensions of the texture of the [`Handle`], in pixels.
+    ///
+    /// [`Handle`]: struct.Handle.html
+    pub fn dimensions(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    pub(crate) fn view(&self) -> &wgpu::TextureView {
+        &self.view
+    }
+}
+
+/// A frame of video in the NV12 format, kept in the GPU.
+///
+/// The frame is made of two planes: a luma plane with a byte per pixel, and
+/// a chroma plane with two interleaved bytes, `Cb` and `Cr`, per block of
+/// 2x2 pixels. The colors are converted assuming BT.709 in limited range,
+/// which is what most HD video uses.
+///
+/// Use [`Renderer::upload_nv12`] to replace the contents of the frame.
+///
+/// [`Renderer::upload_nv12`]: ../struct.Renderer.html#method.upload_nv12
+#[derive(Debug)]
+pub struct Nv12 {
+    luma: wgpu::Texture,
+    chroma: wgpu::Texture,
+    pipeline: wgpu::RenderPipeline,
+    bind_group: wgpu::BindGroup,
+    _output: wgpu::Texture,
+    handle: Handle,
+}
+
+impl Nv12 {
+    /// Creates a new black [`Nv12`] frame with the given dimensions, in
+    /// pixels.
+    ///
+    /// [`Nv12`]: struct.Nv12.html
+    pub fn new(device: &wgpu::Device, width: u32, height: u32) -> Self {
+        let plane = |width, height, format, usage| {
+            device.create_texture(&wgpu::TextureDescriptor {
+                size: wgpu::Extent3d {
+                    width,
+                    height,
+                    depth: 1,
+                },
+                array_layer_count: 1,
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format,
+                usage,
+            })
+        };
+
+        let (chroma_width, chroma_height) = chroma_size(width, height);
+
+        let luma = plane(
+            width,
+            height,
+            wgpu::TextureFormat::R8Unorm,
+            wgpu::TextureUsage::COPY_DST | wgpu::TextureUsage::SAMPLED,
+        );
+        let chroma = plane(
+            chroma_width,
+            chroma_height,
+            wgpu::TextureFormat::Rg8Unorm,
+            wgpu::TextureUsage::COPY_DST | wgpu::TextureUsage::SAMPLED,
+        );
+        let output = plane(
+            width,
+            height,
+            wgpu::TextureFormat::Bgra8UnormSrgb,
+            wgpu::TextureUsage::OUTPUT_ATTACHMENT | wgpu::TextureUsage::SAMPLED,
+        );
+
+        let layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                bindings: &[
+                    wgpu::BindGroupLayoutBinding {
+                        binding: 0,
+                        visibility: wgpu::ShaderStage::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler,
+                    },
+                    wgpu::BindGroupLayoutBinding {
+                        binding: 1,
+                        visibility: wgpu::ShaderStage::FRAGMENT,
+                        ty: wgpu::BindingType::SampledTexture {
+                            multisampled: false,
+                            dimension: wgpu::TextureViewDimension::D2,
+                        },
+                    },
+                    wgpu::BindGroupLayoutBinding {
+                        binding: 2,
+                        visibility: wgpu::ShaderStage::FRAGMENT,
+                        ty: wgpu::BindingType::SampledTexture {
+                            multisampled: false,
+                            dimension: wgpu::TextureViewDimension::D2,
+                        },
+                    },
+                ],
+            });
+
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            mipmap_filter: wgpu::FilterMode::Nearest,
+            lod_min_clamp: -100.0,
+            lod_max_clamp: 100.0,
+            compare_function: wgpu::CompareFunction::Always,
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            layout: &layout,
+            bindings: &[
+                wgpu::Binding {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Sampler(&sampler),
+                },
+                wgpu::Binding {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(
+                        &luma.create_default_view(),
+                    ),
+                },
+                wgpu::Binding {
+                    binding: 2,
+                    resource: wgpu::BindingResource::TextureView(
+                        &chroma.create_default_view(),
+                    ),
+                },
+            ],
+        });
+
+        let pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                bind_group_layouts: &[&layout],
+            });
+
+        let vs = include_bytes!("shader/fullscreen.vert.spv");
+        let vs_module = device.create_shader_module(
+            &wgpu::read_spirv(std::io::Cursor::new(&vs[..]))
+                .expect("Read fullscreen vertex shader as SPIR-V"),
+        );
+
+        let fs = include_bytes!("shader/yuv.frag.spv");
+        let fs_module = device.create_shader_module(
+            &wgpu::read_spirv(std::io::Cursor::new(&fs[..]))
+                .expect("Read yuv fragment shader as SPIR-V"),
+        );
+
+        let pipeline =
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                layout: &pipeline_layout,
+                vertex_stage: wgpu::ProgrammableStageDescriptor {
+                    module: &vs_module,
+                    entry_point: "main",
+                },
+                fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
+                    module: &fs_module,
+                    entry_point: "main",
+                }),
+                rasterization_state: Some(wgpu::RasterizationStateDescriptor {
+                    front_face: wgpu::FrontFace::Cw,
+                    cull_mode: wgpu::CullMode::None,
+                    depth_bias: 0,
+                    depth_bias_slope_scale: 0.0,
+                    depth_bias_clamp: 0.0,
+                }),
+                primitive_topology: wgpu::PrimitiveTopology::TriangleList,
+                color_states: &[wgpu::ColorStateDescriptor {
+                    format: wgpu::TextureFormat::Bgra8UnormSrgb,
+                    color_blend: wgpu::BlendDescriptor::REPLACE,
+                    alpha_blend: wgpu::BlendDescriptor::REPLACE,
+                    write_mask: wgpu::ColorWrite::ALL,
+                }],
+                depth_stencil_state: None,
+                index_format: wgpu::IndexFormat::Uint16,
+                vertex_buffers: &[],
+                sample_count: 1,
+                sample_mask: !0,
+                alpha_to_coverage_enabled: false,
+            });
+
+        let handle =
+            Handle::new(Arc::new(output.create_default_view()), width, height);
+
+        Nv12 {
+            luma,
+            chroma,
+            pipeline,
+            bind_group,
+            _output: output,
+            handle,
+        }
+    }
+
+    /// Returns the [`Handle`] of the converted frame, ready to be drawn.
+    ///
+    /// [`Handle`]: struct.Handle.html
+    pub fn handle(&self) -> Handle {
+        self.handle.clone()
+    }
+
+    /// Records the commands replacing the planes of the frame and converting
+    /// them.
+    pub(crate) fn upload(
+        &self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        luma: &[u8],
+        chroma: &[u8],
+    ) {
+        let (width, height) = self.handle.dimensions();
+        let (chroma_width, chroma_height) = chroma_size(width, height);
+
+        assert_eq!(
+            luma.len(),
+            (width * height) as usize,
+            "Luma plane of {}x{} pixels",
+            width,
+            height
+        );
+        assert_eq!(
+            chroma.len(),
+            (chroma_width * chroma_height * 2) as usize,
+            "Chroma plane of {}x{} samples",
+            chroma_width,
+            chroma_height
+        );
+
+        copy_plane(device, encoder, &self.luma, luma, width, height, 1);
+        copy_plane(
+            device,
+            encoder,
+            &self.chroma,
+            chroma,
+            chroma_width,
+            chroma_height,
+            2,
+        );
+
+        let mut render_pass =
+            encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                color_attachments: &[
+                    wgpu::RenderPassColorAttachmentDescriptor {
+                        attachment: self.handle.view(),
+                        resolve_target: None,
+                        load_op: wgpu::LoadOp::Clear,
+                        store_op: wgpu::StoreOp::Store,
+                        clear_color: wgpu::Color {
+                            r: 0.0,
+                            g: 0.0,
+                            b: 0.0,
+                            a: 1.0,
+                        },
+                    },
+                ],
+                depth_stencil_attachment: None,
+            });
+
+        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_bind_group(0, &self.bind_group, &[]);
+        render_pass.draw(0..3, 0..1);
+    }
+}
+
+fn chroma_size(width: u32, height: u32) -> (u32, u32) {
+    ((width + 1) / 2, (height + 1) / 2)
+}
+
+fn copy_plane(
+    device: &wgpu::Device,
+    encoder: &mut wgpu::CommandEncoder,
+    texture: &wgpu::Texture,
+    data: &[u8],
+    width: u32,
+    height: u32,
+    bytes_per_pixel: u32,
+) {
+    // Rows of a buffer copy must be aligned to 256 bytes
+    let row_size = (width * bytes_per_pixel) as usize;
+    let row_pitch = (row_size + 255) / 256 * 256;
+
+    let mut mapped = device.create_buffer_mapped(
+        row_pitch * height as usize,
+        wgpu::BufferUsage::COPY_SRC,
+    );
+
+    for (row, pixels) in
+        mapped.data.chunks_mut(row_pitch).zip(data.chunks(row_size))
+    {
+        row[..row_size].copy_from_slice(pixels);
+    }
+
+    let buffer = mapped.finish();
+
+    encoder.copy_buffer_to_texture(
+        wgpu::BufferCopyView {
+            buffer: &buffer,
+            offset: 0,
+            row_pitch: row_pitch as u32,
+            image_height: height,
+        },
+        wgpu::TextureCopyView {
+            texture,
+            array_layer: 0,
+            mip_level: 0,
+            origin: wgpu::Origin3d {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+            },
+        },
+        wgpu::Extent3d {
+            width,
+            height,
+            depth: 1,
+        },
+    );
+}
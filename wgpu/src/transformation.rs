@@ -1,4 +1,5 @@
 use glam::{Mat4, Vec3, Vec4};
+use iced_native::Rectangle;
 use std::ops::Mul;
 
 /// A 2D transformation matrix.
@@ -31,6 +32,48 @@ impl Transformation {
     pub fn scale(x: f32, y: f32) -> Transformation {
         Transformation(Mat4::from_scale(Vec3::new(x, y, 1.0)))
     }
+
+    /// Creates a clockwise rotation transformation, in radians.
+    pub fn rotate(angle: f32) -> Transformation {
+        Transformation(Mat4::from_rotation_z(angle))
+    }
+
+    /// Returns the smallest rectangle containing the given bounds once
+    /// transformed.
+    pub(crate) fn transform_bounds(&self, bounds: Rectangle) -> Rectangle {
+        let corners = [
+            (bounds.x, bounds.y),
+            (bounds.x + bounds.width, bounds.y),
+            (bounds.x, bounds.y + bounds.height),
+            (bounds.x + bounds.width, bounds.y + bounds.height),
+        ]
+        .iter()
+        .map(|&(x, y)| self.0.mul_vec4(Vec4::new(x, y, 0.0, 1.0)));
+
+        let (min_x, min_y, max_x, max_y) = corners.fold(
+            (
+                f32::INFINITY,
+                f32::INFINITY,
+                f32::NEG_INFINITY,
+                f32::NEG_INFINITY,
+            ),
+            |(min_x, min_y, max_x, max_y), corner| {
+                (
+                    min_x.min(corner.x()),
+                    min_y.min(corner.y()),
+                    max_x.max(corner.x()),
+                    max_y.max(corner.y()),
+                )
+            },
+        );
+
+        Rectangle {
+            x: min_x,
+            y: min_y,
+            width: max_x - min_x,
+            height: max_y - min_y,
+        }
+    }
 }
 
 impl Mul for Transformation {
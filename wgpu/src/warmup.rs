@@ -0,0 +1,69 @@
+use iced_native::{image, svg, Font, Size};
+
+/// The resources the [`Renderer`] should prepare before the first frame.
+///
+/// Glyphs are rasterized, images are decoded and uploaded, and SVGs are
+/// rasterized the first time they are drawn. A first frame full of them can
+/// take long enough to show a blank or janky window. A [`Warmup`] lets the
+/// [`Renderer`] do that work ahead of time, like while a splash screen or the
+/// window of a previous run is still visible.
+///
+/// # Example
+/// ```ignore
+/// let warmup = Warmup::new()
+///     .text("0123456789", 20, Font::Default)
+///     .image(image::Handle::from_path("logo.png"))
+///     .svg(svg::Handle::from_path("icon.svg"), Size::new(24.0, 24.0));
+///
+/// renderer.warmup(&warmup, window.hidpi_factor() as f32);
+/// ```
+///
+/// [`Renderer`]: struct.Renderer.html
+/// [`Warmup`]: struct.Warmup.html
+#[derive(Debug, Clone, Default)]
+pub struct Warmup {
+    pub(crate) text: Vec<(String, u16, Font)>,
+    pub(crate) images: Vec<image::Handle>,
+    pub(crate) svgs: Vec<(svg::Handle, Size)>,
+}
+
+impl Warmup {
+    /// Creates an empty [`Warmup`].
+    ///
+    /// [`Warmup`]: struct.Warmup.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rasterizes the glyphs of the given content, with the size and font of
+    /// the text showing it.
+    ///
+    /// Every glyph is rasterized once, so a charset like `"0123456789"` is
+    /// enough to cover any number.
+    pub fn text(
+        mut self,
+        content: impl Into<String>,
+        size: u16,
+        font: Font,
+    ) -> Self {
+        self.text.push((content.into(), size, font));
+        self
+    }
+
+    /// Decodes and uploads the image of the given [`Handle`].
+    ///
+    /// [`Handle`]: ../iced_native/image/struct.Handle.html
+    pub fn image(mut self, handle: image::Handle) -> Self {
+        self.images.push(handle);
+        self
+    }
+
+    /// Rasterizes the SVG of the given [`Handle`] at the size it will be
+    /// drawn at, in logical pixels.
+    ///
+    /// [`Handle`]: ../iced_native/svg/struct.Handle.html
+    pub fn svg(mut self, handle: svg::Handle, size: Size) -> Self {
+        self.svgs.push((handle, size));
+        self
+    }
+}
@@ -1,10 +1,13 @@
 use crate::{
-    conversion,
-    input::{keyboard, mouse},
+    dialogs, frame, hotkey,
+    input::{keyboard, ButtonState},
+    notification,
     renderer::{Target, Windowed},
-    subscription, Cache, Clipboard, Command, Container, Debug, Element, Event,
-    Length, MouseCursor, Settings, Subscription, UserInterface,
+    shell, subscription, text_scale, toasts, url, Cache, Command, Container,
+    Debug, Dialogs, Element, Event, Host, Hotkey, Length, MouseCursor, Proxy,
+    Settings, Shell, Size, Subscription, Toasts, UserInterface,
 };
+use std::time::Instant;
 
 /// An interactive, native cross-platform application.
 ///
@@ -18,7 +21,7 @@ pub trait Application: Sized {
     /// The renderer to use to draw the [`Application`].
     ///
     /// [`Application`]: trait.Application.html
-    type Renderer: Windowed;
+    type Renderer: Windowed + toasts::Renderer + dialogs::Renderer;
 
     /// The type of __messages__ your [`Application`] will produce.
     ///
@@ -70,8 +73,50 @@ pub trait Application: Sized {
     ///
     /// These widgets can produce __messages__ based on user interaction.
     ///
+    /// The runtime keeps the local state of widgets created with `managed`,
+    /// like a `Button::managed`, so the [`Application`] only needs to be
+    /// borrowed to be viewed.
+    ///
+    /// [`Application`]: trait.Application.html
+    fn view(&self) -> Element<'_, Self::Message, Self::Renderer>;
+
+    /// Returns the keyboard shortcuts of the [`Application`] for its current
+    /// state.
+    ///
+    /// A key press matching a [`Hotkey`] produces its message before any
+    /// widget sees it, and the widgets never do.
+    ///
+    /// By default, it returns no hotkeys.
+    ///
     /// [`Application`]: trait.Application.html
-    fn view(&mut self) -> Element<'_, Self::Message, Self::Renderer>;
+    /// [`Hotkey`]: struct.Hotkey.html
+    fn hotkeys(&self) -> Vec<Hotkey<Self::Message>> {
+        Vec::new()
+    }
+
+    /// Prepares the renderer before the first frame, like warming up its
+    /// caches, so the window does not open blank or stutter.
+    ///
+    /// It receives the DPI of the window.
+    ///
+    /// By default, it does nothing.
+    fn prepare(&self, _renderer: &mut Self::Renderer, _dpi: f32) {}
+
+    /// Receives a snapshot of the widgets of the [`Application`] every time
+    /// they are drawn, with their bounds after layout.
+    ///
+    /// Development tools can compare the snapshots of consecutive frames to
+    /// show what changed. The root of the snapshot holds the toasts shown by
+    /// commands, on top of the dialogs and the container that fills the
+    /// window with the [`view`].
+    ///
+    /// By default, it does nothing. It is only available with the `devtools`
+    /// feature.
+    ///
+    /// [`Application`]: trait.Application.html
+    /// [`view`]: #tymethod.view
+    #[cfg(feature = "devtools")]
+    fn inspect(&mut self, _snapshot: crate::inspect::Node) {}
 
     /// Runs the [`Application`].
     ///
@@ -85,74 +130,91 @@ pub trait Application: Sized {
     where
         Self: 'static,
     {
-        use winit::{
-            event::{self, WindowEvent},
-            event_loop::{ControlFlow, EventLoop},
-            window::WindowBuilder,
-        };
+        let host = Host::new(&settings);
+
+        Self::run_with(host, settings);
+    }
 
+    /// Runs the [`Application`] in the given [`Shell`].
+    ///
+    /// It allows hosting the [`Application`] outside of `winit`, like in an
+    /// SDL2 window or directly on a Wayland compositor. The title of the
+    /// window of the [`Shell`] is replaced with the title of the
+    /// [`Application`].
+    ///
+    /// Like [`run`], this method will take control of the current thread and
+    /// __will NOT return__.
+    ///
+    /// [`Application`]: trait.Application.html
+    /// [`Shell`]: shell/trait.Shell.html
+    /// [`run`]: #method.run
+    fn run_with<S: Shell>(mut shell: S, settings: Settings)
+    where
+        Self: 'static,
+    {
         let mut debug = Debug::new();
 
         debug.startup_started();
-        let event_loop = EventLoop::with_user_event();
-        let proxy = event_loop.create_proxy();
         let mut thread_pool =
             futures::executor::ThreadPool::new().expect("Create thread pool");
         let mut subscription_pool = subscription::Pool::new();
         let mut external_messages = Vec::new();
 
-        let (mut application, init_command) = Self::new();
-        spawn(init_command, &mut thread_pool, &proxy);
-
-        let subscription = application.subscription();
-        subscription_pool.update(subscription, &mut thread_pool, &proxy);
+        let mut toast_queue = toasts::Queue::new();
+        let mut dialog_queue = dialogs::Queue::new();
 
+        let (mut application, init_command) = Self::new();
         let mut title = application.title();
 
-        let window = {
-            let mut window_builder = WindowBuilder::new();
-
-            let (width, height) = settings.window.size;
+        shell.set_title(&title);
 
-            window_builder = window_builder
-                .with_title(&title)
-                .with_inner_size(winit::dpi::LogicalSize {
-                    width: f64::from(width),
-                    height: f64::from(height),
-                })
-                .with_resizable(settings.window.resizable)
-                .with_decorations(settings.window.decorations);
-
-            #[cfg(target_os = "windows")]
-            {
-                use winit::platform::windows::WindowBuilderExtWindows;
+        let (proxy, receiver) = Proxy::new(shell.waker());
 
-                if let Some(parent) = settings.window.platform_specific.parent {
-                    window_builder = window_builder.with_parent_window(parent);
-                }
-            }
+        spawn(
+            init_command,
+            &mut toast_queue,
+            &mut dialog_queue,
+            &mut thread_pool,
+            &proxy,
+            settings.url_schemes,
+        );
 
-            window_builder.build(&event_loop).expect("Open window")
-        };
+        let subscription = application.subscription();
+        subscription_pool.update(subscription, &mut thread_pool, &proxy);
 
-        let dpi = window.hidpi_factor();
-        let mut size = window.inner_size();
+        let dpi = shell.dpi();
+        let mut size = shell.size();
         let mut resized = false;
 
-        let clipboard = Clipboard::new(&window);
         let mut renderer = Self::Renderer::new();
 
+        let default_text_scale = text_scale::system();
+        let mut current_text_scale = default_text_scale;
+        renderer.set_text_scale(current_text_scale);
+        renderer.set_text_rendering(settings.text_rendering);
+        application.prepare(&mut renderer, dpi);
+
         let mut target = {
             let (width, height) = to_physical(size, dpi);
 
             <Self::Renderer as Windowed>::Target::new(
-                &window, width, height, dpi as f32, &renderer,
+                shell.window(),
+                width,
+                height,
+                dpi,
+                &renderer,
             )
         };
 
         debug.layout_started();
-        let user_interface = UserInterface::build(
-            document(&mut application, size, &mut debug),
+        let mut user_interface = UserInterface::build(
+            document(
+                &application,
+                &mut toast_queue,
+                &mut dialog_queue,
+                size,
+                &mut debug,
+            ),
             Cache::default(),
             &mut renderer,
         );
@@ -162,241 +224,350 @@ pub trait Application: Sized {
         let mut primitive = user_interface.draw(&mut renderer);
         debug.draw_finished();
 
+        // Widgets can schedule redraws to animate, like a blinking caret
+        let mut redraw_at = renderer.next_redraw();
+
+        // Input method editors place their candidate window next to the caret
+        // of the focused text field
+        let mut ime_caret = renderer.ime_caret();
+
+        if let Some(caret) = ime_caret {
+            shell.set_ime_caret(caret);
+        }
+
+        #[cfg(feature = "devtools")]
+        let snapshot = user_interface.inspect();
+
         let mut cache = Some(user_interface.into_cache());
+
+        #[cfg(feature = "devtools")]
+        application.inspect(snapshot);
         let mut events = Vec::new();
         let mut mouse_cursor = MouseCursor::OutOfBounds;
+
+        // The window may move to another display later on, but we only
+        // query the refresh rate on startup
+        let mut pacer = frame::Pacer::new(shell.refresh_rate().unwrap_or(60));
         debug.startup_finished();
 
-        window.request_redraw();
+        let mut is_redraw_requested = true;
 
-        event_loop.run(move |event, _, control_flow| match event {
-            event::Event::MainEventsCleared => {
-                if events.is_empty() && external_messages.is_empty() && !resized
-                {
-                    return;
-                }
+        // The regions changed since the last frame was drawn, or `None` when
+        // the whole frame needs to be drawn again
+        let mut damage: Option<Vec<Rectangle>> = None;
 
-                // TODO: We should be able to keep a user interface alive
-                // between events once we remove state references.
-                //
-                // This will allow us to rebuild it only when a message is
-                // handled.
-                debug.layout_started();
-                let mut user_interface = UserInterface::build(
-                    document(&mut application, size, &mut debug),
-                    cache.take().unwrap(),
-                    &mut renderer,
-                );
-                debug.layout_finished();
+        loop {
+            if is_redraw_requested {
+                debug.render_started();
+                pacer.begin();
+
+                if resized {
+                    let dpi = shell.dpi();
+                    let (width, height) = to_physical(size, dpi);
+
+                    target.resize(width, height, dpi, &renderer);
 
-                debug.event_processing_started();
-                events.iter().for_each(|event| {
-                    subscription_pool.broadcast_event(*event)
-                });
-
-                let mut messages = user_interface.update(
-                    &renderer,
-                    clipboard
-                        .as_ref()
-                        .map(|c| c as &dyn iced_native::Clipboard),
-                    events.drain(..),
+                    resized = false;
+                }
+
+                let new_mouse_cursor = renderer.draw(
+                    &primitive,
+                    damage.as_deref(),
+                    &debug.overlay(),
+                    &mut target,
                 );
-                messages.extend(external_messages.drain(..));
-                debug.event_processing_finished();
 
-                if messages.is_empty() {
-                    debug.draw_started();
-                    primitive = user_interface.draw(&mut renderer);
-                    debug.draw_finished();
+                is_redraw_requested = false;
+                damage = Some(Vec::new());
 
-                    cache = Some(user_interface.into_cache());
-                } else {
-                    // When there are messages, we are forced to rebuild twice
-                    // for now :^)
-                    let temp_cache = user_interface.into_cache();
+                // Animated images schedule their next frame while rendering
+                if let Some(at) = renderer.next_redraw() {
+                    redraw_at = Some(redraw_at.map_or(at, |next| next.min(at)));
+                }
 
-                    for message in messages {
-                        log::debug!("Updating");
+                debug.render_finished();
 
-                        debug.log_message(&message);
+                subscription_pool.broadcast_event(Event::Frame(pacer.end()));
 
-                        debug.update_started();
-                        let command = application.update(message);
-                        spawn(command, &mut thread_pool, &proxy);
-                        debug.update_finished();
-                    }
+                if let Some(pressure) = renderer.memory_pressure() {
+                    subscription_pool
+                        .broadcast_event(Event::MemoryPressure(pressure));
+                }
 
-                    let subscription = application.subscription();
-                    subscription_pool.update(
-                        subscription,
-                        &mut thread_pool,
-                        &proxy,
-                    );
+                if new_mouse_cursor != mouse_cursor {
+                    shell.set_mouse_cursor(new_mouse_cursor);
 
-                    // Update window title
-                    let new_title = application.title();
+                    mouse_cursor = new_mouse_cursor;
+                }
+            }
 
-                    if title != new_title {
-                        window.set_title(&new_title);
+            match shell.pump(&mut events, redraw_at) {
+                shell::Status::Closed => break,
+                shell::Status::Resized => {
+                    size = shell.size();
+                    resized = true;
+                    damage = None;
+                }
+                shell::Status::Redraw => {
+                    is_redraw_requested = true;
+                }
+                shell::Status::Running => {}
+            }
 
-                        title = new_title;
-                    }
+            external_messages.extend(receiver.try_iter());
 
-                    debug.layout_started();
-                    let user_interface = UserInterface::build(
-                        document(&mut application, size, &mut debug),
-                        temp_cache,
-                        &mut renderer,
-                    );
-                    debug.layout_finished();
+            for event in &events {
+                if let Event::Keyboard(keyboard::Event::Input {
+                    key_code,
+                    state: ButtonState::Pressed,
+                    modifiers,
+                }) = event
+                {
+                    if *key_code == keyboard::KeyCode::F12 {
+                        debug.toggle();
 
-                    debug.draw_started();
-                    primitive = user_interface.draw(&mut renderer);
-                    debug.draw_finished();
+                        // The overlay is not part of the damage
+                        is_redraw_requested = true;
+                        damage = None;
+                    }
 
-                    cache = Some(user_interface.into_cache());
+                    if modifiers.control || modifiers.logo {
+                        use keyboard::KeyCode;
+
+                        let new_text_scale = match key_code {
+                            KeyCode::Equals | KeyCode::Add => {
+                                text_scale::increase(current_text_scale)
+                            }
+                            KeyCode::Minus | KeyCode::Subtract => {
+                                text_scale::decrease(current_text_scale)
+                            }
+                            KeyCode::Key0 | KeyCode::Numpad0 => {
+                                default_text_scale
+                            }
+                            _ => current_text_scale,
+                        };
+
+                        if new_text_scale != current_text_scale {
+                            current_text_scale = new_text_scale;
+                            renderer.set_text_scale(current_text_scale);
+                            is_redraw_requested = true;
+                            damage = None;
+
+                            if let Some(cache) = cache.as_mut() {
+                                cache.invalidate_layout();
+                            }
+                        }
+                    }
                 }
+            }
 
-                window.request_redraw();
+            let is_redraw_due =
+                redraw_at.map_or(false, |at| at <= Instant::now());
+
+            if events.is_empty()
+                && external_messages.is_empty()
+                && !resized
+                && !is_redraw_due
+            {
+                continue;
             }
-            event::Event::UserEvent(message) => {
-                external_messages.push(message);
+
+            // Hotkeys take precedence over the widgets, so they are
+            // matched before the user interface is built.
+            let is_key_pressed = events.iter().any(|event| {
+                matches!(
+                    event,
+                    Event::Keyboard(keyboard::Event::Input {
+                        state: ButtonState::Pressed,
+                        ..
+                    })
+                )
+            });
+
+            if is_key_pressed {
+                hotkey::dispatch(
+                    application.hotkeys(),
+                    &mut events,
+                    &mut external_messages,
+                );
             }
-            event::Event::RedrawRequested(_) => {
-                debug.render_started();
 
-                if resized {
-                    let dpi = window.hidpi_factor();
-                    let (width, height) = to_physical(size, dpi);
+            // TODO: We should be able to keep a user interface alive
+            // between events once we remove state references.
+            //
+            // This will allow us to rebuild it only when a message is
+            // handled.
+            debug.layout_started();
+            let mut user_interface = UserInterface::build(
+                document(
+                    &application,
+                    &mut toast_queue,
+                    &mut dialog_queue,
+                    size,
+                    &mut debug,
+                ),
+                cache.take().unwrap(),
+                &mut renderer,
+            );
+            debug.layout_finished();
+
+            debug.event_processing_started();
+            events.iter().for_each(|event| {
+                subscription_pool.broadcast_event(event.clone())
+            });
+
+            let mut messages = user_interface.update(
+                &renderer,
+                shell.clipboard(),
+                events.drain(..),
+            );
+            messages.extend(external_messages.drain(..));
+            shell.set_mouse_captured(user_interface.is_mouse_captured());
+            debug.event_processing_finished();
+
+            #[cfg(feature = "devtools")]
+            let snapshot;
+
+            let new_primitive = if messages.is_empty() {
+                debug.draw_started();
+                let primitive = user_interface.draw(&mut renderer);
+                debug.draw_finished();
+
+                #[cfg(feature = "devtools")]
+                {
+                    snapshot = user_interface.inspect();
+                }
 
-                    target.resize(
-                        width,
-                        height,
-                        window.hidpi_factor() as f32,
-                        &renderer,
-                    );
+                cache = Some(user_interface.into_cache());
 
-                    resized = false;
+                primitive
+            } else {
+                // When there are messages, we are forced to rebuild twice
+                // for now :^)
+                let temp_cache = user_interface.into_cache();
+
+                for message in messages {
+                    log::debug!("Updating");
+
+                    debug.log_message(&message);
+
+                    debug.update_started();
+                    let command = application.update(message);
+                    spawn(
+                        command,
+                        &mut toast_queue,
+                        &mut dialog_queue,
+                        &mut thread_pool,
+                        &proxy,
+                        settings.url_schemes,
+                    );
+                    debug.update_finished();
                 }
 
-                let new_mouse_cursor =
-                    renderer.draw(&primitive, &debug.overlay(), &mut target);
+                let subscription = application.subscription();
+                subscription_pool.update(
+                    subscription,
+                    &mut thread_pool,
+                    &proxy,
+                );
 
-                debug.render_finished();
+                // Update window title
+                let new_title = application.title();
 
-                if new_mouse_cursor != mouse_cursor {
-                    window.set_cursor_icon(conversion::mouse_cursor(
-                        new_mouse_cursor,
-                    ));
+                if title != new_title {
+                    shell.set_title(&new_title);
 
-                    mouse_cursor = new_mouse_cursor;
+                    title = new_title;
                 }
 
-                // TODO: Handle animations!
-                // Maybe we can use `ControlFlow::WaitUntil` for this.
-            }
-            event::Event::WindowEvent {
-                event: window_event,
-                ..
-            } => match window_event {
-                WindowEvent::CursorMoved { position, .. } => {
-                    events.push(Event::Mouse(mouse::Event::CursorMoved {
-                        x: position.x as f32,
-                        y: position.y as f32,
-                    }));
-                }
-                WindowEvent::MouseInput { button, state, .. } => {
-                    events.push(Event::Mouse(mouse::Event::Input {
-                        button: conversion::mouse_button(button),
-                        state: conversion::button_state(state),
-                    }));
-                }
-                WindowEvent::MouseWheel { delta, .. } => match delta {
-                    winit::event::MouseScrollDelta::LineDelta(
-                        delta_x,
-                        delta_y,
-                    ) => {
-                        events.push(Event::Mouse(
-                            mouse::Event::WheelScrolled {
-                                delta: mouse::ScrollDelta::Lines {
-                                    x: delta_x,
-                                    y: delta_y,
-                                },
-                            },
-                        ));
-                    }
-                    winit::event::MouseScrollDelta::PixelDelta(position) => {
-                        events.push(Event::Mouse(
-                            mouse::Event::WheelScrolled {
-                                delta: mouse::ScrollDelta::Pixels {
-                                    x: position.x as f32,
-                                    y: position.y as f32,
-                                },
-                            },
-                        ));
-                    }
-                },
-                WindowEvent::ReceivedCharacter(c)
-                    if !is_private_use_character(c) =>
+                debug.layout_started();
+                let mut user_interface = UserInterface::build(
+                    document(
+                        &application,
+                        &mut toast_queue,
+                        &mut dialog_queue,
+                        size,
+                        &mut debug,
+                    ),
+                    temp_cache,
+                    &mut renderer,
+                );
+                debug.layout_finished();
+
+                debug.draw_started();
+                let primitive = user_interface.draw(&mut renderer);
+                debug.draw_finished();
+
+                #[cfg(feature = "devtools")]
                 {
-                    events.push(Event::Keyboard(
-                        keyboard::Event::CharacterReceived(c),
-                    ));
+                    snapshot = user_interface.inspect();
                 }
-                WindowEvent::KeyboardInput {
-                    input:
-                        winit::event::KeyboardInput {
-                            virtual_keycode: Some(virtual_keycode),
-                            state,
-                            modifiers,
-                            ..
-                        },
-                    ..
-                } => {
-                    match (virtual_keycode, state) {
-                        (
-                            winit::event::VirtualKeyCode::F12,
-                            winit::event::ElementState::Pressed,
-                        ) => debug.toggle(),
-                        _ => {}
-                    }
 
-                    events.push(Event::Keyboard(keyboard::Event::Input {
-                        key_code: conversion::key_code(virtual_keycode),
-                        state: conversion::button_state(state),
-                        modifiers: conversion::modifiers_state(modifiers),
-                    }));
-                }
-                WindowEvent::CloseRequested => {
-                    *control_flow = ControlFlow::Exit;
+                cache = Some(user_interface.into_cache());
+
+                primitive
+            };
+
+            #[cfg(feature = "devtools")]
+            application.inspect(snapshot);
+
+            // Most events, like moving the cursor over a static user
+            // interface, do not change anything on the screen. We skip
+            // the redraw in that case.
+            let regions = renderer.damage(&primitive, &new_primitive);
+            let is_damaged = !regions.is_empty();
+
+            if let Some(damage) = &mut damage {
+                damage.extend(regions);
+            }
+
+            // The old primitive is not needed anymore, but its
+            // allocations can be reused to build the next one
+            renderer.recycle(std::mem::replace(&mut primitive, new_primitive));
+            redraw_at = renderer.next_redraw();
+
+            let new_ime_caret = renderer.ime_caret();
+
+            if new_ime_caret != ime_caret {
+                if let Some(caret) = new_ime_caret {
+                    shell.set_ime_caret(caret);
                 }
-                WindowEvent::Resized(new_size) => {
-                    size = new_size;
-                    resized = true;
 
-                    log::debug!("Resized: {:?}", new_size);
+                ime_caret = new_ime_caret;
+            }
+
+            // A due redraw may not change the primitive at all, like when
+            // an animated image moves on to its next frame
+            if is_damaged || resized || is_redraw_due {
+                is_redraw_requested = true;
+            } else {
+                let new_mouse_cursor = renderer.mouse_cursor(&primitive);
+
+                if new_mouse_cursor != mouse_cursor {
+                    shell.set_mouse_cursor(new_mouse_cursor);
+
+                    mouse_cursor = new_mouse_cursor;
                 }
-                _ => {}
-            },
-            _ => {
-                *control_flow = ControlFlow::Wait;
             }
-        })
+        }
+
+        // Like `winit`, we do not give control back once the window is closed
+        std::process::exit(0);
     }
 }
 
-fn to_physical(size: winit::dpi::LogicalSize, dpi: f64) -> (u16, u16) {
-    let physical_size = size.to_physical(dpi);
-
+fn to_physical(size: Size, dpi: f32) -> (u16, u16) {
     (
-        physical_size.width.round() as u16,
-        physical_size.height.round() as u16,
+        (size.width * dpi).round() as u16,
+        (size.height * dpi).round() as u16,
     )
 }
 
 fn document<'a, Application>(
-    application: &'a mut Application,
-    size: winit::dpi::LogicalSize,
+    application: &'a Application,
+    toast_queue: &'a mut toasts::Queue<Application::Message>,
+    dialog_queue: &'a mut dialogs::Queue<Application::Message>,
+    size: Size,
     debug: &mut Debug,
 ) -> Element<'a, Application::Message, Application::Renderer>
 where
@@ -407,41 +578,54 @@ where
     let view = application.view();
     debug.view_finished();
 
-    Container::new(view)
+    let content = Container::new(view)
         .width(Length::Units(size.width.round() as u16))
-        .height(Length::Units(size.height.round() as u16))
-        .into()
+        .height(Length::Units(size.height.round() as u16));
+
+    Toasts::new(toast_queue, Dialogs::new(dialog_queue, content)).into()
 }
 
-fn spawn<Message: Send>(
-    command: Command<Message>,
+fn spawn<Message: Send + 'static>(
+    mut command: Command<Message>,
+    toast_queue: &mut toasts::Queue<Message>,
+    dialog_queue: &mut dialogs::Queue<Message>,
     thread_pool: &mut futures::executor::ThreadPool,
-    proxy: &winit::event_loop::EventLoopProxy<Message>,
+    proxy: &Proxy<Message>,
+    url_schemes: &[&str],
 ) {
     use futures::FutureExt;
 
+    for toast in command.take_toasts() {
+        toast_queue.push(toast);
+    }
+
+    for dialog in command.take_dialogs() {
+        dialog_queue.push(dialog);
+    }
+
+    let notifications = command
+        .take_notifications()
+        .into_iter()
+        .map(|notification| notification::post(notification).boxed());
+
+    let urls = command
+        .take_urls()
+        .into_iter()
+        .map(|url| url::open(url, url_schemes).map(|_| None).boxed());
+
     let futures = command.futures();
 
-    for future in futures {
+    for future in futures.into_iter().chain(notifications).chain(urls) {
         let proxy = proxy.clone();
 
         let future = future.map(move |message| {
-            proxy
-                .send_event(message)
-                .expect("Send command result to event loop");
+            if let Some(message) = message {
+                proxy
+                    .send(message)
+                    .expect("Send command result to event loop");
+            }
         });
 
         thread_pool.spawn_ok(future);
     }
 }
-
-// As defined in: http://www.unicode.org/faq/private_use.html
-// TODO: Remove once https://github.com/rust-windowing/winit/pull/1254 lands
-fn is_private_use_character(c: char) -> bool {
-    match c {
-        '\u{E000}'..='\u{F8FF}'
-        | '\u{F0000}'..='\u{FFFFD}'
-        | '\u{100000}'..='\u{10FFFD}' => true,
-        _ => false,
-    }
-}
@@ -1,3 +1,5 @@
+use iced_native::clipboard::Error;
+
 pub struct Clipboard(window_clipboard::Clipboard);
 
 impl Clipboard {
@@ -10,4 +12,33 @@ impl iced_native::Clipboard for Clipboard {
     fn content(&self) -> Option<String> {
         self.0.read().ok()
     }
+
+    // The pinned `window_clipboard` can only read, so every write reports
+    // that it is unsupported instead of failing silently.
+    fn write(&self, _content: String) -> Result<(), Error> {
+        log::warn!("Writing text to the clipboard is not supported yet");
+
+        Err(Error::Unsupported)
+    }
+
+    fn write_image(
+        &self,
+        width: u32,
+        height: u32,
+        _pixels: Vec<u8>,
+    ) -> Result<(), Error> {
+        log::warn!(
+            "Writing a {}x{} image to the clipboard is not supported yet",
+            width,
+            height
+        );
+
+        Err(Error::Unsupported)
+    }
+
+    fn write_html(&self, _html: String, text: String) -> Result<(), Error> {
+        log::warn!("Writing HTML to the clipboard is not supported yet");
+
+        self.write(text)
+    }
 }
@@ -7,7 +7,7 @@ use crate::{
         keyboard::{KeyCode, ModifiersState},
         mouse, ButtonState,
     },
-    MouseCursor,
+    MouseCursor, Rectangle,
 };
 
 /// Convert a `MouseCursor` from [`iced_native`] to a [`winit`] cursor icon.
@@ -23,9 +23,41 @@ pub fn mouse_cursor(mouse_cursor: MouseCursor) -> winit::window::CursorIcon {
         MouseCursor::Grab => winit::window::CursorIcon::Grab,
         MouseCursor::Grabbing => winit::window::CursorIcon::Grabbing,
         MouseCursor::Text => winit::window::CursorIcon::Text,
+        MouseCursor::ResizingHorizontally => {
+            winit::window::CursorIcon::EwResize
+        }
+        MouseCursor::ResizingVertically => winit::window::CursorIcon::NsResize,
+        MouseCursor::ResizingDiagonallyDown => {
+            winit::window::CursorIcon::NwseResize
+        }
+        MouseCursor::ResizingDiagonallyUp => {
+            winit::window::CursorIcon::NeswResize
+        }
+        MouseCursor::Move => winit::window::CursorIcon::Move,
+        MouseCursor::Crosshair => winit::window::CursorIcon::Crosshair,
+        MouseCursor::ZoomIn => winit::window::CursorIcon::ZoomIn,
+        MouseCursor::ZoomOut => winit::window::CursorIcon::ZoomOut,
+        MouseCursor::Help => winit::window::CursorIcon::Help,
+        MouseCursor::NotAllowed => winit::window::CursorIcon::NotAllowed,
+        // The cursor is hidden by the window instead
+        MouseCursor::Hidden => winit::window::CursorIcon::Default,
     }
 }
 
+/// Convert the caret of a text field from [`iced_native`] to the position of
+/// the candidate window of an input method editor in [`winit`].
+///
+/// The candidate window is placed right below the caret.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+/// [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
+pub fn ime_position(caret: Rectangle) -> winit::dpi::LogicalPosition {
+    winit::dpi::LogicalPosition::new(
+        f64::from(caret.x),
+        f64::from(caret.y + caret.height),
+    )
+}
+
 /// Convert a `MouseButton` from [`winit`] to an [`iced_native`] mouse button.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -35,10 +67,31 @@ pub fn mouse_button(mouse_button: winit::event::MouseButton) -> mouse::Button {
         winit::event::MouseButton::Left => mouse::Button::Left,
         winit::event::MouseButton::Right => mouse::Button::Right,
         winit::event::MouseButton::Middle => mouse::Button::Middle,
-        winit::event::MouseButton::Other(other) => mouse::Button::Other(other),
+        winit::event::MouseButton::Other(other) => match other {
+            BACK_BUTTON => mouse::Button::Back,
+            FORWARD_BUTTON => mouse::Button::Forward,
+            _ => mouse::Button::Other(other),
+        },
     }
 }
 
+// `winit` reports the side buttons with the identifiers of each platform.
+// On Linux, these are the ones of X11.
+#[cfg(target_os = "windows")]
+const BACK_BUTTON: u8 = 1;
+#[cfg(target_os = "windows")]
+const FORWARD_BUTTON: u8 = 2;
+
+#[cfg(target_os = "macos")]
+const BACK_BUTTON: u8 = 3;
+#[cfg(target_os = "macos")]
+const FORWARD_BUTTON: u8 = 4;
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+const BACK_BUTTON: u8 = 8;
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+const FORWARD_BUTTON: u8 = 9;
+
 /// Convert an `ElementState` from [`winit`] to an [`iced_native`] button state.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
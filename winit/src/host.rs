@@ -0,0 +1,335 @@
+use crate::{
+    conversion,
+    input::{keyboard, mouse, ButtonState},
+    settings, shell, Clipboard, Event, MouseCursor, Rectangle, Settings, Size,
+};
+use std::{
+    sync::{Arc, Mutex},
+    task::Waker,
+    time::Instant,
+};
+use winit::{
+    event::{self, WindowEvent},
+    event_loop::{ControlFlow, EventLoop, EventLoopProxy},
+    platform::desktop::EventLoopExtDesktop,
+    window::{Window, WindowBuilder},
+};
+
+/// A [`Shell`] hosting the runtime in a `winit` window.
+///
+/// `winit` drives its event loop with a callback. Instead, the [`Host`] runs
+/// it until there is something to process, and returns control to the
+/// runtime in between.
+///
+/// [`Shell`]: ../shell/trait.Shell.html
+/// [`Host`]: struct.Host.html
+#[allow(missing_debug_implementations)]
+pub struct Host {
+    event_loop: EventLoop<()>,
+    window: Window,
+    clipboard: Option<Clipboard>,
+    waker: Waker,
+    mouse_cursor: MouseCursor,
+    is_mouse_captured: bool,
+    coalesce_cursor_moves: bool,
+    scroll: settings::Scroll,
+}
+
+impl Host {
+    /// Opens a new window with the given [`Settings`].
+    ///
+    /// [`Settings`]: ../settings/struct.Settings.html
+    pub fn new(settings: &Settings) -> Self {
+        let event_loop = EventLoop::with_user_event();
+
+        let window = {
+            let mut window_builder = WindowBuilder::new();
+
+            let (width, height) = settings.window.size;
+
+            window_builder = window_builder
+                .with_inner_size(winit::dpi::LogicalSize {
+                    width: f64::from(width),
+                    height: f64::from(height),
+                })
+                .with_resizable(settings.window.resizable)
+                .with_decorations(settings.window.decorations);
+
+            #[cfg(target_os = "windows")]
+            {
+                use winit::platform::windows::WindowBuilderExtWindows;
+
+                if let Some(parent) = settings.window.platform_specific.parent {
+                    window_builder = window_builder.with_parent_window(parent);
+                }
+            }
+
+            window_builder.build(&event_loop).expect("Open window")
+        };
+
+        let clipboard = Clipboard::new(&window);
+        let waker = futures::task::waker(Arc::new(Wake(Mutex::new(
+            event_loop.create_proxy(),
+        ))));
+
+        Host {
+            event_loop,
+            window,
+            clipboard,
+            waker,
+            mouse_cursor: MouseCursor::OutOfBounds,
+            is_mouse_captured: false,
+            coalesce_cursor_moves: settings.coalesce_cursor_moves,
+            scroll: settings.scroll,
+        }
+    }
+}
+
+impl shell::Shell for Host {
+    type Window = Window;
+
+    fn window(&self) -> &Window {
+        &self.window
+    }
+
+    fn size(&self) -> Size {
+        let size = self.window.inner_size();
+
+        Size::new(size.width as f32, size.height as f32)
+    }
+
+    fn dpi(&self) -> f32 {
+        self.window.hidpi_factor() as f32
+    }
+
+    fn refresh_rate(&self) -> Option<u16> {
+        // `winit` does not tell us the current video mode of a monitor. We
+        // assume it is the fastest mode with the current resolution.
+        let monitor = self.window.current_monitor();
+        let size = monitor.size();
+
+        monitor
+            .video_modes()
+            .filter(|mode| mode.size() == size)
+            .map(|mode| mode.refresh_rate())
+            .filter(|refresh_rate| *refresh_rate > 0)
+            .max()
+    }
+
+    fn pump(
+        &mut self,
+        events: &mut Vec<Event>,
+        deadline: Option<Instant>,
+    ) -> shell::Status {
+        let Host {
+            event_loop,
+            is_mouse_captured,
+            coalesce_cursor_moves,
+            scroll,
+            ..
+        } = self;
+
+        let mut status = shell::Status::Running;
+        let mut is_woken = false;
+
+        event_loop.run_return(|event, _, control_flow| {
+            // Events may still arrive once we have asked to exit
+            if *control_flow != ControlFlow::Exit {
+                *control_flow = match deadline {
+                    Some(at) => ControlFlow::WaitUntil(at),
+                    None => ControlFlow::Wait,
+                };
+            }
+
+            match event {
+                event::Event::MainEventsCleared => {
+                    let is_deadline_reached =
+                        deadline.map_or(false, |at| at <= Instant::now());
+
+                    if !events.is_empty()
+                        || is_woken
+                        || status != shell::Status::Running
+                        || is_deadline_reached
+                    {
+                        *control_flow = ControlFlow::Exit;
+                    }
+                }
+                event::Event::RedrawRequested(_) => {
+                    if status == shell::Status::Running {
+                        status = shell::Status::Redraw;
+                    }
+
+                    *control_flow = ControlFlow::Exit;
+                }
+                event::Event::UserEvent(()) => {
+                    is_woken = true;
+                }
+                event::Event::WindowEvent {
+                    event: window_event,
+                    ..
+                } => match window_event {
+                    WindowEvent::CursorMoved { position, .. } => {
+                        let event = Event::Mouse(mouse::Event::CursorMoved {
+                            x: position.x as f32,
+                            y: position.y as f32,
+                        });
+
+                        // Only the latest of consecutive movements matters
+                        if *coalesce_cursor_moves {
+                            if let Some(Event::Mouse(
+                                mouse::Event::CursorMoved { .. },
+                            )) = events.last()
+                            {
+                                let _ = events.pop();
+                            }
+                        }
+
+                        events.push(event);
+                    }
+                    WindowEvent::CursorEntered { .. } => {
+                        events.push(Event::Mouse(mouse::Event::CursorEntered));
+                    }
+                    WindowEvent::CursorLeft { .. } => {
+                        events.push(Event::Mouse(mouse::Event::CursorLeft));
+                    }
+                    WindowEvent::Focused(false) if *is_mouse_captured => {
+                        events.push(Event::Mouse(mouse::Event::Input {
+                            button: mouse::Button::Left,
+                            state: ButtonState::Released,
+                        }));
+                    }
+                    WindowEvent::MouseInput { button, state, .. } => {
+                        events.push(Event::Mouse(mouse::Event::Input {
+                            button: conversion::mouse_button(button),
+                            state: conversion::button_state(state),
+                        }));
+                    }
+                    WindowEvent::MouseWheel { delta, .. } => {
+                        let delta = match delta {
+                            event::MouseScrollDelta::LineDelta(x, y) => {
+                                mouse::ScrollDelta::Lines { x, y }
+                            }
+                            event::MouseScrollDelta::PixelDelta(position) => {
+                                mouse::ScrollDelta::Pixels {
+                                    x: position.x as f32,
+                                    y: position.y as f32,
+                                }
+                            }
+                        };
+
+                        events.push(Event::Mouse(
+                            mouse::Event::WheelScrolled {
+                                delta,
+                                pixels: delta.to_pixels(
+                                    scroll.line_height,
+                                    scroll.multiplier,
+                                ),
+                            },
+                        ));
+                    }
+                    // This version of `winit` does not report the text being
+                    // composed with an input method editor, only the
+                    // committed text as characters, so no `ime::Event` is
+                    // produced yet
+                    WindowEvent::ReceivedCharacter(c)
+                        if !is_private_use_character(c) =>
+                    {
+                        events.push(Event::Keyboard(
+                            keyboard::Event::CharacterReceived(c),
+                        ));
+                    }
+                    WindowEvent::KeyboardInput {
+                        input:
+                            event::KeyboardInput {
+                                virtual_keycode: Some(virtual_keycode),
+                                state,
+                                modifiers,
+                                ..
+                            },
+                        ..
+                    } => {
+                        events.push(Event::Keyboard(keyboard::Event::Input {
+                            key_code: conversion::key_code(virtual_keycode),
+                            state: conversion::button_state(state),
+                            modifiers: conversion::modifiers_state(modifiers),
+                        }));
+                    }
+                    WindowEvent::CloseRequested => {
+                        status = shell::Status::Closed;
+                    }
+                    WindowEvent::Resized(new_size) => {
+                        if status != shell::Status::Closed {
+                            status = shell::Status::Resized;
+                        }
+
+                        log::debug!("Resized: {:?}", new_size);
+                    }
+                    _ => {}
+                },
+                _ => {}
+            }
+        });
+
+        status
+    }
+
+    fn waker(&self) -> Waker {
+        self.waker.clone()
+    }
+
+    fn clipboard(&self) -> Option<&dyn iced_native::Clipboard> {
+        self.clipboard
+            .as_ref()
+            .map(|clipboard| clipboard as &dyn iced_native::Clipboard)
+    }
+
+    fn set_mouse_cursor(&mut self, mouse_cursor: MouseCursor) {
+        self.window
+            .set_cursor_icon(conversion::mouse_cursor(mouse_cursor));
+
+        if (mouse_cursor == MouseCursor::Hidden)
+            != (self.mouse_cursor == MouseCursor::Hidden)
+        {
+            self.window
+                .set_cursor_visible(mouse_cursor != MouseCursor::Hidden);
+        }
+
+        self.mouse_cursor = mouse_cursor;
+    }
+
+    fn set_mouse_captured(&mut self, is_mouse_captured: bool) {
+        self.is_mouse_captured = is_mouse_captured;
+    }
+
+    fn set_title(&mut self, title: &str) {
+        self.window.set_title(title);
+    }
+
+    fn set_ime_caret(&mut self, caret: Rectangle) {
+        self.window
+            .set_ime_position(conversion::ime_position(caret));
+    }
+}
+
+// Wakes up the event loop of a `Host` from any thread
+struct Wake(Mutex<EventLoopProxy<()>>);
+
+impl futures::task::ArcWake for Wake {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        if let Ok(proxy) = arc_self.0.lock() {
+            // The event loop may be gone already
+            let _ = proxy.send_event(());
+        }
+    }
+}
+
+// As defined in: http://www.unicode.org/faq/private_use.html
+// TODO: Remove once https://github.com/rust-windowing/winit/pull/1254 lands
+fn is_private_use_character(c: char) -> bool {
+    match c {
+        '\u{E000}'..='\u{F8FF}'
+        | '\u{F0000}'..='\u{FFFFD}'
+        | '\u{100000}'..='\u{10FFFD}' => true,
+        _ => false,
+    }
+}
@@ -30,7 +30,12 @@ pub mod settings;
 
 mod application;
 mod clipboard;
+mod host;
+mod notification;
+mod proxy;
 mod subscription;
+mod text_scale;
+mod url;
 
 // We disable debug capabilities on release builds unless the `debug` feature
 // is explicitly enabled.
@@ -46,3 +51,5 @@ pub use settings::Settings;
 
 use clipboard::Clipboard;
 use debug::Debug;
+use host::Host;
+use proxy::Proxy;
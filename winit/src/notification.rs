@@ -0,0 +1,135 @@
+use crate::Notification;
+
+use futures::future::{Future, FutureExt};
+
+/// Posts the [`Notification`], resolving to its message once it is clicked.
+///
+/// [`Notification`]: ../struct.Notification.html
+pub fn post<Message>(
+    notification: Notification<Message>,
+) -> impl Future<Output = Option<Message>> + Send
+where
+    Message: Send + 'static,
+{
+    let (sender, receiver) = futures::channel::oneshot::channel();
+
+    // Waiting for a click blocks until the notification is closed, so we use a
+    // dedicated thread instead of blocking the executor.
+    let _ = std::thread::spawn(move || {
+        let is_clicked = show(&notification);
+
+        let _ = sender.send(notification.on_click.filter(|_| is_clicked));
+    });
+
+    receiver.map(|message| message.unwrap_or(None))
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn show<Message>(notification: &Notification<Message>) -> bool {
+    let mut command = std::process::Command::new("notify-send");
+
+    if let Some(icon) = &notification.icon {
+        let _ = command.arg("--icon").arg(icon);
+    }
+
+    if notification.on_click.is_some() {
+        let _ = command.arg("--action=default=Open").arg("--wait");
+    }
+
+    let _ = command
+        .arg("--")
+        .arg(&notification.title)
+        .arg(&notification.body);
+
+    match command.output() {
+        Ok(output) => {
+            notification.on_click.is_some()
+                && String::from_utf8_lossy(&output.stdout).trim() == "default"
+        }
+        Err(_) => false,
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn show<Message>(notification: &Notification<Message>) -> bool {
+    let quote = |text: &str| {
+        format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+    };
+
+    let script = format!(
+        "display notification {} with title {}",
+        quote(&notification.body),
+        quote(&notification.title)
+    );
+
+    let _ = std::process::Command::new("osascript")
+        .arg("-e")
+        .arg(script)
+        .status();
+
+    false
+}
+
+#[cfg(target_os = "windows")]
+fn show<Message>(notification: &Notification<Message>) -> bool {
+    use std::os::windows::process::CommandExt;
+
+    // Toasts need the id of an installed application. We borrow the one
+    // of PowerShell, which is always available.
+    const APP_ID: &str = "{1AC14E77-02E7-4E5D-B744-2EB1AE5198B7}\\WindowsPowerShell\\v1.0\\powershell.exe";
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+    let escape = |text: &str| {
+        text.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+            .replace('\'', "&apos;")
+    };
+
+    let image = notification
+        .icon
+        .as_ref()
+        .map(|icon| {
+            format!(
+                "<image placement=\"appLogoOverride\" src=\"{}\"/>",
+                escape(&icon.to_string_lossy())
+            )
+        })
+        .unwrap_or_default();
+
+    let xml = format!(
+        "<toast><visual><binding template=\"ToastGeneric\">\
+         <text>{}</text><text>{}</text>{}\
+         </binding></visual></toast>",
+        escape(&notification.title),
+        escape(&notification.body),
+        image
+    );
+
+    let script = format!(
+        "[Windows.UI.Notifications.ToastNotificationManager, \
+         Windows.UI.Notifications, ContentType = WindowsRuntime] | Out-Null\n\
+         [Windows.Data.Xml.Dom.XmlDocument, \
+         Windows.Data.Xml.Dom.XmlDocument, ContentType = WindowsRuntime] | Out-Null\n\
+         $xml = New-Object Windows.Data.Xml.Dom.XmlDocument\n\
+         $xml.LoadXml('{}')\n\
+         $toast = New-Object Windows.UI.Notifications.ToastNotification $xml\n\
+         [Windows.UI.Notifications.ToastNotificationManager]::\
+         CreateToastNotifier('{}').Show($toast)",
+        xml.replace('\'', "''"),
+        APP_ID
+    );
+
+    let _ = std::process::Command::new("powershell")
+        .args(&["-NoProfile", "-NonInteractive", "-Command", &script])
+        .creation_flags(CREATE_NO_WINDOW)
+        .status();
+
+    false
+}
+
+#[cfg(not(any(unix, target_os = "windows")))]
+fn show<Message>(_notification: &Notification<Message>) -> bool {
+    false
+}
@@ -0,0 +1,40 @@
+use std::{sync::mpsc, task::Waker};
+
+/// Sends messages to the runtime from any thread, waking up its shell.
+pub struct Proxy<Message> {
+    sender: mpsc::Sender<Message>,
+    waker: Waker,
+}
+
+impl<Message> Proxy<Message> {
+    /// Creates a new [`Proxy`] waking up a shell with the given [`Waker`],
+    /// along with the receiver of its messages.
+    ///
+    /// [`Proxy`]: struct.Proxy.html
+    /// [`Waker`]: https://doc.rust-lang.org/std/task/struct.Waker.html
+    pub fn new(waker: Waker) -> (Self, mpsc::Receiver<Message>) {
+        let (sender, receiver) = mpsc::channel();
+
+        (Proxy { sender, waker }, receiver)
+    }
+
+    /// Sends a message to the runtime, waking up its shell.
+    pub fn send(
+        &self,
+        message: Message,
+    ) -> Result<(), mpsc::SendError<Message>> {
+        self.sender.send(message)?;
+        self.waker.wake_by_ref();
+
+        Ok(())
+    }
+}
+
+impl<Message> Clone for Proxy<Message> {
+    fn clone(&self) -> Self {
+        Proxy {
+            sender: self.sender.clone(),
+            waker: self.waker.clone(),
+        }
+    }
+}
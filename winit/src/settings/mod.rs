@@ -7,15 +7,94 @@ mod platform;
 #[path = "not_windows.rs"]
 mod platform;
 
+pub use iced_native::renderer::{TextAntialiasing, TextRendering};
 pub use platform::PlatformSpecific;
 
 /// The settings of an application.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Settings {
     /// The [`Window`] settings
     ///
     /// [`Window`]: struct.Window.html
     pub window: Window,
+
+    /// Whether consecutive cursor movements received in the same frame
+    /// should be delivered as a single movement to the latest position.
+    ///
+    /// Mice with a high polling rate can report a thousand movements per
+    /// second, and processing every one of them is wasteful. Positions are
+    /// absolute, so the movement from the previously delivered position
+    /// still adds up every intermediate movement. Mouse buttons split
+    /// coalesced movements, so drags start and end where expected.
+    ///
+    /// Applications tracing the exact path of the cursor, like drawing
+    /// tools, may want to disable it.
+    ///
+    /// By default, it is enabled.
+    pub coalesce_cursor_moves: bool,
+
+    /// The [`Scroll`] settings
+    ///
+    /// [`Scroll`]: struct.Scroll.html
+    pub scroll: Scroll,
+
+    /// How the glyphs of text are rasterized.
+    ///
+    /// Subpixel antialiasing and hinting make small text sharper on screens
+    /// with a low DPI. By default, text uses grayscale antialiasing without
+    /// hinting.
+    pub text_rendering: TextRendering,
+
+    /// The schemes of the URLs that commands are allowed to open.
+    ///
+    /// URLs with any other scheme, like `file` or the custom scheme of
+    /// another application, are dropped without being opened. Add them here
+    /// only if your application needs them, and never open URLs coming from
+    /// untrusted content with them.
+    ///
+    /// By default, only `http`, `https`, and `mailto` URLs are opened.
+    pub url_schemes: &'static [&'static str],
+}
+
+impl Default for Settings {
+    fn default() -> Settings {
+        Settings {
+            window: Window::default(),
+            coalesce_cursor_moves: true,
+            scroll: Scroll::default(),
+            text_rendering: TextRendering::default(),
+            url_schemes: &["http", "https", "mailto"],
+        }
+    }
+}
+
+/// The scroll settings of an application.
+///
+/// Platforms report scroll movements in different units and at different
+/// rates. Windows and X11 report mouse wheels in lines, while macOS reports
+/// trackpads in pixels. These settings convert both into logical pixels for
+/// the `pixels` of a `WheelScrolled` event.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Scroll {
+    /// The logical pixels scrolled per line.
+    ///
+    /// By default, it is `60.0`.
+    pub line_height: f32,
+
+    /// The factor applied to every scroll movement, after converting lines
+    /// into pixels.
+    ///
+    /// By default, it is `1.0`.
+    pub multiplier: f32,
+}
+
+impl Default for Scroll {
+    fn default() -> Scroll {
+        Scroll {
+            line_height: 60.0,
+            multiplier: 1.0,
+        }
+    }
 }
 
 /// The window settings of an application.
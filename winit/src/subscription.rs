@@ -1,3 +1,4 @@
+use crate::Proxy;
 use iced_native::{Event, Hasher, Subscription};
 use std::collections::HashMap;
 
@@ -21,7 +22,7 @@ impl Pool {
         &mut self,
         subscription: Subscription<Message>,
         thread_pool: &mut futures::executor::ThreadPool,
-        proxy: &winit::event_loop::EventLoopProxy<Message>,
+        proxy: &Proxy<Message>,
     ) {
         use futures::{future::FutureExt, stream::StreamExt};
 
@@ -54,7 +55,7 @@ impl Pool {
                     cancelled,
                     stream.for_each(move |message| {
                         proxy
-                            .send_event(message)
+                            .send(message)
                             .expect("Send subscription result to event loop");
 
                         futures::future::ready(())
@@ -86,7 +87,7 @@ impl Pool {
             .values_mut()
             .filter_map(|connection| connection.listener.as_mut())
             .for_each(|listener| {
-                if let Err(error) = listener.try_send(event) {
+                if let Err(error) = listener.try_send(event.clone()) {
                     log::error!(
                         "Error sending event to subscription: {:?}",
                         error
@@ -0,0 +1,66 @@
+//! Adjust the scale of the text at runtime.
+const MIN: f32 = 0.5;
+const MAX: f32 = 3.0;
+const STEP: f32 = 0.1;
+
+/// Returns the text scale preferred by the user in the operating system.
+pub fn system() -> f32 {
+    platform()
+        .filter(|scale| scale.is_finite() && *scale > 0.0)
+        .unwrap_or(1.0)
+        .clamp(MIN, MAX)
+}
+
+pub fn increase(scale: f32) -> f32 {
+    round(scale + STEP).min(MAX)
+}
+
+pub fn decrease(scale: f32) -> f32 {
+    round(scale - STEP).max(MIN)
+}
+
+// Keeps the steps from accumulating rounding errors
+fn round(scale: f32) -> f32 {
+    (scale / STEP).round() * STEP
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn platform() -> Option<f32> {
+    // Most desktop environments follow the GNOME setting
+    let output = std::process::Command::new("gsettings")
+        .args(&["get", "org.gnome.desktop.interface", "text-scaling-factor"])
+        .output()
+        .ok()?;
+
+    String::from_utf8(output.stdout).ok()?.trim().parse().ok()
+}
+
+#[cfg(target_os = "windows")]
+fn platform() -> Option<f32> {
+    // The "Make text bigger" accessibility setting, as a percentage. The
+    // output of the query looks like:
+    //
+    //     TextScaleFactor    REG_DWORD    0x7d
+    let output = std::process::Command::new("reg")
+        .args(&[
+            "query",
+            "HKCU\\Software\\Microsoft\\Accessibility",
+            "/v",
+            "TextScaleFactor",
+        ])
+        .output()
+        .ok()?;
+
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    let value = stdout.split_whitespace().last()?;
+    let percentage =
+        u32::from_str_radix(value.trim_start_matches("0x"), 16).ok()?;
+
+    Some(percentage as f32 / 100.0)
+}
+
+// TODO: Read the preferred content size on macOS
+#[cfg(not(any(all(unix, not(target_os = "macos")), target_os = "windows")))]
+fn platform() -> Option<f32> {
+    None
+}
@@ -0,0 +1,78 @@
+use futures::future::{Future, FutureExt};
+
+/// Opens the given URL with the default application of the platform,
+/// resolving once the opener has been launched.
+///
+/// The URL is dropped unless its scheme is one of the given ones. Openers
+/// also run local files and hand custom schemes over to other applications.
+pub fn open(url: String, schemes: &[&str]) -> impl Future<Output = ()> + Send {
+    let (sender, receiver) = futures::channel::oneshot::channel();
+
+    match scheme(&url) {
+        Some(scheme)
+            if schemes
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(scheme)) =>
+        {
+            // Some openers only exit once the URL has been handed over, so
+            // we use a dedicated thread instead of blocking the executor.
+            let _ = std::thread::spawn(move || {
+                launch(&url);
+
+                let _ = sender.send(());
+            });
+        }
+        scheme => {
+            log::warn!("Not opening URL with scheme {:?}", scheme);
+        }
+    }
+
+    receiver.map(|_| ())
+}
+
+// As defined in: https://tools.ietf.org/html/rfc3986#section-3.1
+fn scheme(url: &str) -> Option<&str> {
+    let (scheme, _) = url.split_at(url.find(':')?);
+
+    let mut characters = scheme.chars();
+    let is_valid = characters
+        .next()
+        .map_or(false, |first| first.is_ascii_alphabetic())
+        && characters.all(|c| {
+            c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.'
+        });
+
+    if is_valid {
+        Some(scheme)
+    } else {
+        None
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn launch(url: &str) {
+    let _ = std::process::Command::new("xdg-open").arg(url).status();
+}
+
+#[cfg(target_os = "macos")]
+fn launch(url: &str) {
+    let _ = std::process::Command::new("open").arg(url).status();
+}
+
+#[cfg(target_os = "windows")]
+fn launch(url: &str) {
+    use std::os::windows::process::CommandExt;
+
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+    // `start` would interpret the ampersands of the query, so we use the URL
+    // protocol handler directly
+    let _ = std::process::Command::new("rundll32")
+        .arg("url.dll,FileProtocolHandler")
+        .arg(url)
+        .creation_flags(CREATE_NO_WINDOW)
+        .status();
+}
+
+#[cfg(not(any(unix, target_os = "windows")))]
+fn launch(_url: &str) {}